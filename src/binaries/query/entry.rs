@@ -33,6 +33,7 @@ use databend_common_storage::DataOperator;
 use databend_common_tracing::set_panic_hook;
 use databend_enterprise_background_service::get_background_service_handler;
 use databend_query::clusters::ClusterDiscovery;
+use databend_query::history_tables::HistoryLog;
 use databend_query::local;
 use databend_query::servers::admin::AdminService;
 use databend_query::servers::flight::FlightService;
@@ -44,6 +45,7 @@ use databend_query::servers::MySQLHandler;
 use databend_query::servers::MySQLTlsConfig;
 use databend_query::servers::Server;
 use databend_query::servers::ShutdownHandle;
+use databend_query::tasks::TaskScheduler;
 use databend_query::GlobalServices;
 use log::info;
 
@@ -260,6 +262,23 @@ pub async fn start_services(conf: &InnerConfig) -> Result<(), MainError> {
         info!("Listening for FlightSQL API: {}", listening);
     }
 
+    // Embedded task scheduler.
+    let task_scheduler = if conf.query.embedded_task_scheduler() {
+        let scheduler = TaskScheduler::create(conf);
+        scheduler.start();
+        info!("Embedded task scheduler started");
+        Some(scheduler)
+    } else {
+        None
+    };
+
+    // History tables.
+    let history_log = HistoryLog::instance();
+    if let Some(history_log) = &history_log {
+        history_log.start();
+        info!("History log started");
+    }
+
     // Print information to users.
     println!("Databend Query");
 
@@ -282,6 +301,9 @@ pub async fn start_services(conf: &InnerConfig) -> Result<(), MainError> {
     if conf.log.structlog.on {
         println!("    structlog: {}", conf.log.structlog);
     }
+    if conf.log.history.on {
+        println!("    history: {}", conf.log.history);
+    }
 
     println!();
     println!(
@@ -331,6 +353,7 @@ pub async fn start_services(conf: &InnerConfig) -> Result<(), MainError> {
         "    reload policy: {}",
         conf.cache.data_cache_key_reload_policy
     );
+    println!("    eviction policy: {}", conf.cache.policy);
 
     println!();
     println!(
@@ -429,6 +452,12 @@ pub async fn start_services(conf: &InnerConfig) -> Result<(), MainError> {
             .wait_for_termination_request(graceful_shutdown_timeout)
             .await;
     }
+    if let Some(task_scheduler) = task_scheduler {
+        task_scheduler.shutdown();
+    }
+    if let Some(history_log) = history_log {
+        history_log.shutdown();
+    }
     info!("Shutdown server.");
     log::logger().flush();
     Ok(())
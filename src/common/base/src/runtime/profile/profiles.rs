@@ -54,10 +54,19 @@ pub enum ProfileStatisticsName {
     LocalSpillReadBytes,
     LocalSpillReadTime,
 
+    /// The bytes of the spilled data before compression and encryption, compared with the
+    /// spilled bytes to get the compression ratio.
+    SpillWriteUncompressedBytes,
+    SpillReadUncompressedBytes,
+
     RuntimeFilterPruneParts,
     MemoryUsage,
     ExternalServerRetryCount,
     ExternalServerRequestCount,
+
+    /// The rows of the hash join partition built and probed on a node.
+    HashJoinBuildRows,
+    HashJoinProbeRows,
 }
 
 #[derive(Clone, Hash, Eq, PartialEq, serde::Serialize, serde::Deserialize, Debug)]
@@ -284,6 +293,20 @@ pub fn get_statistics_desc() -> Arc<BTreeMap<ProfileStatisticsName, ProfileDesc>
                 unit: StatisticsUnit::MillisSeconds,
                 plain_statistics: false,
             }),
+            (ProfileStatisticsName::SpillWriteUncompressedBytes, ProfileDesc {
+                display_name: "uncompressed bytes spilled by write",
+                desc: "The bytes spilled by write before compression and encryption",
+                index: ProfileStatisticsName::SpillWriteUncompressedBytes as usize,
+                unit: StatisticsUnit::Bytes,
+                plain_statistics: true,
+            }),
+            (ProfileStatisticsName::SpillReadUncompressedBytes, ProfileDesc {
+                display_name: "uncompressed bytes spilled by read",
+                desc: "The bytes spilled by read after decompression and decryption",
+                index: ProfileStatisticsName::SpillReadUncompressedBytes as usize,
+                unit: StatisticsUnit::Bytes,
+                plain_statistics: true,
+            }),
             (ProfileStatisticsName::RuntimeFilterPruneParts, ProfileDesc {
                 display_name: "parts pruned by runtime filter",
                 desc: "The partitions pruned by runtime filter",
@@ -312,6 +335,20 @@ pub fn get_statistics_desc() -> Arc<BTreeMap<ProfileStatisticsName, ProfileDesc>
                 unit: StatisticsUnit::Count,
                 plain_statistics: true,
             }),
+            (ProfileStatisticsName::HashJoinBuildRows, ProfileDesc {
+                display_name: "hash join build rows",
+                desc: "The rows of the hash join partition built on the node",
+                index: ProfileStatisticsName::HashJoinBuildRows as usize,
+                unit: StatisticsUnit::Rows,
+                plain_statistics: true,
+            }),
+            (ProfileStatisticsName::HashJoinProbeRows, ProfileDesc {
+                display_name: "hash join probe rows",
+                desc: "The rows of the hash join partition probed on the node",
+                index: ProfileStatisticsName::HashJoinProbeRows as usize,
+                unit: StatisticsUnit::Rows,
+                plain_statistics: true,
+            }),
         ]))
     }).clone()
 }
@@ -13,10 +13,18 @@
 // limitations under the License.
 
 pub mod lru;
+mod policy;
+mod queue;
+pub mod s3fifo;
+pub mod tinylfu;
 
 use std::borrow::Borrow;
 use std::hash::Hash;
 
+pub use policy::AdmissionStats;
+pub use policy::CachePolicy;
+pub use policy::PolicyCache;
+
 use crate::mem_sized::MemSized;
 
 /// A trait for a cache.
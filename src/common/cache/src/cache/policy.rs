@@ -0,0 +1,364 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Borrow;
+use std::fmt;
+use std::hash::Hash;
+use std::str::FromStr;
+
+use hashbrown::HashMap;
+
+use crate::cache::lru::LruCache;
+use crate::cache::s3fifo::S3FifoCache;
+use crate::cache::tinylfu::TinyLfuCache;
+use crate::cache::Cache;
+use crate::mem_sized::MemSized;
+
+/// The number of new items a cache admitted to, and rejected from, its long-lived items.
+///
+/// An LRU cache admits every new item.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AdmissionStats {
+    pub admitted: u64,
+    pub rejected: u64,
+}
+
+/// The eviction policy of a cache.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CachePolicy {
+    #[default]
+    Lru,
+    S3Fifo,
+    TinyLfu,
+}
+
+impl fmt::Display for CachePolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CachePolicy::Lru => write!(f, "lru"),
+            CachePolicy::S3Fifo => write!(f, "s3fifo"),
+            CachePolicy::TinyLfu => write!(f, "tinylfu"),
+        }
+    }
+}
+
+impl FromStr for CachePolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "lru" => Ok(CachePolicy::Lru),
+            "s3fifo" => Ok(CachePolicy::S3Fifo),
+            "tinylfu" => Ok(CachePolicy::TinyLfu),
+            other => Err(format!(
+                "unknown cache policy '{other}', expecting one of lru, s3fifo, tinylfu"
+            )),
+        }
+    }
+}
+
+enum Inner<K: Eq + Hash + MemSized, V: MemSized> {
+    Lru(LruCache<K, V>),
+    S3Fifo(S3FifoCache<K, V>),
+    TinyLfu(TinyLfuCache<K, V>),
+}
+
+/// A cache evicting by the policy chosen at creation.
+///
+/// Items can be pinned to exempt them from eviction. The pinned items take a share of the
+/// capacity, limited by the pin quota, and the policy evicts within the rest.
+pub struct PolicyCache<K: Eq + Hash + MemSized, V: MemSized> {
+    inner: Inner<K, V>,
+    /// The number of new items inserted into the LRU cache.
+    lru_admitted: u64,
+    pinned: HashMap<K, V>,
+    pinned_bytes: usize,
+    max_items: usize,
+    max_bytes: usize,
+    /// The share of the capacity the pinned items can take, in percent.
+    pin_quota_ratio: usize,
+}
+
+macro_rules! dispatch {
+    ($self:expr, $cache:ident => $body:expr) => {
+        match $self {
+            Inner::Lru($cache) => $body,
+            Inner::S3Fifo($cache) => $body,
+            Inner::TinyLfu($cache) => $body,
+        }
+    };
+}
+
+impl<K: Eq + Hash + MemSized, V: MemSized> PolicyCache<K, V> {
+    pub fn with_items_capacity(policy: CachePolicy, items_capacity: usize) -> Self {
+        let inner = match policy {
+            CachePolicy::Lru => Inner::Lru(LruCache::with_items_capacity(items_capacity)),
+            CachePolicy::S3Fifo => Inner::S3Fifo(S3FifoCache::with_items_capacity(items_capacity)),
+            CachePolicy::TinyLfu => {
+                Inner::TinyLfu(TinyLfuCache::with_items_capacity(items_capacity))
+            }
+        };
+        Self::new(inner, items_capacity, usize::MAX)
+    }
+
+    pub fn with_bytes_capacity(policy: CachePolicy, bytes_capacity: usize) -> Self {
+        let inner = match policy {
+            CachePolicy::Lru => Inner::Lru(LruCache::with_bytes_capacity(bytes_capacity)),
+            CachePolicy::S3Fifo => Inner::S3Fifo(S3FifoCache::with_bytes_capacity(bytes_capacity)),
+            CachePolicy::TinyLfu => {
+                Inner::TinyLfu(TinyLfuCache::with_bytes_capacity(bytes_capacity))
+            }
+        };
+        Self::new(inner, usize::MAX, bytes_capacity)
+    }
+
+    fn new(inner: Inner<K, V>, max_items: usize, max_bytes: usize) -> Self {
+        PolicyCache {
+            inner,
+            lru_admitted: 0,
+            pinned: HashMap::new(),
+            pinned_bytes: 0,
+            max_items,
+            max_bytes,
+            pin_quota_ratio: 0,
+        }
+    }
+
+    pub fn policy(&self) -> CachePolicy {
+        match &self.inner {
+            Inner::Lru(_) => CachePolicy::Lru,
+            Inner::S3Fifo(_) => CachePolicy::S3Fifo,
+            Inner::TinyLfu(_) => CachePolicy::TinyLfu,
+        }
+    }
+
+    pub fn admission_stats(&self) -> AdmissionStats {
+        match &self.inner {
+            Inner::Lru(_) => AdmissionStats {
+                admitted: self.lru_admitted,
+                rejected: 0,
+            },
+            Inner::S3Fifo(cache) => cache.admission_stats(),
+            Inner::TinyLfu(cache) => cache.admission_stats(),
+        }
+    }
+
+    /// Sets the share of the capacity the pinned items can take, in percent. The items pinned
+    /// already stay pinned.
+    pub fn set_pin_quota_ratio(&mut self, ratio: usize) {
+        self.pin_quota_ratio = ratio.min(100);
+    }
+
+    /// Exempts the cached item of the key from eviction, returns false if it is absent or the
+    /// pin quota is used up.
+    pub fn pin(&mut self, k: K) -> bool {
+        if self.pinned.contains_key(&k) {
+            return true;
+        }
+        let bytes = match dispatch!(&self.inner, cache => cache.peek(&k)) {
+            Some(v) => v.mem_bytes(),
+            None => return false,
+        };
+        if !self.within_pin_quota(self.pinned.len() + 1, self.pinned_bytes + bytes) {
+            return false;
+        }
+
+        if let Some(v) = dispatch!(&mut self.inner, cache => cache.pop(&k)) {
+            self.pinned_bytes += v.mem_bytes();
+            self.pinned.insert(k, v);
+            self.resize_inner();
+        }
+        true
+    }
+
+    /// Makes the pinned item of the key evictable again, returns false if it is not pinned.
+    pub fn unpin<Q>(&mut self, k: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        match self.pinned.remove_entry(k) {
+            Some((k, v)) => {
+                self.pinned_bytes -= v.mem_bytes();
+                self.resize_inner();
+                dispatch!(&mut self.inner, cache => cache.insert(k, v));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Unpins the pinned items whose keys match the predicate, returns the number of them.
+    pub fn unpin_where<F: FnMut(&K) -> bool>(&mut self, mut f: F) -> usize {
+        let mut unpinned = vec![];
+        for (k, v) in std::mem::take(&mut self.pinned) {
+            if f(&k) {
+                self.pinned_bytes -= v.mem_bytes();
+                unpinned.push((k, v));
+            } else {
+                self.pinned.insert(k, v);
+            }
+        }
+
+        self.resize_inner();
+        let num_unpinned = unpinned.len();
+        for (k, v) in unpinned {
+            dispatch!(&mut self.inner, cache => cache.insert(k, v));
+        }
+        num_unpinned
+    }
+
+    pub fn is_pinned<Q>(&self, k: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.pinned.contains_key(k)
+    }
+
+    /// Returns the number of the pinned items.
+    pub fn pinned_len(&self) -> usize {
+        self.pinned.len()
+    }
+
+    /// Returns the bytes size of the pinned items.
+    pub fn pinned_bytes_size(&self) -> u64 {
+        self.pinned_bytes as u64
+    }
+
+    fn within_pin_quota(&self, items: usize, bytes: usize) -> bool {
+        let ratio = self.pin_quota_ratio;
+        (self.max_items == usize::MAX || items * 100 <= self.max_items.saturating_mul(ratio))
+            && (self.max_bytes == usize::MAX
+                || bytes.saturating_mul(100) <= self.max_bytes.saturating_mul(ratio))
+    }
+
+    /// Leaves the capacity not taken by the pinned items to the policy.
+    fn resize_inner(&mut self) {
+        if self.max_items != usize::MAX {
+            let items = self.max_items.saturating_sub(self.pinned.len());
+            dispatch!(&mut self.inner, cache => cache.set_items_capacity(items));
+        }
+        if self.max_bytes != usize::MAX {
+            let bytes = self.max_bytes.saturating_sub(self.pinned_bytes);
+            dispatch!(&mut self.inner, cache => cache.set_bytes_capacity(bytes));
+        }
+    }
+}
+
+impl<K: Eq + Hash + MemSized, V: MemSized> Cache<K, V> for PolicyCache<K, V> {
+    fn get<Q>(&mut self, k: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if self.pinned.contains_key(k) {
+            return self.pinned.get(k);
+        }
+        dispatch!(&mut self.inner, cache => cache.get(k))
+    }
+
+    fn peek<Q>(&self, k: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.pinned
+            .get(k)
+            .or_else(|| dispatch!(&self.inner, cache => cache.peek(k)))
+    }
+
+    fn peek_by_policy(&self) -> Option<(&K, &V)> {
+        dispatch!(&self.inner, cache => cache.peek_by_policy())
+    }
+
+    fn insert(&mut self, k: K, v: V) -> Option<V> {
+        if let Some(pinned) = self.pinned.get_mut(&k) {
+            self.pinned_bytes = self.pinned_bytes + v.mem_bytes() - pinned.mem_bytes();
+            let old = std::mem::replace(pinned, v);
+            self.resize_inner();
+            return Some(old);
+        }
+        if let Inner::Lru(cache) = &mut self.inner {
+            let old = cache.insert(k, v);
+            if old.is_none() {
+                self.lru_admitted += 1;
+            }
+            return old;
+        }
+        dispatch!(&mut self.inner, cache => cache.insert(k, v))
+    }
+
+    fn pop<Q>(&mut self, k: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if let Some(v) = self.pinned.remove(k) {
+            self.pinned_bytes -= v.mem_bytes();
+            self.resize_inner();
+            return Some(v);
+        }
+        dispatch!(&mut self.inner, cache => cache.pop(k))
+    }
+
+    fn pop_by_policy(&mut self) -> Option<(K, V)> {
+        dispatch!(&mut self.inner, cache => cache.pop_by_policy())
+    }
+
+    fn contains<Q>(&self, k: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.pinned.contains_key(k) || dispatch!(&self.inner, cache => cache.contains(k))
+    }
+
+    fn len(&self) -> usize {
+        dispatch!(&self.inner, cache => cache.len()) + self.pinned.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        dispatch!(&self.inner, cache => cache.is_empty()) && self.pinned.is_empty()
+    }
+
+    fn bytes_capacity(&self) -> u64 {
+        self.max_bytes as u64
+    }
+
+    fn items_capacity(&self) -> u64 {
+        self.max_items as u64
+    }
+
+    fn set_bytes_capacity(&mut self, capacity: usize) {
+        self.max_bytes = capacity;
+        self.resize_inner();
+    }
+
+    fn set_items_capacity(&mut self, capacity: usize) {
+        self.max_items = capacity;
+        self.resize_inner();
+    }
+
+    fn bytes_size(&self) -> u64 {
+        dispatch!(&self.inner, cache => cache.bytes_size()) + self.pinned_bytes as u64
+    }
+
+    fn clear(&mut self) {
+        dispatch!(&mut self.inner, cache => cache.clear());
+        self.pinned.clear();
+        self.pinned_bytes = 0;
+        self.resize_inner();
+    }
+}
@@ -0,0 +1,150 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Borrow;
+use std::hash::Hash;
+
+use hashlink::linked_hash_map;
+use hashlink::LinkedHashMap;
+
+use crate::mem_sized::MemSized;
+
+/// A cached value with the access frequency tracked by the policy.
+pub(crate) struct Entry<V> {
+    pub value: V,
+    pub freq: u8,
+}
+
+/// An ordered queue of cache entries, which keeps track of the bytes size of its values.
+pub(crate) struct Queue<K: Eq + Hash, V: MemSized> {
+    map: LinkedHashMap<K, Entry<V>>,
+    bytes: usize,
+}
+
+impl<K: Eq + Hash, V: MemSized> Queue<K, V> {
+    pub fn new() -> Self {
+        Queue {
+            map: LinkedHashMap::new(),
+            bytes: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    pub fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    pub fn contains<Q>(&self, k: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.contains_key(k)
+    }
+
+    pub fn get<Q>(&self, k: &Q) -> Option<&Entry<V>>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get(k)
+    }
+
+    pub fn get_mut<Q>(&mut self, k: &Q) -> Option<&mut Entry<V>>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get_mut(k)
+    }
+
+    /// Moves the entry of the key to the back of the queue, returns false if it is absent.
+    pub fn move_to_back<Q>(&mut self, k: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        match self.map.raw_entry_mut().from_key(k) {
+            linked_hash_map::RawEntryMut::Occupied(mut occupied) => {
+                occupied.to_back();
+                true
+            }
+            linked_hash_map::RawEntryMut::Vacant(_) => false,
+        }
+    }
+
+    /// Replaces the value of a queued key in place, returns the old value.
+    pub fn replace(&mut self, k: &K, v: V) -> Option<V> {
+        let entry = self.map.get_mut(k)?;
+        self.bytes = self.bytes + v.mem_bytes() - entry.value.mem_bytes();
+        Some(std::mem::replace(&mut entry.value, v))
+    }
+
+    pub fn push_back(&mut self, k: K, entry: Entry<V>) {
+        self.bytes += entry.value.mem_bytes();
+        self.map.insert(k, entry);
+    }
+
+    pub fn front(&self) -> Option<(&K, &Entry<V>)> {
+        self.map.front()
+    }
+
+    pub fn pop_front(&mut self) -> Option<(K, Entry<V>)> {
+        let (k, entry) = self.map.pop_front()?;
+        self.bytes -= entry.value.mem_bytes();
+        Some((k, entry))
+    }
+
+    pub fn remove<Q>(&mut self, k: &Q) -> Option<(K, Entry<V>)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let (k, entry) = self.map.remove_entry(k)?;
+        self.bytes -= entry.value.mem_bytes();
+        Some((k, entry))
+    }
+
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.bytes = 0;
+    }
+}
+
+/// The capacity of a cache, in items and in bytes.
+#[derive(Clone, Copy)]
+pub(crate) struct Capacity {
+    pub max_items: usize,
+    pub max_bytes: usize,
+}
+
+impl Capacity {
+    pub fn exceeded_by(&self, items: usize, bytes: usize) -> bool {
+        items > self.max_items || bytes > self.max_bytes
+    }
+
+    /// Returns true if `items` or `bytes` exceed `percent` percent of the capacity.
+    pub fn share_exceeded_by(&self, items: usize, bytes: usize, percent: usize) -> bool {
+        let percent = percent as u128;
+        items as u128 * 100 > self.max_items as u128 * percent
+            || bytes as u128 * 100 > self.max_bytes as u128 * percent
+    }
+}
@@ -0,0 +1,300 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A cache evicting by the S3-FIFO policy, see "FIFO queues are all you need for cache
+//! eviction" (SOSP'23).
+//!
+//! New items enter a small FIFO queue taking 10% of the capacity. The items accessed again
+//! before leaving it are admitted to the main FIFO queue, the others are evicted right away and
+//! remembered in a ghost queue, so that they go to the main queue directly if they come back
+//! soon. The main queue gives the accessed items another round instead of evicting them.
+//!
+//! A scan touches every item once, so it churns through the small queue only and leaves the
+//! working set in the main queue alone.
+
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::hash::BuildHasher;
+use std::hash::Hash;
+
+use hashbrown::DefaultHashBuilder;
+
+use crate::cache::queue::Capacity;
+use crate::cache::queue::Entry;
+use crate::cache::queue::Queue;
+use crate::cache::AdmissionStats;
+use crate::cache::Cache;
+use crate::mem_sized::MemSized;
+
+/// The share of the small queue in the capacity, in percent.
+const SMALL_QUEUE_PERCENT: usize = 10;
+
+/// The frequency of an item is capped, so that it survives at most this many rounds in the
+/// main queue without being accessed.
+const MAX_FREQ: u8 = 3;
+
+/// An S3-FIFO cache.
+pub struct S3FifoCache<K: Eq + Hash + MemSized, V: MemSized> {
+    small: Queue<K, V>,
+    main: Queue<K, V>,
+    /// The hashes of the keys recently evicted from the small queue, with the seq they are
+    /// remembered at. A hash that comes back is removed from `ghost_set` only, and its stale
+    /// entry is skipped when it is popped.
+    ghost: VecDeque<(u64, u64)>,
+    /// The hashes in the ghost queue, mapped to the seq of their live entries.
+    ghost_set: HashMap<u64, u64>,
+    ghost_seq: u64,
+    hasher: DefaultHashBuilder,
+    capacity: Capacity,
+    stats: AdmissionStats,
+}
+
+impl<K: Eq + Hash + MemSized, V: MemSized> S3FifoCache<K, V> {
+    pub fn with_items_capacity(items_capacity: usize) -> Self {
+        Self::with_capacity(Capacity {
+            max_items: items_capacity,
+            max_bytes: usize::MAX,
+        })
+    }
+
+    pub fn with_bytes_capacity(bytes_capacity: usize) -> Self {
+        Self::with_capacity(Capacity {
+            max_items: usize::MAX,
+            max_bytes: bytes_capacity,
+        })
+    }
+
+    fn with_capacity(capacity: Capacity) -> Self {
+        S3FifoCache {
+            small: Queue::new(),
+            main: Queue::new(),
+            ghost: VecDeque::new(),
+            ghost_set: HashMap::new(),
+            ghost_seq: 0,
+            hasher: DefaultHashBuilder::default(),
+            capacity,
+            stats: AdmissionStats::default(),
+        }
+    }
+
+    /// Returns the number of items admitted to and rejected from the main queue.
+    pub fn admission_stats(&self) -> AdmissionStats {
+        self.stats
+    }
+
+    fn is_overflow(&self) -> bool {
+        self.capacity
+            .exceeded_by(self.len(), self.bytes_size() as usize)
+    }
+
+    fn remember(&mut self, hash: u64) {
+        if !self.ghost_set.contains_key(&hash) {
+            self.ghost_seq += 1;
+            self.ghost_set.insert(hash, self.ghost_seq);
+            self.ghost.push_back((hash, self.ghost_seq));
+        }
+        while self.ghost.len() > self.ghost_capacity() {
+            if let Some((hash, seq)) = self.ghost.pop_front() {
+                if self.ghost_set.get(&hash) == Some(&seq) {
+                    self.ghost_set.remove(&hash);
+                }
+            }
+        }
+    }
+
+    /// The ghost queue remembers as many keys as the cache holds items, which is estimated
+    /// from the average item size for the caches limited by bytes.
+    fn ghost_capacity(&self) -> usize {
+        if self.capacity.max_items != usize::MAX {
+            return self.capacity.max_items.max(1);
+        }
+        let (len, bytes) = (self.len(), self.bytes_size() as u128);
+        match bytes {
+            0 => len.max(1),
+            _ => (self.capacity.max_bytes as u128 * len as u128 / bytes)
+                .clamp(1, usize::MAX as u128) as usize,
+        }
+    }
+
+    fn evict_small(&mut self) -> Option<(K, V)> {
+        while let Some((k, mut entry)) = self.small.pop_front() {
+            if entry.freq == 0 {
+                self.stats.rejected += 1;
+                self.remember(self.hasher.hash_one(&k));
+                return Some((k, entry.value));
+            }
+
+            self.stats.admitted += 1;
+            entry.freq = 0;
+            self.main.push_back(k, entry);
+            if self
+                .capacity
+                .exceeded_by(self.main.len(), self.main.bytes())
+            {
+                return self.evict_main();
+            }
+        }
+        None
+    }
+
+    fn evict_main(&mut self) -> Option<(K, V)> {
+        while let Some((k, mut entry)) = self.main.pop_front() {
+            if entry.freq == 0 {
+                return Some((k, entry.value));
+            }
+            entry.freq -= 1;
+            self.main.push_back(k, entry);
+        }
+        None
+    }
+}
+
+impl<K: Eq + Hash + MemSized, V: MemSized> Cache<K, V> for S3FifoCache<K, V> {
+    fn get<Q>(&mut self, k: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let entry = match self.small.contains(k) {
+            true => self.small.get_mut(k),
+            false => self.main.get_mut(k),
+        }?;
+        entry.freq = (entry.freq + 1).min(MAX_FREQ);
+        Some(&entry.value)
+    }
+
+    fn peek<Q>(&self, k: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.small
+            .get(k)
+            .or_else(|| self.main.get(k))
+            .map(|entry| &entry.value)
+    }
+
+    /// Returns the item at the head of the queue to evict from. The item is not necessarily
+    /// the one evicted next, as the accessed items are moved rather than evicted.
+    fn peek_by_policy(&self) -> Option<(&K, &V)> {
+        let small_overflow = self.capacity.share_exceeded_by(
+            self.small.len(),
+            self.small.bytes(),
+            SMALL_QUEUE_PERCENT,
+        );
+        let queue = match small_overflow || self.main.is_empty() {
+            true => &self.small,
+            false => &self.main,
+        };
+        queue.front().map(|(k, entry)| (k, &entry.value))
+    }
+
+    fn insert(&mut self, k: K, v: V) -> Option<V> {
+        if self.small.contains(&k) {
+            return self.small.replace(&k, v);
+        }
+        if self.main.contains(&k) {
+            return self.main.replace(&k, v);
+        }
+
+        let hash = self.hasher.hash_one(&k);
+        let entry = Entry { value: v, freq: 0 };
+        if self.ghost_set.remove(&hash).is_some() {
+            self.stats.admitted += 1;
+            self.main.push_back(k, entry);
+        } else {
+            self.small.push_back(k, entry);
+        }
+
+        while self.is_overflow() {
+            self.pop_by_policy();
+        }
+        None
+    }
+
+    fn pop<Q>(&mut self, k: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.small
+            .remove(k)
+            .or_else(|| self.main.remove(k))
+            .map(|(_, entry)| entry.value)
+    }
+
+    fn pop_by_policy(&mut self) -> Option<(K, V)> {
+        let small_overflow = self.capacity.share_exceeded_by(
+            self.small.len(),
+            self.small.bytes(),
+            SMALL_QUEUE_PERCENT,
+        );
+        if small_overflow || self.main.is_empty() {
+            if let Some(evicted) = self.evict_small() {
+                return Some(evicted);
+            }
+        }
+        self.evict_main().or_else(|| self.evict_small())
+    }
+
+    fn contains<Q>(&self, k: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.small.contains(k) || self.main.contains(k)
+    }
+
+    fn len(&self) -> usize {
+        self.small.len() + self.main.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.small.is_empty() && self.main.is_empty()
+    }
+
+    fn bytes_capacity(&self) -> u64 {
+        self.capacity.max_bytes as u64
+    }
+
+    fn items_capacity(&self) -> u64 {
+        self.capacity.max_items as u64
+    }
+
+    fn set_bytes_capacity(&mut self, capacity: usize) {
+        self.capacity.max_bytes = capacity;
+        while self.is_overflow() {
+            self.pop_by_policy();
+        }
+    }
+
+    fn set_items_capacity(&mut self, capacity: usize) {
+        self.capacity.max_items = capacity;
+        while self.is_overflow() {
+            self.pop_by_policy();
+        }
+    }
+
+    fn bytes_size(&self) -> u64 {
+        (self.small.bytes() + self.main.bytes()) as u64
+    }
+
+    fn clear(&mut self) {
+        self.small.clear();
+        self.main.clear();
+        self.ghost.clear();
+        self.ghost_set.clear();
+    }
+}
@@ -0,0 +1,375 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A cache evicting by the W-TinyLFU policy, see "TinyLFU: A Highly Efficient Cache Admission
+//! Policy" (ACM ToS 2017).
+//!
+//! New items enter a small LRU window taking 1% of the capacity. When the window overflows,
+//! its least recently used item competes with the victim of the main segmented LRU: the one
+//! accessed more often recently, as estimated by a count-min sketch of the accesses, stays.
+//!
+//! The items of a scan are seen once, so they lose to the working set and don't evict it.
+
+use std::borrow::Borrow;
+use std::hash::BuildHasher;
+use std::hash::Hash;
+
+use hashbrown::DefaultHashBuilder;
+
+use crate::cache::queue::Capacity;
+use crate::cache::queue::Entry;
+use crate::cache::queue::Queue;
+use crate::cache::AdmissionStats;
+use crate::cache::Cache;
+use crate::mem_sized::MemSized;
+
+/// The share of the window in the capacity, in percent.
+const WINDOW_PERCENT: usize = 1;
+
+/// The share of the protected segment in the capacity, in percent.
+const PROTECTED_PERCENT: usize = 79;
+
+/// The width of the sketch of the caches limited by bytes, whose number of items is unknown.
+const DEFAULT_SKETCH_WIDTH: usize = 1 << 16;
+
+const MIN_SKETCH_WIDTH: usize = 1 << 10;
+
+const MAX_SKETCH_WIDTH: usize = 1 << 24;
+
+/// A count-min sketch of 4 rows of saturating 4-bit counters, which are halved periodically
+/// so that the estimation reflects the recent accesses.
+struct FrequencySketch {
+    /// Each u64 holds 16 counters.
+    table: Vec<u64>,
+    mask: usize,
+    additions: usize,
+    sample_size: usize,
+}
+
+impl FrequencySketch {
+    fn new(width: usize) -> Self {
+        let width = width
+            .clamp(MIN_SKETCH_WIDTH, MAX_SKETCH_WIDTH)
+            .next_power_of_two();
+        FrequencySketch {
+            table: vec![0; width / 16 * 4],
+            mask: width - 1,
+            additions: 0,
+            sample_size: width * 10,
+        }
+    }
+
+    fn counter(&self, hash: u64, row: usize) -> (usize, u32) {
+        let h = hash
+            .rotate_left(row as u32 * 16)
+            .wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        let index = (h >> 32) as usize & self.mask;
+        let slot = row * (self.table.len() / 4) + index / 16;
+        (slot, (index % 16) as u32 * 4)
+    }
+
+    fn frequency(&self, hash: u64) -> u8 {
+        (0..4)
+            .map(|row| {
+                let (slot, shift) = self.counter(hash, row);
+                ((self.table[slot] >> shift) & 0xF) as u8
+            })
+            .min()
+            .unwrap_or_default()
+    }
+
+    fn increment(&mut self, hash: u64) {
+        let mut added = false;
+        for row in 0..4 {
+            let (slot, shift) = self.counter(hash, row);
+            if (self.table[slot] >> shift) & 0xF < 0xF {
+                self.table[slot] += 1 << shift;
+                added = true;
+            }
+        }
+
+        if added {
+            self.additions += 1;
+            if self.additions >= self.sample_size {
+                self.reset();
+            }
+        }
+    }
+
+    fn reset(&mut self) {
+        for counters in self.table.iter_mut() {
+            *counters = (*counters >> 1) & 0x7777_7777_7777_7777;
+        }
+        self.additions /= 2;
+    }
+}
+
+/// A W-TinyLFU cache.
+pub struct TinyLfuCache<K: Eq + Hash + MemSized, V: MemSized> {
+    window: Queue<K, V>,
+    probation: Queue<K, V>,
+    protected: Queue<K, V>,
+    sketch: FrequencySketch,
+    hasher: DefaultHashBuilder,
+    capacity: Capacity,
+    stats: AdmissionStats,
+}
+
+impl<K: Eq + Hash + MemSized, V: MemSized> TinyLfuCache<K, V> {
+    pub fn with_items_capacity(items_capacity: usize) -> Self {
+        Self::with_capacity(
+            Capacity {
+                max_items: items_capacity,
+                max_bytes: usize::MAX,
+            },
+            items_capacity,
+        )
+    }
+
+    pub fn with_bytes_capacity(bytes_capacity: usize) -> Self {
+        Self::with_capacity(
+            Capacity {
+                max_items: usize::MAX,
+                max_bytes: bytes_capacity,
+            },
+            DEFAULT_SKETCH_WIDTH,
+        )
+    }
+
+    fn with_capacity(capacity: Capacity, sketch_width: usize) -> Self {
+        TinyLfuCache {
+            window: Queue::new(),
+            probation: Queue::new(),
+            protected: Queue::new(),
+            sketch: FrequencySketch::new(sketch_width),
+            hasher: DefaultHashBuilder::default(),
+            capacity,
+            stats: AdmissionStats::default(),
+        }
+    }
+
+    /// Returns the number of window items admitted to and rejected from the main segments.
+    pub fn admission_stats(&self) -> AdmissionStats {
+        self.stats
+    }
+
+    fn frequency<Q: Hash + ?Sized>(&self, k: &Q) -> u8 {
+        self.sketch.frequency(self.hasher.hash_one(k))
+    }
+
+    fn is_overflow(&self) -> bool {
+        self.capacity
+            .exceeded_by(self.len(), self.bytes_size() as usize)
+    }
+
+    fn main_has_room_for(&self, entry: &Entry<V>) -> bool {
+        let items = self.probation.len() + self.protected.len() + 1;
+        let bytes = self.probation.bytes() + self.protected.bytes() + entry.value.mem_bytes();
+        !self
+            .capacity
+            .share_exceeded_by(items, bytes, 100 - WINDOW_PERCENT)
+    }
+
+    /// Demotes the least recently used protected items to the probation segment until the
+    /// protected segment fits its share.
+    fn shrink_protected(&mut self) {
+        while self.protected.len() > 1
+            && self.capacity.share_exceeded_by(
+                self.protected.len(),
+                self.protected.bytes(),
+                PROTECTED_PERCENT,
+            )
+        {
+            if let Some((k, entry)) = self.protected.pop_front() {
+                self.probation.push_back(k, entry);
+            }
+        }
+    }
+
+    fn evict_main(&mut self) -> Option<(K, V)> {
+        self.probation
+            .pop_front()
+            .or_else(|| self.protected.pop_front())
+            .map(|(k, entry)| (k, entry.value))
+    }
+}
+
+impl<K: Eq + Hash + MemSized, V: MemSized> Cache<K, V> for TinyLfuCache<K, V> {
+    fn get<Q>(&mut self, k: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.sketch.increment(self.hasher.hash_one(k));
+
+        if self.window.move_to_back(k) {
+            return self.window.get(k).map(|entry| &entry.value);
+        }
+        if self.protected.move_to_back(k) {
+            return self.protected.get(k).map(|entry| &entry.value);
+        }
+
+        // An item accessed again in the probation segment is protected.
+        let (key, entry) = self.probation.remove(k)?;
+        self.protected.push_back(key, entry);
+        self.shrink_protected();
+        self.probation
+            .get(k)
+            .or_else(|| self.protected.get(k))
+            .map(|entry| &entry.value)
+    }
+
+    fn peek<Q>(&self, k: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.window
+            .get(k)
+            .or_else(|| self.probation.get(k))
+            .or_else(|| self.protected.get(k))
+            .map(|entry| &entry.value)
+    }
+
+    /// Returns the victim of the main segments, or the least recently used window item if
+    /// they are empty. Whether the victim or the window candidate is evicted depends on their
+    /// frequencies.
+    fn peek_by_policy(&self) -> Option<(&K, &V)> {
+        self.probation
+            .front()
+            .or_else(|| self.protected.front())
+            .or_else(|| self.window.front())
+            .map(|(k, entry)| (k, &entry.value))
+    }
+
+    fn insert(&mut self, k: K, v: V) -> Option<V> {
+        self.sketch.increment(self.hasher.hash_one(&k));
+
+        if self.window.contains(&k) {
+            return self.window.replace(&k, v);
+        }
+        if self.probation.contains(&k) {
+            return self.probation.replace(&k, v);
+        }
+        if self.protected.contains(&k) {
+            return self.protected.replace(&k, v);
+        }
+
+        self.window.push_back(k, Entry { value: v, freq: 0 });
+        while self.is_overflow() {
+            self.pop_by_policy();
+        }
+        None
+    }
+
+    fn pop<Q>(&mut self, k: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.window
+            .remove(k)
+            .or_else(|| self.probation.remove(k))
+            .or_else(|| self.protected.remove(k))
+            .map(|(_, entry)| entry.value)
+    }
+
+    fn pop_by_policy(&mut self) -> Option<(K, V)> {
+        while self.window.len() > 1
+            && self.capacity.share_exceeded_by(
+                self.window.len(),
+                self.window.bytes(),
+                WINDOW_PERCENT,
+            )
+        {
+            let (candidate, entry) = self.window.pop_front()?;
+            if self.main_has_room_for(&entry) {
+                self.stats.admitted += 1;
+                self.probation.push_back(candidate, entry);
+                continue;
+            }
+
+            let victim_frequency = match self.probation.front().or(self.protected.front()) {
+                Some((victim, _)) => self.frequency(victim),
+                None => {
+                    self.stats.rejected += 1;
+                    return Some((candidate, entry.value));
+                }
+            };
+            if self.frequency(&candidate) > victim_frequency {
+                self.stats.admitted += 1;
+                self.probation.push_back(candidate, entry);
+                return self.evict_main();
+            }
+            self.stats.rejected += 1;
+            return Some((candidate, entry.value));
+        }
+
+        self.evict_main().or_else(|| {
+            self.window
+                .pop_front()
+                .map(|(candidate, entry)| (candidate, entry.value))
+        })
+    }
+
+    fn contains<Q>(&self, k: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.window.contains(k) || self.probation.contains(k) || self.protected.contains(k)
+    }
+
+    fn len(&self) -> usize {
+        self.window.len() + self.probation.len() + self.protected.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.window.is_empty() && self.probation.is_empty() && self.protected.is_empty()
+    }
+
+    fn bytes_capacity(&self) -> u64 {
+        self.capacity.max_bytes as u64
+    }
+
+    fn items_capacity(&self) -> u64 {
+        self.capacity.max_items as u64
+    }
+
+    fn set_bytes_capacity(&mut self, capacity: usize) {
+        self.capacity.max_bytes = capacity;
+        while self.is_overflow() {
+            self.pop_by_policy();
+        }
+        self.shrink_protected();
+    }
+
+    fn set_items_capacity(&mut self, capacity: usize) {
+        self.capacity.max_items = capacity;
+        while self.is_overflow() {
+            self.pop_by_policy();
+        }
+        self.shrink_protected();
+    }
+
+    fn bytes_size(&self) -> u64 {
+        (self.window.bytes() + self.probation.bytes() + self.protected.bytes()) as u64
+    }
+
+    fn clear(&mut self) {
+        self.window.clear();
+        self.probation.clear();
+        self.protected.clear();
+    }
+}
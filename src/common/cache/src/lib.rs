@@ -19,6 +19,11 @@ mod cache;
 mod mem_sized;
 
 pub use cache::lru::LruCache;
+pub use cache::s3fifo::S3FifoCache;
+pub use cache::tinylfu::TinyLfuCache;
+pub use cache::AdmissionStats;
 pub use cache::Cache;
+pub use cache::CachePolicy;
+pub use cache::PolicyCache;
 pub use hashbrown::DefaultHashBuilder;
 pub use mem_sized::MemSized;
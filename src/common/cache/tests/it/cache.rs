@@ -13,3 +13,6 @@
 // limitations under the License.
 
 mod lru;
+mod policy;
+mod s3fifo;
+mod tinylfu;
@@ -0,0 +1,137 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_cache::Cache;
+use databend_common_cache::CachePolicy;
+use databend_common_cache::MemSized;
+use databend_common_cache::PolicyCache;
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+struct TestKey(pub i32);
+
+impl MemSized for TestKey {
+    fn mem_bytes(&self) -> usize {
+        0
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+struct TestValue(pub i32);
+
+impl MemSized for TestValue {
+    fn mem_bytes(&self) -> usize {
+        1
+    }
+}
+
+const POLICIES: [CachePolicy; 3] = [CachePolicy::Lru, CachePolicy::S3Fifo, CachePolicy::TinyLfu];
+
+#[test]
+fn test_pinned_items_are_not_evicted() {
+    for policy in POLICIES {
+        let mut cache = PolicyCache::with_items_capacity(policy, 10);
+        cache.set_pin_quota_ratio(50);
+        for i in 0..3 {
+            cache.insert(TestKey(i), TestValue(i));
+            assert!(cache.pin(TestKey(i)));
+        }
+
+        for i in 100..1000 {
+            cache.insert(TestKey(i), TestValue(i));
+            assert!(cache.len() <= 10);
+        }
+
+        assert_eq!(cache.pinned_len(), 3);
+        for i in 0..3 {
+            assert!(cache.is_pinned(&TestKey(i)));
+            assert_eq!(cache.get(&TestKey(i)), Some(&TestValue(i)));
+        }
+        assert_eq!(cache.items_capacity(), 10);
+    }
+}
+
+#[test]
+fn test_pin_quota() {
+    for policy in POLICIES {
+        let mut cache = PolicyCache::with_bytes_capacity(policy, 10);
+        assert!(cache.insert(TestKey(1), TestValue(1)).is_none());
+        // pinning is disabled by default
+        assert!(!cache.pin(TestKey(1)));
+
+        cache.set_pin_quota_ratio(20);
+        for i in 0..3 {
+            cache.insert(TestKey(i), TestValue(i));
+        }
+        assert!(cache.pin(TestKey(0)));
+        assert!(cache.pin(TestKey(1)));
+        assert!(!cache.pin(TestKey(2)));
+        // absent items can't be pinned
+        assert!(!cache.pin(TestKey(42)));
+
+        assert_eq!(cache.pinned_len(), 2);
+        assert_eq!(cache.pinned_bytes_size(), 2);
+        assert_eq!(cache.bytes_size(), 3);
+    }
+}
+
+#[test]
+fn test_unpin() {
+    for policy in POLICIES {
+        let mut cache = PolicyCache::with_items_capacity(policy, 10);
+        cache.set_pin_quota_ratio(100);
+        for i in 0..4 {
+            cache.insert(TestKey(i), TestValue(i));
+            assert!(cache.pin(TestKey(i)));
+        }
+
+        assert!(cache.unpin(&TestKey(0)));
+        assert!(!cache.unpin(&TestKey(0)));
+        assert_eq!(cache.unpin_where(|k| k.0 % 2 == 1), 2);
+        assert_eq!(cache.pinned_len(), 1);
+        assert!(cache.is_pinned(&TestKey(2)));
+        assert_eq!(cache.len(), 4);
+
+        for i in 100..1000 {
+            cache.insert(TestKey(i), TestValue(i));
+        }
+        assert!(cache.contains(&TestKey(2)));
+        assert_eq!(cache.len(), 10);
+    }
+}
+
+#[test]
+fn test_unpinned_items_are_evictable() {
+    let mut cache = PolicyCache::with_items_capacity(CachePolicy::Lru, 10);
+    cache.set_pin_quota_ratio(50);
+    cache.insert(TestKey(0), TestValue(0));
+    assert!(cache.pin(TestKey(0)));
+    assert!(cache.unpin(&TestKey(0)));
+    for i in 100..110 {
+        cache.insert(TestKey(i), TestValue(i));
+    }
+    assert!(!cache.contains(&TestKey(0)));
+    assert_eq!(cache.len(), 10);
+}
+
+#[test]
+fn test_pop_pinned() {
+    let mut cache = PolicyCache::with_items_capacity(CachePolicy::Lru, 2);
+    cache.set_pin_quota_ratio(50);
+    cache.insert(TestKey(1), TestValue(1));
+    assert!(cache.pin(TestKey(1)));
+    assert_eq!(cache.insert(TestKey(1), TestValue(2)), Some(TestValue(1)));
+    assert_eq!(cache.pop(&TestKey(1)), Some(TestValue(2)));
+    assert_eq!(cache.pinned_len(), 0);
+    assert!(cache.is_empty());
+}
@@ -0,0 +1,139 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+
+use databend_common_cache::Cache;
+use databend_common_cache::MemSized;
+use databend_common_cache::TinyLfuCache;
+
+#[derive(Eq, PartialEq, Hash)]
+struct TestKey(pub i32);
+
+impl MemSized for TestKey {
+    fn mem_bytes(&self) -> usize {
+        0
+    }
+}
+
+impl Debug for TestKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", &self.0)
+    }
+}
+
+#[derive(Eq, PartialEq)]
+struct TestValue(pub i32);
+
+impl MemSized for TestValue {
+    fn mem_bytes(&self) -> usize {
+        1
+    }
+}
+
+impl Debug for TestValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", &self.0)
+    }
+}
+
+#[test]
+fn test_put_and_get() {
+    let mut cache = TinyLfuCache::with_items_capacity(100);
+    cache.insert(TestKey(1), TestValue(10));
+    cache.insert(TestKey(2), TestValue(20));
+    assert_eq!(cache.get(&TestKey(1)), Some(&TestValue(10)));
+    assert_eq!(cache.get(&TestKey(2)), Some(&TestValue(20)));
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.bytes_size(), 2);
+}
+
+#[test]
+fn test_put_update() {
+    let mut cache = TinyLfuCache::with_items_capacity(1);
+    cache.insert(TestKey(1), TestValue(10));
+    assert_eq!(cache.insert(TestKey(1), TestValue(19)), Some(TestValue(10)));
+    assert_eq!(cache.get(&TestKey(1)), Some(&TestValue(19)));
+    assert_eq!(cache.len(), 1);
+}
+
+#[test]
+fn test_pop() {
+    let mut cache = TinyLfuCache::with_items_capacity(100);
+    cache.insert(TestKey(1), TestValue(10));
+    cache.insert(TestKey(2), TestValue(20));
+    assert_eq!(cache.pop(&TestKey(1)), Some(TestValue(10)));
+    assert_eq!(cache.pop(&TestKey(1)), None);
+    assert!(!cache.contains(&TestKey(1)));
+    assert!(cache.contains(&TestKey(2)));
+    assert_eq!(cache.len(), 1);
+    assert_eq!(cache.bytes_size(), 1);
+}
+
+#[test]
+fn test_capacity() {
+    let mut cache = TinyLfuCache::with_items_capacity(100);
+    for i in 0..1000 {
+        cache.insert(TestKey(i), TestValue(i));
+        assert!(cache.len() <= 100);
+    }
+    assert_eq!(cache.len(), 100);
+
+    cache.set_items_capacity(10);
+    assert_eq!(cache.len(), 10);
+
+    let mut cache = TinyLfuCache::with_bytes_capacity(50);
+    for i in 0..1000 {
+        cache.insert(TestKey(i), TestValue(i));
+        assert!(cache.bytes_size() <= 50);
+    }
+    assert_eq!(cache.bytes_size(), 50);
+
+    cache.clear();
+    assert!(cache.is_empty());
+    assert_eq!(cache.bytes_size(), 0);
+}
+
+#[test]
+fn test_scan_resistance() {
+    let mut cache = TinyLfuCache::with_items_capacity(100);
+    let mut get_or_insert = |i: i32| {
+        if cache.get(&TestKey(i)).is_none() {
+            cache.insert(TestKey(i), TestValue(i));
+        }
+    };
+
+    // The working set is accessed between scans. Together they touch more items than the
+    // cache holds, so an LRU cache would miss on every access.
+    let mut next = 1000;
+    for _ in 0..20 {
+        for i in 0..50 {
+            get_or_insert(i);
+        }
+        for _ in 0..60 {
+            get_or_insert(next);
+            next += 1;
+        }
+    }
+
+    let survivors = (0..50).filter(|i| cache.contains(&TestKey(*i))).count();
+    assert!(
+        survivors >= 45,
+        "only {survivors} items of the working set survived"
+    );
+
+    let stats = cache.admission_stats();
+    assert!(stats.rejected > stats.admitted);
+}
@@ -180,6 +180,27 @@ build_exceptions! {
     ///
     /// For example: try to with 3 columns into a table with 4 columns.
     TableSchemaMismatch(1303),
+    /// CheckConstraintViolated is used when rows written to a table violate its CHECK constraint.
+    ///
+    /// For example: insert `-1` into a column with `CHECK (c > 0)`.
+    CheckConstraintViolated(1304),
+    /// UnknownConstraint is used when the constraint to drop does not exist.
+    UnknownConstraint(1305),
+    /// ConstraintAlreadyExists is used when the constraint to add already exists.
+    ConstraintAlreadyExists(1306),
+    /// ColumnReferencedByConstraint is used when dropping or renaming a column referred by a constraint,
+    /// or dropping a key referred by a foreign key.
+    ColumnReferencedByConstraint(1307),
+    /// InvalidConstraint is used when the columns of a key constraint cannot form the key.
+    ///
+    /// For example: a foreign key referencing columns which are not a primary or unique key.
+    InvalidConstraint(1308),
+    /// KeyConstraintViolated is used when the rows of a table violate its informational key constraint.
+    ///
+    /// For example: validate a primary key on a column with duplicated values.
+    KeyConstraintViolated(1309),
+    /// ColumnReferencedByTTL is used when dropping, renaming or modifying a column referred by the TTL of a table.
+    ColumnReferencedByTTL(1310),
 
     // License related errors starts here
 
@@ -209,6 +230,11 @@ build_exceptions! {
     CloudControlNotEnabled(1702),
     IllegalCloudControlMessageFormat(1703),
 
+    // Embedded task scheduler error codes
+    TaskAlreadyExists(1704),
+    UnknownTask(1705),
+    TaskDependencyError(1706),
+
     // Geometry errors.
     GeometryError(1801),
     InvalidGeometryFormat(1802),
@@ -375,6 +401,13 @@ build_exceptions! {
     // dynamic error codes.
     IllegalDynamicTable(2740),
 
+    // Materialized view error codes.
+    IllegalMaterializedView(2750),
+
+    // Plan baseline error codes.
+    UnknownPlanBaseline(2760),
+    IllegalPlanBaseline(2761),
+
     // Variable error codes.
     UnknownVariable(2801),
     OnlySupportAsciiChars(2802),
@@ -422,6 +455,8 @@ build_exceptions! {
     CurrentTransactionIsAborted(4002),
     TransactionTimeout(4003),
     InvalidSessionState(4004),
+    UnknownSavepoint(4005),
+    NoActiveTransaction(4006),
 
     // recluster error codes
     NoNeedToRecluster(4011),
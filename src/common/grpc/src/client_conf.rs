@@ -39,6 +39,9 @@ pub struct RpcClientConf {
     /// None disables auto-sync.
     pub auto_sync_interval: Option<Duration>,
     pub unhealthy_endpoint_evict_time: Duration,
+    /// The staleness a read is allowed to observe, which lets it be served by a follower or a learner.
+    /// None sends every read to the leader.
+    pub max_read_staleness: Option<Duration>,
 }
 
 impl RpcClientConf {
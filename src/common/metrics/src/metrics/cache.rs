@@ -39,6 +39,10 @@ static CACHE_POPULATION_PENDING_COUNT: LazyLock<FamilyCounter<CacheLabels>> =
     LazyLock::new(|| register_counter_family("cache_population_pending_count"));
 static CACHE_POPULATION_OVERFLOW_COUNT: LazyLock<FamilyCounter<CacheLabels>> =
     LazyLock::new(|| register_counter_family("cache_population_overflow_count"));
+static CACHE_WARMUP_SCHEDULED_COUNT: LazyLock<FamilyCounter<CacheLabels>> =
+    LazyLock::new(|| register_counter_family("cache_warmup_scheduled_count"));
+static CACHE_WARMUP_LOADED_COUNT: LazyLock<FamilyCounter<CacheLabels>> =
+    LazyLock::new(|| register_counter_family("cache_warmup_loaded_count"));
 
 pub fn get_cache_access_count(cache_name: &str) -> u64 {
     get_metric_count_by_name(&CACHE_ACCESS_COUNT, cache_name)
@@ -52,6 +56,14 @@ pub fn get_cache_miss_count(cache_name: &str) -> u64 {
     get_metric_count_by_name(&CACHE_MISS_COUNT, cache_name)
 }
 
+pub fn get_cache_warmup_scheduled_count(cache_name: &str) -> u64 {
+    get_metric_count_by_name(&CACHE_WARMUP_SCHEDULED_COUNT, cache_name)
+}
+
+pub fn get_cache_warmup_loaded_count(cache_name: &str) -> u64 {
+    get_metric_count_by_name(&CACHE_WARMUP_LOADED_COUNT, cache_name)
+}
+
 fn get_metric_count_by_name(
     metric: &LazyLock<FamilyCounter<CacheLabels>>,
     cache_name: &str,
@@ -120,3 +132,19 @@ pub fn metrics_inc_cache_population_overflow_count(c: i64, cache_name: &str) {
         })
         .inc_by(c as u64);
 }
+
+pub fn metrics_inc_cache_warmup_scheduled_count(c: u64, cache_name: &str) {
+    CACHE_WARMUP_SCHEDULED_COUNT
+        .get_or_create(&CacheLabels {
+            cache_name: cache_name.to_string(),
+        })
+        .inc_by(c);
+}
+
+pub fn metrics_inc_cache_warmup_loaded_count(c: u64, cache_name: &str) {
+    CACHE_WARMUP_LOADED_COUNT
+        .get_or_create(&CacheLabels {
+            cache_name: cache_name.to_string(),
+        })
+        .inc_by(c);
+}
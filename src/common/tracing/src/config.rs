@@ -26,6 +26,7 @@ pub struct Config {
     pub profile: ProfileLogConfig,
     pub structlog: StructLogConfig,
     pub tracing: TracingConfig,
+    pub history: HistoryLogConfig,
 }
 
 impl Config {
@@ -219,6 +220,35 @@ impl Default for StructLogConfig {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct HistoryLogConfig {
+    pub on: bool,
+    /// Seconds between two flushes of the buffered events into the history tables.
+    pub interval: u64,
+    /// Days to keep the events in the history tables.
+    pub retention: u64,
+}
+
+impl Display for HistoryLogConfig {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "enabled={}, interval={}s, retention={}d",
+            self.on, self.interval, self.retention
+        )
+    }
+}
+
+impl Default for HistoryLogConfig {
+    fn default() -> Self {
+        Self {
+            on: false,
+            interval: 10,
+            retention: 7,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
 pub struct TracingConfig {
     pub on: bool,
@@ -2212,6 +2212,10 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
             update_stream_metas,
             deduplicated_labels,
             update_temp_tables: _,
+            commit_tables,
+            drop_tables,
+            rename_tables,
+            truncate_tables,
         } = req;
 
         let mut tbl_seqs = HashMap::new();
@@ -2250,6 +2254,30 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
             return Ok(Err(mismatched_tbs));
         }
 
+        // The (db_id, table_name) no longer taken once the transaction commits.
+        let mut released_names: HashSet<(u64, String)> = HashSet::new();
+
+        // Drop the tables dropped in the transaction. A dropped table updated in the same
+        // transaction keeps its new meta, which is written after and marked as dropped too.
+        let mut dropped_ids = HashSet::new();
+        for drop in &drop_tables {
+            let (tb_id_seq, _) = construct_drop_table_txn_operations(
+                self,
+                drop.table_name.clone(),
+                &drop.tenant,
+                drop.tb_id,
+                drop.db_id,
+                drop.if_exists,
+                true,
+                &mut txn,
+            )
+            .await?;
+            if tb_id_seq != 0 {
+                released_names.insert((drop.db_id, drop.table_name.clone()));
+                dropped_ids.insert(drop.tb_id);
+            }
+        }
+
         let mut new_table_meta_map: BTreeMap<u64, TableMeta> = BTreeMap::new();
         for (req, (tb_meta_seq, table_meta)) in update_table_metas.iter().zip(tb_meta_vec.iter()) {
             let tbid = TableId {
@@ -2259,6 +2287,13 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
             let table_meta = table_meta.as_ref().unwrap();
             let mut new_table_meta = req.0.new_table_meta.clone();
             new_table_meta.shared_by = table_meta.shared_by.clone();
+            // tables created in this transaction become visible on commit
+            if commit_tables.iter().any(|c| c.table_id == req.0.table_id) {
+                new_table_meta.drop_on = None;
+            }
+            if dropped_ids.contains(&req.0.table_id) {
+                new_table_meta.drop_on = Some(Utc::now());
+            }
 
             tbl_seqs.insert(req.0.table_id, *tb_meta_seq);
             txn.condition.push(txn_cond_seq(&tbid, Eq, *tb_meta_seq));
@@ -2284,6 +2319,22 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         // in each function. In this case there is chance that some `TableCopiedFileInfo` may not be
         // removed in `remove_table_copied_files`, but these data can be purged in case of expire time.
 
+        // Remove the copied files of the tables truncated in the transaction, before the
+        // files copied after the truncation are recorded.
+        let mut truncated_files = HashMap::new();
+        for truncate in &truncate_tables {
+            let dir_name = DirName::new(TableCopiedFileNameIdent {
+                table_id: truncate.table_id,
+                file: "dummy".to_string(),
+            });
+            for (name, seq_file) in self.list_pb_vec(&dir_name).await? {
+                let key = name.to_string_key();
+                txn.if_then
+                    .push(TxnOp::delete_exact(key.clone(), Some(seq_file.seq())));
+                truncated_files.insert(key, seq_file.seq());
+            }
+        }
+
         for (table_id, req) in copied_files {
             let tbid = TableId { table_id };
 
@@ -2297,7 +2348,12 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                 };
 
                 if req.insert_if_not_exists {
-                    txn.condition.push(txn_cond_eq_seq(&key, 0));
+                    // a file removed by the truncation can be copied again
+                    let seq = truncated_files
+                        .get(&key.to_string_key())
+                        .copied()
+                        .unwrap_or(0);
+                    txn.condition.push(txn_cond_eq_seq(&key, seq));
                 }
                 txn.if_then.push(txn_op_put_pb(&key, &file_info, req.ttl)?)
             }
@@ -2351,6 +2407,196 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
             txn.if_then
                 .push(build_upsert_table_deduplicated_label(deduplicated_label));
         }
+
+        // Rename the tables renamed in the transaction, the way `rename_table` does.
+        // All the old names are released before any new name is taken.
+        let mut id_lists = TxnTableIdLists::default();
+        let mut renamed = Vec::with_capacity(rename_tables.len());
+        for rename in &rename_tables {
+            let (seq_db_id, db_meta) = get_db_or_err(
+                self,
+                &rename.name_ident.db_name_ident(),
+                "update_multi_table_meta: rename",
+            )
+            .await?;
+            let db_id = *seq_db_id.data;
+            let dbid_tbname = DBIdTableName {
+                db_id,
+                table_name: rename.name_ident.table_name.clone(),
+            };
+            let (tb_id_seq, table_id) = get_u64_value(self, &dbid_tbname).await?;
+            assert_table_exist(
+                tb_id_seq,
+                &rename.name_ident,
+                "update_multi_table_meta: rename",
+            )?;
+
+            let idlist = TableIdHistoryIdent {
+                database_id: db_id,
+                table_name: rename.name_ident.table_name.clone(),
+            };
+            let history = id_lists
+                .get(self, idlist, TableIdList::new_with_ids([table_id]))
+                .await?;
+            if history.origin.last() != Some(&table_id) {
+                return Err(KVAppError::AppError(AppError::UnknownTable(
+                    UnknownTable::new(
+                        &rename.name_ident.table_name,
+                        "update_multi_table_meta: rename, last table id conflict",
+                    ),
+                )));
+            }
+            history.current.id_list.retain(|id| *id != table_id);
+
+            released_names.insert((db_id, rename.name_ident.table_name.clone()));
+            txn.condition.extend([
+                txn_cond_seq(&seq_db_id.data, Eq, db_meta.seq),
+                txn_cond_seq(&dbid_tbname, Eq, tb_id_seq),
+            ]);
+            txn.if_then.extend([
+                txn_op_put(&seq_db_id.data, serialize_struct(&*db_meta)?),
+                txn_op_del(&dbid_tbname),
+            ]);
+            renamed.push((rename, table_id));
+        }
+
+        for (rename, table_id) in renamed {
+            let new_name_ident = TableNameIdent {
+                tenant: rename.name_ident.tenant.clone(),
+                db_name: rename.new_db_name.clone(),
+                table_name: rename.new_table_name.clone(),
+            };
+            let (new_seq_db_id, new_db_meta) = get_db_or_err(
+                self,
+                &new_name_ident.db_name_ident(),
+                "update_multi_table_meta: rename, new db",
+            )
+            .await?;
+            let new_db_id = *new_seq_db_id.data;
+            let newdbid_newtbname = DBIdTableName {
+                db_id: new_db_id,
+                table_name: rename.new_table_name.clone(),
+            };
+            let (new_tb_id_seq, _) = get_u64_value(self, &newdbid_newtbname).await?;
+            if !released_names.contains(&(new_db_id, rename.new_table_name.clone())) {
+                table_has_to_not_exist(
+                    new_tb_id_seq,
+                    &new_name_ident,
+                    "update_multi_table_meta: rename",
+                )?;
+            }
+
+            let new_idlist = TableIdHistoryIdent {
+                database_id: new_db_id,
+                table_name: rename.new_table_name.clone(),
+            };
+            id_lists
+                .get(self, new_idlist, TableIdList::new())
+                .await?
+                .current
+                .append(table_id);
+
+            let table_id_to_name_key = TableIdToName { table_id };
+            let table_id_to_name_seq = self.get_seq(&table_id_to_name_key).await?;
+
+            txn.condition.extend([
+                txn_cond_seq(&new_seq_db_id.data, Eq, new_db_meta.seq),
+                txn_cond_seq(&newdbid_newtbname, Eq, new_tb_id_seq),
+                txn_cond_seq(&table_id_to_name_key, Eq, table_id_to_name_seq),
+            ]);
+            txn.if_then.extend([
+                txn_op_put(&new_seq_db_id.data, serialize_struct(&*new_db_meta)?),
+                txn_op_put(&newdbid_newtbname, serialize_u64(table_id)?),
+                txn_op_put(&table_id_to_name_key, serialize_struct(&newdbid_newtbname)?),
+            ]);
+        }
+
+        // Make the tables created in a multi-statement transaction visible,
+        // the same way `commit_table_meta` does.
+        for commit in commit_tables {
+            let db_id = commit.db_id;
+            let table_id = commit.table_id;
+            let table_name = &commit.name_ident.table_name;
+            let commit_error = |reason: &str| {
+                KVAppError::AppError(AppError::from(CommitTableMetaError::new(
+                    table_name.clone(),
+                    reason.to_string(),
+                )))
+            };
+
+            let (db_meta_seq, db_meta) =
+                get_db_by_id_or_err(self, db_id, "update_multi_table_meta").await?;
+
+            let dbid_tbname = DBIdTableName {
+                db_id,
+                table_name: table_name.clone(),
+            };
+            let (dbid_tbname_seq, _) = get_u64_value(self, &dbid_tbname).await?;
+
+            let Some(orphan_table_name) = commit.orphan_table_name.clone() else {
+                return Err(commit_error("orphan table name is missing"));
+            };
+            let orphan_idlist = TableIdHistoryIdent {
+                database_id: db_id,
+                table_name: orphan_table_name,
+            };
+            let seq_orphan = self.get_pb(&orphan_idlist).await?;
+            let orphan_seq = seq_orphan.seq();
+            let orphan_ids = seq_orphan.into_value().unwrap_or_default();
+            if orphan_ids.id_list != vec![table_id] {
+                return Err(commit_error("orphan list does not hold the table id"));
+            }
+
+            let idlist = TableIdHistoryIdent {
+                database_id: db_id,
+                table_name: table_name.clone(),
+            };
+            let history = id_lists.get(self, idlist, TableIdList::new()).await?;
+            match commit.prev_table_id {
+                Some(prev_table_id) => {
+                    if history.origin.last() != Some(&prev_table_id) {
+                        return Err(commit_error("prev_table_id has been changed"));
+                    }
+                }
+                // The table is renamed in the transaction, or the name had no table
+                // when it was created: the name has to be free.
+                None => {
+                    if dbid_tbname_seq != 0
+                        && !released_names.contains(&(db_id, table_name.clone()))
+                    {
+                        return Err(commit_error("the table name has been taken"));
+                    }
+                }
+            }
+            history.current.id_list.push(table_id);
+
+            if !new_table_meta_map.contains_key(&table_id) {
+                let tbid = TableId { table_id };
+                let (tb_meta_seq, tb_meta) = self.get_pb_seq_and_value(&tbid).await?;
+                let Some(mut tb_meta) = tb_meta else {
+                    return Err(KVAppError::AppError(AppError::UnknownTableId(
+                        UnknownTableId::new(table_id, "update_multi_table_meta"),
+                    )));
+                };
+                tb_meta.drop_on = None;
+                txn.condition.push(txn_cond_seq(&tbid, Eq, tb_meta_seq));
+                txn.if_then
+                    .push(txn_op_put(&tbid, serialize_struct(&tb_meta)?));
+            }
+
+            txn.condition.extend([
+                txn_cond_seq(&DatabaseId { db_id }, Eq, db_meta_seq),
+                txn_cond_seq(&dbid_tbname, Eq, dbid_tbname_seq),
+                txn_cond_seq(&orphan_idlist, Eq, orphan_seq),
+            ]);
+            txn.if_then.extend([
+                txn_op_put(&DatabaseId { db_id }, serialize_struct(&db_meta)?),
+                txn_op_put(&dbid_tbname, serialize_u64(table_id)?),
+                txn_op_del(&orphan_idlist),
+            ]);
+        }
+        id_lists.write(&mut txn)?;
+
         let (succ, responses) = send_txn(self, txn).await?;
         if succ {
             return Ok(Ok(UpdateTableMetaReply {}));
@@ -3429,6 +3675,57 @@ async fn construct_drop_table_txn_operations(
     Ok((tb_id_seq, table_id))
 }
 
+/// The table id lists changed by a multi-statement transaction.
+///
+/// A list changed by several renames and creations is read once, and written back once
+/// with the condition that it is not changed since it is read.
+#[derive(Default)]
+struct TxnTableIdLists {
+    lists: BTreeMap<String, TxnTableIdList>,
+}
+
+struct TxnTableIdList {
+    ident: TableIdHistoryIdent,
+    seq: u64,
+    /// The list when it is read.
+    origin: TableIdList,
+    /// The list to write back.
+    current: TableIdList,
+}
+
+impl TxnTableIdLists {
+    /// Returns the list of `ident`, an absent list is `default`.
+    async fn get(
+        &mut self,
+        kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+        ident: TableIdHistoryIdent,
+        default: TableIdList,
+    ) -> Result<&mut TxnTableIdList, KVAppError> {
+        let key = ident.to_string_key();
+        if !self.lists.contains_key(&key) {
+            let seq_list = kv_api.get_pb(&ident).await?;
+            let seq = seq_list.seq();
+            let origin = seq_list.into_value().unwrap_or(default);
+            self.lists.insert(key.clone(), TxnTableIdList {
+                ident,
+                seq,
+                current: origin.clone(),
+                origin,
+            });
+        }
+        Ok(self.lists.get_mut(&key).unwrap())
+    }
+
+    fn write(self, txn: &mut TxnRequest) -> Result<(), KVAppError> {
+        for list in self.lists.into_values() {
+            txn.condition.push(txn_cond_seq(&list.ident, Eq, list.seq));
+            txn.if_then
+                .push(txn_op_put(&list.ident, serialize_struct(&list.current)?));
+        }
+        Ok(())
+    }
+}
+
 async fn drop_database_meta(
     kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
     tenant_dbname: &DatabaseNameIdent,
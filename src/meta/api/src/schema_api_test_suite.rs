@@ -5136,6 +5136,162 @@ impl SchemaApiTestSuite {
             mt.commit_table_meta(commit_table_req).await?;
         }
 
+        info!("--- commit tables created as dropped with update_multi_table_meta");
+        {
+            let mut commit_tables = vec![];
+            let mut update_table_metas = vec![];
+            for (i, tbl_name) in ["tb3", "tb4"].into_iter().enumerate() {
+                let create_table_req = CreateTableReq {
+                    create_option: CreateOption::Create,
+                    name_ident: TableNameIdent {
+                        tenant: tenant.clone(),
+                        db_name: db_name.to_string(),
+                        table_name: tbl_name.to_string(),
+                    },
+                    table_meta: drop_table_meta(created_on),
+                    as_dropped: true,
+                };
+                let resp = mt.create_table(create_table_req.clone()).await?;
+
+                // not visible until committed
+                let got = mt.get_table((tenant_name, db_name, tbl_name).into()).await;
+                assert!(got.is_err());
+
+                // only the first table is updated along with the commit
+                if i == 0 {
+                    let req = UpdateTableMetaReq {
+                        table_id: resp.table_id,
+                        seq: MatchSeq::Exact(resp.table_id_seq.unwrap()),
+                        new_table_meta: drop_table_meta(created_on),
+                    };
+                    update_table_metas.push((req, TableInfo::default()));
+                }
+                commit_tables.push(CommitTableMetaReq {
+                    name_ident: create_table_req.name_ident.clone(),
+                    db_id: resp.db_id,
+                    table_id: resp.table_id,
+                    prev_table_id: resp.prev_table_id,
+                    orphan_table_name: resp.orphan_table_name.clone(),
+                });
+            }
+
+            mt.update_multi_table_meta(UpdateMultiTableMetaReq {
+                update_table_metas,
+                commit_tables,
+                ..Default::default()
+            })
+            .await?
+            .unwrap();
+
+            for tbl_name in ["tb3", "tb4"] {
+                let got = mt
+                    .get_table((tenant_name, db_name, tbl_name).into())
+                    .await?;
+                assert!(got.meta.drop_on.is_none());
+            }
+        }
+
+        info!("--- drop, rename and truncate tables with update_multi_table_meta");
+        {
+            let tb3 = mt.get_table((tenant_name, db_name, "tb3").into()).await?;
+            let tb4 = mt.get_table((tenant_name, db_name, "tb4").into()).await?;
+            let tb4_id = tb4.ident.table_id;
+
+            // record a copied file of tb4
+            let mut file_info = BTreeMap::new();
+            file_info.insert("file".to_string(), TableCopiedFileInfo {
+                etag: Some("etag".to_owned()),
+                content_length: 1024,
+                last_modified: Some(Utc::now()),
+            });
+            mt.update_multi_table_meta(UpdateMultiTableMetaReq {
+                update_table_metas: vec![(
+                    UpdateTableMetaReq {
+                        table_id: tb4_id,
+                        seq: MatchSeq::Exact(tb4.ident.seq),
+                        new_table_meta: tb4.meta.clone(),
+                    },
+                    tb4.as_ref().clone(),
+                )],
+                copied_files: vec![(tb4_id, UpsertTableCopiedFileReq {
+                    file_info,
+                    ttl: None,
+                    insert_if_not_exists: true,
+                })],
+                ..Default::default()
+            })
+            .await?
+            .unwrap();
+
+            // a new tb3 replaces the dropped one
+            let create_table_req = CreateTableReq {
+                create_option: CreateOption::CreateOrReplace,
+                name_ident: TableNameIdent {
+                    tenant: tenant.clone(),
+                    db_name: db_name.to_string(),
+                    table_name: "tb3".to_string(),
+                },
+                table_meta: drop_table_meta(created_on),
+                as_dropped: true,
+            };
+            let resp = mt.create_table(create_table_req.clone()).await?;
+            let new_tb3_id = resp.table_id;
+
+            mt.update_multi_table_meta(UpdateMultiTableMetaReq {
+                commit_tables: vec![CommitTableMetaReq {
+                    name_ident: create_table_req.name_ident.clone(),
+                    db_id: resp.db_id,
+                    table_id: new_tb3_id,
+                    prev_table_id: resp.prev_table_id,
+                    orphan_table_name: resp.orphan_table_name.clone(),
+                }],
+                drop_tables: vec![DropTableByIdReq {
+                    if_exists: false,
+                    tenant: tenant.clone(),
+                    tb_id: tb3.ident.table_id,
+                    table_name: "tb3".to_string(),
+                    db_id: resp.db_id,
+                    engine: "FUSE".to_string(),
+                    session_id: "".to_string(),
+                }],
+                rename_tables: vec![RenameTableReq {
+                    if_exists: false,
+                    name_ident: TableNameIdent {
+                        tenant: tenant.clone(),
+                        db_name: db_name.to_string(),
+                        table_name: "tb4".to_string(),
+                    },
+                    new_db_name: db_name.to_string(),
+                    new_table_name: "tb5".to_string(),
+                }],
+                truncate_tables: vec![TruncateTableReq {
+                    table_id: tb4_id,
+                    batch_size: None,
+                }],
+                ..Default::default()
+            })
+            .await?
+            .unwrap();
+
+            let got = mt.get_table((tenant_name, db_name, "tb3").into()).await?;
+            assert_eq!(got.ident.table_id, new_tb3_id);
+            let dropped = mt.get_table_by_id(tb3.ident.table_id).await?.unwrap();
+            assert!(dropped.data.drop_on.is_some());
+
+            let got = mt.get_table((tenant_name, db_name, "tb4").into()).await;
+            assert!(got.is_err());
+            let got = mt.get_table((tenant_name, db_name, "tb5").into()).await?;
+            assert_eq!(got.ident.table_id, tb4_id);
+
+            let resp = mt
+                .get_table_copied_file_info(GetTableCopiedFileReq {
+                    table_id: tb4_id,
+                    files: vec!["file".to_string()],
+                })
+                .await?;
+            assert!(resp.file_info.is_empty());
+        }
+
         // verify the orphan table id list will be vacuum
         {
             // use a new tenant and db do test
@@ -5544,6 +5700,8 @@ impl SchemaApiTestSuite {
                 ident: SequenceIdent::new(&tenant, sequence_name),
                 create_on,
                 comment: Some("seq".to_string()),
+                start: 1,
+                step: 1,
             };
 
             let _resp = mt.create_sequence(req).await?;
@@ -5585,6 +5743,8 @@ impl SchemaApiTestSuite {
                 ident: SequenceIdent::new(&tenant, sequence_name),
                 create_on,
                 comment: Some("seq1".to_string()),
+                start: 1,
+                step: 1,
             };
 
             let _resp = mt.create_sequence(req).await?;
@@ -5597,6 +5757,33 @@ impl SchemaApiTestSuite {
             assert_eq!(resp.current, 1);
         }
 
+        info!("--- replace sequence with start and step");
+        {
+            let req = CreateSequenceReq {
+                create_option: CreateOption::CreateOrReplace,
+                ident: SequenceIdent::new(&tenant, sequence_name),
+                create_on,
+                comment: None,
+                start: 100,
+                step: 5,
+            };
+
+            let _resp = mt.create_sequence(req).await?;
+
+            let req = GetSequenceNextValueReq {
+                ident: SequenceIdent::new(&tenant, sequence_name),
+                count: 3,
+            };
+            let resp = mt.get_sequence_next_value(req).await?;
+            assert_eq!(resp.start, 100);
+            assert_eq!(resp.step, 5);
+            assert_eq!(resp.end, 110);
+
+            let req = SequenceIdent::new(&tenant, sequence_name);
+            let resp = mt.get_sequence(&req).await?;
+            assert_eq!(resp.unwrap().data.current, 115);
+        }
+
         {
             let req = DropSequenceReq {
                 ident: SequenceIdent::new(&tenant, sequence_name),
@@ -121,20 +121,24 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SequenceApi for KV {
 
             let start = sequence_meta.current;
             let count = req.count;
-            if u64::MAX - sequence_meta.current < count {
+            let step = sequence_meta.step as u64;
+            let Some(next) = count
+                .checked_mul(step)
+                .and_then(|n| sequence_meta.current.checked_add(n))
+            else {
                 return Err(KVAppError::AppError(AppError::SequenceError(
                     SequenceError::OutofSequenceRange(OutofSequenceRange::new(
                         sequence_name,
                         format!(
-                            "{:?}: current: {}, count: {}",
-                            sequence_name, sequence_meta.current, count
+                            "{:?}: current: {}, step: {}, count: {}",
+                            sequence_name, sequence_meta.current, step, count
                         ),
                     )),
                 )));
-            }
+            };
 
             // update meta
-            sequence_meta.current += count;
+            sequence_meta.current = next;
             sequence_meta.update_on = Utc::now();
 
             let condition = vec![txn_cond_eq_seq(&ident, sequence_seq)];
@@ -156,7 +160,7 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SequenceApi for KV {
                 return Ok(GetSequenceNextValueReply {
                     start,
                     step: sequence_meta.step,
-                    end: sequence_meta.current - 1,
+                    end: sequence_meta.current - step,
                 });
             }
         }
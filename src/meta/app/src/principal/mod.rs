@@ -19,6 +19,7 @@ mod file_format;
 mod network_policy;
 mod ownership_info;
 mod password_policy;
+mod plan_baseline;
 mod principal_identity;
 pub mod role_ident;
 mod role_info;
@@ -64,6 +65,8 @@ pub use ownership_info::OwnershipInfo;
 pub use ownership_object::OwnershipObject;
 pub use password_policy::PasswordPolicy;
 pub use password_policy_ident::PasswordPolicyIdent;
+pub use plan_baseline::PlanBaseline;
+pub use plan_baseline::PlanBaselineHint;
 pub use principal_identity::PrincipalIdentity;
 pub use procedure::CreateProcedureReply;
 pub use procedure::CreateProcedureReq;
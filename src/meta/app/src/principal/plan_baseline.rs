@@ -0,0 +1,45 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The pinned plan of a query, the query is identified by the hash of its normalized SQL.
+///
+/// The plan is kept as the optimizer hints that reproduce it: the join order, and the join
+/// method and distribution hints.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PlanBaseline {
+    pub sql_hash: String,
+    pub query_text: String,
+    /// The tables in the order they are joined.
+    pub join_order: Vec<String>,
+    pub hints: Vec<PlanBaselineHint>,
+    pub plan_fingerprint: String,
+    /// The execution time of the pinned plan, measured by the first query that uses it.
+    pub execution_time_ms: Option<u64>,
+    /// Fingerprints of the plans that are slower than the pinned plan.
+    pub rejected_fingerprints: Vec<String>,
+    pub created_on: DateTime<Utc>,
+    pub updated_on: DateTime<Utc>,
+}
+
+/// A join hint of a plan baseline, such as `HASH_JOIN(t1)`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PlanBaselineHint {
+    pub name: String,
+    pub tables: Vec<String>,
+}
@@ -38,9 +38,9 @@ impl From<CreateSequenceReq> for SequenceMeta {
             comment: p.comment.clone(),
             create_on: p.create_on,
             update_on: p.create_on,
-            start: 1,
-            step: 1,
-            current: 1,
+            start: p.start,
+            step: p.step,
+            current: p.start,
         }
     }
 }
@@ -51,6 +51,10 @@ pub struct CreateSequenceReq {
     pub ident: SequenceIdent,
     pub create_on: DateTime<Utc>,
     pub comment: Option<String>,
+    /// The first value handed out by the sequence.
+    pub start: u64,
+    /// The difference between two consecutive values, must be positive.
+    pub step: i64,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -65,8 +69,8 @@ pub struct GetSequenceNextValueReq {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct GetSequenceNextValueReply {
     pub start: u64,
-    // step has no use until now
     pub step: i64,
+    /// The last value allocated, i.e. `start + (count - 1) * step`.
     pub end: u64,
 }
 
@@ -279,6 +279,75 @@ pub struct TableMeta {
     pub shared_by: BTreeSet<u64>,
     pub column_mask_policy: Option<BTreeMap<String, String>>,
     pub indexes: BTreeMap<String, TableIndex>,
+    /// Constraints of the table, keyed by the constraint name.
+    pub constraints: BTreeMap<String, TableConstraint>,
+}
+
+/// A constraint that the rows of a table must satisfy.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub enum TableConstraint {
+    /// A boolean expression in SQL which must not evaluate to false for any row.
+    /// Rows for which it evaluates to NULL satisfy the constraint.
+    Check(String),
+    /// The columns uniquely identify a row and are not NULL. Not enforced.
+    PrimaryKey(Vec<String>),
+    /// The non-NULL values of the columns are unique. Not enforced.
+    Unique(Vec<String>),
+    /// The non-NULL values of the columns exist in the referenced primary or unique key.
+    /// Not enforced.
+    ForeignKey {
+        columns: Vec<String>,
+        ref_database: String,
+        ref_table: String,
+        ref_columns: Vec<String>,
+    },
+}
+
+impl TableConstraint {
+    /// Whether the constraint is checked when rows are written.
+    ///
+    /// Key constraints are informational: they are only verified by
+    /// `ALTER TABLE ... VALIDATE CONSTRAINT`.
+    pub fn is_enforced(&self) -> bool {
+        matches!(self, TableConstraint::Check(_))
+    }
+
+    /// The columns of a key constraint, empty for a CHECK constraint.
+    pub fn key_columns(&self) -> &[String] {
+        match self {
+            TableConstraint::Check(_) => &[],
+            TableConstraint::PrimaryKey(columns)
+            | TableConstraint::Unique(columns)
+            | TableConstraint::ForeignKey { columns, .. } => columns,
+        }
+    }
+}
+
+impl Display for TableConstraint {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            TableConstraint::Check(expr) => write!(f, "CHECK ({})", expr),
+            TableConstraint::PrimaryKey(columns) => {
+                write!(f, "PRIMARY KEY ({}) NOT ENFORCED", columns.join(", "))
+            }
+            TableConstraint::Unique(columns) => {
+                write!(f, "UNIQUE ({}) NOT ENFORCED", columns.join(", "))
+            }
+            TableConstraint::ForeignKey {
+                columns,
+                ref_database,
+                ref_table,
+                ref_columns,
+            } => write!(
+                f,
+                "FOREIGN KEY ({}) REFERENCES {}.{} ({}) NOT ENFORCED",
+                columns.join(", "),
+                ref_database,
+                ref_table,
+                ref_columns.join(", ")
+            ),
+        }
+    }
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
@@ -437,6 +506,7 @@ impl Default for TableMeta {
             shared_by: BTreeSet::new(),
             column_mask_policy: None,
             indexes: BTreeMap::new(),
+            constraints: BTreeMap::new(),
         }
     }
 }
@@ -445,13 +515,14 @@ impl Display for TableMeta {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         write!(
             f,
-            "Engine: {}={:?}, Schema: {:?}, Options: {:?}, FieldComments: {:?} Indexes: {:?} CreatedOn: {:?} DropOn: {:?}",
+            "Engine: {}={:?}, Schema: {:?}, Options: {:?}, FieldComments: {:?} Indexes: {:?} Constraints: {:?} CreatedOn: {:?} DropOn: {:?}",
             self.engine,
             self.engine_options,
             self.schema,
             self.options,
             self.field_comments,
             self.indexes,
+            self.constraints,
             self.created_on,
             self.drop_on,
         )
@@ -770,6 +841,17 @@ pub struct UpdateMultiTableMetaReq {
     pub update_stream_metas: Vec<UpdateStreamMetaReq>,
     pub deduplicated_labels: Vec<String>,
     pub update_temp_tables: Vec<UpdateTempTableReq>,
+    /// Tables created as dropped in a multi-statement transaction,
+    /// they are made visible along with the other updates.
+    pub commit_tables: Vec<CommitTableMetaReq>,
+    /// Tables dropped in a multi-statement transaction.
+    pub drop_tables: Vec<DropTableByIdReq>,
+    /// Tables renamed in a multi-statement transaction,
+    /// from the name before the transaction to the final name.
+    pub rename_tables: Vec<RenameTableReq>,
+    /// Tables truncated in a multi-statement transaction,
+    /// their copied files are removed before the new ones are recorded.
+    pub truncate_tables: Vec<TruncateTableReq>,
 }
 
 impl UpdateMultiTableMetaReq {
@@ -779,6 +861,10 @@ impl UpdateMultiTableMetaReq {
             && self.update_stream_metas.is_empty()
             && self.deduplicated_labels.is_empty()
             && self.update_temp_tables.is_empty()
+            && self.commit_tables.is_empty()
+            && self.drop_tables.is_empty()
+            && self.rename_tables.is_empty()
+            && self.truncate_tables.is_empty()
     }
 }
 
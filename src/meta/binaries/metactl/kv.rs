@@ -0,0 +1,254 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Inspect and edit keys in a running meta-service.
+
+use std::fs;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::bail;
+use databend_common_meta_client::ClientHandle;
+use databend_common_meta_client::MetaGrpcClient;
+use databend_common_meta_kvapi::kvapi;
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_types::protobuf::WatchRequest;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::Operation;
+use databend_common_meta_types::SeqV;
+use databend_common_meta_types::UpsertKV;
+use databend_common_meta_types::With;
+use serde_json::json;
+use tokio_stream::StreamExt;
+
+use crate::value_codec::decode_value;
+use crate::value_codec::encode_value;
+use crate::value_codec::raw_value;
+use crate::value_codec::value_type_name;
+use crate::DeleteArgs;
+use crate::GetArgs;
+use crate::ListArgs;
+use crate::UpsertArgs;
+use crate::WatchArgs;
+
+fn new_client(grpc_api_address: &str) -> anyhow::Result<Arc<ClientHandle>> {
+    let client = MetaGrpcClient::try_create(
+        vec![grpc_api_address.to_string()],
+        "root",
+        "xxx",
+        None,
+        None,
+        None,
+    )?;
+    Ok(client)
+}
+
+/// Render a record as a json object, decode the value if `raw` is false,
+/// otherwise the value is rendered as hex encoded bytes.
+fn record_to_json(key: &str, seq_v: &Option<SeqV>, raw: bool) -> serde_json::Value {
+    let Some(seq_v) = seq_v else {
+        return serde_json::Value::Null;
+    };
+
+    let value = if raw {
+        raw_value(&seq_v.data)
+    } else {
+        decode_value(key, &seq_v.data)
+    };
+
+    json!({
+        "seq": seq_v.seq,
+        "meta": seq_v.meta,
+        "type": value_type_name(key),
+        "value": value,
+    })
+}
+
+pub async fn get(args: &GetArgs) -> anyhow::Result<()> {
+    let client = new_client(&args.grpc_api_address)?;
+
+    let got = client.get_kv(&args.key).await?;
+    let record = record_to_json(&args.key, &got, args.raw);
+
+    println!("{}", json!({"key": args.key, "record": record}));
+    Ok(())
+}
+
+pub async fn list(args: &ListArgs) -> anyhow::Result<()> {
+    let client = new_client(&args.grpc_api_address)?;
+
+    let mut strm = client.list_kv(&args.prefix).await?;
+    let mut n = 0;
+
+    while let Some(item) = strm.next().await {
+        if args.limit.is_some_and(|limit| n >= limit) {
+            break;
+        }
+
+        let item = item?;
+        let seq_v = item.value.map(SeqV::from);
+        let record = record_to_json(&item.key, &seq_v, args.raw);
+
+        println!("{}", json!({"key": item.key, "record": record}));
+        n += 1;
+    }
+
+    eprintln!("listed {} keys with prefix '{}'", n, args.prefix);
+    Ok(())
+}
+
+pub async fn watch(args: &WatchArgs) -> anyhow::Result<()> {
+    let client = new_client(&args.grpc_api_address)?;
+
+    let (start, end) = kvapi::prefix_to_range(&args.prefix)?;
+    let mut req = WatchRequest::new(start, Some(end));
+    req.initial_flush = args.initial_flush;
+    if let Some(seq) = args.start_after_seq {
+        req = req.with_start_after_seq(seq);
+    }
+
+    let mut strm = client.request(req).await?;
+
+    while let Some(resp) = strm.message().await? {
+        let Some(ev) = resp.event else {
+            continue;
+        };
+
+        let prev = record_to_json(&ev.key, &ev.prev.map(SeqV::from), args.raw);
+        let current = record_to_json(&ev.key, &ev.current.map(SeqV::from), args.raw);
+
+        println!(
+            "{}",
+            json!({"key": ev.key, "prev": prev, "current": current})
+        );
+    }
+
+    Ok(())
+}
+
+pub async fn upsert(args: &UpsertArgs) -> anyhow::Result<()> {
+    let value = if args.json {
+        encode_value(&args.key, &args.value)?
+    } else {
+        // A string written into a protobuf key would break every reader of it.
+        if let Some(type_name) = value_type_name(&args.key) {
+            if !args.force {
+                bail!(
+                    "{} holds a protobuf encoded {}: use --json to write it as json of {}, or --force to write the value as it is",
+                    args.key,
+                    type_name,
+                    type_name
+                );
+            }
+        }
+        args.value.as_bytes().to_vec()
+    };
+
+    let seq = match args.match_seq {
+        Some(seq) => MatchSeq::Exact(seq),
+        None => MatchSeq::GE(0),
+    };
+
+    let upsert = UpsertKV::new(&args.key, seq, Operation::Update(value), None);
+
+    apply(
+        &args.grpc_api_address,
+        upsert,
+        args.dry_run,
+        &args.audit_log,
+    )
+    .await
+}
+
+pub async fn delete(args: &DeleteArgs) -> anyhow::Result<()> {
+    let mut upsert = UpsertKV::delete(&args.key);
+    if let Some(seq) = args.match_seq {
+        upsert = upsert.with(MatchSeq::Exact(seq));
+    }
+
+    apply(
+        &args.grpc_api_address,
+        upsert,
+        args.dry_run,
+        &args.audit_log,
+    )
+    .await
+}
+
+/// Apply a change to a key, or just show it if `dry_run` is true.
+///
+/// Every applied change is appended to the audit log as a json line.
+async fn apply(
+    grpc_api_address: &str,
+    upsert: UpsertKV,
+    dry_run: bool,
+    audit_log: &str,
+) -> anyhow::Result<()> {
+    let client = new_client(grpc_api_address)?;
+    let key = upsert.key.clone();
+
+    if dry_run {
+        let current = client.get_kv(&key).await?;
+        let new = match &upsert.value {
+            Operation::Update(v) => Some(SeqV::new(0, v.clone())),
+            _ => None,
+        };
+
+        println!(
+            "{}",
+            json!({
+                "dry_run": true,
+                "key": key,
+                "match_seq": upsert.seq.to_string(),
+                "current": record_to_json(&key, &current, false),
+                "new": record_to_json(&key, &new, false),
+            })
+        );
+        return Ok(());
+    }
+
+    let match_seq = upsert.seq.to_string();
+    let change = client.upsert_kv(upsert).await?;
+
+    let entry = json!({
+        "time": chrono::Utc::now().to_rfc3339(),
+        "user": std::env::var("USER").unwrap_or_default(),
+        "grpc_api_address": grpc_api_address,
+        "key": key,
+        "match_seq": match_seq,
+        "prev": record_to_json(&key, &change.prev, true),
+        "result": record_to_json(&key, &change.result, true),
+        "changed": change.is_changed(),
+    });
+
+    append_audit_log(audit_log, &entry)?;
+
+    println!("{}", entry);
+    if !change.is_changed() {
+        eprintln!("WARN: {} is not changed, the seq does not match", key);
+    }
+    Ok(())
+}
+
+fn append_audit_log(path: &str, entry: &serde_json::Value) -> anyhow::Result<()> {
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let mut f = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(f, "{}", entry)?;
+    Ok(())
+}
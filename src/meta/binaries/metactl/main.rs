@@ -20,8 +20,11 @@ pub mod admin;
 pub mod export_from_disk;
 pub mod import;
 pub mod import_v004;
+pub mod kv;
 pub(crate) mod reading;
+pub mod restore;
 pub mod upgrade;
+pub mod value_codec;
 
 use std::collections::BTreeMap;
 
@@ -197,6 +200,30 @@ impl From<ImportArgs> for RaftConfig {
     }
 }
 
+#[derive(Debug, Clone, Deserialize, Args)]
+pub struct RestoreArgs {
+    /// The archive dir written by databend-meta with `--archive-dir`.
+    #[clap(long)]
+    pub archive_dir: String,
+
+    /// The dir to restore meta data into. Existing data in it is removed.
+    #[clap(long)]
+    pub raft_dir: String,
+
+    /// Restore the state upto and including this point:
+    /// a raft log index, or a RFC 3339 time such as `2024-10-01T08:00:00Z`.
+    #[clap(long)]
+    pub until: String,
+
+    /// initial_cluster format: node_id=endpoint,grpc_api_addr
+    #[clap(long)]
+    pub initial_cluster: Vec<String>,
+
+    /// The node id of the restored node.
+    #[clap(long, default_value = "0")]
+    pub id: u64,
+}
+
 #[derive(Debug, Clone, Deserialize, Args)]
 pub struct TransferLeaderArgs {
     #[clap(long)]
@@ -206,6 +233,116 @@ pub struct TransferLeaderArgs {
     pub admin_api_address: String,
 }
 
+#[derive(Debug, Clone, Deserialize, Args)]
+pub struct GetArgs {
+    #[clap(long, default_value = "127.0.0.1:9191")]
+    pub grpc_api_address: String,
+
+    /// The key to get.
+    #[clap(long)]
+    pub key: String,
+
+    /// Print the value as hex encoded bytes, instead of decoding it to json.
+    #[clap(long)]
+    pub raw: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Args)]
+pub struct ListArgs {
+    #[clap(long, default_value = "127.0.0.1:9191")]
+    pub grpc_api_address: String,
+
+    /// List the keys starting with this prefix.
+    #[clap(long)]
+    pub prefix: String,
+
+    /// The max number of keys to list.
+    #[clap(long)]
+    pub limit: Option<usize>,
+
+    /// Print the values as hex encoded bytes, instead of decoding them to json.
+    #[clap(long)]
+    pub raw: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Args)]
+pub struct WatchArgs {
+    #[clap(long, default_value = "127.0.0.1:9191")]
+    pub grpc_api_address: String,
+
+    /// Watch the keys starting with this prefix.
+    #[clap(long)]
+    pub prefix: String,
+
+    /// Print the current values of the watched keys before the changes.
+    #[clap(long)]
+    pub initial_flush: bool,
+
+    /// Resume from the changes after this seq.
+    #[clap(long)]
+    pub start_after_seq: Option<u64>,
+
+    /// Print the values as hex encoded bytes, instead of decoding them to json.
+    #[clap(long)]
+    pub raw: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Args)]
+pub struct UpsertArgs {
+    #[clap(long, default_value = "127.0.0.1:9191")]
+    pub grpc_api_address: String,
+
+    /// The key to update.
+    #[clap(long)]
+    pub key: String,
+
+    /// The value to set.
+    #[clap(long)]
+    pub value: String,
+
+    /// The value is json of the type of the key, and is encoded to protobuf before writing.
+    #[clap(long)]
+    pub json: bool,
+
+    /// Write the value as it is into a key whose value type is protobuf, without `--json`.
+    #[clap(long)]
+    pub force: bool,
+
+    /// Update only when the current seq of the key is this value, 0 means the key is absent.
+    #[clap(long)]
+    pub match_seq: Option<u64>,
+
+    /// Only print the current value and the new value, do not write.
+    #[clap(long)]
+    pub dry_run: bool,
+
+    /// The file to append a json record of every applied change to.
+    #[clap(long, default_value = ".databend/metactl_audit.log")]
+    pub audit_log: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Args)]
+pub struct DeleteArgs {
+    #[clap(long, default_value = "127.0.0.1:9191")]
+    pub grpc_api_address: String,
+
+    /// The key to delete.
+    #[clap(long)]
+    pub key: String,
+
+    /// Delete only when the current seq of the key is this value.
+    #[clap(long)]
+    pub match_seq: Option<u64>,
+
+    /// Only print the current value, do not delete.
+    #[clap(long)]
+    pub dry_run: bool,
+
+    /// The file to append a json record of every applied change to.
+    #[clap(long, default_value = ".databend/metactl_audit.log")]
+    pub audit_log: String,
+}
+
 #[derive(Debug, Clone, Deserialize, Args)]
 pub struct BenchArgs {
     #[clap(long, default_value = "127.0.0.1:9191")]
@@ -347,8 +484,14 @@ enum CtlCommand {
     Status(StatusArgs),
     Export(ExportArgs),
     Import(ImportArgs),
+    Restore(RestoreArgs),
     TransferLeader(TransferLeaderArgs),
     BenchClientNumConn(BenchArgs),
+    Get(GetArgs),
+    List(ListArgs),
+    Watch(WatchArgs),
+    Upsert(UpsertArgs),
+    Delete(DeleteArgs),
 }
 
 /// Usage:
@@ -395,6 +538,24 @@ async fn main() -> anyhow::Result<()> {
             CtlCommand::Import(args) => {
                 app.import(args).await?;
             }
+            CtlCommand::Restore(args) => {
+                restore::restore(args).await?;
+            }
+            CtlCommand::Get(args) => {
+                kv::get(args).await?;
+            }
+            CtlCommand::List(args) => {
+                kv::list(args).await?;
+            }
+            CtlCommand::Watch(args) => {
+                kv::watch(args).await?;
+            }
+            CtlCommand::Upsert(args) => {
+                kv::upsert(args).await?;
+            }
+            CtlCommand::Delete(args) => {
+                kv::delete(args).await?;
+            }
         },
         // for backward compatibility
         None => {
@@ -0,0 +1,171 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Rebuild the meta data of a node from an archive dir, upto a chosen log index or time.
+
+use std::sync::Arc;
+
+use anyhow::anyhow;
+use databend_common_meta_raft_store::archive::ArchiveReader;
+use databend_common_meta_raft_store::config::RaftConfig;
+use databend_common_meta_raft_store::key_spaces::RaftStoreEntry;
+use databend_common_meta_raft_store::ondisk::OnDisk;
+use databend_common_meta_raft_store::raft_log_v004;
+use databend_common_meta_raft_store::raft_log_v004::RaftLogV004;
+use databend_common_meta_raft_store::sm_v003::SnapshotStoreV004;
+use databend_common_meta_raft_store::state_machine::MetaSnapshotId;
+use databend_common_meta_types::raft_types::Vote;
+
+use crate::import;
+use crate::upgrade;
+use crate::ImportArgs;
+use crate::RestoreArgs;
+
+/// Parse `--until`: a log index, or a RFC 3339 time that is converted to the last log index proposed before it.
+fn resolve_until(reader: &ArchiveReader, until: &str) -> anyhow::Result<u64> {
+    if let Ok(index) = until.parse::<u64>() {
+        return Ok(index);
+    }
+
+    let t = chrono::DateTime::parse_from_rfc3339(until).map_err(|e| {
+        anyhow!(
+            "invalid --until: '{}', expect a log index or a RFC 3339 time: {}",
+            until,
+            e
+        )
+    })?;
+
+    let time_ms = t.timestamp_millis() as u64;
+
+    let index = reader
+        .index_at_time(time_ms)?
+        .ok_or_else(|| anyhow!("no archived raft log is proposed at or before {}", until))?;
+
+    Ok(index)
+}
+
+pub async fn restore(args: &RestoreArgs) -> anyhow::Result<()> {
+    let import_args = ImportArgs {
+        raft_dir: Some(args.raft_dir.clone()),
+        db: "".to_string(),
+        initial_cluster: args.initial_cluster.clone(),
+        id: args.id,
+    };
+    let raft_config: RaftConfig = import_args.clone().into();
+
+    let reader = ArchiveReader::new(&args.archive_dir);
+    let until = resolve_until(&reader, &args.until)?;
+
+    eprintln!();
+    eprintln!("Restore:");
+    eprintln!("    From Archive Dir: '{}'", args.archive_dir);
+    eprintln!("    Into Meta Dir: '{}'", args.raft_dir);
+    eprintln!("    Until Log Index: {}", until);
+
+    let nodes = import::build_nodes(args.initial_cluster.clone(), args.id)?;
+
+    let chain = reader.snapshot_chain(until)?;
+    let snapshot_index = chain.last().map(|s| s.last_index);
+
+    for s in chain.iter() {
+        eprintln!("    Snapshot: {}", s.path.display());
+    }
+
+    let logs = reader.read_logs(snapshot_index, until)?;
+
+    // Logs must be contiguous, from the one after the snapshot upto `until`.
+    let mut next_index = snapshot_index.map(|i| i + 1).unwrap_or_default();
+    for entry in logs.iter() {
+        if entry.log_id.index != next_index {
+            return Err(anyhow!(
+                "archived raft logs are missing: expect index {}, but found {}",
+                next_index,
+                entry.log_id.index
+            ));
+        }
+        next_index += 1;
+    }
+
+    if next_index <= until {
+        return Err(anyhow!(
+            "archived raft logs end before {}, the last restorable index is {:?}",
+            until,
+            next_index.checked_sub(1)
+        ));
+    }
+
+    eprintln!("    Logs: {} entries after snapshot", logs.len());
+
+    import::clear(&import_args)?;
+    OnDisk::ensure_dirs(&raft_config.raft_dir)?;
+
+    let mut snapshot_last_applied = None;
+
+    if !chain.is_empty() {
+        let (sys_data, strm) = ArchiveReader::merge_snapshots(&chain, &raft_config)?;
+        snapshot_last_applied = *sys_data.last_applied_ref();
+
+        let snapshot_store = SnapshotStoreV004::new(raft_config.clone());
+        let writer = snapshot_store.new_writer()?;
+        let temp_snapshot_data = writer.write_kv_stream(strm, sys_data).await?;
+
+        let snapshot_id = MetaSnapshotId::new_with_epoch(snapshot_last_applied);
+        let db = temp_snapshot_data.move_to_final_path(snapshot_id.to_string())?;
+
+        eprintln!(
+            "Restored snapshot: {}; snapshot_path: {}; snapshot_stat: {}",
+            snapshot_id,
+            db.path(),
+            db.stat()
+        );
+    }
+
+    let mut raft_log_importer = {
+        let raft_log_config = Arc::new(raft_config.to_raft_log_config());
+        let raft_log = RaftLogV004::open(raft_log_config)?;
+
+        raft_log_v004::Importer::new(raft_log)
+    };
+
+    raft_log_importer.import_raft_store_entry(RaftStoreEntry::NodeId(Some(args.id)))?;
+
+    // Logs included in the snapshot are not restored.
+    raft_log_importer.import_raft_store_entry(RaftStoreEntry::Purged(snapshot_last_applied))?;
+
+    for entry in logs {
+        raft_log_importer.import_raft_store_entry(RaftStoreEntry::LogEntry(entry))?;
+    }
+
+    let last_log_id = std::cmp::max(raft_log_importer.max_log_id, snapshot_last_applied);
+
+    // Every restored log is committed and will be applied upon startup.
+    if let Some(last) = last_log_id {
+        let vote = Vote::new_committed(last.leader_id.term, last.leader_id.node_id);
+        raft_log_importer.import_raft_store_entry(RaftStoreEntry::Vote(Some(vote)))?;
+        raft_log_importer.import_raft_store_entry(RaftStoreEntry::Committed(Some(last)))?;
+    }
+
+    raft_log_importer.flush().await?;
+
+    upgrade::upgrade(&raft_config).await?;
+
+    eprintln!("Restored upto: {:?}", last_log_id);
+
+    if args.initial_cluster.is_empty() {
+        return Ok(());
+    }
+
+    import::init_new_cluster(&import_args, nodes, last_log_id).await?;
+    Ok(())
+}
@@ -0,0 +1,161 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Convert the protobuf encoded values in meta-service to and from readable json.
+
+use std::sync::LazyLock;
+
+use anyhow::anyhow;
+use databend_common_meta_app::data_mask::DataMaskIdIdent;
+use databend_common_meta_app::principal::client_session_ident::ClientSessionIdent;
+use databend_common_meta_app::principal::connection_ident::ConnectionIdent;
+use databend_common_meta_app::principal::user_defined_file_format_ident::UserDefinedFileFormatIdent;
+use databend_common_meta_app::principal::NetworkPolicyIdent;
+use databend_common_meta_app::principal::PasswordPolicyIdent;
+use databend_common_meta_app::principal::RoleIdent;
+use databend_common_meta_app::principal::StageIdent;
+use databend_common_meta_app::principal::TenantOwnershipObjectIdent;
+use databend_common_meta_app::principal::TenantUserIdent;
+use databend_common_meta_app::schema::dictionary_id_ident::DictionaryIdIdent;
+use databend_common_meta_app::schema::index_id_ident::IndexIdIdent;
+use databend_common_meta_app::schema::CatalogIdIdent;
+use databend_common_meta_app::schema::DatabaseId;
+use databend_common_meta_app::schema::DatabaseIdHistoryIdent;
+use databend_common_meta_app::schema::TableId;
+use databend_common_meta_app::schema::TableIdHistoryIdent;
+use databend_common_meta_app::schema::TableLockIdent;
+use databend_common_meta_app::tenant::TenantQuotaIdent;
+use databend_common_meta_kvapi::kvapi;
+use databend_common_proto_conv::FromToProto;
+use prost::Message;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// The rust type of the values stored under a key prefix.
+struct ValueType {
+    prefix: &'static str,
+    name: &'static str,
+    decode: fn(&[u8]) -> anyhow::Result<serde_json::Value>,
+    encode: fn(&str) -> anyhow::Result<Vec<u8>>,
+}
+
+impl ValueType {
+    fn of<K>() -> Self
+    where
+        K: kvapi::Key,
+        K::ValueType: FromToProto + Serialize + DeserializeOwned,
+    {
+        let name = std::any::type_name::<K::ValueType>();
+
+        ValueType {
+            prefix: K::PREFIX,
+            name: name.rsplit("::").next().unwrap_or(name),
+            decode: decode_pb::<K::ValueType>,
+            encode: encode_pb::<K::ValueType>,
+        }
+    }
+}
+
+static VALUE_TYPES: LazyLock<Vec<ValueType>> = LazyLock::new(|| {
+    vec![
+        ValueType::of::<DatabaseId>(),
+        ValueType::of::<DatabaseIdHistoryIdent>(),
+        ValueType::of::<TableId>(),
+        ValueType::of::<TableIdHistoryIdent>(),
+        ValueType::of::<TableLockIdent>(),
+        ValueType::of::<CatalogIdIdent>(),
+        ValueType::of::<IndexIdIdent>(),
+        ValueType::of::<DictionaryIdIdent>(),
+        ValueType::of::<DataMaskIdIdent>(),
+        ValueType::of::<TenantUserIdent>(),
+        ValueType::of::<RoleIdent>(),
+        ValueType::of::<StageIdent>(),
+        ValueType::of::<NetworkPolicyIdent>(),
+        ValueType::of::<PasswordPolicyIdent>(),
+        ValueType::of::<UserDefinedFileFormatIdent>(),
+        ValueType::of::<TenantOwnershipObjectIdent>(),
+        ValueType::of::<ConnectionIdent>(),
+        ValueType::of::<ClientSessionIdent>(),
+        ValueType::of::<TenantQuotaIdent>(),
+    ]
+});
+
+fn decode_pb<T>(buf: &[u8]) -> anyhow::Result<serde_json::Value>
+where T: FromToProto + Serialize {
+    let p = T::PB::decode(buf)?;
+    let v = T::from_pb(p)?;
+    Ok(serde_json::to_value(v)?)
+}
+
+fn encode_pb<T>(json: &str) -> anyhow::Result<Vec<u8>>
+where T: FromToProto + DeserializeOwned {
+    let v: T = serde_json::from_str(json)?;
+    let p = v.to_pb()?;
+    Ok(p.encode_to_vec())
+}
+
+/// Find the value type by the longest prefix that matches the key.
+fn value_type(key: &str) -> Option<&'static ValueType> {
+    VALUE_TYPES
+        .iter()
+        .filter(|t| {
+            key.strip_prefix(t.prefix)
+                .map(|rest| rest.starts_with('/'))
+                .unwrap_or(false)
+        })
+        .max_by_key(|t| t.prefix.len())
+}
+
+/// Returns the name of the rust type of the value of `key`, if it is known.
+///
+/// The value of a key of a known type is protobuf encoded.
+pub fn value_type_name(key: &str) -> Option<&'static str> {
+    value_type(key).map(|t| t.name)
+}
+
+/// Convert the value of `key` to json.
+///
+/// A protobuf value of a known type is decoded with `proto-conv`.
+/// Otherwise the value is returned as json if it is json,
+/// or as a string if it is utf8, or as a hex string.
+pub fn decode_value(key: &str, value: &[u8]) -> serde_json::Value {
+    if let Some(t) = value_type(key) {
+        match (t.decode)(value) {
+            Ok(v) => return v,
+            Err(e) => {
+                eprintln!("WARN: fail to decode {} as {}: {}", key, t.name, e);
+            }
+        }
+    }
+
+    if let Ok(v) = serde_json::from_slice(value) {
+        return v;
+    }
+
+    match std::str::from_utf8(value) {
+        Ok(s) => serde_json::Value::String(s.to_string()),
+        Err(_) => serde_json::Value::String(format!("0x{}", hex::encode(value))),
+    }
+}
+
+/// Render the value as it is, as a hex string, which keeps any non-utf8 bytes.
+pub fn raw_value(value: &[u8]) -> serde_json::Value {
+    serde_json::Value::String(format!("0x{}", hex::encode(value)))
+}
+
+/// Convert the json value of `key` to protobuf encoded bytes.
+pub fn encode_value(key: &str, json: &str) -> anyhow::Result<Vec<u8>> {
+    let t = value_type(key).ok_or_else(|| anyhow!("unknown value type for key: {}", key))?;
+    (t.encode)(json).map_err(|e| anyhow!("fail to encode {} as {}: {}", key, t.name, e))
+}
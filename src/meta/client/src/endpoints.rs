@@ -29,6 +29,11 @@ pub struct Endpoints {
 
     /// Nodes of the meta-service cluster.
     nodes: BTreeMap<String, Status>,
+
+    /// The position in `nodes` of the node the last read was sent to.
+    ///
+    /// A read that does not have to be served by the leader is sent to the nodes in turn.
+    read_cursor: usize,
 }
 
 #[allow(clippy::len_without_is_empty)]
@@ -41,6 +46,7 @@ impl Endpoints {
                 .into_iter()
                 .map(|x| (x.to_string(), Status::default()))
                 .collect(),
+            read_cursor: 0,
         }
     }
 
@@ -75,6 +81,14 @@ impl Endpoints {
         self.current.as_deref().unwrap()
     }
 
+    /// Choose the node to send a read to, which may be a follower or a learner.
+    ///
+    /// Reads are spread over all of the nodes in turn, to take the load off the leader.
+    pub fn choose_reader(&mut self) -> &str {
+        self.read_cursor = (self.read_cursor + 1) % self.nodes.len();
+        self.nodes.keys().nth(self.read_cursor).unwrap()
+    }
+
     pub fn current(&self) -> Option<&str> {
         self.current.as_deref()
     }
@@ -179,6 +193,24 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_endpoints_choose_reader() -> anyhow::Result<()> {
+        let mut es = Endpoints::new(["a", "b", "c"]);
+        assert_eq!("a", es.choose_next());
+
+        assert_eq!("b", es.choose_reader());
+        assert_eq!("c", es.choose_reader());
+        assert_eq!("a", es.choose_reader());
+
+        // Choosing a reader does not change the leader.
+        assert_eq!(Some("a"), es.current());
+
+        // Reduce nodes list size is ok.
+        es.replace_nodes(["d"]);
+        assert_eq!("d", es.choose_reader());
+        Ok(())
+    }
+
     #[test]
     fn test_endpoints_current() -> anyhow::Result<()> {
         let mut es = Endpoints::new(["a", "b"]);
@@ -101,8 +101,10 @@ use crate::to_digit_ver;
 use crate::ClientWorkerRequest;
 use crate::MetaGrpcReadReq;
 use crate::MetaGrpcReq;
+use crate::ReadConsistency;
 use crate::METACLI_COMMIT_SEMVER;
 use crate::MIN_METASRV_SEMVER;
+use crate::READ_CONSISTENCY_KEY;
 
 const RPC_RETRIES: usize = 4;
 const AUTH_TOKEN_KEY: &str = "auth-token-bin";
@@ -434,6 +436,9 @@ pub struct MetaGrpcClient {
     endpoints: Arc<Mutex<Endpoints>>,
     endpoints_str: Vec<String>,
     auto_sync_interval: Option<Duration>,
+
+    /// The consistency of reads, which decides whether a read is sent to the leader.
+    read_consistency: ReadConsistency,
 }
 
 impl Debug for MetaGrpcClient {
@@ -441,6 +446,7 @@ impl Debug for MetaGrpcClient {
         let mut de = f.debug_struct("MetaGrpcClient");
         de.field("endpoints", &*self.endpoints.lock());
         de.field("auto_sync_interval", &self.auto_sync_interval);
+        de.field("read_consistency", &self.read_consistency);
         de.finish()
     }
 }
@@ -463,13 +469,14 @@ impl MetaGrpcClient {
     /// The worker is a singleton and the returned handle is cheap to clone.
     /// When all handles are dropped the worker will quit, then the runtime will be destroyed.
     pub fn try_new(conf: &RpcClientConf) -> Result<Arc<ClientHandle>, MetaClientError> {
-        Self::try_create(
+        Self::try_create_with_read_consistency(
             conf.get_endpoints(),
             &conf.username,
             &conf.password,
             conf.timeout,
             conf.auto_sync_interval,
             conf.tls_conf.clone(),
+            ReadConsistency::from_max_staleness(conf.max_read_staleness),
         )
     }
 
@@ -481,6 +488,31 @@ impl MetaGrpcClient {
         timeout: Option<Duration>,
         auto_sync_interval: Option<Duration>,
         tls_config: Option<RpcClientTlsConfig>,
+    ) -> Result<Arc<ClientHandle>, MetaClientError> {
+        Self::try_create_with_read_consistency(
+            endpoints_str,
+            username,
+            password,
+            timeout,
+            auto_sync_interval,
+            tls_config,
+            ReadConsistency::Linearizable,
+        )
+    }
+
+    /// Create a new client of metasrv whose reads are served with the given consistency.
+    ///
+    /// With [`ReadConsistency::BoundedStaleness`], reads are spread over all of the nodes,
+    /// including followers and learners, instead of all being sent to the leader.
+    #[fastrace::trace]
+    pub fn try_create_with_read_consistency(
+        endpoints_str: Vec<String>,
+        username: &str,
+        password: &str,
+        timeout: Option<Duration>,
+        auto_sync_interval: Option<Duration>,
+        tls_config: Option<RpcClientTlsConfig>,
+        read_consistency: ReadConsistency,
     ) -> Result<Arc<ClientHandle>, MetaClientError> {
         Self::endpoints_non_empty(&endpoints_str)?;
 
@@ -517,6 +549,7 @@ impl MetaGrpcClient {
             endpoints,
             endpoints_str,
             auto_sync_interval,
+            read_consistency,
         });
 
         let worker_name = worker.to_string();
@@ -768,6 +801,44 @@ impl MetaGrpcClient {
         ))
     }
 
+    /// Return a client to the node chosen to serve a read that does not have to go to the leader.
+    #[fastrace::trace]
+    #[async_backtrace::framed]
+    async fn get_read_client(&self) -> Result<EstablishedClient, MetaClientError> {
+        let n = self.endpoints.lock().len();
+
+        let mut last_err = None::<MetaClientError>;
+
+        for _ith in 0..n {
+            let addr = {
+                let mut es = self.endpoints.lock();
+                es.choose_reader().to_string()
+            };
+
+            debug!("{} get or build read client to {}", self, addr);
+
+            match self.conn_pool.get(&addr).await {
+                Ok(client) => {
+                    return Ok(client);
+                }
+                Err(client_err) => {
+                    error!(
+                        "Failed to get or build read client to {}, err: {:?}",
+                        addr, client_err
+                    );
+                    grpc_metrics::incr_meta_grpc_make_client_fail(&addr);
+                    last_err = Some(client_err);
+                }
+            }
+        }
+
+        if let Some(e) = last_err {
+            return Err(e);
+        }
+
+        self.get_established_client().await
+    }
+
     pub fn endpoints_non_empty(endpoints: &[String]) -> Result<(), MetaClientError> {
         if endpoints.is_empty() {
             return Err(MetaClientError::ConfigError(AnyError::error(
@@ -1117,17 +1188,34 @@ impl MetaGrpcClient {
 
         let mut failures = vec![];
 
+        let linearizable = self.read_consistency.is_linearizable();
+
         for i in 0..RPC_RETRIES {
-            let mut established_client = self
-                .get_established_client()
-                .with_timing_threshold(
-                    threshold(),
-                    info_spent("MetaGrpcClient::get_established_client"),
-                )
-                .await?;
+            let mut established_client = if linearizable {
+                self.get_established_client()
+                    .with_timing_threshold(
+                        threshold(),
+                        info_spent("MetaGrpcClient::get_established_client"),
+                    )
+                    .await?
+            } else {
+                self.get_read_client()
+                    .with_timing_threshold(
+                        threshold(),
+                        info_spent("MetaGrpcClient::get_read_client"),
+                    )
+                    .await?
+            };
 
             let raft_req: RaftRequest = grpc_req.clone().into();
-            let req = traced_req(raft_req.clone());
+            let mut req = traced_req(raft_req.clone());
+
+            if !linearizable {
+                let consistency = self.read_consistency.to_string();
+                if let Ok(value) = tonic::metadata::AsciiMetadataValue::from_str(&consistency) {
+                    req.metadata_mut().insert(READ_CONSISTENCY_KEY, value);
+                }
+            }
 
             let result = established_client
                 .kv_read_v1(req)
@@ -1150,7 +1238,10 @@ impl MetaGrpcClient {
                 );
 
                 if is_status_retryable(e) {
-                    self.choose_next_endpoint();
+                    // A read that is not linearizable goes to the next reader in the next try.
+                    if linearizable {
+                        self.choose_next_endpoint();
+                    }
                     failures.push(e.clone());
                     continue;
                 }
@@ -23,6 +23,7 @@ mod grpc_client;
 mod grpc_metrics;
 mod kv_api_impl;
 mod message;
+mod read_consistency;
 
 use std::sync::LazyLock;
 
@@ -35,6 +36,8 @@ pub use grpc_client::MetaChannelManager;
 pub use grpc_client::MetaGrpcClient;
 pub use message::ClientWorkerRequest;
 pub use message::Streamed;
+pub use read_consistency::ReadConsistency;
+pub use read_consistency::READ_CONSISTENCY_KEY;
 use semver::Version;
 
 pub static METACLI_COMMIT_SEMVER: LazyLock<Version> = LazyLock::new(|| {
@@ -121,6 +124,16 @@ pub static METACLI_COMMIT_SEMVER: LazyLock<Version> = LazyLock::new(|| {
 ///   🖥 server: add `TxnRequest::condition_tree`,
 ///              to specify a complex bool expression.
 ///
+/// - 2025-*: since 1.2.*
+///   🖥 server: kv_read_v1() accepts `read-consistency` in the request metadata,
+///              to serve bounded-staleness reads on followers and learners.
+///   👥 client: send bounded-staleness reads to any node;
+///              an older server ignores the metadata and serves them through the leader.
+///
+/// - 2025-01-27: since 1.2.681
+///   🖥 server: add `WatchRequest::start_after_seq`,
+///              to resume a watch stream from the in-memory change log.
+///
 ///
 /// Server feature set:
 /// ```yaml
@@ -0,0 +1,107 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// The gRPC metadata key in which a read request carries its [`ReadConsistency`].
+///
+/// A server that does not know about it serves the read through the leader.
+pub const READ_CONSISTENCY_KEY: &str = "read-consistency";
+
+/// How up-to-date the result of a read must be.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ReadConsistency {
+    /// The read is served by the leader and sees every write committed before it is sent.
+    #[default]
+    Linearizable,
+
+    /// The read can be served by any node, including a follower or a learner.
+    ///
+    /// The serving node waits until it has applied the read index it got from the leader,
+    /// and reuses that read index for at most `max_staleness`.
+    /// Thus the read misses only the writes committed within `max_staleness` before it.
+    BoundedStaleness { max_staleness: Duration },
+}
+
+impl ReadConsistency {
+    /// Build from a configured staleness bound, `None` means reading from the leader.
+    pub fn from_max_staleness(max_staleness: Option<Duration>) -> Self {
+        match max_staleness {
+            None => ReadConsistency::Linearizable,
+            Some(max_staleness) => ReadConsistency::BoundedStaleness { max_staleness },
+        }
+    }
+
+    /// Whether the read has to be sent to the leader.
+    pub fn is_linearizable(&self) -> bool {
+        matches!(self, ReadConsistency::Linearizable)
+    }
+}
+
+impl fmt::Display for ReadConsistency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadConsistency::Linearizable => write!(f, "linearizable"),
+            ReadConsistency::BoundedStaleness { max_staleness } => {
+                write!(f, "bounded-staleness:{}", max_staleness.as_millis())
+            }
+        }
+    }
+}
+
+impl FromStr for ReadConsistency {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "linearizable" {
+            return Ok(ReadConsistency::Linearizable);
+        }
+
+        let millis = s
+            .strip_prefix("bounded-staleness:")
+            .and_then(|ms| ms.parse::<u64>().ok())
+            .ok_or_else(|| format!("invalid read consistency: {}", s))?;
+
+        Ok(ReadConsistency::BoundedStaleness {
+            max_staleness: Duration::from_millis(millis),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::read_consistency::ReadConsistency;
+
+    #[test]
+    fn test_read_consistency_display_parse() -> anyhow::Result<()> {
+        let linearizable = ReadConsistency::Linearizable;
+        assert_eq!("linearizable", linearizable.to_string());
+        assert_eq!(linearizable, "linearizable".parse().unwrap());
+
+        let stale = ReadConsistency::BoundedStaleness {
+            max_staleness: Duration::from_millis(500),
+        };
+        assert_eq!("bounded-staleness:500", stale.to_string());
+        assert_eq!(stale, "bounded-staleness:500".parse().unwrap());
+
+        assert!("bounded-staleness:".parse::<ReadConsistency>().is_err());
+        assert!("foo".parse::<ReadConsistency>().is_err());
+
+        Ok(())
+    }
+}
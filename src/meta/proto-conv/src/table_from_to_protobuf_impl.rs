@@ -192,6 +192,11 @@ impl FromToProto for mt::TableMeta {
             indexes.insert(name, mt::TableIndex::from_pb(index)?);
         }
 
+        let mut constraints = BTreeMap::new();
+        for (name, constraint) in p.constraints {
+            constraints.insert(name, mt::TableConstraint::from_pb(constraint)?);
+        }
+
         let cluster_key_seq = if let Some(seq) = p.cluster_key_seq {
             seq
         } else if p.cluster_keys.is_empty() {
@@ -232,6 +237,7 @@ impl FromToProto for mt::TableMeta {
                 Some(p.column_mask_policy)
             },
             indexes,
+            constraints,
         };
         Ok(v)
     }
@@ -241,6 +247,10 @@ impl FromToProto for mt::TableMeta {
         for (name, index) in &self.indexes {
             indexes.insert(name.clone(), index.to_pb()?);
         }
+        let mut constraints = BTreeMap::new();
+        for (name, constraint) in &self.constraints {
+            constraints.insert(name.clone(), constraint.to_pb()?);
+        }
         let p = pb::TableMeta {
             ver: VER,
             min_reader_ver: MIN_READER_VER,
@@ -273,11 +283,81 @@ impl FromToProto for mt::TableMeta {
             shared_by: Vec::from_iter(self.shared_by.clone()),
             column_mask_policy: self.column_mask_policy.clone().unwrap_or_default(),
             indexes,
+            constraints,
         };
         Ok(p)
     }
 }
 
+impl FromToProto for mt::TableConstraint {
+    type PB = pb::TableConstraint;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::TableConstraint) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        match p.constraint {
+            Some(pb::table_constraint::Constraint::Check(expr)) => {
+                Ok(mt::TableConstraint::Check(expr))
+            }
+            Some(pb::table_constraint::Constraint::PrimaryKey(key)) => {
+                Ok(mt::TableConstraint::PrimaryKey(key.columns))
+            }
+            Some(pb::table_constraint::Constraint::Unique(key)) => {
+                Ok(mt::TableConstraint::Unique(key.columns))
+            }
+            Some(pb::table_constraint::Constraint::ForeignKey(fk)) => {
+                Ok(mt::TableConstraint::ForeignKey {
+                    columns: fk.columns,
+                    ref_database: fk.ref_database,
+                    ref_table: fk.ref_table,
+                    ref_columns: fk.ref_columns,
+                })
+            }
+            None => Err(Incompatible::new(
+                "TableConstraint.constraint cannot be None".to_string(),
+            )),
+        }
+    }
+
+    fn to_pb(&self) -> Result<pb::TableConstraint, Incompatible> {
+        let constraint = match self {
+            mt::TableConstraint::Check(expr) => {
+                pb::table_constraint::Constraint::Check(expr.clone())
+            }
+            mt::TableConstraint::PrimaryKey(columns) => {
+                pb::table_constraint::Constraint::PrimaryKey(pb::table_constraint::KeyConstraint {
+                    columns: columns.clone(),
+                })
+            }
+            mt::TableConstraint::Unique(columns) => {
+                pb::table_constraint::Constraint::Unique(pb::table_constraint::KeyConstraint {
+                    columns: columns.clone(),
+                })
+            }
+            mt::TableConstraint::ForeignKey {
+                columns,
+                ref_database,
+                ref_table,
+                ref_columns,
+            } => pb::table_constraint::Constraint::ForeignKey(
+                pb::table_constraint::ForeignKeyConstraint {
+                    columns: columns.clone(),
+                    ref_database: ref_database.clone(),
+                    ref_table: ref_table.clone(),
+                    ref_columns: ref_columns.clone(),
+                },
+            ),
+        };
+        Ok(pb::TableConstraint {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            constraint: Some(constraint),
+        })
+    }
+}
+
 impl FromToProto for mt::TableStatistics {
     type PB = pb::TableStatistics;
     fn get_pb_ver(p: &Self::PB) -> u64 {
@@ -117,3 +117,5 @@ mod v116_marked_deleted_index_meta;
 mod v117_webhdfs_add_disable_list_batch;
 mod v118_webhdfs_add_user_name;
 mod v119_virtual_column;
+mod v120_table_constraint;
+mod v121_table_key_constraint;
@@ -150,6 +150,7 @@ fn new_table_meta() -> mt::TableMeta {
         statistics: Default::default(),
         shared_by: btreeset! {1},
         column_mask_policy: Some(btreemap! {s("a") => s("b")}),
+        constraints: btreemap! {},
         indexes: btreemap! {},
     }
 }
@@ -143,6 +143,7 @@ fn test_decode_v10_table_meta() -> anyhow::Result<()> {
         statistics: Default::default(),
         shared_by: BTreeSet::new(),
         column_mask_policy: None,
+        constraints: btreemap! {},
         indexes: btreemap! {},
     };
 
@@ -147,6 +147,7 @@ fn test_decode_v40_table_meta() -> anyhow::Result<()> {
         statistics: Default::default(),
         shared_by: btreeset! {1},
         column_mask_policy: Some(btreemap! {s("a") => s("b")}),
+        constraints: btreemap! {},
         indexes: btreemap! {},
     };
 
@@ -89,6 +89,7 @@ fn test_decode_v55_table_meta() -> anyhow::Result<()> {
         statistics: Default::default(),
         shared_by: btreeset! {1},
         column_mask_policy: Some(btreemap! {s("a") => s("b")}),
+        constraints: btreemap! {},
         indexes: btreemap! {},
     };
     common::test_pb_from_to(func_name!(), want())?;
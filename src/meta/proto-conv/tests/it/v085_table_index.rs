@@ -89,6 +89,7 @@ fn test_decode_v85_table_meta() -> anyhow::Result<()> {
         statistics: Default::default(),
         shared_by: btreeset! {1},
         column_mask_policy: Some(btreemap! {s("a") => s("b")}),
+        constraints: btreemap! {},
         indexes: btreemap! {s("idx1") => mt::TableIndex {
             name: "idx1".to_string(),
             column_ids: vec![1, 2],
@@ -0,0 +1,59 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_app::schema::TableConstraint;
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `proto_conv::test_build_pb_buf()`
+#[test]
+fn test_decode_v121_table_primary_key() -> anyhow::Result<()> {
+    let table_constraint_v121 = vec![18, 6, 10, 1, 97, 10, 1, 98, 160, 6, 121, 168, 6, 24];
+
+    let want = || TableConstraint::PrimaryKey(vec!["a".to_string(), "b".to_string()]);
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), table_constraint_v121.as_slice(), 121, want())?;
+
+    Ok(())
+}
+
+#[test]
+fn test_decode_v121_table_foreign_key() -> anyhow::Result<()> {
+    let table_constraint_v121 = vec![
+        34, 13, 10, 1, 97, 18, 2, 100, 98, 26, 1, 116, 34, 1, 98, 160, 6, 121, 168, 6, 24,
+    ];
+
+    let want = || TableConstraint::ForeignKey {
+        columns: vec!["a".to_string()],
+        ref_database: "db".to_string(),
+        ref_table: "t".to_string(),
+        ref_columns: vec!["b".to_string()],
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), table_constraint_v121.as_slice(), 121, want())?;
+
+    Ok(())
+}
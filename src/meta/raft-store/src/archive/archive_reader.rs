@@ -0,0 +1,228 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::path::Path;
+use std::path::PathBuf;
+
+use databend_common_meta_types::raft_types::Entry;
+use databend_common_meta_types::raft_types::EntryPayload;
+use databend_common_meta_types::snapshot_db::DB;
+use databend_common_meta_types::sys_data::SysData;
+use futures_util::future;
+use futures_util::StreamExt;
+use futures_util::TryStreamExt;
+use log::warn;
+use rotbl::v001::SeqMarked;
+use stream_more::KMerge;
+use stream_more::StreamMore;
+
+use crate::archive::ArchivedSnapshot;
+use crate::archive::Archiver;
+use crate::config::RaftConfig;
+use crate::leveled_store::map_api::IOResultStream;
+use crate::leveled_store::util;
+use crate::sm_v003::open_snapshot::OpenSnapshot;
+
+/// A raft log segment file in an archive dir.
+#[derive(Debug, Clone)]
+struct ArchivedLogSegment {
+    first_index: u64,
+
+    /// `None` if the segment is not closed.
+    last_index: Option<u64>,
+
+    path: PathBuf,
+}
+
+impl ArchivedLogSegment {
+    fn parse(path: &Path) -> Option<Self> {
+        let file_name = path.file_name()?.to_str()?;
+
+        let (first_index, last_index) = if let Some(name) = file_name.strip_suffix(".part") {
+            // `<first>.<node_id>.part`, or `<first>.part` written by older versions.
+            let first = name.split_once('.').map_or(name, |(first, _node_id)| first);
+            (first.parse().ok()?, None)
+        } else if let Some(name) = file_name.strip_suffix(".log") {
+            let (first, last) = name.split_once('-')?;
+            (first.parse().ok()?, Some(last.parse().ok()?))
+        } else {
+            return None;
+        };
+
+        Some(Self {
+            first_index,
+            last_index,
+            path: path.to_path_buf(),
+        })
+    }
+}
+
+/// Read an archive dir written by [`Archiver`], to rebuild the state at a log index.
+pub struct ArchiveReader {
+    dir: PathBuf,
+}
+
+impl ArchiveReader {
+    pub fn new(archive_dir: impl AsRef<Path>) -> Self {
+        Self {
+            dir: archive_dir.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Returns the snapshots to rebuild the state at log index `until`:
+    /// the newest full snapshot at or before `until`, followed by the chain of incremental snapshots based on it.
+    ///
+    /// An empty Vec is returned if there is no such full snapshot.
+    pub fn snapshot_chain(&self, until: u64) -> Result<Vec<ArchivedSnapshot>, io::Error> {
+        let snapshots = ArchivedSnapshot::list(Archiver::snapshot_dir(&self.dir))?;
+        let snapshots = snapshots
+            .into_iter()
+            .filter(|s| s.last_index <= until)
+            .collect::<Vec<_>>();
+
+        let Some(full) = snapshots.iter().rposition(|s| s.is_full()) else {
+            return Ok(vec![]);
+        };
+
+        let mut chain = vec![snapshots[full].clone()];
+
+        for s in &snapshots[full + 1..] {
+            let last_index = chain.last().unwrap().last_index;
+            if s.base_index == Some(last_index) {
+                chain.push(s.clone());
+            }
+        }
+
+        Ok(chain)
+    }
+
+    /// Merge a chain of snapshots into a stream of the complete state, with tombstones removed.
+    ///
+    /// It returns the sys data of the last snapshot in the chain,
+    /// and a stream of encoded `String` key and rotbl value [`SeqMarked`].
+    pub fn merge_snapshots(
+        chain: &[ArchivedSnapshot],
+        raft_config: &RaftConfig,
+    ) -> Result<(SysData, IOResultStream<(String, SeqMarked)>), io::Error> {
+        let mut sys_data = None;
+        let mut kmerge = KMerge::by(util::rotbl_by_key_seq);
+
+        for s in chain {
+            let path = s.path.to_string_lossy().to_string();
+            let db = DB::open_snapshot(path, s.last_index.to_string(), raft_config)?;
+
+            kmerge = kmerge.merge(db.inner_range());
+            sys_data = Some(db.sys_data().clone());
+        }
+
+        let sys_data = sys_data.ok_or_else(|| {
+            io::Error::new(io::ErrorKind::NotFound, "no archived snapshot to merge")
+        })?;
+
+        let coalesce = kmerge.coalesce(util::rotbl_choose_greater);
+
+        // Filter out tombstone
+        let normal_strm = coalesce.try_filter(|(_k, v)| future::ready(v.is_normal()));
+
+        Ok((sys_data, normal_strm.boxed()))
+    }
+
+    /// Read archived raft logs with index in range `(after, until]`, sorted by index.
+    ///
+    /// A log archived more than once is returned only once.
+    pub fn read_logs(&self, after: Option<u64>, until: u64) -> Result<Vec<Entry>, io::Error> {
+        let log_dir = Archiver::log_dir(&self.dir);
+        if !log_dir.exists() {
+            return Ok(vec![]);
+        }
+
+        let mut segments = vec![];
+        for ent in fs::read_dir(&log_dir)? {
+            let path = ent?.path();
+            if let Some(seg) = ArchivedLogSegment::parse(&path) {
+                segments.push(seg);
+            }
+        }
+        segments.sort_by_key(|s| s.first_index);
+
+        let in_range = |index: u64| after.is_none_or(|a| index > a) && index <= until;
+
+        let mut logs = BTreeMap::new();
+
+        for seg in segments {
+            if seg.first_index > until {
+                continue;
+            }
+            if let (Some(last), Some(after)) = (seg.last_index, after) {
+                if last <= after {
+                    continue;
+                }
+            }
+
+            let lines = BufReader::new(fs::File::open(&seg.path)?)
+                .lines()
+                .collect::<Result<Vec<_>, _>>()?;
+            let n = lines.len();
+
+            for (i, line) in lines.into_iter().enumerate() {
+                let entry: Entry = match serde_json::from_str(&line) {
+                    Ok(entry) => entry,
+                    // The last line of an unclosed segment may be partially written.
+                    Err(e) if seg.last_index.is_none() && i + 1 == n => {
+                        warn!("skip torn raft log in {}: {}", seg.path.display(), e);
+                        continue;
+                    }
+                    Err(e) => {
+                        return Err(io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!("{}; when:(parse raft log in {})", e, seg.path.display()),
+                        ));
+                    }
+                };
+
+                if in_range(entry.log_id.index) {
+                    logs.insert(entry.log_id.index, entry);
+                }
+            }
+        }
+
+        Ok(logs.into_values().collect())
+    }
+
+    /// Returns the index of the last archived raft log proposed at or before `time_ms`.
+    ///
+    /// The proposing time is the `time_ms` in a normal log entry.
+    /// Logs without time, such as membership logs, are included if no log after `time_ms` precedes it.
+    pub fn index_at_time(&self, time_ms: u64) -> Result<Option<u64>, io::Error> {
+        let logs = self.read_logs(None, u64::MAX)?;
+
+        let mut index = None;
+
+        for entry in logs {
+            if let EntryPayload::Normal(log_entry) = &entry.payload {
+                if log_entry.time_ms.is_some_and(|t| t > time_ms) {
+                    break;
+                }
+            }
+            index = Some(entry.log_id.index);
+        }
+
+        Ok(index)
+    }
+}
@@ -0,0 +1,177 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs;
+use std::time::Duration;
+
+use databend_common_meta_types::raft_types::Entry;
+use databend_common_meta_types::raft_types::EntryPayload;
+use databend_common_meta_types::Cmd;
+use databend_common_meta_types::LogEntry;
+use databend_common_meta_types::UpsertKV;
+use openraft::testing::log_id;
+use pretty_assertions::assert_eq;
+
+use crate::archive::ArchiveReader;
+use crate::archive::ArchivedSnapshot;
+use crate::archive::Archiver;
+use crate::archive::LogArchiveStatus;
+use crate::archive::LogArchiver;
+use crate::config::RaftConfig;
+
+fn ent(index: u64, time_ms: Option<u64>) -> Entry {
+    Entry {
+        log_id: log_id(1, 1, index),
+        payload: EntryPayload::Normal(LogEntry {
+            txid: None,
+            time_ms,
+            cmd: Cmd::UpsertKV(UpsertKV::insert(format!("k{}", index), b"v")),
+        }),
+    }
+}
+
+#[test]
+fn test_archive_logs() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let dir = temp_dir.path();
+
+    {
+        let mut a = LogArchiver::open(Archiver::log_dir(dir), 1)?;
+        a.append(&[ent(0, Some(100)), ent(1, Some(200)), ent(2, None)])?;
+        a.close_segment()?;
+
+        // Re-applied after restart.
+        a.append(&[ent(2, None), ent(3, Some(300))])?;
+    }
+
+    let reader = ArchiveReader::new(dir);
+
+    let got = reader.read_logs(None, 10)?;
+    let indexes = got.iter().map(|e| e.log_id.index).collect::<Vec<_>>();
+    assert_eq!(indexes, vec![0, 1, 2, 3]);
+
+    let got = reader.read_logs(Some(0), 2)?;
+    let indexes = got.iter().map(|e| e.log_id.index).collect::<Vec<_>>();
+    assert_eq!(indexes, vec![1, 2]);
+
+    assert_eq!(reader.index_at_time(50)?, None);
+    assert_eq!(reader.index_at_time(250)?, Some(2));
+    assert_eq!(reader.index_at_time(300)?, Some(3));
+
+    Ok(())
+}
+
+#[test]
+fn test_archive_logs_of_nodes() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let dir = temp_dir.path();
+    let log_dir = Archiver::log_dir(dir);
+
+    // Two nodes sharing an archive dir write the same logs to different unclosed segments.
+    let mut a1 = LogArchiver::open(&log_dir, 1)?;
+    let mut a2 = LogArchiver::open(&log_dir, 2)?;
+    a1.append(&[ent(5, None), ent(6, None)])?;
+    a2.append(&[ent(5, None), ent(6, None)])?;
+    a1.sync()?;
+    a2.sync()?;
+
+    assert!(LogArchiver::segment_path(&log_dir, 1, 5, None).exists());
+    assert!(LogArchiver::segment_path(&log_dir, 2, 5, None).exists());
+
+    // An unclosed segment written by an older version.
+    fs::write(
+        log_dir.join(format!("{:020}.part", 7)),
+        format!("{}\n", serde_json::to_string(&ent(7, None))?),
+    )?;
+
+    let got = ArchiveReader::new(dir).read_logs(None, 10)?;
+    let indexes = got.iter().map(|e| e.log_id.index).collect::<Vec<_>>();
+    assert_eq!(indexes, vec![5, 6, 7]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 3)]
+async fn test_archiver_log_status() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let dir = temp_dir.path();
+
+    let config = RaftConfig {
+        archive_dir: dir.to_string_lossy().to_string(),
+        ..Default::default()
+    };
+    let archiver = Archiver::open(&config, 1)?.unwrap();
+
+    assert_eq!(archiver.log_status(), LogArchiveStatus::default());
+
+    archiver
+        .archive_logs(vec![ent(0, Some(100)), ent(1, Some(200))])
+        .await?;
+    archiver.archive_logs(vec![ent(2, None)]).await?;
+
+    for _ in 0..100 {
+        if archiver.log_status().last_archived == Some(2) {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    assert_eq!(archiver.log_status(), LogArchiveStatus {
+        last_archived: Some(2),
+        first_missing: None,
+        last_error: None,
+    });
+
+    // Synced logs in the open segment are readable.
+    let got = ArchiveReader::new(dir).read_logs(None, 10)?;
+    let indexes = got.iter().map(|e| e.log_id.index).collect::<Vec<_>>();
+    assert_eq!(indexes, vec![0, 1, 2]);
+
+    Ok(())
+}
+
+#[test]
+fn test_snapshot_chain() -> anyhow::Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let dir = temp_dir.path();
+    let snapshot_dir = Archiver::snapshot_dir(dir);
+    fs::create_dir_all(&snapshot_dir)?;
+
+    let snapshots = [
+        ArchivedSnapshot::new(&snapshot_dir, 10, None),
+        ArchivedSnapshot::new(&snapshot_dir, 20, Some(10)),
+        ArchivedSnapshot::new(&snapshot_dir, 30, Some(20)),
+        ArchivedSnapshot::new(&snapshot_dir, 40, None),
+        ArchivedSnapshot::new(&snapshot_dir, 50, Some(40)),
+        // Broken chain
+        ArchivedSnapshot::new(&snapshot_dir, 70, Some(60)),
+    ];
+    for s in snapshots.iter() {
+        fs::write(&s.path, b"")?;
+    }
+
+    let reader = ArchiveReader::new(dir);
+
+    let last_indexes = |until| -> anyhow::Result<Vec<u64>> {
+        let chain = reader.snapshot_chain(until)?;
+        Ok(chain.iter().map(|s| s.last_index).collect())
+    };
+
+    assert_eq!(last_indexes(5)?, Vec::<u64>::new());
+    assert_eq!(last_indexes(25)?, vec![10, 20]);
+    assert_eq!(last_indexes(39)?, vec![10, 20, 30]);
+    assert_eq!(last_indexes(100)?, vec![40, 50]);
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+/// A snapshot file in an archive dir.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArchivedSnapshot {
+    /// The index of the last log applied to this snapshot.
+    pub last_index: u64,
+
+    /// The `last_index` of the snapshot this incremental snapshot is based on.
+    ///
+    /// `None` for a full snapshot.
+    pub base_index: Option<u64>,
+
+    pub path: PathBuf,
+}
+
+impl ArchivedSnapshot {
+    pub fn new(dir: impl AsRef<Path>, last_index: u64, base_index: Option<u64>) -> Self {
+        let file_name = match base_index {
+            None => format!("{:020}.full", last_index),
+            Some(base) => format!("{:020}-{:020}.incr", last_index, base),
+        };
+
+        Self {
+            last_index,
+            base_index,
+            path: dir.as_ref().join(file_name),
+        }
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.base_index.is_none()
+    }
+
+    /// Parse a snapshot file name, returns `None` if it is not an archived snapshot.
+    pub fn parse(path: &Path) -> Option<Self> {
+        let file_name = path.file_name()?.to_str()?;
+
+        let (last_index, base_index) = if let Some(last) = file_name.strip_suffix(".full") {
+            (last.parse().ok()?, None)
+        } else if let Some(name) = file_name.strip_suffix(".incr") {
+            let (last, base) = name.split_once('-')?;
+            (last.parse().ok()?, Some(base.parse().ok()?))
+        } else {
+            return None;
+        };
+
+        Some(Self {
+            last_index,
+            base_index,
+            path: path.to_path_buf(),
+        })
+    }
+
+    /// List the archived snapshots in `dir`, sorted by `last_index`.
+    ///
+    /// If there are both a full and an incremental snapshot at the same index, the full one comes first.
+    pub fn list(dir: impl AsRef<Path>) -> Result<Vec<Self>, io::Error> {
+        let dir = dir.as_ref();
+        if !dir.exists() {
+            return Ok(vec![]);
+        }
+
+        let mut snapshots = vec![];
+        for ent in fs::read_dir(dir)? {
+            let path = ent?.path();
+            if let Some(s) = Self::parse(&path) {
+                snapshots.push(s);
+            }
+        }
+
+        snapshots.sort_by_key(|s| (s.last_index, s.base_index.is_some()));
+        Ok(snapshots)
+    }
+}
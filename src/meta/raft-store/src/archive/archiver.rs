@@ -0,0 +1,230 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use databend_common_meta_types::raft_types::Entry;
+use databend_common_meta_types::raft_types::NodeId;
+use databend_common_meta_types::snapshot_db::DB;
+use futures_util::TryStreamExt;
+use log::error;
+use log::info;
+use tokio::sync::mpsc;
+
+use crate::archive::ArchivedSnapshot;
+use crate::archive::LogArchiver;
+use crate::config::RaftConfig;
+use crate::leveled_store::db_builder::DBBuilder;
+use crate::leveled_store::leveled_map::compactor::Compactor;
+
+/// Archive applied raft logs and built snapshots to [`RaftConfig::archive_dir`].
+pub struct Archiver {
+    dir: PathBuf,
+
+    full_snapshot_interval: u64,
+
+    rotbl_config: rotbl::v001::Config,
+
+    /// Send logs to the blocking task that writes them to the archive.
+    ///
+    /// It is bounded so that applying logs waits for the archive when the disk falls behind.
+    log_tx: mpsc::Sender<Vec<Entry>>,
+
+    log_status: Arc<Mutex<LogArchiveStatus>>,
+}
+
+/// The progress of archiving raft logs.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize)]
+pub struct LogArchiveStatus {
+    /// The index of the last log that is archived and synced to disk.
+    pub last_archived: Option<u64>,
+
+    /// The index of the first log that failed to be archived.
+    ///
+    /// The archived logs have a gap since this index,
+    /// and can not be used to restore a state after it.
+    pub first_missing: Option<u64>,
+
+    /// The last error occurred when archiving logs.
+    pub last_error: Option<String>,
+}
+
+impl Archiver {
+    /// The max number of log batches sent to but not yet written by the archiving task.
+    pub const MAX_PENDING_LOG_BATCHES: usize = 1024;
+
+    /// Open an archiver for node `node_id`, or returns `None` if archiving is disabled.
+    pub fn open(config: &RaftConfig, node_id: NodeId) -> Result<Option<Self>, io::Error> {
+        if config.archive_dir.is_empty() {
+            return Ok(None);
+        }
+
+        let dir = PathBuf::from(&config.archive_dir);
+        fs::create_dir_all(Self::snapshot_dir(&dir))?;
+
+        let log_archiver = LogArchiver::open(Self::log_dir(&dir), node_id)?;
+        let log_status = Arc::new(Mutex::new(LogArchiveStatus::default()));
+
+        let (log_tx, log_rx) = mpsc::channel(Self::MAX_PENDING_LOG_BATCHES);
+        {
+            let log_status = log_status.clone();
+            databend_common_base::runtime::spawn_blocking(move || {
+                Self::write_logs(log_archiver, log_rx, log_status)
+            });
+        }
+
+        info!("raft logs and snapshots are archived to: {}", dir.display());
+
+        Ok(Some(Self {
+            dir,
+            full_snapshot_interval: config.archive_full_snapshot_interval,
+            rotbl_config: config.to_rotbl_config(),
+            log_tx,
+            log_status,
+        }))
+    }
+
+    pub fn log_dir(archive_dir: impl AsRef<Path>) -> PathBuf {
+        archive_dir.as_ref().join("log")
+    }
+
+    pub fn snapshot_dir(archive_dir: impl AsRef<Path>) -> PathBuf {
+        archive_dir.as_ref().join("snapshot")
+    }
+
+    /// Archive raft logs that are about to be applied to the state machine.
+    ///
+    /// The logs are written to disk by a blocking task in the background,
+    /// the result is reported by [`Self::log_status`].
+    /// It waits if [`Self::MAX_PENDING_LOG_BATCHES`] batches are not yet written,
+    /// and returns an error only if the task has quit.
+    pub async fn archive_logs(&self, entries: Vec<Entry>) -> Result<(), io::Error> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+
+        self.log_tx
+            .send(entries)
+            .await
+            .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "raft log archiver has quit"))
+    }
+
+    pub fn log_status(&self) -> LogArchiveStatus {
+        self.log_status.lock().unwrap().clone()
+    }
+
+    /// Write logs received from `rx` until the [`Archiver`] is dropped.
+    ///
+    /// All pending logs are written as a batch and then synced to disk.
+    fn write_logs(
+        mut log_archiver: LogArchiver,
+        mut rx: mpsc::Receiver<Vec<Entry>>,
+        status: Arc<Mutex<LogArchiveStatus>>,
+    ) {
+        while let Some(mut batch) = rx.blocking_recv() {
+            while let Ok(entries) = rx.try_recv() {
+                batch.extend(entries);
+            }
+
+            let first_index = batch[0].log_id.index;
+            let last_index = batch[batch.len() - 1].log_id.index;
+
+            let res = log_archiver
+                .append(&batch)
+                .and_then(|_| log_archiver.sync());
+
+            let mut status = status.lock().unwrap();
+            match res {
+                Ok(_) => {
+                    status.last_archived = Some(last_index);
+                }
+                Err(e) => {
+                    error!(
+                        "failed to archive raft logs [{}, {}]: {}",
+                        first_index, last_index, e
+                    );
+
+                    // The segment may end with a partially written log,
+                    // following logs are written to a new segment.
+                    log_archiver.abandon_segment();
+
+                    status.first_missing.get_or_insert(first_index);
+                    status.last_error = Some(e.to_string());
+                }
+            }
+        }
+    }
+
+    /// Archive a snapshot `db` that is just built from `compactor`.
+    ///
+    /// If the snapshot the compactor is based on is the last archived one,
+    /// only the changes in the in-memory levels are archived, as an incremental snapshot.
+    /// Otherwise, or after [`RaftConfig::archive_full_snapshot_interval`] incremental snapshots,
+    /// the snapshot file is copied as a full snapshot.
+    pub async fn archive_snapshot(
+        &self,
+        compactor: &mut Compactor,
+        db: &DB,
+    ) -> Result<Option<ArchivedSnapshot>, io::Error> {
+        let Some(last_applied) = db.sys_data().last_applied_ref() else {
+            return Ok(None);
+        };
+
+        let snapshot_dir = Self::snapshot_dir(&self.dir);
+        let archived = ArchivedSnapshot::list(&snapshot_dir)?;
+
+        let incremental_since_full = archived.iter().rev().take_while(|s| !s.is_full()).count();
+
+        let base_index = compactor
+            .db()
+            .and_then(|base| *base.sys_data().last_applied_ref())
+            .map(|log_id| log_id.index);
+
+        let base_is_archived =
+            base_index.is_some() && archived.last().map(|s| s.last_index) == base_index;
+
+        let incremental =
+            base_is_archived && (incremental_since_full as u64) < self.full_snapshot_interval;
+
+        let snapshot = if incremental {
+            ArchivedSnapshot::new(&snapshot_dir, last_applied.index, base_index)
+        } else {
+            ArchivedSnapshot::new(&snapshot_dir, last_applied.index, None)
+        };
+
+        let temp_path = snapshot.path.with_extension("tmp");
+
+        if incremental {
+            let (sys_data, mut strm) = compactor.compact_changes().await?;
+
+            let mut builder = DBBuilder::new(&temp_path, self.rotbl_config.clone())?;
+            while let Some((k, v)) = strm.try_next().await? {
+                builder.append_kv(k, v)?;
+            }
+            builder.flush(sys_data)?;
+        } else {
+            tokio::fs::copy(db.path(), &temp_path).await?;
+        }
+
+        fs::rename(&temp_path, &snapshot.path)?;
+
+        info!("archived snapshot: {}", snapshot.path.display());
+        Ok(Some(snapshot))
+    }
+}
@@ -0,0 +1,173 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs;
+use std::io;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+use databend_common_meta_types::raft_types::Entry;
+use databend_common_meta_types::raft_types::NodeId;
+use log::info;
+use log::warn;
+
+/// The log segment being written.
+struct Segment {
+    first_index: u64,
+    last_index: u64,
+    n: u64,
+    path: PathBuf,
+    f: fs::File,
+}
+
+/// Append applied raft logs to segment files in the `log/` sub dir of an archive dir.
+///
+/// Entries are written to `<first>.part` and the segment is closed by renaming it to `<first>-<last>.log`
+/// when it reaches [`Self::MAX_ENTRIES_PER_SEGMENT`] entries.
+///
+/// A log may be archived more than once, e.g., logs after the last snapshot are re-applied after restart.
+/// The reader keeps only one copy of each index.
+pub struct LogArchiver {
+    dir: PathBuf,
+
+    /// The node writing the archive, included in the names of unclosed segments,
+    /// so that nodes sharing an archive dir do not append to the same file.
+    node_id: NodeId,
+
+    current: Option<Segment>,
+}
+
+impl LogArchiver {
+    pub const MAX_ENTRIES_PER_SEGMENT: u64 = 10_000;
+
+    pub fn open(dir: impl AsRef<Path>, node_id: NodeId) -> Result<Self, io::Error> {
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(&dir)?;
+
+        Ok(Self {
+            dir,
+            node_id,
+            current: None,
+        })
+    }
+
+    /// Returns the path of a segment: `<first>.<node_id>.part` if it is not closed,
+    /// otherwise `<first>-<last>.log`.
+    pub fn segment_path(
+        dir: impl AsRef<Path>,
+        node_id: NodeId,
+        first_index: u64,
+        last_index: Option<u64>,
+    ) -> PathBuf {
+        let file_name = match last_index {
+            None => format!("{:020}.{}.part", first_index, node_id),
+            Some(last) => format!("{:020}-{:020}.log", first_index, last),
+        };
+        dir.as_ref().join(file_name)
+    }
+
+    /// Append entries and close the current segment if it is full.
+    ///
+    /// Entries are written to the file without buffering,
+    /// call [`Self::sync`] to sync them to disk.
+    pub fn append<'a>(
+        &mut self,
+        entries: impl IntoIterator<Item = &'a Entry>,
+    ) -> Result<(), io::Error> {
+        for entry in entries {
+            let index = entry.log_id.index;
+
+            if self.current.is_none() {
+                self.current = Some(self.new_segment(index)?);
+            }
+            let seg = self.current.as_mut().unwrap();
+
+            let mut line = serde_json::to_vec(entry)?;
+            line.push(b'\n');
+            seg.f.write_all(&line)?;
+
+            seg.last_index = index;
+            seg.n += 1;
+
+            if seg.n >= Self::MAX_ENTRIES_PER_SEGMENT {
+                self.close_segment()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Sync the written entries in the current segment to disk.
+    pub fn sync(&mut self) -> Result<(), io::Error> {
+        if let Some(seg) = &self.current {
+            seg.f.sync_data()?;
+        }
+        Ok(())
+    }
+
+    /// Stop writing to the current segment without closing it.
+    ///
+    /// The segment is left as `<first>.<node_id>.part` and the next entry starts a new segment.
+    pub fn abandon_segment(&mut self) {
+        if let Some(seg) = self.current.take() {
+            warn!("abandon archived raft log segment: {}", seg.path.display());
+        }
+    }
+
+    /// Sync the current segment and rename it to `<first>-<last>.log`.
+    pub fn close_segment(&mut self) -> Result<(), io::Error> {
+        let Some(seg) = self.current.take() else {
+            return Ok(());
+        };
+
+        seg.f.sync_all()?;
+
+        let closed = Self::segment_path(
+            &self.dir,
+            self.node_id,
+            seg.first_index,
+            Some(seg.last_index),
+        );
+        fs::rename(&seg.path, &closed)?;
+
+        info!("archived raft log segment: {}", closed.display());
+        Ok(())
+    }
+
+    fn new_segment(&self, first_index: u64) -> Result<Segment, io::Error> {
+        let path = Self::segment_path(&self.dir, self.node_id, first_index, None);
+
+        let f = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)?;
+
+        Ok(Segment {
+            first_index,
+            last_index: first_index,
+            n: 0,
+            path,
+            f,
+        })
+    }
+}
+
+impl Drop for LogArchiver {
+    fn drop(&mut self) {
+        if let Err(e) = self.close_segment() {
+            warn!("failed to close archived raft log segment: {}", e);
+        }
+    }
+}
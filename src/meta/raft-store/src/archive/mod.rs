@@ -0,0 +1,47 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Continuously archive raft logs and snapshots to a dir, for point-in-time recovery.
+//!
+//! The layout of an archive dir:
+//!
+//! ```text
+//! <archive_dir>/
+//!     log/
+//!         <first>.<node_id>.part       // The log segment being written by node `<node_id>`
+//!         <first>-<last>.log           // Closed log segments, one json serialized `Entry` per line
+//!     snapshot/
+//!         <last>.full                  // A full snapshot
+//!         <last>-<base>.incr           // Changed keys, including tombstones, since the snapshot at `<base>`
+//! ```
+//!
+//! `<first>`, `<last>` and `<base>` are log indexes, zero-padded to 20 digits so that file names sort by index.
+//! A snapshot at `<last>` contains the state after applying log at index `<last>`.
+//!
+//! The state at a log index `N` is rebuilt with the newest full snapshot at or before `N`,
+//! the chain of incremental snapshots following it, and the archived logs after the last snapshot upto `N`.
+
+mod archive_reader;
+mod archived_snapshot;
+mod archiver;
+mod log_archiver;
+
+pub use archive_reader::ArchiveReader;
+pub use archived_snapshot::ArchivedSnapshot;
+pub use archiver::Archiver;
+pub use archiver::LogArchiveStatus;
+pub use log_archiver::LogArchiver;
+
+#[cfg(test)]
+mod archive_test;
@@ -110,6 +110,17 @@ pub struct RaftConfig {
     /// By default it is 1GB.
     pub snapshot_db_block_cache_size: u64,
 
+    /// The dir to continuously archive raft logs and snapshots to, for point-in-time recovery.
+    ///
+    /// An object store can be used by mounting it as a local dir.
+    /// Archiving is disabled if it is empty.
+    pub archive_dir: String,
+
+    /// Archive a full snapshot after every this many incremental snapshots.
+    ///
+    /// Other snapshots are archived as incremental snapshots that contain only the changed keys.
+    pub archive_full_snapshot_interval: u64,
+
     /// Single node metasrv. It creates a single node cluster if meta data is not initialized.
     /// Otherwise it opens the previous one.
     /// This is mainly for testing purpose.
@@ -120,6 +131,11 @@ pub struct RaftConfig {
     /// The value is one or more addresses of a node in the cluster, to which this node sends a `join` request.
     pub join: Vec<String>,
 
+    /// Join the cluster as a learner instead of a voter.
+    ///
+    /// A learner replicates the state machine and serves reads, but never votes or becomes the leader.
+    pub learner: bool,
+
     /// Do not run databend-meta, but just remove a node from its cluster.
     ///
     /// The value is one or more addresses of a node in the cluster, to which this node sends a `leave` request.
@@ -179,8 +195,12 @@ impl Default for RaftConfig {
             snapshot_db_block_cache_item: 1024,
             snapshot_db_block_cache_size: 1073741824,
 
+            archive_dir: "".to_string(),
+            archive_full_snapshot_interval: 10,
+
             single: false,
             join: vec![],
+            learner: false,
             leave_via: vec![],
             leave_id: None,
             id: 0,
@@ -282,6 +302,12 @@ impl RaftConfig {
             )));
         }
 
+        if self.learner && self.join.is_empty() {
+            return Err(MetaStartupError::InvalidConfig(String::from(
+                "--learner must be used along with --join",
+            )));
+        }
+
         let self_addr = self.raft_api_listen_host_string();
         if self.join.contains(&self_addr) {
             return Err(MetaStartupError::InvalidConfig(String::from(
@@ -139,4 +139,33 @@ impl<'a> CompactingData<'a> {
 
         Ok((sys_data, normal_strm.boxed()))
     }
+
+    /// Compact only the in-memory immutable levels into a stream, tombstones are kept.
+    ///
+    /// The output is the changes made since the persisted db,
+    /// applying it on top of the db produces the same data as [`Self::compact`] does.
+    pub async fn compact_changes(
+        &self,
+    ) -> Result<(SysData, IOResultStream<(String, SeqMarked)>), io::Error> {
+        let d = self.immutable_levels.newest().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, "no immutable level to compact")
+        })?;
+
+        let sys_data = d.sys_data_ref().clone();
+
+        let strm = (*self.immutable_levels).expire_map().range(..).await?;
+        let expire_strm = strm.map(|item: Result<(ExpireKey, Marked<String>), io::Error>| {
+            let (k, v) = item?;
+            RotblCodec::encode_key_seq_marked(&k, v)
+        });
+
+        let strm = (*self.immutable_levels).str_map().range(..).await?;
+        let kv_strm = strm.map(|item: Result<(String, Marked), io::Error>| {
+            let (k, v) = item?;
+            RotblCodec::encode_key_seq_marked(&k, v)
+        });
+
+        // `exp-/*` < `kv--/`: expire keys should be output first to maintain the order.
+        Ok((sys_data, expire_strm.chain(kv_strm).boxed()))
+    }
 }
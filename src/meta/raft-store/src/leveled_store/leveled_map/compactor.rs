@@ -74,4 +74,14 @@ impl Compactor {
         let compacting_data = CompactingData::new(&mut self.immutable_levels, self.db.as_ref());
         compacting_data.compact().await
     }
+
+    /// Compact only the in-memory immutable levels into a stream, keeping tombstones.
+    ///
+    /// It is the changes made since [`Self::db`], e.g., to build an incremental snapshot.
+    pub async fn compact_changes(
+        &mut self,
+    ) -> Result<(SysData, IOResultStream<(String, SeqMarked)>), io::Error> {
+        let compacting_data = CompactingData::new(&mut self.immutable_levels, self.db.as_ref());
+        compacting_data.compact_changes().await
+    }
 }
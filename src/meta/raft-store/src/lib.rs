@@ -19,6 +19,7 @@
 #![allow(clippy::diverging_sub_expression)]
 
 pub mod applier;
+pub mod archive;
 pub mod config;
 pub mod key_spaces;
 pub mod leveled_store;
@@ -121,6 +121,10 @@ impl SMV003 {
         let levels = self.levels_mut();
         *levels.sys_data_mut() = sys_data;
         *levels.persisted_mut() = Some(db);
+
+        if let Some(sender) = self.event_sender() {
+            sender.reset_history(self.sys_data_ref().curr_seq());
+        }
         Ok(())
     }
 
@@ -37,6 +37,12 @@ pub trait SMEventSender: Debug + Sync + Send {
         tx: mpsc::Sender<Result<WatchResponse, Status>>,
         strm: IOResultStream<(String, SeqV)>,
     );
+
+    /// Inform that the changes upto `seq` are no longer available to a resuming watcher.
+    ///
+    /// It is called when the state machine is replaced without emitting change events,
+    /// e.g., when a snapshot is installed.
+    fn reset_history(&self, seq: u64);
 }
 
 /// The API a state machine implements
@@ -25,6 +25,8 @@ use databend_common_grpc::GrpcClaim;
 use databend_common_grpc::GrpcToken;
 use databend_common_meta_client::MetaGrpcReadReq;
 use databend_common_meta_client::MetaGrpcReq;
+use databend_common_meta_client::ReadConsistency;
+use databend_common_meta_client::READ_CONSISTENCY_KEY;
 use databend_common_meta_kvapi::kvapi::KVApi;
 use databend_common_meta_types::protobuf as pb;
 use databend_common_meta_types::protobuf::meta_service_server::MetaService;
@@ -132,16 +134,23 @@ impl MetaServiceImpl {
         &self,
         request: Request<RaftRequest>,
     ) -> Result<(Option<Endpoint>, BoxStream<StreamItem>), Status> {
+        let consistency = read_consistency(request.metadata())?;
         let req: MetaGrpcReadReq = GrpcHelper::parse_req(request)?;
 
-        debug!("{}: Received ReadRequest: {:?}", func_name!(), req);
-
-        let req = ForwardRequest::new(1, req);
+        debug!(
+            "{}: Received ReadRequest: {:?}, consistency: {}",
+            func_name!(),
+            req,
+            consistency
+        );
 
         let res = self
             .meta_node
-            .handle_forwardable_request::<MetaGrpcReadReq>(req.clone())
-            .log_elapsed_info(format!("ReadRequest: {:?}", req))
+            .handle_read_request(req.clone(), consistency)
+            .log_elapsed_info(format!(
+                "ReadRequest: {:?}, consistency: {}",
+                req, consistency
+            ))
             .await
             .map_err(GrpcHelper::internal_err);
 
@@ -395,8 +404,10 @@ impl MetaService for MetaServiceImpl {
 
         let mn = &self.meta_node;
 
-        let sender = mn.add_watcher(watch, tx.clone()).await?;
-        let stream = WatchStream::new(rx, sender, mn.subscriber_handle.clone());
+        let (sender, replay) = mn.add_watcher(watch, tx.clone()).await?;
+        // The missed changes are replayed by the stream before the new ones.
+        let stream = WatchStream::new(rx, sender, mn.subscriber_handle.clone())
+            .with_replay(replay.into_iter().map(Ok));
 
         if flush {
             let sm = mn.raft_store.state_machine.clone();
@@ -496,6 +507,18 @@ impl MetaService for MetaServiceImpl {
     }
 }
 
+/// Get the consistency a read request asks for, a request without it is linearizable.
+fn read_consistency(metadata: &MetadataMap) -> Result<ReadConsistency, Status> {
+    let Some(value) = metadata.get(READ_CONSISTENCY_KEY) else {
+        return Ok(ReadConsistency::Linearizable);
+    };
+
+    let value = value
+        .to_str()
+        .map_err(|e| Status::invalid_argument(e.to_string()))?;
+    value.parse().map_err(Status::invalid_argument)
+}
+
 fn thread_tracking_guard<T>(req: &tonic::Request<T>) -> Option<TrackingGuard> {
     if let Some(value) = req.metadata().get("QueryID") {
         if let Ok(value) = value.to_str() {
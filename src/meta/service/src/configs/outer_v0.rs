@@ -22,6 +22,7 @@ use databend_common_meta_raft_store::config::RaftConfig as InnerRaftConfig;
 use databend_common_meta_types::MetaStartupError;
 use databend_common_tracing::Config as InnerLogConfig;
 use databend_common_tracing::FileConfig as InnerFileLogConfig;
+use databend_common_tracing::HistoryLogConfig;
 use databend_common_tracing::OTLPConfig;
 use databend_common_tracing::ProfileLogConfig;
 use databend_common_tracing::QueryLogConfig;
@@ -302,8 +303,12 @@ pub struct ConfigViaEnv {
     pub raft_snapshot_db_block_cache_item: u64,
     pub raft_snapshot_db_block_cache_size: u64,
 
+    pub kvsrv_archive_dir: String,
+    pub kvsrv_archive_full_snapshot_interval: u64,
+
     pub kvsrv_single: bool,
     pub metasrv_join: Vec<String>,
+    pub metasrv_join_as_learner: bool,
     pub kvsrv_id: u64,
     pub cluster_name: String,
 }
@@ -358,8 +363,12 @@ impl From<Config> for ConfigViaEnv {
             raft_snapshot_db_block_cache_item: cfg.raft_config.snapshot_db_block_cache_item,
             raft_snapshot_db_block_cache_size: cfg.raft_config.snapshot_db_block_cache_size,
 
+            kvsrv_archive_dir: cfg.raft_config.archive_dir,
+            kvsrv_archive_full_snapshot_interval: cfg.raft_config.archive_full_snapshot_interval,
+
             kvsrv_single: cfg.raft_config.single,
             metasrv_join: cfg.raft_config.join,
+            metasrv_join_as_learner: cfg.raft_config.learner,
             kvsrv_id: cfg.raft_config.id,
             cluster_name: cfg.raft_config.cluster_name,
         }
@@ -394,8 +403,12 @@ impl Into<Config> for ConfigViaEnv {
             snapshot_db_block_cache_item: self.raft_snapshot_db_block_cache_item,
             snapshot_db_block_cache_size: self.raft_snapshot_db_block_cache_size,
 
+            archive_dir: self.kvsrv_archive_dir,
+            archive_full_snapshot_interval: self.kvsrv_archive_full_snapshot_interval,
+
             single: self.kvsrv_single,
             join: self.metasrv_join,
+            learner: self.metasrv_join_as_learner,
             // Do not allow to leave via environment variable
             leave_via: vec![],
             // Do not allow to leave via environment variable
@@ -537,6 +550,18 @@ pub struct RaftConfig {
     #[clap(long, default_value = "1073741824")]
     pub snapshot_db_block_cache_size: u64,
 
+    /// The dir to continuously archive raft logs and snapshots to, for point-in-time recovery
+    /// with `databend-metactl restore`.
+    ///
+    /// An object store can be used by mounting it as a local dir.
+    /// Archiving is disabled if it is empty.
+    #[clap(long, default_value = "")]
+    pub archive_dir: String,
+
+    /// Archive a full snapshot after every this many incremental snapshots.
+    #[clap(long, default_value = "10")]
+    pub archive_full_snapshot_interval: u64,
+
     /// Start databend-meta in single node mode.
     /// It initialize a single node cluster, if meta data is not initialized.
     /// If on-disk data is already initialized, this argument has no effect.
@@ -552,6 +577,13 @@ pub struct RaftConfig {
     #[clap(long)]
     pub join: Vec<String>,
 
+    /// Join the cluster as a learner instead of a voter.
+    ///
+    /// A learner replicates the state machine and serves reads, but never votes or becomes the leader.
+    /// It takes effect only along with `--join`.
+    #[clap(long)]
+    pub learner: bool,
+
     /// Do not run databend-meta, but just remove a node from its cluster via the provided endpoints.
     ///
     /// This node will be removed by `id`.
@@ -611,8 +643,12 @@ impl From<RaftConfig> for InnerRaftConfig {
             snapshot_db_block_cache_item: x.snapshot_db_block_cache_item,
             snapshot_db_block_cache_size: x.snapshot_db_block_cache_size,
 
+            archive_dir: x.archive_dir,
+            archive_full_snapshot_interval: x.archive_full_snapshot_interval,
+
             single: x.single,
             join: x.join,
+            learner: x.learner,
             leave_via: x.leave_via,
             leave_id: x.leave_id,
             id: x.id,
@@ -647,8 +683,12 @@ impl From<InnerRaftConfig> for RaftConfig {
             snapshot_db_block_cache_item: inner.snapshot_db_block_cache_item,
             snapshot_db_block_cache_size: inner.snapshot_db_block_cache_size,
 
+            archive_dir: inner.archive_dir,
+            archive_full_snapshot_interval: inner.archive_full_snapshot_interval,
+
             single: inner.single,
             join: inner.join,
+            learner: inner.learner,
             leave_via: inner.leave_via,
             leave_id: inner.leave_id,
             id: inner.id,
@@ -685,6 +725,7 @@ impl Into<InnerLogConfig> for LogConfig {
             profile: ProfileLogConfig::default(),
             structlog: StructLogConfig::default(),
             tracing: TracingConfig::default(),
+            history: HistoryLogConfig::default(),
         }
     }
 }
@@ -21,6 +21,7 @@ use databend_common_meta_kvapi::kvapi::ListKVReq;
 use databend_common_meta_kvapi::kvapi::MGetKVReply;
 use databend_common_meta_kvapi::kvapi::MGetKVReq;
 use databend_common_meta_types::protobuf::RaftRequest;
+use databend_common_meta_types::raft_types::LogId;
 use databend_common_meta_types::raft_types::NodeId;
 use databend_common_meta_types::AppliedState;
 use databend_common_meta_types::Endpoint;
@@ -37,6 +38,10 @@ pub struct JoinRequest {
     pub grpc_api_addr: String,
 
     pub grpc_api_advertise_address: Option<String>,
+
+    /// Join as a learner, which replicates the state machine but does not vote.
+    #[serde(default)]
+    pub learner: bool,
 }
 
 impl JoinRequest {
@@ -52,6 +57,11 @@ impl JoinRequest {
             ..Default::default()
         }
     }
+
+    pub fn with_learner(mut self, learner: bool) -> Self {
+        self.learner = learner;
+        self
+    }
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
@@ -77,6 +87,10 @@ pub enum ForwardRequestBody {
 
     Write(LogEntry),
 
+    /// Get the log id up to which a read has to wait for the state machine to apply,
+    /// to see every write committed before it.
+    ReadIndex,
+
     GetKV(GetKVReq),
     MGetKV(MGetKVReq),
     ListKV(ListKVReq),
@@ -143,6 +157,8 @@ pub enum ForwardResponse {
     Leave(()),
     AppliedState(AppliedState),
 
+    ReadIndex(Option<LogId>),
+
     GetKV(GetKVReply),
     MGetKV(MGetKVReply),
     ListKV(ListKVReply),
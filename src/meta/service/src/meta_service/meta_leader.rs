@@ -23,6 +23,7 @@ use databend_common_meta_sled_store::openraft::ChangeMembers;
 use databend_common_meta_stoerr::MetaStorageError;
 use databend_common_meta_types::protobuf::StreamItem;
 use databend_common_meta_types::raft_types::ClientWriteError;
+use databend_common_meta_types::raft_types::LogId;
 use databend_common_meta_types::raft_types::MembershipNode;
 use databend_common_meta_types::raft_types::NodeId;
 use databend_common_meta_types::raft_types::RaftError;
@@ -89,6 +90,10 @@ impl Handler<ForwardRequestBody> for MetaLeader<'_> {
                 let res = self.write(entry.clone()).await?;
                 Ok(ForwardResponse::AppliedState(res))
             }
+            ForwardRequestBody::ReadIndex => {
+                let read_log_id = self.read_index().await?;
+                Ok(ForwardResponse::ReadIndex(read_log_id))
+            }
 
             ForwardRequestBody::GetKV(req) => {
                 let sm = self.get_state_machine().await;
@@ -118,48 +123,57 @@ impl Handler<MetaGrpcReadReq> for MetaLeader<'_> {
     ) -> Result<BoxStream<StreamItem>, MetaOperationError> {
         debug!(req :? =(&req); "handle(MetaGrpcReadReq)");
 
-        let sm = self.get_state_machine().await;
-        let kv_api = sm.kv_api();
+        read_local(self.sto, req.body).await
+    }
+}
 
-        match req.body {
-            MetaGrpcReadReq::GetKV(req) => {
-                // safe unwrap(): Infallible
-                let got = kv_api.get_kv(&req.key).await.unwrap();
+/// Serve a read request from the local state machine.
+pub(crate) async fn read_local(
+    sto: &RaftStore,
+    req: MetaGrpcReadReq,
+) -> Result<BoxStream<StreamItem>, MetaOperationError> {
+    let sm = sto.state_machine.read().await;
+    let kv_api = sm.kv_api();
 
-                let item = StreamItem::from((req.key.clone(), got));
-                let strm = futures::stream::iter([Ok(item)]);
+    match req {
+        MetaGrpcReadReq::GetKV(req) => {
+            // safe unwrap(): Infallible
+            let got = kv_api.get_kv(&req.key).await.unwrap();
 
-                Ok(strm.boxed())
-            }
+            let item = StreamItem::from((req.key.clone(), got));
+            let strm = futures::stream::iter([Ok(item)]);
 
-            MetaGrpcReadReq::MGetKV(req) => {
-                // safe unwrap(): Infallible
-                let values = kv_api.mget_kv(&req.keys).await.unwrap();
+            Ok(strm.boxed())
+        }
 
-                let kv_iter = req
-                    .keys
-                    .clone()
-                    .into_iter()
-                    .zip(values)
-                    .map(|(k, v)| Ok(StreamItem::from((k, v))));
+        MetaGrpcReadReq::MGetKV(req) => {
+            // safe unwrap(): Infallible
+            let values = kv_api.mget_kv(&req.keys).await.unwrap();
 
-                let strm = futures::stream::iter(kv_iter);
+            let kv_iter = req
+                .keys
+                .clone()
+                .into_iter()
+                .zip(values)
+                .map(|(k, v)| Ok(StreamItem::from((k, v))));
 
-                Ok(strm.boxed())
-            }
+            let strm = futures::stream::iter(kv_iter);
 
-            MetaGrpcReadReq::ListKV(req) => {
-                let strm =
-                    kv_api.list_kv(&req.prefix).await.map_err(|e| {
-                        MetaOperationError::DataError(MetaDataError::ReadError(
-                            MetaDataReadError::new("list_kv", &req.prefix, &e),
-                        ))
-                    })?;
+            Ok(strm.boxed())
+        }
 
-                let strm = strm.map_err(|e| Status::internal(e.to_string()));
+        MetaGrpcReadReq::ListKV(req) => {
+            let strm = kv_api.list_kv(&req.prefix).await.map_err(|e| {
+                MetaOperationError::DataError(MetaDataError::ReadError(MetaDataReadError::new(
+                    "list_kv",
+                    &req.prefix,
+                    &e,
+                )))
+            })?;
 
-                Ok(strm.boxed())
-            }
+            let strm = strm.map_err(|e| Status::internal(e.to_string()));
+
+            Ok(strm.boxed())
         }
     }
 }
@@ -175,19 +189,27 @@ impl<'a> MetaLeader<'a> {
     /// Join a new node to the cluster.
     ///
     /// - Adds the node to cluster as a non-voter persistently and starts replication.
-    /// - Adds the node to membership to let it become a voter.
+    /// - Adds the node to membership to let it become a voter, unless it joins as a learner.
     ///
     /// If the node is already in cluster membership, it still returns Ok.
     #[fastrace::trace]
     pub async fn join(&self, req: JoinRequest) -> Result<(), RaftError<ClientWriteError>> {
         let node_id = req.node_id;
         let endpoint = req.endpoint;
+        let learner = req.learner;
         let metrics = self.raft.metrics().borrow().clone();
         let membership = metrics.membership_config.membership();
 
-        let voters = membership.voter_ids().collect::<BTreeSet<_>>();
+        let joined = if learner {
+            membership.nodes().any(|(id, _)| *id == node_id)
+        } else {
+            membership
+                .voter_ids()
+                .collect::<BTreeSet<_>>()
+                .contains(&node_id)
+        };
 
-        if voters.contains(&node_id) {
+        if joined {
             return Ok(());
         }
 
@@ -203,15 +225,23 @@ impl<'a> MetaLeader<'a> {
         };
         self.write(ent).await?;
 
-        self.raft
-            .change_membership(
-                ChangeMembers::AddVoters(btreemap! {node_id=>MembershipNode{}}),
-                false,
-            )
-            .await?;
+        let change = if learner {
+            ChangeMembers::AddNodes(btreemap! {node_id=>MembershipNode{}})
+        } else {
+            ChangeMembers::AddVoters(btreemap! {node_id=>MembershipNode{}})
+        };
+        self.raft.change_membership(change, false).await?;
         Ok(())
     }
 
+    /// Return the log id a read has to wait for to be applied, after confirming this node is
+    /// still the leader.
+    #[fastrace::trace]
+    pub async fn read_index(&self) -> Result<Option<LogId>, MetaOperationError> {
+        let read_log_id = self.raft.ensure_linearizable().await?;
+        Ok(read_log_id)
+    }
+
     /// A node leave the cluster.
     ///
     /// - Remove the node from membership.
@@ -33,6 +33,7 @@ use databend_common_meta_client::RequestFor;
 use databend_common_meta_raft_store::config::RaftConfig;
 use databend_common_meta_raft_store::ondisk::DATA_VERSION;
 use databend_common_meta_raft_store::raft_log_v004::RaftLogStat;
+use databend_common_meta_raft_store::state_machine_api::SMEventSender;
 use databend_common_meta_sled_store::openraft;
 use databend_common_meta_sled_store::openraft::error::RaftError;
 use databend_common_meta_sled_store::openraft::ChangeMembers;
@@ -113,6 +114,11 @@ pub struct MetaNode {
     pub running_rx: watch::Receiver<()>,
     pub join_handles: Mutex<Vec<JoinHandle<Result<(), AnyError>>>>,
     pub joined_tasks: AtomicI32,
+
+    /// The latest read index got from the leader and when it is got.
+    ///
+    /// It is shared by the bounded-staleness reads served by this node.
+    pub(crate) last_read_index: Mutex<Option<(Instant, Option<LogId>)>>,
 }
 
 impl Drop for MetaNode {
@@ -161,9 +167,12 @@ impl MetaNodeBuilder {
 
         let handle = EventSubscriber::spawn();
 
-        sto.get_state_machine()
-            .await
-            .set_event_sender(Box::new(handle.clone()));
+        {
+            let mut sm = sto.get_state_machine().await;
+            // Changes applied before the sender is set are not logged for resuming watchers.
+            handle.reset_history(sm.sys_data_ref().curr_seq());
+            sm.set_event_sender(Box::new(handle.clone()));
+        }
 
         let meta_node = Arc::new(MetaNode {
             raft_store: sto.clone(),
@@ -173,6 +182,7 @@ impl MetaNodeBuilder {
             running_rx: rx,
             join_handles: Mutex::new(Vec::new()),
             joined_tasks: AtomicI32::new(1),
+            last_read_index: Mutex::new(None),
         });
 
         MetaNode::subscribe_metrics(meta_node.clone(), raft.metrics()).await;
@@ -577,7 +587,7 @@ impl MetaNode {
         // Try to join a cluster only when this node has no log.
         // Joining a node with log has risk messing up the data in this node and in the target cluster.
         let in_cluster = self
-            .is_in_cluster()
+            .is_in_cluster(conf.learner)
             .await
             .map_err(|e| MetaManagementError::Join(AnyError::new(&e)))?;
 
@@ -664,11 +674,14 @@ impl MetaNode {
 
         let req = ForwardRequest {
             forward_to_leader: 1,
-            body: ForwardRequestBody::Join(JoinRequest::new(
-                conf.id,
-                advertise_endpoint.clone(),
-                grpc_api_advertise_address.clone(),
-            )),
+            body: ForwardRequestBody::Join(
+                JoinRequest::new(
+                    conf.id,
+                    advertise_endpoint.clone(),
+                    grpc_api_advertise_address.clone(),
+                )
+                .with_learner(conf.learner),
+            ),
         };
 
         let join_res = raft_client.forward(req.clone()).await;
@@ -721,7 +734,13 @@ impl MetaNode {
     ///   Then the next leader does not know about this new node.
     ///
     ///   Only when the membership is committed, this node can be sure it is in a cluster.
-    async fn is_in_cluster(&self) -> Result<Result<String, String>, MetaStorageError> {
+    ///
+    /// A node joining as a `learner` is in the cluster once it is in the committed membership
+    /// as a learner.
+    async fn is_in_cluster(
+        &self,
+        learner: bool,
+    ) -> Result<Result<String, String>, MetaStorageError> {
         let membership = {
             let sm = self.raft_store.get_state_machine().await;
             sm.sys_data_ref().last_membership_ref().membership().clone()
@@ -729,8 +748,11 @@ impl MetaNode {
         info!("is_in_cluster: membership: {:?}", membership);
 
         let voter_ids = membership.voter_ids().collect::<BTreeSet<_>>();
+        let learner_ids = membership.learner_ids().collect::<BTreeSet<_>>();
 
-        if voter_ids.contains(&self.raft_store.id) {
+        if voter_ids.contains(&self.raft_store.id)
+            || (learner && learner_ids.contains(&self.raft_store.id))
+        {
             return Ok(Ok(format!(
                 "node {} already in cluster",
                 self.raft_store.id
@@ -880,6 +902,7 @@ impl MetaNode {
 
         let raft_log_status = self.get_raft_log_stat().await.into();
         let snapshot_key_count = self.get_snapshot_key_count().await;
+        let log_archive = self.raft_store.archiver.as_ref().map(|a| a.log_status());
 
         let metrics = self.raft.metrics().borrow().clone();
 
@@ -897,6 +920,7 @@ impl MetaNode {
             data_version: DATA_VERSION,
             endpoint: endpoint.to_string(),
             raft_log: raft_log_status,
+            log_archive,
             snapshot_key_count,
             state: format!("{:?}", metrics.state),
             is_leader: metrics.state == openraft::ServerState::Leader,
@@ -1144,18 +1168,12 @@ impl MetaNode {
         }
     }
 
+    /// Add a watcher, returns the changes it missed since `request.start_after_seq`.
     pub(crate) async fn add_watcher(
         &self,
         request: WatchRequest,
         tx: mpsc::Sender<Result<WatchResponse, Status>>,
-    ) -> Result<Arc<StreamSender>, Status> {
-        let stream_sender = self
-            .subscriber_handle
-            .request_blocking(|d: &mut EventSubscriber| d.add_watcher(request, tx))
-            .await
-            .map_err(|_e| Status::internal("EventSubscriber closed"))?
-            .map_err(Status::invalid_argument)?;
-
-        Ok(stream_sender)
+    ) -> Result<(Arc<StreamSender>, Vec<WatchResponse>), Status> {
+        self.subscriber_handle.add_watcher(request, tx).await
     }
 }
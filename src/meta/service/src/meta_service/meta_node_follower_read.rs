@@ -0,0 +1,114 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use anyerror::AnyError;
+use databend_common_base::base::tokio::time::Instant;
+use databend_common_meta_client::MetaGrpcReadReq;
+use databend_common_meta_client::ReadConsistency;
+use databend_common_meta_types::protobuf::StreamItem;
+use databend_common_meta_types::raft_types::LogId;
+use databend_common_meta_types::Endpoint;
+use databend_common_meta_types::InvalidReply;
+use databend_common_meta_types::MetaAPIError;
+use databend_common_meta_types::MetaDataError;
+use databend_common_meta_types::MetaDataReadError;
+use databend_common_meta_types::MetaNetworkError;
+use log::debug;
+use tonic::codegen::BoxStream;
+
+use crate::message::ForwardRequest;
+use crate::message::ForwardRequestBody;
+use crate::message::ForwardResponse;
+use crate::meta_service::meta_leader::read_local;
+use crate::meta_service::MetaNode;
+
+/// Max time to wait for the local state machine to apply a read index.
+const APPLY_READ_INDEX_TIMEOUT: Duration = Duration::from_millis(2_000);
+
+impl MetaNode {
+    /// Serve a read request with the given consistency.
+    ///
+    /// A linearizable read is handled by the leader, and is forwarded to it if this node is not.
+    ///
+    /// A bounded-staleness read is served by this node, be it the leader, a follower or a learner,
+    /// once the local state machine has applied a read index not older than `max_staleness`.
+    /// Only the read index is got from the leader, the data is read locally.
+    #[fastrace::trace]
+    pub async fn handle_read_request(
+        &self,
+        req: MetaGrpcReadReq,
+        consistency: ReadConsistency,
+    ) -> Result<(Option<Endpoint>, BoxStream<StreamItem>), MetaAPIError> {
+        let ReadConsistency::BoundedStaleness { max_staleness } = consistency else {
+            return self
+                .handle_forwardable_request(ForwardRequest::new(1, req))
+                .await;
+        };
+
+        let read_log_id = self.get_read_index(max_staleness).await?;
+        debug!("serve read locally at read index: {:?}", read_log_id);
+
+        if let Some(log_id) = read_log_id {
+            self.raft
+                .wait(Some(APPLY_READ_INDEX_TIMEOUT))
+                .applied_index_at_least(Some(log_id.index), "apply read index")
+                .await
+                .map_err(|e| {
+                    MetaDataError::ReadError(MetaDataReadError::new(
+                        "wait for read index",
+                        log_id,
+                        &e,
+                    ))
+                })?;
+        }
+
+        let strm = read_local(&self.raft_store, req).await?;
+        Ok((None, strm))
+    }
+
+    /// Return the read index got from the leader within `max_staleness`, or get a new one.
+    ///
+    /// The lock is held while getting a new one, so that the concurrent reads share it.
+    async fn get_read_index(&self, max_staleness: Duration) -> Result<Option<LogId>, MetaAPIError> {
+        let mut last = self.last_read_index.lock().await;
+
+        if let Some((got_at, read_log_id)) = *last {
+            if got_at.elapsed() <= max_staleness {
+                return Ok(read_log_id);
+            }
+        }
+
+        let got_at = Instant::now();
+        let (_endpoint, resp) = self
+            .handle_forwardable_request(ForwardRequest::new(1, ForwardRequestBody::ReadIndex))
+            .await?;
+
+        // The leader may reply with an unexpected response, e.g., when running a different version.
+        let read_log_id = match resp {
+            ForwardResponse::ReadIndex(read_log_id) => read_log_id,
+            other => {
+                let inv = InvalidReply::new(
+                    "expect ReadIndex response from leader",
+                    &AnyError::error(format!("got: {:?}", other)),
+                );
+                return Err(MetaNetworkError::from(inv).into());
+            }
+        };
+        *last = Some((got_at, read_log_id));
+
+        Ok(read_log_id)
+    }
+}
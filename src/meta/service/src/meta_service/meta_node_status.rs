@@ -14,6 +14,7 @@
 
 use std::collections::BTreeMap;
 
+use databend_common_meta_raft_store::archive::LogArchiveStatus;
 use databend_common_meta_raft_store::ondisk::DataVersion;
 use databend_common_meta_raft_store::raft_log_v004::RaftLogStat;
 use databend_common_meta_types::raft_types::LogId;
@@ -36,6 +37,9 @@ pub struct MetaNodeStatus {
     /// The status about local raft-log
     pub raft_log: RaftLogStatus,
 
+    /// The progress of archiving raft logs, `None` if archiving is disabled.
+    pub log_archive: Option<LogArchiveStatus>,
+
     /// Total number of keys in current snapshot
     pub snapshot_key_count: u64,
 
@@ -14,6 +14,7 @@
 
 mod errors;
 mod forwarder;
+mod meta_node_follower_read;
 mod meta_node_kv_api_impl;
 
 pub(crate) mod snapshot_receiver_v1;
@@ -63,6 +63,16 @@ impl RaftStateMachine<TypeConfig> for RaftStore {
         I::IntoIter: OptionalSend,
     {
         let mut sm = self.state_machine.write().await;
+
+        let entries = entries.into_iter().collect::<Vec<_>>();
+
+        if let Some(archiver) = &self.archiver {
+            if let Err(e) = archiver.archive_logs(entries.clone()).await {
+                raft_metrics::storage::incr_raft_storage_fail("archive_logs", true);
+                error!(error :% = e; "failed to archive raft logs");
+            }
+        }
+
         let res = sm.apply_entries(entries).await?;
 
         Ok(res)
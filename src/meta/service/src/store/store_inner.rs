@@ -22,6 +22,7 @@ use anyerror::AnyError;
 use databend_common_base::base::tokio;
 use databend_common_base::base::tokio::sync::RwLock;
 use databend_common_base::base::tokio::sync::RwLockWriteGuard;
+use databend_common_meta_raft_store::archive::Archiver;
 use databend_common_meta_raft_store::config::RaftConfig;
 use databend_common_meta_raft_store::key_spaces::RaftStoreEntry;
 use databend_common_meta_raft_store::leveled_store::db_exporter::DBExporter;
@@ -72,6 +73,9 @@ pub struct RaftStoreInner {
 
     /// The Raft state machine.
     pub state_machine: Arc<RwLock<SMV003>>,
+
+    /// Archives applied logs and built snapshots, if `archive_dir` is configured.
+    pub(crate) archiver: Option<Arc<Archiver>>,
 }
 
 impl AsRef<RaftStoreInner> for RaftStoreInner {
@@ -150,12 +154,15 @@ impl RaftStoreInner {
             Default::default()
         };
 
+        let archiver = Archiver::open(config, id).map_err(to_startup_err)?;
+
         let store = Self {
             id,
             config: config.clone(),
             is_opened: is_open,
             log: Arc::new(RwLock::new(log)),
             state_machine: Arc::new(RwLock::new(sm)),
+            archiver: archiver.map(Arc::new),
         };
 
         Ok(store)
@@ -258,6 +265,14 @@ impl RaftStoreInner {
             snapshot_file_size :% = db.file_size(),
             snapshot_stat :% = db.stat(); "do_build_snapshot complete");
 
+        // Archiving is best effort and does not fail building a snapshot.
+        // A missing archived snapshot is fixed by archiving a full one next time.
+        if let Some(archiver) = &self.archiver {
+            if let Err(e) = archiver.archive_snapshot(&mut compactor, &db).await {
+                error!(error :% = e; "failed to archive snapshot: {}", snapshot_id);
+            }
+        }
+
         {
             let mut sm = self.state_machine.write().await;
             sm.levels_mut()
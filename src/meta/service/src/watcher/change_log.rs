@@ -0,0 +1,279 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::fmt;
+
+use databend_common_meta_types::Change;
+
+/// The max number of changes kept by [`ChangeLog`].
+pub(crate) const CHANGE_LOG_CAPACITY: usize = 64 * 1024;
+
+/// The max total size in bytes of the keys and values of the changes kept by [`ChangeLog`].
+pub(crate) const CHANGE_LOG_MAX_BYTES: usize = 64 * 1024 * 1024;
+
+/// A watcher tries to resume from a seq that is no longer kept in the [`ChangeLog`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Compacted {
+    pub(crate) start_after_seq: u64,
+
+    /// Changes with seq `<=` this value are no longer available.
+    pub(crate) unavailable_upto: u64,
+}
+
+impl fmt::Display for Compacted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "watch history compacted: start_after_seq={} but changes upto seq={} are no longer available; re-watch with initial_flush",
+            self.start_after_seq, self.unavailable_upto
+        )
+    }
+}
+
+/// A bounded in-memory log of the most recent kv changes,
+/// for a reconnecting watcher to catch up with the changes it missed.
+///
+/// Every change is tagged with a seq:
+/// an update is tagged with the seq of the new value,
+/// a delete does not consume a seq and is tagged with the seq of the last update before it.
+/// Thus a watcher resuming from `start_after_seq` is replayed every change with seq `>= start_after_seq`,
+/// and it may receive the deletes it has already seen.
+#[derive(Debug)]
+pub(crate) struct ChangeLog {
+    capacity: usize,
+
+    max_bytes: usize,
+
+    /// The total size of the keys and values of the kept changes.
+    bytes: usize,
+
+    /// The changes that are not kept, either evicted or happened before this log is created,
+    /// all have a seq `<=` this value.
+    unavailable_upto: u64,
+
+    /// The seq of the last update.
+    last_seq: u64,
+
+    changes: VecDeque<(u64, Change<Vec<u8>, String>)>,
+}
+
+impl ChangeLog {
+    pub(crate) fn new(capacity: usize, max_bytes: usize) -> Self {
+        Self {
+            capacity,
+            max_bytes,
+            bytes: 0,
+            unavailable_upto: 0,
+            last_seq: 0,
+            changes: VecDeque::new(),
+        }
+    }
+
+    /// Discard all changes, changes upto `seq` are no longer available.
+    ///
+    /// It is called when the state machine is replaced without emitting change events,
+    /// e.g., on startup or when a snapshot is installed.
+    pub(crate) fn reset(&mut self, seq: u64) {
+        self.changes.clear();
+        self.bytes = 0;
+        self.unavailable_upto = seq;
+        self.last_seq = seq;
+    }
+
+    pub(crate) fn append(&mut self, change: Change<Vec<u8>, String>) {
+        if let Some(result) = &change.result {
+            self.last_seq = std::cmp::max(self.last_seq, result.seq);
+        }
+
+        self.bytes += Self::change_size(&change);
+        self.changes.push_back((self.last_seq, change));
+
+        while self.changes.len() > self.capacity || self.bytes > self.max_bytes {
+            let Some((seq, change)) = self.changes.pop_front() else {
+                break;
+            };
+            self.bytes -= Self::change_size(&change);
+            self.unavailable_upto = std::cmp::max(self.unavailable_upto, seq);
+        }
+    }
+
+    fn change_size(change: &Change<Vec<u8>, String>) -> usize {
+        let key = change.ident.as_ref().map_or(0, |k| k.len());
+        let prev = change.prev.as_ref().map_or(0, |v| v.data.len());
+        let result = change.result.as_ref().map_or(0, |v| v.data.len());
+        key + prev + result
+    }
+
+    /// Returns the changes a watcher that has seen changes upto `start_after_seq` should be replayed.
+    pub(crate) fn changes_since(
+        &self,
+        start_after_seq: u64,
+    ) -> Result<impl Iterator<Item = &Change<Vec<u8>, String>>, Compacted> {
+        if start_after_seq <= self.unavailable_upto {
+            return Err(Compacted {
+                start_after_seq,
+                unavailable_upto: self.unavailable_upto,
+            });
+        }
+
+        let it = self
+            .changes
+            .iter()
+            .filter(move |(seq, _)| *seq >= start_after_seq)
+            .map(|(_, change)| change);
+
+        Ok(it)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use databend_common_meta_types::Change;
+    use databend_common_meta_types::SeqV;
+    use pretty_assertions::assert_eq;
+
+    use super::ChangeLog;
+    use super::Compacted;
+
+    fn upsert(key: &str, seq: u64) -> Change<Vec<u8>, String> {
+        Change::new(None, Some(SeqV::new(seq, b"v".to_vec()))).with_id(key.to_string())
+    }
+
+    fn delete(key: &str, prev_seq: u64) -> Change<Vec<u8>, String> {
+        Change::new(Some(SeqV::new(prev_seq, b"v".to_vec())), None).with_id(key.to_string())
+    }
+
+    /// Returns the keys of the replayed changes, with a `-` prefix for a delete.
+    fn replayed(log: &ChangeLog, start_after_seq: u64) -> Result<Vec<String>, Compacted> {
+        let changes = log.changes_since(start_after_seq)?;
+        Ok(changes
+            .map(|c| {
+                let key = c.ident.clone().unwrap();
+                if c.result.is_some() {
+                    key
+                } else {
+                    format!("-{}", key)
+                }
+            })
+            .collect())
+    }
+
+    #[test]
+    fn test_change_log_replay_is_inclusive() -> anyhow::Result<()> {
+        let mut log = ChangeLog::new(10, usize::MAX);
+        log.append(upsert("a", 1));
+        log.append(upsert("b", 2));
+        // A delete is tagged with the seq of the last update, 2.
+        log.append(delete("a", 1));
+        log.append(upsert("c", 3));
+
+        assert_eq!(replayed(&log, 1)?, vec!["a", "b", "-a", "c"]);
+        assert_eq!(replayed(&log, 2)?, vec!["b", "-a", "c"]);
+        assert_eq!(replayed(&log, 3)?, vec!["c"]);
+        assert_eq!(replayed(&log, 4)?, Vec::<String>::new());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_change_log_history_before_creation() -> anyhow::Result<()> {
+        let log = ChangeLog::new(10, usize::MAX);
+
+        assert_eq!(replayed(&log, 0).unwrap_err(), Compacted {
+            start_after_seq: 0,
+            unavailable_upto: 0,
+        });
+        assert_eq!(replayed(&log, 1)?, Vec::<String>::new());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_change_log_capacity_eviction() -> anyhow::Result<()> {
+        let mut log = ChangeLog::new(2, usize::MAX);
+        log.append(upsert("a", 1));
+        log.append(upsert("b", 2));
+        log.append(upsert("c", 3));
+
+        assert_eq!(replayed(&log, 1).unwrap_err(), Compacted {
+            start_after_seq: 1,
+            unavailable_upto: 1,
+        });
+        assert_eq!(replayed(&log, 2)?, vec!["b", "c"]);
+
+        // The deletes are tagged with seq 3, which becomes unavailable once the update at 3 is evicted.
+        log.append(delete("b", 2));
+        log.append(delete("c", 3));
+
+        assert_eq!(replayed(&log, 3).unwrap_err(), Compacted {
+            start_after_seq: 3,
+            unavailable_upto: 3,
+        });
+        assert_eq!(replayed(&log, 4)?, Vec::<String>::new());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_change_log_max_bytes_eviction() -> anyhow::Result<()> {
+        // Every upsert takes 2 bytes: a 1-byte key and a 1-byte value.
+        let mut log = ChangeLog::new(10, 5);
+        log.append(upsert("a", 1));
+        log.append(upsert("b", 2));
+        assert_eq!(replayed(&log, 1)?, vec!["a", "b"]);
+
+        log.append(upsert("c", 3));
+
+        assert_eq!(replayed(&log, 1).unwrap_err(), Compacted {
+            start_after_seq: 1,
+            unavailable_upto: 1,
+        });
+        assert_eq!(replayed(&log, 2)?, vec!["b", "c"]);
+
+        // A delete takes the size of its key and the deleted value.
+        log.append(delete("b", 2));
+
+        assert_eq!(replayed(&log, 3)?, vec!["c", "-b"]);
+        assert_eq!(replayed(&log, 2).unwrap_err(), Compacted {
+            start_after_seq: 2,
+            unavailable_upto: 2,
+        });
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_change_log_reset() -> anyhow::Result<()> {
+        let mut log = ChangeLog::new(10, usize::MAX);
+        log.append(upsert("a", 1));
+        log.append(upsert("b", 2));
+
+        log.reset(10);
+
+        assert_eq!(replayed(&log, 2).unwrap_err(), Compacted {
+            start_after_seq: 2,
+            unavailable_upto: 10,
+        });
+        assert_eq!(replayed(&log, 11)?, Vec::<String>::new());
+
+        // A delete after reset is tagged with the reset seq, which is unavailable.
+        log.append(delete("a", 1));
+        log.append(upsert("c", 11));
+
+        assert_eq!(replayed(&log, 11)?, vec!["c"]);
+
+        Ok(())
+    }
+}
@@ -14,6 +14,7 @@
 
 //! The client watch a key range and get notified when the key range changes.
 
+mod change_log;
 mod command;
 mod desc;
 mod id;
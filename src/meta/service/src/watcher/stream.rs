@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::VecDeque;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::task::Context;
@@ -26,6 +27,8 @@ use crate::watcher::SubscriberHandle;
 /// A wrapper around [`tokio::sync::mpsc::Receiver`] that implements [`Stream`].
 #[derive(Debug)]
 pub(crate) struct WatchStream<T> {
+    /// The items to yield before the ones received from `rx`.
+    replay: VecDeque<T>,
     rx: Receiver<T>,
     // TODO: use a Box<dyn Fn> to replace these two fields
     /// Hold a clone of the sender to remove itself from the dispatcher when dropped.
@@ -46,17 +49,30 @@ impl<T> WatchStream<T> {
     /// Create a new `WatcherStream`.
     pub fn new(rx: Receiver<T>, sender: Arc<StreamSender>, dispatcher: SubscriberHandle) -> Self {
         Self {
+            replay: VecDeque::new(),
             rx,
             sender,
             subscriber_handle: dispatcher,
         }
     }
+
+    /// Yield `replay` before the items received from the channel.
+    ///
+    /// They are sent at the pace the client consumes the stream,
+    /// instead of being pushed into the channel by the dispatcher.
+    pub fn with_replay(mut self, replay: impl IntoIterator<Item = T>) -> Self {
+        self.replay.extend(replay);
+        self
+    }
 }
 
 impl<T> Stream for WatchStream<T> {
     type Item = T;
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if let Some(item) = self.replay.pop_front() {
+            return Poll::Ready(Some(item));
+        }
         self.rx.poll_recv(cx)
     }
 }
@@ -14,6 +14,7 @@
 
 use std::collections::BTreeSet;
 use std::io;
+use std::ops::RangeBounds;
 use std::sync::Arc;
 
 use databend_common_meta_types::protobuf::watch_request::FilterType;
@@ -33,9 +34,14 @@ use tonic::Status;
 
 use crate::metrics::network_metrics;
 use crate::metrics::server_metrics;
+use crate::watcher::change_log::ChangeLog;
+use crate::watcher::change_log::Compacted;
+use crate::watcher::change_log::CHANGE_LOG_CAPACITY;
+use crate::watcher::change_log::CHANGE_LOG_MAX_BYTES;
 use crate::watcher::command::Command;
 use crate::watcher::id::WatcherId;
 use crate::watcher::subscriber_handle::SubscriberHandle;
+use crate::watcher::KeyRange;
 use crate::watcher::StreamSender;
 use crate::watcher::WatchDesc;
 
@@ -47,6 +53,9 @@ pub struct EventSubscriber {
     watchers: SpanMap<String, Arc<StreamSender>>,
 
     current_watcher_id: WatcherId,
+
+    /// The recent changes, for a reconnecting watcher to catch up with.
+    change_log: ChangeLog,
 }
 
 impl EventSubscriber {
@@ -58,6 +67,7 @@ impl EventSubscriber {
             rx,
             watchers: SpanMap::new(),
             current_watcher_id: 1,
+            change_log: ChangeLog::new(CHANGE_LOG_CAPACITY, CHANGE_LOG_MAX_BYTES),
         };
 
         let _h = databend_common_base::runtime::spawn(subscriber.main());
@@ -123,23 +133,13 @@ impl EventSubscriber {
         let resp = WatchResponse::new(&change).unwrap();
         let resp_size = resp.encoded_len() as u64;
 
+        self.change_log.append(change);
+
         let mut removed = vec![];
 
         for sender in self.watchers.get(&key) {
-            let interested = sender.desc.interested;
-
-            match interested {
-                FilterType::All => {}
-                FilterType::Update => {
-                    if is_delete {
-                        continue;
-                    }
-                }
-                FilterType::Delete => {
-                    if !is_delete {
-                        continue;
-                    }
-                }
+            if !Self::is_interested(sender.desc.interested, is_delete) {
+                continue;
             }
 
             if let Err(_err) = sender.send(resp.clone()).await {
@@ -158,6 +158,71 @@ impl EventSubscriber {
         }
     }
 
+    fn is_interested(interested: FilterType, is_delete: bool) -> bool {
+        match interested {
+            FilterType::All => true,
+            FilterType::Update => !is_delete,
+            FilterType::Delete => is_delete,
+        }
+    }
+
+    /// Discard the change log, changes upto `seq` are no longer available to a resuming watcher.
+    pub fn reset_change_log(&mut self, seq: u64) {
+        info!("EventSubscriber::reset_change_log: upto seq={}", seq);
+        self.change_log.reset(seq);
+    }
+
+    /// Build the responses of the logged changes a watcher resuming from `start_after_seq` missed.
+    fn changes_since(
+        &self,
+        start_after_seq: u64,
+        interested: FilterType,
+        key_range: &KeyRange,
+    ) -> Result<Vec<WatchResponse>, Compacted> {
+        let changes = self.change_log.changes_since(start_after_seq)?;
+
+        let resps = changes
+            .filter(|change| {
+                let Some(key) = &change.ident else {
+                    return false;
+                };
+                key_range.contains(key) && Self::is_interested(interested, change.result.is_none())
+            })
+            .map(|change| WatchResponse::new(change).unwrap())
+            .collect();
+
+        Ok(resps)
+    }
+
+    /// Add a watcher, and if `req.start_after_seq` is set,
+    /// returns the changes it missed, which must be sent before any new change.
+    ///
+    /// It returns an `OUT_OF_RANGE` error if the missed changes are no longer kept.
+    #[fastrace::trace]
+    pub fn add_resuming_watcher(
+        &mut self,
+        req: WatchRequest,
+        tx: mpsc::Sender<Result<WatchResponse, Status>>,
+    ) -> Result<(Arc<StreamSender>, Vec<WatchResponse>), Status> {
+        let replay = match req.start_after_seq {
+            None => vec![],
+            Some(start_after_seq) => {
+                let key_range = req.key_range().map_err(Status::invalid_argument)?;
+                self.changes_since(start_after_seq, req.filter_type(), &key_range)
+                    .map_err(|e| {
+                        warn!("EventSubscriber: can not resume watcher: {}", e);
+                        Status::out_of_range(e.to_string())
+                    })?
+            }
+        };
+
+        let stream_sender = self
+            .add_watcher(req, tx)
+            .map_err(Status::invalid_argument)?;
+
+        Ok((stream_sender, replay))
+    }
+
     #[fastrace::trace]
     pub fn add_watcher(
         &mut self,
@@ -13,8 +13,10 @@
 // limitations under the License.
 
 use std::io;
+use std::sync::Arc;
 
 use databend_common_meta_raft_store::state_machine_api::SMEventSender;
+use databend_common_meta_types::protobuf::WatchRequest;
 use databend_common_meta_types::protobuf::WatchResponse;
 use databend_common_meta_types::Change;
 use databend_common_meta_types::SeqV;
@@ -27,6 +29,7 @@ use tonic::Status;
 
 use crate::watcher::command::Command;
 use crate::watcher::EventSubscriber;
+use crate::watcher::StreamSender;
 
 #[derive(Clone, Debug)]
 pub struct SubscriberHandle {
@@ -50,6 +53,10 @@ impl SMEventSender for SubscriberHandle {
             })
             .ok();
     }
+
+    fn reset_history(&self, seq: u64) {
+        self.request(move |d| d.reset_change_log(seq));
+    }
 }
 
 impl SubscriberHandle {
@@ -62,6 +69,21 @@ impl SubscriberHandle {
         let _ = self.tx.send(Command::Request { req: Box::new(req) });
     }
 
+    /// Add a watcher, and return the changes it missed since `req.start_after_seq`.
+    ///
+    /// The missed changes are collected when the watcher is added,
+    /// the caller must send them before any change received from the watcher's channel.
+    /// They are not sent by the dispatcher, which would block dispatching to other watchers.
+    pub(crate) async fn add_watcher(
+        &self,
+        req: WatchRequest,
+        tx: mpsc::Sender<Result<WatchResponse, Status>>,
+    ) -> Result<(Arc<StreamSender>, Vec<WatchResponse>), Status> {
+        self.request_blocking(move |d| d.add_resuming_watcher(req, tx))
+            .await
+            .map_err(|_e| Status::internal("EventSubscriber closed"))?
+    }
+
     /// Send a request to the watch dispatcher and block until finished
     pub async fn request_blocking<V>(
         &self,
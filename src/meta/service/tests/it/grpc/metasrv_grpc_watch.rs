@@ -35,6 +35,7 @@ use databend_common_meta_types::txn_condition;
 use databend_common_meta_types::txn_op;
 use databend_common_meta_types::ConditionResult;
 use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::MetaError;
 use databend_common_meta_types::Operation;
 use databend_common_meta_types::TxnCondition;
 use databend_common_meta_types::TxnDeleteByPrefixRequest;
@@ -128,6 +129,7 @@ async fn test_watch_single_key() -> anyhow::Result<()> {
         key_end: None,
         filter_type: FilterType::All.into(),
         initial_flush: false,
+        start_after_seq: None,
     };
 
     let key_a = s("a");
@@ -172,6 +174,7 @@ async fn test_watch() -> anyhow::Result<()> {
             key_end: Some("z".to_string()),
             filter_type: FilterType::All.into(),
             initial_flush: false,
+            start_after_seq: None,
         };
 
         let key_a = s("a");
@@ -231,6 +234,7 @@ async fn test_watch() -> anyhow::Result<()> {
             // filter only delete events
             filter_type: FilterType::Delete.into(),
             initial_flush: false,
+            start_after_seq: None,
         };
 
         let key = s(key_str);
@@ -295,6 +299,7 @@ async fn test_watch() -> anyhow::Result<()> {
             key_end: Some(end),
             filter_type: FilterType::All.into(),
             initial_flush: false,
+            start_after_seq: None,
         };
 
         let conditions = vec![TxnCondition {
@@ -364,6 +369,7 @@ async fn test_watch_initial_flush() -> anyhow::Result<()> {
             key_end: Some(s("e")),
             filter_type: FilterType::All.into(),
             initial_flush: true,
+            start_after_seq: None,
         };
         client.request(watch).await?
     };
@@ -411,6 +417,54 @@ async fn test_watch_initial_flush() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test(harness = meta_service_test_harness)]
+#[fastrace::trace]
+async fn test_watch_start_after_seq() -> anyhow::Result<()> {
+    // - Write some data before watching.
+    // - Watch from a seq, the missed changes are replayed, then new changes follow.
+    // - Watch from a seq that is no longer kept fails with a compacted error.
+
+    let (tc, _addr) = crate::tests::start_metasrv().await?;
+
+    let client = tc.grpc_client().await?;
+    client.upsert_kv(UpsertKV::update("a", b"a")).await?; // seq=1
+    client.upsert_kv(UpsertKV::update("b", b"b")).await?; // seq=2
+    client.upsert_kv(UpsertKV::delete("a")).await?;
+    client.upsert_kv(UpsertKV::update("c", b"c")).await?; // seq=3
+    client.upsert_kv(UpsertKV::update("z", b"z")).await?; // seq=4, out of range
+
+    info!("--- resume from seq=2");
+    {
+        let watch = WatchRequest::new(s("a"), Some(s("e"))).with_start_after_seq(2);
+        let mut strm = client.request(watch).await?;
+
+        client.upsert_kv(UpsertKV::update("d", b"d")).await?; // seq=5
+
+        let want = vec![
+            add_event("b", 2, "b", None),
+            del_event("a", 1, "a", None),
+            add_event("c", 3, "c", None),
+            add_event("d", 5, "d", None),
+        ];
+
+        for ev in want {
+            let msg = strm.message().await?.unwrap();
+            assert_eq!(Some(ev), msg.event);
+        }
+    }
+
+    info!("--- resume from a seq before the server started");
+    {
+        let watch = WatchRequest::new(s("a"), Some(s("e"))).with_start_after_seq(0);
+        let res: Result<_, MetaError> = client.request(watch).await;
+
+        let err = res.unwrap_err();
+        assert!(err.to_string().contains("watch history compacted"));
+    }
+
+    Ok(())
+}
+
 #[test(harness = meta_service_test_harness)]
 #[fastrace::trace]
 async fn test_watch_expired_events() -> anyhow::Result<()> {
@@ -463,6 +517,7 @@ async fn test_watch_expired_events() -> anyhow::Result<()> {
             key_end: Some(end),
             filter_type: FilterType::All.into(),
             initial_flush: false,
+            start_after_seq: None,
         };
         watch_client.request(watch).await?
     };
@@ -560,6 +615,7 @@ async fn test_watch_stream_count() -> anyhow::Result<()> {
         key_end: Some("z".to_string()),
         filter_type: FilterType::All.into(),
         initial_flush: false,
+        start_after_seq: None,
     };
 
     let client1 = make_client(&addr)?;
@@ -16,6 +16,9 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use databend_common_base::base::tokio::time::sleep;
+use databend_common_meta_client::MetaGrpcReadReq;
+use databend_common_meta_client::ReadConsistency;
+use databend_common_meta_kvapi::kvapi::GetKVReq;
 use databend_common_meta_kvapi::kvapi::KVApi;
 use databend_common_meta_sled_store::openraft::LogIdOptionExt;
 use databend_common_meta_sled_store::openraft::RaftLogReader;
@@ -33,6 +36,7 @@ use databend_meta::message::ForwardRequestBody;
 use databend_meta::message::JoinRequest;
 use databend_meta::message::LeaveRequest;
 use databend_meta::meta_service::MetaNode;
+use futures::TryStreamExt;
 use log::info;
 use maplit::btreeset;
 use pretty_assertions::assert_eq;
@@ -211,6 +215,74 @@ async fn test_meta_node_join() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test(harness = meta_service_test_harness)]
+#[fastrace::trace]
+async fn test_meta_node_join_as_learner() -> anyhow::Result<()> {
+    // - Bring up a leader.
+    // - Join a new node as a learner.
+    // - Write on the leader and read on the learner with bounded staleness.
+
+    let (_nid0, tc0) = start_meta_node_leader().await?;
+    let leader = tc0.meta_node();
+
+    info!("--- join learner 1 to cluster by leader");
+
+    let node_id = 1;
+    let tc1 = MetaSrvTestContext::new(node_id);
+    let learner = MetaNode::open(&tc1.config.raft_config).await?;
+    {
+        let admin_req = ForwardRequest {
+            forward_to_leader: 0,
+            body: ForwardRequestBody::Join(
+                JoinRequest::new(
+                    node_id,
+                    tc1.config.raft_config.raft_api_addr().await?,
+                    tc1.config.grpc_api_advertise_address(),
+                )
+                .with_learner(true),
+            ),
+        };
+        leader.handle_forwardable_request(admin_req).await?;
+    }
+
+    info!("--- check node-1 joined as a learner");
+
+    for mn in [&leader, &learner] {
+        mn.raft
+            .wait(timeout())
+            .metrics(
+                |m| {
+                    let membership = m.membership_config.membership();
+                    membership.voter_ids().eq([0]) && membership.learner_ids().eq([1])
+                },
+                format!("node-1 is a learner: {}", mn.raft_store.id),
+            )
+            .await?;
+    }
+
+    info!("--- write on leader, read on learner");
+
+    leader.upsert_kv(UpsertKV::update("foo", b"bar")).await?;
+
+    let (endpoint, strm) = learner
+        .handle_read_request(
+            MetaGrpcReadReq::GetKV(GetKVReq {
+                key: "foo".to_string(),
+            }),
+            ReadConsistency::BoundedStaleness {
+                max_staleness: Duration::ZERO,
+            },
+        )
+        .await?;
+    assert!(endpoint.is_none(), "the read is served by the learner");
+
+    let items = strm.try_collect::<Vec<_>>().await?;
+    assert_eq!(1, items.len());
+    assert_eq!(b"bar".to_vec(), items[0].value.as_ref().unwrap().data);
+
+    Ok(())
+}
+
 #[test(harness = meta_service_test_harness)]
 #[fastrace::trace]
 async fn test_meta_node_join_rejoin() -> anyhow::Result<()> {
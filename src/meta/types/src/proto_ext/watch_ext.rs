@@ -45,6 +45,7 @@ impl WatchRequest {
             key_end,
             filter_type: FilterType::All as _,
             initial_flush: false,
+            start_after_seq: None,
         }
     }
 
@@ -53,6 +54,12 @@ impl WatchRequest {
         self
     }
 
+    /// Resume watching from the changes after `seq`, which is the last seq the client has seen.
+    pub fn with_start_after_seq(mut self, seq: u64) -> Self {
+        self.start_after_seq = Some(seq);
+        self
+    }
+
     pub fn key_range(&self) -> Result<(Bound<String>, Bound<String>), &'static str> {
         Self::build_key_range(&self.key, &self.key_end)
     }
@@ -234,6 +234,20 @@ pub(crate) fn write_comma_separated_list(
     Ok(())
 }
 
+/// Write input items into `a b c`
+pub(crate) fn write_space_separated_list(
+    f: &mut Formatter,
+    items: impl IntoIterator<Item = impl Display>,
+) -> std::fmt::Result {
+    for (i, item) in items.into_iter().enumerate() {
+        if i > 0 {
+            write!(f, " ")?;
+        }
+        write!(f, "{item}")?;
+    }
+    Ok(())
+}
+
 /// Write input items into `'a', 'b', 'c'`
 pub(crate) fn write_comma_separated_string_list(
     f: &mut Formatter,
@@ -22,6 +22,7 @@ use crate::ast::statements::show::ShowLimit;
 use crate::ast::write_dot_separated_list;
 use crate::ast::CreateOption;
 use crate::ast::DatabaseRef;
+use crate::ast::FileLocation;
 use crate::ast::Identifier;
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
@@ -90,6 +91,7 @@ pub struct CreateDatabaseStmt {
     pub database: DatabaseRef,
     pub engine: Option<DatabaseEngine>,
     pub options: Vec<SQLProperty>,
+    pub clone_from: Option<DatabaseRef>,
 }
 
 impl Display for CreateDatabaseStmt {
@@ -109,6 +111,10 @@ impl Display for CreateDatabaseStmt {
             write!(f, " ENGINE = {engine}")?;
         }
 
+        if let Some(clone_from) = &self.clone_from {
+            write!(f, " CLONE {clone_from}")?;
+        }
+
         // TODO(leiysky): display rest information
         Ok(())
     }
@@ -147,6 +153,42 @@ impl Display for UndropDatabaseStmt {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub struct BackupDatabaseStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Identifier,
+    pub location: FileLocation,
+}
+
+impl Display for BackupDatabaseStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "BACKUP DATABASE ")?;
+        write_dot_separated_list(f, self.catalog.iter().chain(Some(&self.database)))?;
+        write!(f, " TO {}", self.location)?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub struct RestoreDatabaseStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Identifier,
+    pub location: FileLocation,
+    pub new_database: Option<Identifier>,
+}
+
+impl Display for RestoreDatabaseStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "RESTORE DATABASE ")?;
+        write_dot_separated_list(f, self.catalog.iter().chain(Some(&self.database)))?;
+        write!(f, " FROM {}", self.location)?;
+        if let Some(new_database) = &self.new_database {
+            write!(f, " AS {new_database}")?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
 pub struct AlterDatabaseStmt {
     pub if_exists: bool,
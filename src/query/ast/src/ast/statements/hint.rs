@@ -18,12 +18,14 @@ use std::fmt::Formatter;
 use derive_visitor::Drive;
 use derive_visitor::DriveMut;
 
+use crate::ast::write_space_separated_list;
 use crate::ast::Expr;
 use crate::ast::Identifier;
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub struct Hint {
     pub hints_list: Vec<HintItem>,
+    pub optimizer_hints: Vec<OptimizerHint>,
 }
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
@@ -32,6 +34,52 @@ pub struct HintItem {
     pub expr: Expr,
 }
 
+/// Hints that steer the optimizer, they are ignored if they can not be applied to the plan.
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub enum OptimizerHint {
+    /// Join the tables first, in the given order.
+    Leading(Vec<Identifier>),
+    /// Use hash join for the joins of the tables, or of all joins if no table is given.
+    HashJoin(Vec<Identifier>),
+    /// Use merge join for the range joins of the tables, or of all range joins.
+    MergeJoin(Vec<Identifier>),
+    /// Use nested loop join for the joins of the tables, or of all joins.
+    NlJoin(Vec<Identifier>),
+    /// Broadcast the tables to all nodes when they are joined.
+    Broadcast(Vec<Identifier>),
+    /// Shuffle both sides by the join keys when the tables are joined.
+    Shuffle(Vec<Identifier>),
+    /// Do not rewrite correlated subqueries into semi or anti joins.
+    NoDecorrelate,
+}
+
+impl OptimizerHint {
+    /// The tables named by the hint.
+    pub fn tables(&self) -> &[Identifier] {
+        match self {
+            OptimizerHint::Leading(tables)
+            | OptimizerHint::HashJoin(tables)
+            | OptimizerHint::MergeJoin(tables)
+            | OptimizerHint::NlJoin(tables)
+            | OptimizerHint::Broadcast(tables)
+            | OptimizerHint::Shuffle(tables) => tables,
+            OptimizerHint::NoDecorrelate => &[],
+        }
+    }
+
+    pub fn tables_mut(&mut self) -> &mut [Identifier] {
+        match self {
+            OptimizerHint::Leading(tables)
+            | OptimizerHint::HashJoin(tables)
+            | OptimizerHint::MergeJoin(tables)
+            | OptimizerHint::NlJoin(tables)
+            | OptimizerHint::Broadcast(tables)
+            | OptimizerHint::Shuffle(tables) => tables,
+            OptimizerHint::NoDecorrelate => &mut [],
+        }
+    }
+}
+
 impl Display for Hint {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         write!(f, "/*+ ")?;
@@ -42,6 +90,34 @@ impl Display for Hint {
             write!(f, "{}", hint.expr)?;
             write!(f, ")")?;
         }
+        for (i, hint) in self.optimizer_hints.iter().enumerate() {
+            if i > 0 || !self.hints_list.is_empty() {
+                write!(f, " ")?;
+            }
+            write!(f, "{}", hint)?;
+        }
         write!(f, "*/")
     }
 }
+
+impl Display for OptimizerHint {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let name = match self {
+            OptimizerHint::Leading(_) => "LEADING",
+            OptimizerHint::HashJoin(_) => "HASH_JOIN",
+            OptimizerHint::MergeJoin(_) => "MERGE_JOIN",
+            OptimizerHint::NlJoin(_) => "NL_JOIN",
+            OptimizerHint::Broadcast(_) => "BROADCAST",
+            OptimizerHint::Shuffle(_) => "SHUFFLE",
+            OptimizerHint::NoDecorrelate => "NO_DECORRELATE",
+        };
+        write!(f, "{name}")?;
+        let tables = self.tables();
+        if !tables.is_empty() {
+            write!(f, "(")?;
+            write_space_separated_list(f, tables)?;
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
@@ -34,6 +34,7 @@ mod network_policy;
 mod notification;
 mod password_policy;
 mod pipe;
+mod plan_baseline;
 mod presign;
 mod principal;
 mod priority;
@@ -79,6 +80,7 @@ pub use network_policy::*;
 pub use notification::*;
 pub use password_policy::*;
 pub use pipe::*;
+pub use plan_baseline::*;
 pub use presign::*;
 pub use principal::*;
 pub use priority::*;
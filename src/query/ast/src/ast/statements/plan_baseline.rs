@@ -0,0 +1,50 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use derive_visitor::Drive;
+use derive_visitor::DriveMut;
+
+use crate::ast::Query;
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct AlterQueryStmt {
+    pub query: Box<Query>,
+    pub action: AlterQueryAction,
+}
+
+impl Display for AlterQueryStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "ALTER QUERY {} {}", self.query, self.action)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub enum AlterQueryAction {
+    /// Pin the current plan of the query as its baseline.
+    PinPlan,
+    /// Drop the baseline of the query.
+    UnpinPlan,
+}
+
+impl Display for AlterQueryAction {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            AlterQueryAction::PinPlan => write!(f, "PIN PLAN"),
+            AlterQueryAction::UnpinPlan => write!(f, "UNPIN PLAN"),
+        }
+    }
+}
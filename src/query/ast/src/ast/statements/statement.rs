@@ -153,6 +153,8 @@ pub enum Statement {
     DropDatabase(DropDatabaseStmt),
     UndropDatabase(UndropDatabaseStmt),
     AlterDatabase(AlterDatabaseStmt),
+    BackupDatabase(BackupDatabaseStmt),
+    RestoreDatabase(RestoreDatabaseStmt),
     UseDatabase {
         database: Identifier,
     },
@@ -193,6 +195,12 @@ pub enum Statement {
     DropView(DropViewStmt),
     ShowViews(ShowViewsStmt),
     DescribeView(DescribeViewStmt),
+    CreateMaterializedView(CreateMaterializedViewStmt),
+    DropMaterializedView(DropMaterializedViewStmt),
+    RefreshMaterializedView(RefreshMaterializedViewStmt),
+
+    // Plan baselines
+    AlterQuery(AlterQueryStmt),
 
     // Streams
     CreateStream(CreateStreamStmt),
@@ -329,6 +337,9 @@ pub enum Statement {
     Begin,
     Commit,
     Abort,
+    Savepoint(Identifier),
+    RollbackToSavepoint(Identifier),
+    ReleaseSavepoint(Identifier),
 
     // Notifications
     CreateNotification(CreateNotificationStmt),
@@ -453,6 +464,7 @@ impl Statement {
             | Statement::DescribeView(..)
             | Statement::ShowStreams(..)
             | Statement::DescribeStream(..)
+            | Statement::RefreshMaterializedView(..)
             | Statement::RefreshIndex(..)
             | Statement::RefreshInvertedIndex(..)
             | Statement::RefreshVirtualColumn(..)
@@ -482,6 +494,15 @@ impl Statement {
             | Statement::Begin
             | Statement::Commit
             | Statement::Abort
+            | Statement::Savepoint(..)
+            | Statement::RollbackToSavepoint(..)
+            | Statement::ReleaseSavepoint(..)
+            | Statement::CreateTable(..)
+            | Statement::CreateView(..)
+            | Statement::AlterView(..)
+            | Statement::DropView(..)
+            | Statement::RenameTable(..)
+            | Statement::TruncateTable(..)
             | Statement::DescribeNotification(..)
             | Statement::ExecuteImmediate(..)
             | Statement::ShowProcedures { .. }
@@ -491,31 +512,41 @@ impl Statement {
             | Statement::ShowOnlineNodes(..)
             | Statement::InspectWarehouse(..) => true,
 
+            // changes of columns, names and options are buffered in the transaction like DML
+            Statement::AlterTable(stmt) => matches!(
+                stmt.action,
+                AlterTableAction::AddColumn { .. }
+                    | AlterTableAction::RenameColumn { .. }
+                    | AlterTableAction::DropColumn { .. }
+                    | AlterTableAction::RenameTable { .. }
+                    | AlterTableAction::SetOptions { .. }
+                    | AlterTableAction::UnsetOptions { .. }
+            ),
+            // `DROP TABLE ... ALL` purges the data at once
+            Statement::DropTable(stmt) => !stmt.all,
+
             Statement::CreateDatabase(..)
-            | Statement::CreateTable(..)
-            | Statement::CreateView(..)
+            | Statement::CreateMaterializedView(..)
             | Statement::CreateIndex(..)
             | Statement::CreateStage(..)
             | Statement::CreateSequence(..)
             | Statement::CreateDictionary(..)
             | Statement::CreateConnection(..)
             | Statement::CreatePipe(..)
-            | Statement::AlterTable(..)
-            | Statement::AlterView(..)
+            | Statement::AlterQuery(..)
             | Statement::AlterUser(..)
             | Statement::AlterDatabase(..)
             | Statement::DropDatabase(..)
-            | Statement::DropTable(..)
-            | Statement::DropView(..)
+            | Statement::DropMaterializedView(..)
             | Statement::DropIndex(..)
             | Statement::DropSequence(..)
             | Statement::DropDictionary(..)
-            | Statement::TruncateTable(..)
             | Statement::AttachTable(..)
-            | Statement::RenameTable(..)
             | Statement::CreateCatalog(..)
             | Statement::DropCatalog(..)
             | Statement::UndropDatabase(..)
+            | Statement::BackupDatabase(..)
+            | Statement::RestoreDatabase(..)
             | Statement::UndropTable(..)
             | Statement::RenameDictionary(..)
             | Statement::CreateStream(..)
@@ -577,7 +608,10 @@ impl Statement {
     pub fn is_transaction_command(&self) -> bool {
         matches!(
             self,
-            Statement::Commit | Statement::Abort | Statement::Begin
+            Statement::Commit
+                | Statement::Abort
+                | Statement::Begin
+                | Statement::RollbackToSavepoint(..)
         )
     }
 }
@@ -760,6 +794,8 @@ impl Display for Statement {
             Statement::DropDatabase(stmt) => write!(f, "{stmt}")?,
             Statement::UndropDatabase(stmt) => write!(f, "{stmt}")?,
             Statement::AlterDatabase(stmt) => write!(f, "{stmt}")?,
+            Statement::BackupDatabase(stmt) => write!(f, "{stmt}")?,
+            Statement::RestoreDatabase(stmt) => write!(f, "{stmt}")?,
             Statement::UseDatabase { database } => write!(f, "USE {database}")?,
             Statement::ShowTables(stmt) => write!(f, "{stmt}")?,
             Statement::ShowColumns(stmt) => write!(f, "{stmt}")?,
@@ -790,6 +826,10 @@ impl Display for Statement {
             Statement::DropView(stmt) => write!(f, "{stmt}")?,
             Statement::ShowViews(stmt) => write!(f, "{stmt}")?,
             Statement::DescribeView(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateMaterializedView(stmt) => write!(f, "{stmt}")?,
+            Statement::DropMaterializedView(stmt) => write!(f, "{stmt}")?,
+            Statement::RefreshMaterializedView(stmt) => write!(f, "{stmt}")?,
+            Statement::AlterQuery(stmt) => write!(f, "{stmt}")?,
             Statement::CreateStream(stmt) => write!(f, "{stmt}")?,
             Statement::DropStream(stmt) => write!(f, "{stmt}")?,
             Statement::ShowStreams(stmt) => write!(f, "{stmt}")?,
@@ -951,6 +991,9 @@ impl Display for Statement {
             Statement::Begin => write!(f, "BEGIN")?,
             Statement::Commit => write!(f, "COMMIT")?,
             Statement::Abort => write!(f, "ABORT")?,
+            Statement::Savepoint(name) => write!(f, "SAVEPOINT {name}")?,
+            Statement::RollbackToSavepoint(name) => write!(f, "ROLLBACK TO SAVEPOINT {name}")?,
+            Statement::ReleaseSavepoint(name) => write!(f, "RELEASE SAVEPOINT {name}")?,
             Statement::CreateNotification(stmt) => write!(f, "{stmt}")?,
             Statement::AlterNotification(stmt) => write!(f, "{stmt}")?,
             Statement::DropNotification(stmt) => write!(f, "{stmt}")?,
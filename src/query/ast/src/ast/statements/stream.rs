@@ -32,6 +32,7 @@ pub struct CreateStreamStmt {
     pub stream: Identifier,
     pub table_database: Option<Identifier>,
     pub table: Identifier,
+    pub on_view: bool,
     pub travel_point: Option<TimeTravelPoint>,
     pub append_only: bool,
     pub comment: Option<String>,
@@ -54,7 +55,11 @@ impl Display for CreateStreamStmt {
                 .chain(self.database.iter())
                 .chain(Some(&self.stream)),
         )?;
-        write!(f, " ON TABLE ")?;
+        if self.on_view {
+            write!(f, " ON VIEW ")?;
+        } else {
+            write!(f, " ON TABLE ")?;
+        }
         write_dot_separated_list(f, self.table_database.iter().chain(Some(&self.table)))?;
         if let Some(travel_point) = &self.travel_point {
             write!(f, " AT {}", travel_point)?;
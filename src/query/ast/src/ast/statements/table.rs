@@ -271,24 +271,38 @@ impl Display for AttachTableStmt {
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub enum CreateTableSource {
-    Columns(Vec<ColumnDefinition>, Option<Vec<InvertedIndexDefinition>>),
+    Columns(
+        Vec<ColumnDefinition>,
+        Option<Vec<InvertedIndexDefinition>>,
+        Option<Vec<ConstraintDefinition>>,
+    ),
     Like {
         catalog: Option<Identifier>,
         database: Option<Identifier>,
         table: Identifier,
     },
+    Clone {
+        catalog: Option<Identifier>,
+        database: Option<Identifier>,
+        table: Identifier,
+        travel_point: Option<TimeTravelPoint>,
+    },
 }
 
 impl Display for CreateTableSource {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match self {
-            CreateTableSource::Columns(columns, inverted_indexes) => {
+            CreateTableSource::Columns(columns, inverted_indexes, constraints) => {
                 write!(f, "(")?;
                 write_comma_separated_list(f, columns)?;
                 if let Some(inverted_indexes) = inverted_indexes {
                     write!(f, ", ")?;
                     write_comma_separated_list(f, inverted_indexes)?;
                 }
+                if let Some(constraints) = constraints {
+                    write!(f, ", ")?;
+                    write_comma_separated_list(f, constraints)?;
+                }
                 write!(f, ")")
             }
             CreateTableSource::Like {
@@ -299,6 +313,19 @@ impl Display for CreateTableSource {
                 write!(f, "LIKE ")?;
                 write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                travel_point,
+            } => {
+                write!(f, "CLONE ")?;
+                write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))?;
+                if let Some(travel_point) = travel_point {
+                    write!(f, " AT {travel_point}")?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -430,6 +457,25 @@ pub enum AlterTableAction {
     UnsetOptions {
         targets: Vec<Identifier>,
     },
+    SetTtl {
+        ttl: Expr,
+    },
+    UnsetTtl,
+    AddConstraint {
+        constraint: ConstraintDefinition,
+    },
+    DropConstraint {
+        name: Identifier,
+    },
+    ValidateConstraint {
+        name: Identifier,
+    },
+    CacheWarmup {
+        columns: Option<Vec<Identifier>>,
+        selection: Option<Expr>,
+        pin: bool,
+    },
+    CacheUnpin,
 }
 
 impl Display for AlterTableAction {
@@ -499,6 +545,42 @@ impl Display for AlterTableAction {
                     write!(f, ")")?;
                 }
             }
+            AlterTableAction::SetTtl { ttl } => {
+                write!(f, "SET TTL = {ttl}")?;
+            }
+            AlterTableAction::UnsetTtl => {
+                write!(f, "UNSET TTL")?;
+            }
+            AlterTableAction::AddConstraint { constraint } => {
+                write!(f, "ADD {constraint}")?;
+            }
+            AlterTableAction::DropConstraint { name } => {
+                write!(f, "DROP CONSTRAINT {name}")?;
+            }
+            AlterTableAction::ValidateConstraint { name } => {
+                write!(f, "VALIDATE CONSTRAINT {name}")?;
+            }
+            AlterTableAction::CacheWarmup {
+                columns,
+                selection,
+                pin,
+            } => {
+                write!(f, "CACHE WARMUP")?;
+                if let Some(columns) = columns {
+                    write!(f, " COLUMNS (")?;
+                    write_comma_separated_list(f, columns)?;
+                    write!(f, ")")?;
+                }
+                if let Some(conditions) = selection {
+                    write!(f, " WHERE {conditions}")?;
+                }
+                if *pin {
+                    write!(f, " PIN")?;
+                }
+            }
+            AlterTableAction::CacheUnpin => {
+                write!(f, "CACHE UNPIN")?;
+            }
         };
         Ok(())
     }
@@ -684,6 +766,8 @@ pub struct AnalyzeTableStmt {
     pub catalog: Option<Identifier>,
     pub database: Option<Identifier>,
     pub table: Identifier,
+    /// columns of `COMPUTE STATISTICS ON (...)`, whose joint statistics are collected
+    pub columns: Vec<Identifier>,
 }
 
 impl Display for AnalyzeTableStmt {
@@ -696,6 +780,11 @@ impl Display for AnalyzeTableStmt {
                 .chain(&self.database)
                 .chain(Some(&self.table)),
         )?;
+        if !self.columns.is_empty() {
+            write!(f, " COMPUTE STATISTICS ON (")?;
+            write_comma_separated_list(f, &self.columns)?;
+            write!(f, ")")?;
+        }
 
         Ok(())
     }
@@ -845,6 +934,13 @@ pub enum ColumnExpr {
     Default(Box<Expr>),
     Virtual(Box<Expr>),
     Stored(Box<Expr>),
+    /// An `AUTOINCREMENT` / `IDENTITY` column, filled from a sequence owned by the table.
+    /// The values of an `ALWAYS` generated column can not be given explicitly.
+    Identity {
+        always: bool,
+        start: u64,
+        increment: u64,
+    },
 }
 
 impl Display for ColumnExpr {
@@ -859,6 +955,23 @@ impl Display for ColumnExpr {
             ColumnExpr::Stored(expr) => {
                 write!(f, " AS ({expr}) STORED")?;
             }
+            ColumnExpr::Identity {
+                always: true,
+                start,
+                increment,
+            } => {
+                write!(
+                    f,
+                    " GENERATED ALWAYS AS IDENTITY START {start} INCREMENT {increment}"
+                )?;
+            }
+            ColumnExpr::Identity {
+                always: false,
+                start,
+                increment,
+            } => {
+                write!(f, " AUTOINCREMENT START {start} INCREMENT {increment}")?;
+            }
         }
         Ok(())
     }
@@ -875,6 +988,7 @@ pub struct ColumnDefinition {
     pub name: Identifier,
     pub data_type: TypeName,
     pub expr: Option<ColumnExpr>,
+    pub constraints: Vec<ConstraintDefinition>,
     pub comment: Option<String>,
 }
 
@@ -884,6 +998,9 @@ impl Display for ColumnDefinition {
         if let Some(expr) = &self.expr {
             write!(f, "{expr}")?;
         }
+        for constraint in &self.constraints {
+            write!(f, " {constraint}")?;
+        }
         if let Some(comment) = &self.comment {
             write!(f, " COMMENT '{comment}'")?;
         }
@@ -919,10 +1036,88 @@ impl Display for InvertedIndexDefinition {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct ConstraintDefinition {
+    pub name: Option<Identifier>,
+    pub constraint: ConstraintType,
+}
+
+impl Display for ConstraintDefinition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = &self.name {
+            write!(f, "CONSTRAINT {name} ")?;
+        }
+        write!(f, "{}", self.constraint)
+    }
+}
+
+/// The column lists of the key constraints are empty when they are declared on a column.
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub enum ConstraintType {
+    Check(Expr),
+    PrimaryKey(Vec<Identifier>),
+    Unique(Vec<Identifier>),
+    ForeignKey {
+        columns: Vec<Identifier>,
+        reference: ForeignKeyReference,
+    },
+}
+
+impl Display for ConstraintType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConstraintType::Check(expr) => return write!(f, "CHECK ({expr})"),
+            ConstraintType::PrimaryKey(columns) => {
+                write!(f, "PRIMARY KEY")?;
+                write_column_list(f, columns)?;
+            }
+            ConstraintType::Unique(columns) => {
+                write!(f, "UNIQUE")?;
+                write_column_list(f, columns)?;
+            }
+            ConstraintType::ForeignKey { columns, reference } => {
+                if !columns.is_empty() {
+                    write!(f, "FOREIGN KEY")?;
+                    write_column_list(f, columns)?;
+                    write!(f, " ")?;
+                }
+                write!(f, "{reference}")?;
+            }
+        }
+        write!(f, " NOT ENFORCED")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct ForeignKeyReference {
+    pub database: Option<Identifier>,
+    pub table: Identifier,
+    /// Empty if the primary key of the referenced table is referenced.
+    pub columns: Vec<Identifier>,
+}
+
+impl Display for ForeignKeyReference {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "REFERENCES ")?;
+        write_dot_separated_list(f, self.database.iter().chain(Some(&self.table)))?;
+        write_column_list(f, &self.columns)
+    }
+}
+
+fn write_column_list(f: &mut Formatter<'_>, columns: &[Identifier]) -> std::fmt::Result {
+    if !columns.is_empty() {
+        write!(f, " (")?;
+        write_comma_separated_list(f, columns)?;
+        write!(f, ")")?;
+    }
+    Ok(())
+}
+
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub enum CreateDefinition {
     Column(ColumnDefinition),
     InvertedIndex(InvertedIndexDefinition),
+    Constraint(ConstraintDefinition),
 }
 
 impl Display for CreateDefinition {
@@ -934,6 +1129,9 @@ impl Display for CreateDefinition {
             CreateDefinition::InvertedIndex(inverted_index_def) => {
                 write!(f, "{}", inverted_index_def)?;
             }
+            CreateDefinition::Constraint(constraint_def) => {
+                write!(f, "{}", constraint_def)?;
+            }
         }
         Ok(())
     }
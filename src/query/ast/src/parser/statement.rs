@@ -51,6 +51,7 @@ pub type ShareDatabaseParams = (ShareNameIdent, Identifier);
 #[derive(Clone)]
 pub enum CreateDatabaseOption {
     DatabaseEngine(DatabaseEngine),
+    Clone(DatabaseRef),
 }
 
 pub fn statement_body(i: Input) -> IResult<Statement> {
@@ -708,6 +709,16 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                         database,
                         engine: Some(engine),
                         options: vec![],
+                        clone_from: None,
+                    })
+                }
+                Some(CreateDatabaseOption::Clone(clone_from)) => {
+                    Statement::CreateDatabase(CreateDatabaseStmt {
+                        create_option,
+                        database,
+                        engine: None,
+                        options: vec![],
+                        clone_from: Some(clone_from),
                     })
                 }
                 None => Statement::CreateDatabase(CreateDatabaseStmt {
@@ -715,6 +726,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                     database,
                     engine: None,
                     options: vec![],
+                    clone_from: None,
                 }),
             };
 
@@ -744,6 +756,33 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         },
     );
 
+    let backup_database = map(
+        rule! {
+            BACKUP ~ DATABASE ~ #dot_separated_idents_1_to_2 ~ TO ~ #file_location
+        },
+        |(_, _, (catalog, database), _, location)| {
+            Statement::BackupDatabase(BackupDatabaseStmt {
+                catalog,
+                database,
+                location,
+            })
+        },
+    );
+
+    let restore_database = map(
+        rule! {
+            RESTORE ~ DATABASE ~ #dot_separated_idents_1_to_2 ~ FROM ~ #file_location ~ ( AS ~ ^#ident )?
+        },
+        |(_, _, (catalog, database), _, location, opt_new_database)| {
+            Statement::RestoreDatabase(RestoreDatabaseStmt {
+                catalog,
+                database,
+                location,
+                new_database: opt_new_database.map(|(_, name)| name),
+            })
+        },
+    );
+
     let alter_database = map(
         rule! {
             ALTER ~ DATABASE ~ ( IF ~ ^EXISTS )? ~ #dot_separated_idents_1_to_2 ~ #alter_database_action
@@ -1061,12 +1100,16 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
     let analyze_table = map(
         rule! {
             ANALYZE ~ TABLE ~ #dot_separated_idents_1_to_3
+            ~ ( COMPUTE ~ STATISTICS ~ ON ~ "(" ~ #comma_separated_list1(ident) ~ ")" )?
         },
-        |(_, _, (catalog, database, table))| {
+        |(_, _, (catalog, database, table), opt_columns)| {
             Statement::AnalyzeTable(AnalyzeTableStmt {
                 catalog,
                 database,
                 table,
+                columns: opt_columns
+                    .map(|(_, _, _, _, columns, _)| columns)
+                    .unwrap_or_default(),
             })
         },
     );
@@ -1287,6 +1330,82 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         },
     );
 
+    let alter_query_action = |i| {
+        alt((
+            value(AlterQueryAction::PinPlan, rule! { PIN ~ ^PLAN }),
+            value(AlterQueryAction::UnpinPlan, rule! { UNPIN ~ ^PLAN }),
+        ))(i)
+    };
+    let alter_query = map(
+        rule! {
+            ALTER ~ QUERY ~ ^#query ~ ^#alter_query_action
+        },
+        |(_, _, query, action)| {
+            Statement::AlterQuery(AlterQueryStmt {
+                query: Box::new(query),
+                action,
+            })
+        },
+    );
+
+    let create_materialized_view = map_res(
+        rule! {
+            CREATE ~ ( OR ~ ^REPLACE )? ~ MATERIALIZED ~ ^VIEW ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ #dot_separated_idents_1_to_3
+            ~ ( REFRESH ~ ^ON ~ ^WRITE )?
+            ~ AS ~ #query
+        },
+        |(
+            _,
+            opt_or_replace,
+            _,
+            _,
+            opt_if_not_exists,
+            (catalog, database, view),
+            opt_refresh_on_write,
+            _,
+            query,
+        )| {
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            Ok(Statement::CreateMaterializedView(
+                CreateMaterializedViewStmt {
+                    create_option,
+                    catalog,
+                    database,
+                    view,
+                    refresh_on_write: opt_refresh_on_write.is_some(),
+                    query: Box::new(query),
+                },
+            ))
+        },
+    );
+    let drop_materialized_view = map(
+        rule! {
+            DROP ~ MATERIALIZED ~ ^VIEW ~ ( IF ~ ^EXISTS )? ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, _, opt_if_exists, (catalog, database, view))| {
+            Statement::DropMaterializedView(DropMaterializedViewStmt {
+                if_exists: opt_if_exists.is_some(),
+                catalog,
+                database,
+                view,
+            })
+        },
+    );
+    let refresh_materialized_view = map(
+        rule! {
+            REFRESH ~ MATERIALIZED ~ ^VIEW ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, _, (catalog, database, view))| {
+            Statement::RefreshMaterializedView(RefreshMaterializedViewStmt {
+                catalog,
+                database,
+                view,
+            })
+        },
+    );
+
     let create_index = map_res(
         rule! {
             CREATE
@@ -2216,6 +2335,16 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
     let begin = value(Statement::Begin, rule! { BEGIN ~ TRANSACTION? });
     let commit = value(Statement::Commit, rule! { COMMIT });
     let abort = value(Statement::Abort, rule! { ABORT | ROLLBACK });
+    let savepoint = map(rule! { SAVEPOINT ~ #ident }, |(_, name)| {
+        Statement::Savepoint(name)
+    });
+    let rollback_to_savepoint = map(
+        rule! { ROLLBACK ~ TO ~ SAVEPOINT? ~ #ident },
+        |(_, _, _, name)| Statement::RollbackToSavepoint(name),
+    );
+    let release_savepoint = map(rule! { RELEASE ~ SAVEPOINT ~ #ident }, |(_, _, name)| {
+        Statement::ReleaseSavepoint(name)
+    });
 
     let execute_immediate = map(
         rule! {
@@ -2458,6 +2587,8 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #create_database : "`CREATE [OR REPLACE] DATABASE [IF NOT EXISTS] <database> [ENGINE = <engine>]`"
             | #drop_database : "`DROP DATABASE [IF EXISTS] <database>`"
             | #alter_database : "`ALTER DATABASE [IF EXISTS] <action>`"
+            | #backup_database : "`BACKUP DATABASE <database> TO <location>`"
+            | #restore_database : "`RESTORE DATABASE <database> FROM <location> [AS <new_database>]`"
         ),
         // network policy / password policy
         rule!(
@@ -2482,6 +2613,9 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #update : "`UPDATE <table> SET <column> = <expr> [, <column> = <expr> , ... ] [WHERE ...]`"
             | #begin
             | #commit
+            | #savepoint : "`SAVEPOINT <name>`"
+            | #rollback_to_savepoint : "`ROLLBACK TO [SAVEPOINT] <name>`"
+            | #release_savepoint : "`RELEASE SAVEPOINT <name>`"
             | #abort
         ),
         rule!(
@@ -2519,7 +2653,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #optimize_table : "`OPTIMIZE TABLE [<database>.]<table> (ALL | PURGE | COMPACT [SEGMENT])`"
             | #vacuum_table : "`VACUUM TABLE [<database>.]<table> [RETAIN number HOURS] [DRY RUN | DRY RUN SUMMARY]`"
             | #vacuum_drop_table : "`VACUUM DROP TABLE [FROM [<catalog>.]<database>] [RETAIN number HOURS] [DRY RUN | DRY RUN SUMMARY]`"
-            | #analyze_table : "`ANALYZE TABLE [<database>.]<table>`"
+            | #analyze_table : "`ANALYZE TABLE [<database>.]<table> [COMPUTE STATISTICS ON (<column>, ...)]`"
             | #exists_table : "`EXISTS TABLE [<database>.]<table>`"
             | #show_table_functions : "`SHOW TABLE_FUNCTIONS [<show_limit>]`"
         ),
@@ -2537,6 +2671,10 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #drop_view : "`DROP VIEW [IF EXISTS] [<database>.]<view>`"
             | #alter_view : "`ALTER VIEW [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
             | #show_views : "`SHOW [FULL] VIEWS [FROM <database>] [<show_limit>]`"
+            | #create_materialized_view : "`CREATE [OR REPLACE] MATERIALIZED VIEW [IF NOT EXISTS] [<database>.]<view> [REFRESH ON WRITE] AS SELECT ...`"
+            | #drop_materialized_view : "`DROP MATERIALIZED VIEW [IF EXISTS] [<database>.]<view>`"
+            | #refresh_materialized_view : "`REFRESH MATERIALIZED VIEW [<database>.]<view>`"
+            | #alter_query : "`ALTER QUERY <query> {PIN | UNPIN} PLAN`"
             | #create_index: "`CREATE [OR REPLACE] AGGREGATING INDEX [IF NOT EXISTS] <index> AS SELECT ...`"
             | #drop_index: "`DROP <index_type> INDEX [IF EXISTS] <index>`"
             | #refresh_index: "`REFRESH <index_type> INDEX <index> [LIMIT <limit>]`"
@@ -2973,18 +3111,92 @@ pub fn set_var_hints(i: Input) -> IResult<HintItem> {
     )(i)
 }
 
+pub fn optimizer_hint(i: Input) -> IResult<OptimizerHint> {
+    let tables = |i| {
+        map(
+            rule! {
+                ( "(" ~ #ident* ~ ^")" )?
+            },
+            |tables| tables.map(|(_, tables, _)| tables).unwrap_or_default(),
+        )(i)
+    };
+    let leading = map(
+        rule! {
+            LEADING ~ ^"(" ~ ^#ident+ ~ ^")"
+        },
+        |(_, _, tables, _)| OptimizerHint::Leading(tables),
+    );
+    let hash_join = map(rule! { HASH_JOIN ~ #tables }, |(_, tables)| {
+        OptimizerHint::HashJoin(tables)
+    });
+    let merge_join = map(rule! { MERGE_JOIN ~ #tables }, |(_, tables)| {
+        OptimizerHint::MergeJoin(tables)
+    });
+    let nl_join = map(rule! { NL_JOIN ~ #tables }, |(_, tables)| {
+        OptimizerHint::NlJoin(tables)
+    });
+    let broadcast = map(
+        rule! {
+            BROADCAST ~ ^"(" ~ ^#ident+ ~ ^")"
+        },
+        |(_, _, tables, _)| OptimizerHint::Broadcast(tables),
+    );
+    let shuffle = map(
+        rule! {
+            SHUFFLE ~ ^"(" ~ ^#ident+ ~ ^")"
+        },
+        |(_, _, tables, _)| OptimizerHint::Shuffle(tables),
+    );
+    let no_decorrelate = value(OptimizerHint::NoDecorrelate, rule! { NO_DECORRELATE });
+    rule!(
+        #leading
+        | #hash_join
+        | #merge_join
+        | #nl_join
+        | #broadcast
+        | #shuffle
+        | #no_decorrelate
+    )(i)
+}
+
+enum HintEntry {
+    SetVar(HintItem),
+    Optimizer(OptimizerHint),
+}
+
 pub fn hint(i: Input) -> IResult<Hint> {
+    let hint_entry = |i| {
+        alt((
+            map(set_var_hints, HintEntry::SetVar),
+            map(optimizer_hint, HintEntry::Optimizer),
+        ))(i)
+    };
     let hint = map(
         rule! {
-            "/*+" ~ #set_var_hints+ ~ "*/"
+            "/*+" ~ #hint_entry+ ~ "*/"
+        },
+        |(_, items, _)| {
+            let mut hint = Hint {
+                hints_list: vec![],
+                optimizer_hints: vec![],
+            };
+            for item in items {
+                match item {
+                    HintEntry::SetVar(item) => hint.hints_list.push(item),
+                    HintEntry::Optimizer(item) => hint.optimizer_hints.push(item),
+                }
+            }
+            hint
         },
-        |(_, hints_list, _)| Hint { hints_list },
     );
     let invalid_hint = map(
         rule! {
             "/*+" ~ (!"*/" ~ #any_token)* ~ "*/"
         },
-        |_| Hint { hints_list: vec![] },
+        |_| Hint {
+            hints_list: vec![],
+            optimizer_hints: vec![],
+        },
     );
     rule!(#hint|#invalid_hint)(i)
 }
@@ -3055,6 +3267,12 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
         DefaultExpr(Box<Expr>),
         VirtualExpr(Box<Expr>),
         StoredExpr(Box<Expr>),
+        Identity {
+            always: bool,
+            start: u64,
+            increment: u64,
+        },
+        Constraint(ConstraintDefinition),
     }
 
     let nullable = alt((
@@ -3068,6 +3286,13 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
             },
             |(_, default_expr)| ColumnConstraint::DefaultExpr(Box::new(default_expr)),
         ),
+        map(rule! { #identity_column }, |(always, start, increment)| {
+            ColumnConstraint::Identity {
+                always,
+                start,
+                increment,
+            }
+        }),
         map(
             rule! {
                 (GENERATED ~ ^ALWAYS)? ~ AS ~ ^"(" ~ ^#subexpr(NOT_PREC) ~ ^")" ~ VIRTUAL
@@ -3081,6 +3306,10 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
             |(_, _, _, stored_expr, _, _)| ColumnConstraint::StoredExpr(Box::new(stored_expr)),
         ),
     ));
+    let constraint = map(
+        rule! { #column_constraint_def },
+        ColumnConstraint::Constraint,
+    );
 
     let comment = map(
         rule! {
@@ -3093,15 +3322,16 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
         rule! {
             #ident
             ~ #type_name
-            ~ ( #nullable | #expr )*
+            ~ ( #nullable | #expr | #constraint )*
             ~ ( #comment )?
-            : "`<column name> <type> [DEFAULT <expr>] [AS (<expr>) VIRTUAL] [AS (<expr>) STORED] [COMMENT '<comment>']`"
+            : "`<column name> <type> [DEFAULT <expr>] [AS (<expr>) VIRTUAL] [AS (<expr>) STORED] [AUTOINCREMENT [START <n>] [INCREMENT <n>]] [[CONSTRAINT <name>] { CHECK (<expr>) | PRIMARY KEY | UNIQUE | REFERENCES <table> [(<column>)] }] [COMMENT '<comment>']`"
         },
         |(name, data_type, constraints, comment)| {
             let def = ColumnDefinition {
                 name,
                 data_type,
                 expr: None,
+                constraints: vec![],
                 comment,
             };
             (def, constraints)
@@ -3134,12 +3364,56 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
             ColumnConstraint::StoredExpr(stored_expr) => {
                 def.expr = Some(ColumnExpr::Stored(stored_expr))
             }
+            ColumnConstraint::Identity {
+                always,
+                start,
+                increment,
+            } => {
+                def.expr = Some(ColumnExpr::Identity {
+                    always,
+                    start,
+                    increment,
+                })
+            }
+            ColumnConstraint::Constraint(constraint) => def.constraints.push(constraint),
         }
     }
 
     Ok((i, def))
 }
 
+/// `AUTOINCREMENT`, `IDENTITY` or `GENERATED { ALWAYS | BY DEFAULT } AS IDENTITY`,
+/// followed by the optional start value and increment of the column.
+///
+/// Returns whether the values are `ALWAYS` generated, the start value and the increment.
+fn identity_column(i: Input) -> IResult<(bool, u64, u64)> {
+    let params = |i: Input| {
+        alt((
+            map(
+                rule! {
+                    "(" ~ ^#literal_u64 ~ ^"," ~ ^#literal_u64 ~ ^")"
+                },
+                |(_, start, _, increment, _)| (Some(start), Some(increment)),
+            ),
+            map(
+                rule! {
+                    ( START ~ WITH? ~ ^#literal_u64 )? ~ ( INCREMENT ~ BY? ~ ^#literal_u64 )?
+                },
+                |(start, increment)| (start.map(|(_, _, v)| v), increment.map(|(_, _, v)| v)),
+            ),
+        ))(i)
+    };
+    let prefix = alt((
+        value(false, rule! { AUTOINCREMENT | IDENTITY }),
+        value(true, rule! { GENERATED ~ ALWAYS ~ AS ~ IDENTITY }),
+        value(false, rule! { GENERATED ~ BY ~ ^DEFAULT ~ ^AS ~ ^IDENTITY }),
+    ));
+    map(
+        rule! { #prefix ~ #params },
+        |(always, (start, increment))| (always, start.unwrap_or(1), increment.unwrap_or(1)),
+    )(i)
+}
+
 pub fn inverted_index_def(i: Input) -> IResult<InvertedIndexDefinition> {
     map_res(
         rule! {
@@ -3160,8 +3434,83 @@ pub fn inverted_index_def(i: Input) -> IResult<InvertedIndexDefinition> {
     )(i)
 }
 
+/// `[CONSTRAINT <name>] { CHECK (<expr>) | PRIMARY KEY (<columns>) | UNIQUE (<columns>)
+/// | FOREIGN KEY (<columns>) REFERENCES <table> [(<columns>)] }`
+///
+/// Key constraints may be followed by `NOT ENFORCED`, they are never enforced.
+pub fn constraint_def(i: Input) -> IResult<ConstraintDefinition> {
+    let column_list = |i: Input| {
+        map(
+            rule! { "(" ~ ^#comma_separated_list1(ident) ~ ^")" },
+            |(_, columns, _)| columns,
+        )(i)
+    };
+    let primary_key = map(rule! { PRIMARY ~ KEY ~ #column_list }, |(_, _, columns)| {
+        ConstraintType::PrimaryKey(columns)
+    });
+    let unique = map(rule! { UNIQUE ~ #column_list }, |(_, columns)| {
+        ConstraintType::Unique(columns)
+    });
+    let foreign_key = map(
+        rule! { FOREIGN ~ KEY ~ #column_list ~ ^#foreign_key_reference },
+        |(_, _, columns, reference)| ConstraintType::ForeignKey { columns, reference },
+    );
+    named_constraint(alt((primary_key, unique, foreign_key)))(i)
+}
+
+/// The constraints declared on a column, the key constraints apply to the column itself.
+fn column_constraint_def(i: Input) -> IResult<ConstraintDefinition> {
+    let primary_key = value(ConstraintType::PrimaryKey(vec![]), rule! { PRIMARY ~ ^KEY });
+    let unique = value(ConstraintType::Unique(vec![]), rule! { UNIQUE });
+    let foreign_key = map(rule! { #foreign_key_reference }, |reference| {
+        ConstraintType::ForeignKey {
+            columns: vec![],
+            reference,
+        }
+    });
+    named_constraint(alt((primary_key, unique, foreign_key)))(i)
+}
+
+fn named_constraint<'a>(
+    key_constraint: impl FnMut(Input<'a>) -> IResult<'a, ConstraintType>,
+) -> impl FnMut(Input<'a>) -> IResult<'a, ConstraintDefinition> {
+    let check = map(rule! { CHECK ~ ^"(" ~ ^#expr ~ ^")" }, |(_, _, expr, _)| {
+        ConstraintType::Check(expr)
+    });
+    let key_constraint = map(
+        rule! { #key_constraint ~ ( NOT ~ ENFORCED )? },
+        |(constraint, _)| constraint,
+    );
+    map(
+        rule! {
+            ( CONSTRAINT ~ #ident )? ~ ( #check | #key_constraint )
+        },
+        |(opt_name, constraint)| ConstraintDefinition {
+            name: opt_name.map(|(_, name)| name),
+            constraint,
+        },
+    )
+}
+
+fn foreign_key_reference(i: Input) -> IResult<ForeignKeyReference> {
+    map(
+        rule! {
+            REFERENCES ~ ^#dot_separated_idents_1_to_2
+            ~ ( "(" ~ ^#comma_separated_list1(ident) ~ ^")" )?
+        },
+        |(_, (database, table), opt_columns)| ForeignKeyReference {
+            database,
+            table,
+            columns: opt_columns
+                .map(|(_, columns, _)| columns)
+                .unwrap_or_default(),
+        },
+    )(i)
+}
+
 pub fn create_def(i: Input) -> IResult<CreateDefinition> {
     alt((
+        map(rule! { #constraint_def }, CreateDefinition::Constraint),
         map(rule! { #column_def }, CreateDefinition::Column),
         map(
             rule! { #inverted_index_def },
@@ -3517,6 +3866,7 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
         |(_, create_defs, _)| {
             let mut columns = Vec::with_capacity(create_defs.len());
             let mut inverted_indexes = Vec::new();
+            let mut constraints = Vec::new();
             for create_def in create_defs {
                 match create_def {
                     CreateDefinition::Column(column) => {
@@ -3525,6 +3875,9 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
                     CreateDefinition::InvertedIndex(inverted_index) => {
                         inverted_indexes.push(inverted_index);
                     }
+                    CreateDefinition::Constraint(constraint) => {
+                        constraints.push(constraint);
+                    }
                 }
             }
             let opt_inverted_indexes = if !inverted_indexes.is_empty() {
@@ -3532,7 +3885,12 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
             } else {
                 None
             };
-            CreateTableSource::Columns(columns, opt_inverted_indexes)
+            let opt_constraints = if !constraints.is_empty() {
+                Some(constraints)
+            } else {
+                None
+            };
+            CreateTableSource::Columns(columns, opt_inverted_indexes, opt_constraints)
         },
     );
     let like = map(
@@ -3546,9 +3904,22 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
         },
     );
 
+    let clone = map(
+        rule! {
+            CLONE ~ #dot_separated_idents_1_to_3 ~ ( AT ~ ^#at_snapshot_or_ts )?
+        },
+        |(_, (catalog, database, table), opt_travel_point)| CreateTableSource::Clone {
+            catalog,
+            database,
+            table,
+            travel_point: opt_travel_point.map(|(_, point)| point),
+        },
+    );
+
     rule!(
         #columns
         | #like
+        | #clone
     )(i)
 }
 
@@ -3603,6 +3974,7 @@ pub fn modify_column_type(i: Input) -> IResult<ColumnDefinition> {
                 name,
                 data_type,
                 expr: None,
+                constraints: vec![],
                 comment,
             };
             for constraint in constraints {
@@ -3772,12 +4144,69 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, targets)| AlterTableAction::UnsetOptions { targets },
     );
 
+    let set_table_ttl = map(
+        rule! {
+            SET ~ TTL ~ "=" ~ ^#expr
+        },
+        |(_, _, _, ttl)| AlterTableAction::SetTtl { ttl },
+    );
+
+    let unset_table_ttl = map(
+        rule! {
+            UNSET ~ TTL
+        },
+        |(_, _)| AlterTableAction::UnsetTtl,
+    );
+
+    let add_constraint = map(
+        rule! {
+            ADD ~ #constraint_def
+        },
+        |(_, constraint)| AlterTableAction::AddConstraint { constraint },
+    );
+
+    let drop_constraint = map(
+        rule! {
+            DROP ~ CONSTRAINT ~ ^#ident
+        },
+        |(_, _, name)| AlterTableAction::DropConstraint { name },
+    );
+
+    let validate_constraint = map(
+        rule! {
+            VALIDATE ~ ^CONSTRAINT ~ ^#ident
+        },
+        |(_, _, name)| AlterTableAction::ValidateConstraint { name },
+    );
+
+    let cache_warmup = map(
+        rule! {
+            CACHE ~ WARMUP ~ ( COLUMNS ~ "(" ~ #comma_separated_list1(ident) ~ ")" )?
+            ~ ( WHERE ~ ^#expr )? ~ PIN?
+        },
+        |(_, _, opt_columns, opt_selection, opt_pin)| AlterTableAction::CacheWarmup {
+            columns: opt_columns.map(|(_, _, columns, _)| columns),
+            selection: opt_selection.map(|(_, selection)| selection),
+            pin: opt_pin.is_some(),
+        },
+    );
+
+    let cache_unpin = map(
+        rule! {
+            CACHE ~ UNPIN
+        },
+        |(_, _)| AlterTableAction::CacheUnpin,
+    );
+
     rule!(
         #alter_table_cluster_key
         | #drop_table_cluster_key
         | #rename_table
         | #rename_column
         | #modify_table_comment
+        | #add_constraint
+        | #drop_constraint
+        | #validate_constraint
         | #add_column
         | #drop_column
         | #modify_column
@@ -3785,6 +4214,10 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         | #revert_table
         | #set_table_options
         | #unset_table_options
+        | #set_table_ttl
+        | #unset_table_ttl
+        | #cache_warmup
+        | #cache_unpin
     )(i)
 }
 
@@ -4342,15 +4775,23 @@ pub fn database_engine(i: Input) -> IResult<DatabaseEngine> {
 }
 
 pub fn create_database_option(i: Input) -> IResult<CreateDatabaseOption> {
-    let mut create_db_engine = map(
+    let create_db_engine = map(
         rule! {
             ENGINE ~  ^"=" ~ ^#database_engine
         },
         |(_, _, option)| CreateDatabaseOption::DatabaseEngine(option),
     );
 
+    let create_db_clone = map(
+        rule! {
+            CLONE ~ ^#database_ref
+        },
+        |(_, source)| CreateDatabaseOption::Clone(source),
+    );
+
     rule!(
         #create_db_engine
+        | #create_db_clone
     )(i)
 }
 
@@ -35,7 +35,7 @@ use crate::parser::Input;
 
 pub fn stream_table(i: Input) -> IResult<Statement> {
     rule!(
-         #create_stream: "`CREATE [OR REPLACE] STREAM [IF NOT EXISTS] [<database>.]<stream> ON TABLE [<database>.]<table> [<travel_point>] [COMMENT = '<string_literal>']`"
+         #create_stream: "`CREATE [OR REPLACE] STREAM [IF NOT EXISTS] [<database>.]<stream> ON { TABLE | VIEW } [<database>.]<table> [<travel_point>] [COMMENT = '<string_literal>']`"
          | #drop_stream: "`DROP STREAM [IF EXISTS] [<database>.]<stream>`"
          | #show_streams: "`SHOW [FULL] STREAMS [FROM <database>] [<show_limit>]`"
          | #describe_stream: "`DESCRIBE STREAM [<database>.]<stream>`"
@@ -47,7 +47,7 @@ fn create_stream(i: Input) -> IResult<Statement> {
         rule! {
             CREATE ~ ( OR ~ ^REPLACE )? ~ STREAM ~ ( IF ~ ^NOT ~ ^EXISTS )?
             ~ #dot_separated_idents_1_to_3
-            ~ ON ~ TABLE ~ #dot_separated_idents_1_to_2
+            ~ ON ~ ( TABLE | VIEW ) ~ #dot_separated_idents_1_to_2
             ~ ( AT ~ ^#travel_point )?
             ~ ( APPEND_ONLY ~ "=" ~ #literal_bool )?
             ~ ( COMMENT ~ "=" ~ #literal_string )?
@@ -59,7 +59,7 @@ fn create_stream(i: Input) -> IResult<Statement> {
             opt_if_not_exists,
             (catalog, database, stream),
             _,
-            _,
+            on,
             (table_database, table),
             opt_travel_point,
             opt_append_only,
@@ -74,6 +74,7 @@ fn create_stream(i: Input) -> IResult<Statement> {
                 stream,
                 table_database,
                 table,
+                on_view: on.kind == VIEW,
                 travel_point: opt_travel_point.map(|p| p.1),
                 append_only: opt_append_only
                     .map(|(_, _, append_only)| append_only)
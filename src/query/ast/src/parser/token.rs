@@ -355,6 +355,8 @@ pub enum TokenKind {
     ARGS,
     #[token("AUTO", ignore(ascii_case))]
     AUTO,
+    #[token("AUTOINCREMENT", ignore(ascii_case))]
+    AUTOINCREMENT,
     #[token("SOME", ignore(ascii_case))]
     SOME,
     #[token("ALTER", ignore(ascii_case))]
@@ -381,6 +383,8 @@ pub enum TokenKind {
     ASYNC,
     #[token("ATTACH", ignore(ascii_case))]
     ATTACH,
+    #[token("BACKUP", ignore(ascii_case))]
+    BACKUP,
     #[token("BEFORE", ignore(ascii_case))]
     BEFORE,
     #[token("BETWEEN", ignore(ascii_case))]
@@ -415,10 +419,14 @@ pub enum TokenKind {
     BY,
     #[token("BROTLI", ignore(ascii_case))]
     BROTLI,
+    #[token("BROADCAST", ignore(ascii_case))]
+    BROADCAST,
     #[token("BZ2", ignore(ascii_case))]
     BZ2,
     #[token("BLOCK", ignore(ascii_case))]
     BLOCK,
+    #[token("CACHE", ignore(ascii_case))]
+    CACHE,
     #[token("CALL", ignore(ascii_case))]
     CALL,
     #[token("CASE", ignore(ascii_case))]
@@ -435,6 +443,10 @@ pub enum TokenKind {
     CENTURY,
     #[token("CHANGES", ignore(ascii_case))]
     CHANGES,
+    #[token("CHECK", ignore(ascii_case))]
+    CHECK,
+    #[token("CLONE", ignore(ascii_case))]
+    CLONE,
     #[token("CLUSTER", ignore(ascii_case))]
     CLUSTER,
     #[token("COMMENT", ignore(ascii_case))]
@@ -447,6 +459,8 @@ pub enum TokenKind {
     CONNECTION,
     #[token("CONNECTIONS", ignore(ascii_case))]
     CONNECTIONS,
+    #[token("CONSTRAINT", ignore(ascii_case))]
+    CONSTRAINT,
     #[token("CONSUME", ignore(ascii_case))]
     CONSUME,
     #[token("CONTENT_TYPE", ignore(ascii_case))]
@@ -467,6 +481,8 @@ pub enum TokenKind {
     CONFLICT,
     #[token("COMPRESSION", ignore(ascii_case))]
     COMPRESSION,
+    #[token("COMPUTE", ignore(ascii_case))]
+    COMPUTE,
     #[token("COPY_OPTIONS", ignore(ascii_case))]
     COPY_OPTIONS,
     #[token("COPY", ignore(ascii_case))]
@@ -533,6 +549,8 @@ pub enum TokenKind {
     DISTINCT,
     #[token("RESPECT", ignore(ascii_case))]
     RESPECT,
+    #[token("RESTORE", ignore(ascii_case))]
+    RESTORE,
     #[token("IGNORE", ignore(ascii_case))]
     IGNORE,
     #[token("DIV", ignore(ascii_case))]
@@ -577,6 +595,8 @@ pub enum TokenKind {
     END,
     #[token("ENDPOINT", ignore(ascii_case))]
     ENDPOINT,
+    #[token("ENFORCED", ignore(ascii_case))]
+    ENFORCED,
     #[token("ENGINE", ignore(ascii_case))]
     ENGINE,
     #[token("ENGINES", ignore(ascii_case))]
@@ -633,6 +653,8 @@ pub enum TokenKind {
     FOR,
     #[token("FORCE", ignore(ascii_case))]
     FORCE,
+    #[token("FOREIGN", ignore(ascii_case))]
+    FOREIGN,
     #[token("FORMAT", ignore(ascii_case))]
     FORMAT,
     #[token("FOLLOWING", ignore(ascii_case))]
@@ -677,6 +699,8 @@ pub enum TokenKind {
     GZIP,
     #[token("HAVING", ignore(ascii_case))]
     HAVING,
+    #[token("HASH_JOIN", ignore(ascii_case))]
+    HASH_JOIN,
     #[token("HIGH", ignore(ascii_case))]
     HIGH,
     #[token("HILBERT", ignore(ascii_case))]
@@ -697,6 +721,8 @@ pub enum TokenKind {
     IDENTIFIED,
     #[token("IDENTIFIER", ignore(ascii_case))]
     IDENTIFIER,
+    #[token("IDENTITY", ignore(ascii_case))]
+    IDENTITY,
     #[token("IF", ignore(ascii_case))]
     IF,
     #[token("IN", ignore(ascii_case))]
@@ -705,6 +731,8 @@ pub enum TokenKind {
     INCLUDE_QUERY_ID,
     #[token("INCREMENTAL", ignore(ascii_case))]
     INCREMENTAL,
+    #[token("INCREMENT", ignore(ascii_case))]
+    INCREMENT,
     #[token("INDEX", ignore(ascii_case))]
     INDEX,
     #[token("INFORMATION", ignore(ascii_case))]
@@ -842,6 +870,10 @@ pub enum TokenKind {
     NDJSON,
     #[token("NO_PASSWORD", ignore(ascii_case))]
     NO_PASSWORD,
+    #[token("NL_JOIN", ignore(ascii_case))]
+    NL_JOIN,
+    #[token("NO_DECORRELATE", ignore(ascii_case))]
+    NO_DECORRELATE,
     #[token("NONE", ignore(ascii_case))]
     NONE,
     #[token("NOT", ignore(ascii_case))]
@@ -916,10 +948,14 @@ pub enum TokenKind {
     PASSWORD_HISTORY,
     #[token("PATTERN", ignore(ascii_case))]
     PATTERN,
+    #[token("PIN", ignore(ascii_case))]
+    PIN,
     #[token("PIPELINE", ignore(ascii_case))]
     PIPELINE,
     #[token("PLAINTEXT_PASSWORD", ignore(ascii_case))]
     PLAINTEXT_PASSWORD,
+    #[token("PLAN", ignore(ascii_case))]
+    PLAN,
     #[token("POLICIES", ignore(ascii_case))]
     POLICIES,
     #[token("POLICY", ignore(ascii_case))]
@@ -954,6 +990,8 @@ pub enum TokenKind {
     RECORD_DELIMITER,
     #[token("REFERENCE_USAGE", ignore(ascii_case))]
     REFERENCE_USAGE,
+    #[token("REFERENCES", ignore(ascii_case))]
+    REFERENCES,
     #[token("REFRESH", ignore(ascii_case))]
     REFRESH,
     #[token("REGEXP", ignore(ascii_case))]
@@ -970,6 +1008,8 @@ pub enum TokenKind {
     SAMPLE,
     #[token("MERGE", ignore(ascii_case))]
     MERGE,
+    #[token("MERGE_JOIN", ignore(ascii_case))]
+    MERGE_JOIN,
     #[token("MATCHED", ignore(ascii_case))]
     MATCHED,
     #[token("MISSING_FIELD_AS", ignore(ascii_case))]
@@ -1044,6 +1084,8 @@ pub enum TokenKind {
     SELECT,
     #[token("PIVOT", ignore(ascii_case))]
     PIVOT,
+    #[token("UNPIN", ignore(ascii_case))]
+    UNPIN,
     #[token("UNPIVOT", ignore(ascii_case))]
     UNPIVOT,
     #[token("SEGMENT", ignore(ascii_case))]
@@ -1062,12 +1104,16 @@ pub enum TokenKind {
     STAGES,
     #[token("STATISTIC", ignore(ascii_case))]
     STATISTIC,
+    #[token("STATISTICS", ignore(ascii_case))]
+    STATISTICS,
     #[token("SUMMARY", ignore(ascii_case))]
     SUMMARY,
     #[token("SHA256_PASSWORD", ignore(ascii_case))]
     SHA256_PASSWORD,
     #[token("SHOW", ignore(ascii_case))]
     SHOW,
+    #[token("SHUFFLE", ignore(ascii_case))]
+    SHUFFLE,
     #[token("SINCE", ignore(ascii_case))]
     SINCE,
     #[token("SIGNED", ignore(ascii_case))]
@@ -1090,6 +1136,8 @@ pub enum TokenKind {
     SNAPSHOT,
     #[token("SPLIT_SIZE", ignore(ascii_case))]
     SPLIT_SIZE,
+    #[token("START", ignore(ascii_case))]
+    START,
     #[token("STAGE", ignore(ascii_case))]
     STAGE,
     #[token("SYNTAX", ignore(ascii_case))]
@@ -1186,6 +1234,8 @@ pub enum TokenKind {
     TRY_CAST,
     #[token("TSV", ignore(ascii_case))]
     TSV,
+    #[token("TTL", ignore(ascii_case))]
+    TTL,
     #[token("TUESDAY", ignore(ascii_case))]
     TUESDAY,
     #[token("TUPLE", ignore(ascii_case))]
@@ -1196,6 +1246,8 @@ pub enum TokenKind {
     UNBOUNDED,
     #[token("UNION", ignore(ascii_case))]
     UNION,
+    #[token("UNIQUE", ignore(ascii_case))]
+    UNIQUE,
     #[token("UINT16", ignore(ascii_case))]
     UINT16,
     #[token("UINT32", ignore(ascii_case))]
@@ -1224,6 +1276,8 @@ pub enum TokenKind {
     USING,
     #[token("VACUUM", ignore(ascii_case))]
     VACUUM,
+    #[token("VALIDATE", ignore(ascii_case))]
+    VALIDATE,
     #[token("VALUES", ignore(ascii_case))]
     VALUES,
     #[token("VARBINARY", ignore(ascii_case))]
@@ -1244,6 +1298,8 @@ pub enum TokenKind {
     VIEWS,
     #[token("VIRTUAL", ignore(ascii_case))]
     VIRTUAL,
+    #[token("WARMUP", ignore(ascii_case))]
+    WARMUP,
     #[token("WHEN", ignore(ascii_case))]
     WHEN,
     #[token("WHERE", ignore(ascii_case))]
@@ -1360,6 +1416,10 @@ pub enum TokenKind {
     ABORT,
     #[token("ROLLBACK", ignore(ascii_case))]
     ROLLBACK,
+    #[token("SAVEPOINT", ignore(ascii_case))]
+    SAVEPOINT,
+    #[token("RELEASE", ignore(ascii_case))]
+    RELEASE,
     #[token("TEMPORARY", ignore(ascii_case))]
     TEMPORARY,
     #[token("TEMP", ignore(ascii_case))]
@@ -1767,6 +1827,8 @@ impl TokenKind {
             | TokenKind::PIPE
             | TokenKind::STREAM
             | TokenKind::NOTIFICATION
+            | TokenKind::PIN
+            | TokenKind::UNPIN
             if !after_as => true,
             _ => false
         }
@@ -159,11 +159,17 @@ fn test_statement() {
         r#"show full views"#,
         r#"show full views from db"#,
         r#"show full views from ctl.db"#,
+        r#"create materialized view test.mv refresh on write as select number % 3 as a from numbers(1000);"#,
+        r#"drop materialized view if exists test.mv;"#,
+        r#"refresh materialized view mv;"#,
+        r#"alter query select number % 3 as a from numbers(1000) pin plan;"#,
+        r#"alter query select number % 3 as a from numbers(1000) unpin plan;"#,
         r#"create stream test2.s1 on table test.t append_only = false;"#,
         r#"create stream if not exists test2.s2 on table test.t at (stream => test1.s1) comment = 'this is a stream';"#,
         r#"create stream if not exists test2.s3 on table test.t at (TIMESTAMP => '2023-06-26 09:49:02.038483'::TIMESTAMP) append_only = false;"#,
         r#"create stream if not exists test2.s3 on table test.t at (SNAPSHOT => '9828b23f74664ff3806f44bbc1925ea5') append_only = true;"#,
         r#"create or replace stream test2.s1 on table test.t append_only = false;"#,
+        r#"create stream test2.s4 on view test.v append_only = false;"#,
         r#"show full streams from default.test2 like 's%';"#,
         r#"describe stream test2.s2;"#,
         r#"drop stream if exists test2.s2;"#,
@@ -893,6 +899,8 @@ fn test_statement() {
                 RETURN sum;
             END;
             $$;"#,
+        r#"select /*+ HASH_JOIN(t1 t2) MERGE_JOIN NL_JOIN(t2) */ * from t1"#,
+        r#"select /*+ LEADING(t2 t1) BROADCAST(t2) SHUFFLE(t1 t2) NO_DECORRELATE */ * from t1"#,
     ];
 
     for case in cases {
@@ -1037,6 +1045,9 @@ fn test_statement_error() {
                 RETURN sum;
             END;
             $$;"#,
+        r#"select /*+ BROADCAST() */ * from t"#,
+        r#"select /*+ LEADING(t1, t2) */ * from t1, t2"#,
+        r#"select /*+ SHUFFLE */ * from t"#,
     ];
 
     for case in cases {
@@ -43,6 +43,7 @@ use databend_common_pipeline_core::Pipeline;
 use databend_common_storage::Histogram;
 use databend_common_storage::StorageMetrics;
 use databend_storages_common_table_meta::meta::ClusterKey;
+use databend_storages_common_table_meta::meta::MultiColumnStatistics;
 use databend_storages_common_table_meta::meta::SnapshotId;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::table::ChangeType;
@@ -372,6 +373,35 @@ pub trait Table: Sync + Send {
         )))
     }
 
+    /// The current version of a table that tracks its changes by its own snapshots or
+    /// commit log, e.g. Delta and Iceberg, which a stream on the table starts from.
+    async fn changes_version(&self) -> Result<Option<String>> {
+        Err(ErrorCode::Unimplemented(format!(
+            "Change tracking operation is not supported for the table '{}', which uses the '{}' engine.",
+            self.name(),
+            self.get_table_info().engine(),
+        )))
+    }
+
+    /// Reads the data files added (`ChangeType::Append` and `ChangeType::Insert`) or
+    /// removed (`ChangeType::Delete`) since the version `base_version`.
+    #[async_backtrace::framed]
+    async fn read_changes_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+        change_type: ChangeType,
+        base_version: &Option<String>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let (_, _, _, _) = (ctx, push_downs, change_type, base_version);
+
+        Err(ErrorCode::Unimplemented(format!(
+            "Change tracking operation is not supported for the table '{}', which uses the '{}' engine.",
+            self.name(),
+            self.get_table_info().engine(),
+        )))
+    }
+
     fn get_block_thresholds(&self) -> BlockThresholds {
         BlockThresholds {
             max_rows_per_block: DEFAULT_BLOCK_MAX_ROWS,
@@ -395,6 +425,14 @@ pub trait Table: Sync + Send {
         )))
     }
 
+    /// Drops the blocks whose rows are all expired by the TTL of the table, returns the number
+    /// of blocks dropped.
+    #[async_backtrace::framed]
+    async fn drop_expired_blocks(&self, ctx: Arc<dyn TableContext>) -> Result<usize> {
+        let _ = ctx;
+        Ok(0)
+    }
+
     #[async_backtrace::framed]
     async fn compact_blocks(
         &self,
@@ -597,6 +635,11 @@ pub trait ColumnStatisticsProvider: Send {
     fn histogram(&self, _column_id: ColumnId) -> Option<Histogram> {
         None
     }
+
+    // returns the joint statistics of column groups, if any
+    fn multi_column_statistics(&self) -> &[MultiColumnStatistics] {
+        &[]
+    }
 }
 
 pub struct DummyColumnStatisticsProvider;
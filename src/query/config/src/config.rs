@@ -45,6 +45,7 @@ use databend_common_meta_app::tenant::TenantQuota;
 use databend_common_storage::StorageConfig as InnerStorageConfig;
 use databend_common_tracing::Config as InnerLogConfig;
 use databend_common_tracing::FileConfig as InnerFileLogConfig;
+use databend_common_tracing::HistoryLogConfig as InnerHistoryLogConfig;
 use databend_common_tracing::OTLPConfig as InnerOTLPLogConfig;
 use databend_common_tracing::OTLPEndpointConfig as InnerOTLPEndpointConfig;
 use databend_common_tracing::OTLPProtocol;
@@ -1710,6 +1711,13 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value = "0")]
     pub cloud_control_grpc_timeout: u64,
 
+    /// Where tasks are stored and scheduled, `cloud_control` or `embedded`.
+    ///
+    /// `embedded` keeps task definitions and run history in the meta service and runs
+    /// them with the scheduler built into the query nodes.
+    #[clap(long, value_name = "VALUE", default_value = "cloud_control")]
+    pub task_backend: String,
+
     #[clap(long, value_name = "VALUE", default_value = "50")]
     pub max_cached_queries_profiles: usize,
 
@@ -1734,6 +1742,13 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
     type Error = ErrorCode;
 
     fn try_into(self) -> Result<InnerQueryConfig> {
+        if !matches!(self.task_backend.as_str(), "cloud_control" | "embedded") {
+            return Err(ErrorCode::InvalidConfig(format!(
+                "invalid task_backend `{}`, expected `cloud_control` or `embedded`",
+                self.task_backend
+            )));
+        }
+
         Ok(InnerQueryConfig {
             tenant_id: Tenant::new_or_err(self.tenant_id, "")
                 .map_err(|_e| ErrorCode::InvalidConfig("tenant-id can not be empty"))?,
@@ -1810,6 +1825,7 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             udf_server_allow_insecure: self.udf_server_allow_insecure,
             cloud_control_grpc_server_address: self.cloud_control_grpc_server_address,
             cloud_control_grpc_timeout: self.cloud_control_grpc_timeout,
+            task_backend: self.task_backend,
             max_cached_queries_profiles: self.max_cached_queries_profiles,
             network_policy_whitelist: self.network_policy_whitelist,
             settings: self
@@ -1916,6 +1932,7 @@ impl From<InnerQueryConfig> for QueryConfig {
             udf_server_allow_insecure: inner.udf_server_allow_insecure,
             cloud_control_grpc_server_address: inner.cloud_control_grpc_server_address,
             cloud_control_grpc_timeout: inner.cloud_control_grpc_timeout,
+            task_backend: inner.task_backend,
             max_cached_queries_profiles: inner.max_cached_queries_profiles,
             network_policy_whitelist: inner.network_policy_whitelist,
             settings: HashMap::new(),
@@ -1975,6 +1992,9 @@ pub struct LogConfig {
 
     #[clap(flatten)]
     pub tracing: TracingConfig,
+
+    #[clap(flatten)]
+    pub history: HistoryLogConfig,
 }
 
 impl Default for LogConfig {
@@ -2058,6 +2078,13 @@ impl TryInto<InnerLogConfig> for LogConfig {
 
         let tracing: InnerTracingConfig = self.tracing.try_into()?;
 
+        let history: InnerHistoryLogConfig = self.history.try_into()?;
+        if history.on && history.interval == 0 {
+            return Err(ErrorCode::InvalidConfig(
+                "`history.interval` must be greater than 0 when `history.on` is true".to_string(),
+            ));
+        }
+
         Ok(InnerLogConfig {
             file,
             stderr: self.stderr.try_into()?,
@@ -2066,6 +2093,7 @@ impl TryInto<InnerLogConfig> for LogConfig {
             profile,
             structlog,
             tracing,
+            history,
         })
     }
 }
@@ -2082,6 +2110,7 @@ impl From<InnerLogConfig> for LogConfig {
             profile: inner.profile.into(),
             structlog: inner.structlog.into(),
             tracing: inner.tracing.into(),
+            history: inner.history.into(),
 
             // Deprecated fields
             log_dir: None,
@@ -2411,6 +2440,63 @@ impl From<InnerStructLogConfig> for StructLogConfig {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
+#[serde(default)]
+pub struct HistoryLogConfig {
+    /// Flush the query log, profile, login and error events into the `system_history` tables
+    #[clap(
+        long = "log-history-on", value_name = "VALUE", default_value = "false", action = ArgAction::Set, num_args = 0..=1, require_equals = true, default_missing_value = "true"
+    )]
+    #[serde(rename = "on")]
+    pub log_history_on: bool,
+
+    /// Seconds between two flushes of the buffered events
+    #[clap(
+        long = "log-history-interval",
+        value_name = "VALUE",
+        default_value = "10"
+    )]
+    #[serde(rename = "interval")]
+    pub log_history_interval: u64,
+
+    /// Days to keep the events in the history tables
+    #[clap(
+        long = "log-history-retention",
+        value_name = "VALUE",
+        default_value = "7"
+    )]
+    #[serde(rename = "retention")]
+    pub log_history_retention: u64,
+}
+
+impl Default for HistoryLogConfig {
+    fn default() -> Self {
+        InnerHistoryLogConfig::default().into()
+    }
+}
+
+impl TryInto<InnerHistoryLogConfig> for HistoryLogConfig {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<InnerHistoryLogConfig> {
+        Ok(InnerHistoryLogConfig {
+            on: self.log_history_on,
+            interval: self.log_history_interval,
+            retention: self.log_history_retention,
+        })
+    }
+}
+
+impl From<InnerHistoryLogConfig> for HistoryLogConfig {
+    fn from(inner: InnerHistoryLogConfig) -> Self {
+        Self {
+            log_history_on: inner.on,
+            log_history_interval: inner.interval,
+            log_history_retention: inner.retention,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
 #[serde(default)]
 pub struct TracingConfig {
@@ -2569,6 +2655,16 @@ pub struct MetaConfig {
     )]
     pub unhealth_endpoint_evict_time: u64,
 
+    /// The staleness in milliseconds a read from meta is allowed to observe,
+    /// which lets it be served by a follower or a learner.
+    /// 0 sends every read to the leader.
+    #[clap(
+        long = "meta-max-read-staleness-ms",
+        value_name = "VALUE",
+        default_value = "0"
+    )]
+    pub max_read_staleness_ms: u64,
+
     /// Certificate for client to identify meta rpc serve
     #[clap(
         long = "meta-rpc-tls-meta-server-root-ca-cert",
@@ -2625,6 +2721,7 @@ impl TryInto<InnerMetaConfig> for MetaConfig {
             client_timeout_in_second: self.client_timeout_in_second,
             auto_sync_interval: self.auto_sync_interval,
             unhealth_endpoint_evict_time: self.unhealth_endpoint_evict_time,
+            max_read_staleness_ms: self.max_read_staleness_ms,
             rpc_tls_meta_server_root_ca_cert: self.rpc_tls_meta_server_root_ca_cert,
             rpc_tls_meta_service_domain_name: self.rpc_tls_meta_service_domain_name,
         })
@@ -2641,6 +2738,7 @@ impl From<InnerMetaConfig> for MetaConfig {
             client_timeout_in_second: inner.client_timeout_in_second,
             auto_sync_interval: inner.auto_sync_interval,
             unhealth_endpoint_evict_time: inner.unhealth_endpoint_evict_time,
+            max_read_staleness_ms: inner.max_read_staleness_ms,
             rpc_tls_meta_server_root_ca_cert: inner.rpc_tls_meta_server_root_ca_cert,
             rpc_tls_meta_service_domain_name: inner.rpc_tls_meta_service_domain_name,
 
@@ -2666,6 +2764,7 @@ impl Debug for MetaConfig {
                 "unhealth_endpoint_evict_time",
                 &self.unhealth_endpoint_evict_time,
             )
+            .field("max_read_staleness_ms", &self.max_read_staleness_ms)
             .field(
                 "rpc_tls_meta_server_root_ca_cert",
                 &self.rpc_tls_meta_server_root_ca_cert,
@@ -2906,6 +3005,28 @@ pub struct CacheConfig {
     )]
     pub table_data_deserialized_memory_ratio: u64,
 
+    /// Eviction policy of the caches, one of lru, s3fifo and tinylfu
+    #[clap(
+        long = "cache-policy",
+        value_name = "VALUE",
+        value_enum,
+        default_value_t
+    )]
+    pub policy: CachePolicyConfig,
+
+    /// Eviction policies overriding `policy`, by cache name as listed in `system.caches`
+    #[clap(skip)]
+    pub policies: BTreeMap<String, CachePolicyConfig>,
+
+    /// Max percentage of the capacity of each cache taken by the items pinned by
+    /// `ALTER TABLE ... CACHE WARMUP ... PIN`
+    #[clap(
+        long = "cache-pin-quota-ratio",
+        value_name = "VALUE",
+        default_value = "30"
+    )]
+    pub pin_quota_ratio: u64,
+
     // ----- the following options/args are all deprecated               ----
     /// Max number of cached table segment
     #[clap(long = "cache-table-meta-segment-count", value_name = "VALUE")]
@@ -2924,6 +3045,22 @@ fn bool_true() -> bool {
     true
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum CachePolicyConfig {
+    Lru,
+    #[value(name = "s3fifo")]
+    S3Fifo,
+    #[value(name = "tinylfu")]
+    TinyLfu,
+}
+
+impl Default for CachePolicyConfig {
+    fn default() -> Self {
+        Self::Lru
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
 #[serde(rename_all = "lowercase")]
 pub enum CacheStorageTypeConfig {
@@ -3134,6 +3271,13 @@ mod cache_config_converters {
                 data_cache_key_reload_policy: value.data_cache_key_reload_policy.try_into()?,
                 table_data_deserialized_data_bytes: value.table_data_deserialized_data_bytes,
                 table_data_deserialized_memory_ratio: value.table_data_deserialized_memory_ratio,
+                policy: value.policy.into(),
+                policies: value
+                    .policies
+                    .into_iter()
+                    .map(|(name, policy)| (name, policy.into()))
+                    .collect(),
+                pin_quota_ratio: value.pin_quota_ratio,
             })
         }
     }
@@ -3161,6 +3305,13 @@ mod cache_config_converters {
                 disk_cache_config: value.disk_cache_config.into(),
                 table_data_deserialized_data_bytes: value.table_data_deserialized_data_bytes,
                 table_data_deserialized_memory_ratio: value.table_data_deserialized_memory_ratio,
+                policy: value.policy.into(),
+                policies: value
+                    .policies
+                    .into_iter()
+                    .map(|(name, policy)| (name, policy.into()))
+                    .collect(),
+                pin_quota_ratio: value.pin_quota_ratio,
                 table_meta_segment_count: None,
                 segment_block_metas_count: value.segment_block_metas_count,
             }
@@ -3256,6 +3407,26 @@ mod cache_config_converters {
         }
     }
 
+    impl From<CachePolicyConfig> for inner::CachePolicyConfig {
+        fn from(value: CachePolicyConfig) -> Self {
+            match value {
+                CachePolicyConfig::Lru => inner::CachePolicyConfig::Lru,
+                CachePolicyConfig::S3Fifo => inner::CachePolicyConfig::S3Fifo,
+                CachePolicyConfig::TinyLfu => inner::CachePolicyConfig::TinyLfu,
+            }
+        }
+    }
+
+    impl From<inner::CachePolicyConfig> for CachePolicyConfig {
+        fn from(value: inner::CachePolicyConfig) -> Self {
+            match value {
+                inner::CachePolicyConfig::Lru => CachePolicyConfig::Lru,
+                inner::CachePolicyConfig::S3Fifo => CachePolicyConfig::S3Fifo,
+                inner::CachePolicyConfig::TinyLfu => CachePolicyConfig::TinyLfu,
+            }
+        }
+    }
+
     impl TryFrom<DiskCacheKeyReloadPolicy> for inner::DiskCacheKeyReloadPolicy {
         type Error = ErrorCode;
         fn try_from(value: DiskCacheKeyReloadPolicy) -> std::result::Result<Self, Self::Error> {
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::fmt;
 use std::fmt::Debug;
@@ -249,6 +250,7 @@ pub struct QueryConfig {
 
     pub cloud_control_grpc_server_address: Option<String>,
     pub cloud_control_grpc_timeout: u64,
+    pub task_backend: String,
     pub max_cached_queries_profiles: usize,
 
     pub network_policy_whitelist: Vec<String>,
@@ -330,6 +332,7 @@ impl Default for QueryConfig {
             udf_server_allow_insecure: false,
             cloud_control_grpc_server_address: None,
             cloud_control_grpc_timeout: 0,
+            task_backend: "cloud_control".to_string(),
             data_retention_time_in_days_max: 90,
             max_cached_queries_profiles: 50,
             network_policy_whitelist: Vec::new(),
@@ -347,6 +350,11 @@ impl QueryConfig {
         }
     }
 
+    /// Tasks are stored in meta and run by the embedded scheduler instead of cloud control.
+    pub fn embedded_task_scheduler(&self) -> bool {
+        self.task_backend == "embedded"
+    }
+
     pub fn sanitize(&self) -> Self {
         let mut sanitized = self.clone();
         sanitized.node_secret = mask_string(&self.node_secret, 3);
@@ -375,6 +383,10 @@ pub struct MetaConfig {
     /// 0 disables auto-sync. By default auto-sync is disabled.
     pub auto_sync_interval: u64,
     pub unhealth_endpoint_evict_time: u64,
+    /// The staleness in milliseconds a read from meta is allowed to observe,
+    /// which lets it be served by a follower or a learner.
+    /// 0 sends every read to the leader.
+    pub max_read_staleness_ms: u64,
     /// Certificate for client to identify meta rpc serve
     pub rpc_tls_meta_server_root_ca_cert: String,
     pub rpc_tls_meta_service_domain_name: String,
@@ -390,6 +402,7 @@ impl Default for MetaConfig {
             client_timeout_in_second: 4,
             auto_sync_interval: 0,
             unhealth_endpoint_evict_time: 120,
+            max_read_staleness_ms: 0,
             rpc_tls_meta_server_root_ca_cert: "".to_string(),
             rpc_tls_meta_service_domain_name: "localhost".to_string(),
         }
@@ -449,6 +462,11 @@ impl MetaConfig {
                 None
             },
             unhealthy_endpoint_evict_time: Duration::from_secs(self.unhealth_endpoint_evict_time),
+            max_read_staleness: if self.max_read_staleness_ms > 0 {
+                Some(Duration::from_millis(self.max_read_staleness_ms))
+            } else {
+                None
+            },
         }
     }
 }
@@ -466,6 +484,7 @@ impl Debug for MetaConfig {
                 "unhealth_endpoint_evict_time",
                 &self.unhealth_endpoint_evict_time,
             )
+            .field("max_read_staleness_ms", &self.max_read_staleness_ms)
             .field(
                 "rpc_tls_meta_server_root_ca_cert",
                 &self.rpc_tls_meta_server_root_ca_cert,
@@ -626,6 +645,52 @@ pub struct CacheConfig {
     /// Only if query nodes have plenty of un-utilized memory, the working set can be fitted into,
     /// and the access pattern will benefit from caching, consider enabled this cache.
     pub table_data_deserialized_memory_ratio: u64,
+
+    /// Eviction policy of the caches, `lru` by default.
+    pub policy: CachePolicyConfig,
+
+    /// Eviction policies overriding `policy`, by cache name as listed in `system.caches`.
+    pub policies: BTreeMap<String, CachePolicyConfig>,
+
+    /// Max percentage of the capacity of each cache taken by the items pinned by
+    /// `ALTER TABLE ... CACHE WARMUP ... PIN`, 30 by default.
+    pub pin_quota_ratio: u64,
+}
+
+impl CacheConfig {
+    /// Returns the eviction policy of the cache of the given name.
+    pub fn policy_of(&self, cache_name: &str) -> CachePolicyConfig {
+        self.policies
+            .get(cache_name)
+            .cloned()
+            .unwrap_or_else(|| self.policy.clone())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CachePolicyConfig {
+    // evict the least recently used items
+    Lru,
+    // S3-FIFO, admit the items accessed again shortly after they are cached
+    S3Fifo,
+    // W-TinyLFU, admit the items accessed more frequently than the items they evict
+    TinyLfu,
+}
+
+impl Default for CachePolicyConfig {
+    fn default() -> Self {
+        Self::Lru
+    }
+}
+
+impl Display for CachePolicyConfig {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            CachePolicyConfig::Lru => write!(f, "lru"),
+            CachePolicyConfig::S3Fifo => write!(f, "s3fifo"),
+            CachePolicyConfig::TinyLfu => write!(f, "tinylfu"),
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -721,6 +786,9 @@ impl Default for CacheConfig {
             data_cache_key_reload_policy: Default::default(),
             table_data_deserialized_data_bytes: 0,
             table_data_deserialized_memory_ratio: 0,
+            policy: Default::default(),
+            policies: BTreeMap::new(),
+            pin_quota_ratio: 30,
         }
     }
 }
@@ -46,6 +46,7 @@ pub use databend_common_base::version::DATABEND_GIT_SHA;
 pub use databend_common_base::version::DATABEND_SEMVER;
 pub use global::GlobalConfig;
 pub use inner::CacheConfig;
+pub use inner::CachePolicyConfig;
 pub use inner::CacheStorageTypeConfig as CacheStorageTypeInnerConfig;
 pub use inner::CatalogConfig;
 pub use inner::CatalogHiveConfig;
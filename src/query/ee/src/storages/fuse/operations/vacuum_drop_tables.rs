@@ -20,6 +20,8 @@ use databend_common_base::runtime::execute_futures_in_parallel;
 use databend_common_catalog::table::Table;
 use databend_common_exception::Result;
 use databend_common_meta_app::schema::TableInfo;
+use databend_common_storages_fuse::operations::has_clone_markers;
+use databend_common_storages_fuse::operations::unregister_clone;
 use databend_common_storages_fuse::FuseTable;
 use databend_enterprise_vacuum_handler::vacuum_handler::VacuumDropFileInfo;
 use databend_enterprise_vacuum_handler::vacuum_handler::VacuumDropTablesResult;
@@ -38,7 +40,8 @@ pub async fn do_vacuum_drop_table(
     for (table_info, operator) in tables {
         let result =
             vacuum_drop_single_table(&table_info, operator, dry_run_limit, &mut list_files).await;
-        if result.is_err() {
+        // a skipped table keeps its meta data, it is vacuumed again once its clones are gone
+        if !matches!(result, Ok(true)) {
             let table_id = table_info.ident.table_id;
             failed_tables.insert(table_id);
         }
@@ -50,12 +53,13 @@ pub async fn do_vacuum_drop_table(
     })
 }
 
+/// Returns false if the table is skipped because its files are still referenced by its clones.
 async fn vacuum_drop_single_table(
     table_info: &TableInfo,
     operator: Operator,
     dry_run_limit: Option<usize>,
     list_files: &mut Vec<VacuumDropFileInfo>,
-) -> Result<()> {
+) -> Result<bool> {
     let dir = format!(
         "{}/",
         FuseTable::parse_storage_prefix_from_table_info(table_info)?
@@ -72,6 +76,18 @@ async fn vacuum_drop_single_table(
 
     match dry_run_limit {
         None => {
+            // files of the tables this one was cloned from are no longer referenced by it
+            unregister_clone(&operator, table_info.options(), table_info.ident.table_id).await?;
+            // files of this table are still referenced by its clones, retry later
+            let prefix = FuseTable::parse_storage_prefix_from_table_info(table_info)?;
+            if has_clone_markers(&operator, &prefix).await? {
+                info!(
+                    "table {:?} is still referenced by its clones, skip removing {:?}",
+                    table_info.name, dir
+                );
+                return Ok(false);
+            }
+
             let result = operator.remove_all(&dir).await;
             if let Err(ref err) = result {
                 error!("failed to remove all in directory {}: {}", dir, err);
@@ -122,7 +138,7 @@ async fn vacuum_drop_single_table(
         dir,
         start.elapsed()
     );
-    Ok(())
+    Ok(true)
 }
 
 #[async_backtrace::framed]
@@ -27,6 +27,7 @@ use databend_common_storages_fuse::io::MetaReaders;
 use databend_common_storages_fuse::io::SnapshotLiteExtended;
 use databend_common_storages_fuse::io::SnapshotsIO;
 use databend_common_storages_fuse::io::TableMetaLocationGenerator;
+use databend_common_storages_fuse::operations::SharedLocations;
 use databend_common_storages_fuse::FuseTable;
 use databend_storages_common_cache::LoadParams;
 use databend_storages_common_table_meta::meta::SegmentInfo;
@@ -135,18 +136,21 @@ pub async fn get_snapshot_referenced_files(
     }))
 }
 
-// return orphan files to be purged
+// return orphan files to be purged, files still referenced by the clones of the table are kept
 #[async_backtrace::framed]
 async fn get_orphan_files_to_be_purged(
     fuse_table: &FuseTable,
     prefix: &str,
     referenced_files: HashSet<String>,
+    shared_locations: &SharedLocations,
     retention_time: DateTime<Utc>,
 ) -> Result<Vec<String>> {
     let prefix = prefix.to_string();
     fuse_table
         .list_files(prefix, |location, modified| {
-            modified <= retention_time && !referenced_files.contains(&location)
+            modified <= retention_time
+                && !referenced_files.contains(&location)
+                && !shared_locations.is_referenced_by_clones(&location)
         })
         .await
 }
@@ -171,6 +175,8 @@ pub async fn do_gc_orphan_files(
         start.elapsed()
     );
     ctx.set_status_info(&status);
+    // files of this table referenced by any snapshot of its clones
+    let shared_locations = fuse_table.shared_locations(ctx.clone(), false).await?;
 
     // 2. Purge orphan segment files.
     // 2.1 Get orphan segment files to be purged
@@ -179,6 +185,7 @@ pub async fn do_gc_orphan_files(
         fuse_table,
         location_gen.segment_info_prefix(),
         referenced_files.segments,
+        &shared_locations,
         retention_time,
     )
     .await?;
@@ -212,6 +219,7 @@ pub async fn do_gc_orphan_files(
         fuse_table,
         location_gen.block_location_prefix(),
         referenced_files.blocks,
+        &shared_locations,
         retention_time,
     )
     .await?;
@@ -243,6 +251,7 @@ pub async fn do_gc_orphan_files(
         fuse_table,
         location_gen.block_bloom_index_prefix(),
         referenced_files.blocks_index,
+        &shared_locations,
         retention_time,
     )
     .await?;
@@ -293,6 +302,8 @@ pub async fn do_dry_run_orphan_files(
         start.elapsed()
     );
     ctx.set_status_info(&status);
+    // files of this table referenced by any snapshot of its clones
+    let shared_locations = fuse_table.shared_locations(ctx.clone(), true).await?;
 
     let location_gen = fuse_table.meta_location_generator();
     // 2. Get purge orphan segment files.
@@ -300,6 +311,7 @@ pub async fn do_dry_run_orphan_files(
         fuse_table,
         location_gen.segment_info_prefix(),
         referenced_files.segments,
+        &shared_locations,
         retention_time,
     )
     .await?;
@@ -320,6 +332,7 @@ pub async fn do_dry_run_orphan_files(
         fuse_table,
         location_gen.block_location_prefix(),
         referenced_files.blocks,
+        &shared_locations,
         retention_time,
     )
     .await?;
@@ -339,6 +352,7 @@ pub async fn do_dry_run_orphan_files(
         fuse_table,
         location_gen.block_bloom_index_prefix(),
         referenced_files.blocks_index,
+        &shared_locations,
         retention_time,
     )
     .await?;
@@ -17,6 +17,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use databend_common_base::base::GlobalInstance;
+use databend_common_catalog::catalog::Catalog;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table::TableExt;
 use databend_common_exception::ErrorCode;
@@ -33,17 +34,26 @@ use databend_common_sql::plans::CreateStreamPlan;
 use databend_common_sql::plans::DropStreamPlan;
 use databend_common_storages_fuse::FuseTable;
 use databend_common_storages_fuse::TableContext;
+use databend_common_storages_stream::changes_query::view_changes_query;
 use databend_common_storages_stream::stream_table::STREAM_ENGINE;
+use databend_common_storages_view::view_table::QUERY;
+use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_enterprise_stream_handler::StreamHandler;
 use databend_enterprise_stream_handler::StreamHandlerWrapper;
+use databend_storages_common_table_meta::table::encode_view_source_offsets;
+use databend_storages_common_table_meta::table::StreamMode;
+use databend_storages_common_table_meta::table::StreamSourceOffset;
 use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
 use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING_BEGIN_VER;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_MODE;
 use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use databend_storages_common_table_meta::table::OPT_KEY_SOURCE_DATABASE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_SOURCE_ENGINE;
 use databend_storages_common_table_meta::table::OPT_KEY_SOURCE_TABLE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_SOURCE_VERSION;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_VER;
+use databend_storages_common_table_meta::table::OPT_KEY_VIEW_SOURCE_OFFSETS;
 
 pub struct RealStreamHandler {}
 
@@ -58,80 +68,27 @@ impl StreamHandler for RealStreamHandler {
         let tenant = ctx.get_tenant();
         let catalog = ctx.get_catalog(&plan.catalog).await?;
 
-        let mut table = catalog
+        let table = catalog
             .get_table(&tenant, &plan.table_database, &plan.table_name)
             .await?;
-        let table_info = table.get_table_info();
-        if table_info.options().contains_key("TRANSIENT") {
+        let engine = table.get_table_info().engine().to_string();
+        if plan.on_view != (engine == VIEW_ENGINE) {
             return Err(ErrorCode::IllegalStream(format!(
-                "The table '{}.{}' is transient, can't create stream",
-                plan.table_database, plan.table_name
+                "{}.{} is {}, please use `CREATE STREAM ... ON {} {}.{}`",
+                plan.table_database,
+                plan.table_name,
+                if plan.on_view { "not a VIEW" } else { "a VIEW" },
+                if plan.on_view { "TABLE" } else { "VIEW" },
+                plan.table_database,
+                plan.table_name,
             )));
         }
-        if table.is_temp() {
-            return Err(ErrorCode::IllegalStream(format!(
-                "The table '{}.{}' is temporary, can't create stream",
-                plan.table_database, plan.table_name
-            )));
-        }
-
-        let table_id = table_info.ident.table_id;
-        if !table.change_tracking_enabled() {
-            let table_seq = table_info.ident.seq;
-            // enable change tracking.
-            let req = UpsertTableOptionReq {
-                table_id,
-                seq: MatchSeq::Exact(table_seq),
-                options: HashMap::from([
-                    (
-                        OPT_KEY_CHANGE_TRACKING.to_string(),
-                        Some("true".to_string()),
-                    ),
-                    (
-                        OPT_KEY_CHANGE_TRACKING_BEGIN_VER.to_string(),
-                        Some(table_seq.to_string()),
-                    ),
-                ]),
-            };
 
-            catalog
-                .upsert_table_option(&tenant, &plan.table_database, req)
-                .await?;
-            // refreash table.
-            table = table.refresh(ctx.as_ref()).await?;
-        }
-
-        let table = FuseTable::try_from_table(table.as_ref())?;
-        let abort_checker = ctx.get_abort_checker();
-        let change_desc = table
-            .get_change_descriptor(
-                plan.append_only,
-                "".to_string(),
-                plan.navigation.as_ref(),
-                abort_checker,
-            )
-            .await?;
-        table.check_changes_valid(&table.get_table_info().desc, change_desc.seq)?;
-
-        let db_id = table
-            .get_table_info()
-            .options()
-            .get(OPT_KEY_DATABASE_ID)
-            .ok_or_else(|| {
-                ErrorCode::Internal(format!(
-                    "Invalid fuse table, table option {} not found",
-                    OPT_KEY_DATABASE_ID
-                ))
-            })?;
-
-        let mut options = BTreeMap::new();
-        options.insert(OPT_KEY_MODE.to_string(), change_desc.mode.to_string());
-        options.insert(OPT_KEY_SOURCE_DATABASE_ID.to_owned(), db_id.to_string());
-        options.insert(OPT_KEY_SOURCE_TABLE_ID.to_string(), table_id.to_string());
-        options.insert(OPT_KEY_TABLE_VER.to_string(), change_desc.seq.to_string());
-        if let Some(snapshot_loc) = change_desc.location {
-            options.insert(OPT_KEY_SNAPSHOT_LOCATION.to_string(), snapshot_loc);
-        }
+        let options = match engine.as_str() {
+            VIEW_ENGINE => view_stream_options(ctx, catalog.as_ref(), plan, table).await?,
+            "FUSE" => fuse_stream_options(ctx, catalog.as_ref(), plan, table).await?,
+            _ => lake_stream_options(ctx, plan, &engine).await?,
+        };
 
         let req = CreateTableReq {
             create_option: plan.create_option,
@@ -205,6 +162,251 @@ impl StreamHandler for RealStreamHandler {
     }
 }
 
+fn check_source_table(table: &dyn Table, database: &str, name: &str) -> Result<()> {
+    if table.get_table_info().options().contains_key("TRANSIENT") {
+        return Err(ErrorCode::IllegalStream(format!(
+            "The table '{}.{}' is transient, can't create stream",
+            database, name
+        )));
+    }
+    if table.is_temp() {
+        return Err(ErrorCode::IllegalStream(format!(
+            "The table '{}.{}' is temporary, can't create stream",
+            database, name
+        )));
+    }
+    Ok(())
+}
+
+async fn enable_change_tracking(
+    ctx: &dyn TableContext,
+    catalog: &dyn Catalog,
+    database: &str,
+    table: Arc<dyn Table>,
+) -> Result<Arc<dyn Table>> {
+    if table.change_tracking_enabled() {
+        return Ok(table);
+    }
+
+    let table_info = table.get_table_info();
+    let table_seq = table_info.ident.seq;
+    let req = UpsertTableOptionReq {
+        table_id: table_info.ident.table_id,
+        seq: MatchSeq::Exact(table_seq),
+        options: HashMap::from([
+            (
+                OPT_KEY_CHANGE_TRACKING.to_string(),
+                Some("true".to_string()),
+            ),
+            (
+                OPT_KEY_CHANGE_TRACKING_BEGIN_VER.to_string(),
+                Some(table_seq.to_string()),
+            ),
+        ]),
+    };
+
+    catalog
+        .upsert_table_option(&ctx.get_tenant(), database, req)
+        .await?;
+    // refreash table.
+    table.refresh(ctx).await
+}
+
+fn fuse_database_id(table: &FuseTable) -> Result<u64> {
+    let db_id = table
+        .get_table_info()
+        .options()
+        .get(OPT_KEY_DATABASE_ID)
+        .ok_or_else(|| {
+            ErrorCode::Internal(format!(
+                "Invalid fuse table, table option {} not found",
+                OPT_KEY_DATABASE_ID
+            ))
+        })?;
+    Ok(db_id.parse::<u64>()?)
+}
+
+async fn fuse_stream_options(
+    ctx: Arc<dyn TableContext>,
+    catalog: &dyn Catalog,
+    plan: &CreateStreamPlan,
+    table: Arc<dyn Table>,
+) -> Result<BTreeMap<String, String>> {
+    check_source_table(table.as_ref(), &plan.table_database, &plan.table_name)?;
+    let table_id = table.get_id();
+    let table = enable_change_tracking(ctx.as_ref(), catalog, &plan.table_database, table).await?;
+
+    let table = FuseTable::try_from_table(table.as_ref())?;
+    let abort_checker = ctx.get_abort_checker();
+    let change_desc = table
+        .get_change_descriptor(
+            plan.append_only,
+            "".to_string(),
+            plan.navigation.as_ref(),
+            abort_checker,
+        )
+        .await?;
+    table.check_changes_valid(&table.get_table_info().desc, change_desc.seq)?;
+
+    let db_id = fuse_database_id(table)?;
+
+    let mut options = BTreeMap::new();
+    options.insert(OPT_KEY_MODE.to_string(), change_desc.mode.to_string());
+    options.insert(OPT_KEY_SOURCE_DATABASE_ID.to_owned(), db_id.to_string());
+    options.insert(OPT_KEY_SOURCE_TABLE_ID.to_string(), table_id.to_string());
+    options.insert(OPT_KEY_TABLE_VER.to_string(), change_desc.seq.to_string());
+    if let Some(snapshot_loc) = change_desc.location {
+        options.insert(OPT_KEY_SNAPSHOT_LOCATION.to_string(), snapshot_loc);
+    }
+    Ok(options)
+}
+
+/// The stream on a view keeps an offset for each fuse table underlying the view.
+async fn view_stream_options(
+    ctx: Arc<dyn TableContext>,
+    catalog: &dyn Catalog,
+    plan: &CreateStreamPlan,
+    view: Arc<dyn Table>,
+) -> Result<BTreeMap<String, String>> {
+    if plan.navigation.is_some() {
+        return Err(ErrorCode::IllegalStream(
+            "Stream on view doesn't support the AT clause",
+        ));
+    }
+
+    let view_query = view
+        .get_table_info()
+        .options()
+        .get(QUERY)
+        .ok_or_else(|| ErrorCode::Internal("Invalid VIEW object"))?;
+    let (_, tables) = view_changes_query(
+        view_query,
+        ctx.get_settings().get_sql_dialect()?,
+        &plan.catalog,
+        &plan.database,
+        &plan.stream_name,
+        &plan.table_database,
+        plan.append_only,
+    )?;
+
+    let mut offsets: Vec<StreamSourceOffset> = Vec::with_capacity(tables.len());
+    for (database, name) in tables {
+        let table = catalog
+            .get_table(&ctx.get_tenant(), &database, &name)
+            .await?;
+        if table.get_table_info().engine() != "FUSE" {
+            return Err(ErrorCode::IllegalStream(format!(
+                "The table '{}.{}' underlying the view is not a FUSE table, can't create stream",
+                database, name
+            )));
+        }
+        check_source_table(table.as_ref(), &database, &name)?;
+        let table_id = table.get_id();
+        if offsets.iter().any(|offset| offset.table_id == table_id) {
+            continue;
+        }
+        let table = enable_change_tracking(ctx.as_ref(), catalog, &database, table).await?;
+
+        let table = FuseTable::try_from_table(table.as_ref())?;
+        let change_desc = table
+            .get_change_descriptor(
+                plan.append_only,
+                "".to_string(),
+                None,
+                ctx.get_abort_checker(),
+            )
+            .await?;
+        offsets.push(StreamSourceOffset {
+            database_id: fuse_database_id(table)?,
+            table_id,
+            table_version: change_desc.seq,
+            snapshot_location: change_desc.location,
+        });
+    }
+
+    let db_id = catalog
+        .get_database(&ctx.get_tenant(), &plan.table_database)
+        .await?
+        .get_db_info()
+        .database_id
+        .db_id;
+    let mode = if plan.append_only {
+        StreamMode::AppendOnly
+    } else {
+        StreamMode::Standard
+    };
+
+    let view_info = view.get_table_info();
+    let mut options = BTreeMap::new();
+    options.insert(OPT_KEY_MODE.to_string(), mode.to_string());
+    options.insert(OPT_KEY_SOURCE_DATABASE_ID.to_owned(), db_id.to_string());
+    options.insert(
+        OPT_KEY_SOURCE_TABLE_ID.to_string(),
+        view_info.ident.table_id.to_string(),
+    );
+    options.insert(
+        OPT_KEY_TABLE_VER.to_string(),
+        view_info.ident.seq.to_string(),
+    );
+    options.insert(OPT_KEY_SOURCE_ENGINE.to_string(), VIEW_ENGINE.to_string());
+    options.insert(
+        OPT_KEY_VIEW_SOURCE_OFFSETS.to_string(),
+        encode_view_source_offsets(&offsets)?,
+    );
+    Ok(options)
+}
+
+/// The stream on a Delta or Iceberg table starts from the current version of the table.
+async fn lake_stream_options(
+    ctx: Arc<dyn TableContext>,
+    plan: &CreateStreamPlan,
+    engine: &str,
+) -> Result<BTreeMap<String, String>> {
+    if plan.navigation.is_some() {
+        return Err(ErrorCode::IllegalStream(format!(
+            "Stream on {} table doesn't support the AT clause",
+            engine
+        )));
+    }
+
+    // The storage parameters of the table are attached by the query context.
+    let table = ctx
+        .get_table(&plan.catalog, &plan.table_database, &plan.table_name)
+        .await?;
+    let source_version = table.changes_version().await?;
+
+    let catalog = ctx.get_catalog(&plan.catalog).await?;
+    let db_id = catalog
+        .get_database(&ctx.get_tenant(), &plan.table_database)
+        .await?
+        .get_db_info()
+        .database_id
+        .db_id;
+    let mode = if plan.append_only {
+        StreamMode::AppendOnly
+    } else {
+        StreamMode::Standard
+    };
+
+    let table_info = table.get_table_info();
+    let mut options = BTreeMap::new();
+    options.insert(OPT_KEY_MODE.to_string(), mode.to_string());
+    options.insert(OPT_KEY_SOURCE_DATABASE_ID.to_owned(), db_id.to_string());
+    options.insert(
+        OPT_KEY_SOURCE_TABLE_ID.to_string(),
+        table_info.ident.table_id.to_string(),
+    );
+    options.insert(
+        OPT_KEY_TABLE_VER.to_string(),
+        table_info.ident.seq.to_string(),
+    );
+    options.insert(OPT_KEY_SOURCE_ENGINE.to_string(), engine.to_string());
+    if let Some(version) = source_version {
+        options.insert(OPT_KEY_SOURCE_VERSION.to_string(), version);
+    }
+    Ok(options)
+}
+
 impl RealStreamHandler {
     pub fn init() -> Result<()> {
         let rm = RealStreamHandler {};
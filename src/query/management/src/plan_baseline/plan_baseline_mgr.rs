@@ -0,0 +1,127 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::base::escape_for_key;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::PlanBaseline;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_kvapi::kvapi;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::MetaError;
+use databend_common_meta_types::SeqV;
+use databend_common_meta_types::UpsertKV;
+use databend_common_meta_types::With;
+
+pub static PLAN_BASELINE_API_KEY_PREFIX: &str = "__fd_plan_baselines";
+
+// example:
+// __fd_plan_baselines/v1/test_tenant
+//      |- /932d5555e275c38b46574f4adfa5e01b: PlanBaseline { sql_hash: "932d...", ... }
+pub struct PlanBaselineMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    baseline_key_prefix: String,
+}
+
+impl PlanBaselineMgr {
+    pub fn create(
+        kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+        tenant: &Tenant,
+    ) -> Result<Self> {
+        Ok(PlanBaselineMgr {
+            kv_api,
+            baseline_key_prefix: format!(
+                "{}/v1/{}",
+                PLAN_BASELINE_API_KEY_PREFIX,
+                escape_for_key(tenant.tenant_name())?
+            ),
+        })
+    }
+
+    fn baseline_key(&self, sql_hash: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}",
+            self.baseline_key_prefix,
+            escape_for_key(sql_hash)?
+        ))
+    }
+
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn get_baseline(&self, sql_hash: &str) -> Result<Option<SeqV<PlanBaseline>>> {
+        let key = self.baseline_key(sql_hash)?;
+        match self.kv_api.get_kv(&key).await? {
+            None => Ok(None),
+            Some(seq_v) => Ok(Some(SeqV::new(seq_v.seq, decode(&seq_v.data)?))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn list_baselines(&self) -> Result<Vec<PlanBaseline>> {
+        let prefix = format!("{}/", self.baseline_key_prefix);
+        let values = self.kv_api.prefix_list_kv(&prefix).await?;
+
+        let mut baselines = Vec::with_capacity(values.len());
+        for (_key, seq_v) in values {
+            baselines.push(decode(&seq_v.data)?);
+        }
+        Ok(baselines)
+    }
+
+    /// Create or replace the baseline of a query.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn upsert_baseline(&self, baseline: &PlanBaseline) -> Result<u64> {
+        let key = self.baseline_key(&baseline.sql_hash)?;
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKV::update(&key, &serde_json::to_vec(baseline)?))
+            .await?;
+        Ok(res.result.map(|v| v.seq).unwrap_or_default())
+    }
+
+    /// Replace a baseline if it is not changed since `seq` was read.
+    ///
+    /// Returns `false` if the baseline has been changed or dropped by someone else.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn update_baseline(&self, seq: u64, baseline: &PlanBaseline) -> Result<bool> {
+        let key = self.baseline_key(&baseline.sql_hash)?;
+        let res = self
+            .kv_api
+            .upsert_kv(
+                UpsertKV::update(&key, &serde_json::to_vec(baseline)?).with(MatchSeq::Exact(seq)),
+            )
+            .await?;
+        Ok(res.is_changed())
+    }
+
+    /// Returns `false` if the query has no baseline.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn drop_baseline(&self, sql_hash: &str) -> Result<bool> {
+        let key = self.baseline_key(sql_hash)?;
+        let res = self.kv_api.upsert_kv(UpsertKV::delete(&key)).await?;
+        Ok(res.is_changed())
+    }
+}
+
+fn decode(data: &[u8]) -> Result<PlanBaseline> {
+    serde_json::from_slice(data).map_err(|e| {
+        ErrorCode::IllegalPlanBaseline(format!("Illegal plan baseline record in meta: {e}"))
+    })
+}
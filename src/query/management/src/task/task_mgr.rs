@@ -0,0 +1,715 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_base::base::escape_for_key;
+use databend_common_cloud_control::pb;
+use databend_common_cloud_control::pb::alter_task_request::AlterTaskType;
+use databend_common_cloud_control::pb::schedule_options::ScheduleType;
+use databend_common_cloud_control::pb::task::Status;
+use databend_common_cloud_control::pb::task_run::State;
+use databend_common_cloud_control::pb::AlterTaskRequest;
+use databend_common_cloud_control::pb::AlterTaskResponse;
+use databend_common_cloud_control::pb::CreateTaskRequest;
+use databend_common_cloud_control::pb::CreateTaskResponse;
+use databend_common_cloud_control::pb::DescribeTaskRequest;
+use databend_common_cloud_control::pb::DescribeTaskResponse;
+use databend_common_cloud_control::pb::DropTaskRequest;
+use databend_common_cloud_control::pb::DropTaskResponse;
+use databend_common_cloud_control::pb::EnableTaskDependentsRequest;
+use databend_common_cloud_control::pb::EnableTaskDependentsResponse;
+use databend_common_cloud_control::pb::GetTaskDependentsRequest;
+use databend_common_cloud_control::pb::GetTaskDependentsResponse;
+use databend_common_cloud_control::pb::ShowTaskRunsRequest;
+use databend_common_cloud_control::pb::ShowTaskRunsResponse;
+use databend_common_cloud_control::pb::ShowTasksRequest;
+use databend_common_cloud_control::pb::ShowTasksResponse;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_kvapi::kvapi;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::MetaError;
+use databend_common_meta_types::Operation;
+use databend_common_meta_types::SeqV;
+use databend_common_meta_types::UpsertKV;
+use databend_common_meta_types::With;
+use prost::Message;
+
+pub static TASK_API_KEY_PREFIX: &str = "__fd_tasks";
+
+/// How long the history of a task run is kept in meta.
+pub const TASK_RUN_TTL: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// The default number of task runs returned by `show_task_runs`.
+const DEFAULT_TASK_RUNS_LIMIT: usize = 100;
+
+/// How many times an update of a task is retried when it races with the scheduler.
+const MAX_UPDATE_RETRIES: usize = 10;
+
+// example:
+// __fd_tasks/v1/test_tenant
+//      |- /tasks/daily_compact: pb::Task { task_id: 7, task_name: "daily_compact", ... }
+//      |- /runs/00000000000000000007/00000000000000000042: pb::TaskRun { task_id: 7, run_id: "42", ... }
+//      |- /id_gen: ""
+//      |- /leader: "RUV9DQArNnP4Hej4A74f07"
+//
+// Tasks and task runs are stored with the same protobuf messages the cloud control task
+// service exchanges, so both backends share the interpreters and the system tables.
+pub struct TaskMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    task_key_prefix: String,
+    run_key_prefix: String,
+    id_gen_key: String,
+    leader_key: String,
+}
+
+impl TaskMgr {
+    pub fn create(
+        kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+        tenant: &Tenant,
+    ) -> Result<Self> {
+        let prefix = format!(
+            "{}/v1/{}",
+            TASK_API_KEY_PREFIX,
+            escape_for_key(tenant.tenant_name())?
+        );
+        Ok(TaskMgr {
+            kv_api,
+            task_key_prefix: format!("{}/tasks", prefix),
+            run_key_prefix: format!("{}/runs", prefix),
+            id_gen_key: format!("{}/id_gen", prefix),
+            leader_key: format!("{}/leader", prefix),
+        })
+    }
+
+    fn task_key(&self, task_name: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}",
+            self.task_key_prefix,
+            escape_for_key(task_name)?
+        ))
+    }
+
+    fn task_runs_prefix(&self, task_id: u64) -> String {
+        format!("{}/{:020}/", self.run_key_prefix, task_id)
+    }
+
+    /// Generate an id for a task or a task run.
+    ///
+    /// The seq of the id generator key is monotonically incremental.
+    #[async_backtrace::framed]
+    pub async fn fetch_id(&self) -> Result<u64> {
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKV::update(&self.id_gen_key, b""))
+            .await?;
+        // seq: MatchSeq::GE(0) always succeeds
+        Ok(res.result.map(|v| v.seq).unwrap_or_default())
+    }
+
+    #[async_backtrace::framed]
+    pub async fn get_task(&self, task_name: &str) -> Result<Option<SeqV<pb::Task>>> {
+        let key = self.task_key(task_name)?;
+        match self.kv_api.get_kv(&key).await? {
+            None => Ok(None),
+            Some(seq_v) => Ok(Some(SeqV::new(seq_v.seq, decode(&seq_v.data)?))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn list_tasks(&self) -> Result<Vec<SeqV<pb::Task>>> {
+        let prefix = format!("{}/", self.task_key_prefix);
+        let values = self.kv_api.prefix_list_kv(&prefix).await?;
+
+        let mut tasks = Vec::with_capacity(values.len());
+        for (_key, seq_v) in values {
+            tasks.push(SeqV::new(seq_v.seq, decode(&seq_v.data)?));
+        }
+        Ok(tasks)
+    }
+
+    /// Replace a task if it is not changed since `seq` was read.
+    ///
+    /// Returns `false` if the task has been changed or dropped by someone else.
+    #[async_backtrace::framed]
+    pub async fn update_task(&self, seq: u64, task: &pb::Task) -> Result<bool> {
+        let key = self.task_key(&task.task_name)?;
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKV::update(&key, &task.encode_to_vec()).with(MatchSeq::Exact(seq)))
+            .await?;
+        Ok(res.is_changed())
+    }
+
+    #[async_backtrace::framed]
+    pub async fn create_task(&self, req: CreateTaskRequest) -> Result<CreateTaskResponse> {
+        if let Some(task) = self.get_task(&req.task_name).await? {
+            return match req.if_not_exist {
+                true => Ok(CreateTaskResponse {
+                    error: None,
+                    task_id: task.data.task_id,
+                }),
+                false => Err(task_already_exists(&req.task_name)),
+            };
+        }
+
+        self.check_predecessors(&req.task_name, &req.after).await?;
+
+        let now = Utc::now().to_rfc3339();
+        let task = pb::Task {
+            task_id: self.fetch_id().await?,
+            task_name: req.task_name.clone(),
+            query_text: req.query_text,
+            comment: req.comment,
+            owner: req.owner,
+            schedule_options: req.schedule_options,
+            warehouse_options: req.warehouse_options,
+            next_scheduled_at: None,
+            suspend_task_after_num_failures: req.suspend_task_after_num_failures,
+            status: Status::Suspended as i32,
+            created_at: now.clone(),
+            updated_at: now,
+            last_suspended_at: None,
+            after: dedup(req.after),
+            when_condition: req.when_condition,
+            session_parameters: req.session_parameters,
+            error_integration: req.error_integration,
+        };
+
+        let key = self.task_key(&req.task_name)?;
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKV::insert(&key, &task.encode_to_vec()))
+            .await?;
+        if !res.is_changed() && !req.if_not_exist {
+            return Err(task_already_exists(&req.task_name));
+        }
+
+        Ok(CreateTaskResponse {
+            error: None,
+            task_id: task.task_id,
+        })
+    }
+
+    #[async_backtrace::framed]
+    pub async fn describe_task(&self, req: DescribeTaskRequest) -> Result<DescribeTaskResponse> {
+        let task = self.get_task(&req.task_name).await?;
+        if task.is_none() && !req.if_exist {
+            return Err(unknown_task(&req.task_name));
+        }
+
+        Ok(DescribeTaskResponse {
+            task: task.map(|v| v.data),
+            error: None,
+        })
+    }
+
+    #[async_backtrace::framed]
+    pub async fn drop_task(&self, req: DropTaskRequest) -> Result<DropTaskResponse> {
+        let Some(task) = self.get_task(&req.task_name).await? else {
+            return match req.if_exist {
+                true => Ok(DropTaskResponse { error: None }),
+                false => Err(unknown_task(&req.task_name)),
+            };
+        };
+
+        if let Some(dependent) = self
+            .list_tasks()
+            .await?
+            .into_iter()
+            .find(|v| v.data.after.contains(&req.task_name))
+        {
+            return Err(ErrorCode::TaskDependencyError(format!(
+                "Task '{}' can not be dropped, it is a predecessor of task '{}'",
+                req.task_name, dependent.data.task_name
+            )));
+        }
+
+        let key = self.task_key(&req.task_name)?;
+        self.kv_api
+            .upsert_kv(UpsertKV::new(
+                &key,
+                MatchSeq::Exact(task.seq),
+                Operation::Delete,
+                None,
+            ))
+            .await?;
+        Ok(DropTaskResponse { error: None })
+    }
+
+    #[async_backtrace::framed]
+    pub async fn alter_task(&self, req: AlterTaskRequest) -> Result<AlterTaskResponse> {
+        let alter_type = AlterTaskType::try_from(req.alter_task_type).map_err(|_| {
+            ErrorCode::BadArguments(format!("Illegal alter task type {}", req.alter_task_type))
+        })?;
+        if alter_type == AlterTaskType::AddAfter {
+            self.check_predecessors(&req.task_name, &req.add_after)
+                .await?;
+        }
+
+        for _ in 0..MAX_UPDATE_RETRIES {
+            let Some(SeqV { seq, data, .. }) = self.get_task(&req.task_name).await? else {
+                return match req.if_exist {
+                    true => Ok(AlterTaskResponse {
+                        error: None,
+                        task: None,
+                    }),
+                    false => Err(unknown_task(&req.task_name)),
+                };
+            };
+
+            let mut task = data;
+            let now = Utc::now();
+            match alter_type {
+                AlterTaskType::Suspend => {
+                    task.status = Status::Suspended as i32;
+                    task.next_scheduled_at = None;
+                    task.last_suspended_at = Some(now.to_rfc3339());
+                }
+                AlterTaskType::Resume => {
+                    task.status = Status::Started as i32;
+                    task.next_scheduled_at = first_scheduled_at(&task, now)?;
+                }
+                AlterTaskType::Set => {
+                    if let Some(schedule_options) = &req.schedule_options {
+                        task.schedule_options = Some(schedule_options.clone());
+                        if task.status == Status::Started as i32 {
+                            task.next_scheduled_at = first_scheduled_at(&task, now)?;
+                        }
+                    }
+                    if req.comment.is_some() {
+                        task.comment = req.comment.clone();
+                    }
+                    if req.warehouse_options.is_some() {
+                        task.warehouse_options = req.warehouse_options.clone();
+                    }
+                    if req.error_integration.is_some() {
+                        task.error_integration = req.error_integration.clone();
+                    }
+                    if req.suspend_task_after_num_failures.is_some() {
+                        task.suspend_task_after_num_failures = req.suspend_task_after_num_failures;
+                    }
+                    if req.set_session_parameters {
+                        task.session_parameters = req.session_parameters.clone();
+                    }
+                }
+                AlterTaskType::ModifyAs => {
+                    task.query_text = req.query_text.clone().unwrap_or_default();
+                }
+                AlterTaskType::ModifyWhen => {
+                    task.when_condition = req.when_condition.clone();
+                }
+                AlterTaskType::AddAfter => {
+                    task.after.extend(req.add_after.iter().cloned());
+                    task.after = dedup(std::mem::take(&mut task.after));
+                    task.next_scheduled_at = None;
+                }
+                AlterTaskType::RemoveAfter => {
+                    task.after.retain(|name| !req.remove_after.contains(name));
+                    if task.status == Status::Started as i32 {
+                        task.next_scheduled_at = first_scheduled_at(&task, now)?;
+                    }
+                }
+            }
+            task.updated_at = now.to_rfc3339();
+
+            if self.update_task(seq, &task).await? {
+                return Ok(AlterTaskResponse {
+                    error: None,
+                    task: Some(task),
+                });
+            }
+        }
+
+        Err(ErrorCode::MetaServiceError(format!(
+            "Task '{}' is modified concurrently, please retry",
+            req.task_name
+        )))
+    }
+
+    #[async_backtrace::framed]
+    pub async fn show_tasks(&self, req: ShowTasksRequest) -> Result<ShowTasksResponse> {
+        let mut tasks = self
+            .list_tasks()
+            .await?
+            .into_iter()
+            .map(|v| v.data)
+            .filter(|task| req.owners.is_empty() || req.owners.contains(&task.owner))
+            .filter(|task| {
+                req.task_ids.is_empty() || req.task_ids.contains(&task.task_id.to_string())
+            })
+            .collect::<Vec<_>>();
+        tasks.sort_by(|a, b| a.task_name.cmp(&b.task_name));
+        if req.result_limit > 0 {
+            tasks.truncate(req.result_limit as usize);
+        }
+
+        Ok(ShowTasksResponse { tasks, error: None })
+    }
+
+    #[async_backtrace::framed]
+    pub async fn show_task_runs(&self, req: ShowTaskRunsRequest) -> Result<ShowTaskRunsResponse> {
+        let start = parse_time_opt(&req.scheduled_time_start)?;
+        let end = parse_time_opt(&req.scheduled_time_end)?;
+
+        let prefix = format!("{}/", self.run_key_prefix);
+        let values = self.kv_api.prefix_list_kv(&prefix).await?;
+
+        let mut task_runs = Vec::with_capacity(values.len());
+        for (_key, seq_v) in values {
+            let run: pb::TaskRun = decode(&seq_v.data)?;
+            if !req.task_name.is_empty() && run.task_name != req.task_name {
+                continue;
+            }
+            if !req.task_names.is_empty() && !req.task_names.contains(&run.task_name) {
+                continue;
+            }
+            if !req.task_ids.is_empty() && !req.task_ids.contains(&run.task_id.to_string()) {
+                continue;
+            }
+            if !req.owners.is_empty() && !req.owners.contains(&run.owner) {
+                continue;
+            }
+            if req
+                .root_task_id
+                .as_ref()
+                .is_some_and(|id| id != &run.root_task_id)
+            {
+                continue;
+            }
+            if req.error_only
+                && run.state != State::Failed as i32
+                && run.state != State::Cancelled as i32
+            {
+                continue;
+            }
+            if start.is_some() || end.is_some() {
+                let scheduled_at = parse_time(&run.scheduled_time)?;
+                if start.is_some_and(|start| scheduled_at < start)
+                    || end.is_some_and(|end| scheduled_at > end)
+                {
+                    continue;
+                }
+            }
+            task_runs.push(run);
+        }
+
+        // The latest runs first.
+        task_runs.sort_by(|a, b| {
+            b.scheduled_time
+                .cmp(&a.scheduled_time)
+                .then_with(|| b.run_id.cmp(&a.run_id))
+        });
+        let limit = match req.result_limit {
+            limit if limit > 0 => limit as usize,
+            _ => DEFAULT_TASK_RUNS_LIMIT,
+        };
+        task_runs.truncate(limit);
+
+        Ok(ShowTaskRunsResponse {
+            task_runs,
+            error: None,
+            next_page_token: None,
+            previous_page_token: None,
+        })
+    }
+
+    /// Returns the task followed by the tasks that run after it, in breadth-first order.
+    #[async_backtrace::framed]
+    pub async fn get_task_dependents(
+        &self,
+        req: GetTaskDependentsRequest,
+    ) -> Result<GetTaskDependentsResponse> {
+        let tasks = self
+            .list_tasks()
+            .await?
+            .into_iter()
+            .map(|v| v.data)
+            .collect::<Vec<_>>();
+        let Some(root) = tasks.iter().find(|t| t.task_name == req.task_name) else {
+            return Err(unknown_task(&req.task_name));
+        };
+
+        let mut dependents = vec![root.clone()];
+        let mut visited = HashSet::from([root.task_name.clone()]);
+        let mut queue = VecDeque::from([(root.task_name.clone(), 0)]);
+        while let Some((name, depth)) = queue.pop_front() {
+            if depth > 0 && !req.recursive {
+                break;
+            }
+            for task in tasks.iter().filter(|t| t.after.contains(&name)) {
+                if visited.insert(task.task_name.clone()) {
+                    dependents.push(task.clone());
+                    queue.push_back((task.task_name.clone(), depth + 1));
+                }
+            }
+        }
+
+        Ok(GetTaskDependentsResponse {
+            task: dependents,
+            error: None,
+        })
+    }
+
+    /// Resume the task and all the tasks that run after it, recursively.
+    #[async_backtrace::framed]
+    pub async fn enable_task_dependents(
+        &self,
+        req: EnableTaskDependentsRequest,
+    ) -> Result<EnableTaskDependentsResponse> {
+        let dependents = self
+            .get_task_dependents(GetTaskDependentsRequest {
+                tenant_id: req.tenant_id.clone(),
+                task_name: req.task_name.clone(),
+                recursive: true,
+            })
+            .await?;
+
+        for task in dependents.task {
+            if task.status == Status::Started as i32 {
+                continue;
+            }
+            self.alter_task(AlterTaskRequest {
+                task_name: task.task_name,
+                tenant_id: req.tenant_id.clone(),
+                alter_task_type: AlterTaskType::Resume as i32,
+                if_exist: true,
+                ..Default::default()
+            })
+            .await?;
+        }
+        Ok(EnableTaskDependentsResponse { error: None })
+    }
+
+    /// List the runs of a task kept in meta, ordered by run id.
+    #[async_backtrace::framed]
+    pub async fn list_task_runs(&self, task_id: u64) -> Result<Vec<pb::TaskRun>> {
+        let values = self
+            .kv_api
+            .prefix_list_kv(&self.task_runs_prefix(task_id))
+            .await?;
+
+        let mut task_runs = Vec::with_capacity(values.len());
+        for (_key, seq_v) in values {
+            task_runs.push(decode(&seq_v.data)?);
+        }
+        task_runs.sort_by_key(|run: &pb::TaskRun| run.run_id.parse::<u64>().unwrap_or_default());
+        Ok(task_runs)
+    }
+
+    /// Insert or replace a task run, it expires after [`TASK_RUN_TTL`].
+    #[async_backtrace::framed]
+    pub async fn save_task_run(&self, run: &pb::TaskRun) -> Result<()> {
+        let run_id = run.run_id.parse::<u64>().map_err(|e| {
+            ErrorCode::BadArguments(format!("Illegal task run id {}: {e}", run.run_id))
+        })?;
+        let key = format!("{}{:020}", self.task_runs_prefix(run.task_id), run_id);
+        self.kv_api
+            .upsert_kv(UpsertKV::update(&key, &run.encode_to_vec()).with_ttl(TASK_RUN_TTL))
+            .await?;
+        Ok(())
+    }
+
+    /// Try to become, or stay, the node that schedules the tasks of the tenant.
+    ///
+    /// The leadership is a lease: it is lost if it is not renewed within `ttl`.
+    #[async_backtrace::framed]
+    pub async fn try_acquire_leader(&self, node_id: &str, ttl: Duration) -> Result<bool> {
+        let seq = match self.kv_api.get_kv(&self.leader_key).await? {
+            None => MatchSeq::Exact(0),
+            Some(seq_v) if seq_v.data == node_id.as_bytes() => MatchSeq::Exact(seq_v.seq),
+            Some(_) => return Ok(false),
+        };
+
+        let res = self
+            .kv_api
+            .upsert_kv(
+                UpsertKV::update(&self.leader_key, node_id.as_bytes())
+                    .with(seq)
+                    .with_ttl(ttl),
+            )
+            .await?;
+        Ok(res.is_changed())
+    }
+
+    /// Give up the leadership so that another node can take over without waiting for the lease.
+    #[async_backtrace::framed]
+    pub async fn resign_leader(&self, node_id: &str) -> Result<()> {
+        if let Some(seq_v) = self.kv_api.get_kv(&self.leader_key).await? {
+            if seq_v.data == node_id.as_bytes() {
+                self.kv_api
+                    .upsert_kv(UpsertKV::new(
+                        &self.leader_key,
+                        MatchSeq::Exact(seq_v.seq),
+                        Operation::Delete,
+                        None,
+                    ))
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// All predecessors must exist and adding them must not make the task graph cyclic.
+    #[async_backtrace::framed]
+    async fn check_predecessors(&self, task_name: &str, after: &[String]) -> Result<()> {
+        if after.is_empty() {
+            return Ok(());
+        }
+
+        let predecessors = self
+            .list_tasks()
+            .await?
+            .into_iter()
+            .map(|v| (v.data.task_name, v.data.after))
+            .collect::<HashMap<_, _>>();
+
+        for name in after {
+            if name == task_name {
+                return Err(ErrorCode::TaskDependencyError(format!(
+                    "Task '{}' can not run after itself",
+                    task_name
+                )));
+            }
+            if !predecessors.contains_key(name) {
+                return Err(ErrorCode::UnknownTask(format!(
+                    "Predecessor task '{}' does not exist",
+                    name
+                )));
+            }
+        }
+
+        // Walk upstream from the new predecessors, the task must not be reachable.
+        let mut visited = HashSet::new();
+        let mut stack = after.to_vec();
+        while let Some(name) = stack.pop() {
+            if name == task_name {
+                return Err(ErrorCode::TaskDependencyError(format!(
+                    "Task '{}' can not run after {:?}, the task graph would be cyclic",
+                    task_name, after
+                )));
+            }
+            if visited.insert(name.clone()) {
+                if let Some(upstream) = predecessors.get(&name) {
+                    stack.extend(upstream.iter().cloned());
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the first time after `now` a task with `schedule` should run.
+pub fn next_scheduled_at(
+    schedule: &pb::ScheduleOptions,
+    now: DateTime<Utc>,
+) -> Result<DateTime<Utc>> {
+    match ScheduleType::try_from(schedule.schedule_type) {
+        Ok(ScheduleType::IntervalType) => {
+            let interval_ms = schedule.interval.unwrap_or_default() as i64 * 1000
+                + schedule.milliseconds_interval.unwrap_or_default() as i64;
+            if interval_ms <= 0 {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Illegal task schedule interval {}ms",
+                    interval_ms
+                )));
+            }
+            Ok(now + chrono::Duration::milliseconds(interval_ms))
+        }
+        Ok(ScheduleType::CronType) => {
+            let Some(expr) = schedule.cron.as_ref() else {
+                return Err(ErrorCode::BadArguments(
+                    "cron expression schedule has null value",
+                ));
+            };
+            let cron = cron::Schedule::from_str(expr).map_err(|e| {
+                ErrorCode::BadArguments(format!("invalid cron expression {}: {e}", expr))
+            })?;
+            let tz = match schedule.time_zone.as_deref() {
+                Some(tz) if !tz.is_empty() => chrono_tz::Tz::from_str(tz)
+                    .map_err(|e| ErrorCode::BadArguments(format!("invalid time zone {tz}: {e}")))?,
+                _ => chrono_tz::UTC,
+            };
+            cron.after(&now.with_timezone(&tz))
+                .next()
+                .map(|t| t.with_timezone(&Utc))
+                .ok_or_else(|| {
+                    ErrorCode::BadArguments(format!("cron expression {} never fires", expr))
+                })
+        }
+        Err(_) => Err(ErrorCode::BadArguments(format!(
+            "Illegal schedule type {}",
+            schedule.schedule_type
+        ))),
+    }
+}
+
+/// Only started root tasks are fired by schedule, the others are triggered by their predecessors.
+fn first_scheduled_at(task: &pb::Task, now: DateTime<Utc>) -> Result<Option<String>> {
+    match &task.schedule_options {
+        Some(schedule) if task.after.is_empty() => {
+            Ok(Some(next_scheduled_at(schedule, now)?.to_rfc3339()))
+        }
+        _ => Ok(None),
+    }
+}
+
+fn decode<T: Message + Default>(data: &[u8]) -> Result<T> {
+    T::decode(data).map_err(|e| {
+        ErrorCode::IllegalCloudControlMessageFormat(format!("Illegal task record in meta: {e}"))
+    })
+}
+
+fn dedup(names: Vec<String>) -> Vec<String> {
+    let mut seen = HashSet::with_capacity(names.len());
+    names
+        .into_iter()
+        .filter(|name| seen.insert(name.clone()))
+        .collect()
+}
+
+/// Parse a RFC 3339 time, a trailing time zone annotation like `[UTC]` is ignored.
+fn parse_time(s: &str) -> Result<DateTime<Utc>> {
+    let trimmed = match s.find('[') {
+        Some(idx) => &s[..idx],
+        None => s,
+    };
+    DateTime::parse_from_rfc3339(trimmed)
+        .map(|t| t.with_timezone(&Utc))
+        .map_err(|e| ErrorCode::BadArguments(format!("Illegal time {}: {e}", s)))
+}
+
+fn parse_time_opt(s: &str) -> Result<Option<DateTime<Utc>>> {
+    match s.is_empty() {
+        true => Ok(None),
+        false => parse_time(s).map(Some),
+    }
+}
+
+fn task_already_exists(task_name: &str) -> ErrorCode {
+    ErrorCode::TaskAlreadyExists(format!("Task '{}' already exists", task_name))
+}
+
+fn unknown_task(task_name: &str) -> ErrorCode {
+    ErrorCode::UnknownTask(format!("Task '{}' does not exist", task_name))
+}
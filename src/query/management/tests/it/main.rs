@@ -15,10 +15,12 @@
 #![feature(duration_constructors)]
 #![allow(clippy::uninlined_format_args)]
 
+mod plan_baseline;
 mod quota;
 mod role;
 mod setting;
 mod stage;
+mod task;
 mod udf;
 mod user;
 mod warehouse;
@@ -0,0 +1,101 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_management::*;
+use databend_common_meta_app::principal::PlanBaseline;
+use databend_common_meta_app::principal::PlanBaselineHint;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_embedded::MemMeta;
+use databend_common_meta_kvapi::kvapi::KVApi;
+use fastrace::func_name;
+
+fn new_baseline(sql_hash: &str) -> PlanBaseline {
+    let now = Utc::now();
+    PlanBaseline {
+        sql_hash: sql_hash.to_string(),
+        query_text: "SELECT * FROM t1, t2 WHERE t1.a > t2.a".to_string(),
+        join_order: vec!["t2".to_string(), "t1".to_string()],
+        hints: vec![PlanBaselineHint {
+            name: "MERGE_JOIN".to_string(),
+            tables: vec!["t1".to_string()],
+        }],
+        plan_fingerprint: "e3b0c44298fc1c14".to_string(),
+        execution_time_ms: None,
+        rejected_fingerprints: vec![],
+        created_on: now,
+        updated_on: now,
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_plan_baseline() -> Result<()> {
+    let (kv_api, mgr) = new_plan_baseline_api()?;
+
+    let baseline = new_baseline("hash1");
+    let seq = mgr.upsert_baseline(&baseline).await?;
+    let value = kv_api
+        .get_kv("__fd_plan_baselines/v1/databend_query/hash1")
+        .await?
+        .unwrap();
+    assert_eq!(value.seq, seq);
+    assert_eq!(value.data, serde_json::to_vec(&baseline)?);
+
+    let got = mgr.get_baseline("hash1").await?.unwrap();
+    assert_eq!(got.seq, seq);
+    assert_eq!(got.data, baseline);
+    assert!(mgr.get_baseline("hash2").await?.is_none());
+
+    // Update with the seq it is read at.
+    let mut updated = baseline.clone();
+    updated.execution_time_ms = Some(100);
+    assert!(mgr.update_baseline(seq, &updated).await?);
+    // The baseline is changed since it is read.
+    let mut stale = baseline.clone();
+    stale
+        .rejected_fingerprints
+        .push("a1b2c3d4e5f60718".to_string());
+    assert!(!mgr.update_baseline(seq, &stale).await?);
+    assert_eq!(mgr.get_baseline("hash1").await?.unwrap().data, updated);
+
+    mgr.upsert_baseline(&new_baseline("hash2")).await?;
+    let mut hashes = mgr
+        .list_baselines()
+        .await?
+        .into_iter()
+        .map(|v| v.sql_hash)
+        .collect::<Vec<_>>();
+    hashes.sort();
+    assert_eq!(hashes, vec!["hash1".to_string(), "hash2".to_string()]);
+
+    assert!(mgr.drop_baseline("hash1").await?);
+    assert!(!mgr.drop_baseline("hash1").await?);
+    assert!(mgr.get_baseline("hash1").await?.is_none());
+    assert_eq!(mgr.list_baselines().await?.len(), 1);
+
+    Ok(())
+}
+
+fn new_plan_baseline_api() -> Result<(Arc<MemMeta>, PlanBaselineMgr)> {
+    let test_api = Arc::new(MemMeta::default());
+    let mgr = PlanBaselineMgr::create(
+        test_api.clone(),
+        &Tenant::new_or_err("databend_query", func_name!()).unwrap(),
+    )?;
+    Ok((test_api, mgr))
+}
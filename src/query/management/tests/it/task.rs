@@ -0,0 +1,221 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_base::base::tokio;
+use databend_common_cloud_control::pb;
+use databend_common_cloud_control::pb::alter_task_request::AlterTaskType;
+use databend_common_cloud_control::pb::schedule_options::ScheduleType;
+use databend_common_cloud_control::pb::task::Status;
+use databend_common_cloud_control::pb::AlterTaskRequest;
+use databend_common_cloud_control::pb::CreateTaskRequest;
+use databend_common_cloud_control::pb::DropTaskRequest;
+use databend_common_cloud_control::pb::GetTaskDependentsRequest;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_management::*;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_embedded::MemMeta;
+use fastrace::func_name;
+
+fn create_request(name: &str, after: &[&str]) -> CreateTaskRequest {
+    CreateTaskRequest {
+        task_name: name.to_string(),
+        tenant_id: "tenant1".to_string(),
+        query_text: "SELECT 1".to_string(),
+        owner: "account_admin".to_string(),
+        schedule_options: match after.is_empty() {
+            true => Some(pb::ScheduleOptions {
+                interval: Some(60),
+                schedule_type: ScheduleType::IntervalType as i32,
+                ..Default::default()
+            }),
+            false => None,
+        },
+        after: after.iter().map(|v| v.to_string()).collect(),
+        ..Default::default()
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_task_create_and_alter() -> Result<()> {
+    let mgr = new_task_api()?;
+
+    mgr.create_task(create_request("root", &[])).await?;
+    mgr.create_task(create_request("child", &["root"])).await?;
+
+    // Create again.
+    {
+        let res = mgr.create_task(create_request("root", &[])).await;
+        assert_eq!(
+            res.unwrap_err().code(),
+            ErrorCode::TaskAlreadyExists("").code()
+        );
+
+        let mut req = create_request("root", &[]);
+        req.if_not_exist = true;
+        assert!(mgr.create_task(req).await.is_ok());
+    }
+
+    // Unknown predecessor.
+    {
+        let res = mgr
+            .create_task(create_request("orphan", &["unknown"]))
+            .await;
+        assert_eq!(res.unwrap_err().code(), ErrorCode::UnknownTask("").code());
+    }
+
+    // Created tasks are suspended, resuming a root task schedules it.
+    {
+        let task = mgr.get_task("root").await?.unwrap().data;
+        assert_eq!(task.status, Status::Suspended as i32);
+        assert!(task.next_scheduled_at.is_none());
+
+        mgr.alter_task(AlterTaskRequest {
+            task_name: "root".to_string(),
+            tenant_id: "tenant1".to_string(),
+            alter_task_type: AlterTaskType::Resume as i32,
+            ..Default::default()
+        })
+        .await?;
+        let task = mgr.get_task("root").await?.unwrap().data;
+        assert_eq!(task.status, Status::Started as i32);
+        assert!(task.next_scheduled_at.is_some());
+    }
+
+    // The graph must stay acyclic.
+    {
+        let res = mgr
+            .alter_task(AlterTaskRequest {
+                task_name: "root".to_string(),
+                tenant_id: "tenant1".to_string(),
+                alter_task_type: AlterTaskType::AddAfter as i32,
+                add_after: vec!["child".to_string()],
+                ..Default::default()
+            })
+            .await;
+        assert_eq!(
+            res.unwrap_err().code(),
+            ErrorCode::TaskDependencyError("").code()
+        );
+    }
+
+    // Dependents.
+    {
+        let resp = mgr
+            .get_task_dependents(GetTaskDependentsRequest {
+                task_name: "root".to_string(),
+                tenant_id: "tenant1".to_string(),
+                recursive: true,
+            })
+            .await?;
+        let names = resp
+            .task
+            .iter()
+            .map(|t| t.task_name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["root", "child"]);
+    }
+
+    // A predecessor can not be dropped.
+    {
+        let res = mgr
+            .drop_task(DropTaskRequest {
+                task_name: "root".to_string(),
+                tenant_id: "tenant1".to_string(),
+                if_exist: false,
+            })
+            .await;
+        assert_eq!(
+            res.unwrap_err().code(),
+            ErrorCode::TaskDependencyError("").code()
+        );
+
+        for name in ["child", "root"] {
+            mgr.drop_task(DropTaskRequest {
+                task_name: name.to_string(),
+                tenant_id: "tenant1".to_string(),
+                if_exist: false,
+            })
+            .await?;
+        }
+        assert!(mgr.list_tasks().await?.is_empty());
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_task_leader() -> Result<()> {
+    let mgr = new_task_api()?;
+    let lease = Duration::from_secs(10);
+
+    assert!(mgr.try_acquire_leader("node1", lease).await?);
+    // Renew.
+    assert!(mgr.try_acquire_leader("node1", lease).await?);
+    assert!(!mgr.try_acquire_leader("node2", lease).await?);
+
+    mgr.resign_leader("node1").await?;
+    assert!(mgr.try_acquire_leader("node2", lease).await?);
+    assert!(!mgr.try_acquire_leader("node1", lease).await?);
+
+    Ok(())
+}
+
+#[test]
+fn test_next_scheduled_at() -> Result<()> {
+    let now = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 30).unwrap();
+
+    let interval = pb::ScheduleOptions {
+        interval: Some(2),
+        milliseconds_interval: Some(500),
+        schedule_type: ScheduleType::IntervalType as i32,
+        ..Default::default()
+    };
+    assert_eq!(
+        next_scheduled_at(&interval, now)?,
+        now + chrono::Duration::milliseconds(2500)
+    );
+
+    let cron = pb::ScheduleOptions {
+        cron: Some("0 */5 * * * *".to_string()),
+        time_zone: Some("Asia/Shanghai".to_string()),
+        schedule_type: ScheduleType::CronType as i32,
+        ..Default::default()
+    };
+    assert_eq!(
+        next_scheduled_at(&cron, now)?,
+        Utc.with_ymd_and_hms(2024, 1, 1, 0, 5, 0).unwrap()
+    );
+
+    let zero = pb::ScheduleOptions {
+        schedule_type: ScheduleType::IntervalType as i32,
+        ..Default::default()
+    };
+    assert!(next_scheduled_at(&zero, now).is_err());
+
+    Ok(())
+}
+
+fn new_task_api() -> Result<TaskMgr> {
+    let test_api = Arc::new(MemMeta::default());
+    TaskMgr::create(
+        test_api,
+        &Tenant::new_or_err("tenant1", func_name!()).unwrap(),
+    )
+}
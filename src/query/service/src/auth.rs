@@ -27,6 +27,7 @@ use databend_common_users::JwtAuthenticator;
 use databend_common_users::UserApiProvider;
 use fastrace::func_name;
 
+use crate::history_tables::HistoryLog;
 use crate::servers::http::v1::ClientSessionManager;
 use crate::sessions::Session;
 
@@ -97,6 +98,41 @@ impl AuthMgr {
         session: &mut Session,
         credential: &Credential,
         need_user_info: bool,
+    ) -> Result<(String, Option<String>)> {
+        let res = self
+            .auth_credential(session, credential, need_user_info)
+            .await;
+
+        // The databend tokens are verified on every request of a session, they are not logins.
+        let login = match credential {
+            Credential::Jwt { client_ip, .. } => Some(("", client_ip)),
+            Credential::Password {
+                name, client_ip, ..
+            } => Some((name.as_str(), client_ip)),
+            Credential::DatabendToken { .. } | Credential::NoNeed => None,
+        };
+        if let (Some(history), Some((name, client_ip))) = (HistoryLog::instance(), login) {
+            let user_name = match &res {
+                Ok((user_name, _)) => user_name.clone(),
+                Err(_) => name.to_string(),
+            };
+            history.record_login(
+                session.get_type().to_string(),
+                format!("{:?}", credential.type_name()),
+                user_name,
+                client_ip.clone().unwrap_or_default(),
+                res.as_ref().map(|_| ()),
+            );
+        }
+        res
+    }
+
+    #[async_backtrace::framed]
+    async fn auth_credential(
+        &self,
+        session: &mut Session,
+        credential: &Credential,
+        need_user_info: bool,
     ) -> Result<(String, Option<String>)> {
         let user_api = UserApiProvider::instance();
         let global_network_policy = session
@@ -16,6 +16,7 @@ use std::any::Any;
 use std::fmt::Debug;
 use std::sync::Arc;
 
+use chrono::Utc;
 use databend_common_catalog::catalog::StorageDescription;
 use databend_common_catalog::database::Database;
 use databend_common_catalog::table::Table;
@@ -36,6 +37,7 @@ use databend_common_meta_app::schema::CreateIndexReply;
 use databend_common_meta_app::schema::CreateIndexReq;
 use databend_common_meta_app::schema::CreateLockRevReply;
 use databend_common_meta_app::schema::CreateLockRevReq;
+use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_app::schema::CreateSequenceReply;
 use databend_common_meta_app::schema::CreateSequenceReq;
 use databend_common_meta_app::schema::CreateTableIndexReq;
@@ -84,6 +86,7 @@ use databend_common_meta_app::schema::RenameTableReply;
 use databend_common_meta_app::schema::RenameTableReq;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::schema::TruncateTableReply;
@@ -98,11 +101,14 @@ use databend_common_meta_app::schema::UpdateIndexReply;
 use databend_common_meta_app::schema::UpdateIndexReq;
 use databend_common_meta_app::schema::UpdateMultiTableMetaReq;
 use databend_common_meta_app::schema::UpdateMultiTableMetaResult;
+use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_app::schema::UpdateVirtualColumnReq;
 use databend_common_meta_app::schema::UpsertTableOptionReply;
 use databend_common_meta_app::schema::UpsertTableOptionReq;
 use databend_common_meta_app::schema::VirtualColumnMeta;
 use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::MatchSeqExt;
 use databend_common_meta_types::MetaId;
 use databend_common_meta_types::SeqV;
 use databend_storages_common_session::SessionState;
@@ -394,6 +400,12 @@ impl Catalog for SessionCatalog {
         if let Some(table) = table_in_txn {
             return table;
         }
+        if is_active && self.txn_mgr.lock().is_table_released(db_name, table_name) {
+            return Err(ErrorCode::UnknownTable(format!(
+                "Unknown table '{}'.'{}'",
+                db_name, table_name
+            )));
+        }
         if let Some(table) = self.temp_tbl_mgr.lock().get_table(db_name, table_name)? {
             return self.get_table_by_info(&table);
         }
@@ -445,11 +457,99 @@ impl Catalog for SessionCatalog {
         self.inner.gc_drop_tables(req).await
     }
 
-    async fn create_table(&self, req: CreateTableReq) -> Result<CreateTableReply> {
-        match req.table_meta.options.get(OPT_KEY_TEMP_PREFIX) {
-            Some(_) => self.temp_tbl_mgr.lock().create_table(req),
-            None => self.inner.create_table(req).await,
+    async fn create_table(&self, mut req: CreateTableReq) -> Result<CreateTableReply> {
+        if req.table_meta.options.contains_key(OPT_KEY_TEMP_PREFIX) {
+            return self.temp_tbl_mgr.lock().create_table(req);
         }
+        if req.as_dropped || !self.txn_mgr.lock().is_active() {
+            return self.inner.create_table(req).await;
+        }
+
+        // In a multi-statement transaction, the table is created as dropped,
+        // and made visible when the transaction commits.
+        let name_ident = req.name_ident.clone();
+        let table_in_txn = self.txn_mgr.lock().get_table_from_buffer(
+            &name_ident.tenant,
+            &name_ident.db_name,
+            &name_ident.table_name,
+        );
+        if let Some(table_info) = table_in_txn {
+            return match req.create_option {
+                CreateOption::Create => Err(ErrorCode::TableAlreadyExists(format!(
+                    "Table '{}' already exists",
+                    name_ident.table_name
+                ))),
+                CreateOption::CreateIfNotExists => {
+                    let db = self
+                        .get_database(&name_ident.tenant, &name_ident.db_name)
+                        .await?;
+                    Ok(CreateTableReply {
+                        table_id: table_info.ident.table_id,
+                        table_id_seq: None,
+                        db_id: db.get_db_info().database_id.db_id,
+                        new_table: false,
+                        spec_vec: None,
+                        prev_table_id: None,
+                        orphan_table_name: None,
+                    })
+                }
+                CreateOption::CreateOrReplace => Err(ErrorCode::Unimplemented(format!(
+                    "Cannot replace table '{}' changed in the current transaction",
+                    name_ident.table_name
+                ))),
+            };
+        }
+
+        req.as_dropped = true;
+        req.table_meta.drop_on = Some(Utc::now());
+        if self
+            .txn_mgr
+            .lock()
+            .is_table_released(&name_ident.db_name, &name_ident.table_name)
+        {
+            // the name is taken over from the table dropped or renamed in this txn
+            req.create_option = CreateOption::CreateOrReplace;
+        }
+        let reply = self.inner.create_table(req.clone()).await?;
+        let Some(orphan_table_name) = reply.orphan_table_name.clone() else {
+            // `CREATE TABLE IF NOT EXISTS` on an existing table
+            return Ok(reply);
+        };
+        let table_id_seq = reply.table_id_seq.ok_or_else(|| {
+            ErrorCode::Internal(format!(
+                "table id seq of the new table '{}' is missing",
+                name_ident.table_name
+            ))
+        })?;
+
+        let table_id = reply.table_id;
+        let table_info = TableInfo::new_full(
+            &name_ident.db_name,
+            &name_ident.table_name,
+            TableIdent::new(table_id, table_id_seq),
+            req.table_meta.clone(),
+            self.info(),
+        );
+        let mut txn_mgr = self.txn_mgr.lock();
+        txn_mgr.update_multi_table_meta(UpdateMultiTableMetaReq {
+            update_table_metas: vec![(
+                UpdateTableMetaReq {
+                    table_id,
+                    seq: MatchSeq::Exact(table_id_seq),
+                    new_table_meta: req.table_meta,
+                },
+                table_info,
+            )],
+            ..Default::default()
+        });
+        txn_mgr.add_created_table(CommitTableMetaReq {
+            name_ident,
+            db_id: reply.db_id,
+            table_id,
+            prev_table_id: reply.prev_table_id,
+            orphan_table_name: Some(orphan_table_name),
+        });
+        Ok(reply)
     }
 
     async fn drop_table_by_id(&self, req: DropTableByIdReq) -> Result<DropTableReply> {
@@ -461,6 +561,13 @@ impl Catalog for SessionCatalog {
         {
             return Ok(reply);
         }
+        if self.txn_mgr.lock().is_active() {
+            // the table is dropped when the transaction commits
+            let db_name = self.get_db_name_by_id(req.db_id).await?;
+            let desc = format!("'{}'.'{}'", db_name, req.table_name);
+            self.txn_mgr.lock().drop_table(&desc, req);
+            return Ok(DropTableReply {});
+        }
         self.inner.drop_table_by_id(req).await
     }
 
@@ -474,17 +581,29 @@ impl Catalog for SessionCatalog {
 
     async fn commit_table_meta(&self, req: CommitTableMetaReq) -> Result<CommitTableMetaReply> {
         if is_temp_table_id(req.table_id) {
-            self.temp_tbl_mgr.lock().commit_table_meta(&req)
-        } else {
-            self.inner.commit_table_meta(req).await
+            return self.temp_tbl_mgr.lock().commit_table_meta(&req);
+        }
+        {
+            // the table becomes visible when the transaction commits
+            let mut txn_mgr = self.txn_mgr.lock();
+            if txn_mgr.is_active() {
+                txn_mgr.add_created_table(req);
+                return Ok(CommitTableMetaReply {});
+            }
         }
+        self.inner.commit_table_meta(req).await
     }
 
     async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply> {
         let reply = self.temp_tbl_mgr.lock().rename_table(&req)?;
         match reply {
             Some(r) => Ok(r),
-            None => self.inner.rename_table(req).await,
+            None => {
+                if self.txn_mgr.lock().is_active() {
+                    return self.rename_table_in_txn(req).await;
+                }
+                self.inner.rename_table(req).await
+            }
         }
     }
 
@@ -496,6 +615,8 @@ impl Catalog for SessionCatalog {
     ) -> Result<UpsertTableOptionReply> {
         if is_temp_table_id(req.table_id) {
             self.temp_tbl_mgr.lock().upsert_table_option(req)
+        } else if self.txn_mgr.lock().is_active() {
+            self.upsert_table_option_in_txn(db_name, req).await
         } else {
             self.inner.upsert_table_option(tenant, db_name, req).await
         }
@@ -571,6 +692,11 @@ impl Catalog for SessionCatalog {
             self.temp_tbl_mgr
                 .lock()
                 .get_table_copied_file_info(req.clone())?
+        } else if self.txn_mgr.lock().is_table_truncated(table_id) {
+            // the copied files are removed by the truncation in this transaction
+            GetTableCopiedFileReply {
+                file_info: Default::default(),
+            }
         } else {
             self.inner
                 .get_table_copied_file_info(tenant, db_name, req)
@@ -589,6 +715,10 @@ impl Catalog for SessionCatalog {
     ) -> Result<TruncateTableReply> {
         if is_temp_table_id(req.table_id) {
             self.temp_tbl_mgr.lock().truncate_table(req.table_id)
+        } else if self.txn_mgr.lock().is_active() {
+            // the copied files are removed when the transaction commits
+            self.txn_mgr.lock().truncate_table(req.table_id);
+            Ok(TruncateTableReply {})
         } else {
             self.inner.truncate_table(table_info, req).await
         }
@@ -761,4 +891,110 @@ impl SessionCatalog {
     pub fn inner(&self) -> MutableCatalog {
         self.inner.clone()
     }
+
+    // The table is renamed when the transaction commits.
+    async fn rename_table_in_txn(&self, req: RenameTableReq) -> Result<RenameTableReply> {
+        let tenant = req.tenant().clone();
+        let name_ident = &req.name_ident;
+        let table = match self
+            .get_table(&tenant, &name_ident.db_name, &name_ident.table_name)
+            .await
+        {
+            Ok(table) => table,
+            Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE && req.if_exists => {
+                return Ok(RenameTableReply { table_id: 0 });
+            }
+            Err(e) => return Err(e),
+        };
+        let table_info = table.get_table_info().clone();
+        let table_id = table_info.ident.table_id;
+
+        match self
+            .get_table(&tenant, &req.new_db_name, &req.new_table_name)
+            .await
+        {
+            Ok(_) => {
+                return Err(ErrorCode::TableAlreadyExists(format!(
+                    "Table '{}' already exists",
+                    req.new_table_name
+                )));
+            }
+            Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE => {}
+            Err(e) => return Err(e),
+        }
+        if req.new_db_name != name_ident.db_name {
+            // ensure the presence of the new database
+            self.get_database(&tenant, &req.new_db_name).await?;
+            if self.txn_mgr.lock().is_created_table(table_id) {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "Cannot move table '{}' created in the current transaction to database '{}'",
+                    name_ident.table_name, req.new_db_name
+                )));
+            }
+        }
+
+        self.txn_mgr.lock().rename_table(req, table_info);
+        Ok(RenameTableReply { table_id })
+    }
+
+    // The options are changed when the transaction commits.
+    async fn upsert_table_option_in_txn(
+        &self,
+        db_name: &str,
+        req: UpsertTableOptionReq,
+    ) -> Result<UpsertTableOptionReply> {
+        let table_id = req.table_id;
+        let table_in_txn = self.txn_mgr.lock().get_table_from_buffer_by_id(table_id);
+        let mut table_info = match table_in_txn {
+            Some(table_info) => table_info,
+            None => {
+                let seq_meta = self.inner.get_table_meta_by_id(table_id).await?;
+                let name = self.inner.get_table_name_by_id(table_id).await?;
+                let (Some(seq_meta), Some(name)) = (seq_meta, name) else {
+                    return Err(ErrorCode::UnknownTableId(format!(
+                        "Unknown table id {}",
+                        table_id
+                    )));
+                };
+                TableInfo::new_full(
+                    db_name,
+                    &name,
+                    TableIdent::new(table_id, seq_meta.seq),
+                    seq_meta.data,
+                    self.info(),
+                )
+            }
+        };
+        if req.seq.match_seq(table_info.ident.seq).is_err() {
+            return Err(ErrorCode::TableVersionMismatched(format!(
+                "Table {} version mismatched, expect {:?}, got {}",
+                table_id, req.seq, table_info.ident.seq
+            )));
+        }
+
+        for (k, v) in req.options {
+            match v {
+                Some(v) => {
+                    table_info.meta.options.insert(k, v);
+                }
+                None => {
+                    table_info.meta.options.remove(&k);
+                }
+            }
+        }
+        self.txn_mgr
+            .lock()
+            .update_multi_table_meta(UpdateMultiTableMetaReq {
+                update_table_metas: vec![(
+                    UpdateTableMetaReq {
+                        table_id,
+                        seq: MatchSeq::Exact(table_info.ident.seq),
+                        new_table_meta: table_info.meta.clone(),
+                    },
+                    table_info,
+                )],
+                ..Default::default()
+            });
+        Ok(UpsertTableOptionReply {})
+    }
 }
@@ -51,6 +51,7 @@ use databend_common_storages_system::NotificationHistoryTable;
 use databend_common_storages_system::NotificationsTable;
 use databend_common_storages_system::OneTable;
 use databend_common_storages_system::PasswordPoliciesTable;
+use databend_common_storages_system::PlanBaselinesTable;
 use databend_common_storages_system::ProceduresTable;
 use databend_common_storages_system::ProcessesTable;
 use databend_common_storages_system::QueriesProfilingTable;
@@ -152,6 +153,7 @@ impl SystemDatabase {
             TemporaryTablesTable::create(sys_db_meta.next_table_id()),
             ProceduresTable::create(sys_db_meta.next_table_id()),
             DictionariesTable::create(sys_db_meta.next_table_id()),
+            PlanBaselinesTable::create(sys_db_meta.next_table_id()),
         ];
 
         let disable_tables = Self::disable_system_tables();
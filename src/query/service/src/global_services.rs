@@ -44,6 +44,7 @@ use crate::builtin::BuiltinUDFs;
 use crate::builtin::BuiltinUsers;
 use crate::catalogs::DatabaseCatalog;
 use crate::clusters::ClusterDiscovery;
+use crate::history_tables::HistoryLog;
 use crate::locks::LockManager;
 #[cfg(feature = "enable_queries_executor")]
 use crate::pipelines::executor::GlobalQueriesExecutor;
@@ -117,6 +118,7 @@ impl GlobalServices {
         SessionManager::init(config)?;
         LockManager::init()?;
         AuthMgr::init(config)?;
+        HistoryLog::init(config)?;
 
         // Init user manager.
         // Builtin users and udfs are created here.
@@ -0,0 +1,297 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use databend_common_base::runtime::profile::ProfileStatisticsName;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_pipeline_core::PlanProfile;
+use databend_common_storages_system::QueryLogElement;
+use databend_common_storages_system::SystemLogElement;
+
+/// The profiles of the plan nodes of a finished query, one row per plan node.
+#[derive(Clone)]
+pub struct ProfileHistoryElement {
+    pub event_time: i64,
+    pub node_id: String,
+    pub query_id: String,
+    pub profiles: Vec<PlanProfile>,
+}
+
+impl SystemLogElement for ProfileHistoryElement {
+    const TABLE_NAME: &'static str = "profile_history";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("event_time", TableDataType::Timestamp),
+            TableField::new("node_id", TableDataType::String),
+            TableField::new("query_id", TableDataType::String),
+            TableField::new(
+                "plan_id",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt32))),
+            ),
+            TableField::new(
+                "parent_plan_id",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt32))),
+            ),
+            TableField::new(
+                "plan_name",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new("errors", TableDataType::Variant),
+            TableField::new("statistics", TableDataType::Variant),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        for profile in &self.profiles {
+            let mut statistics = HashMap::with_capacity(profile.statistics.len());
+            for (idx, value) in profile.statistics.iter().enumerate() {
+                statistics.insert(ProfileStatisticsName::from(idx).to_string(), *value);
+            }
+
+            let row = [
+                Scalar::Timestamp(self.event_time),
+                Scalar::String(self.node_id.clone()),
+                Scalar::String(self.query_id.clone()),
+                profile
+                    .id
+                    .map(|id| Scalar::Number(NumberScalar::UInt32(id)))
+                    .unwrap_or(Scalar::Null),
+                profile
+                    .parent_id
+                    .map(|id| Scalar::Number(NumberScalar::UInt32(id)))
+                    .unwrap_or(Scalar::Null),
+                profile
+                    .name
+                    .clone()
+                    .map(Scalar::String)
+                    .unwrap_or(Scalar::Null),
+                Scalar::Variant(to_jsonb(&profile.errors)?),
+                Scalar::Variant(to_jsonb(&statistics)?),
+            ];
+            for (column, scalar) in columns.iter_mut().zip(row.iter()) {
+                column.push(scalar.as_ref());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An attempt to authenticate a user, successful or not.
+#[derive(Clone)]
+pub struct LoginHistoryElement {
+    pub event_time: i64,
+    pub node_id: String,
+    pub handler_type: String,
+    pub auth_type: String,
+    pub user_name: String,
+    pub client_address: String,
+    pub success: bool,
+    pub error_code: i32,
+    pub error_message: String,
+}
+
+impl SystemLogElement for LoginHistoryElement {
+    const TABLE_NAME: &'static str = "login_history";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("event_time", TableDataType::Timestamp),
+            TableField::new("node_id", TableDataType::String),
+            TableField::new("handler_type", TableDataType::String),
+            TableField::new("auth_type", TableDataType::String),
+            TableField::new("user_name", TableDataType::String),
+            TableField::new("client_address", TableDataType::String),
+            TableField::new("success", TableDataType::Boolean),
+            TableField::new("error_code", TableDataType::Number(NumberDataType::Int32)),
+            TableField::new("error_message", TableDataType::String),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        let row = [
+            Scalar::Timestamp(self.event_time),
+            Scalar::String(self.node_id.clone()),
+            Scalar::String(self.handler_type.clone()),
+            Scalar::String(self.auth_type.clone()),
+            Scalar::String(self.user_name.clone()),
+            Scalar::String(self.client_address.clone()),
+            Scalar::Boolean(self.success),
+            Scalar::Number(NumberScalar::Int32(self.error_code)),
+            Scalar::String(self.error_message.clone()),
+        ];
+        for (column, scalar) in columns.iter_mut().zip(row.iter()) {
+            column.push(scalar.as_ref());
+        }
+        Ok(())
+    }
+}
+
+/// A query that failed or was aborted.
+#[derive(Clone)]
+pub struct ErrorHistoryElement {
+    pub event_time: i64,
+    pub node_id: String,
+    pub query_id: String,
+    pub sql_user: String,
+    pub client_address: String,
+    pub query_text: String,
+    pub error_code: i32,
+    pub error_message: String,
+    pub stack_trace: String,
+}
+
+impl ErrorHistoryElement {
+    pub fn from_query_log(event: &QueryLogElement) -> Self {
+        ErrorHistoryElement {
+            event_time: event.event_time,
+            node_id: event.node_id.clone(),
+            query_id: event.query_id.clone(),
+            sql_user: event.sql_user.clone(),
+            client_address: event.client_address.clone(),
+            query_text: event.query_text.clone(),
+            error_code: event.exception_code,
+            error_message: event.exception_text.clone(),
+            stack_trace: event.stack_trace.clone(),
+        }
+    }
+}
+
+impl SystemLogElement for ErrorHistoryElement {
+    const TABLE_NAME: &'static str = "error_history";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("event_time", TableDataType::Timestamp),
+            TableField::new("node_id", TableDataType::String),
+            TableField::new("query_id", TableDataType::String),
+            TableField::new("sql_user", TableDataType::String),
+            TableField::new("client_address", TableDataType::String),
+            TableField::new("query_text", TableDataType::String),
+            TableField::new("error_code", TableDataType::Number(NumberDataType::Int32)),
+            TableField::new("error_message", TableDataType::String),
+            TableField::new("stack_trace", TableDataType::String),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        let row = [
+            Scalar::Timestamp(self.event_time),
+            Scalar::String(self.node_id.clone()),
+            Scalar::String(self.query_id.clone()),
+            Scalar::String(self.sql_user.clone()),
+            Scalar::String(self.client_address.clone()),
+            Scalar::String(self.query_text.clone()),
+            Scalar::Number(NumberScalar::Int32(self.error_code)),
+            Scalar::String(self.error_message.clone()),
+            Scalar::String(self.stack_trace.clone()),
+        ];
+        for (column, scalar) in columns.iter_mut().zip(row.iter()) {
+            column.push(scalar.as_ref());
+        }
+        Ok(())
+    }
+}
+
+/// The objects read and written by a finished query and its column lineage. The tables and
+/// columns are recorded by ID as well as by name, so that the history survives renames.
+#[derive(Clone)]
+pub struct AccessHistoryElement {
+    pub event_time: i64,
+    pub node_id: String,
+    pub query_id: String,
+    pub query_kind: String,
+    pub sql_user: String,
+    pub objects_read: String,
+    pub objects_written: String,
+    pub column_lineage: String,
+}
+
+impl AccessHistoryElement {
+    /// Returns None if the query accessed no object.
+    pub fn from_query_log(event: &QueryLogElement) -> Option<Self> {
+        if event.objects_read.is_empty() && event.objects_written.is_empty() {
+            return None;
+        }
+        Some(AccessHistoryElement {
+            event_time: event.event_time,
+            node_id: event.node_id.clone(),
+            query_id: event.query_id.clone(),
+            query_kind: event.query_kind.clone(),
+            sql_user: event.sql_user.clone(),
+            objects_read: event.objects_read.clone(),
+            objects_written: event.objects_written.clone(),
+            column_lineage: event.column_lineage.clone(),
+        })
+    }
+}
+
+impl SystemLogElement for AccessHistoryElement {
+    const TABLE_NAME: &'static str = "access_history";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("event_time", TableDataType::Timestamp),
+            TableField::new("node_id", TableDataType::String),
+            TableField::new("query_id", TableDataType::String),
+            TableField::new("query_kind", TableDataType::String),
+            TableField::new("sql_user", TableDataType::String),
+            TableField::new("objects_read", TableDataType::Variant),
+            TableField::new("objects_written", TableDataType::Variant),
+            TableField::new("column_lineage", TableDataType::Variant),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        let row = [
+            Scalar::Timestamp(self.event_time),
+            Scalar::String(self.node_id.clone()),
+            Scalar::String(self.query_id.clone()),
+            Scalar::String(self.query_kind.clone()),
+            Scalar::String(self.sql_user.clone()),
+            Scalar::Variant(json_to_jsonb(&self.objects_read)?),
+            Scalar::Variant(json_to_jsonb(&self.objects_written)?),
+            Scalar::Variant(json_to_jsonb(&self.column_lineage)?),
+        ];
+        for (column, scalar) in columns.iter_mut().zip(row.iter()) {
+            column.push(scalar.as_ref());
+        }
+        Ok(())
+    }
+}
+
+fn to_jsonb<T: serde::Serialize>(value: &T) -> Result<Vec<u8>> {
+    let value = serde_json::to_value(value)?;
+    Ok(jsonb::Value::from(&value).to_vec())
+}
+
+/// The empty strings of the queries without lineage are stored as empty arrays.
+fn json_to_jsonb(json: &str) -> Result<Vec<u8>> {
+    match json.is_empty() {
+        true => Ok(jsonb::Value::Array(vec![]).to_vec()),
+        false => jsonb::parse_value(json.as_bytes())
+            .map(|value| value.to_vec())
+            .map_err(|e| ErrorCode::BadBytes(format!("invalid lineage json: {}", e))),
+    }
+}
@@ -0,0 +1,482 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use chrono::Utc;
+use databend_common_base::base::tokio::sync::Notify;
+use databend_common_base::base::tokio::time::sleep;
+use databend_common_base::base::GlobalInstance;
+use databend_common_base::runtime::spawn_blocking;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_catalog::catalog::CATALOG_DEFAULT;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_config::InnerConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
+use databend_common_meta_app::principal::UserInfo;
+use databend_common_pipeline_core::PlanProfile;
+use databend_common_pipeline_sources::BlocksSource;
+use databend_common_sql::Planner;
+use databend_common_storages_system::LogType;
+use databend_common_storages_system::QueryLogElement;
+use databend_common_storages_system::SystemLogElement;
+use databend_common_users::BUILTIN_ROLE_ACCOUNT_ADMIN;
+use futures::future::select;
+use futures::future::Either;
+use futures_util::TryStreamExt;
+use log::info;
+use log::warn;
+use parking_lot::Mutex;
+
+use crate::history_tables::AccessHistoryElement;
+use crate::history_tables::ErrorHistoryElement;
+use crate::history_tables::LoginHistoryElement;
+use crate::history_tables::ProfileHistoryElement;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::pipelines::PipelineBuildResult;
+use crate::pipelines::PipelineBuilder;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+
+pub const HISTORY_DATABASE: &str = "system_history";
+
+/// The events buffered on a node between two flushes, the oldest ones are dropped beyond it.
+const MAX_BUFFERED_EVENTS: usize = 100_000;
+
+/// How often the expired events are deleted from the history tables.
+const RETENTION_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// The queries of the history log itself are not recorded, or every flush would log more events.
+const HISTORY_SESSION: &str = "HistoryLog";
+
+/// Persists the query log, profile, login, error and access events into the fuse tables of the
+/// `system_history` database.
+///
+/// Every query node buffers its own events in memory and appends them to the history tables
+/// periodically, so the tables cover the whole warehouse and survive restarts. The first node
+/// of the cluster deletes the events older than the retention.
+pub struct HistoryLog {
+    node_id: String,
+    interval: Duration,
+    retention_days: u64,
+
+    queries: HistoryBuffer<QueryLogElement>,
+    profiles: HistoryBuffer<ProfileHistoryElement>,
+    logins: HistoryBuffer<LoginHistoryElement>,
+    errors: HistoryBuffer<ErrorHistoryElement>,
+    accesses: HistoryBuffer<AccessHistoryElement>,
+
+    /// Whether the history database and tables are known to exist.
+    prepared: AtomicBool,
+    last_retention: Mutex<Option<Instant>>,
+    shutdown_flag: AtomicBool,
+    shutdown_notify: Notify,
+}
+
+impl HistoryLog {
+    pub fn init(conf: &InnerConfig) -> Result<()> {
+        if conf.log.history.on {
+            GlobalInstance::set(Arc::new(HistoryLog {
+                node_id: conf.query.node_id.clone(),
+                interval: Duration::from_secs(conf.log.history.interval),
+                retention_days: conf.log.history.retention,
+                queries: HistoryBuffer::new("query_history"),
+                profiles: HistoryBuffer::new(ProfileHistoryElement::TABLE_NAME),
+                logins: HistoryBuffer::new(LoginHistoryElement::TABLE_NAME),
+                errors: HistoryBuffer::new(ErrorHistoryElement::TABLE_NAME),
+                accesses: HistoryBuffer::new(AccessHistoryElement::TABLE_NAME),
+                prepared: AtomicBool::new(false),
+                last_retention: Mutex::new(None),
+                shutdown_flag: AtomicBool::new(false),
+                shutdown_notify: Notify::new(),
+            }));
+        }
+        Ok(())
+    }
+
+    /// Returns None if the history log is disabled.
+    pub fn instance() -> Option<Arc<HistoryLog>> {
+        GlobalInstance::try_get()
+    }
+
+    pub fn record_query(&self, event: &QueryLogElement) {
+        if event.handler_type == SessionType::HTTPAPI(HISTORY_SESSION.to_string()).to_string() {
+            return;
+        }
+        if matches!(event.log_type, LogType::Error | LogType::Aborted) {
+            self.errors.push(ErrorHistoryElement::from_query_log(event));
+        }
+        if matches!(event.log_type, LogType::Finish) {
+            if let Some(access) = AccessHistoryElement::from_query_log(event) {
+                self.accesses.push(access);
+            }
+        }
+        self.queries.push(event.clone());
+    }
+
+    pub fn record_profiles(&self, ctx: &QueryContext, profiles: &[PlanProfile]) {
+        if ctx.get_current_session().get_type() == SessionType::HTTPAPI(HISTORY_SESSION.to_string())
+        {
+            return;
+        }
+        self.profiles.push(ProfileHistoryElement {
+            event_time: Utc::now().timestamp_micros(),
+            node_id: self.node_id.clone(),
+            query_id: ctx.get_id(),
+            profiles: profiles.to_vec(),
+        });
+    }
+
+    pub fn record_login(
+        &self,
+        handler_type: String,
+        auth_type: String,
+        user_name: String,
+        client_address: String,
+        result: Result<(), &ErrorCode>,
+    ) {
+        let (error_code, error_message) = match result {
+            Ok(_) => (0, "".to_string()),
+            Err(cause) => (cause.code() as i32, cause.message()),
+        };
+        self.logins.push(LoginHistoryElement {
+            event_time: Utc::now().timestamp_micros(),
+            node_id: self.node_id.clone(),
+            handler_type,
+            auth_type,
+            user_name,
+            client_address,
+            success: error_code == 0,
+            error_code,
+            error_message,
+        });
+    }
+
+    pub fn start(self: &Arc<Self>) {
+        let history = self.clone();
+        GlobalIORuntime::instance().spawn(async move {
+            info!("History log started on node {}", history.node_id);
+            let mut notified = Box::pin(history.shutdown_notify.notified());
+            while !history.shutdown_flag.load(Ordering::SeqCst) {
+                match select(notified, Box::pin(sleep(history.interval))).await {
+                    Either::Left((_, _)) => break,
+                    Either::Right((_, new_notified)) => notified = new_notified,
+                }
+
+                if let Err(cause) = history.flush().await {
+                    warn!("History log failed to flush: {:?}", cause);
+                }
+            }
+
+            // The events buffered before the shutdown are flushed too.
+            if let Err(cause) = history.flush().await {
+                warn!("History log failed to flush: {:?}", cause);
+            }
+            info!("History log stopped on node {}", history.node_id);
+        });
+    }
+
+    pub fn shutdown(&self) {
+        self.shutdown_flag.store(true, Ordering::SeqCst);
+        self.shutdown_notify.notify_one();
+    }
+
+    /// Append the buffered events to the history tables, and delete the expired events.
+    #[async_backtrace::framed]
+    pub async fn flush(&self) -> Result<()> {
+        let session = create_session().await?;
+        if !self.prepared.load(Ordering::SeqCst) {
+            self.prepare(&session).await?;
+            self.prepared.store(true, Ordering::SeqCst);
+        }
+
+        let results = [
+            self.queries.flush(&session).await,
+            self.profiles.flush(&session).await,
+            self.logins.flush(&session).await,
+            self.errors.flush(&session).await,
+            self.accesses.flush(&session).await,
+        ];
+        if let Some(Err(cause)) = results.into_iter().find(|r| r.is_err()) {
+            // The tables may have been dropped, they are created again on the next flush.
+            self.prepared.store(false, Ordering::SeqCst);
+            return Err(cause);
+        }
+
+        self.purge(&session).await
+    }
+
+    /// Create the history database and tables if they don't exist, and migrate the tables
+    /// created by an older version to the current schema of the events.
+    #[async_backtrace::framed]
+    async fn prepare(&self, session: &Arc<Session>) -> Result<()> {
+        let sql = format!("CREATE DATABASE IF NOT EXISTS {}", HISTORY_DATABASE);
+        execute_sql(session.create_query_context().await?, &sql).await?;
+
+        for sql in [
+            self.queries.create_table_sql(),
+            self.profiles.create_table_sql(),
+            self.logins.create_table_sql(),
+            self.errors.create_table_sql(),
+            self.accesses.create_table_sql(),
+        ] {
+            execute_sql(session.create_query_context().await?, &sql).await?;
+        }
+
+        let migrations = [
+            self.queries.migrate_table_sqls(session).await?,
+            self.profiles.migrate_table_sqls(session).await?,
+            self.logins.migrate_table_sqls(session).await?,
+            self.errors.migrate_table_sqls(session).await?,
+            self.accesses.migrate_table_sqls(session).await?,
+        ];
+        for sql in migrations.iter().flatten() {
+            info!("History log migrates the history table: {}", sql);
+            execute_sql(session.create_query_context().await?, sql).await?;
+        }
+        Ok(())
+    }
+
+    /// Delete the events older than the retention, which is done by the first node of the
+    /// cluster only.
+    #[async_backtrace::framed]
+    async fn purge(&self, session: &Arc<Session>) -> Result<()> {
+        if self.retention_days == 0 {
+            return Ok(());
+        }
+        {
+            let mut last_retention = self.last_retention.lock();
+            if matches!(*last_retention, Some(last) if last.elapsed() < RETENTION_INTERVAL) {
+                return Ok(());
+            }
+            *last_retention = Some(Instant::now());
+        }
+
+        let ctx = session.create_query_context().await?;
+        let cluster = ctx.get_cluster();
+        let first_node = cluster.nodes.iter().map(|node| &node.id).min();
+        if first_node.is_some_and(|id| id != &cluster.local_id) {
+            return Ok(());
+        }
+
+        for table in [
+            self.queries.table,
+            self.profiles.table,
+            self.logins.table,
+            self.errors.table,
+            self.accesses.table,
+        ] {
+            let sql = format!(
+                "DELETE FROM {}.{} WHERE event_time < NOW() - INTERVAL {} DAY",
+                HISTORY_DATABASE, table, self.retention_days
+            );
+            execute_sql(session.create_query_context().await?, &sql).await?;
+        }
+        info!(
+            "History log deleted the events older than {} days",
+            self.retention_days
+        );
+        Ok(())
+    }
+}
+
+struct HistoryBuffer<Event: SystemLogElement> {
+    table: &'static str,
+    events: Mutex<VecDeque<Event>>,
+}
+
+impl<Event: SystemLogElement> HistoryBuffer<Event> {
+    fn new(table: &'static str) -> Self {
+        HistoryBuffer {
+            table,
+            events: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    fn push(&self, event: Event) {
+        let mut events = self.events.lock();
+        if events.len() == MAX_BUFFERED_EVENTS {
+            events.pop_front();
+        }
+        events.push_back(event);
+    }
+
+    fn create_table_sql(&self) -> String {
+        let columns = Event::schema()
+            .fields()
+            .iter()
+            .map(|field| {
+                format!(
+                    "`{}` {}",
+                    field.name(),
+                    field.data_type().sql_name_explicit_null()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!(
+            "CREATE TABLE IF NOT EXISTS {}.{} ({}) CLUSTER BY (event_time)",
+            HISTORY_DATABASE, self.table, columns
+        )
+    }
+
+    /// The statements that bring the history table up to the current schema of the events.
+    ///
+    /// The columns added by a newer version are added to the table. If the type of a column
+    /// has changed, the table is renamed with a suffix of the current time and a new table is
+    /// created, so the old events are kept but not mixed with the new ones.
+    #[async_backtrace::framed]
+    async fn migrate_table_sqls(&self, session: &Arc<Session>) -> Result<Vec<String>> {
+        let ctx = session.create_query_context().await?;
+        let table = ctx
+            .get_table(CATALOG_DEFAULT, HISTORY_DATABASE, self.table)
+            .await?;
+        let table_schema = table.schema();
+
+        let mut sqls = vec![];
+        for field in Event::schema().fields() {
+            match table_schema.field_with_name(field.name()) {
+                Ok(existing) if existing.data_type() == field.data_type() => {}
+                Ok(_) => {
+                    let rotated = format!("{}_{}", self.table, Utc::now().format("%Y%m%d%H%M%S"));
+                    return Ok(vec![
+                        format!(
+                            "RENAME TABLE {}.{} TO {}.{}",
+                            HISTORY_DATABASE, self.table, HISTORY_DATABASE, rotated
+                        ),
+                        self.create_table_sql(),
+                    ]);
+                }
+                Err(_) => sqls.push(format!(
+                    "ALTER TABLE {}.{} ADD COLUMN `{}` {}",
+                    HISTORY_DATABASE,
+                    self.table,
+                    field.name(),
+                    field.data_type().sql_name_explicit_null()
+                )),
+            }
+        }
+        Ok(sqls)
+    }
+
+    /// Append the buffered events to the history table, they are kept for the next flush if
+    /// the append fails.
+    #[async_backtrace::framed]
+    async fn flush(&self, session: &Arc<Session>) -> Result<()> {
+        let events = std::mem::take(&mut *self.events.lock());
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        match self.append(session, &events).await {
+            Ok(_) => Ok(()),
+            Err(cause) => {
+                let mut buffered = self.events.lock();
+                let room = MAX_BUFFERED_EVENTS.saturating_sub(buffered.len());
+                for event in events.into_iter().rev().take(room) {
+                    buffered.push_front(event);
+                }
+                Err(cause)
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn append(&self, session: &Arc<Session>, events: &VecDeque<Event>) -> Result<()> {
+        let schema = Event::schema();
+        let mut columns = schema
+            .fields()
+            .iter()
+            .map(|field| ColumnBuilder::with_capacity(&DataType::from(field.data_type()), 0))
+            .collect::<Vec<_>>();
+        for event in events {
+            event.fill_to_data_block(&mut columns)?;
+        }
+        let block = DataBlock::new_from_columns(columns.into_iter().map(|c| c.build()).collect());
+
+        let ctx = session.create_query_context().await?;
+        let table = ctx
+            .get_table(CATALOG_DEFAULT, HISTORY_DATABASE, self.table)
+            .await?;
+
+        let mut build_res = PipelineBuildResult::create();
+        let blocks = Arc::new(Mutex::new(VecDeque::from([block])));
+        build_res.main_pipeline.add_source(
+            |output| BlocksSource::create(ctx.clone(), output, blocks.clone()),
+            1,
+        )?;
+        PipelineBuilder::build_append2table_with_commit_pipeline(
+            ctx.clone(),
+            &mut build_res.main_pipeline,
+            table,
+            Arc::new(DataSchema::from(schema.as_ref())),
+            None,
+            vec![],
+            false,
+            None,
+        )?;
+
+        build_res.set_max_threads(1);
+        let executor_settings = ExecutorSettings::try_create(ctx.clone())?;
+        let mut pipelines = build_res.sources_pipelines;
+        pipelines.push(build_res.main_pipeline);
+        let executor = PipelineCompleteExecutor::from_pipelines(pipelines, executor_settings)?;
+        ctx.set_executor(executor.get_inner())?;
+        // The executor blocks the thread until the pipeline finishes, which must not be a
+        // worker of the async runtime.
+        spawn_blocking(move || executor.execute())
+            .await
+            .map_err(|cause| {
+                ErrorCode::Internal(format!("History log failed to append events: {}", cause))
+            })?
+    }
+}
+
+#[async_backtrace::framed]
+async fn create_session() -> Result<Arc<Session>> {
+    let session = SessionManager::instance()
+        .create_session(SessionType::HTTPAPI(HISTORY_SESSION.to_string()))
+        .await?;
+    let user = UserInfo::new_no_auth(HISTORY_SESSION, "0.0.0.0");
+    session
+        .set_authed_user(user, Some(BUILTIN_ROLE_ACCOUNT_ADMIN.to_string()))
+        .await?;
+    Ok(session)
+}
+
+#[async_backtrace::framed]
+async fn execute_sql(ctx: Arc<QueryContext>, sql: &str) -> Result<()> {
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(sql).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let stream = interpreter.execute(ctx).await?;
+    stream.try_collect::<Vec<_>>().await?;
+    Ok(())
+}
@@ -83,6 +83,8 @@ impl AccessChecker for ManagementModeAccess {
                 | Plan::DropTable(_)
                 | Plan::DropView(_)
                 | Plan::CreateView(_)
+                | Plan::CreateMaterializedView(_)
+                | Plan::DropMaterializedView(_)
                 | Plan::CreateStream(_)
                 | Plan::DropStream(_)
 
@@ -808,13 +808,25 @@ impl AccessChecker for PrivilegeAccess {
             Plan::ShowCreateDatabase(plan) => {
                 self.validate_db_access(&plan.catalog, &plan.database, UserPrivilegeType::Select, false).await?
             }
-            Plan::CreateDatabase(_) => {
+            Plan::CreateDatabase(plan) => {
                 self.validate_access(&GrantObject::Global, UserPrivilegeType::CreateDatabase, true, false)
                     .await?;
+                if let Some(source) = &plan.clone_from {
+                    self.validate_db_access(&plan.catalog, source, UserPrivilegeType::Select, false).await?;
+                }
             }
             Plan::DropDatabase(plan) => {
                 self.validate_db_access(&plan.catalog, &plan.database, UserPrivilegeType::Drop, plan.if_exists).await?;
             }
+            Plan::BackupDatabase(plan) => {
+                self.validate_db_access(&plan.catalog, &plan.database, UserPrivilegeType::Select, false).await?;
+                self.validate_stage_access(&plan.stage_info, UserPrivilegeType::Write).await?;
+            }
+            Plan::RestoreDatabase(plan) => {
+                self.validate_access(&GrantObject::Global, UserPrivilegeType::CreateDatabase, true, false)
+                    .await?;
+                self.validate_stage_access(&plan.stage_info, UserPrivilegeType::Read).await?;
+            }
             Plan::UndropDatabase(_)
             | Plan::DropIndex(_)
             | Plan::DropTableIndex(_) => {
@@ -916,6 +928,9 @@ impl AccessChecker for PrivilegeAccess {
                 if let Some(query) = &plan.as_select {
                     self.check(ctx, query).await?;
                 }
+                if let Some(clone_from) = &plan.clone_from {
+                    self.validate_table_access(&clone_from.catalog, &clone_from.database, &clone_from.table, UserPrivilegeType::Select, false, false).await?;
+                }
             }
             Plan::DropTable(plan) => {
                 // For attach table
@@ -959,6 +974,15 @@ impl AccessChecker for PrivilegeAccess {
             Plan::DropTableColumn(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false, false).await?
             }
+            Plan::AddTableConstraint(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false, false).await?
+            }
+            Plan::DropTableConstraint(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false, false).await?
+            }
+            Plan::ValidateTableConstraint(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Select, false, false).await?
+            }
             Plan::AlterTableClusterKey(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false, false).await?
             }
@@ -973,6 +997,9 @@ impl AccessChecker for PrivilegeAccess {
             Plan::TruncateTable(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Delete, false, false).await?
             }
+            Plan::CacheWarmupTable(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false, false).await?
+            }
             Plan::OptimizePurge(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Super, false, false).await?
             },
@@ -1101,6 +1128,26 @@ impl AccessChecker for PrivilegeAccess {
             Plan::DescribeView(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.view_name, UserPrivilegeType::Select, false, false).await?
             }
+            Plan::CreateMaterializedView(plan) => {
+                let plan = &plan.create_table;
+                self.validate_db_access(&plan.catalog, &plan.database, UserPrivilegeType::Create, false).await?;
+                if let Some(query) = &plan.as_select {
+                    self.check(ctx, query).await?;
+                }
+            }
+            Plan::DropMaterializedView(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.view_name, UserPrivilegeType::Drop, plan.if_exists, false).await?
+            }
+            Plan::RefreshMaterializedView(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.view_name, UserPrivilegeType::Insert, false, false).await?
+            }
+            Plan::PinPlanBaseline(plan) => {
+                self.validate_access(&GrantObject::Global, UserPrivilegeType::Super, false, false).await?;
+                self.check(ctx, &plan.plan).await?;
+            }
+            Plan::UnpinPlanBaseline(_) => {
+                self.validate_access(&GrantObject::Global, UserPrivilegeType::Super, false, false).await?;
+            }
             Plan::CreateStream(plan) => {
                 self.validate_db_access(&plan.catalog, &plan.database, UserPrivilegeType::Create, false).await?
             }
@@ -1270,6 +1317,9 @@ impl AccessChecker for PrivilegeAccess {
             }
             Plan::Commit => {}
             Plan::Abort => {}
+            Plan::Savepoint(_) => {}
+            Plan::RollbackToSavepoint(_) => {}
+            Plan::ReleaseSavepoint(_) => {}
             Plan::ShowWarehouses => {}
             Plan::ShowOnlineNodes => {}
             Plan::DropWarehouse(_) => {}
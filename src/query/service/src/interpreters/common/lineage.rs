@@ -0,0 +1,42 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::QueryLineage;
+use log::warn;
+
+use crate::sessions::QueryContext;
+
+/// Records the objects accessed by `plan` and its column lineage into the query log and the
+/// access history, if `enable_query_lineage` is set. Failing to compute the lineage doesn't
+/// fail the query.
+#[async_backtrace::framed]
+pub async fn attach_query_lineage(ctx: &Arc<QueryContext>, plan: &Plan) {
+    if !matches!(ctx.get_settings().get_enable_query_lineage(), Ok(true)) {
+        return;
+    }
+
+    match QueryLineage::from_plan(ctx.as_ref(), plan).await {
+        Ok(Some(lineage)) => ctx.attach_query_lineage(lineage),
+        Ok(None) => {}
+        Err(cause) => warn!(
+            "Failed to compute the lineage of query {}: {:?}",
+            ctx.get_id(),
+            cause
+        ),
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_catalog::catalog::Catalog;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::UpsertTableOptionReq;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_types::MatchSeq;
+use databend_storages_common_table_meta::table::encode_materialized_views;
+use databend_storages_common_table_meta::table::parse_materialized_views;
+use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEWS;
+
+// The options of the source tables may be changed by concurrent commits.
+const MAX_RETRIES: usize = 5;
+
+/// Adds (or removes) the materialized view `view_id` to the materialized views of the source
+/// tables, the dropped materialized views are also removed.
+pub async fn update_source_materialized_views(
+    catalog: &Arc<dyn Catalog>,
+    tenant: &Tenant,
+    view_id: u64,
+    table_ids: &[u64],
+    add: bool,
+) -> Result<()> {
+    for table_id in table_ids {
+        let mut retries = 0;
+        loop {
+            match update_table_materialized_views(catalog, tenant, view_id, *table_id, add).await {
+                Err(e)
+                    if e.code() == ErrorCode::TABLE_VERSION_MISMATCHED && retries < MAX_RETRIES =>
+                {
+                    retries += 1;
+                }
+                result => break result?,
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn update_table_materialized_views(
+    catalog: &Arc<dyn Catalog>,
+    tenant: &Tenant,
+    view_id: u64,
+    table_id: u64,
+    add: bool,
+) -> Result<()> {
+    let Some(table_meta) = catalog.get_table_meta_by_id(table_id).await? else {
+        return Ok(());
+    };
+    if table_meta.drop_on.is_some() {
+        return Ok(());
+    }
+    let Some(db_id) = table_meta
+        .options
+        .get(OPT_KEY_DATABASE_ID)
+        .and_then(|id| id.parse::<u64>().ok())
+    else {
+        return Ok(());
+    };
+
+    let old_views = parse_materialized_views(&table_meta.options);
+    let mut views = Vec::with_capacity(old_views.len() + 1);
+    for id in old_views.iter() {
+        if *id == view_id {
+            continue;
+        }
+        if let Some(meta) = catalog.get_table_meta_by_id(*id).await? {
+            if meta.drop_on.is_none() {
+                views.push(*id);
+            }
+        }
+    }
+    if add {
+        views.push(view_id);
+    }
+    if views == old_views {
+        return Ok(());
+    }
+
+    let db_name = catalog.get_db_name_by_id(db_id).await?;
+    let req = UpsertTableOptionReq {
+        table_id,
+        seq: MatchSeq::Exact(table_meta.seq),
+        options: HashMap::from([(
+            OPT_KEY_MATERIALIZED_VIEWS.to_string(),
+            encode_materialized_views(&views),
+        )]),
+    };
+    catalog.upsert_table_option(tenant, &db_name, req).await?;
+    Ok(())
+}
@@ -13,8 +13,11 @@
 // limitations under the License.
 
 mod grant;
+mod lineage;
+mod materialized_view;
 mod metrics;
 mod notification;
+mod plan_baseline;
 mod query_log;
 mod stream;
 mod table;
@@ -24,11 +27,21 @@ mod util;
 pub mod table_option_validation;
 
 pub use grant::validate_grant_object_exists;
+pub use lineage::attach_query_lineage;
+pub use materialized_view::update_source_materialized_views;
 pub use notification::get_notification_client_config;
+pub use plan_baseline::record_plan_baseline_execution;
 pub use query_log::InterpreterQueryLog;
 pub use stream::dml_build_update_stream_req;
 pub use stream::query_build_update_stream_req;
 pub use table::check_referenced_computed_columns;
+pub use table::check_referenced_constraints;
+pub use table::check_referenced_ttl;
+pub use table::copy_identity_sequences;
+pub use table::create_identity_sequences;
+pub use table::drop_identity_sequences;
+pub use table::fuse_table_create_plan;
+pub use table::validate_constraint_rows;
 pub use task::get_task_client_config;
 pub use task::make_schedule_options;
 pub use task::make_warehouse_options;
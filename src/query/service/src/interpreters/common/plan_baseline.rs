@@ -0,0 +1,81 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use chrono::Utc;
+use databend_common_exception::Result;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_sql::optimizer::PlanBaselineState;
+use databend_common_sql::optimizer::PlanBaselineUsage;
+use databend_common_users::UserApiProvider;
+use log::info;
+
+/// The plans known to be slower than the pinned plan, the oldest ones are forgotten.
+const MAX_REJECTED_PLANS: usize = 16;
+
+/// Record the execution time of a query planned with its plan baseline.
+///
+/// The first execution of the pinned plan measures its time. A new plan replaces the pinned
+/// plan if it is faster, otherwise it is rejected and not tried again.
+pub async fn record_plan_baseline_execution(
+    tenant: &Tenant,
+    state: PlanBaselineState,
+    elapsed: Duration,
+) -> Result<()> {
+    let PlanBaselineState { baseline, usage } = state;
+    let mut data = baseline.data;
+    let elapsed_ms = elapsed.as_millis() as u64;
+    match usage {
+        PlanBaselineUsage::Applied => {
+            if data.execution_time_ms.is_some() {
+                return Ok(());
+            }
+            data.execution_time_ms = Some(elapsed_ms);
+        }
+        PlanBaselineUsage::Evolving(outline) => {
+            let fingerprint = outline.fingerprint();
+            let rejected = match data.execution_time_ms {
+                Some(baseline_ms) if elapsed_ms < baseline_ms => {
+                    info!(
+                        "plan {} of query {} replaces the baseline plan {}, {}ms < {}ms",
+                        fingerprint, data.sql_hash, data.plan_fingerprint, elapsed_ms, baseline_ms
+                    );
+                    data.join_order = outline.join_order.clone();
+                    data.hints = outline.baseline_hints();
+                    data.execution_time_ms = Some(elapsed_ms);
+                    std::mem::replace(&mut data.plan_fingerprint, fingerprint)
+                }
+                _ => fingerprint,
+            };
+            if data.rejected_fingerprints.len() >= MAX_REJECTED_PLANS {
+                data.rejected_fingerprints.remove(0);
+            }
+            data.rejected_fingerprints.push(rejected);
+        }
+    }
+    data.updated_on = Utc::now();
+
+    let updated = UserApiProvider::instance()
+        .plan_baseline_api(tenant)?
+        .update_baseline(baseline.seq, &data)
+        .await?;
+    if !updated {
+        info!(
+            "plan baseline of query {} is changed by another query, skip the update",
+            data.sql_hash
+        );
+    }
+    Ok(())
+}
@@ -27,12 +27,26 @@ use log::error;
 use log::info;
 use serde_json;
 
+use crate::history_tables::HistoryLog;
 use crate::sessions::convert_query_log_timestamp;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
 
 pub struct InterpreterQueryLog;
 
+/// The objects read and written by the query and its column lineage, in JSON.
+fn lineage_fields(ctx: &QueryContext) -> (String, String, String) {
+    let Some(lineage) = ctx.get_query_lineage() else {
+        return ("".to_string(), "".to_string(), "".to_string());
+    };
+    let to_json = |value: serde_json::Result<String>| value.unwrap_or_default();
+    (
+        to_json(serde_json::to_string(&lineage.objects_read)),
+        to_json(serde_json::to_string(&lineage.objects_written)),
+        to_json(serde_json::to_string(&lineage.columns)),
+    )
+}
+
 fn error_fields<C>(log_type: LogType, err: Option<ErrorCode<C>>) -> (LogType, i32, String, String) {
     match err {
         None => (log_type, 0, "".to_string(), "".to_string()),
@@ -70,6 +84,9 @@ impl InterpreterQueryLog {
         info!(target: "databend::log::query", "{}", event_str);
         // log the query event in the system log
         info!("query: {} becomes {:?}", event.query_id, event.log_type);
+        if let Some(history) = HistoryLog::instance() {
+            history.record_query(&event);
+        }
         QueryLogQueue::instance()?.append_data(event)
     }
 
@@ -95,6 +112,8 @@ impl InterpreterQueryLog {
         let query_text = ctx.get_query_str();
         let query_hash = ctx.get_query_text_hash();
         let query_parameterized_hash = ctx.get_query_parameterized_hash();
+        let (plan_fingerprint, plan_baseline) = ctx.get_plan_baseline();
+        let (objects_read, objects_written, column_lineage) = lineage_fields(ctx);
         // Schema.
         let current_database = ctx.get_current_database();
 
@@ -176,6 +195,8 @@ impl InterpreterQueryLog {
             query_text,
             query_hash,
             query_parameterized_hash,
+            plan_fingerprint,
+            plan_baseline,
             event_date,
             event_time,
             query_start_time,
@@ -186,6 +207,9 @@ impl InterpreterQueryLog {
             tables: "".to_string(),
             columns: "".to_string(),
             projections: "".to_string(),
+            objects_read,
+            objects_written,
+            column_lineage,
             written_rows,
             written_bytes,
             written_io_bytes,
@@ -254,6 +278,8 @@ impl InterpreterQueryLog {
         let query_text = ctx.get_query_str();
         let query_hash = ctx.get_query_text_hash();
         let query_parameterized_hash = ctx.get_query_parameterized_hash();
+        let (plan_fingerprint, plan_baseline) = ctx.get_plan_baseline();
+        let (objects_read, objects_written, column_lineage) = lineage_fields(ctx);
 
         // Stats.
         let event_time = convert_query_log_timestamp(now);
@@ -350,6 +376,8 @@ impl InterpreterQueryLog {
             query_text,
             query_hash,
             query_parameterized_hash,
+            plan_fingerprint,
+            plan_baseline,
             event_date,
             event_time,
             query_start_time,
@@ -359,6 +387,9 @@ impl InterpreterQueryLog {
             tables: "".to_string(),
             columns: "".to_string(),
             projections: "".to_string(),
+            objects_read,
+            objects_written,
+            column_lineage,
             written_rows,
             written_bytes,
             written_io_bytes,
@@ -53,6 +53,17 @@ pub async fn dml_build_update_stream_req(
         let stream_info = stream.get_table_info();
 
         let source_table = stream.source_table(ctx.clone()).await?;
+        if stream.source_engine().is_some() {
+            let options = stream
+                .consumed_options(ctx.clone(), source_table.as_ref())
+                .await?;
+            reqs.push(UpdateStreamMetaReq {
+                stream_id: stream_info.ident.table_id,
+                seq: MatchSeq::Exact(stream_info.ident.seq),
+                options,
+            });
+            continue;
+        }
         let inner_fuse = FuseTable::try_from_table(source_table.as_ref())?;
 
         let table_version = inner_fuse.get_table_info().ident.seq;
@@ -113,14 +124,21 @@ pub async fn query_build_update_stream_req(
         let stream_info = stream.get_table_info();
 
         let source_table = stream.source_table(ctx.clone()).await?;
-        let inner_fuse = FuseTable::try_from_table(source_table.as_ref())?;
-
-        let table_version = inner_fuse.get_table_info().ident.seq;
-        let mut options = stream.options().clone();
-        options.insert(OPT_KEY_TABLE_VER.to_string(), table_version.to_string());
-        if let Some(snapshot_loc) = inner_fuse.snapshot_loc() {
-            options.insert(OPT_KEY_SNAPSHOT_LOCATION.to_string(), snapshot_loc);
-        }
+        let options = if stream.source_engine().is_some() {
+            stream
+                .consumed_options(ctx.clone(), source_table.as_ref())
+                .await?
+        } else {
+            let inner_fuse = FuseTable::try_from_table(source_table.as_ref())?;
+
+            let table_version = inner_fuse.get_table_info().ident.seq;
+            let mut options = stream.options().clone();
+            options.insert(OPT_KEY_TABLE_VER.to_string(), table_version.to_string());
+            if let Some(snapshot_loc) = inner_fuse.snapshot_loc() {
+                options.insert(OPT_KEY_SNAPSHOT_LOCATION.to_string(), snapshot_loc);
+            }
+            options
+        };
         let mut new_table_meta = stream_info.meta.clone();
         new_table_meta.options = options;
         new_table_meta.updated_on = Utc::now();
@@ -12,14 +12,44 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
+use chrono::Utc;
+use databend_common_ast::ast::Engine;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::ComputedExpr;
 use databend_common_expression::DataSchemaRef;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::CreateSequenceReq;
+use databend_common_meta_app::schema::DropSequenceReq;
+use databend_common_meta_app::schema::GetSequenceReq;
+use databend_common_meta_app::schema::SequenceIdent;
+use databend_common_meta_app::schema::TableConstraint;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_sql::parse_check_constraint_expr;
 use databend_common_sql::parse_computed_expr;
+use databend_common_sql::parse_ttl_expr;
+use databend_common_sql::plans::CreateTablePlan;
+use databend_common_sql::Planner;
+use databend_storages_common_table_meta::table::encode_identity_columns;
+use databend_storages_common_table_meta::table::is_materialized_view_opt_key;
+use databend_storages_common_table_meta::table::parse_identity_columns;
+use databend_storages_common_table_meta::table::IdentityColumn;
+use databend_storages_common_table_meta::table::IDENTITY_SEQUENCE_PREFIX;
+use databend_storages_common_table_meta::table::OPT_KEY_COMMENT;
+use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_IDENTITY_COLUMNS;
+use databend_storages_common_table_meta::table::OPT_KEY_TTL;
+use futures_util::TryStreamExt;
+use uuid::Uuid;
+
+use crate::interpreters::common::table_option_validation::is_valid_create_opt;
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::QueryContext;
 
 pub fn check_referenced_computed_columns(
     ctx: Arc<dyn TableContext>,
@@ -55,3 +85,296 @@ pub fn check_referenced_computed_columns(
     }
     Ok(())
 }
+
+/// Checks that the `constraints` of a table still apply to `schema`, the schema of the
+/// table after `column` is dropped or renamed.
+pub fn check_referenced_constraints(
+    ctx: Arc<dyn TableContext>,
+    constraints: &BTreeMap<String, TableConstraint>,
+    schema: DataSchemaRef,
+    column: &str,
+) -> Result<()> {
+    for (name, constraint) in constraints {
+        match constraint {
+            TableConstraint::Check(check) => {
+                if parse_check_constraint_expr(ctx.clone(), schema.clone(), check).is_err() {
+                    return Err(ErrorCode::ColumnReferencedByConstraint(format!(
+                        "column `{}` is referenced by CHECK constraint `{}`, drop the constraint first",
+                        column, name
+                    )));
+                }
+            }
+            TableConstraint::PrimaryKey(columns)
+            | TableConstraint::Unique(columns)
+            | TableConstraint::ForeignKey { columns, .. } => {
+                if columns.iter().any(|c| schema.field_with_name(c).is_err()) {
+                    return Err(ErrorCode::ColumnReferencedByConstraint(format!(
+                        "column `{}` is referenced by constraint `{}`, drop the constraint first",
+                        column, name
+                    )));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Checks that the TTL of a table still applies to `schema`, the schema of the table after
+/// `column` is dropped, renamed or modified.
+pub fn check_referenced_ttl(
+    ctx: Arc<dyn TableContext>,
+    options: &BTreeMap<String, String>,
+    schema: DataSchemaRef,
+    column: &str,
+) -> Result<()> {
+    if let Some(ttl) = options.get(OPT_KEY_TTL) {
+        if parse_ttl_expr(ctx, schema, ttl).is_err() {
+            return Err(ErrorCode::ColumnReferencedByTTL(format!(
+                "column `{}` is referenced by the TTL `{}` of the table, unset the TTL first",
+                column, ttl
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Checks that the existing rows of the table `catalog`.`database`.`table` satisfy the
+/// constraint `name`, by running a query which returns a row for each violation.
+#[async_backtrace::framed]
+pub async fn validate_constraint_rows(
+    ctx: &QueryContext,
+    catalog: &str,
+    database: &str,
+    table: &str,
+    name: &str,
+    constraint: &TableConstraint,
+) -> Result<()> {
+    let quote = |columns: &[String], alias: &str| {
+        columns
+            .iter()
+            .map(|c| format!("{}`{}`", alias, c))
+            .collect::<Vec<_>>()
+    };
+    let source = format!("`{}`.`{}`.`{}`", catalog, database, table);
+    let sql = match constraint {
+        TableConstraint::Check(check) => {
+            format!("SELECT 1 FROM {} WHERE NOT ({}) LIMIT 1", source, check)
+        }
+        // a primary key is neither NULL nor duplicated
+        TableConstraint::PrimaryKey(columns) => {
+            let columns = quote(columns, "");
+            format!(
+                "SELECT 1 FROM {source} WHERE {} UNION ALL SELECT 1 FROM {source} GROUP BY {} HAVING count(*) > 1 LIMIT 1",
+                columns
+                    .iter()
+                    .map(|c| format!("{} IS NULL", c))
+                    .collect::<Vec<_>>()
+                    .join(" OR "),
+                columns.join(", "),
+            )
+        }
+        TableConstraint::Unique(columns) => {
+            let columns = quote(columns, "");
+            format!(
+                "SELECT 1 FROM {} WHERE {} GROUP BY {} HAVING count(*) > 1 LIMIT 1",
+                source,
+                columns
+                    .iter()
+                    .map(|c| format!("{} IS NOT NULL", c))
+                    .collect::<Vec<_>>()
+                    .join(" AND "),
+                columns.join(", "),
+            )
+        }
+        // a row with any NULL in the foreign key references nothing
+        TableConstraint::ForeignKey {
+            columns,
+            ref_database,
+            ref_table,
+            ref_columns,
+        } => {
+            let columns = quote(columns, "c.");
+            let ref_columns = quote(ref_columns, "p.");
+            format!(
+                "SELECT 1 FROM {} AS c WHERE {} AND NOT EXISTS (SELECT 1 FROM `{}`.`{}`.`{}` AS p WHERE {}) LIMIT 1",
+                source,
+                columns
+                    .iter()
+                    .map(|c| format!("{} IS NOT NULL", c))
+                    .collect::<Vec<_>>()
+                    .join(" AND "),
+                catalog,
+                ref_database,
+                ref_table,
+                ref_columns
+                    .iter()
+                    .zip(columns.iter())
+                    .map(|(p, c)| format!("{} = {}", p, c))
+                    .collect::<Vec<_>>()
+                    .join(" AND "),
+            )
+        }
+    };
+
+    let ctx = ctx.get_current_session().create_query_context().await?;
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(&sql).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let stream = interpreter.execute(ctx).await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    if blocks.iter().any(|block| block.num_rows() > 0) {
+        return Err(match constraint {
+            TableConstraint::Check(_) => ErrorCode::CheckConstraintViolated(format!(
+                "CHECK constraint `{}` is violated by the existing rows of table `{}`",
+                name, table
+            )),
+            _ => ErrorCode::KeyConstraintViolated(format!(
+                "constraint `{}` is violated by the existing rows of table `{}`",
+                name, table
+            )),
+        });
+    }
+    Ok(())
+}
+
+/// Builds the plan to create an empty fuse table in database `db_id` with the same
+/// definition as `meta`, which is the meta of an existing fuse table.
+pub fn fuse_table_create_plan(
+    tenant: &Tenant,
+    catalog: &str,
+    database: &str,
+    db_id: u64,
+    table: &str,
+    meta: &TableMeta,
+) -> CreateTablePlan {
+    let mut options = meta
+        .options
+        .iter()
+        .filter(|(k, _)| {
+            // the identity columns are not a user option, their sequences are replaced by
+            // `copy_identity_sequences` before the table is created.
+            (is_valid_create_opt(k, &Engine::Fuse) || k.as_str() == OPT_KEY_IDENTITY_COLUMNS)
+                && !is_materialized_view_opt_key(k)
+        })
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect::<BTreeMap<_, _>>();
+    options.insert(OPT_KEY_DATABASE_ID.to_owned(), db_id.to_string());
+    if !meta.comment.is_empty() {
+        options.insert(OPT_KEY_COMMENT.to_owned(), meta.comment.clone());
+    }
+
+    CreateTablePlan {
+        create_option: CreateOption::Create,
+        tenant: tenant.clone(),
+        catalog: catalog.to_string(),
+        database: database.to_string(),
+        table: table.to_string(),
+        schema: meta.schema.clone(),
+        engine: Engine::Fuse,
+        engine_options: Default::default(),
+        storage_params: None,
+        options,
+        field_comments: meta.field_comments.clone(),
+        cluster_key: meta.cluster_key.clone(),
+        as_select: None,
+        inverted_indexes: None,
+        constraints: meta.constraints.clone(),
+        clone_from: None,
+    }
+}
+
+/// Creates the sequences of the identity columns kept in the table `options` that have
+/// no sequence yet, and records the names of the sequences back to `options`.
+pub async fn create_identity_sequences(
+    ctx: &dyn TableContext,
+    options: &mut BTreeMap<String, String>,
+) -> Result<()> {
+    let mut columns = parse_identity_columns(options)?;
+    if columns.iter().all(|column| !column.sequence.is_empty()) {
+        return Ok(());
+    }
+
+    for column in columns
+        .iter_mut()
+        .filter(|column| column.sequence.is_empty())
+    {
+        column.sequence = create_identity_sequence(ctx, column.start, column.increment).await?;
+    }
+    options.insert(
+        OPT_KEY_IDENTITY_COLUMNS.to_owned(),
+        encode_identity_columns(&columns)?,
+    );
+    Ok(())
+}
+
+/// Replaces the sequences of the identity columns kept in the table `options`, which are
+/// copied from another table, by new sequences owned by this table.
+///
+/// A new sequence continues from the current value of the sequence it replaces, so that it
+/// does not allocate the values of the rows shared with, or restored from, the other table.
+pub async fn copy_identity_sequences(
+    ctx: &dyn TableContext,
+    options: &mut BTreeMap<String, String>,
+) -> Result<()> {
+    let mut columns = parse_identity_columns(options)?;
+    if columns.is_empty() {
+        return Ok(());
+    }
+
+    let tenant = ctx.get_tenant();
+    let catalog = ctx.get_default_catalog()?;
+    for column in columns.iter_mut() {
+        let mut start = column.start;
+        if !column.sequence.is_empty() {
+            let req = GetSequenceReq {
+                ident: SequenceIdent::new(&tenant, &column.sequence),
+            };
+            match catalog.get_sequence(req).await {
+                Ok(reply) => start = reply.meta.current,
+                Err(e) if e.code() == ErrorCode::UNKNOWN_SEQUENCE => {}
+                Err(e) => return Err(e),
+            }
+        }
+        column.sequence = create_identity_sequence(ctx, start, column.increment).await?;
+    }
+    options.insert(
+        OPT_KEY_IDENTITY_COLUMNS.to_owned(),
+        encode_identity_columns(&columns)?,
+    );
+    Ok(())
+}
+
+/// Drops the sequences owned by the identity `columns`.
+pub async fn drop_identity_sequences(
+    ctx: &dyn TableContext,
+    columns: &[IdentityColumn],
+) -> Result<()> {
+    let tenant = ctx.get_tenant();
+    let catalog = ctx.get_default_catalog()?;
+    for column in columns.iter().filter(|column| !column.sequence.is_empty()) {
+        let req = DropSequenceReq {
+            if_exists: true,
+            ident: SequenceIdent::new(&tenant, &column.sequence),
+        };
+        catalog.drop_sequence(req).await?;
+    }
+    Ok(())
+}
+
+async fn create_identity_sequence(
+    ctx: &dyn TableContext,
+    start: u64,
+    increment: u64,
+) -> Result<String> {
+    let name = format!("{}{}", IDENTITY_SEQUENCE_PREFIX, Uuid::new_v4().simple());
+    let req = CreateSequenceReq {
+        create_option: CreateOption::Create,
+        ident: SequenceIdent::new(&ctx.get_tenant(), &name),
+        create_on: Utc::now(),
+        comment: Some("owned by an identity column".to_string()),
+        start,
+        step: increment as i64,
+    };
+    ctx.get_default_catalog()?.create_sequence(req).await?;
+    Ok(name)
+}
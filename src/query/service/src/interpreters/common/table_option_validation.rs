@@ -14,19 +14,24 @@
 
 use std::collections::BTreeMap;
 use std::collections::HashSet;
+use std::sync::Arc;
 use std::sync::LazyLock;
 
 use chrono::Duration;
 use databend_common_ast::ast::Engine;
+use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
+use databend_common_expression::DataSchema;
 use databend_common_expression::TableSchemaRef;
 use databend_common_io::constants::DEFAULT_BLOCK_MAX_ROWS;
 use databend_common_io::constants::DEFAULT_MIN_TABLE_LEVEL_DATA_RETENTION_PERIOD_IN_HOURS;
 use databend_common_settings::Settings;
+use databend_common_sql::parse_ttl_expr;
 use databend_common_sql::BloomIndexColumns;
 use databend_common_storages_fuse::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use databend_common_storages_fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
 use databend_common_storages_fuse::FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS;
+use databend_common_storages_fuse::FUSE_OPT_KEY_ENABLE_DELETION_VECTOR;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_PER_PAGE;
@@ -39,6 +44,10 @@ use databend_storages_common_table_meta::table::OPT_KEY_CONNECTION_NAME;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_ENGINE;
 use databend_storages_common_table_meta::table::OPT_KEY_LOCATION;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEWS;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_AUTO_REFRESH;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCES;
 use databend_storages_common_table_meta::table::OPT_KEY_RANDOM_MAX_ARRAY_LEN;
 use databend_storages_common_table_meta::table::OPT_KEY_RANDOM_MAX_STRING_LEN;
 use databend_storages_common_table_meta::table::OPT_KEY_RANDOM_MIN_STRING_LEN;
@@ -46,6 +55,7 @@ use databend_storages_common_table_meta::table::OPT_KEY_RANDOM_SEED;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
+use databend_storages_common_table_meta::table::OPT_KEY_TTL;
 use log::error;
 
 /// Table option keys that can occur in 'create table statement'.
@@ -57,6 +67,7 @@ pub static CREATE_FUSE_OPTIONS: LazyLock<HashSet<&'static str>> = LazyLock::new(
     r.insert(FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD);
     r.insert(FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD);
     r.insert(FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS);
+    r.insert(FUSE_OPT_KEY_ENABLE_DELETION_VECTOR);
 
     r.insert(OPT_KEY_BLOOM_INDEX_COLUMNS);
     r.insert(OPT_KEY_TABLE_COMPRESSION);
@@ -65,6 +76,11 @@ pub static CREATE_FUSE_OPTIONS: LazyLock<HashSet<&'static str>> = LazyLock::new(
     r.insert(OPT_KEY_COMMENT);
     r.insert(OPT_KEY_CHANGE_TRACKING);
     r.insert(OPT_KEY_CLUSTER_TYPE);
+    r.insert(OPT_KEY_TTL);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_AUTO_REFRESH);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_SOURCES);
+    r.insert(OPT_KEY_MATERIALIZED_VIEWS);
 
     r.insert(OPT_KEY_ENGINE);
 
@@ -109,6 +125,8 @@ pub static UNSET_TABLE_OPTIONS_WHITE_LIST: LazyLock<HashSet<&'static str>> = Laz
     r.insert(FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD);
     r.insert(FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD);
     r.insert(FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS);
+    r.insert(FUSE_OPT_KEY_ENABLE_DELETION_VECTOR);
+    r.insert(OPT_KEY_TTL);
     r
 });
 
@@ -194,6 +212,18 @@ pub fn is_valid_bloom_index_columns(
     Ok(())
 }
 
+pub fn is_valid_ttl(
+    ctx: Arc<dyn TableContext>,
+    options: &BTreeMap<String, String>,
+    schema: TableSchemaRef,
+) -> databend_common_exception::Result<()> {
+    if let Some(value) = options.get(OPT_KEY_TTL) {
+        let schema = Arc::new(DataSchema::from(schema));
+        parse_ttl_expr(ctx, schema, value)?;
+    }
+    Ok(())
+}
+
 pub fn is_valid_change_tracking(
     options: &BTreeMap<String, String>,
 ) -> databend_common_exception::Result<()> {
@@ -203,6 +233,23 @@ pub fn is_valid_change_tracking(
     Ok(())
 }
 
+/// Deletion vectors are only supported by the parquet storage format.
+pub fn is_valid_deletion_vector(
+    options: &BTreeMap<String, String>,
+    storage_format: Option<&String>,
+) -> databend_common_exception::Result<()> {
+    if let Some(value) = options.get(FUSE_OPT_KEY_ENABLE_DELETION_VECTOR) {
+        let enabled = value.to_lowercase().parse::<bool>()?;
+        if enabled && storage_format.is_some_and(|format| format.eq_ignore_ascii_case("native")) {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "{} is not supported by the native storage format",
+                FUSE_OPT_KEY_ENABLE_DELETION_VECTOR
+            )));
+        }
+    }
+    Ok(())
+}
+
 pub fn is_valid_random_seed(
     options: &BTreeMap<String, String>,
 ) -> databend_common_exception::Result<()> {
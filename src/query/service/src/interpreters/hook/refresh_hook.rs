@@ -35,12 +35,16 @@ use databend_common_sql::Binder;
 use databend_common_sql::Metadata;
 use databend_common_sql::NameResolutionContext;
 use databend_storages_common_table_meta::meta::Location;
+use databend_storages_common_table_meta::table::is_materialized_view_auto_refresh;
+use databend_storages_common_table_meta::table::parse_materialized_views;
+use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use log::info;
 use parking_lot::RwLock;
 
 use crate::interpreters::hook::vacuum_hook::hook_clear_m_cte_temp_table;
 use crate::interpreters::hook::vacuum_hook::hook_disk_temp_dir;
 use crate::interpreters::hook::vacuum_hook::hook_vacuum_temp_files;
+use crate::interpreters::refresh_materialized_view;
 use crate::interpreters::Interpreter;
 use crate::interpreters::RefreshIndexInterpreter;
 use crate::interpreters::RefreshTableIndexInterpreter;
@@ -83,6 +87,7 @@ async fn do_refresh(ctx: Arc<QueryContext>, desc: RefreshDesc) -> Result<()> {
         .get_table(&desc.catalog, &desc.database, &desc.table)
         .await?;
     let table_id = table.get_id();
+    let materialized_views = parse_materialized_views(table.options());
 
     let mut plans = Vec::new();
 
@@ -235,6 +240,41 @@ async fn do_refresh(ctx: Arc<QueryContext>, desc: RefreshDesc) -> Result<()> {
     }
 
     let _ = futures::future::try_join_all(tasks).await?;
+
+    // Refresh the materialized views refreshed on write, the writes in a transaction are not
+    // visible to the new queries until committed.
+    if !materialized_views.is_empty() && !ctx.txn_mgr().lock().is_active() {
+        refresh_materialized_views(ctx, &desc.catalog, materialized_views).await?;
+    }
+    Ok(())
+}
+
+async fn refresh_materialized_views(
+    ctx: Arc<QueryContext>,
+    catalog_name: &str,
+    view_ids: Vec<u64>,
+) -> Result<()> {
+    let catalog = ctx.get_catalog(catalog_name).await?;
+    for view_id in view_ids {
+        let Some(view_meta) = catalog.get_table_meta_by_id(view_id).await? else {
+            continue;
+        };
+        if view_meta.drop_on.is_some() || !is_materialized_view_auto_refresh(&view_meta.options) {
+            continue;
+        }
+        let Some(db_id) = view_meta
+            .options
+            .get(OPT_KEY_DATABASE_ID)
+            .and_then(|id| id.parse::<u64>().ok())
+        else {
+            continue;
+        };
+        let database = catalog.get_db_name_by_id(db_id).await?;
+        let Some(view_name) = catalog.get_table_name_by_id(view_id).await? else {
+            continue;
+        };
+        refresh_materialized_view(ctx.clone(), catalog_name, &database, &view_name).await?;
+    }
     Ok(())
 }
 
@@ -54,6 +54,7 @@ use super::hook::vacuum_hook::hook_disk_temp_dir;
 use super::hook::vacuum_hook::hook_vacuum_temp_files;
 use super::InterpreterMetrics;
 use super::InterpreterQueryLog;
+use crate::history_tables::HistoryLog;
 use crate::pipelines::executor::ExecutorSettings;
 use crate::pipelines::executor::PipelineCompleteExecutor;
 use crate::pipelines::executor::PipelinePullingExecutor;
@@ -311,6 +312,9 @@ pub fn on_execution_finished(info: &ExecutionInfo, query_ctx: Arc<QueryContext>)
                 statistics_desc: get_statistics_desc(),
             })?
         );
+        if let Some(history) = HistoryLog::instance() {
+            history.record_profiles(&query_ctx, &query_profiles);
+        }
         let profiles_queue = ProfilesLogQueue::instance()?;
         profiles_queue.append_data(ProfilesLogElement {
             query_id: query_ctx.get_id(),
@@ -0,0 +1,138 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_sql::plans::BackupDatabasePlan;
+use databend_common_storage::init_stage_operator;
+use databend_common_storages_fuse::operations::BackupManifest;
+use databend_common_storages_fuse::operations::BackupTable;
+use databend_common_storages_fuse::FuseTable;
+use databend_common_storages_view::view_table::VIEW_ENGINE;
+use log::debug;
+use log::info;
+use log::warn;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Backs up the fuse tables and views of a database to a stage.
+///
+/// If the location already holds backups of the database, only the files which are not
+/// stored by the latest one are copied.
+#[derive(Debug)]
+pub struct BackupDatabaseInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: BackupDatabasePlan,
+}
+
+impl BackupDatabaseInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: BackupDatabasePlan) -> Result<Self> {
+        Ok(BackupDatabaseInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for BackupDatabaseInterpreter {
+    fn name(&self) -> &str {
+        "BackupDatabaseInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[fastrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "backup_database_execute");
+
+        let plan = &self.plan;
+        let operator = init_stage_operator(&plan.stage_info)?;
+
+        let (base, stored) = match BackupManifest::read_latest(&operator, &plan.path).await? {
+            Some((name, manifest)) => {
+                if manifest.database != plan.database {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "location {} already holds backups of database {}",
+                        plan.path, manifest.database
+                    )));
+                }
+                (Some(name), manifest.stored_files())
+            }
+            None => (None, HashSet::new()),
+        };
+
+        let catalog = self.ctx.get_catalog(&plan.catalog).await?;
+        let mut views = vec![];
+        let mut fuse_tables = vec![];
+        for table in catalog.list_tables(&plan.tenant, &plan.database).await? {
+            if table.engine() == VIEW_ENGINE {
+                views.push(BackupTable {
+                    name: table.name().to_string(),
+                    meta: table.get_table_info().meta.clone(),
+                    snapshot_location: None,
+                    files: vec![],
+                });
+                continue;
+            }
+
+            if table.engine() != "FUSE"
+                || table.is_read_only()
+                || table.is_temp()
+                || table.get_table_info().meta.storage_params.is_some()
+            {
+                warn!(
+                    "skip table {}.{} of engine {} while backing up database",
+                    plan.database,
+                    table.name(),
+                    table.engine()
+                );
+                continue;
+            }
+
+            fuse_tables.push(table);
+        }
+
+        // pin the snapshots of all the tables before copying, so the backup is consistent
+        let mut snapshots = Vec::with_capacity(fuse_tables.len());
+        for table in &fuse_tables {
+            let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+            snapshots.push(fuse_table.backup_snapshot().await?);
+        }
+
+        let mut tables = views;
+        for (table, snapshot) in fuse_tables.iter().zip(snapshots) {
+            let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+            let backup = fuse_table
+                .backup(self.ctx.clone(), &operator, &plan.path, &stored, snapshot)
+                .await?;
+            tables.push(backup);
+        }
+
+        let manifest = BackupManifest::create(plan.database.clone(), base, tables);
+        let name = manifest.write(&operator, &plan.path).await?;
+        info!(
+            "backup database {} to {}, manifest {}",
+            plan.database, plan.path, name
+        );
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -14,17 +14,29 @@
 
 use std::sync::Arc;
 
+use databend_common_catalog::catalog::Catalog;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_management::RoleApi;
 use databend_common_meta_app::principal::OwnershipObject;
+use databend_common_meta_app::schema::database_name_ident::DatabaseNameIdent;
 use databend_common_meta_app::schema::CreateDatabaseReq;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::CreateTableReq;
+use databend_common_meta_app::schema::DropDatabaseReq;
+use databend_common_meta_app::schema::TableNameIdent;
 use databend_common_meta_types::MatchSeq;
+use databend_common_sql::plans::CloneTableSource;
 use databend_common_sql::plans::CreateDatabasePlan;
+use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_common_users::RoleCacheManager;
 use databend_common_users::UserApiProvider;
 use log::debug;
+use log::info;
+use log::warn;
 
+use crate::interpreters::common::fuse_table_create_plan;
+use crate::interpreters::CreateTableInterpreter;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -40,6 +52,73 @@ impl CreateDatabaseInterpreter {
     pub fn try_create(ctx: Arc<QueryContext>, plan: CreateDatabasePlan) -> Result<Self> {
         Ok(CreateDatabaseInterpreter { ctx, plan })
     }
+
+    /// Clones the fuse tables and views of the source database into the newly created one.
+    ///
+    /// Tables of other engines are skipped.
+    #[async_backtrace::framed]
+    async fn clone_database(
+        &self,
+        catalog: Arc<dyn Catalog>,
+        source_db: &str,
+        db_id: u64,
+    ) -> Result<()> {
+        let tenant = &self.plan.tenant;
+        for table in catalog.list_tables(tenant, source_db).await? {
+            let table_info = table.get_table_info();
+            let name = table.name().to_string();
+
+            if table.engine() == VIEW_ENGINE {
+                let req = CreateTableReq {
+                    create_option: CreateOption::Create,
+                    name_ident: TableNameIdent {
+                        tenant: tenant.clone(),
+                        db_name: self.plan.database.clone(),
+                        table_name: name,
+                    },
+                    table_meta: table_info.meta.clone(),
+                    as_dropped: false,
+                };
+                catalog.create_table(req).await?;
+                continue;
+            }
+
+            if table.engine() != "FUSE"
+                || table.is_read_only()
+                || table.is_temp()
+                || table_info.meta.storage_params.is_some()
+            {
+                warn!(
+                    "skip table {}.{} of engine {} while cloning database",
+                    source_db,
+                    name,
+                    table.engine()
+                );
+                continue;
+            }
+
+            let mut plan = fuse_table_create_plan(
+                tenant,
+                &self.plan.catalog,
+                &self.plan.database,
+                db_id,
+                &name,
+                &table_info.meta,
+            );
+            plan.clone_from = Some(CloneTableSource {
+                catalog: self.plan.catalog.clone(),
+                database: source_db.to_string(),
+                table: name,
+                table_info: table_info.clone(),
+            });
+            CreateTableInterpreter::try_create(self.ctx.clone(), plan)?
+                .execute2()
+                .await?;
+        }
+
+        info!("database {} cloned from {}", self.plan.database, source_db);
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -70,6 +149,15 @@ impl Interpreter for CreateDatabaseInterpreter {
             )));
         };
 
+        if self.plan.clone_from.is_some()
+            && self.plan.create_option == CreateOption::CreateIfNotExists
+            && catalog
+                .exists_database(&tenant, &self.plan.database)
+                .await?
+        {
+            return Ok(PipelineBuildResult::create());
+        }
+
         let create_db_req: CreateDatabaseReq = self.plan.clone().into();
         let reply = catalog.create_database(create_db_req).await?;
 
@@ -89,6 +177,31 @@ impl Interpreter for CreateDatabaseInterpreter {
             RoleCacheManager::instance().invalidate_cache(&tenant);
         }
 
+        if let Some(source_db) = &self.plan.clone_from {
+            if let Err(e) = self
+                .clone_database(catalog.clone(), source_db, *reply.db_id)
+                .await
+            {
+                // do not leave a partially cloned database, the tables cloned so far are
+                // dropped with it and left for vacuum.
+                warn!(
+                    "clone database {} from {} failed, drop it: {}",
+                    self.plan.database, source_db, e
+                );
+                let req = DropDatabaseReq {
+                    if_exists: true,
+                    name_ident: DatabaseNameIdent::new(&tenant, &self.plan.database),
+                };
+                if let Err(drop_err) = catalog.drop_database(req).await {
+                    warn!(
+                        "failed to drop the partially cloned database {}: {}",
+                        self.plan.database, drop_err
+                    );
+                }
+                return Err(e);
+            }
+        }
+
         Ok(PipelineBuildResult::create())
     }
 }
@@ -0,0 +1,204 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::CreateTableReq;
+use databend_common_meta_app::schema::DatabaseMeta;
+use databend_common_meta_app::schema::TableNameIdent;
+use databend_common_sql::plans::CreateDatabasePlan;
+use databend_common_sql::plans::DropDatabasePlan;
+use databend_common_sql::plans::RestoreDatabasePlan;
+use databend_common_storage::init_stage_operator;
+use databend_common_storages_fuse::operations::BackupManifest;
+use databend_common_storages_fuse::FuseTable;
+use databend_common_storages_view::view_table::VIEW_ENGINE;
+use log::debug;
+use log::info;
+use log::warn;
+use opendal::Operator;
+
+use crate::interpreters::common::copy_identity_sequences;
+use crate::interpreters::common::fuse_table_create_plan;
+use crate::interpreters::CreateDatabaseInterpreter;
+use crate::interpreters::CreateTableInterpreter;
+use crate::interpreters::DropDatabaseInterpreter;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Restores the latest backup of a database in a stage into a new database.
+#[derive(Debug)]
+pub struct RestoreDatabaseInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RestoreDatabasePlan,
+}
+
+impl RestoreDatabaseInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RestoreDatabasePlan) -> Result<Self> {
+        Ok(RestoreDatabaseInterpreter { ctx, plan })
+    }
+}
+
+impl RestoreDatabaseInterpreter {
+    /// Restores the tables of the backup into the new database.
+    ///
+    /// Like a clone, every table is created as a dropped table first, and all of them are made
+    /// visible only after all of them are restored.
+    #[async_backtrace::framed]
+    async fn restore_tables(&self, operator: &Operator, manifest: &BackupManifest) -> Result<()> {
+        let plan = &self.plan;
+        let catalog = self.ctx.get_catalog(&plan.catalog).await?;
+        let db_id = catalog
+            .get_database(&plan.tenant, &plan.new_database)
+            .await?
+            .get_db_info()
+            .database_id
+            .db_id;
+
+        let mut restored = vec![];
+        for table in manifest
+            .tables
+            .iter()
+            .filter(|t| t.meta.engine != VIEW_ENGINE)
+        {
+            let mut create_table = fuse_table_create_plan(
+                &plan.tenant,
+                &plan.catalog,
+                &plan.new_database,
+                db_id,
+                &table.name,
+                &table.meta,
+            );
+            copy_identity_sequences(self.ctx.as_ref(), &mut create_table.options).await?;
+            let interpreter = CreateTableInterpreter::try_create(self.ctx.clone(), create_table)?;
+            let req = interpreter.build_request(None)?;
+            let Some((table_info, reply)) = interpreter.create_dropped_table(req).await? else {
+                return Err(ErrorCode::TableAlreadyExists(format!(
+                    "table {} already exists in database {}",
+                    table.name, plan.new_database
+                )));
+            };
+
+            let target = catalog.get_table_by_info(&table_info)?;
+            FuseTable::try_from_table(target.as_ref())?
+                .restore(self.ctx.clone(), operator, &plan.path, table)
+                .await?;
+            restored.push((interpreter, reply));
+        }
+
+        for (interpreter, reply) in restored {
+            interpreter.commit_dropped_table(reply).await?;
+        }
+
+        // the views are created last, as the tables they query are visible now
+        for table in manifest
+            .tables
+            .iter()
+            .filter(|t| t.meta.engine == VIEW_ENGINE)
+        {
+            let req = CreateTableReq {
+                create_option: CreateOption::Create,
+                name_ident: TableNameIdent {
+                    tenant: plan.tenant.clone(),
+                    db_name: plan.new_database.clone(),
+                    table_name: table.name.clone(),
+                },
+                table_meta: table.meta.clone(),
+                as_dropped: false,
+            };
+            catalog.create_table(req).await?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RestoreDatabaseInterpreter {
+    fn name(&self) -> &str {
+        "RestoreDatabaseInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[fastrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "restore_database_execute");
+
+        let plan = &self.plan;
+        let operator = init_stage_operator(&plan.stage_info)?;
+        let Some((name, manifest)) = BackupManifest::read_latest(&operator, &plan.path).await?
+        else {
+            return Err(ErrorCode::BadArguments(format!(
+                "no backup found in location {}",
+                plan.path
+            )));
+        };
+        if manifest.database != plan.database {
+            return Err(ErrorCode::BadArguments(format!(
+                "location {} holds backups of database {}, not {}",
+                plan.path, manifest.database, plan.database
+            )));
+        }
+
+        let create_database = CreateDatabasePlan {
+            create_option: CreateOption::Create,
+            tenant: plan.tenant.clone(),
+            catalog: plan.catalog.clone(),
+            database: plan.new_database.clone(),
+            meta: DatabaseMeta {
+                engine: "".to_string(),
+                ..Default::default()
+            },
+            clone_from: None,
+        };
+        CreateDatabaseInterpreter::try_create(self.ctx.clone(), create_database)?
+            .execute2()
+            .await?;
+
+        if let Err(cause) = self.restore_tables(&operator, &manifest).await {
+            // the tables are not visible yet, drop the database and leave them for vacuum
+            let drop_database = DropDatabasePlan {
+                if_exists: true,
+                tenant: plan.tenant.clone(),
+                catalog: plan.catalog.clone(),
+                database: plan.new_database.clone(),
+            };
+            if let Err(e) = DropDatabaseInterpreter::try_create(self.ctx.clone(), drop_database)?
+                .execute2()
+                .await
+            {
+                warn!(
+                    "failed to drop database {} of the failed restore: {}",
+                    plan.new_database, e
+                );
+            }
+            return Err(cause);
+        }
+
+        info!(
+            "restore database {} from {}, manifest {}, into {}",
+            plan.database, plan.path, name, plan.new_database
+        );
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -333,9 +333,20 @@ impl ExplainInterpreter {
             }
         }
 
-        let result = plan
+        let mut result = plan
             .format(metadata.clone(), Default::default())?
             .format_pretty()?;
+        // Show the optimizer hints and whether they were applied.
+        let hints = metadata
+            .read()
+            .optimizer_hints()
+            .iter()
+            .map(|(hint, state)| FormatTreeNode::new(format!("{hint}: {state}")))
+            .collect::<Vec<_>>();
+        if !hints.is_empty() {
+            let hints = FormatTreeNode::with_children("Hints".to_string(), hints);
+            result = format!("{}{}", hints.format_pretty()?, result);
+        }
         let line_split_result: Vec<&str> = result.lines().collect();
         let formatted_plan = StringType::from_data(line_split_result);
         Ok(vec![DataBlock::new_from_columns(vec![formatted_plan])])
@@ -38,6 +38,7 @@ use super::interpreter_table_set_options::SetOptionsInterpreter;
 use super::interpreter_user_stage_drop::DropUserStageInterpreter;
 use super::*;
 use crate::interpreters::access::Accessor;
+use crate::interpreters::common::attach_query_lineage;
 use crate::interpreters::interpreter_add_warehouse_cluster::AddWarehouseClusterInterpreter;
 use crate::interpreters::interpreter_assign_warehouse_nodes::AssignWarehouseNodesInterpreter;
 use crate::interpreters::interpreter_catalog_drop::DropCatalogInterpreter;
@@ -83,14 +84,20 @@ use crate::interpreters::interpreter_tasks_show::ShowTasksInterpreter;
 use crate::interpreters::interpreter_txn_abort::AbortInterpreter;
 use crate::interpreters::interpreter_txn_begin::BeginInterpreter;
 use crate::interpreters::interpreter_txn_commit::CommitInterpreter;
+use crate::interpreters::interpreter_txn_release_savepoint::ReleaseSavepointInterpreter;
+use crate::interpreters::interpreter_txn_rollback_to_savepoint::RollbackToSavepointInterpreter;
+use crate::interpreters::interpreter_txn_savepoint::SavepointInterpreter;
 use crate::interpreters::interpreter_unassign_warehouse_nodes::UnassignWarehouseNodesInterpreter;
 use crate::interpreters::interpreter_use_warehouse::UseWarehouseInterpreter;
 use crate::interpreters::interpreter_view_describe::DescribeViewInterpreter;
 use crate::interpreters::AlterUserInterpreter;
+use crate::interpreters::CreateMaterializedViewInterpreter;
 use crate::interpreters::CreateStreamInterpreter;
 use crate::interpreters::DescUserInterpreter;
+use crate::interpreters::DropMaterializedViewInterpreter;
 use crate::interpreters::DropStreamInterpreter;
 use crate::interpreters::DropUserInterpreter;
+use crate::interpreters::RefreshMaterializedViewInterpreter;
 use crate::interpreters::SetRoleInterpreter;
 use crate::sessions::QueryContext;
 use crate::sql::plans::Plan;
@@ -110,6 +117,7 @@ impl InterpreterFactory {
                 error!("Access.denied(v2): {:?}", e);
             }
         })?;
+        attach_query_lineage(&ctx, plan).await;
 
         Self::get_warehouses_interpreter(ctx, plan, Self::get_inner)
     }
@@ -287,6 +295,14 @@ impl InterpreterFactory {
                 RenameDatabaseInterpreter::try_create(ctx, *rename_database.clone())?,
             )),
 
+            Plan::BackupDatabase(backup_database) => Ok(Arc::new(
+                BackupDatabaseInterpreter::try_create(ctx, *backup_database.clone())?,
+            )),
+
+            Plan::RestoreDatabase(restore_database) => Ok(Arc::new(
+                RestoreDatabaseInterpreter::try_create(ctx, *restore_database.clone())?,
+            )),
+
             // Tables
             Plan::ShowCreateTable(show_create_table) => Ok(Arc::new(
                 ShowCreateTableInterpreter::try_create(ctx, *show_create_table.clone())?,
@@ -333,6 +349,18 @@ impl InterpreterFactory {
             Plan::DropTableColumn(drop_table_column) => Ok(Arc::new(
                 DropTableColumnInterpreter::try_create(ctx, *drop_table_column.clone())?,
             )),
+            Plan::AddTableConstraint(add_table_constraint) => Ok(Arc::new(
+                AddTableConstraintInterpreter::try_create(ctx, *add_table_constraint.clone())?,
+            )),
+            Plan::DropTableConstraint(drop_table_constraint) => Ok(Arc::new(
+                DropTableConstraintInterpreter::try_create(ctx, *drop_table_constraint.clone())?,
+            )),
+            Plan::ValidateTableConstraint(validate_table_constraint) => {
+                Ok(Arc::new(ValidateTableConstraintInterpreter::try_create(
+                    ctx,
+                    *validate_table_constraint.clone(),
+                )?))
+            }
             Plan::AlterTableClusterKey(alter_table_cluster_key) => Ok(Arc::new(
                 AlterTableClusterKeyInterpreter::try_create(ctx, *alter_table_cluster_key.clone())?,
             )),
@@ -353,6 +381,9 @@ impl InterpreterFactory {
             Plan::TruncateTable(truncate_table) => Ok(Arc::new(
                 TruncateTableInterpreter::try_create(ctx, *truncate_table.clone())?,
             )),
+            Plan::CacheWarmupTable(cache_warmup) => Ok(Arc::new(
+                CacheWarmupTableInterpreter::try_create(ctx, *cache_warmup.clone())?,
+            )),
             Plan::OptimizePurge(purge) => Ok(Arc::new(OptimizePurgeInterpreter::try_create(
                 ctx,
                 *purge.clone(),
@@ -405,6 +436,31 @@ impl InterpreterFactory {
                 *describe_view.clone(),
             )?)),
 
+            // Materialized views
+            Plan::CreateMaterializedView(create_materialized_view) => {
+                Ok(Arc::new(CreateMaterializedViewInterpreter::try_create(
+                    ctx,
+                    *create_materialized_view.clone(),
+                )?))
+            }
+            Plan::DropMaterializedView(drop_materialized_view) => Ok(Arc::new(
+                DropMaterializedViewInterpreter::try_create(ctx, *drop_materialized_view.clone())?,
+            )),
+            Plan::RefreshMaterializedView(refresh_materialized_view) => {
+                Ok(Arc::new(RefreshMaterializedViewInterpreter::try_create(
+                    ctx,
+                    *refresh_materialized_view.clone(),
+                )?))
+            }
+
+            // Plan baselines
+            Plan::PinPlanBaseline(pin_plan_baseline) => Ok(Arc::new(
+                PinPlanBaselineInterpreter::try_create(ctx, *pin_plan_baseline.clone())?,
+            )),
+            Plan::UnpinPlanBaseline(unpin_plan_baseline) => Ok(Arc::new(
+                UnpinPlanBaselineInterpreter::try_create(ctx, *unpin_plan_baseline.clone())?,
+            )),
+
             // Streams
             Plan::CreateStream(create_stream) => Ok(Arc::new(CreateStreamInterpreter::try_create(
                 ctx,
@@ -658,6 +714,17 @@ impl InterpreterFactory {
             Plan::Begin => Ok(Arc::new(BeginInterpreter::try_create(ctx)?)),
             Plan::Commit => Ok(Arc::new(CommitInterpreter::try_create(ctx)?)),
             Plan::Abort => Ok(Arc::new(AbortInterpreter::try_create(ctx)?)),
+            Plan::Savepoint(name) => Ok(Arc::new(SavepointInterpreter::try_create(
+                ctx,
+                name.clone(),
+            )?)),
+            Plan::RollbackToSavepoint(name) => Ok(Arc::new(
+                RollbackToSavepointInterpreter::try_create(ctx, name.clone())?,
+            )),
+            Plan::ReleaseSavepoint(name) => Ok(Arc::new(ReleaseSavepointInterpreter::try_create(
+                ctx,
+                name.clone(),
+            )?)),
             Plan::CreateNotification(p) => Ok(Arc::new(CreateNotificationInterpreter::try_create(
                 ctx,
                 *p.clone(),
@@ -0,0 +1,108 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_exception::Result;
+use databend_common_pipeline_core::ExecutionInfo;
+use databend_common_sql::plans::CreateMaterializedViewPlan;
+use databend_storages_common_table_meta::table::parse_materialized_view_sources;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use log::info;
+
+use crate::interpreters::common::update_source_materialized_views;
+use crate::interpreters::CreateTableInterpreter;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateMaterializedViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateMaterializedViewPlan,
+}
+
+impl CreateMaterializedViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateMaterializedViewPlan) -> Result<Self> {
+        Ok(CreateMaterializedViewInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateMaterializedViewInterpreter {
+    fn name(&self) -> &str {
+        "CreateMaterializedViewInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let interpreter =
+            CreateTableInterpreter::try_create(self.ctx.clone(), self.plan.create_table.clone())?;
+        let mut build_res = interpreter.execute2().await?;
+
+        // The view is visible after the table is committed, then it is registered to the
+        // source tables so that it is refreshed and used by the queries on them.
+        let ctx = self.ctx.clone();
+        let catalog = self.plan.create_table.catalog.clone();
+        let database = self.plan.create_table.database.clone();
+        let view_name = self.plan.create_table.table.clone();
+        if build_res.main_pipeline.is_empty() {
+            register_materialized_view(ctx, catalog, database, view_name).await?;
+        } else {
+            build_res
+                .main_pipeline
+                .set_on_finished(move |info: &ExecutionInfo| {
+                    if info.res.is_ok() {
+                        GlobalIORuntime::instance().block_on(register_materialized_view(
+                            ctx, catalog, database, view_name,
+                        ))?;
+                    }
+                    Ok(())
+                });
+        }
+
+        Ok(build_res)
+    }
+}
+
+async fn register_materialized_view(
+    ctx: Arc<QueryContext>,
+    catalog: String,
+    database: String,
+    view_name: String,
+) -> Result<()> {
+    let catalog = ctx.get_catalog(&catalog).await?;
+    let view = catalog
+        .get_table(&ctx.get_tenant(), &database, &view_name)
+        .await?;
+    // The table may already exist with `IF NOT EXISTS`.
+    if !view.options().contains_key(OPT_KEY_MATERIALIZED_VIEW_QUERY) {
+        return Ok(());
+    }
+    let table_ids = parse_materialized_view_sources(view.options())?
+        .into_iter()
+        .map(|source| source.table_id)
+        .collect::<Vec<_>>();
+    info!(
+        "register materialized view {}.{} to tables {:?}",
+        database, view_name, table_ids
+    );
+    update_source_materialized_views(&catalog, &ctx.get_tenant(), view.get_id(), &table_ids, true)
+        .await
+}
@@ -0,0 +1,113 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::DropTableByIdReq;
+use databend_common_sql::plans::DropMaterializedViewPlan;
+use databend_storages_common_table_meta::table::parse_materialized_view_sources;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+
+use crate::interpreters::common::update_source_materialized_views;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct DropMaterializedViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropMaterializedViewPlan,
+}
+
+impl DropMaterializedViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropMaterializedViewPlan) -> Result<Self> {
+        Ok(DropMaterializedViewInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropMaterializedViewInterpreter {
+    fn name(&self) -> &str {
+        "DropMaterializedViewInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog_name = self.plan.catalog.clone();
+        let db_name = self.plan.database.clone();
+        let view_name = self.plan.view_name.clone();
+        let tbl = self
+            .ctx
+            .get_table(&catalog_name, &db_name, &view_name)
+            .await
+            .ok();
+
+        let Some(table) = tbl else {
+            if self.plan.if_exists {
+                return Ok(PipelineBuildResult::create());
+            }
+            return Err(ErrorCode::UnknownTable(format!(
+                "unknown materialized view `{}`.`{}` in catalog '{}'",
+                db_name, view_name, &catalog_name
+            )));
+        };
+
+        if !table
+            .options()
+            .contains_key(OPT_KEY_MATERIALIZED_VIEW_QUERY)
+        {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} is not MATERIALIZED VIEW",
+                &self.plan.database, &self.plan.view_name
+            )));
+        }
+
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let db = catalog
+            .get_database(&self.plan.tenant, &self.plan.database)
+            .await?;
+        catalog
+            .drop_table_by_id(DropTableByIdReq {
+                if_exists: self.plan.if_exists,
+                tenant: self.plan.tenant.clone(),
+                table_name: self.plan.view_name.clone(),
+                tb_id: table.get_id(),
+                db_id: db.get_db_info().database_id.db_id,
+                engine: table.engine().to_string(),
+                session_id: "".to_string(),
+            })
+            .await?;
+
+        let table_ids = parse_materialized_view_sources(table.options())?
+            .into_iter()
+            .map(|source| source.table_id)
+            .collect::<Vec<_>>();
+        update_source_materialized_views(
+            &catalog,
+            &self.plan.tenant,
+            table.get_id(),
+            &table_ids,
+            false,
+        )
+        .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
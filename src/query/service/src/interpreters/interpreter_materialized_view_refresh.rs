@@ -0,0 +1,153 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::UpsertTableOptionReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::plans::Insert;
+use databend_common_sql::plans::InsertInputSource;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::plans::RefreshMaterializedViewPlan;
+use databend_common_sql::Planner;
+use databend_storages_common_table_meta::table::encode_materialized_view_sources;
+use databend_storages_common_table_meta::table::MaterializedViewSource;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCES;
+use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use futures_util::TryStreamExt;
+use log::info;
+
+use crate::interpreters::InsertInterpreter;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct RefreshMaterializedViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RefreshMaterializedViewPlan,
+}
+
+impl RefreshMaterializedViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RefreshMaterializedViewPlan) -> Result<Self> {
+        Ok(RefreshMaterializedViewInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RefreshMaterializedViewInterpreter {
+    fn name(&self) -> &str {
+        "RefreshMaterializedViewInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        false
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        refresh_materialized_view(
+            self.ctx.clone(),
+            &self.plan.catalog,
+            &self.plan.database,
+            &self.plan.view_name,
+        )
+        .await?;
+        Ok(PipelineBuildResult::create())
+    }
+}
+
+/// Recomputes the materialized view with its query, and records the snapshots of the source
+/// tables read by the query.
+pub async fn refresh_materialized_view(
+    ctx: Arc<QueryContext>,
+    catalog_name: &str,
+    database: &str,
+    view_name: &str,
+) -> Result<()> {
+    let view = ctx.get_table(catalog_name, database, view_name).await?;
+    let Some(query) = view.options().get(OPT_KEY_MATERIALIZED_VIEW_QUERY) else {
+        return Err(ErrorCode::TableEngineNotSupported(format!(
+            "{}.{} is not MATERIALIZED VIEW",
+            database, view_name
+        )));
+    };
+    info!(
+        "refresh materialized view {}.{} with query: {}",
+        database, view_name, query
+    );
+
+    // The view is refreshed in a new query, so that the tables are read at their latest
+    // snapshots.
+    let refresh_ctx = ctx.get_current_session().create_query_context().await?;
+    let mut planner = Planner::new(refresh_ctx.clone());
+    let (plan, _) = planner.plan_sql(query).await?;
+    let Plan::Query { metadata, .. } = &plan else {
+        return Err(ErrorCode::IllegalMaterializedView(format!(
+            "Invalid query of materialized view {}.{}: {}",
+            database, view_name, query
+        )));
+    };
+    let mut sources: Vec<MaterializedViewSource> = vec![];
+    for table_entry in metadata.read().tables() {
+        // The query may read from other materialized views.
+        if table_entry.is_source_of_index() {
+            continue;
+        }
+        let table = table_entry.table();
+        if sources
+            .iter()
+            .all(|source| source.table_id != table.get_id())
+        {
+            sources.push(MaterializedViewSource {
+                table_id: table.get_id(),
+                snapshot_location: table.options().get(OPT_KEY_SNAPSHOT_LOCATION).cloned(),
+            });
+        }
+    }
+
+    let insert = Insert {
+        catalog: catalog_name.to_string(),
+        database: database.to_string(),
+        table: view_name.to_string(),
+        schema: view.schema(),
+        overwrite: true,
+        source: InsertInputSource::SelectPlan(Box::new(plan)),
+        table_info: None,
+    };
+    let interpreter = InsertInterpreter::try_create(refresh_ctx.clone(), insert)?;
+    let stream = interpreter.execute(refresh_ctx).await?;
+    stream.try_collect::<Vec<_>>().await?;
+
+    let catalog = ctx.get_catalog(catalog_name).await?;
+    let Some(view_meta) = catalog.get_table_meta_by_id(view.get_id()).await? else {
+        return Ok(());
+    };
+    let req = UpsertTableOptionReq {
+        table_id: view.get_id(),
+        seq: MatchSeq::Exact(view_meta.seq),
+        options: HashMap::from([(
+            OPT_KEY_MATERIALIZED_VIEW_SOURCES.to_string(),
+            Some(encode_materialized_view_sources(&sources)?),
+        )]),
+    };
+    catalog
+        .upsert_table_option(&ctx.get_tenant(), database, req)
+        .await?;
+    Ok(())
+}
@@ -81,6 +81,17 @@ impl Interpreter for OptimizeCompactBlockInterpreter {
             .acquire_table_lock(&catalog, &database, &table, &self.lock_opt)
             .await?;
 
+        // blocks wholly expired by the TTL of the table are dropped without being compacted,
+        // under the table lock and before the compaction is planned.
+        let tbl = self
+            .ctx
+            .get_catalog(&catalog)
+            .await?
+            .get_table(&self.ctx.get_tenant(), &database, &table)
+            .await?;
+        tbl.check_mutable()?;
+        tbl.drop_expired_blocks(self.ctx.clone()).await?;
+
         let mut build_res = PipelineBuildResult::create();
         let mut builder = PhysicalPlanBuilder::new(MetadataRef::default(), self.ctx.clone(), false);
         match builder.build(&self.s_expr, HashSet::new()).await {
@@ -0,0 +1,88 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::PlanBaseline;
+use databend_common_sql::optimizer::PlanOutline;
+use databend_common_sql::plans::PinPlanBaselinePlan;
+use databend_common_sql::plans::Plan;
+use databend_common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct PinPlanBaselineInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: PinPlanBaselinePlan,
+}
+
+impl PinPlanBaselineInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: PinPlanBaselinePlan) -> Result<Self> {
+        Ok(PinPlanBaselineInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for PinPlanBaselineInterpreter {
+    fn name(&self) -> &str {
+        "PinPlanBaselineInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let outline = match self.plan.plan.as_ref() {
+            Plan::Query {
+                s_expr, metadata, ..
+            } => PlanOutline::extract(&metadata.read(), s_expr)?,
+            _ => None,
+        };
+        let Some(outline) = outline else {
+            return Err(ErrorCode::IllegalPlanBaseline(format!(
+                "The plan of query has no join to pin: {}",
+                self.plan.query_text
+            )));
+        };
+
+        let api = UserApiProvider::instance().plan_baseline_api(&self.ctx.get_tenant())?;
+        let now = Utc::now();
+        let created_on = api
+            .get_baseline(&self.plan.sql_hash)
+            .await?
+            .map_or(now, |baseline| baseline.data.created_on);
+        api.upsert_baseline(&PlanBaseline {
+            sql_hash: self.plan.sql_hash.clone(),
+            query_text: self.plan.query_text.clone(),
+            join_order: outline.join_order.clone(),
+            hints: outline.baseline_hints(),
+            plan_fingerprint: outline.fingerprint(),
+            execution_time_ms: None,
+            rejected_fingerprints: vec![],
+            created_on,
+            updated_on: now,
+        })
+        .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,63 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_sql::plans::UnpinPlanBaselinePlan;
+use databend_common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct UnpinPlanBaselineInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: UnpinPlanBaselinePlan,
+}
+
+impl UnpinPlanBaselineInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: UnpinPlanBaselinePlan) -> Result<Self> {
+        Ok(UnpinPlanBaselineInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for UnpinPlanBaselineInterpreter {
+    fn name(&self) -> &str {
+        "UnpinPlanBaselineInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let dropped = UserApiProvider::instance()
+            .plan_baseline_api(&self.ctx.get_tenant())?
+            .drop_baseline(&self.plan.sql_hash)
+            .await?;
+        if !dropped {
+            return Err(ErrorCode::UnknownPlanBaseline(format!(
+                "The query has no plan baseline: {}",
+                self.plan.query_text
+            )));
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
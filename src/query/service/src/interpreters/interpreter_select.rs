@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Instant;
 
 use databend_common_base::runtime::GlobalIORuntime;
 use databend_common_catalog::table::Table;
@@ -43,8 +44,10 @@ use databend_common_storages_result_cache::WriteResultCacheSink;
 use databend_common_users::UserApiProvider;
 use log::error;
 use log::info;
+use log::warn;
 
 use crate::interpreters::common::query_build_update_stream_req;
+use crate::interpreters::common::record_plan_baseline_execution;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::schedulers::build_query_pipeline;
@@ -129,6 +132,7 @@ impl SelectInterpreter {
             }
         }
 
+        let start = Instant::now();
         let mut build_res = build_query_pipeline(
             &self.ctx,
             &self.bind_context.columns,
@@ -159,9 +163,44 @@ impl SelectInterpreter {
                 }),
                 Err(error_code) => Err(error_code.clone()),
             });
+
+        // Measure the plan against its baseline.
+        let plan_baseline = self.metadata.read().plan_baseline().cloned();
+        if let Some(plan_baseline) = plan_baseline {
+            let tenant = self.ctx.get_tenant();
+            build_res
+                .main_pipeline
+                .set_on_finished(move |info: &ExecutionInfo| match &info.res {
+                    Ok(_) => {
+                        let elapsed = start.elapsed();
+                        GlobalIORuntime::instance().block_on(async move {
+                            let res =
+                                record_plan_baseline_execution(&tenant, plan_baseline, elapsed)
+                                    .await;
+                            if let Err(e) = res {
+                                warn!("failed to update the plan baseline: {}", e);
+                            }
+                            Ok(())
+                        })
+                    }
+                    Err(_) => Ok(()),
+                });
+        }
         Ok(build_res)
     }
 
+    fn attach_plan_baseline_to_ctx(&self) {
+        let metadata = self.metadata.read();
+        if let Some(plan_fingerprint) = metadata.plan_fingerprint() {
+            let plan_baseline = metadata
+                .plan_baseline()
+                .map(|state| state.usage.to_string())
+                .unwrap_or_default();
+            self.ctx
+                .attach_plan_baseline(plan_fingerprint.clone(), plan_baseline);
+        }
+    }
+
     /// Add pipelines for writing query result cache.
     fn add_result_cache(
         &self,
@@ -278,6 +317,7 @@ impl Interpreter for SelectInterpreter {
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         self.attach_tables_to_ctx();
+        self.attach_plan_baseline_to_ctx();
 
         self.ctx.set_status_info("preparing plan");
 
@@ -15,10 +15,12 @@
 use std::sync::Arc;
 
 use chrono::Utc;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::schema::CreateSequenceReq;
 use databend_common_sql::plans::CreateSequencePlan;
 use databend_common_storages_fuse::TableContext;
+use databend_storages_common_table_meta::table::is_identity_sequence;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -47,11 +49,19 @@ impl Interpreter for CreateSequenceInterpreter {
 
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
+        if is_identity_sequence(self.plan.ident.name()) {
+            return Err(ErrorCode::BadArguments(format!(
+                "sequence name {} is reserved for identity columns",
+                self.plan.ident.name()
+            )));
+        }
         let req = CreateSequenceReq {
             create_option: self.plan.create_option,
             ident: self.plan.ident.clone(),
             comment: self.plan.comment.clone(),
             create_on: Utc::now(),
+            start: 1,
+            step: 1,
         };
         let catalog = self.ctx.get_default_catalog()?;
         let _reply = catalog.create_sequence(req).await?;
@@ -19,6 +19,7 @@ use databend_common_exception::Result;
 use databend_common_meta_app::schema::DropSequenceReq;
 use databend_common_sql::plans::DropSequencePlan;
 use databend_common_storages_fuse::TableContext;
+use databend_storages_common_table_meta::table::is_identity_sequence;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -47,6 +48,12 @@ impl Interpreter for DropSequenceInterpreter {
 
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
+        if is_identity_sequence(self.plan.ident.name()) {
+            return Err(ErrorCode::BadArguments(format!(
+                "sequence {} is owned by an identity column, it is dropped along with the column",
+                self.plan.ident.name()
+            )));
+        }
         let req = DropSequenceReq {
             ident: self.plan.ident.clone(),
             if_exists: self.plan.if_exists,
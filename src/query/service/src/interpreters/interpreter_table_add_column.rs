@@ -36,9 +36,14 @@ use databend_common_storages_stream::stream_table::STREAM_ENGINE;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::meta::Versioned;
+use databend_storages_common_table_meta::table::encode_identity_columns;
+use databend_storages_common_table_meta::table::parse_identity_columns;
+use databend_storages_common_table_meta::table::IdentityColumn;
+use databend_storages_common_table_meta::table::OPT_KEY_IDENTITY_COLUMNS;
 use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use log::info;
 
+use crate::interpreters::common::create_identity_sequences;
 use crate::interpreters::interpreter_table_create::is_valid_column;
 use crate::interpreters::interpreter_table_modify_column::build_select_insert_plan;
 use crate::interpreters::Interpreter;
@@ -113,6 +118,36 @@ impl Interpreter for AddTableColumnInterpreter {
             .meta
             .add_column(&field, &self.plan.comment, index)?;
 
+        if let Some(identity) = &self.plan.identity {
+            // the existing rows would have no value allocated from the sequence
+            let fuse_table = FuseTable::try_from_table(tbl.as_ref())?;
+            if let Some(snapshot) = fuse_table.read_table_snapshot().await? {
+                if snapshot.summary.row_count > 0 {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "can not add identity column `{}` to table {}.{} which is not empty",
+                        field.name(),
+                        &self.plan.database,
+                        &self.plan.table
+                    )));
+                }
+            }
+
+            let mut identity_columns = parse_identity_columns(&table_info.meta.options)?;
+            identity_columns.push(IdentityColumn {
+                column_id: table_info
+                    .meta
+                    .schema
+                    .field_with_name(field.name())?
+                    .column_id(),
+                ..identity.clone()
+            });
+            table_info.meta.options.insert(
+                OPT_KEY_IDENTITY_COLUMNS.to_owned(),
+                encode_identity_columns(&identity_columns)?,
+            );
+            create_identity_sequences(self.ctx.as_ref(), &mut table_info.meta.options).await?;
+        }
+
         // if the new column is a stored computed field,
         // need rebuild the table to generate stored computed column.
         if let Some(ComputedExpr::Stored(_)) = field.computed_expr {
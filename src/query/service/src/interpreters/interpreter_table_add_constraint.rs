@@ -0,0 +1,112 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table::TableExt;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::UpdateTableMetaReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::plans::AddTableConstraintPlan;
+
+use crate::interpreters::common::validate_constraint_rows;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct AddTableConstraintInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AddTableConstraintPlan,
+}
+
+impl AddTableConstraintInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AddTableConstraintPlan) -> Result<Self> {
+        Ok(AddTableConstraintInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AddTableConstraintInterpreter {
+    fn name(&self) -> &str {
+        "AddTableConstraintInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let table = catalog
+            .get_table(
+                &self.ctx.get_tenant(),
+                &self.plan.database,
+                &self.plan.table,
+            )
+            .await?;
+
+        // check mutability
+        table.check_mutable()?;
+
+        let table_info = table.get_table_info();
+        if table_info.engine() != "FUSE" {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} engine is {} that doesn't support constraints",
+                &self.plan.database,
+                &self.plan.table,
+                table_info.engine()
+            )));
+        }
+        if table_info
+            .meta
+            .constraints
+            .contains_key(&self.plan.constraint_name)
+        {
+            return Err(ErrorCode::ConstraintAlreadyExists(format!(
+                "constraint `{}` already exists in table `{}`",
+                self.plan.constraint_name, self.plan.table
+            )));
+        }
+
+        // the key constraints are not enforced, they are checked by `VALIDATE CONSTRAINT`
+        if self.plan.constraint.is_enforced() {
+            validate_constraint_rows(
+                &self.ctx,
+                &self.plan.catalog,
+                &self.plan.database,
+                &self.plan.table,
+                &self.plan.constraint_name,
+                &self.plan.constraint,
+            )
+            .await?;
+        }
+
+        let mut new_table_meta = table_info.meta.clone();
+        new_table_meta.constraints.insert(
+            self.plan.constraint_name.clone(),
+            self.plan.constraint.clone(),
+        );
+        let req = UpdateTableMetaReq {
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            new_table_meta,
+        };
+        catalog.update_single_table_meta(req, table_info).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -17,6 +17,7 @@ use std::sync::Arc;
 
 use chrono::Utc;
 use databend_common_catalog::table::TableExt;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_sql::executor::physical_plans::AggregateExpand;
@@ -49,6 +50,9 @@ use crate::schedulers::build_query_pipeline_without_render_result_set;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
 
+/// Number of the most common values kept in the joint statistics of a column group.
+const MULTI_COLUMN_MCV_SIZE: usize = 100;
+
 pub struct AnalyzeTableInterpreter {
     ctx: Arc<QueryContext>,
     plan: AnalyzeTablePlan,
@@ -252,6 +256,102 @@ impl Interpreter for AnalyzeTableInterpreter {
                     histogram_info_receivers.insert(col_id, rx);
                 }
             }
+
+            // Joint statistics of the column groups are always refreshed, together with
+            // the group given by `COMPUTE STATISTICS ON (...)`.
+            let schema = table.schema();
+            let mut column_groups = table_statistics
+                .as_ref()
+                .map(|s| {
+                    s.multi_column_stats
+                        .iter()
+                        .map(|stat| stat.column_ids.clone())
+                        .filter(|column_ids| {
+                            column_ids
+                                .iter()
+                                .all(|id| schema.fields().iter().any(|f| f.column_id() == *id))
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
+            if !plan.columns.is_empty() {
+                let mut column_ids = Vec::with_capacity(plan.columns.len());
+                for name in plan.columns.iter() {
+                    let field = schema
+                        .fields()
+                        .iter()
+                        .find(|f| &f.name == name)
+                        .ok_or_else(|| {
+                            ErrorCode::UnknownColumn(format!("Unknown column '{name}'"))
+                        })?;
+                    if !RangeIndex::supported_type(&field.data_type().into()) {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "Column '{name}' of type {} does not support statistics",
+                            field.data_type()
+                        )));
+                    }
+                    column_ids.push(field.column_id());
+                }
+                column_ids.sort();
+                if !column_groups.contains(&column_ids) {
+                    column_groups.push(column_ids);
+                }
+            }
+
+            let mut multi_column_receivers = Vec::with_capacity(column_groups.len());
+            for column_ids in column_groups.into_iter() {
+                let col_names = column_ids
+                    .iter()
+                    .map(|id| {
+                        let field = schema
+                            .fields()
+                            .iter()
+                            .find(|f| f.column_id() == *id)
+                            .unwrap();
+                        format!("{quote}{}{quote}", field.name)
+                    })
+                    .join(", ");
+                let sql = format!(
+                    "SELECT COUNT() OVER () AS ndv, SUM(cnt) OVER () AS total, cnt, {col_names} \
+                    FROM ( \
+                        SELECT {col_names}, COUNT() AS cnt \
+                        FROM {}.{} AT (snapshot => '{}') \
+                        GROUP BY {col_names} \
+                    ) \
+                    ORDER BY cnt DESC LIMIT {MULTI_COLUMN_MCV_SIZE}",
+                    plan.database,
+                    plan.table,
+                    snapshot.snapshot_id.simple(),
+                );
+                info!("Analyze multi-column statistics via sql: {sql}");
+                let (mut stats_plan, bind_context) = self.plan_sql(sql).await?;
+                if !self.ctx.get_cluster().is_empty() {
+                    stats_plan = remove_exchange(stats_plan);
+                }
+                let mut stats_build_res = build_query_pipeline(
+                    &QueryContext::create_from(self.ctx.as_ref()),
+                    &bind_context.columns,
+                    &stats_plan,
+                    false,
+                )
+                .await?;
+                let (tx, rx) = async_channel::unbounded();
+                stats_build_res.main_pipeline.add_sink(|input_port| {
+                    Ok(ProcessorPtr::create(HistogramInfoSink::create(
+                        Some(tx.clone()),
+                        input_port.clone(),
+                    )))
+                })?;
+
+                build_res
+                    .sources_pipelines
+                    .push(stats_build_res.main_pipeline.finalize());
+                build_res
+                    .sources_pipelines
+                    .extend(stats_build_res.sources_pipelines);
+                multi_column_receivers.push((column_ids, rx));
+            }
+
             FuseTable::do_analyze(
                 self.ctx.clone(),
                 bind_context.output_schema(),
@@ -261,6 +361,7 @@ impl Interpreter for AnalyzeTableInterpreter {
                 snapshot.snapshot_id,
                 &mut build_res.main_pipeline,
                 histogram_info_receivers,
+                multi_column_receivers,
             )?;
             return Ok(build_res);
         }
@@ -0,0 +1,155 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::Partitions;
+use databend_common_exception::Result;
+use databend_common_sql::plans::CacheWarmupTablePlan;
+use databend_common_storages_fuse::FuseTable;
+use log::info;
+
+use crate::clusters::ClusterHelper;
+use crate::clusters::FlightParams;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::servers::flight::v1::actions::CacheWarmupRequest;
+use crate::servers::flight::v1::actions::CACHE_WARMUP;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Warms up (or unpins) the caches of a table on every node of the warehouse. The coordinator
+/// prunes the blocks and sends each node the share of the partitions it reads in queries.
+pub struct CacheWarmupTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CacheWarmupTablePlan,
+
+    /// The partitions to warm up on this node, set when the request comes from the coordinator.
+    parts: Option<Partitions>,
+}
+
+impl CacheWarmupTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CacheWarmupTablePlan) -> Result<Self> {
+        Ok(CacheWarmupTableInterpreter {
+            ctx,
+            plan,
+            parts: None,
+        })
+    }
+
+    pub fn from_flight(
+        ctx: Arc<QueryContext>,
+        plan: CacheWarmupTablePlan,
+        parts: Partitions,
+    ) -> Result<Self> {
+        Ok(CacheWarmupTableInterpreter {
+            ctx,
+            plan,
+            parts: Some(parts),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CacheWarmupTableInterpreter {
+    fn name(&self) -> &str {
+        "CacheWarmupTableInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let table = self
+            .ctx
+            .get_table(&self.plan.catalog, &self.plan.database, &self.plan.table)
+            .await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let columns = self.plan.columns.as_deref();
+
+        let local_parts = match &self.parts {
+            Some(parts) => parts.clone(),
+            None => {
+                let parts = match self.plan.unpin {
+                    true => Partitions::default(),
+                    false => {
+                        fuse_table
+                            .cache_warmup_partitions(
+                                self.ctx.clone(),
+                                columns,
+                                self.plan.filters.clone(),
+                            )
+                            .await?
+                    }
+                };
+                self.broadcast_to_warehouse(parts).await?
+            }
+        };
+
+        if self.plan.unpin {
+            let unpinned = fuse_table.unpin_cache();
+            info!(
+                "unpinned {} cached items of table {}.{}",
+                unpinned, self.plan.database, self.plan.table
+            );
+        } else {
+            fuse_table
+                .warm_up_cache(&local_parts, columns, self.plan.pin)
+                .await?;
+        }
+        Ok(PipelineBuildResult::create())
+    }
+}
+
+impl CacheWarmupTableInterpreter {
+    /// Sends the other nodes their shares of `parts`, returns the share of this node.
+    async fn broadcast_to_warehouse(&self, parts: Partitions) -> Result<Partitions> {
+        let warehouse = self.ctx.get_warehouse_cluster().await?;
+        let executors = warehouse
+            .nodes
+            .iter()
+            .map(|node| node.id.clone())
+            .collect::<Vec<_>>();
+        let mut shares = parts.reshuffle(executors)?;
+
+        let mut message = HashMap::with_capacity(warehouse.nodes.len());
+        for node_info in &warehouse.nodes {
+            if node_info.id != warehouse.local_id {
+                let request = CacheWarmupRequest {
+                    plan: self.plan.clone(),
+                    parts: shares.remove(&node_info.id).unwrap_or_default(),
+                };
+                message.insert(node_info.id.clone(), request);
+            }
+        }
+
+        if !message.is_empty() {
+            let settings = self.ctx.get_settings();
+            let flight_params = FlightParams {
+                timeout: settings.get_flight_client_timeout()?,
+                retry_times: settings.get_flight_max_retry_times()?,
+                retry_interval: settings.get_flight_retry_interval()?,
+            };
+            warehouse
+                .do_action::<_, ()>(CACHE_WARMUP, message, flight_params)
+                .await?;
+        }
+
+        Ok(shares.remove(&warehouse.local_id).unwrap_or_default())
+    }
+}
@@ -30,6 +30,7 @@ use databend_common_management::RoleApi;
 use databend_common_meta_app::principal::OwnershipObject;
 use databend_common_meta_app::schema::CommitTableMetaReq;
 use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::CreateTableReply;
 use databend_common_meta_app::schema::CreateTableReq;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
@@ -40,9 +41,11 @@ use databend_common_meta_types::MatchSeq;
 use databend_common_pipeline_core::always_callback;
 use databend_common_pipeline_core::ExecutionInfo;
 use databend_common_sql::field_default_value;
+use databend_common_sql::plans::CloneTableSource;
 use databend_common_sql::plans::CreateTablePlan;
 use databend_common_storages_fuse::io::MetaReaders;
 use databend_common_storages_fuse::FuseStorageFormat;
+use databend_common_storages_fuse::FuseTable;
 use databend_common_users::RoleCacheManager;
 use databend_common_users::UserApiProvider;
 use databend_enterprise_attach_table::get_attach_table_handler;
@@ -50,6 +53,7 @@ use databend_storages_common_cache::LoadParams;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::meta::Versioned;
 use databend_storages_common_table_meta::table::OPT_KEY_COMMENT;
+use databend_storages_common_table_meta::table::OPT_KEY_IDENTITY_COLUMNS;
 use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
@@ -57,13 +61,18 @@ use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
 use log::error;
 use log::info;
 
+use crate::interpreters::common::attach_query_lineage;
+use crate::interpreters::common::copy_identity_sequences;
+use crate::interpreters::common::create_identity_sequences;
 use crate::interpreters::common::table_option_validation::is_valid_block_per_segment;
 use crate::interpreters::common::table_option_validation::is_valid_bloom_index_columns;
 use crate::interpreters::common::table_option_validation::is_valid_change_tracking;
 use crate::interpreters::common::table_option_validation::is_valid_create_opt;
 use crate::interpreters::common::table_option_validation::is_valid_data_retention_period;
+use crate::interpreters::common::table_option_validation::is_valid_deletion_vector;
 use crate::interpreters::common::table_option_validation::is_valid_random_seed;
 use crate::interpreters::common::table_option_validation::is_valid_row_per_block;
+use crate::interpreters::common::table_option_validation::is_valid_ttl;
 use crate::interpreters::hook::vacuum_hook::hook_clear_m_cte_temp_table;
 use crate::interpreters::hook::vacuum_hook::hook_disk_temp_dir;
 use crate::interpreters::hook::vacuum_hook::hook_vacuum_temp_files;
@@ -175,6 +184,7 @@ impl CreateTableInterpreter {
         let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
 
         let mut req = self.build_request(None)?;
+        create_identity_sequences(self.ctx.as_ref(), &mut req.table_meta.options).await?;
 
         // create a dropped table first.
         req.as_dropped = true;
@@ -238,6 +248,7 @@ impl CreateTableInterpreter {
             source: InsertInputSource::SelectPlan(select_plan),
             table_info: Some(table_info),
         };
+        attach_query_lineage(&self.ctx, &Plan::Insert(Box::new(insert_plan.clone()))).await;
 
         let mut pipeline = InsertInterpreter::try_create(self.ctx.clone(), insert_plan)?
             .execute2()
@@ -304,6 +315,10 @@ impl CreateTableInterpreter {
 
     #[async_backtrace::framed]
     async fn create_table(&self) -> Result<PipelineBuildResult> {
+        if let Some(clone_from) = &self.plan.clone_from {
+            return self.create_table_clone(clone_from).await;
+        }
+
         let catalog = self.ctx.get_catalog(self.plan.catalog.as_str()).await?;
         let mut stat = None;
         if !GlobalConfig::instance().query.management_mode {
@@ -331,11 +346,12 @@ impl CreateTableInterpreter {
                 });
             }
         }
-        let req = if let Some(storage_prefix) = self.plan.options.get(OPT_KEY_STORAGE_PREFIX) {
+        let mut req = if let Some(storage_prefix) = self.plan.options.get(OPT_KEY_STORAGE_PREFIX) {
             self.build_attach_request(storage_prefix).await
         } else {
             self.build_request(stat)
         }?;
+        create_identity_sequences(self.ctx.as_ref(), &mut req.table_meta.options).await?;
 
         let reply = catalog.create_table(req.clone()).await?;
 
@@ -364,11 +380,115 @@ impl CreateTableInterpreter {
         Ok(PipelineBuildResult::create())
     }
 
+    /// Creates the table as a clone sharing the snapshot of the source table.
+    ///
+    /// Like CTAS, a dropped table is created first, and it is made visible only after it shares
+    /// the snapshot of the source table. If cloning fails, the dropped table is left for vacuum.
+    #[async_backtrace::framed]
+    async fn create_table_clone(
+        &self,
+        clone_from: &CloneTableSource,
+    ) -> Result<PipelineBuildResult> {
+        let mut req = self.build_request(None)?;
+        // the clone must not allocate values from the sequences of the source table
+        copy_identity_sequences(self.ctx.as_ref(), &mut req.table_meta.options).await?;
+
+        let Some((table_info, reply)) = self.create_dropped_table(req).await? else {
+            return Ok(PipelineBuildResult::create());
+        };
+        let catalog = self.ctx.get_catalog(self.plan.catalog.as_str()).await?;
+        let target = catalog.get_table_by_info(&table_info)?;
+        let target = FuseTable::try_from_table(target.as_ref())?;
+        let source = FuseTable::try_create(clone_from.table_info.clone())?;
+        let source = FuseTable::try_from_table(source.as_ref())?;
+        source.do_clone_to(self.ctx.clone(), target).await?;
+
+        info!(
+            "create table {}.{} clone success, commit table meta data by table id {}",
+            self.plan.database, self.plan.table, reply.table_id
+        );
+        self.commit_dropped_table(reply).await?;
+        Ok(PipelineBuildResult::create())
+    }
+
+    /// Creates the table of the request as a dropped table, it is made visible by
+    /// `commit_dropped_table` once its data is ready.
+    ///
+    /// Returns `None` if the table already exists and is not replaced.
+    #[async_backtrace::framed]
+    pub(crate) async fn create_dropped_table(
+        &self,
+        mut req: CreateTableReq,
+    ) -> Result<Option<(TableInfo, CreateTableReply)>> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(self.plan.catalog.as_str()).await?;
+
+        req.as_dropped = true;
+        req.table_meta.drop_on = Some(Utc::now());
+        let table_meta = req.table_meta.clone();
+        let reply = catalog.create_table(req.clone()).await?;
+        if !reply.new_table && self.plan.create_option != CreateOption::CreateOrReplace {
+            return Ok(None);
+        }
+
+        let table_id = reply.table_id;
+        let table_id_seq = reply.table_id_seq.ok_or_else(|| {
+            ErrorCode::Internal("table_id_seq must have been set when creating a dropped table")
+        })?;
+        let table_info = TableInfo::new(
+            &self.plan.database,
+            &self.plan.table,
+            TableIdent::new(table_id, table_id_seq),
+            table_meta,
+        );
+
+        if !req.table_meta.options.contains_key(OPT_KEY_TEMP_PREFIX) {
+            if let Some(current_role) = self.ctx.get_current_role() {
+                let role_api = UserApiProvider::instance().role_api(&tenant);
+                role_api
+                    .grant_ownership(
+                        &OwnershipObject::Table {
+                            catalog_name: self.plan.catalog.clone(),
+                            db_id: reply.db_id,
+                            table_id,
+                        },
+                        &current_role.name,
+                    )
+                    .await?;
+                RoleCacheManager::instance().invalidate_cache(&tenant);
+            }
+        }
+        Ok(Some((table_info, reply)))
+    }
+
+    /// Makes a table created by `create_dropped_table` visible.
+    #[async_backtrace::framed]
+    pub(crate) async fn commit_dropped_table(&self, reply: CreateTableReply) -> Result<()> {
+        let catalog = self.ctx.get_catalog(self.plan.catalog.as_str()).await?;
+        catalog
+            .commit_table_meta(CommitTableMetaReq {
+                name_ident: TableNameIdent {
+                    tenant: self.ctx.get_tenant(),
+                    db_name: self.plan.database.clone(),
+                    table_name: self.plan.table.clone(),
+                },
+                db_id: reply.db_id,
+                table_id: reply.table_id,
+                prev_table_id: reply.prev_table_id,
+                orphan_table_name: reply.orphan_table_name,
+            })
+            .await?;
+        Ok(())
+    }
+
     /// Build CreateTableReq from CreateTablePlanV2.
     ///
     /// - Rebuild `DataSchema` with default exprs.
     /// - Update cluster key of table meta.
-    fn build_request(&self, statistics: Option<TableStatistics>) -> Result<CreateTableReq> {
+    pub(crate) fn build_request(
+        &self,
+        statistics: Option<TableStatistics>,
+    ) -> Result<CreateTableReq> {
         let fields = self.plan.schema.fields().clone();
         for field in fields.iter() {
             if field.default_expr().is_some() {
@@ -400,14 +520,20 @@ impl CreateTableInterpreter {
             statistics: statistics.unwrap_or_default(),
             comment: comment.unwrap_or_default(),
             indexes: self.plan.inverted_indexes.clone().unwrap_or_default(),
+            constraints: self.plan.constraints.clone(),
             ..Default::default()
         };
 
         is_valid_block_per_segment(&table_meta.options)?;
         is_valid_row_per_block(&table_meta.options)?;
         // check bloom_index_columns.
-        is_valid_bloom_index_columns(&table_meta.options, schema)?;
+        is_valid_bloom_index_columns(&table_meta.options, schema.clone())?;
+        is_valid_ttl(self.ctx.clone(), &table_meta.options, schema)?;
         is_valid_change_tracking(&table_meta.options)?;
+        is_valid_deletion_vector(
+            &table_meta.options,
+            table_meta.options.get(OPT_KEY_STORAGE_FORMAT),
+        )?;
         // check random seed
         is_valid_random_seed(&table_meta.options)?;
         // check table level data_retention_period_in_hours
@@ -415,7 +541,8 @@ impl CreateTableInterpreter {
 
         for table_option in table_meta.options.iter() {
             let key = table_option.0.to_lowercase();
-            if !is_valid_create_opt(&key, &self.plan.engine) {
+            // the identity columns are set by the binder, which rejects them as a user option
+            if !is_valid_create_opt(&key, &self.plan.engine) && key != OPT_KEY_IDENTITY_COLUMNS {
                 error!("invalid opt for fuse table in create table statement");
                 return Err(ErrorCode::TableOptionInvalid(format!(
                     "table option {key} is invalid for create table statement",
@@ -133,7 +133,10 @@ impl Interpreter for DropTableInterpreter {
             })
             .await?;
 
-        if !is_temp {
+        // in an explicit transaction the table is dropped when the transaction commits,
+        // the ownership is kept in case the transaction is rolled back.
+        let in_txn = self.ctx.txn_mgr().lock().is_active();
+        if !is_temp && !in_txn {
             // we should do `drop ownership` after actually drop table, otherwise when we drop the ownership,
             // but the table still exists, in the interval maybe some unexpected things will happen.
             // drop the ownership
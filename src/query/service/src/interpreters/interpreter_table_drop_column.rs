@@ -25,9 +25,15 @@ use databend_common_sql::plans::DropTableColumnPlan;
 use databend_common_sql::BloomIndexColumns;
 use databend_common_storages_stream::stream_table::STREAM_ENGINE;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
+use databend_storages_common_table_meta::table::encode_identity_columns;
+use databend_storages_common_table_meta::table::parse_identity_columns;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
+use databend_storages_common_table_meta::table::OPT_KEY_IDENTITY_COLUMNS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
+use crate::interpreters::common::check_referenced_constraints;
+use crate::interpreters::common::check_referenced_ttl;
+use crate::interpreters::common::drop_identity_sequences;
 use crate::interpreters::interpreter_table_add_column::generate_new_snapshot;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -90,10 +96,23 @@ impl Interpreter for DropTableColumnInterpreter {
         if field.computed_expr().is_none() {
             let mut schema: DataSchema = table_info.schema().into();
             schema.drop_column(self.plan.column.as_str())?;
+            let schema = Arc::new(schema);
             // Check if this column is referenced by computed columns.
             check_referenced_computed_columns(
                 self.ctx.clone(),
-                Arc::new(schema),
+                schema.clone(),
+                self.plan.column.as_str(),
+            )?;
+            check_referenced_constraints(
+                self.ctx.clone(),
+                &table_info.meta.constraints,
+                schema.clone(),
+                self.plan.column.as_str(),
+            )?;
+            check_referenced_ttl(
+                self.ctx.clone(),
+                table_info.options(),
+                schema,
                 self.plan.column.as_str(),
             )?;
         }
@@ -126,6 +145,21 @@ impl Interpreter for DropTableColumnInterpreter {
             }
         }
 
+        // the sequence of an identity column is dropped along with the column
+        let (dropped_identity, identity_columns): (Vec<_>, Vec<_>) = parse_identity_columns(opts)?
+            .into_iter()
+            .partition(|column| column.column_id == field.column_id);
+        if !dropped_identity.is_empty() {
+            if identity_columns.is_empty() {
+                opts.remove(OPT_KEY_IDENTITY_COLUMNS);
+            } else {
+                opts.insert(
+                    OPT_KEY_IDENTITY_COLUMNS.to_owned(),
+                    encode_identity_columns(&identity_columns)?,
+                );
+            }
+        }
+
         let table_id = table_info.ident.table_id;
         let table_version = table_info.ident.seq;
 
@@ -138,6 +172,7 @@ impl Interpreter for DropTableColumnInterpreter {
         };
 
         let _resp = catalog.update_single_table_meta(req, table_info).await?;
+        drop_identity_sequences(self.ctx.as_ref(), &dropped_identity).await?;
 
         Ok(PipelineBuildResult::create())
     }
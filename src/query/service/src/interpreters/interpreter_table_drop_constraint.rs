@@ -0,0 +1,124 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::catalog::Catalog;
+use databend_common_catalog::table::TableExt;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::TableConstraint;
+use databend_common_meta_app::schema::UpdateTableMetaReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::plans::DropTableConstraintPlan;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct DropTableConstraintInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropTableConstraintPlan,
+}
+
+impl DropTableConstraintInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropTableConstraintPlan) -> Result<Self> {
+        Ok(DropTableConstraintInterpreter { ctx, plan })
+    }
+}
+
+impl DropTableConstraintInterpreter {
+    /// Rejects dropping the primary or unique key `key` if a foreign key references it.
+    #[async_backtrace::framed]
+    async fn check_referenced_key(&self, catalog: &dyn Catalog, key: &[String]) -> Result<()> {
+        let tenant = self.ctx.get_tenant();
+        for database in catalog.list_databases(&tenant).await? {
+            for table in database.list_tables().await? {
+                for (name, constraint) in &table.get_table_info().meta.constraints {
+                    let TableConstraint::ForeignKey {
+                        ref_database,
+                        ref_table,
+                        ref_columns,
+                        ..
+                    } = constraint
+                    else {
+                        continue;
+                    };
+                    if ref_database == &self.plan.database
+                        && ref_table == &self.plan.table
+                        && ref_columns.len() == key.len()
+                        && ref_columns.iter().all(|column| key.contains(column))
+                    {
+                        return Err(ErrorCode::ColumnReferencedByConstraint(format!(
+                            "constraint `{}` is referenced by foreign key `{}` of table `{}`.`{}`, drop the foreign key first",
+                            self.plan.constraint_name,
+                            name,
+                            database.name(),
+                            table.name()
+                        )));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropTableConstraintInterpreter {
+    fn name(&self) -> &str {
+        "DropTableConstraintInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let table = catalog
+            .get_table(
+                &self.ctx.get_tenant(),
+                &self.plan.database,
+                &self.plan.table,
+            )
+            .await?;
+
+        // check mutability
+        table.check_mutable()?;
+
+        let table_info = table.get_table_info();
+        let mut new_table_meta = table_info.meta.clone();
+        let Some(constraint) = new_table_meta.constraints.remove(&self.plan.constraint_name)
+        else {
+            return Err(ErrorCode::UnknownConstraint(format!(
+                "constraint `{}` does not exist in table `{}`",
+                self.plan.constraint_name, self.plan.table
+            )));
+        };
+        if let TableConstraint::PrimaryKey(key) | TableConstraint::Unique(key) = &constraint {
+            self.check_referenced_key(catalog.as_ref(), key).await?;
+        }
+        let req = UpdateTableMetaReq {
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            new_table_meta,
+        };
+        catalog.update_single_table_meta(req, table_info).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -57,6 +57,7 @@ use databend_storages_common_table_meta::meta::SnapshotId;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
+use crate::interpreters::common::check_referenced_ttl;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::schedulers::build_query_pipeline_without_render_result_set;
@@ -170,10 +171,16 @@ impl ModifyTableColumnInterpreter {
                 }
                 if old_field.data_type != field.data_type {
                     // Check if this column is referenced by computed columns.
-                    let data_schema = DataSchema::from(&new_schema);
+                    let data_schema = Arc::new(DataSchema::from(&new_schema));
                     check_referenced_computed_columns(
                         self.ctx.clone(),
-                        Arc::new(data_schema),
+                        data_schema.clone(),
+                        &field.name,
+                    )?;
+                    check_referenced_ttl(
+                        self.ctx.clone(),
+                        table_info.options(),
+                        data_schema,
                         &field.name,
                     )?;
                 }
@@ -28,6 +28,8 @@ use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
+use crate::interpreters::common::check_referenced_constraints;
+use crate::interpreters::common::check_referenced_ttl;
 use crate::interpreters::interpreter_table_create::is_valid_column;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -97,10 +99,23 @@ impl Interpreter for RenameTableColumnInterpreter {
             if field.computed_expr().is_none() {
                 let index = schema.index_of(self.plan.old_column.as_str())?;
                 schema.rename_field(index, self.plan.new_column.as_str());
+                let schema = Arc::new(schema);
                 // Check if old column is referenced by computed columns.
                 check_referenced_computed_columns(
                     self.ctx.clone(),
-                    Arc::new(schema),
+                    schema.clone(),
+                    self.plan.old_column.as_str(),
+                )?;
+                check_referenced_constraints(
+                    self.ctx.clone(),
+                    &table_info.meta.constraints,
+                    schema.clone(),
+                    self.plan.old_column.as_str(),
+                )?;
+                check_referenced_ttl(
+                    self.ctx.clone(),
+                    table_info.options(),
+                    schema,
                     self.plan.old_column.as_str(),
                 )?;
             }
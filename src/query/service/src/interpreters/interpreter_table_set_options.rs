@@ -35,7 +35,9 @@ use crate::interpreters::common::table_option_validation::is_valid_block_per_seg
 use crate::interpreters::common::table_option_validation::is_valid_bloom_index_columns;
 use crate::interpreters::common::table_option_validation::is_valid_create_opt;
 use crate::interpreters::common::table_option_validation::is_valid_data_retention_period;
+use crate::interpreters::common::table_option_validation::is_valid_deletion_vector;
 use crate::interpreters::common::table_option_validation::is_valid_row_per_block;
+use crate::interpreters::common::table_option_validation::is_valid_ttl;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -139,6 +141,13 @@ impl Interpreter for SetOptionsInterpreter {
 
         // check bloom_index_columns.
         is_valid_bloom_index_columns(&self.plan.set_options, table.schema())?;
+        // check ttl.
+        is_valid_ttl(self.ctx.clone(), &self.plan.set_options, table.schema())?;
+        // check enable_deletion_vector.
+        is_valid_deletion_vector(
+            &self.plan.set_options,
+            table.options().get(OPT_KEY_STORAGE_FORMAT),
+        )?;
 
         let req = UpsertTableOptionReq {
             table_id: table.get_id(),
@@ -26,12 +26,14 @@ use databend_common_expression::ComputedExpr;
 use databend_common_expression::DataBlock;
 use databend_common_expression::Scalar;
 use databend_common_expression::Value;
+use databend_common_meta_app::schema::TableConstraint;
 use databend_common_sql::plans::ShowCreateTablePlan;
 use databend_common_storages_stream::stream_table::StreamTable;
 use databend_common_storages_stream::stream_table::STREAM_ENGINE;
 use databend_common_storages_view::view_table::QUERY;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_storages_common_table_meta::table::is_internal_opt_key;
+use databend_storages_common_table_meta::table::parse_identity_columns;
 use databend_storages_common_table_meta::table::StreamMode;
 use databend_storages_common_table_meta::table::OPT_KEY_CLUSTER_TYPE;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
@@ -167,6 +169,7 @@ impl ShowCreateTableInterpreter {
 
         // Append columns and indexes.
         {
+            let identity_columns = parse_identity_columns(table.options())?;
             let mut create_defs = vec![];
             for (idx, field) in schema.fields().iter().enumerate() {
                 let default_expr = match field.default_expr() {
@@ -175,6 +178,20 @@ impl ShowCreateTableInterpreter {
                     }
                     None => "".to_string(),
                 };
+                let identity = match identity_columns
+                    .iter()
+                    .find(|column| column.column_id == field.column_id())
+                {
+                    Some(column) if column.always => format!(
+                        " GENERATED ALWAYS AS IDENTITY START {} INCREMENT {}",
+                        column.start, column.increment
+                    ),
+                    Some(column) => format!(
+                        " AUTOINCREMENT START {} INCREMENT {}",
+                        column.start, column.increment
+                    ),
+                    None => "".to_string(),
+                };
                 let computed_expr = match field.computed_expr() {
                     Some(ComputedExpr::Virtual(expr)) => {
                         format!(" AS ({expr}) VIRTUAL")
@@ -198,8 +215,9 @@ impl ShowCreateTableInterpreter {
                 };
                 let ident = display_ident(field.name(), quoted_ident_case_sensitive, sql_dialect);
                 let data_type = field.data_type().sql_name_explicit_null();
-                let column_str =
-                    format!("  {ident} {data_type}{default_expr}{computed_expr}{comment}");
+                let column_str = format!(
+                    "  {ident} {data_type}{default_expr}{identity}{computed_expr}{comment}"
+                );
 
                 create_defs.push(column_str);
             }
@@ -235,6 +253,42 @@ impl ShowCreateTableInterpreter {
                 create_defs.push(index_str);
             }
 
+            let display_columns = |columns: &[String]| {
+                columns
+                    .iter()
+                    .map(|c| display_ident(c, quoted_ident_case_sensitive, sql_dialect))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            for (name, constraint) in table_info.meta.constraints.iter() {
+                let constraint = match constraint {
+                    TableConstraint::Check(_) => constraint.to_string(),
+                    TableConstraint::PrimaryKey(columns) => {
+                        format!("PRIMARY KEY ({}) NOT ENFORCED", display_columns(columns))
+                    }
+                    TableConstraint::Unique(columns) => {
+                        format!("UNIQUE ({}) NOT ENFORCED", display_columns(columns))
+                    }
+                    TableConstraint::ForeignKey {
+                        columns,
+                        ref_database,
+                        ref_table,
+                        ref_columns,
+                    } => format!(
+                        "FOREIGN KEY ({}) REFERENCES {}.{} ({}) NOT ENFORCED",
+                        display_columns(columns),
+                        display_ident(ref_database, quoted_ident_case_sensitive, sql_dialect),
+                        display_ident(ref_table, quoted_ident_case_sensitive, sql_dialect),
+                        display_columns(ref_columns)
+                    ),
+                };
+                create_defs.push(format!(
+                    "  CONSTRAINT {} {}",
+                    display_ident(name, quoted_ident_case_sensitive, sql_dialect),
+                    constraint
+                ));
+            }
+
             // Format is:
             //  (
             //      x,
@@ -303,8 +357,13 @@ impl ShowCreateTableInterpreter {
         let mode = stream_table.mode();
 
         let mut create_sql = format!(
-            "CREATE STREAM `{}` ON TABLE `{}`.`{}`",
+            "CREATE STREAM `{}` ON {} `{}`.`{}`",
             stream_table.name(),
+            if stream_table.is_view_stream() {
+                "VIEW"
+            } else {
+                "TABLE"
+            },
             source_database_name,
             source_table_name
         );
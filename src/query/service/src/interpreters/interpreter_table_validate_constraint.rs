@@ -0,0 +1,82 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_sql::plans::ValidateTableConstraintPlan;
+
+use crate::interpreters::common::validate_constraint_rows;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct ValidateTableConstraintInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ValidateTableConstraintPlan,
+}
+
+impl ValidateTableConstraintInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: ValidateTableConstraintPlan) -> Result<Self> {
+        Ok(ValidateTableConstraintInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ValidateTableConstraintInterpreter {
+    fn name(&self) -> &str {
+        "ValidateTableConstraintInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        false
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let table = catalog
+            .get_table(
+                &self.ctx.get_tenant(),
+                &self.plan.database,
+                &self.plan.table,
+            )
+            .await?;
+
+        let constraint = table
+            .get_table_info()
+            .meta
+            .constraints
+            .get(&self.plan.constraint_name)
+            .ok_or_else(|| {
+                ErrorCode::UnknownConstraint(format!(
+                    "constraint `{}` does not exist in table `{}`",
+                    self.plan.constraint_name, self.plan.table
+                ))
+            })?;
+        validate_constraint_rows(
+            &self.ctx,
+            &self.plan.catalog,
+            &self.plan.database,
+            &self.plan.table,
+            &self.plan.constraint_name,
+            constraint,
+        )
+        .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -27,6 +27,7 @@ use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_sql::plans::AlterTaskPlan;
+use databend_common_users::UserApiProvider;
 
 use crate::interpreters::common::get_task_client_config;
 use crate::interpreters::common::make_schedule_options;
@@ -153,6 +154,11 @@ impl Interpreter for AlterTaskInterpreter {
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let config = GlobalConfig::instance();
+        if config.query.embedded_task_scheduler() {
+            let task_mgr = UserApiProvider::instance().task_api(&self.plan.tenant)?;
+            task_mgr.alter_task(self.build_request()).await?;
+            return Ok(PipelineBuildResult::create());
+        }
         if config.query.cloud_control_grpc_server_address.is_none() {
             return Err(ErrorCode::CloudControlNotEnabled(
                 "cannot alter task without cloud control enabled, please set cloud_control_grpc_server_address in config",
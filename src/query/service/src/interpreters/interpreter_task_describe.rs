@@ -22,6 +22,7 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_sql::plans::DescribeTaskPlan;
 use databend_common_storages_system::parse_tasks_to_datablock;
+use databend_common_users::UserApiProvider;
 
 use crate::interpreters::common::get_task_client_config;
 use crate::interpreters::Interpreter;
@@ -65,6 +66,15 @@ impl Interpreter for DescribeTaskInterpreter {
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let config = GlobalConfig::instance();
+        if config.query.embedded_task_scheduler() {
+            let task_mgr = UserApiProvider::instance().task_api(&self.plan.tenant)?;
+            let resp = task_mgr.describe_task(self.build_request()).await?;
+            let Some(task) = resp.task else {
+                return Ok(PipelineBuildResult::create());
+            };
+            let result = parse_tasks_to_datablock(vec![task])?;
+            return PipelineBuildResult::from_blocks(vec![result]);
+        }
         if config.query.cloud_control_grpc_server_address.is_none() {
             return Err(ErrorCode::CloudControlNotEnabled(
                 "cannot describe task without cloud control enabled, please set cloud_control_grpc_server_address in config",
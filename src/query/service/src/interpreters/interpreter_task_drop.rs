@@ -21,6 +21,7 @@ use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_sql::plans::DropTaskPlan;
+use databend_common_users::UserApiProvider;
 
 use crate::interpreters::common::get_task_client_config;
 use crate::interpreters::Interpreter;
@@ -64,6 +65,11 @@ impl Interpreter for DropTaskInterpreter {
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let config = GlobalConfig::instance();
+        if config.query.embedded_task_scheduler() {
+            let task_mgr = UserApiProvider::instance().task_api(&self.plan.tenant)?;
+            task_mgr.drop_task(self.build_request()).await?;
+            return Ok(PipelineBuildResult::create());
+        }
         if config.query.cloud_control_grpc_server_address.is_none() {
             return Err(ErrorCode::CloudControlNotEnabled(
                 "cannot drop task without cloud control enabled, please set cloud_control_grpc_server_address in config",
@@ -14,6 +14,9 @@
 
 use std::sync::Arc;
 
+use chrono::Utc;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
 use databend_common_cloud_control::client_config::make_request;
 use databend_common_cloud_control::cloud_api::CloudControlApiProvider;
 use databend_common_cloud_control::pb::ExecuteTaskRequest;
@@ -21,11 +24,14 @@ use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_sql::plans::ExecuteTaskPlan;
+use databend_common_users::UserApiProvider;
+use log::warn;
 
 use crate::interpreters::common::get_task_client_config;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
+use crate::tasks::TaskRunner;
 
 #[derive(Debug)]
 pub struct ExecuteTaskInterpreter {
@@ -53,6 +59,26 @@ impl Interpreter for ExecuteTaskInterpreter {
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let config = GlobalConfig::instance();
+        if config.query.embedded_task_scheduler() {
+            let tenant = self.plan.tenant.clone();
+            let task_mgr = UserApiProvider::instance().task_api(&tenant)?;
+            let Some(task) = task_mgr.get_task(&self.plan.task_name).await? else {
+                return Err(ErrorCode::UnknownTask(format!(
+                    "Task '{}' does not exist",
+                    self.plan.task_name
+                )));
+            };
+
+            // Like the cloud control backend, the task runs in the background.
+            let runner = TaskRunner::create(tenant)?;
+            GlobalIORuntime::instance().spawn(async move {
+                let task_name = task.data.task_name.clone();
+                if let Err(cause) = runner.run(task.data, Utc::now()).await {
+                    warn!("Failed to execute task {}: {:?}", task_name, cause);
+                }
+            });
+            return Ok(PipelineBuildResult::create());
+        }
         if config.query.cloud_control_grpc_server_address.is_none() {
             return Err(ErrorCode::CloudControlNotEnabled(
                 "cannot execute task without cloud control enabled, please set cloud_control_grpc_server_address in config",
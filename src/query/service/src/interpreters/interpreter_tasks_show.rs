@@ -22,6 +22,7 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_sql::plans::ShowTasksPlan;
 use databend_common_storages_system::parse_tasks_to_datablock;
+use databend_common_users::UserApiProvider;
 
 use crate::interpreters::common::get_task_client_config;
 use crate::interpreters::Interpreter;
@@ -76,6 +77,12 @@ impl Interpreter for ShowTasksInterpreter {
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let config = GlobalConfig::instance();
+        if config.query.embedded_task_scheduler() {
+            let task_mgr = UserApiProvider::instance().task_api(&self.plan.tenant)?;
+            let resp = task_mgr.show_tasks(self.build_request().await?).await?;
+            let result = parse_tasks_to_datablock(resp.tasks)?;
+            return PipelineBuildResult::from_blocks(vec![result]);
+        }
         if config.query.cloud_control_grpc_server_address.is_none() {
             return Err(ErrorCode::CloudControlNotEnabled(
                 "cannot drop task without cloud control enabled, please set cloud_control_grpc_server_address in config",
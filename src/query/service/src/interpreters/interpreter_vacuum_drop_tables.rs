@@ -33,8 +33,10 @@ use databend_common_meta_app::schema::ListDroppedTableReq;
 use databend_common_sql::plans::VacuumDropTablePlan;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_enterprise_vacuum_handler::get_vacuum_handler;
+use databend_storages_common_table_meta::table::parse_identity_columns;
 use log::info;
 
+use crate::interpreters::common::drop_identity_sequences;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -161,6 +163,17 @@ impl Interpreter for VacuumDropTablesInterpreter {
             drop_ids
         );
 
+        // map: table id to the identity columns whose sequences are owned by the table
+        let mut identity_columns = BTreeMap::new();
+        for table in tables.iter() {
+            let table_info = table.get_table_info();
+            if let Ok(columns) = parse_identity_columns(&table_info.meta.options) {
+                if !columns.is_empty() {
+                    identity_columns.insert(table_info.ident.table_id, columns);
+                }
+            }
+        }
+
         // Filter out read-only tables and views.
         // Note: The drop_ids list still includes view IDs
         let (views, tables): (Vec<_>, Vec<_>) = tables
@@ -227,6 +240,13 @@ impl Interpreter for VacuumDropTablesInterpreter {
             );
 
             self.gc_drop_tables(catalog, success_dropped_ids).await?;
+
+            // the sequences of identity columns go away with the metadata of their tables
+            for (table_id, columns) in identity_columns.iter() {
+                if !failed_tables.contains(table_id) {
+                    drop_identity_sequences(self.ctx.as_ref(), columns).await?;
+                }
+            }
         }
 
         match files_opt {
@@ -12,12 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
 use std::sync::Arc;
 
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
-use databend_common_meta_app::schema::UpsertTableOptionReq;
+use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_types::MatchSeq;
 use databend_common_sql::plans::AlterViewPlan;
 use databend_common_sql::Planner;
@@ -55,7 +54,6 @@ impl Interpreter for AlterViewInterpreter {
             .get_table(&self.plan.tenant, &self.plan.database, &self.plan.view_name)
             .await
         {
-            let mut options = HashMap::new();
             let subquery = if self.plan.column_names.is_empty() {
                 self.plan.subquery.clone()
             } else {
@@ -75,17 +73,18 @@ impl Interpreter for AlterViewInterpreter {
                     self.plan.column_names.join(", ")
                 )
             };
-            options.insert("query".to_string(), Some(subquery));
+            let table_info = tbl.get_table_info();
+            let mut new_table_meta = table_info.meta.clone();
+            new_table_meta.options.insert("query".to_string(), subquery);
 
-            let req = UpsertTableOptionReq {
+            // goes through `update_single_table_meta` to be buffered in a transaction
+            let req = UpdateTableMetaReq {
                 table_id: tbl.get_id(),
-                seq: MatchSeq::Exact(tbl.get_table_info().ident.seq),
-                options,
+                seq: MatchSeq::Exact(table_info.ident.seq),
+                new_table_meta,
             };
 
-            catalog
-                .upsert_table_option(&self.plan.tenant, &self.plan.database, req)
-                .await?;
+            catalog.update_single_table_meta(req, table_info).await?;
 
             Ok(PipelineBuildResult::create())
         } else {
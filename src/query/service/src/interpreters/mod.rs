@@ -35,9 +35,11 @@ mod interpreter_create_warehouses;
 mod interpreter_data_mask_create;
 mod interpreter_data_mask_desc;
 mod interpreter_data_mask_drop;
+mod interpreter_database_backup;
 mod interpreter_database_create;
 mod interpreter_database_drop;
 mod interpreter_database_rename;
+mod interpreter_database_restore;
 mod interpreter_database_show_create;
 mod interpreter_database_undrop;
 mod interpreter_dictionary_create;
@@ -59,6 +61,9 @@ mod interpreter_insert;
 mod interpreter_insert_multi_table;
 mod interpreter_inspect_warehouse;
 mod interpreter_kill;
+mod interpreter_materialized_view_create;
+mod interpreter_materialized_view_drop;
+mod interpreter_materialized_view_refresh;
 mod interpreter_metrics;
 mod interpreter_mutation;
 mod interpreter_network_policies_show;
@@ -77,6 +82,8 @@ mod interpreter_password_policy_alter;
 mod interpreter_password_policy_create;
 mod interpreter_password_policy_desc;
 mod interpreter_password_policy_drop;
+mod interpreter_plan_baseline_pin;
+mod interpreter_plan_baseline_unpin;
 mod interpreter_presign;
 mod interpreter_privilege_grant;
 mod interpreter_privilege_revoke;
@@ -107,11 +114,14 @@ mod interpreter_stream_drop;
 mod interpreter_suspend_warehouse;
 mod interpreter_system_action;
 mod interpreter_table_add_column;
+mod interpreter_table_add_constraint;
 mod interpreter_table_analyze;
+mod interpreter_table_cache_warmup;
 mod interpreter_table_create;
 mod interpreter_table_describe;
 mod interpreter_table_drop;
 mod interpreter_table_drop_column;
+mod interpreter_table_drop_constraint;
 mod interpreter_table_exists;
 mod interpreter_table_index_create;
 mod interpreter_table_index_drop;
@@ -128,6 +138,7 @@ mod interpreter_table_truncate;
 mod interpreter_table_undrop;
 mod interpreter_table_unset_options;
 mod interpreter_table_vacuum;
+mod interpreter_table_validate_constraint;
 mod interpreter_task_alter;
 mod interpreter_task_create;
 mod interpreter_task_describe;
@@ -137,6 +148,9 @@ mod interpreter_tasks_show;
 mod interpreter_txn_abort;
 mod interpreter_txn_begin;
 mod interpreter_txn_commit;
+mod interpreter_txn_release_savepoint;
+mod interpreter_txn_rollback_to_savepoint;
+mod interpreter_txn_savepoint;
 mod interpreter_unassign_warehouse_nodes;
 mod interpreter_unset;
 mod interpreter_use_database;
@@ -176,9 +190,11 @@ pub use interpreter_clustering_history::InterpreterClusteringHistory;
 pub use interpreter_data_mask_create::CreateDataMaskInterpreter;
 pub use interpreter_data_mask_desc::DescDataMaskInterpreter;
 pub use interpreter_data_mask_drop::DropDataMaskInterpreter;
+pub use interpreter_database_backup::BackupDatabaseInterpreter;
 pub use interpreter_database_create::CreateDatabaseInterpreter;
 pub use interpreter_database_drop::DropDatabaseInterpreter;
 pub use interpreter_database_rename::RenameDatabaseInterpreter;
+pub use interpreter_database_restore::RestoreDatabaseInterpreter;
 pub use interpreter_database_show_create::ShowCreateDatabaseInterpreter;
 pub use interpreter_database_undrop::UndropDatabaseInterpreter;
 pub use interpreter_dictionary_rename::RenameDictionaryInterpreter;
@@ -189,6 +205,10 @@ pub use interpreter_index_refresh::RefreshIndexInterpreter;
 pub use interpreter_insert::InsertInterpreter;
 pub use interpreter_insert_multi_table::InsertMultiTableInterpreter;
 pub use interpreter_kill::KillInterpreter;
+pub use interpreter_materialized_view_create::CreateMaterializedViewInterpreter;
+pub use interpreter_materialized_view_drop::DropMaterializedViewInterpreter;
+pub use interpreter_materialized_view_refresh::refresh_materialized_view;
+pub use interpreter_materialized_view_refresh::RefreshMaterializedViewInterpreter;
 pub use interpreter_metrics::InterpreterMetrics;
 pub use interpreter_mutation::MutationInterpreter;
 pub use interpreter_network_policies_show::ShowNetworkPoliciesInterpreter;
@@ -203,6 +223,8 @@ pub use interpreter_password_policy_alter::AlterPasswordPolicyInterpreter;
 pub use interpreter_password_policy_create::CreatePasswordPolicyInterpreter;
 pub use interpreter_password_policy_desc::DescPasswordPolicyInterpreter;
 pub use interpreter_password_policy_drop::DropPasswordPolicyInterpreter;
+pub use interpreter_plan_baseline_pin::PinPlanBaselineInterpreter;
+pub use interpreter_plan_baseline_unpin::UnpinPlanBaselineInterpreter;
 pub use interpreter_privilege_grant::GrantPrivilegeInterpreter;
 pub use interpreter_privilege_revoke::RevokePrivilegeInterpreter;
 pub use interpreter_procedure_desc::DescProcedureInterpreter;
@@ -222,11 +244,14 @@ pub use interpreter_stream_create::CreateStreamInterpreter;
 pub use interpreter_stream_drop::DropStreamInterpreter;
 pub use interpreter_system_action::SystemActionInterpreter;
 pub use interpreter_table_add_column::AddTableColumnInterpreter;
+pub use interpreter_table_add_constraint::AddTableConstraintInterpreter;
 pub use interpreter_table_analyze::AnalyzeTableInterpreter;
+pub use interpreter_table_cache_warmup::CacheWarmupTableInterpreter;
 pub use interpreter_table_create::CreateTableInterpreter;
 pub use interpreter_table_describe::DescribeTableInterpreter;
 pub use interpreter_table_drop::DropTableInterpreter;
 pub use interpreter_table_drop_column::DropTableColumnInterpreter;
+pub use interpreter_table_drop_constraint::DropTableConstraintInterpreter;
 pub use interpreter_table_exists::ExistsTableInterpreter;
 pub use interpreter_table_index_create::CreateTableIndexInterpreter;
 pub use interpreter_table_index_drop::DropTableIndexInterpreter;
@@ -241,6 +266,7 @@ pub use interpreter_table_show_create::ShowCreateTableInterpreter;
 pub use interpreter_table_truncate::TruncateTableInterpreter;
 pub use interpreter_table_undrop::UndropTableInterpreter;
 pub use interpreter_table_vacuum::VacuumTableInterpreter;
+pub use interpreter_table_validate_constraint::ValidateTableConstraintInterpreter;
 pub use interpreter_unset::UnSetInterpreter;
 pub use interpreter_use_database::UseDatabaseInterpreter;
 pub use interpreter_user_alter::AlterUserInterpreter;
@@ -47,6 +47,7 @@ pub mod auth;
 pub mod catalogs;
 pub mod clusters;
 pub mod databases;
+pub mod history_tables;
 pub mod interpreters;
 pub mod local;
 pub mod locks;
@@ -57,6 +58,7 @@ pub mod sessions;
 pub mod spillers;
 pub mod stream;
 pub mod table_functions;
+pub mod tasks;
 pub mod test_kits;
 
 mod builtin;
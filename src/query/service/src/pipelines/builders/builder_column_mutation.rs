@@ -13,9 +13,12 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::sync::Arc;
 
 use databend_common_catalog::table::Table;
 use databend_common_exception::Result;
+use databend_common_expression::DataSchema;
+use databend_common_expression::DataSchemaRef;
 use databend_common_expression::RemoteExpr;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
@@ -26,6 +29,7 @@ use databend_common_sql::executor::physical_plans::MutationKind;
 use databend_common_storages_fuse::operations::TransformSerializeBlock;
 use databend_common_storages_fuse::FuseTable;
 
+use crate::pipelines::processors::transforms::TransformCheckConstraints;
 use crate::pipelines::PipelineBuilder;
 
 impl PipelineBuilder {
@@ -46,6 +50,17 @@ impl PipelineBuilder {
             .build_table_by_table_info(&column_mutation.table_info, None)?;
         let table = FuseTable::try_from_table(table.as_ref())?;
 
+        // reject the updated rows violating the CHECK constraints
+        if matches!(column_mutation.mutation_kind, MutationKind::Update)
+            && TransformCheckConstraints::need_check(table)
+        {
+            let schema = table.schema_with_stream().remove_virtual_computed_fields();
+            let schema: DataSchemaRef = Arc::new(DataSchema::from(&schema));
+            self.main_pipeline.try_add_transformer(|| {
+                TransformCheckConstraints::try_new(self.ctx.clone(), table, schema.clone())
+            })?;
+        }
+
         let block_thresholds = table.get_block_thresholds();
         let cluster_stats_gen = if matches!(column_mutation.mutation_kind, MutationKind::Delete) {
             table.get_cluster_stats_gen(self.ctx.clone(), 0, block_thresholds, None)?
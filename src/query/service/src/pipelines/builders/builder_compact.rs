@@ -124,14 +124,26 @@ impl PipelineBuilder {
             max_threads,
         )?;
         let storage_format = table.get_storage_format();
-        self.main_pipeline.add_block_meta_transformer(|| {
-            CompactTransform::create(
+        let schema = table.schema();
+        let ttl_filter = match table.ttl_alive_filter(self.ctx.clone())? {
+            Some(filter) => {
+                // the columns referenced by the TTL must still exist in the table
+                for name in filter.column_refs().keys() {
+                    schema.index_of(name)?;
+                }
+                Some(filter.project_column_ref(|name| schema.index_of(name).unwrap()))
+            }
+            None => None,
+        };
+        self.main_pipeline.try_add_block_meta_transformer(|| {
+            CompactTransform::try_create(
                 self.ctx.clone(),
                 block_reader.clone(),
                 storage_format,
                 stream_ctx.clone(),
+                ttl_filter.clone(),
             )
-        });
+        })?;
 
         // sort
         let cluster_stats_gen = table.cluster_gen_for_append(
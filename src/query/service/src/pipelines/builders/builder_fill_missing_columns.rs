@@ -16,11 +16,15 @@ use std::sync::Arc;
 
 use databend_common_catalog::table::Table;
 use databend_common_exception::Result;
+use databend_common_expression::DataSchema;
 use databend_common_expression::DataSchemaRef;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
 
+use crate::pipelines::processors::transforms::MissingIdentityColumn;
 use crate::pipelines::processors::transforms::TransformAddComputedColumns;
+use crate::pipelines::processors::transforms::TransformAddIdentityColumns;
+use crate::pipelines::processors::transforms::TransformCheckConstraints;
 use crate::pipelines::processors::TransformResortAddOn;
 use crate::pipelines::PipelineBuilder;
 use crate::sessions::QueryContext;
@@ -35,6 +39,19 @@ impl PipelineBuilder {
         table: Arc<dyn Table>,
         source_schema: DataSchemaRef,
     ) -> Result<()> {
+        // Fill missing identity columns from their sequences.
+        let identity_columns = MissingIdentityColumn::of_table(table.as_ref(), &source_schema)?;
+        let source_schema = if identity_columns.is_empty() {
+            source_schema
+        } else {
+            let mut fields = source_schema.fields().clone();
+            fields.extend(identity_columns.iter().map(|column| column.field.clone()));
+            pipeline.try_add_async_transformer(|| {
+                TransformAddIdentityColumns::try_new(ctx.clone(), identity_columns.clone())
+            })?;
+            Arc::new(DataSchema::new(fields))
+        };
+
         let table_default_schema = &table.schema().remove_computed_fields();
         let table_computed_schema = &table.schema().remove_virtual_computed_fields();
         let default_schema: DataSchemaRef = Arc::new(table_default_schema.into());
@@ -63,6 +80,17 @@ impl PipelineBuilder {
             })?;
         }
 
+        // Reject the rows violating the CHECK constraints.
+        if TransformCheckConstraints::need_check(table.as_ref()) {
+            pipeline.try_add_transformer(|| {
+                TransformCheckConstraints::try_new(
+                    ctx.clone(),
+                    table.as_ref(),
+                    computed_schema.clone(),
+                )
+            })?;
+        }
+
         Ok(())
     }
 }
@@ -27,6 +27,7 @@ use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::Pipe;
 use databend_common_pipeline_transforms::processors::create_dummy_item;
 use databend_common_pipeline_transforms::processors::AccumulatingTransformer;
+use databend_common_pipeline_transforms::processors::AsyncTransformer;
 use databend_common_pipeline_transforms::processors::BlockCompactBuilder;
 use databend_common_pipeline_transforms::processors::BlockMetaTransformer;
 use databend_common_pipeline_transforms::processors::TransformCompactBlock;
@@ -39,7 +40,10 @@ use databend_common_storages_fuse::operations::TransformSerializeSegment;
 use databend_common_storages_fuse::operations::UnMatchedExprs;
 use databend_common_storages_fuse::FuseTable;
 
+use crate::pipelines::processors::transforms::MissingIdentityColumn;
 use crate::pipelines::processors::transforms::TransformAddComputedColumns;
+use crate::pipelines::processors::transforms::TransformAddIdentityColumns;
+use crate::pipelines::processors::transforms::TransformCheckConstraints;
 use crate::pipelines::processors::TransformResortAddOnWithoutSourceSchema;
 use crate::pipelines::PipelineBuilder;
 
@@ -185,6 +189,43 @@ impl PipelineBuilder {
     ) -> Result<()> {
         let table = FuseTable::try_from_table(tbl.as_ref())?;
 
+        // fill identity columns missing in the inserted blocks
+        let identity_columns = unmatched
+            .iter()
+            .map(|(source_schema, _, _)| MissingIdentityColumn::of_table(table, source_schema))
+            .collect::<Result<Vec<_>>>()?;
+        let unmatched: UnMatchedExprs = if identity_columns.iter().all(|c| c.is_empty()) {
+            unmatched
+        } else {
+            let mut builder = self.main_pipeline.add_transform_with_specified_len(
+                |input, output| {
+                    Ok(ProcessorPtr::create(AsyncTransformer::create(
+                        input,
+                        output,
+                        TransformAddIdentityColumns::try_new_by_source_schema(
+                            self.ctx.clone(),
+                            identity_columns.clone(),
+                        )?,
+                    )))
+                },
+                transform_len,
+            )?;
+            if need_match {
+                builder.add_items_prepend(vec![create_dummy_item()]);
+            }
+            self.main_pipeline.add_pipe(builder.finalize());
+
+            unmatched
+                .into_iter()
+                .zip(identity_columns.iter())
+                .map(|((source_schema, condition, values), columns)| {
+                    let mut fields = source_schema.fields().clone();
+                    fields.extend(columns.iter().map(|column| column.field.clone()));
+                    (Arc::new(DataSchema::new(fields)), condition, values)
+                })
+                .collect()
+        };
+
         // fill default columns
         let table_default_schema = &table.schema_with_stream().remove_computed_fields();
         let mut builder = self
@@ -228,6 +269,26 @@ impl PipelineBuilder {
             }
             self.main_pipeline.add_pipe(builder.finalize());
         }
+
+        // reject the rows violating the CHECK constraints
+        if TransformCheckConstraints::need_check(tbl.as_ref()) {
+            builder = self
+                .main_pipeline
+                .try_create_transform_pipeline_builder_with_len(
+                    || {
+                        TransformCheckConstraints::try_new(
+                            self.ctx.clone(),
+                            tbl.as_ref(),
+                            computed_schema.clone(),
+                        )
+                    },
+                    transform_len,
+                )?;
+            if need_match {
+                builder.add_items_prepend(vec![create_dummy_item()]);
+            }
+            self.main_pipeline.add_pipe(builder.finalize());
+        }
         Ok(())
     }
 
@@ -32,6 +32,8 @@ use databend_common_expression::PartitionedPayload;
 use databend_common_expression::Payload;
 use databend_common_expression::ProbeState;
 
+use crate::spillers::AggregateSpillEncoding;
+
 pub struct SerializedPayload {
     pub bucket: isize,
     pub data_block: DataBlock,
@@ -112,6 +114,7 @@ pub struct BucketSpilledPayload {
     pub data_range: Range<u64>,
     pub columns_layout: Vec<u64>,
     pub max_partition_count: usize,
+    pub encoding: AggregateSpillEncoding,
 }
 
 pub struct AggregatePayload {
@@ -18,6 +18,8 @@ use databend_common_expression::BlockMetaInfo;
 use databend_common_expression::BlockMetaInfoDowncast;
 use databend_common_expression::BlockMetaInfoPtr;
 
+use crate::spillers::AggregateSpillEncoding;
+
 pub const BUCKET_TYPE: usize = 1;
 pub const SPILLED_TYPE: usize = 2;
 
@@ -32,6 +34,8 @@ pub struct AggregateSerdeMeta {
     // use for new agg hashtable
     pub max_partition_count: usize,
     pub is_empty: bool,
+    // how the spilled buckets are encoded
+    pub encoding: AggregateSpillEncoding,
 }
 
 impl AggregateSerdeMeta {
@@ -48,6 +52,7 @@ impl AggregateSerdeMeta {
             columns_layout: vec![],
             max_partition_count,
             is_empty,
+            encoding: AggregateSpillEncoding::default(),
         })
     }
 
@@ -66,6 +71,7 @@ impl AggregateSerdeMeta {
             data_range: Some(data_range),
             max_partition_count: 0,
             is_empty,
+            encoding: AggregateSpillEncoding::default(),
         })
     }
 
@@ -75,6 +81,7 @@ impl AggregateSerdeMeta {
         data_range: Range<u64>,
         columns_layout: Vec<usize>,
         max_partition_count: usize,
+        encoding: AggregateSpillEncoding,
     ) -> BlockMetaInfoPtr {
         Box::new(AggregateSerdeMeta {
             typ: SPILLED_TYPE,
@@ -84,6 +91,7 @@ impl AggregateSerdeMeta {
             data_range: Some(data_range),
             max_partition_count,
             is_empty: false,
+            encoding,
         })
     }
 }
@@ -220,15 +220,20 @@ pub fn agg_spilling_aggregate_payload(
             data_range: begin..write_size,
             columns_layout,
             max_partition_count: partition_count,
+            encoding: Default::default(),
         });
     }
 
     Ok(Box::pin(async move {
         let instant = Instant::now();
         if !write_data.is_empty() {
-            let (location, write_bytes) = spiller
+            let (location, ranges, encoding, write_bytes) = spiller
                 .spill_stream_aggregate_buffer(Some(location), write_data)
                 .await?;
+            for (payload, range) in spilled_buckets_payloads.iter_mut().zip(ranges) {
+                payload.data_range = range;
+                payload.encoding = encoding.clone();
+            }
             // perf
             {
                 Profile::record_usize_profile(ProfileStatisticsName::RemoteSpillWriteCount, 1);
@@ -151,6 +151,7 @@ impl TransformDeserializer {
                                             ..columns_layout.offsets[index + 1] as usize]
                                             .to_vec(),
                                         max_partition_count: meta.max_partition_count,
+                                        encoding: meta.encoding.clone(),
                                     });
                                 }
                             }
@@ -187,11 +187,8 @@ fn exchange_agg_spilling_aggregate_payload(
     partitioned_payload: PartitionedPayload,
 ) -> Result<BoxFuture<'static, Result<DataBlock>>> {
     let partition_count = partitioned_payload.partition_count();
-    let mut write_size = 0;
     let mut write_data = Vec::with_capacity(partition_count);
     let mut buckets_column_data = Vec::with_capacity(partition_count);
-    let mut data_range_start_column_data = Vec::with_capacity(partition_count);
-    let mut data_range_end_column_data = Vec::with_capacity(partition_count);
     let mut columns_layout_column_data = Vec::with_capacity(partition_count);
     // Record how many rows are spilled.
     let mut rows = 0;
@@ -204,7 +201,6 @@ fn exchange_agg_spilling_aggregate_payload(
         let data_block = payload.aggregate_flush_all()?;
         rows += data_block.num_rows();
 
-        let old_write_size = write_size;
         let columns = data_block.columns().to_vec();
         let mut columns_data = Vec::with_capacity(columns.len());
         let mut columns_layout = Vec::with_capacity(columns.len());
@@ -212,22 +208,19 @@ fn exchange_agg_spilling_aggregate_payload(
         for column in columns.into_iter() {
             let column = column.to_column(data_block.num_rows());
             let column_data = serialize_column(&column);
-            write_size += column_data.len() as u64;
             columns_layout.push(column_data.len() as u64);
             columns_data.push(column_data);
         }
 
         write_data.push(columns_data);
         buckets_column_data.push(bucket as i64);
-        data_range_end_column_data.push(write_size);
         columns_layout_column_data.push(columns_layout);
-        data_range_start_column_data.push(old_write_size);
     }
 
     Ok(Box::pin(async move {
         if !write_data.is_empty() {
             let instant = Instant::now();
-            let (location, write_bytes) = spiller
+            let (location, ranges, encoding, write_bytes) = spiller
                 .spill_stream_aggregate_buffer(None, write_data)
                 .await?;
             // perf
@@ -261,8 +254,8 @@ fn exchange_agg_spilling_aggregate_payload(
 
             let data_block = DataBlock::new_from_columns(vec![
                 Int64Type::from_data(buckets_column_data),
-                UInt64Type::from_data(data_range_start_column_data),
-                UInt64Type::from_data(data_range_end_column_data),
+                UInt64Type::from_data(ranges.iter().map(|range| range.start).collect::<Vec<_>>()),
+                UInt64Type::from_data(ranges.iter().map(|range| range.end).collect::<Vec<_>>()),
                 ArrayType::upcast_column(ArrayType::<UInt64Type>::column_from_iter(
                     columns_layout_column_data
                         .into_iter()
@@ -277,6 +270,7 @@ fn exchange_agg_spilling_aggregate_payload(
                 0..0,
                 vec![],
                 partition_count,
+                encoding,
             )))?;
 
             let write_options = exchange_defines::spilled_write_options();
@@ -33,6 +33,7 @@ use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use itertools::Itertools;
 use log::info;
+use opendal::Buffer;
 use opendal::Operator;
 use tokio::sync::Semaphore;
 
@@ -141,7 +142,7 @@ impl Processor for TransformSpillReader {
                     debug_assert!(read_data.len() == 1);
                     let data = read_data.pop_front().unwrap();
 
-                    self.deserialized_meta = Some(Box::new(Self::deserialize(payload, data)));
+                    self.deserialized_meta = Some(Box::new(Self::deserialize(payload, data)?));
                 }
                 AggregateMeta::Partitioned { bucket, data } => {
                     let mut new_data = Vec::with_capacity(data.len());
@@ -150,7 +151,7 @@ impl Processor for TransformSpillReader {
                         if matches!(&meta, AggregateMeta::BucketSpilled(_)) {
                             if let AggregateMeta::BucketSpilled(payload) = meta {
                                 let data = read_data.pop_front().unwrap();
-                                new_data.push(Self::deserialize(payload, data));
+                                new_data.push(Self::deserialize(payload, data)?);
                             }
 
                             continue;
@@ -294,7 +295,8 @@ impl TransformSpillReader {
         })))
     }
 
-    fn deserialize(payload: BucketSpilledPayload, data: Vec<u8>) -> AggregateMeta {
+    fn deserialize(payload: BucketSpilledPayload, data: Vec<u8>) -> Result<AggregateMeta> {
+        let data = payload.encoding.decode(Buffer::from(data))?.to_vec();
         let mut begin = 0;
         let mut columns = Vec::with_capacity(payload.columns_layout.len());
 
@@ -303,11 +305,11 @@ impl TransformSpillReader {
             begin += column_layout as usize;
         }
 
-        AggregateMeta::Serialized(SerializedPayload {
+        Ok(AggregateMeta::Serialized(SerializedPayload {
             bucket: payload.bucket,
             data_block: DataBlock::new_from_columns(columns),
             max_partition_count: payload.max_partition_count,
-        })
+        }))
     }
 }
 
@@ -18,6 +18,8 @@ use std::sync::Arc;
 
 use byte_unit::Byte;
 use byte_unit::ByteUnit;
+use databend_common_base::runtime::profile::Profile;
+use databend_common_base::runtime::profile::ProfileStatisticsName;
 use databend_common_base::runtime::GLOBAL_MEM_STAT;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -331,6 +333,10 @@ impl Processor for TransformHashJoinBuild {
 
 impl TransformHashJoinBuild {
     fn add_data_block(&mut self, data_block: DataBlock) {
+        Profile::record_usize_profile(
+            ProfileStatisticsName::HashJoinBuildRows,
+            data_block.num_rows(),
+        );
         self.data_blocks_memory_size += data_block.memory_size();
         self.data_blocks.push(data_block);
     }
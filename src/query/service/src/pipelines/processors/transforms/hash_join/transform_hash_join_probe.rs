@@ -17,6 +17,8 @@ use std::collections::VecDeque;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
+use databend_common_base::runtime::profile::Profile;
+use databend_common_base::runtime::profile::ProfileStatisticsName;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
@@ -221,6 +223,10 @@ impl TransformHashJoinProbe {
 
         if self.input_port.has_data() {
             let data_block = self.input_port.pull_data().unwrap()?;
+            Profile::record_usize_profile(
+                ProfileStatisticsName::HashJoinProbeRows,
+                data_block.num_rows(),
+            );
             self.add_data_block(data_block);
         }
 
@@ -18,11 +18,13 @@ pub(crate) mod range_join;
 mod runtime_pool;
 mod transform_add_computed_columns;
 mod transform_add_const_columns;
+mod transform_add_identity_columns;
 mod transform_add_internal_columns;
 mod transform_add_stream_columns;
 mod transform_async_function;
 mod transform_cache_scan;
 mod transform_cast_schema;
+mod transform_check_constraints;
 mod transform_create_sets;
 mod transform_dictionary;
 mod transform_expression_scan;
@@ -44,6 +46,8 @@ mod window;
 pub use hash_join::*;
 pub use transform_add_computed_columns::TransformAddComputedColumns;
 pub use transform_add_const_columns::TransformAddConstColumns;
+pub use transform_add_identity_columns::MissingIdentityColumn;
+pub use transform_add_identity_columns::TransformAddIdentityColumns;
 pub use transform_add_internal_columns::TransformAddInternalColumns;
 pub use transform_add_stream_columns::TransformAddStreamColumns;
 pub use transform_async_function::TransformAsyncFunction;
@@ -51,6 +55,7 @@ pub use transform_cache_scan::CacheSourceState;
 pub use transform_cache_scan::HashJoinCacheState;
 pub use transform_cache_scan::TransformCacheScan;
 pub use transform_cast_schema::TransformCastSchema;
+pub use transform_check_constraints::TransformCheckConstraints;
 pub use transform_create_sets::TransformCreateSets;
 pub use transform_expression_scan::TransformExpressionScan;
 pub use transform_filter::TransformFilter;
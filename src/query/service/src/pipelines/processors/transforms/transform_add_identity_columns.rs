@@ -0,0 +1,177 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::type_check::check_cast;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchema;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FromData;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::SourceSchemaIndex;
+use databend_common_expression::Value;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_app::schema::GetSequenceNextValueReq;
+use databend_common_meta_app::schema::SequenceIdent;
+use databend_common_pipeline_transforms::processors::AsyncTransform;
+use databend_storages_common_table_meta::table::identity_columns_of_schema;
+
+use crate::sessions::QueryContext;
+
+/// An identity column missing in the blocks written to the table.
+#[derive(Clone)]
+pub struct MissingIdentityColumn {
+    pub field: DataField,
+    pub sequence: String,
+}
+
+impl MissingIdentityColumn {
+    /// The identity columns of `table` which are not in `source_schema`.
+    pub fn of_table(table: &dyn Table, source_schema: &DataSchema) -> Result<Vec<Self>> {
+        let schema = table.schema();
+        Ok(identity_columns_of_schema(table.options(), &schema)?
+            .into_iter()
+            .map(|(index, column)| MissingIdentityColumn {
+                field: DataField::from(schema.field(index)),
+                sequence: column.sequence,
+            })
+            .filter(|column| !source_schema.has_field(column.field.name()))
+            .collect())
+    }
+}
+
+/// Appends the identity columns missing in the blocks, the values of each block are
+/// allocated from the sequences in one batch.
+pub struct TransformAddIdentityColumns {
+    ctx: Arc<QueryContext>,
+    func_ctx: FunctionContext,
+    // missing_columns[i] is the identity columns missing in the blocks of the i-th source schema
+    missing_columns: Vec<Vec<MissingIdentityColumn>>,
+    // if set, only the blocks tagged with `SourceSchemaIndex` are filled, the others
+    // have the entire schema of the table already.
+    by_source_schema: bool,
+}
+
+impl TransformAddIdentityColumns {
+    pub fn try_new(
+        ctx: Arc<QueryContext>,
+        missing_columns: Vec<MissingIdentityColumn>,
+    ) -> Result<Self> {
+        Ok(Self {
+            func_ctx: ctx.get_function_context()?,
+            ctx,
+            missing_columns: vec![missing_columns],
+            by_source_schema: false,
+        })
+    }
+
+    /// For the blocks inserted by the unmatched clauses of MERGE INTO.
+    pub fn try_new_by_source_schema(
+        ctx: Arc<QueryContext>,
+        missing_columns: Vec<Vec<MissingIdentityColumn>>,
+    ) -> Result<Self> {
+        Ok(Self {
+            func_ctx: ctx.get_function_context()?,
+            ctx,
+            missing_columns,
+            by_source_schema: true,
+        })
+    }
+
+    async fn next_values(
+        &self,
+        column: &MissingIdentityColumn,
+        num_rows: usize,
+    ) -> Result<BlockEntry> {
+        let values = if num_rows == 0 {
+            vec![]
+        } else {
+            let req = GetSequenceNextValueReq {
+                ident: SequenceIdent::new(self.ctx.get_tenant(), &column.sequence),
+                count: num_rows as u64,
+            };
+            let catalog = self.ctx.get_default_catalog()?;
+            let resp = catalog.get_sequence_next_value(req).await?;
+            let step = resp.step as u64;
+            (0..num_rows as u64)
+                .map(|i| resp.start + i * step)
+                .collect::<Vec<_>>()
+        };
+
+        // the values may overflow the integer type of the column
+        let data_type = DataType::Number(NumberDataType::UInt64);
+        let block = DataBlock::new(
+            vec![BlockEntry {
+                data_type: data_type.clone(),
+                value: Value::Column(UInt64Type::from_data(values)),
+            }],
+            num_rows,
+        );
+        let expr = check_cast(
+            None,
+            false,
+            Expr::ColumnRef {
+                span: None,
+                id: 0,
+                data_type,
+                display_name: column.field.name().clone(),
+            },
+            column.field.data_type(),
+            &BUILTIN_FUNCTIONS,
+        )?;
+        let evaluator = Evaluator::new(&block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        Ok(BlockEntry {
+            data_type: column.field.data_type().clone(),
+            value: evaluator.run(&expr)?,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncTransform for TransformAddIdentityColumns {
+    const NAME: &'static str = "AddIdentityColumnsTransform";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, mut data_block: DataBlock) -> Result<DataBlock> {
+        let index = if self.by_source_schema {
+            match data_block
+                .get_meta()
+                .and_then(SourceSchemaIndex::downcast_ref_from)
+            {
+                Some(index) => *index,
+                None => return Ok(data_block),
+            }
+        } else {
+            0
+        };
+
+        let num_rows = data_block.num_rows();
+        for column in &self.missing_columns[index] {
+            let entry = self.next_values(column, num_rows).await?;
+            data_block.add_column(entry);
+        }
+        Ok(data_block)
+    }
+}
@@ -70,8 +70,12 @@ impl TransformAsyncFunction {
                 count,
             };
             let resp = catalog.get_sequence_next_value(req).await?;
-            let range = resp.start..resp.start + count;
-            UInt64Type::from_data(range.collect::<Vec<u64>>())
+            let step = resp.step as u64;
+            UInt64Type::from_data(
+                (0..count)
+                    .map(|i| resp.start + i * step)
+                    .collect::<Vec<_>>(),
+            )
         };
         let entry = BlockEntry {
             data_type: data_type.clone(),
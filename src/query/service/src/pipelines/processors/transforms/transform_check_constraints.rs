@@ -0,0 +1,110 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Value;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_app::schema::TableConstraint;
+use databend_common_pipeline_transforms::processors::Transform;
+use databend_common_sql::parse_check_constraint_filter;
+
+use crate::sessions::QueryContext;
+
+/// Rejects the blocks to be written to a table if any of their rows violates a CHECK
+/// constraint of the table. The blocks pass through unchanged otherwise.
+pub struct TransformCheckConstraints {
+    func_ctx: FunctionContext,
+    table: String,
+    // (constraint name, the predicate which holds for the rows satisfying the constraint)
+    checks: Vec<(String, Expr)>,
+}
+
+impl TransformCheckConstraints
+where Self: Transform
+{
+    /// Whether the blocks written to `table` need to be checked.
+    pub fn need_check(table: &dyn Table) -> bool {
+        table
+            .get_table_info()
+            .meta
+            .constraints
+            .values()
+            .any(|constraint| constraint.is_enforced())
+    }
+
+    /// The blocks to check are of `input_schema`, which must contain the columns referred
+    /// by the constraints of `table`.
+    pub fn try_new(
+        ctx: Arc<QueryContext>,
+        table: &dyn Table,
+        input_schema: DataSchemaRef,
+    ) -> Result<Self> {
+        let mut checks = vec![];
+        for (name, constraint) in &table.get_table_info().meta.constraints {
+            match constraint {
+                TableConstraint::Check(check) => {
+                    let filter =
+                        parse_check_constraint_filter(ctx.clone(), input_schema.clone(), check)?;
+                    checks.push((name.clone(), filter));
+                }
+                // the key constraints are informational
+                TableConstraint::PrimaryKey(_)
+                | TableConstraint::Unique(_)
+                | TableConstraint::ForeignKey { .. } => {}
+            }
+        }
+
+        Ok(Self {
+            func_ctx: ctx.get_function_context()?,
+            table: table.name().to_string(),
+            checks,
+        })
+    }
+}
+
+impl Transform for TransformCheckConstraints {
+    const NAME: &'static str = "CheckConstraintsTransform";
+
+    fn transform(&mut self, block: DataBlock) -> Result<DataBlock> {
+        let evaluator = Evaluator::new(&block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        for (name, filter) in &self.checks {
+            let passed = evaluator
+                .run(filter)?
+                .try_downcast::<BooleanType>()
+                .unwrap();
+            let violated = match &passed {
+                Value::Scalar(v) => !*v,
+                Value::Column(bitmap) => bitmap.null_count() > 0,
+            };
+            if violated {
+                return Err(ErrorCode::CheckConstraintViolated(format!(
+                    "CHECK constraint `{}` of table `{}` is violated",
+                    name, self.table
+                )));
+            }
+        }
+        Ok(block)
+    }
+}
@@ -306,6 +306,8 @@ async fn create_memory_table_for_cte_scan(
                 cluster_key: None,
                 as_select: None,
                 inverted_indexes: None,
+                constraints: Default::default(),
+                clone_from: None,
             };
             let create_table_interpreter =
                 CreateTableInterpreter::try_create(ctx.clone(), create_table_plan)?;
@@ -0,0 +1,436 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::DataSourceInfo;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Evaluator;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::RemoteExpr;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_sql::executor::physical_plans::ConstantTableScan;
+use databend_common_sql::executor::physical_plans::Exchange;
+use databend_common_sql::executor::physical_plans::FragmentKind;
+use databend_common_sql::executor::physical_plans::HashJoin;
+use databend_common_sql::executor::physical_plans::JoinSkew;
+use databend_common_sql::executor::PhysicalPlanReplacer;
+use databend_common_sql::plans::JoinType;
+use futures_util::TryStreamExt;
+use log::info;
+
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelinePullingExecutor;
+use crate::schedulers::build_distributed_pipeline;
+use crate::sessions::QueryContext;
+use crate::sessions::QueryContextShared;
+use crate::sessions::TableContext;
+use crate::sql::executor::PhysicalPlan;
+use crate::stream::PullingExecutorStream;
+
+/// Re-plan the distributed hash joins of a plan with the actual sizes of their build sides.
+///
+/// The build side of a join is executed as a separate stage before the rest of the plan. If its
+/// output fits in `adaptive_broadcast_join_threshold`, the output replaces the build side and is
+/// broadcast to all the nodes. Otherwise the stage is aborted once the threshold is exceeded, and
+/// both sides of the join are shuffled by the join keys, the build side being executed again as
+/// part of the plan. The output read before the abort is a sample of the build side, the keys
+/// in more than `join_skew_hot_key_percent` of its rows are split across the nodes, see
+/// [`JoinSkew`].
+/// The joins are re-planned bottom up, so the build side of a join is executed with the
+/// re-planned joins under it.
+pub struct AdaptiveJoinPlanner {
+    ctx: Arc<QueryContext>,
+    broadcast_threshold: usize,
+    hot_key_percent: u64,
+    // The joins whose distributions have been decided.
+    adapted: HashSet<u32>,
+}
+
+impl AdaptiveJoinPlanner {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        let settings = ctx.get_settings();
+        let broadcast_threshold = settings.get_adaptive_broadcast_join_threshold()?;
+        let hot_key_percent = settings.get_join_skew_hot_key_percent()?;
+        Ok(Self {
+            ctx,
+            broadcast_threshold,
+            hot_key_percent,
+            adapted: HashSet::new(),
+        })
+    }
+
+    /// Returns true if the distributions of the joins can be changed by adaptive execution.
+    pub fn enabled(ctx: &QueryContext) -> Result<bool> {
+        let settings = ctx.get_settings();
+        Ok(settings.get_enable_adaptive_query_execution()?
+            && !settings.get_enforce_broadcast_join()?
+            && !settings.get_enforce_shuffle_join()?)
+    }
+
+    #[async_backtrace::framed]
+    pub async fn adapt(mut self, mut plan: PhysicalPlan) -> Result<PhysicalPlan> {
+        while let Some((join_id, build)) = self.next_stage(&plan)? {
+            let (materialized, sample) = match self.execute_stage(&build).await? {
+                StageOutput::Materialized(scan) => (Some(scan), vec![]),
+                StageOutput::Exceeded(sample) => (None, sample),
+            };
+            let mut next_plan_id = max_plan_id(&plan) + 1;
+            let mut rewriter = JoinDistributionRewriter {
+                join_id,
+                materialized,
+                sample,
+                hot_key_percent: self.hot_key_percent,
+                func_ctx: self.ctx.get_function_context()?,
+                next_plan_id: &mut next_plan_id,
+            };
+            plan = rewriter.replace(&plan)?;
+            self.adapted.insert(join_id);
+        }
+        Ok(plan)
+    }
+
+    // Find the deepest join to re-plan, returns its id and the input of its build side exchange.
+    fn next_stage(&self, plan: &PhysicalPlan) -> Result<Option<(u32, PhysicalPlan)>> {
+        let Some(join) = self.find_join(plan, false) else {
+            return Ok(None);
+        };
+        match join.build.as_ref() {
+            PhysicalPlan::Exchange(exchange) => Ok(Some((join.plan_id, *exchange.input.clone()))),
+            _ => Err(ErrorCode::Internal(format!(
+                "the build side of adaptive join {} is not an exchange",
+                join.plan_id
+            ))),
+        }
+    }
+
+    // `repartitioned` is true if the output of the plan is exchanged before it is consumed by an
+    // operator that depends on how the output is distributed across the nodes.
+    #[recursive::recursive]
+    fn find_join<'a>(&self, plan: &'a PhysicalPlan, repartitioned: bool) -> Option<&'a HashJoin> {
+        match plan {
+            PhysicalPlan::Exchange(exchange) => self.find_join(&exchange.input, true),
+            PhysicalPlan::Filter(_)
+            | PhysicalPlan::EvalScalar(_)
+            | PhysicalPlan::ProjectSet(_)
+            | PhysicalPlan::AggregateExpand(_)
+            | PhysicalPlan::AggregatePartial(_)
+            | PhysicalPlan::Sort(_)
+            | PhysicalPlan::Limit(_)
+            | PhysicalPlan::RowFetch(_)
+            | PhysicalPlan::Udf(_) => plan
+                .children()
+                .find_map(|child| self.find_join(child, repartitioned)),
+            PhysicalPlan::HashJoin(join) => {
+                // A broadcast join passes the distribution of its probe side through.
+                let is_broadcast = matches!(
+                    join.build.as_ref(),
+                    PhysicalPlan::Exchange(Exchange {
+                        kind: FragmentKind::Expansive,
+                        ..
+                    })
+                );
+                self.find_join(&join.build, false)
+                    .or_else(|| self.find_join(&join.probe, repartitioned && is_broadcast))
+                    .or_else(|| (repartitioned && self.is_adaptive_join(join)).then_some(join))
+            }
+            _ => plan
+                .children()
+                .find_map(|child| self.find_join(child, false)),
+        }
+    }
+
+    fn is_adaptive_join(&self, join: &HashJoin) -> bool {
+        if self.adapted.contains(&join.plan_id)
+            || join.build_keys.is_empty()
+            || join.need_hold_hash_table
+            || join.build_side_cache_info.is_some()
+            // The keys of the other join types are evaluated against nullable columns, they can
+            // not be used as the shuffle keys of the join inputs.
+            || !matches!(
+                join.join_type,
+                JoinType::Inner | JoinType::LeftSemi | JoinType::LeftAnti
+            )
+        {
+            return false;
+        }
+
+        match (join.build.as_ref(), join.probe.as_ref()) {
+            (
+                PhysicalPlan::Exchange(Exchange {
+                    kind: FragmentKind::Expansive,
+                    input,
+                    ..
+                }),
+                _,
+            )
+            | (
+                PhysicalPlan::Exchange(Exchange {
+                    kind: FragmentKind::Normal,
+                    input,
+                    ..
+                }),
+                PhysicalPlan::Exchange(Exchange {
+                    kind: FragmentKind::Normal,
+                    ..
+                }),
+            ) => is_stage_plan(input),
+            _ => false,
+        }
+    }
+
+    // Execute the build side of a join in a separate query, returns its output as a constant
+    // table scan if it fits in the broadcast threshold.
+    #[async_backtrace::framed]
+    async fn execute_stage(&self, input: &PhysicalPlan) -> Result<StageOutput> {
+        let stage_ctx = QueryContext::create_from_shared(QueryContextShared::try_create(
+            self.ctx.get_current_session(),
+            self.ctx.get_cluster(),
+        )?);
+        let plan = PhysicalPlan::Exchange(Exchange {
+            plan_id: input.get_id(),
+            input: Box::new(input.clone()),
+            kind: FragmentKind::Merge,
+            keys: vec![],
+            ignore_exchange: false,
+            allow_adjust_parallelism: true,
+        });
+
+        let build_res = build_distributed_pipeline(&stage_ctx, &plan).await?;
+        let settings = ExecutorSettings::try_create(stage_ctx.clone())?;
+        let pulling_executor = PipelinePullingExecutor::from_pipelines(build_res, settings)?;
+        stage_ctx.set_executor(pulling_executor.get_inner())?;
+        let mut stream = PullingExecutorStream::create(pulling_executor)?;
+
+        let mut blocks = vec![];
+        let mut num_bytes = 0;
+        while let Some(block) = stream.try_next().await? {
+            num_bytes += block.memory_size();
+            blocks.push(block);
+            if num_bytes > self.broadcast_threshold {
+                // The stage is aborted when the stream is dropped.
+                info!(
+                    "build side {} of query {} exceeds {} bytes, shuffle it",
+                    input.get_id(),
+                    self.ctx.get_id(),
+                    self.broadcast_threshold
+                );
+                return Ok(StageOutput::Exceeded(blocks));
+            }
+        }
+
+        let output_schema = input.output_schema()?;
+        let block = match blocks.is_empty() {
+            true => DataBlock::empty_with_schema(output_schema.clone()),
+            false => DataBlock::concat(&blocks)?,
+        };
+        let num_rows = block.num_rows();
+        info!(
+            "build side {} of query {} has {} rows and {} bytes, broadcast it",
+            input.get_id(),
+            self.ctx.get_id(),
+            num_rows,
+            num_bytes
+        );
+        let values = block
+            .columns()
+            .iter()
+            .map(|entry| {
+                entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, num_rows)
+            })
+            .collect();
+        let scan = PhysicalPlan::ConstantTableScan(ConstantTableScan {
+            plan_id: input.get_id(),
+            values,
+            num_rows,
+            output_schema,
+        });
+        Ok(StageOutput::Materialized(scan))
+    }
+}
+
+enum StageOutput {
+    // The output of the stage as a constant table scan.
+    Materialized(PhysicalPlan),
+    // The output read before the stage exceeds the broadcast threshold.
+    Exceeded(Vec<DataBlock>),
+}
+
+// The operators that can be executed in a separate query.
+#[recursive::recursive]
+fn is_stage_plan(plan: &PhysicalPlan) -> bool {
+    let supported = match plan {
+        PhysicalPlan::TableScan(scan) => {
+            matches!(scan.source.source_info, DataSourceInfo::TableSource(_))
+        }
+        PhysicalPlan::HashJoin(join) => join.build_side_cache_info.is_none(),
+        PhysicalPlan::ConstantTableScan(_)
+        | PhysicalPlan::Filter(_)
+        | PhysicalPlan::EvalScalar(_)
+        | PhysicalPlan::ProjectSet(_)
+        | PhysicalPlan::AggregateExpand(_)
+        | PhysicalPlan::AggregatePartial(_)
+        | PhysicalPlan::AggregateFinal(_)
+        | PhysicalPlan::Window(_)
+        | PhysicalPlan::WindowPartition(_)
+        | PhysicalPlan::Sort(_)
+        | PhysicalPlan::Limit(_)
+        | PhysicalPlan::RowFetch(_)
+        | PhysicalPlan::Udf(_)
+        | PhysicalPlan::Exchange(_)
+        | PhysicalPlan::UnionAll(_) => true,
+        _ => false,
+    };
+    supported && plan.children().all(is_stage_plan)
+}
+
+#[recursive::recursive]
+fn max_plan_id(plan: &PhysicalPlan) -> u32 {
+    plan.children()
+        .map(max_plan_id)
+        .fold(plan.get_id(), u32::max)
+}
+
+// The values of the shuffle key that are in at least `percent` of the rows of the sample.
+fn hot_keys(
+    sample: &[DataBlock],
+    key: &RemoteExpr,
+    func_ctx: &FunctionContext,
+    percent: u64,
+) -> Result<Vec<Scalar>> {
+    let key = key.as_expr(&BUILTIN_FUNCTIONS);
+    let mut counts = HashMap::<Scalar, usize>::new();
+    let mut num_rows = 0;
+    for block in sample {
+        let evaluator = Evaluator::new(block, func_ctx, &BUILTIN_FUNCTIONS);
+        let column = evaluator
+            .run(&key)?
+            .convert_to_full_column(key.data_type(), block.num_rows());
+        for value in column.iter() {
+            if !matches!(value, ScalarRef::Null) {
+                *counts.entry(value.to_owned()).or_default() += 1;
+            }
+        }
+        num_rows += block.num_rows();
+    }
+    Ok(counts
+        .into_iter()
+        .filter(|(_, count)| *count as u64 * 100 >= num_rows as u64 * percent)
+        .map(|(value, _)| value)
+        .collect())
+}
+
+struct JoinDistributionRewriter<'a> {
+    join_id: u32,
+    // The output of the build side if it is broadcast, it replaces the input of the build side
+    // exchange. The join is shuffled if it is None.
+    materialized: Option<PhysicalPlan>,
+    // A sample of the build side of a shuffled join, to find its hot keys.
+    sample: Vec<DataBlock>,
+    hot_key_percent: u64,
+    func_ctx: FunctionContext,
+    next_plan_id: &'a mut u32,
+}
+
+impl PhysicalPlanReplacer for JoinDistributionRewriter<'_> {
+    fn replace_hash_join(&mut self, plan: &HashJoin) -> Result<PhysicalPlan> {
+        let mut join = plan.clone();
+        join.build = Box::new(self.replace(&plan.build)?);
+        join.probe = Box::new(self.replace(&plan.probe)?);
+        if join.plan_id != self.join_id {
+            return Ok(PhysicalPlan::HashJoin(join));
+        }
+
+        let PhysicalPlan::Exchange(build) = *join.build else {
+            return Err(ErrorCode::Internal(format!(
+                "the build side of adaptive join {} is not an exchange",
+                join.plan_id
+            )));
+        };
+        let shuffled = build.kind == FragmentKind::Normal;
+        let broadcast = self.materialized.is_some();
+        if let Some(materialized) = self.materialized.take() {
+            join.build = Box::new(PhysicalPlan::Exchange(Exchange {
+                input: Box::new(materialized),
+                kind: FragmentKind::Expansive,
+                keys: vec![],
+                ..build
+            }));
+            if shuffled {
+                let PhysicalPlan::Exchange(probe) = *join.probe else {
+                    return Err(ErrorCode::Internal(format!(
+                        "the probe side of shuffle join {} is not an exchange",
+                        join.plan_id
+                    )));
+                };
+                join.probe = probe.input;
+            }
+            join.skew = None;
+        } else if shuffled {
+            join.build = Box::new(PhysicalPlan::Exchange(build));
+        } else {
+            join.build = Box::new(PhysicalPlan::Exchange(Exchange {
+                kind: FragmentKind::Normal,
+                keys: join.build_keys.clone(),
+                ..build
+            }));
+            let plan_id = *self.next_plan_id;
+            *self.next_plan_id += 1;
+            join.probe = Box::new(PhysicalPlan::Exchange(Exchange {
+                plan_id,
+                input: join.probe,
+                kind: FragmentKind::Normal,
+                keys: join.probe_keys.clone(),
+                ignore_exchange: false,
+                allow_adjust_parallelism: true,
+            }));
+        }
+
+        // The build rows with a hot key can only be split if the unmatched build rows and the
+        // duplicated probe rows are not in the output.
+        if !broadcast
+            && join.skew.is_none()
+            && self.hot_key_percent > 0
+            && join.join_type == JoinType::Inner
+            && join.build_keys.len() == 1
+        {
+            if let PhysicalPlan::Exchange(Exchange { keys, .. }) = join.build.as_ref() {
+                let hot_keys =
+                    hot_keys(&self.sample, &keys[0], &self.func_ctx, self.hot_key_percent)?;
+                if !hot_keys.is_empty() {
+                    info!(
+                        "split {} hot keys of the build side of join {}",
+                        hot_keys.len(),
+                        join.plan_id
+                    );
+                    join.skew = Some(JoinSkew {
+                        hot_keys,
+                        build_skewed: true,
+                    });
+                }
+            }
+        }
+
+        join.broadcast = broadcast;
+        Ok(PhysicalPlan::HashJoin(join))
+    }
+}
@@ -37,6 +37,7 @@ use crate::schedulers::fragments::plan_fragment::FragmentType;
 use crate::schedulers::PlanFragment;
 use crate::servers::flight::v1::exchange::BroadcastExchange;
 use crate::servers::flight::v1::exchange::DataExchange;
+use crate::servers::flight::v1::exchange::HotKeys;
 use crate::servers::flight::v1::exchange::MergeExchange;
 use crate::servers::flight::v1::exchange::ShuffleDataExchange;
 use crate::sessions::QueryContext;
@@ -49,6 +50,8 @@ pub struct Fragmenter {
     fragments: Vec<PlanFragment>,
     query_id: String,
     state: State,
+    // The hot keys of the exchange being visited, set by its parent join.
+    hot_keys: Option<HotKeys>,
 }
 
 /// A state to track if is visiting a source fragment, useful when building fragments.
@@ -77,6 +80,7 @@ impl Fragmenter {
             fragments: vec![],
             state: State::Other,
             query_id,
+            hot_keys: None,
         })
     }
 
@@ -132,6 +136,34 @@ impl Fragmenter {
         Ok(root_fragment)
     }
 
+    // The hot keys of the shuffled build and probe sides of a skewed join.
+    fn join_hot_keys(plan: &HashJoin) -> (Option<HotKeys>, Option<HotKeys>) {
+        let Some(skew) = &plan.skew else {
+            return (None, None);
+        };
+        let is_shuffle = |plan: &PhysicalPlan| {
+            matches!(
+                plan,
+                PhysicalPlan::Exchange(Exchange {
+                    kind: FragmentKind::Normal,
+                    ..
+                })
+            )
+        };
+        if !is_shuffle(&plan.build) || !is_shuffle(&plan.probe) {
+            return (None, None);
+        }
+
+        let hot_keys = |replicate| HotKeys {
+            keys: skew.hot_keys.clone(),
+            replicate,
+        };
+        (
+            Some(hot_keys(!skew.build_skewed)),
+            Some(hot_keys(skew.build_skewed)),
+        )
+    }
+
     fn resolve_fragment_connection(fragment: &mut PlanFragment) {
         for source_fragment in fragment.source_fragments.iter_mut() {
             if let PhysicalPlan::ExchangeSink(ExchangeSink {
@@ -207,11 +239,15 @@ impl PhysicalPlanReplacer for Fragmenter {
     }
 
     fn replace_hash_join(&mut self, plan: &HashJoin) -> Result<PhysicalPlan> {
+        let (build_hot_keys, probe_hot_keys) = Self::join_hot_keys(plan);
+
         let mut fragments = vec![];
+        self.hot_keys = build_hot_keys;
         let build_input = self.replace(plan.build.as_ref())?;
 
         // Consume current fragments to prevent them being consumed by `probe_input`.
         fragments.append(&mut self.fragments);
+        self.hot_keys = probe_hot_keys;
         let probe_input = self.replace(plan.probe.as_ref())?;
         fragments.append(&mut self.fragments);
         self.fragments = fragments;
@@ -239,6 +275,7 @@ impl PhysicalPlanReplacer for Fragmenter {
             broadcast: plan.broadcast,
             single_to_inner: plan.single_to_inner.clone(),
             build_side_cache_info: plan.build_side_cache_info.clone(),
+            skew: plan.skew.clone(),
         }))
     }
 
@@ -270,6 +307,8 @@ impl PhysicalPlanReplacer for Fragmenter {
     }
 
     fn replace_exchange(&mut self, plan: &Exchange) -> Result<PhysicalPlan> {
+        let hot_keys = self.hot_keys.take();
+
         // Recursively rewrite input
         let input = self.replace(plan.input.as_ref())?;
         let input_schema = input.output_schema()?;
@@ -304,7 +343,10 @@ impl PhysicalPlanReplacer for Fragmenter {
             State::Recluster => FragmentType::Recluster,
         };
         self.state = State::Other;
-        let exchange = Self::get_exchange(self.ctx.clone(), &plan)?;
+        let mut exchange = Self::get_exchange(self.ctx.clone(), &plan)?;
+        if let Some(DataExchange::ShuffleDataExchange(exchange)) = &mut exchange {
+            exchange.hot_keys = hot_keys;
+        }
 
         let mut source_fragment = PlanFragment {
             plan,
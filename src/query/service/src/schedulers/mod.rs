@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod adaptive;
 mod fragments;
 mod scheduler;
 
+pub use adaptive::AdaptiveJoinPlanner;
 pub use fragments::*;
 pub use scheduler::*;
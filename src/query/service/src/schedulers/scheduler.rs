@@ -26,6 +26,7 @@ use crate::pipelines::executor::ExecutorSettings;
 use crate::pipelines::executor::PipelinePullingExecutor;
 use crate::pipelines::PipelineBuildResult;
 use crate::pipelines::PipelineBuilder;
+use crate::schedulers::AdaptiveJoinPlanner;
 use crate::schedulers::Fragmenter;
 use crate::schedulers::QueryFragmentsActions;
 use crate::sessions::QueryContext;
@@ -69,7 +70,14 @@ pub async fn build_query_pipeline_without_render_result_set(
             ctx.set_cluster(ctx.get_warehouse_cluster().await?);
         }
 
-        build_distributed_pipeline(ctx, plan).await
+        if AdaptiveJoinPlanner::enabled(ctx)? {
+            let plan = AdaptiveJoinPlanner::try_create(ctx.clone())?
+                .adapt(plan.clone())
+                .await?;
+            build_distributed_pipeline(ctx, &plan).await
+        } else {
+            build_distributed_pipeline(ctx, plan).await
+        }
     }?;
     Ok(build_res)
 }
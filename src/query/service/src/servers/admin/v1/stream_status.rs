@@ -51,17 +51,25 @@ async fn check_stream_status(
         .await?;
     let stream = StreamTable::try_from_table(tbl.as_ref())?;
 
-    let table_id = stream.source_table_id()?;
-    let seqv = catalog
-        .get_table_meta_by_id(table_id)
-        .await?
-        .ok_or_else(|| {
-            let err = UnknownTableId::new(table_id, "check_stream_status");
-            AppError::from(err)
-        })?;
+    let has_data = match stream.source_engine() {
+        None => {
+            let table_id = stream.source_table_id()?;
+            let seqv = catalog
+                .get_table_meta_by_id(table_id)
+                .await?
+                .ok_or_else(|| {
+                    let err = UnknownTableId::new(table_id, "check_stream_status");
+                    AppError::from(err)
+                })?;
+            seqv.seq != stream.offset()?
+        }
+        Some(_) if stream.is_view_stream() => stream.view_sources_changed(catalog.as_ref()).await?,
+        // The versions of Delta and Iceberg tables are not kept in meta.
+        Some(_) => true,
+    };
 
     Ok(StreamStatusResponse {
-        has_data: seqv.seq != stream.offset()?,
+        has_data,
         params: params.0,
     })
 }
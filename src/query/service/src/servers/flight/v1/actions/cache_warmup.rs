@@ -0,0 +1,40 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_catalog::plan::Partitions;
+use databend_common_exception::Result;
+use databend_common_sql::plans::CacheWarmupTablePlan;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::interpreters::CacheWarmupTableInterpreter;
+use crate::interpreters::Interpreter;
+use crate::servers::flight::v1::actions::create_session;
+
+pub static CACHE_WARMUP: &str = "/actions/cache_warmup";
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CacheWarmupRequest {
+    pub plan: CacheWarmupTablePlan,
+    /// The partitions the receiving node warms up.
+    pub parts: Partitions,
+}
+
+pub async fn cache_warmup(request: CacheWarmupRequest) -> Result<()> {
+    let session = create_session()?;
+    let query_context = session.create_query_context().await?;
+    let interpreter =
+        CacheWarmupTableInterpreter::from_flight(query_context, request.plan, request.parts)?;
+    interpreter.execute2().await.map(|_| ())
+}
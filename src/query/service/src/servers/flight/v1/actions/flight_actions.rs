@@ -25,6 +25,7 @@ use futures_util::future::BoxFuture;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::servers::flight::v1::actions::cache_warmup::cache_warmup;
 use crate::servers::flight::v1::actions::get_profile::get_profile;
 use crate::servers::flight::v1::actions::init_query_env::init_query_env;
 use crate::servers::flight::v1::actions::init_query_env::INIT_QUERY_ENV;
@@ -36,6 +37,7 @@ use crate::servers::flight::v1::actions::start_prepared_query::start_prepared_qu
 use crate::servers::flight::v1::actions::system_action::system_action;
 use crate::servers::flight::v1::actions::truncate_table::truncate_table;
 use crate::servers::flight::v1::actions::truncate_table::TRUNCATE_TABLE;
+use crate::servers::flight::v1::actions::CACHE_WARMUP;
 use crate::servers::flight::v1::actions::GET_PROFILE;
 use crate::servers::flight::v1::actions::INIT_QUERY_FRAGMENTS;
 use crate::servers::flight::v1::actions::KILL_QUERY;
@@ -131,6 +133,7 @@ pub fn flight_actions() -> FlightActions {
         .action(INIT_QUERY_FRAGMENTS, init_query_fragments)
         .action(START_PREPARED_QUERY, start_prepared_query)
         .action(TRUNCATE_TABLE, truncate_table)
+        .action(CACHE_WARMUP, cache_warmup)
         .action(KILL_QUERY, kill_query)
         .action(SET_PRIORITY, set_priority)
         .action(SYSTEM_ACTION, system_action)
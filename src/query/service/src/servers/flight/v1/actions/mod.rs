@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod cache_warmup;
 mod flight_actions;
 mod get_profile;
 mod init_query_env;
@@ -24,6 +25,8 @@ mod truncate_table;
 
 use std::sync::Arc;
 
+pub use cache_warmup::CacheWarmupRequest;
+pub use cache_warmup::CACHE_WARMUP;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::Result;
 use databend_common_settings::Settings;
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use databend_common_expression::RemoteExpr;
+use databend_common_expression::Scalar;
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum DataExchange {
@@ -35,6 +36,7 @@ impl DataExchange {
 pub struct ShuffleDataExchange {
     pub destination_ids: Vec<String>,
     pub shuffle_keys: Vec<RemoteExpr>,
+    pub hot_keys: Option<HotKeys>,
 }
 
 impl ShuffleDataExchange {
@@ -42,10 +44,20 @@ impl ShuffleDataExchange {
         DataExchange::ShuffleDataExchange(ShuffleDataExchange {
             destination_ids,
             shuffle_keys,
+            hot_keys: None,
         })
     }
 }
 
+/// The values of the shuffle key whose rows are not sent to the destination of their hash.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct HotKeys {
+    pub keys: Vec<Scalar>,
+    /// Send the rows with the hot keys to all the destinations if true, otherwise spread them
+    /// evenly across the destinations.
+    pub replicate: bool,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct MergeExchange {
     pub destination_id: String,
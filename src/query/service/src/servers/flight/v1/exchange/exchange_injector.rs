@@ -29,6 +29,7 @@ use crate::servers::flight::v1::exchange::ShuffleExchangeParams;
 use crate::servers::flight::v1::scatter::BroadcastFlightScatter;
 use crate::servers::flight::v1::scatter::FlightScatter;
 use crate::servers::flight::v1::scatter::HashFlightScatter;
+use crate::servers::flight::v1::scatter::HotKeysFlightScatter;
 use crate::sessions::QueryContext;
 
 pub trait ExchangeInjector: Send + Sync + 'static {
@@ -93,12 +94,22 @@ impl ExchangeInjector for DefaultExchangeInjector {
                     .iter()
                     .position(|x| x == local_id)
                     .unwrap();
-                HashFlightScatter::try_create(
-                    ctx.get_function_context()?,
-                    exchange.shuffle_keys.clone(),
-                    exchange.destination_ids.len(),
-                    local_pos,
-                )?
+                match &exchange.hot_keys {
+                    Some(hot_keys) if exchange.shuffle_keys.len() == 1 => {
+                        HotKeysFlightScatter::try_create(
+                            ctx.get_function_context()?,
+                            &exchange.shuffle_keys[0],
+                            hot_keys,
+                            exchange.destination_ids.len(),
+                        )?
+                    }
+                    _ => HashFlightScatter::try_create(
+                        ctx.get_function_context()?,
+                        exchange.shuffle_keys.clone(),
+                        exchange.destination_ids.len(),
+                        local_pos,
+                    )?,
+                }
             }
         }))
     }
@@ -31,6 +31,7 @@ pub mod serde;
 
 pub use data_exchange::BroadcastExchange;
 pub use data_exchange::DataExchange;
+pub use data_exchange::HotKeys;
 pub use data_exchange::MergeExchange;
 pub use data_exchange::ShuffleDataExchange;
 pub use exchange_injector::DefaultExchangeInjector;
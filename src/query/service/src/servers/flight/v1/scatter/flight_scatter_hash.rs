@@ -223,7 +223,7 @@ fn shuffle_by_block_id_in_merge_into(expr: &RemoteExpr) -> bool {
     false
 }
 
-fn get_hash_values(
+pub(super) fn get_hash_values(
     column: Value<AnyType>,
     rows: usize,
     default_scatter_index: u64,
@@ -0,0 +1,122 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::type_check::check_function;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::RemoteExpr;
+use databend_common_expression::Scalar;
+use databend_common_expression::Value;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+
+use crate::servers::flight::v1::exchange::HotKeys;
+use crate::servers::flight::v1::scatter::flight_scatter::FlightScatter;
+use crate::servers::flight::v1::scatter::flight_scatter_hash::get_hash_values;
+
+/// Scatter the rows by the hash of the shuffle key, except the rows with the hot keys of a
+/// skewed join, which are spread evenly across the destinations or sent to all of them.
+pub struct HotKeysFlightScatter {
+    func_ctx: FunctionContext,
+    hash_key: Expr,
+    // The hashes of the hot keys, the rows colliding with them are treated as hot rows, which
+    // is correct as long as both sides of the join treat them in the same way.
+    hot_hashes: HashSet<u64>,
+    replicate: bool,
+    scatter_size: usize,
+    // The destination of the next hot row to spread.
+    next_destination: AtomicUsize,
+}
+
+impl HotKeysFlightScatter {
+    pub fn try_create(
+        func_ctx: FunctionContext,
+        shuffle_key: &RemoteExpr,
+        hot_keys: &HotKeys,
+        scatter_size: usize,
+    ) -> Result<Box<dyn FlightScatter>> {
+        let hash = |expr: Expr| check_function(None, "siphash", &[], &[expr], &BUILTIN_FUNCTIONS);
+        let shuffle_key = shuffle_key.as_expr(&BUILTIN_FUNCTIONS);
+        let data_type = shuffle_key.data_type().clone();
+        let hash_key = hash(shuffle_key)?;
+
+        let block = DataBlock::new(vec![], 1);
+        let evaluator = Evaluator::new(&block, &func_ctx, &BUILTIN_FUNCTIONS);
+        let mut hot_hashes = HashSet::with_capacity(hot_keys.keys.len());
+        for key in &hot_keys.keys {
+            let expr = hash(Expr::Constant {
+                span: None,
+                scalar: key.clone(),
+                data_type: data_type.clone(),
+            })?;
+            match evaluator.run(&expr)? {
+                Value::Scalar(Scalar::Number(NumberScalar::UInt64(hash))) => {
+                    hot_hashes.insert(hash);
+                }
+                _ => {
+                    return Err(ErrorCode::Internal(format!(
+                        "Cannot hash the hot key {} of the shuffle",
+                        key
+                    )));
+                }
+            }
+        }
+
+        Ok(Box::new(HotKeysFlightScatter {
+            func_ctx,
+            hash_key,
+            hot_hashes,
+            replicate: hot_keys.replicate,
+            scatter_size,
+            next_destination: AtomicUsize::new(0),
+        }))
+    }
+}
+
+impl FlightScatter for HotKeysFlightScatter {
+    fn execute(&self, data_block: DataBlock) -> Result<Vec<DataBlock>> {
+        let evaluator = Evaluator::new(&data_block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let num_rows = data_block.num_rows();
+        let hashes = get_hash_values(evaluator.run(&self.hash_key)?, num_rows, 0)?;
+
+        let mut indices = vec![Vec::<u32>::new(); self.scatter_size];
+        for (row, hash) in hashes.iter().enumerate() {
+            let row = row as u32;
+            if !self.hot_hashes.contains(hash) {
+                indices[(*hash % self.scatter_size as u64) as usize].push(row);
+            } else if self.replicate {
+                indices.iter_mut().for_each(|indices| indices.push(row));
+            } else {
+                let destination = self.next_destination.fetch_add(1, Ordering::Relaxed);
+                indices[destination % self.scatter_size].push(row);
+            }
+        }
+
+        let block_meta = data_block.get_meta();
+        let mut res = Vec::with_capacity(self.scatter_size);
+        for indices in indices {
+            let block = data_block.take(&indices)?;
+            res.push(block.add_meta(block_meta.cloned())?);
+        }
+        Ok(res)
+    }
+}
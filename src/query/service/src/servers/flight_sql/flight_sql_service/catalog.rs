@@ -16,15 +16,20 @@ use std::sync::Arc;
 
 use arrow_array::builder::StringBuilder;
 use arrow_array::ArrayRef;
+use arrow_array::Int32Array;
 use arrow_array::RecordBatch;
+use arrow_array::StringArray;
+use arrow_array::UInt8Array;
 use arrow_flight::utils::batches_to_flight_data;
 use arrow_schema::DataType;
 use arrow_schema::Field;
 use arrow_schema::Schema;
 use databend_common_catalog::catalog::Catalog;
 use databend_common_catalog::catalog::CatalogManager;
+use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
+use databend_common_meta_app::schema::TableConstraint;
 use futures_util::stream;
 use log::warn;
 use tonic::Status;
@@ -126,6 +131,155 @@ impl CatalogInfoProvider {
         Self::batch_to_get_stream(batch)
     }
 
+    /// Resolves the table of a key metadata command, the catalog and the database default to
+    /// the current ones of the session.
+    async fn resolve_table(
+        ctx: &Arc<dyn TableContext>,
+        catalog_name: Option<String>,
+        database_name: Option<String>,
+        table_name: &str,
+    ) -> databend_common_exception::Result<(String, String, Arc<dyn Table>)> {
+        let catalog_name = catalog_name.unwrap_or_else(|| ctx.get_current_catalog());
+        let database_name = database_name.unwrap_or_else(|| ctx.get_current_database());
+        let table = ctx
+            .get_table(&catalog_name, &database_name, table_name)
+            .await?;
+        Ok((catalog_name, database_name, table))
+    }
+
+    pub(crate) async fn get_primary_keys(
+        ctx: Arc<dyn TableContext>,
+        catalog_name: Option<String>,
+        database_name: Option<String>,
+        table_name: String,
+    ) -> Result<DoGetStream, Status> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("catalog_name", DataType::Utf8, true),
+            Field::new("db_schema_name", DataType::Utf8, true),
+            Field::new("table_name", DataType::Utf8, false),
+            Field::new("column_name", DataType::Utf8, false),
+            Field::new("key_name", DataType::Utf8, true),
+            Field::new("key_sequence", DataType::Int32, false),
+        ]));
+        let (catalog_name, database_name, table) =
+            Self::resolve_table(&ctx, catalog_name, database_name, &table_name)
+                .await
+                .map_err(|e| Status::internal(format!("{e:?}")))?;
+
+        let mut columns = vec![];
+        let mut key_names = vec![];
+        let mut key_sequences = vec![];
+        for (name, constraint) in table.get_table_info().meta.constraints.iter() {
+            if let TableConstraint::PrimaryKey(key) = constraint {
+                for (i, column) in key.iter().enumerate() {
+                    columns.push(column.clone());
+                    key_names.push(name.clone());
+                    key_sequences.push(i as i32 + 1);
+                }
+            }
+        }
+        let num_rows = columns.len();
+        let batch = RecordBatch::try_new(schema, vec![
+            Self::string_array(vec![catalog_name; num_rows]),
+            Self::string_array(vec![database_name; num_rows]),
+            Self::string_array(vec![table.name().to_string(); num_rows]),
+            Self::string_array(columns),
+            Self::string_array(key_names),
+            Arc::new(Int32Array::from(key_sequences)),
+        ])
+        .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    /// The foreign keys of the table, which the table imports from the tables it references.
+    pub(crate) async fn get_imported_keys(
+        ctx: Arc<dyn TableContext>,
+        catalog_name: Option<String>,
+        database_name: Option<String>,
+        table_name: String,
+    ) -> Result<DoGetStream, Status> {
+        let mut keys = ForeignKeys::default();
+        async {
+            let (catalog_name, database_name, table) =
+                Self::resolve_table(&ctx, catalog_name, database_name, &table_name).await?;
+            keys.push_table(&ctx, &catalog_name, &database_name, table.as_ref(), None)
+                .await
+        }
+        .await
+        .map_err(|e| Status::internal(format!("{e:?}")))?;
+        Self::batch_to_get_stream(keys.into_batch()?)
+    }
+
+    /// The foreign keys of all the tables in the catalog referencing the table.
+    pub(crate) async fn get_exported_keys(
+        ctx: Arc<dyn TableContext>,
+        catalog_name: Option<String>,
+        database_name: Option<String>,
+        table_name: String,
+    ) -> Result<DoGetStream, Status> {
+        let mut keys = ForeignKeys::default();
+        async {
+            let (catalog_name, database_name, table) =
+                Self::resolve_table(&ctx, catalog_name, database_name, &table_name).await?;
+            let referenced = (database_name, table.name().to_string());
+            let tenant = ctx.get_tenant();
+            let catalog = ctx.get_catalog(&catalog_name).await?;
+            for db in catalog.list_databases(&tenant).await? {
+                for table in catalog.list_tables(&tenant, db.name()).await? {
+                    keys.push_table(
+                        &ctx,
+                        &catalog_name,
+                        db.name(),
+                        table.as_ref(),
+                        Some(&referenced),
+                    )
+                    .await?;
+                }
+            }
+            Ok::<_, ErrorCode>(())
+        }
+        .await
+        .map_err(|e| Status::internal(format!("{e:?}")))?;
+        Self::batch_to_get_stream(keys.into_batch()?)
+    }
+
+    /// The foreign keys of the foreign key table referencing the primary key table.
+    pub(crate) async fn get_cross_reference(
+        ctx: Arc<dyn TableContext>,
+        pk_catalog_name: Option<String>,
+        pk_database_name: Option<String>,
+        pk_table_name: String,
+        fk_catalog_name: Option<String>,
+        fk_database_name: Option<String>,
+        fk_table_name: String,
+    ) -> Result<DoGetStream, Status> {
+        let mut keys = ForeignKeys::default();
+        async {
+            let (pk_catalog_name, pk_database_name, pk_table) =
+                Self::resolve_table(&ctx, pk_catalog_name, pk_database_name, &pk_table_name)
+                    .await?;
+            let (fk_catalog_name, fk_database_name, fk_table) =
+                Self::resolve_table(&ctx, fk_catalog_name, fk_database_name, &fk_table_name)
+                    .await?;
+            // a table only references the tables in the same catalog
+            if pk_catalog_name == fk_catalog_name {
+                let referenced = (pk_database_name, pk_table.name().to_string());
+                keys.push_table(
+                    &ctx,
+                    &fk_catalog_name,
+                    &fk_database_name,
+                    fk_table.as_ref(),
+                    Some(&referenced),
+                )
+                .await?;
+            }
+            Ok::<_, ErrorCode>(())
+        }
+        .await
+        .map_err(|e| Status::internal(format!("{e:?}")))?;
+        Self::batch_to_get_stream(keys.into_batch()?)
+    }
+
     fn string_array(values: Vec<String>) -> ArrayRef {
         let mut builder = StringBuilder::new();
         for v in &values {
@@ -134,3 +288,114 @@ impl CatalogInfoProvider {
         Arc::new(builder.finish())
     }
 }
+
+/// The rows of the result of the foreign key metadata commands, one for each column of the keys.
+#[derive(Default)]
+struct ForeignKeys {
+    pk_catalog_name: Vec<String>,
+    pk_db_schema_name: Vec<String>,
+    pk_table_name: Vec<String>,
+    pk_column_name: Vec<String>,
+    fk_catalog_name: Vec<String>,
+    fk_db_schema_name: Vec<String>,
+    fk_table_name: Vec<String>,
+    fk_column_name: Vec<String>,
+    key_sequence: Vec<i32>,
+    fk_key_name: Vec<String>,
+    pk_key_name: Vec<Option<String>>,
+}
+
+impl ForeignKeys {
+    // The constraints are not enforced, so nothing is done on update or delete.
+    const NO_ACTION: u8 = 3;
+
+    /// Adds the foreign keys of `table`, only the ones referencing `referenced` if it is set.
+    async fn push_table(
+        &mut self,
+        ctx: &Arc<dyn TableContext>,
+        catalog_name: &str,
+        database_name: &str,
+        table: &dyn Table,
+        referenced: Option<&(String, String)>,
+    ) -> databend_common_exception::Result<()> {
+        for (name, constraint) in table.get_table_info().meta.constraints.iter() {
+            let TableConstraint::ForeignKey {
+                columns,
+                ref_database,
+                ref_table,
+                ref_columns,
+            } = constraint
+            else {
+                continue;
+            };
+            if referenced.is_some_and(|(db, name)| db != ref_database || name != ref_table) {
+                continue;
+            }
+            // the name of the referenced key, unknown if the referenced table is gone
+            let pk_key_name = match ctx.get_table(catalog_name, ref_database, ref_table).await {
+                Ok(ref_table) => ref_table
+                    .get_table_info()
+                    .meta
+                    .constraints
+                    .iter()
+                    .find(|(_, c)| match c {
+                        TableConstraint::PrimaryKey(key) | TableConstraint::Unique(key) => {
+                            key == ref_columns
+                        }
+                        _ => false,
+                    })
+                    .map(|(name, _)| name.clone()),
+                Err(_) => None,
+            };
+            for (i, (column, ref_column)) in columns.iter().zip(ref_columns.iter()).enumerate() {
+                self.pk_catalog_name.push(catalog_name.to_string());
+                self.pk_db_schema_name.push(ref_database.clone());
+                self.pk_table_name.push(ref_table.clone());
+                self.pk_column_name.push(ref_column.clone());
+                self.fk_catalog_name.push(catalog_name.to_string());
+                self.fk_db_schema_name.push(database_name.to_string());
+                self.fk_table_name.push(table.name().to_string());
+                self.fk_column_name.push(column.clone());
+                self.key_sequence.push(i as i32 + 1);
+                self.fk_key_name.push(name.clone());
+                self.pk_key_name.push(pk_key_name.clone());
+            }
+        }
+        Ok(())
+    }
+
+    fn into_batch(self) -> Result<RecordBatch, Status> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("pk_catalog_name", DataType::Utf8, true),
+            Field::new("pk_db_schema_name", DataType::Utf8, true),
+            Field::new("pk_table_name", DataType::Utf8, false),
+            Field::new("pk_column_name", DataType::Utf8, false),
+            Field::new("fk_catalog_name", DataType::Utf8, true),
+            Field::new("fk_db_schema_name", DataType::Utf8, true),
+            Field::new("fk_table_name", DataType::Utf8, false),
+            Field::new("fk_column_name", DataType::Utf8, false),
+            Field::new("key_sequence", DataType::Int32, false),
+            Field::new("fk_key_name", DataType::Utf8, true),
+            Field::new("pk_key_name", DataType::Utf8, true),
+            Field::new("update_rule", DataType::UInt8, false),
+            Field::new("delete_rule", DataType::UInt8, false),
+        ]));
+        let num_rows = self.key_sequence.len();
+        RecordBatch::try_new(schema, vec![
+            CatalogInfoProvider::string_array(self.pk_catalog_name),
+            CatalogInfoProvider::string_array(self.pk_db_schema_name),
+            CatalogInfoProvider::string_array(self.pk_table_name),
+            CatalogInfoProvider::string_array(self.pk_column_name),
+            CatalogInfoProvider::string_array(self.fk_catalog_name),
+            CatalogInfoProvider::string_array(self.fk_db_schema_name),
+            CatalogInfoProvider::string_array(self.fk_table_name),
+            CatalogInfoProvider::string_array(self.fk_column_name),
+            Arc::new(Int32Array::from(self.key_sequence)),
+            CatalogInfoProvider::string_array(self.fk_key_name),
+            Arc::new(StringArray::from(self.pk_key_name)),
+            Arc::new(UInt8Array::from(vec![Self::NO_ACTION; num_rows])),
+            Arc::new(UInt8Array::from(vec![Self::NO_ACTION; num_rows])),
+        ])
+        .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))
+    }
+}
@@ -329,48 +329,44 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn get_flight_info_primary_keys(
         &self,
         query: CommandGetPrimaryKeys,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> std::result::Result<Response<FlightInfo>, Status> {
-        info!("get_flight_info_primary_keys({query:?})",);
-        Err(Status::unimplemented(
-            "get_flight_info_primary_keys not implemented",
-        ))
+        info!("get_flight_info_primary_keys({query:?})");
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     #[async_backtrace::framed]
     async fn get_flight_info_exported_keys(
         &self,
         query: CommandGetExportedKeys,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> std::result::Result<Response<FlightInfo>, Status> {
         info!("get_flight_info_exported_keys({query:?})");
-        Err(Status::unimplemented(
-            "get_flight_info_exported_keys not implemented",
-        ))
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     #[async_backtrace::framed]
     async fn get_flight_info_imported_keys(
         &self,
         query: CommandGetImportedKeys,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> std::result::Result<Response<FlightInfo>, Status> {
         info!("get_flight_info_imported_keys({query:?})");
-        Err(Status::unimplemented(
-            "get_flight_info_imported_keys not implemented",
-        ))
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     #[async_backtrace::framed]
     async fn get_flight_info_cross_reference(
         &self,
         query: CommandGetCrossReference,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> std::result::Result<Response<FlightInfo>, Status> {
         info!("get_flight_info_cross_reference({query:?})");
-        Err(Status::unimplemented(
-            "get_flight_info_imported_keys not implemented",
-        ))
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     // do_get
@@ -458,21 +454,45 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_primary_keys(
         &self,
         query: CommandGetPrimaryKeys,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> std::result::Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_primary_keys({query:?})");
-        Err(Status::unimplemented("do_get_primary_keys not implemented"))
+        let session = self.get_session(&request)?;
+        let context = session
+            .create_query_context()
+            .await
+            .map_err(|e| status!("Could not create_query_context", e))?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_primary_keys(
+                context,
+                query.catalog,
+                query.db_schema,
+                query.table,
+            )
+            .await?,
+        ))
     }
 
     #[async_backtrace::framed]
     async fn do_get_exported_keys(
         &self,
         query: CommandGetExportedKeys,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> std::result::Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_exported_keys({query:?})");
-        Err(Status::unimplemented(
-            "do_get_exported_keys not implemented",
+        let session = self.get_session(&request)?;
+        let context = session
+            .create_query_context()
+            .await
+            .map_err(|e| status!("Could not create_query_context", e))?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_exported_keys(
+                context,
+                query.catalog,
+                query.db_schema,
+                query.table,
+            )
+            .await?,
         ))
     }
 
@@ -480,11 +500,22 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_imported_keys(
         &self,
         query: CommandGetImportedKeys,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> std::result::Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_imported_keys({query:?})");
-        Err(Status::unimplemented(
-            "do_get_imported_keys not implemented",
+        let session = self.get_session(&request)?;
+        let context = session
+            .create_query_context()
+            .await
+            .map_err(|e| status!("Could not create_query_context", e))?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_imported_keys(
+                context,
+                query.catalog,
+                query.db_schema,
+                query.table,
+            )
+            .await?,
         ))
     }
 
@@ -492,11 +523,25 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_cross_reference(
         &self,
         query: CommandGetCrossReference,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> std::result::Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_cross_reference({query:?})");
-        Err(Status::unimplemented(
-            "do_get_cross_reference not implemented",
+        let session = self.get_session(&request)?;
+        let context = session
+            .create_query_context()
+            .await
+            .map_err(|e| status!("Could not create_query_context", e))?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_cross_reference(
+                context,
+                query.pk_catalog,
+                query.pk_db_schema,
+                query.pk_table,
+                query.fk_catalog,
+                query.fk_db_schema,
+                query.fk_table,
+            )
+            .await?,
         ))
     }
 
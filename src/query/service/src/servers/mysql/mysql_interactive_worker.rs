@@ -47,6 +47,7 @@ use opensrv_mysql::StatementMetaWriter;
 use rand::RngCore;
 use uuid::Uuid;
 
+use crate::history_tables::HistoryLog;
 use crate::interpreters::interpreter_plan_sql;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterFactory;
@@ -116,8 +117,22 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
         let client_addr = self.client_addr.clone();
         let info = CertifiedInfo::create(&username, auth_data, &client_addr);
 
-        let authenticate = self.base.authenticate(salt, info);
-        match authenticate.await {
+        let authenticate = self.base.authenticate(salt, info).await;
+        if let Some(history) = HistoryLog::instance() {
+            let result = match &authenticate {
+                Ok(true) => Ok(()),
+                Ok(false) => Err(ErrorCode::AuthenticateFailure("wrong password")),
+                Err(failure) => Err(failure.clone()),
+            };
+            history.record_login(
+                self.base.session.get_type().to_string(),
+                "Password".to_string(),
+                username.to_string(),
+                client_addr.clone(),
+                result.as_ref().map(|_| ()),
+            );
+        }
+        match authenticate {
             Ok(res) => res,
             Err(failure) => {
                 error!(
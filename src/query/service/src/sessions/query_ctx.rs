@@ -91,6 +91,7 @@ use databend_common_pipeline_core::InputError;
 use databend_common_pipeline_core::LockGuard;
 use databend_common_settings::Settings;
 use databend_common_sql::IndexType;
+use databend_common_sql::QueryLineage;
 use databend_common_storage::CopyStatus;
 use databend_common_storage::DataOperator;
 use databend_common_storage::FileStatus;
@@ -358,6 +359,23 @@ impl QueryContext {
         self.shared.created_time
     }
 
+    pub fn attach_plan_baseline(&self, plan_fingerprint: String, plan_baseline: String) {
+        self.shared
+            .attach_plan_baseline(plan_fingerprint, plan_baseline);
+    }
+
+    pub fn get_plan_baseline(&self) -> (String, String) {
+        self.shared.get_plan_baseline()
+    }
+
+    pub fn attach_query_lineage(&self, lineage: QueryLineage) {
+        self.shared.attach_query_lineage(lineage);
+    }
+
+    pub fn get_query_lineage(&self) -> Option<QueryLineage> {
+        self.shared.get_query_lineage()
+    }
+
     pub fn set_finish_time(&self, time: SystemTime) {
         *self.shared.finish_time.write() = Some(time)
     }
@@ -48,6 +48,7 @@ use databend_common_pipeline_core::processors::PlanProfile;
 use databend_common_pipeline_core::InputError;
 use databend_common_settings::Settings;
 use databend_common_sql::IndexType;
+use databend_common_sql::QueryLineage;
 use databend_common_storage::CopyStatus;
 use databend_common_storage::DataOperator;
 use databend_common_storage::MultiTableInsertStatus;
@@ -99,6 +100,10 @@ pub struct QueryContextShared {
     pub(in crate::sessions) running_query_kind: Arc<RwLock<Option<QueryKind>>>,
     pub(in crate::sessions) running_query_text_hash: Arc<RwLock<Option<String>>>,
     pub(in crate::sessions) running_query_parameterized_hash: Arc<RwLock<Option<String>>>,
+    /// The plan fingerprint of the running query, with how its plan baseline is used.
+    pub(in crate::sessions) running_query_plan_baseline: Arc<RwLock<Option<(String, String)>>>,
+    /// The objects accessed by the running query and its column lineage.
+    pub(in crate::sessions) running_query_lineage: Arc<RwLock<Option<QueryLineage>>>,
     pub(in crate::sessions) aborting: Arc<AtomicBool>,
     pub(in crate::sessions) tables_refs: Arc<Mutex<HashMap<DatabaseAndTable, Arc<dyn Table>>>>,
     pub(in crate::sessions) streams_refs: Arc<RwLock<HashMap<DatabaseAndTable, bool>>>,
@@ -176,6 +181,8 @@ impl QueryContextShared {
             running_query_kind: Arc::new(RwLock::new(None)),
             running_query_text_hash: Arc::new(RwLock::new(None)),
             running_query_parameterized_hash: Arc::new(RwLock::new(None)),
+            running_query_plan_baseline: Arc::new(RwLock::new(None)),
+            running_query_lineage: Arc::new(RwLock::new(None)),
             aborting: Arc::new(AtomicBool::new(false)),
             tables_refs: Arc::new(Mutex::new(HashMap::new())),
             streams_refs: Default::default(),
@@ -584,6 +591,26 @@ impl QueryContextShared {
             .clone()
     }
 
+    pub fn attach_plan_baseline(&self, plan_fingerprint: String, plan_baseline: String) {
+        *self.running_query_plan_baseline.write() = Some((plan_fingerprint, plan_baseline));
+    }
+
+    /// Returns the plan fingerprint of the running query and how its plan baseline is used.
+    pub fn get_plan_baseline(&self) -> (String, String) {
+        self.running_query_plan_baseline
+            .read()
+            .clone()
+            .unwrap_or_default()
+    }
+
+    pub fn attach_query_lineage(&self, lineage: QueryLineage) {
+        *self.running_query_lineage.write() = Some(lineage);
+    }
+
+    pub fn get_query_lineage(&self) -> Option<QueryLineage> {
+        self.running_query_lineage.read().clone()
+    }
+
     pub fn get_query_kind(&self) -> QueryKind {
         let running_query_kind = self.running_query_kind.read();
         running_query_kind
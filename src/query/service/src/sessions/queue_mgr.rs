@@ -411,8 +411,10 @@ impl QueryEntry {
             | Plan::VacuumTable(_)
             | Plan::VacuumTemporaryFiles(_)
             | Plan::RefreshIndex(_)
+            | Plan::RefreshMaterializedView(_)
             | Plan::ReclusterTable { .. }
-            | Plan::TruncateTable(_) => {
+            | Plan::TruncateTable(_)
+            | Plan::CacheWarmupTable(_) => {
                 return true;
             }
             Plan::DropTable(v) if v.all => {
@@ -18,5 +18,6 @@ mod spiller;
 
 pub use partition_buffer::PartitionBuffer;
 pub use partition_buffer::PartitionBufferFetchOption;
+pub use serialize::AggregateSpillEncoding;
 pub use serialize::Layout;
 pub use spiller::*;
@@ -13,14 +13,24 @@
 // limitations under the License.
 
 use std::io::Write;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::sync::LazyLock;
 
+use aes_gcm::aead::Aead;
+use aes_gcm::aead::KeyInit;
+use aes_gcm::aead::OsRng;
+use aes_gcm::Aes256Gcm;
+use aes_gcm::Nonce;
 use arrow_schema::Schema;
 use buf_list::BufList;
 use buf_list::Cursor;
 use bytes::Buf;
+use bytes::Bytes;
 use databend_common_base::base::Alignment;
 use databend_common_base::base::DmaWriteBuf;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::arrow::read_column;
 use databend_common_expression::arrow::write_column;
@@ -48,52 +58,49 @@ pub enum Layout {
 
 pub(super) struct BlocksEncoder {
     pub(super) use_parquet: bool,
+    pub(super) codec: SpillCodec,
     pub(super) buf: DmaWriteBuf,
     pub(super) offsets: Vec<usize>,
     pub(super) columns_layout: Vec<Layout>,
+    /// The bytes of the serialized blocks before they are encoded by the codec.
+    pub(super) plain_size: usize,
 }
 
 impl BlocksEncoder {
-    pub(super) fn new(use_parquet: bool, align: Alignment, chunk: usize) -> Self {
+    pub(super) fn new(
+        use_parquet: bool,
+        codec: SpillCodec,
+        align: Alignment,
+        chunk: usize,
+    ) -> Self {
         Self {
             use_parquet,
+            codec,
             buf: DmaWriteBuf::new(align, chunk),
             offsets: vec![0],
             columns_layout: Vec::new(),
+            plain_size: 0,
         }
     }
 
-    pub(super) fn add_blocks(&mut self, mut blocks: Vec<DataBlock>) {
-        let layout = if self.use_parquet {
-            // Currently we splice multiple complete parquet files into one,
-            // so that the file contains duplicate headers/footers and metadata,
-            // which can lead to file bloat. A better approach would be for the entire file to be ONE parquet,
-            // with each group of blocks (i.e. Chunk) corresponding to one or more row groupsx
-            bare_blocks_to_parquet(blocks, &mut self.buf).unwrap();
-            Layout::Parquet
+    pub(super) fn add_blocks(&mut self, blocks: Vec<DataBlock>) -> Result<()> {
+        let layout = if self.codec.is_plain() {
+            let start = self.size();
+            let layout = write_blocks(self.use_parquet, blocks, &mut self.buf);
+            self.plain_size += self.size() - start;
+            layout
         } else {
-            let block = if blocks.len() == 1 {
-                blocks.remove(0)
-            } else {
-                DataBlock::concat(&blocks).unwrap()
-            };
-            let columns_layout = std::iter::once(self.size())
-                .chain(block.columns().iter().map(|entry| {
-                    let column = entry
-                        .value
-                        .convert_to_full_column(&entry.data_type, block.num_rows());
-                    write_column(&column, &mut self.buf).unwrap();
-                    self.size()
-                }))
-                .map_windows(|x: &[_; 2]| x[1] - x[0])
-                .collect::<Vec<_>>()
-                .into_boxed_slice();
-
-            Layout::ArrowIpc(columns_layout)
+            // Each group of blocks is encoded independently, so that it can be read by its range.
+            let mut data = Vec::new();
+            let layout = write_blocks(self.use_parquet, blocks, &mut data);
+            self.plain_size += data.len();
+            self.codec.encode(data, &mut self.buf)?;
+            layout
         };
 
         self.columns_layout.push(layout);
-        self.offsets.push(self.size())
+        self.offsets.push(self.size());
+        Ok(())
     }
 
     pub(super) fn size(&self) -> usize {
@@ -101,6 +108,196 @@ impl BlocksEncoder {
     }
 }
 
+trait SpillBuf: Write {
+    fn size(&self) -> usize;
+}
+
+impl SpillBuf for DmaWriteBuf {
+    fn size(&self) -> usize {
+        DmaWriteBuf::size(self)
+    }
+}
+
+impl SpillBuf for Vec<u8> {
+    fn size(&self) -> usize {
+        self.len()
+    }
+}
+
+fn write_blocks<W: SpillBuf + Send>(
+    use_parquet: bool,
+    mut blocks: Vec<DataBlock>,
+    buf: &mut W,
+) -> Layout {
+    if use_parquet {
+        // Currently we splice multiple complete parquet files into one,
+        // so that the file contains duplicate headers/footers and metadata,
+        // which can lead to file bloat. A better approach would be for the entire file to be ONE parquet,
+        // with each group of blocks (i.e. Chunk) corresponding to one or more row groupsx
+        bare_blocks_to_parquet(blocks, &mut *buf).unwrap();
+        Layout::Parquet
+    } else {
+        let block = if blocks.len() == 1 {
+            blocks.remove(0)
+        } else {
+            DataBlock::concat(&blocks).unwrap()
+        };
+        let columns_layout = std::iter::once(buf.size())
+            .chain(block.columns().iter().map(|entry| {
+                let column = entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, block.num_rows());
+                write_column(&column, buf).unwrap();
+                buf.size()
+            }))
+            .map_windows(|x: &[_; 2]| x[1] - x[0])
+            .collect::<Vec<_>>()
+            .into_boxed_slice();
+
+        Layout::ArrowIpc(columns_layout)
+    }
+}
+
+const SPILL_ZSTD_LEVEL: i32 = 3;
+const SPILL_NONCE_SIZE: usize = 12;
+
+// The key is generated when the process spills the first encrypted data and is never persisted,
+// the spill files can not be read after the process exits.
+static SPILL_CIPHER: LazyLock<Aes256Gcm> =
+    LazyLock::new(|| Aes256Gcm::new(&Aes256Gcm::generate_key(OsRng)));
+// The nonces are never reused with the key of the process.
+static SPILL_NONCE: AtomicU64 = AtomicU64::new(0);
+
+/// How the serialized blocks are encoded in the spill files.
+///
+/// An encoded chunk is the zstd frame of the serialized blocks if compressed, prefixed with the
+/// nonce and followed by the tag of AES-256-GCM if encrypted.
+#[derive(Debug, Clone, Copy, Default)]
+pub(super) struct SpillCodec {
+    pub(super) zstd: bool,
+    pub(super) encryption: bool,
+}
+
+impl SpillCodec {
+    pub(super) fn is_plain(&self) -> bool {
+        !self.zstd && !self.encryption
+    }
+
+    fn cipher(&self) -> Option<&'static Aes256Gcm> {
+        self.encryption.then(|| &*SPILL_CIPHER)
+    }
+
+    fn encode(&self, data: Vec<u8>, buf: &mut impl Write) -> Result<()> {
+        encode_chunk(self.zstd, self.cipher(), data, buf)
+    }
+
+    pub(super) fn decode(&self, data: Buffer) -> Result<Buffer> {
+        if self.is_plain() {
+            return Ok(data);
+        }
+        decode_chunk(self.zstd, self.cipher(), data)
+    }
+}
+
+/// How the buckets of an aggregate spill file are encoded.
+///
+/// The aggregate spill files may be read by the other nodes of the cluster, so they are encrypted
+/// with an ephemeral key of each file instead of the key of the process. The key is only kept in
+/// memory with the locations of the spilled buckets.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct AggregateSpillEncoding {
+    pub zstd: bool,
+    pub key: Option<Vec<u8>>,
+}
+
+impl AggregateSpillEncoding {
+    pub(super) fn new(codec: SpillCodec) -> Self {
+        Self {
+            zstd: codec.zstd,
+            key: codec
+                .encryption
+                .then(|| Aes256Gcm::generate_key(OsRng).to_vec()),
+        }
+    }
+
+    pub fn is_plain(&self) -> bool {
+        !self.zstd && self.key.is_none()
+    }
+
+    fn cipher(&self) -> Result<Option<Aes256Gcm>> {
+        self.key
+            .as_ref()
+            .map(|key| {
+                Aes256Gcm::new_from_slice(key)
+                    .map_err(|_| ErrorCode::Internal("Invalid key of the aggregate spill file"))
+            })
+            .transpose()
+    }
+
+    pub(super) fn encode(&self, data: Vec<u8>, buf: &mut impl Write) -> Result<()> {
+        encode_chunk(self.zstd, self.cipher()?.as_ref(), data, buf)
+    }
+
+    /// Decode the data of a spilled bucket.
+    pub fn decode(&self, data: Buffer) -> Result<Buffer> {
+        if self.is_plain() {
+            return Ok(data);
+        }
+        decode_chunk(self.zstd, self.cipher()?.as_ref(), data)
+    }
+}
+
+fn encode_chunk(
+    zstd: bool,
+    cipher: Option<&Aes256Gcm>,
+    data: Vec<u8>,
+    buf: &mut impl Write,
+) -> Result<()> {
+    let data = if zstd {
+        zstd::bulk::compress(&data, SPILL_ZSTD_LEVEL)?
+    } else {
+        data
+    };
+
+    if let Some(cipher) = cipher {
+        let mut nonce = [0; SPILL_NONCE_SIZE];
+        nonce[..8].copy_from_slice(&SPILL_NONCE.fetch_add(1, Ordering::Relaxed).to_le_bytes());
+        let ciphertext = cipher
+            .encrypt(Nonce::from_slice(&nonce), data.as_slice())
+            .map_err(|_| ErrorCode::Internal("Failed to encrypt the spilled data"))?;
+        buf.write_all(&nonce)?;
+        buf.write_all(&ciphertext)?;
+    } else {
+        buf.write_all(&data)?;
+    }
+    Ok(())
+}
+
+fn decode_chunk(zstd: bool, cipher: Option<&Aes256Gcm>, data: Buffer) -> Result<Buffer> {
+    let data = if let Some(cipher) = cipher {
+        if data.len() < SPILL_NONCE_SIZE {
+            return Err(ErrorCode::Internal(
+                "The encrypted spilled data is truncated",
+            ));
+        }
+        let nonce = data.slice(0..SPILL_NONCE_SIZE).to_bytes();
+        let ciphertext = data.slice(SPILL_NONCE_SIZE..data.len()).to_bytes();
+        let plaintext = cipher
+            .decrypt(Nonce::from_slice(&nonce), ciphertext.as_ref())
+            .map_err(|_| ErrorCode::Internal("Failed to decrypt the spilled data"))?;
+        Bytes::from(plaintext)
+    } else {
+        data.to_bytes()
+    };
+
+    let data = if zstd {
+        Bytes::from(zstd::stream::decode_all(data.as_ref())?)
+    } else {
+        data
+    };
+    Ok(Buffer::from(data))
+}
+
 pub(super) fn deserialize_block(columns_layout: &Layout, mut data: Buffer) -> DataBlock {
     match columns_layout {
         Layout::ArrowIpc(layout) => {
@@ -209,7 +406,7 @@ impl ChunkReader for Reader {
 
 #[cfg(test)]
 mod tests {
-    use bytes::Bytes;
+    use databend_common_base::base::dma_buffer_to_bytes;
     use databend_common_expression::block_debug::assert_block_value_eq;
     use databend_common_expression::types::Int64Type;
     use databend_common_expression::types::StringType;
@@ -247,4 +444,95 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_codec_chunks() -> Result<()> {
+        let blocks = [
+            [
+                StringType::from_data(vec!["SM CASE", "a"]),
+                Int64Type::from_data(vec![1, 3]),
+            ],
+            [
+                StringType::from_data(vec!["b", "e"]),
+                Int64Type::from_data(vec![99, 7]),
+            ],
+        ]
+        .into_iter()
+        .map(|columns| DataBlock::new_from_columns(columns.to_vec()))
+        .collect::<Vec<_>>();
+
+        for use_parquet in [false, true] {
+            for (zstd, encryption) in [(false, false), (true, false), (false, true), (true, true)] {
+                let codec = SpillCodec { zstd, encryption };
+                let mut encoder = BlocksEncoder::new(use_parquet, codec, Alignment::MIN, 1024);
+                for block in &blocks {
+                    encoder.add_blocks(vec![block.clone()])?;
+                }
+                if codec.is_plain() {
+                    assert_eq!(encoder.plain_size, encoder.size());
+                }
+
+                let BlocksEncoder {
+                    buf,
+                    offsets,
+                    columns_layout,
+                    ..
+                } = encoder;
+                let data = buf
+                    .into_data()
+                    .into_iter()
+                    .map(dma_buffer_to_bytes)
+                    .collect::<Buffer>();
+                if encryption {
+                    assert!(!data.to_vec().windows(7).any(|w| w == b"SM CASE"));
+                }
+
+                for ((range, layout), want) in offsets
+                    .windows(2)
+                    .map(|x| x[0]..x[1])
+                    .zip(columns_layout.iter())
+                    .zip(blocks.iter())
+                {
+                    let chunk = codec.decode(data.slice(range))?;
+                    let got = deserialize_block(layout, chunk);
+                    assert_block_value_eq(want, &got);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_aggregate_spill_encoding() -> Result<()> {
+        let data = b"SM CASE".repeat(16);
+        for (zstd, encryption) in [(false, false), (true, false), (false, true), (true, true)] {
+            let encoding = AggregateSpillEncoding::new(SpillCodec { zstd, encryption });
+            assert_eq!(encoding.key.is_some(), encryption);
+
+            let mut buf = Vec::new();
+            encoding.encode(data.clone(), &mut buf)?;
+            if encryption {
+                assert!(!buf.windows(7).any(|w| w == b"SM CASE"));
+            }
+
+            let got = encoding.decode(Buffer::from(Bytes::from(buf)))?;
+            assert_eq!(got.to_vec(), data);
+        }
+
+        // The other files can not be decoded with the key of a file.
+        let encoding = AggregateSpillEncoding::new(SpillCodec {
+            zstd: false,
+            encryption: true,
+        });
+        let mut buf = Vec::new();
+        encoding.encode(data.clone(), &mut buf)?;
+        let other = AggregateSpillEncoding::new(SpillCodec {
+            zstd: false,
+            encryption: true,
+        });
+        assert!(other.decode(Buffer::from(Bytes::from(buf))).is_err());
+
+        Ok(())
+    }
 }
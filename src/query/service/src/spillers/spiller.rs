@@ -31,6 +31,7 @@ use databend_common_base::runtime::profile::ProfileStatisticsName;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
+use databend_common_settings::SpillCompression;
 use databend_storages_common_cache::TempDir;
 use databend_storages_common_cache::TempPath;
 use opendal::Buffer;
@@ -92,6 +93,7 @@ pub struct Spiller {
     // for dio disabled
     local_operator: Option<Operator>,
     use_parquet: bool,
+    codec: SpillCodec,
     _spiller_type: SpillerType,
 
     // Stores the spilled files that controlled by current spiller
@@ -126,6 +128,12 @@ impl Spiller {
             None => (None, None),
         };
 
+        // The parquet format is compressed by itself.
+        let codec = SpillCodec {
+            zstd: !use_parquet && settings.get_spilling_compression()? == SpillCompression::Zstd,
+            encryption: settings.get_enable_spilling_encryption()?,
+        };
+
         Ok(Self {
             ctx,
             operator,
@@ -133,6 +141,7 @@ impl Spiller {
             temp_dir,
             local_operator,
             use_parquet,
+            codec,
             _spiller_type: spiller_type,
             private_spilled_files: Default::default(),
             join_spilling_partition_bits: settings.get_join_spilling_partition_bits()?,
@@ -167,18 +176,19 @@ impl Spiller {
 
         // Spill data to storage.
         let mut encoder = self.block_encoder();
-        encoder.add_blocks(data_block);
+        encoder.add_blocks(data_block)?;
         let data_size = encoder.size();
         let BlocksEncoder {
             buf,
             mut columns_layout,
+            plain_size,
             ..
         } = encoder;
 
         let location = self.write_encodes(data_size, buf).await?;
 
         // Record statistics.
-        record_write_profile(&location, &instant, data_size);
+        record_write_profile(&location, &instant, data_size, plain_size);
         let layout = columns_layout.pop().unwrap();
         Ok((location, layout, data_size))
     }
@@ -187,12 +197,17 @@ impl Spiller {
         format!("{}/{}", self.location_prefix, GlobalUniqName::unique())
     }
 
+    /// Spill the serialized columns of the aggregate buckets, each bucket is encoded
+    /// independently. Returns the location, the ranges of the buckets in the file, how
+    /// they are encoded and the written bytes.
     pub async fn spill_stream_aggregate_buffer(
         &self,
         location: Option<String>,
         write_data: Vec<Vec<Vec<u8>>>,
-    ) -> Result<(String, usize)> {
+    ) -> Result<(String, Vec<Range<u64>>, AggregateSpillEncoding, usize)> {
         let mut write_bytes = 0;
+        let mut ranges = Vec::with_capacity(write_data.len());
+        let encoding = AggregateSpillEncoding::new(self.codec);
         let location = location
             .unwrap_or_else(|| format!("{}/{}", self.location_prefix, GlobalUniqName::unique()));
 
@@ -202,10 +217,19 @@ impl Spiller {
             .chunk(8 * 1024 * 1024)
             .await?;
         for write_bucket_data in write_data.into_iter() {
-            for data in write_bucket_data.into_iter() {
-                write_bytes += data.len();
-                writer.write(data).await?;
+            let begin = write_bytes;
+            if encoding.is_plain() {
+                for data in write_bucket_data.into_iter() {
+                    write_bytes += data.len();
+                    writer.write(data).await?;
+                }
+            } else {
+                let mut buf = Vec::new();
+                encoding.encode(write_bucket_data.concat(), &mut buf)?;
+                write_bytes += buf.len();
+                writer.write(buf).await?;
             }
+            ranges.push(begin as u64..write_bytes as u64);
         }
 
         writer.close().await?;
@@ -218,7 +242,7 @@ impl Spiller {
         self.private_spilled_files
             .write()
             .insert(Location::Remote(location.clone()), Layout::Aggregate);
-        Ok((location, write_bytes))
+        Ok((location, ranges, encoding, write_bytes))
     }
 
     #[async_backtrace::framed]
@@ -267,7 +291,7 @@ impl Spiller {
         let mut partition_ids = Vec::new();
         for (partition_id, data_blocks) in partitioned_data.into_iter() {
             partition_ids.push(partition_id);
-            encoder.add_blocks(data_blocks);
+            encoder.add_blocks(data_blocks)?;
         }
 
         let write_bytes = encoder.size();
@@ -275,6 +299,7 @@ impl Spiller {
             buf,
             offsets,
             columns_layout,
+            plain_size,
             ..
         } = encoder;
 
@@ -294,7 +319,7 @@ impl Spiller {
         let instant = Instant::now();
         let location = self.write_encodes(write_bytes, buf).await?;
         // Record statistics.
-        record_write_profile(&location, &instant, write_bytes);
+        record_write_profile(&location, &instant, write_bytes, plain_size);
 
         self.ctx
             .add_spill_file(location.clone(), layout.clone(), write_bytes);
@@ -324,9 +349,10 @@ impl Spiller {
         let data = match location {
             Location::Local(path) => {
                 match columns_layout {
-                    Layout::ArrowIpc(layout) => {
+                    Layout::ArrowIpc(layout) if self.codec.is_plain() => {
                         debug_assert_eq!(path.size(), layout.iter().sum::<usize>())
                     }
+                    Layout::ArrowIpc(_) => {}
                     Layout::Parquet => {}
                     Layout::Aggregate => {}
                 }
@@ -347,7 +373,9 @@ impl Spiller {
             Location::Remote(loc) => self.operator.read(loc).await?,
         };
 
-        record_read_profile(location, &instant, data.len());
+        let read_bytes = data.len();
+        let data = self.codec.decode(data)?;
+        record_read_profile(location, &instant, read_bytes, data.len());
 
         Ok(deserialize_block(columns_layout, data))
     }
@@ -403,17 +431,19 @@ impl Spiller {
             (Location::Remote(loc), _) => self.operator.read(loc).await?,
         };
 
-        // Record statistics.
-        record_read_profile(location, &instant, data.len());
-
         // Deserialize partitioned data block.
+        let mut plain_size = 0;
         let partitioned_data = partitions
             .iter()
             .map(|(partition_id, Chunk { range, layout })| {
-                let block = deserialize_block(layout, data.slice(range.clone()));
-                (*partition_id, block)
+                let chunk = self.codec.decode(data.slice(range.clone()))?;
+                plain_size += chunk.len();
+                Ok((*partition_id, deserialize_block(layout, chunk)))
             })
-            .collect();
+            .collect::<Result<Vec<_>>>()?;
+
+        // Record statistics.
+        record_read_profile(location, &instant, data.len(), plain_size);
 
         Ok(partitioned_data)
     }
@@ -440,7 +470,9 @@ impl Spiller {
             Location::Remote(loc) => self.operator.read_with(loc).range(data_range).await?,
         };
 
-        record_read_profile(location, &instant, data.len());
+        let read_bytes = data.len();
+        let data = self.codec.decode(data)?;
+        record_read_profile(location, &instant, read_bytes, data.len());
 
         Ok(deserialize_block(layout, data))
     }
@@ -489,7 +521,7 @@ impl Spiller {
             .as_ref()
             .map(|dir| dir.block_alignment())
             .unwrap_or(Alignment::MIN);
-        BlocksEncoder::new(self.use_parquet, align, 8 * 1024 * 1024)
+        BlocksEncoder::new(self.use_parquet, self.codec, align, 8 * 1024 * 1024)
     }
 
     pub(crate) fn private_spilled_files(&self) -> Vec<Location> {
@@ -514,7 +546,16 @@ pub enum Location {
     Local(TempPath),
 }
 
-fn record_write_profile(location: &Location, start: &Instant, write_bytes: usize) {
+fn record_write_profile(
+    location: &Location,
+    start: &Instant,
+    write_bytes: usize,
+    uncompressed_bytes: usize,
+) {
+    Profile::record_usize_profile(
+        ProfileStatisticsName::SpillWriteUncompressedBytes,
+        uncompressed_bytes,
+    );
     match location {
         Location::Remote(_) => {
             Profile::record_usize_profile(ProfileStatisticsName::RemoteSpillWriteCount, 1);
@@ -538,7 +579,16 @@ fn record_write_profile(location: &Location, start: &Instant, write_bytes: usize
     }
 }
 
-fn record_read_profile(location: &Location, start: &Instant, read_bytes: usize) {
+fn record_read_profile(
+    location: &Location,
+    start: &Instant,
+    read_bytes: usize,
+    uncompressed_bytes: usize,
+) {
+    Profile::record_usize_profile(
+        ProfileStatisticsName::SpillReadUncompressedBytes,
+        uncompressed_bytes,
+    );
     match location {
         Location::Remote(_) => {
             Profile::record_usize_profile(ProfileStatisticsName::RemoteSpillReadCount, 1);
@@ -51,6 +51,7 @@ use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_sources::AsyncSource;
 use databend_common_pipeline_sources::AsyncSourcer;
+use databend_common_users::UserApiProvider;
 
 pub struct TaskDependentsTable {
     table_info: TableInfo,
@@ -243,6 +244,11 @@ impl AsyncSource for TaskDependentsSource {
         }
         self.is_finished = true;
         let config = GlobalConfig::instance();
+        if config.query.embedded_task_scheduler() {
+            let task_mgr = UserApiProvider::instance().task_api(&self.ctx.get_tenant())?;
+            let dependents = task_mgr.get_task_dependents(self.build_request()).await?;
+            return Ok(Some(self.to_block(dependents.task.as_ref())?));
+        }
         if config.query.cloud_control_grpc_server_address.is_none() {
             return Err(ErrorCode::CloudControlNotEnabled(
                 "cannot create task without cloud control enabled, please set cloud_control_grpc_server_address in config",
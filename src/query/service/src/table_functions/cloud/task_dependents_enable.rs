@@ -42,6 +42,7 @@ use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_sources::AsyncSource;
 use databend_common_pipeline_sources::AsyncSourcer;
 use databend_common_storages_factory::Table;
+use databend_common_users::UserApiProvider;
 
 pub struct TaskDependentsEnableTable {
     task_name: String,
@@ -157,6 +158,13 @@ impl AsyncSource for TaskDependentsEnableSource {
     #[async_backtrace::framed]
     async fn generate(&mut self) -> Result<Option<DataBlock>> {
         let config = GlobalConfig::instance();
+        if config.query.embedded_task_scheduler() {
+            let task_mgr = UserApiProvider::instance().task_api(&self.ctx.get_tenant())?;
+            task_mgr
+                .enable_task_dependents(self.build_request())
+                .await?;
+            return Ok(None);
+        }
         if config.query.cloud_control_grpc_server_address.is_none() {
             return Err(ErrorCode::CloudControlNotEnabled(
                 "cannot create task without cloud control enabled, please set cloud_control_grpc_server_address in config",
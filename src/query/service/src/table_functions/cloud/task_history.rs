@@ -44,6 +44,7 @@ use databend_common_pipeline_sources::AsyncSourcer;
 use databend_common_sql::plans::task_run_schema;
 use databend_common_storages_factory::Table;
 use databend_common_storages_system::parse_task_runs_to_datablock;
+use databend_common_users::UserApiProvider;
 use jiff::tz::TimeZone;
 
 pub struct TaskHistoryTable {
@@ -187,6 +188,11 @@ impl AsyncSource for TaskHistorySource {
         }
         self.is_finished = true;
         let config = GlobalConfig::instance();
+        if config.query.embedded_task_scheduler() {
+            let task_mgr = UserApiProvider::instance().task_api(&self.ctx.get_tenant())?;
+            let resp = task_mgr.show_task_runs(self.build_request().await?).await?;
+            return parse_task_runs_to_datablock(resp.task_runs).map(Some);
+        }
         if config.query.cloud_control_grpc_server_address.is_none() {
             return Err(ErrorCode::CloudControlNotEnabled(
                 "cannot view system.task_history table without cloud control enabled, please set cloud_control_grpc_server_address in config",
@@ -0,0 +1,260 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_cloud_control::pb;
+use databend_common_cloud_control::pb::alter_task_request::AlterTaskType;
+use databend_common_cloud_control::pb::task::Status;
+use databend_common_cloud_control::pb::task_run::State;
+use databend_common_cloud_control::pb::AlterTaskRequest;
+use databend_common_exception::Result;
+use databend_common_management::TaskMgr;
+use databend_common_meta_app::principal::UserInfo;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_sql::Planner;
+use databend_common_users::UserApiProvider;
+use futures_util::TryStreamExt;
+use log::info;
+use log::warn;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+
+/// Runs tasks of the embedded task backend in service sessions and records their runs in meta.
+pub struct TaskRunner {
+    tenant: Tenant,
+    task_mgr: TaskMgr,
+}
+
+impl TaskRunner {
+    pub fn create(tenant: Tenant) -> Result<Self> {
+        let task_mgr = UserApiProvider::instance().task_api(&tenant)?;
+        Ok(TaskRunner { tenant, task_mgr })
+    }
+
+    /// Run `root`, then the started tasks that run after it.
+    ///
+    /// A task runs once all of its predecessors in the graph have succeeded, the tasks after
+    /// a failed or skipped task are not run.
+    #[async_backtrace::framed]
+    pub async fn run(&self, root: pb::Task, scheduled_at: DateTime<Utc>) -> Result<()> {
+        let root_task_id = root.task_id.to_string();
+        let tasks = self
+            .task_mgr
+            .list_tasks()
+            .await?
+            .into_iter()
+            .map(|v| (v.data.task_name.clone(), v.data))
+            .filter(|(_, task)| task.status == Status::Started as i32)
+            .collect::<HashMap<_, _>>();
+
+        // All the tasks reachable from the root.
+        let mut graph = HashSet::from([root.task_name.clone()]);
+        let mut stack = vec![root.task_name.clone()];
+        while let Some(name) = stack.pop() {
+            for task in tasks.values().filter(|t| t.after.contains(&name)) {
+                if graph.insert(task.task_name.clone()) {
+                    stack.push(task.task_name.clone());
+                }
+            }
+        }
+
+        let mut succeeded = HashSet::new();
+        let mut queued = HashSet::from([root.task_name.clone()]);
+        let mut queue = VecDeque::from([root]);
+        while let Some(task) = queue.pop_front() {
+            if !self.run_task(&task, &root_task_id, scheduled_at).await? {
+                continue;
+            }
+            succeeded.insert(task.task_name.clone());
+
+            for dependent in tasks.values().filter(|t| t.after.contains(&task.task_name)) {
+                let ready = dependent
+                    .after
+                    .iter()
+                    .all(|name| succeeded.contains(name) || !graph.contains(name));
+                if ready && queued.insert(dependent.task_name.clone()) {
+                    queue.push_back(dependent.clone());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns true if the task succeeded.
+    #[async_backtrace::framed]
+    async fn run_task(
+        &self,
+        task: &pb::Task,
+        root_task_id: &str,
+        scheduled_at: DateTime<Utc>,
+    ) -> Result<bool> {
+        let mut run = pb::TaskRun {
+            task_id: task.task_id,
+            task_name: task.task_name.clone(),
+            query_text: task.query_text.clone(),
+            comment: task.comment.clone(),
+            owner: task.owner.clone(),
+            schedule_options: task.schedule_options.clone(),
+            run_id: self.task_mgr.fetch_id().await?.to_string(),
+            attempt_number: 1,
+            warehouse_options: task.warehouse_options.clone(),
+            state: State::Executing as i32,
+            error_code: 0,
+            error_message: None,
+            scheduled_time: scheduled_at.to_rfc3339(),
+            completed_time: None,
+            query_id: "".to_string(),
+            condition_text: task.when_condition.clone().unwrap_or_default(),
+            root_task_id: root_task_id.to_string(),
+            session_parameters: task.session_parameters.clone(),
+        };
+        self.task_mgr.save_task_run(&run).await?;
+
+        info!(
+            "Task runner starts task {} (run {})",
+            task.task_name, run.run_id
+        );
+        let result = self.execute(task, &mut run.query_id).await;
+        let succeeded = match result {
+            Ok(true) => {
+                run.state = State::Succeeded as i32;
+                true
+            }
+            Ok(false) => {
+                run.state = State::Cancelled as i32;
+                run.error_message = Some("WHEN condition is not satisfied".to_string());
+                false
+            }
+            Err(cause) => {
+                warn!(
+                    "Task runner failed to run task {} (run {}): {:?}",
+                    task.task_name, run.run_id, cause
+                );
+                run.state = State::Failed as i32;
+                run.error_code = cause.code() as i64;
+                run.error_message = Some(cause.message());
+                false
+            }
+        };
+        run.completed_time = Some(Utc::now().to_rfc3339());
+        self.task_mgr.save_task_run(&run).await?;
+
+        if run.state == State::Failed as i32 {
+            self.suspend_after_failures(task).await?;
+        }
+        Ok(succeeded)
+    }
+
+    /// Returns false if the WHEN condition of the task is not satisfied.
+    #[async_backtrace::framed]
+    async fn execute(&self, task: &pb::Task, query_id: &mut String) -> Result<bool> {
+        let session = self.create_session(task).await?;
+
+        if let Some(condition) = &task.when_condition {
+            let ctx = session.create_query_context().await?;
+            *query_id = ctx.get_id();
+            let sql = format!("SELECT 1 WHERE {}", condition);
+            if execute_sql(ctx, &sql).await? == 0 {
+                return Ok(false);
+            }
+        }
+
+        let ctx = session.create_query_context().await?;
+        *query_id = ctx.get_id();
+        // A script block is displayed as `BEGIN ... END;`.
+        let sql = match task.query_text.starts_with("BEGIN\n") {
+            true => format!("EXECUTE IMMEDIATE $$\n{}\n$$", task.query_text),
+            false => task.query_text.clone(),
+        };
+        execute_sql(ctx, &sql).await?;
+        Ok(true)
+    }
+
+    /// The task runs with the privileges of its owner role.
+    #[async_backtrace::framed]
+    async fn create_session(&self, task: &pb::Task) -> Result<Arc<Session>> {
+        let session = SessionManager::instance()
+            .create_session(SessionType::HTTPAPI("TaskRunner".to_string()))
+            .await?;
+
+        let user = UserInfo::new_no_auth(
+            &format!("{}-task-runner", self.tenant.tenant_name()),
+            "0.0.0.0",
+        );
+        let owner = match task.owner.is_empty() {
+            true => None,
+            false => Some(task.owner.clone()),
+        };
+        session.set_authed_user(user, owner).await?;
+
+        let settings = session.get_settings();
+        for (key, value) in task.session_parameters.iter() {
+            settings.set_setting(key.clone(), value.clone())?;
+        }
+        Ok(session)
+    }
+
+    #[async_backtrace::framed]
+    async fn suspend_after_failures(&self, task: &pb::Task) -> Result<()> {
+        let Some(limit) = task.suspend_task_after_num_failures.filter(|v| *v > 0) else {
+            return Ok(());
+        };
+
+        let runs = self.task_mgr.list_task_runs(task.task_id).await?;
+        let failures = runs
+            .iter()
+            .rev()
+            .take_while(|run| run.state == State::Failed as i32)
+            .count();
+        if failures >= limit as usize {
+            info!(
+                "Task runner suspends task {} after {} consecutive failures",
+                task.task_name, failures
+            );
+            self.task_mgr
+                .alter_task(AlterTaskRequest {
+                    task_name: task.task_name.clone(),
+                    tenant_id: self.tenant.tenant_name().to_string(),
+                    alter_task_type: AlterTaskType::Suspend as i32,
+                    if_exist: true,
+                    ..Default::default()
+                })
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the number of result rows.
+#[async_backtrace::framed]
+async fn execute_sql(ctx: Arc<QueryContext>, sql: &str) -> Result<usize> {
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(sql).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let stream = interpreter.execute(ctx).await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    Ok(blocks.iter().map(|block| block.num_rows()).sum())
+}
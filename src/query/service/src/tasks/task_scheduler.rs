@@ -0,0 +1,199 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_base::base::tokio::sync::Notify;
+use databend_common_base::base::tokio::time::sleep;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_cloud_control::pb;
+use databend_common_cloud_control::pb::task::Status;
+use databend_common_config::InnerConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_management::next_scheduled_at;
+use databend_common_management::TaskMgr;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_users::UserApiProvider;
+use futures::future::select;
+use futures::future::Either;
+use log::info;
+use log::warn;
+use parking_lot::Mutex;
+
+use crate::tasks::TaskRunner;
+
+/// How often the scheduler looks for due tasks.
+const SCHEDULE_TICK: Duration = Duration::from_millis(500);
+
+/// The leadership is renewed on every tick, and taken over by another node once it expires.
+const LEADER_LEASE: Duration = Duration::from_secs(10);
+
+/// Fires the scheduled tasks of the embedded task backend.
+///
+/// Every query node of the tenant runs a scheduler, but only the one holding the leader lease
+/// in meta fires tasks. A run is claimed by advancing the task's `next_scheduled_at` with a
+/// compare-and-swap, so it fires exactly once even if two nodes briefly both act as the leader.
+pub struct TaskScheduler {
+    tenant: Tenant,
+    node_id: String,
+    /// Root tasks whose graph is still running on this node, they are not fired again until done.
+    running: Arc<Mutex<HashSet<u64>>>,
+    shutdown_flag: AtomicBool,
+    shutdown_notify: Notify,
+}
+
+impl TaskScheduler {
+    pub fn create(conf: &InnerConfig) -> Arc<TaskScheduler> {
+        Arc::new(TaskScheduler {
+            tenant: conf.query.tenant_id.clone(),
+            node_id: conf.query.node_id.clone(),
+            running: Arc::new(Mutex::new(HashSet::new())),
+            shutdown_flag: AtomicBool::new(false),
+            shutdown_notify: Notify::new(),
+        })
+    }
+
+    pub fn start(self: &Arc<Self>) {
+        let scheduler = self.clone();
+        GlobalIORuntime::instance().spawn(async move {
+            info!("Task scheduler started on node {}", scheduler.node_id);
+            let mut notified = Box::pin(scheduler.shutdown_notify.notified());
+            while !scheduler.shutdown_flag.load(Ordering::SeqCst) {
+                if let Err(cause) = scheduler.tick().await {
+                    warn!("Task scheduler tick failed: {:?}", cause);
+                }
+
+                match select(notified, Box::pin(sleep(SCHEDULE_TICK))).await {
+                    Either::Left((_, _)) => break,
+                    Either::Right((_, new_notified)) => notified = new_notified,
+                }
+            }
+
+            if let Err(cause) = scheduler.resign().await {
+                warn!(
+                    "Task scheduler failed to resign the leadership: {:?}",
+                    cause
+                );
+            }
+            info!("Task scheduler stopped on node {}", scheduler.node_id);
+        });
+    }
+
+    pub fn shutdown(&self) {
+        self.shutdown_flag.store(true, Ordering::SeqCst);
+        self.shutdown_notify.notify_one();
+    }
+
+    #[async_backtrace::framed]
+    async fn resign(&self) -> Result<()> {
+        let task_mgr = UserApiProvider::instance().task_api(&self.tenant)?;
+        task_mgr.resign_leader(&self.node_id).await
+    }
+
+    #[async_backtrace::framed]
+    async fn tick(&self) -> Result<()> {
+        let task_mgr = UserApiProvider::instance().task_api(&self.tenant)?;
+        if !task_mgr
+            .try_acquire_leader(&self.node_id, LEADER_LEASE)
+            .await?
+        {
+            return Ok(());
+        }
+
+        let now = Utc::now();
+        for task in task_mgr.list_tasks().await? {
+            let task_name = task.data.task_name.clone();
+            if let Err(cause) = self.schedule(&task_mgr, task.seq, task.data, now).await {
+                warn!(
+                    "Task scheduler failed to schedule task {}: {:?}",
+                    task_name, cause
+                );
+            }
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn schedule(
+        &self,
+        task_mgr: &TaskMgr,
+        seq: u64,
+        mut task: pb::Task,
+        now: DateTime<Utc>,
+    ) -> Result<()> {
+        // Tasks with predecessors are triggered by the runs of their root task.
+        if task.status != Status::Started as i32 || !task.after.is_empty() {
+            return Ok(());
+        }
+        let Some(schedule) = task.schedule_options.clone() else {
+            return Ok(());
+        };
+
+        let scheduled_at = match &task.next_scheduled_at {
+            Some(next) => DateTime::parse_from_rfc3339(next)
+                .map_err(|e| {
+                    ErrorCode::IllegalCloudControlMessageFormat(format!(
+                        "illegal next_scheduled_at message {}, {e}",
+                        next
+                    ))
+                })?
+                .with_timezone(&Utc),
+            None => {
+                task.next_scheduled_at = Some(next_scheduled_at(&schedule, now)?.to_rfc3339());
+                task_mgr.update_task(seq, &task).await?;
+                return Ok(());
+            }
+        };
+        if scheduled_at > now {
+            return Ok(());
+        }
+
+        // Claim the run, only one scheduler can advance the schedule from `seq`.
+        task.next_scheduled_at = Some(next_scheduled_at(&schedule, now)?.to_rfc3339());
+        if !task_mgr.update_task(seq, &task).await? {
+            return Ok(());
+        }
+
+        let runner = TaskRunner::create(self.tenant.clone())?;
+        if !self.running.lock().insert(task.task_id) {
+            info!(
+                "Task scheduler skips task {} scheduled at {}, the previous run is not finished",
+                task.task_name, scheduled_at
+            );
+            return Ok(());
+        }
+
+        let running = self.running.clone();
+        GlobalIORuntime::instance().spawn(async move {
+            let task_id = task.task_id;
+            let task_name = task.task_name.clone();
+            if let Err(cause) = runner.run(task, scheduled_at).await {
+                warn!(
+                    "Task scheduler failed to run task {}: {:?}",
+                    task_name, cause
+                );
+            }
+            running.lock().remove(&task_id);
+        });
+        Ok(())
+    }
+}
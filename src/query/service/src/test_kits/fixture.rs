@@ -348,6 +348,8 @@ impl TestFixture {
             as_select: None,
             cluster_key: Some("(id)".to_string()),
             inverted_indexes: None,
+            constraints: Default::default(),
+            clone_from: None,
         }
     }
 
@@ -372,6 +374,8 @@ impl TestFixture {
             as_select: None,
             cluster_key: None,
             inverted_indexes: None,
+            constraints: Default::default(),
+            clone_from: None,
         }
     }
 
@@ -407,6 +411,8 @@ impl TestFixture {
             as_select: None,
             cluster_key: None,
             inverted_indexes: None,
+            constraints: Default::default(),
+            clone_from: None,
         }
     }
 
@@ -442,6 +448,8 @@ impl TestFixture {
             as_select: None,
             cluster_key: None,
             inverted_indexes: None,
+            constraints: Default::default(),
+            clone_from: None,
         }
     }
 
@@ -486,6 +494,8 @@ impl TestFixture {
             as_select: None,
             cluster_key: None,
             inverted_indexes: None,
+            constraints: Default::default(),
+            clone_from: None,
         }
     }
 
@@ -534,6 +544,7 @@ impl TestFixture {
                 engine: "".to_string(),
                 ..Default::default()
             },
+            clone_from: None,
         };
 
         self.default_ctx
@@ -0,0 +1,191 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::block_debug::assert_blocks_sorted_eq;
+use databend_common_expression::DataBlock;
+use databend_query::history_tables::HistoryLog;
+use databend_query::test_kits::ConfigBuilder;
+use databend_query::test_kits::TestFixture;
+use futures::TryStreamExt;
+
+async fn setup() -> Result<TestFixture> {
+    let mut config = ConfigBuilder::create().build();
+    config.log.history.on = true;
+    TestFixture::setup_with_config(&config).await
+}
+
+fn record_logins(history: &HistoryLog) {
+    history.record_login(
+        "MySQL".to_string(),
+        "password".to_string(),
+        "u1".to_string(),
+        "127.0.0.1".to_string(),
+        Ok(()),
+    );
+    history.record_login(
+        "MySQL".to_string(),
+        "password".to_string(),
+        "u2".to_string(),
+        "127.0.0.1".to_string(),
+        Err(&ErrorCode::AuthenticateFailure("wrong password")),
+    );
+}
+
+async fn query(fixture: &TestFixture, sql: &str) -> Result<Vec<DataBlock>> {
+    fixture.execute_query(sql).await?.try_collect().await
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_history_log_flush() -> Result<()> {
+    let fixture = setup().await?;
+    let history = HistoryLog::instance().unwrap();
+
+    // the history tables are created by the first flush
+    record_logins(&history);
+    history.flush().await?;
+
+    let blocks = query(
+        &fixture,
+        "select name from system.tables where database = 'system_history'",
+    )
+    .await?;
+    let expected = vec![
+        "+-------------------+",
+        "| Column 0          |",
+        "+-------------------+",
+        "| 'access_history'  |",
+        "| 'error_history'   |",
+        "| 'login_history'   |",
+        "| 'profile_history' |",
+        "| 'query_history'   |",
+        "+-------------------+",
+    ];
+    assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    let sql = "select user_name, success, error_code from system_history.login_history";
+    let blocks = query(&fixture, sql).await?;
+    let expected = vec![
+        "+----------+----------+----------+",
+        "| Column 0 | Column 1 | Column 2 |",
+        "+----------+----------+----------+",
+        "| 'u1'     | true     | 0        |",
+        "| 'u2'     | false    | 5100     |",
+        "+----------+----------+----------+",
+    ];
+    assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    // the events are appended once, a flush without new events appends nothing
+    history.flush().await?;
+    record_logins(&history);
+    history.flush().await?;
+
+    let sql = "select user_name, count(*) from system_history.login_history group by user_name";
+    let blocks = query(&fixture, sql).await?;
+    let expected = vec![
+        "+----------+----------+",
+        "| Column 0 | Column 1 |",
+        "+----------+----------+",
+        "| 'u1'     | 2        |",
+        "| 'u2'     | 2        |",
+        "+----------+----------+",
+    ];
+    assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_history_log_add_columns() -> Result<()> {
+    let fixture = setup().await?;
+    let history = HistoryLog::instance().unwrap();
+
+    // a table created by a version without the error columns
+    fixture
+        .execute_command("create database system_history")
+        .await?;
+    fixture
+        .execute_command(
+            "create table system_history.login_history (event_time TIMESTAMP NOT NULL, \
+            node_id STRING NOT NULL, handler_type STRING NOT NULL, auth_type STRING NOT NULL, \
+            user_name STRING NOT NULL, client_address STRING NOT NULL, success BOOLEAN NOT NULL)",
+        )
+        .await?;
+
+    record_logins(&history);
+    history.flush().await?;
+
+    let sql = "select user_name, error_code from system_history.login_history";
+    let blocks = query(&fixture, sql).await?;
+    let expected = vec![
+        "+----------+----------+",
+        "| Column 0 | Column 1 |",
+        "+----------+----------+",
+        "| 'u1'     | 0        |",
+        "| 'u2'     | 5100     |",
+        "+----------+----------+",
+    ];
+    assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_history_log_rotate_table() -> Result<()> {
+    let fixture = setup().await?;
+    let history = HistoryLog::instance().unwrap();
+
+    // a table whose column type differs from the current one
+    fixture
+        .execute_command("create database system_history")
+        .await?;
+    fixture
+        .execute_command("create table system_history.login_history (success STRING NOT NULL)")
+        .await?;
+    fixture
+        .execute_command("insert into system_history.login_history values ('old')")
+        .await?;
+
+    record_logins(&history);
+    history.flush().await?;
+
+    // the old events are kept in the rotated table
+    let sql = "select count(*) from system.tables where database = 'system_history' \
+        and name like 'login_history_2%'";
+    let blocks = query(&fixture, sql).await?;
+    let expected = vec![
+        "+----------+",
+        "| Column 0 |",
+        "+----------+",
+        "| 1        |",
+        "+----------+",
+    ];
+    assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    let sql = "select user_name, success from system_history.login_history";
+    let blocks = query(&fixture, sql).await?;
+    let expected = vec![
+        "+----------+----------+",
+        "| Column 0 | Column 1 |",
+        "+----------+----------+",
+        "| 'u1'     | true     |",
+        "| 'u2'     | false    |",
+        "+----------+----------+",
+    ];
+    assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    Ok(())
+}
@@ -27,6 +27,7 @@ mod configs;
 mod databases;
 mod distributed;
 mod frame;
+mod history_tables;
 mod metrics;
 mod parquet_rs;
 mod pipelines;
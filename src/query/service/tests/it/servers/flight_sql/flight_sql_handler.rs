@@ -21,6 +21,9 @@ use arrow_array::RecordBatch;
 use arrow_cast::pretty::pretty_format_batches;
 use arrow_flight::flight_service_server::FlightServiceServer;
 use arrow_flight::sql::client::FlightSqlServiceClient;
+use arrow_flight::sql::CommandGetImportedKeys;
+use arrow_flight::sql::CommandGetPrimaryKeys;
+use arrow_flight::FlightInfo;
 use arrow_schema::ArrowError;
 use databend_common_base::base::tokio;
 use databend_common_base::runtime::Runtime;
@@ -82,6 +85,16 @@ async fn run_query(
     Ok(res)
 }
 
+async fn get_flight_data(
+    client: &mut FlightSqlServiceClient<Channel>,
+    flight_info: std::result::Result<FlightInfo, ArrowError>,
+) -> std::result::Result<String, ArrowError> {
+    let ticket = flight_info?.endpoint[0].ticket.as_ref().unwrap().clone();
+    let flight_data = client.do_get(ticket).await?;
+    let batches: Vec<RecordBatch> = flight_data.try_collect().await?;
+    Ok(pretty_format_batches(batches.as_slice())?.to_string())
+}
+
 fn prepare_config() -> InnerConfig {
     let hash_method = PasswordHashMethod::DoubleSha1;
     let hash_value = hash_method.hash(TEST_PASSWORD.as_bytes());
@@ -136,6 +149,10 @@ async fn test_query() -> Result<()> {
                 "create table test1(a int, b string)",
                 "insert into table test1(a, b) values (1, 'x'), (2, 'y')",
                 "select * from test1",
+                "drop table if exists test3",
+                "drop table if exists test2",
+                "create table test2(a int primary key, b string unique)",
+                "create table test3(c int references test2 (a))",
             ];
             for case in cases {
                 writeln!(file, "---------- Input ----------").unwrap();
@@ -147,6 +164,39 @@ async fn test_query() -> Result<()> {
                 };
                 writeln!(file, "{}", res).unwrap();
             }
+
+            let key_cases = [
+                (
+                    "primary keys of test2",
+                    client
+                        .get_primary_keys(CommandGetPrimaryKeys {
+                            catalog: None,
+                            db_schema: None,
+                            table: "test2".to_string(),
+                        })
+                        .await,
+                ),
+                (
+                    "imported keys of test3",
+                    client
+                        .get_imported_keys(CommandGetImportedKeys {
+                            catalog: None,
+                            db_schema: None,
+                            table: "test3".to_string(),
+                        })
+                        .await,
+                ),
+            ];
+            for (case, flight_info) in key_cases {
+                writeln!(file, "---------- Input ----------").unwrap();
+                writeln!(file, "{}", case).unwrap();
+                writeln!(file, "---------- Output ---------").unwrap();
+                let res = match get_flight_data(&mut client, flight_info).await {
+                    Ok(s) => s,
+                    Err(e) => format!("{e:?}"),
+                };
+                writeln!(file, "{}", res).unwrap();
+            }
         };
         tokio::pin!(serve_future);
 
@@ -0,0 +1,198 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_sql::LineageColumn;
+use databend_common_sql::ObjectDomain;
+use databend_common_sql::Planner;
+use databend_common_sql::QueryLineage;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::test_kits::TestFixture;
+use futures::TryStreamExt;
+
+async fn setup() -> Result<TestFixture> {
+    let fixture = TestFixture::setup().await?;
+    fixture
+        .execute_command("create table t1(a int, b string)")
+        .await?;
+    fixture
+        .execute_command("create table t2(c int, d int)")
+        .await?;
+    fixture
+        .execute_command("create table t3(x int, y string)")
+        .await?;
+    Ok(fixture)
+}
+
+/// Runs `sql` with `enable_query_lineage` set to `enabled`, and returns the lineage attached
+/// to the query.
+async fn lineage_of(
+    fixture: &TestFixture,
+    sql: &str,
+    enabled: bool,
+) -> Result<Option<QueryLineage>> {
+    let ctx = fixture.new_query_ctx().await?;
+    ctx.get_settings().set_setting(
+        "enable_query_lineage".to_string(),
+        (enabled as u8).to_string(),
+    )?;
+
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(sql).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    interpreter
+        .execute(ctx.clone())
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    Ok(ctx.get_query_lineage())
+}
+
+/// `table.column` of the table columns, `column` of the result columns.
+fn column_name(column: &LineageColumn) -> String {
+    match column.object_domain {
+        ObjectDomain::Result => column.column_name.clone(),
+        _ => {
+            let table = column.object_name.rsplit('.').next().unwrap();
+            format!("{}.{}", table, column.column_name)
+        }
+    }
+}
+
+fn object_names(lineage: &QueryLineage, written: bool) -> Vec<String> {
+    let objects = match written {
+        true => &lineage.objects_written,
+        false => &lineage.objects_read,
+    };
+    objects
+        .iter()
+        .map(|object| object.object_name.rsplit('.').next().unwrap().to_string())
+        .collect()
+}
+
+fn columns(lineage: &QueryLineage) -> Vec<(String, Vec<String>)> {
+    lineage
+        .columns
+        .iter()
+        .map(|column| {
+            let sources = column.sources.iter().map(column_name).collect();
+            (column_name(&column.target), sources)
+        })
+        .collect()
+}
+
+fn pair(target: &str, sources: &[&str]) -> (String, Vec<String>) {
+    let sources = sources.iter().map(|source| source.to_string()).collect();
+    (target.to_string(), sources)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_lineage_join() -> Result<()> {
+    let fixture = setup().await?;
+
+    let sql = "select t1.a, t2.c, t1.b || t2.d::string as e from t1 join t2 on t1.a = t2.c";
+    let lineage = lineage_of(&fixture, sql, true).await?.unwrap();
+
+    assert_eq!(object_names(&lineage, false), vec!["t1", "t2"]);
+    assert!(lineage.objects_written.is_empty());
+    assert_eq!(columns(&lineage), vec![
+        pair("a", &["t1.a"]),
+        pair("c", &["t2.c"]),
+        pair("e", &["t1.b", "t2.d"]),
+    ]);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_lineage_subquery() -> Result<()> {
+    let fixture = setup().await?;
+
+    // The table only referenced by the filter is read, but feeds no result column.
+    let sql = "select b from t1 where a in (select c from t2)";
+    let lineage = lineage_of(&fixture, sql, true).await?.unwrap();
+    assert_eq!(object_names(&lineage, false), vec!["t1", "t2"]);
+    assert_eq!(columns(&lineage), vec![pair("b", &["t1.b"])]);
+
+    let sql = "select a + (select max(d) from t2) as m from t1";
+    let lineage = lineage_of(&fixture, sql, true).await?.unwrap();
+    assert_eq!(object_names(&lineage, false), vec!["t1", "t2"]);
+    assert_eq!(columns(&lineage), vec![pair("m", &["t1.a", "t2.d"])]);
+
+    let sql = "select s.k from (select a + 1 as k from t1) as s";
+    let lineage = lineage_of(&fixture, sql, true).await?.unwrap();
+    assert_eq!(object_names(&lineage, false), vec!["t1"]);
+    assert_eq!(columns(&lineage), vec![pair("k", &["t1.a"])]);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_lineage_insert_select() -> Result<()> {
+    let fixture = setup().await?;
+
+    let sql = "insert into t3 select a + d, b from t1 join t2 on t1.a = t2.c";
+    let lineage = lineage_of(&fixture, sql, true).await?.unwrap();
+
+    assert_eq!(object_names(&lineage, false), vec!["t1", "t2"]);
+    assert_eq!(object_names(&lineage, true), vec!["t3"]);
+    assert_eq!(columns(&lineage), vec![
+        pair("t3.x", &["t1.a", "t2.d"]),
+        pair("t3.y", &["t1.b"]),
+    ]);
+
+    // The values come from no object.
+    let sql = "insert into t3 values (1, 'a')";
+    let lineage = lineage_of(&fixture, sql, true).await?.unwrap();
+    assert!(lineage.objects_read.is_empty());
+    assert_eq!(object_names(&lineage, true), vec!["t3"]);
+    assert!(lineage.columns.is_empty());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_lineage_create_table_as_select() -> Result<()> {
+    let fixture = setup().await?;
+
+    let sql = "create table t4 as select b, c from t1 join t2 on t1.a = t2.c";
+    let lineage = lineage_of(&fixture, sql, true).await?.unwrap();
+
+    assert_eq!(object_names(&lineage, false), vec!["t1", "t2"]);
+    assert_eq!(object_names(&lineage, true), vec!["t4"]);
+    assert_eq!(columns(&lineage), vec![
+        pair("t4.b", &["t1.b"]),
+        pair("t4.c", &["t2.c"]),
+    ]);
+
+    // The table created is the one recorded.
+    let ctx = fixture.new_query_ctx().await?;
+    let table = ctx
+        .get_table(&fixture.default_catalog_name(), "default", "t4")
+        .await?;
+    assert_eq!(lineage.objects_written[0].object_id, table.get_id());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_lineage_disabled() -> Result<()> {
+    let fixture = setup().await?;
+
+    let sql = "insert into t3 select a, b from t1";
+    assert!(lineage_of(&fixture, sql, false).await?.is_none());
+
+    let sql = "create table t4 as select a, b from t1";
+    assert!(lineage_of(&fixture, sql, false).await?.is_none());
+    Ok(())
+}
@@ -336,6 +336,7 @@ fn build_test_segment_info(
         inverted_index_size: None,
         compression: Compression::Lz4,
         create_on: Some(Utc::now()),
+        deletion_vector: None,
     };
 
     let block_metas = (0..num_blocks_per_seg)
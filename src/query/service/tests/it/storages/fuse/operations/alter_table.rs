@@ -185,6 +185,7 @@ async fn test_fuse_table_optimize_alter_table() -> Result<()> {
         comment: "".to_string(),
         option: AddColumnOption::End,
         is_deterministic: true,
+        identity: None,
     };
     let interpreter = AddTableColumnInterpreter::try_create(ctx.clone(), add_table_column_plan)?;
     let _ = interpreter.execute(ctx.clone()).await?;
@@ -174,6 +174,8 @@ async fn test_snapshot_pruner() -> Result<()> {
         as_select: None,
         cluster_key: None,
         inverted_indexes: None,
+        constraints: Default::default(),
+        clone_from: None,
     };
 
     let interpreter = CreateTableInterpreter::try_create(ctx.clone(), create_table_plan)?;
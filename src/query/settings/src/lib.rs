@@ -25,3 +25,5 @@ pub use settings_default::SettingMode;
 pub use settings_default::SettingRange;
 pub use settings_default::SettingScope;
 pub use settings_getter_setter::FlightCompression;
+pub use settings_getter_setter::PlanBaselineMode;
+pub use settings_getter_setter::SpillCompression;
@@ -23,6 +23,8 @@ use databend_common_exception::Result;
 use databend_common_meta_app::principal::UserSettingValue;
 use once_cell::sync::OnceCell;
 
+use super::settings_getter_setter::PlanBaselineMode;
+use super::settings_getter_setter::SpillCompression;
 use super::settings_getter_setter::SpillFileFormat;
 
 static DEFAULT_SETTINGS: OnceCell<Arc<DefaultSettings>> = OnceCell::new();
@@ -310,6 +312,13 @@ impl DefaultSettings {
                     scope: SettingScope::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_join_elimination", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables trusting the primary, unique and foreign key constraints to eliminate redundant joins and estimate the distinct values of keys. The constraints are not enforced, query results are wrong if the data violates them.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("enable_cbo", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables cost-based optimization.",
@@ -373,6 +382,20 @@ impl DefaultSettings {
                     scope: SettingScope::Both,
                     range: Some(SettingRange::String(SpillFileFormat::range())),
                 }),
+                ("spilling_compression", DefaultSettingValue {
+                    value: UserSettingValue::String("none".to_string()),
+                    desc: "Set the compression of the spill files in the arrow format, available values: 'none', 'zstd'.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::String(SpillCompression::range())),
+                }),
+                ("enable_spilling_encryption", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enable the AES-GCM encryption of the spill files with an ephemeral key of the process.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("spilling_to_disk_vacuum_unknown_temp_dirs_limit", DefaultSettingValue {
                     value: UserSettingValue::UInt64(u64::MAX),
                     desc: "Set the maximum number of directories to clean up. If there are some temporary dirs when another query is unexpectedly interrupted, which needs to be cleaned up after this query.",
@@ -457,6 +480,27 @@ impl DefaultSettings {
                     scope: SettingScope::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_adaptive_query_execution", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables materializing the build sides of distributed hash joins to choose between broadcast and shuffle by their actual sizes.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("adaptive_broadcast_join_threshold", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(32 * 1024 * 1024),
+                    desc: "Sets the maximum bytes of the materialized build side of a join to be broadcast by adaptive query execution.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("join_skew_hot_key_percent", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(20),
+                    desc: "Sets the minimum percentage of the rows of a shuffle join input having the same key for the key to be split across the nodes, 0 disables the split of hot keys.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=100)),
+                }),
                 ("storage_fetch_part_num", DefaultSettingValue {
                     value: UserSettingValue::UInt64(2),
                     desc: "Sets the number of partitions that are fetched in parallel from storage during query execution.",
@@ -748,6 +792,27 @@ impl DefaultSettings {
                     scope: SettingScope::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_materialized_view_rewrite", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables rewriting queries to read from fresh materialized views.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("plan_baseline_mode", DefaultSettingValue {
+                    value: UserSettingValue::String("off".to_string()),
+                    desc: "Sets how the plan baselines pinned by `ALTER QUERY ... PIN PLAN` are used: off (default), fixed, or evolve (a new plan replaces the baseline if it executes faster).",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::String(PlanBaselineMode::range())),
+                }),
+                ("enable_query_lineage", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables recording the objects accessed by queries and their column lineage into the query log and the access history.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("enable_compact_after_write", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables compact after write(copy/insert/replace-into/merge-into), need more memory.",
@@ -69,6 +69,72 @@ impl FromStr for SpillFileFormat {
     }
 }
 
+/// The compression of the spill files in the arrow format, the parquet format is always
+/// compressed with LZ4.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SpillCompression {
+    None,
+    Zstd,
+}
+
+impl SpillCompression {
+    pub fn range() -> Vec<String> {
+        ["none", "zstd"].iter().copied().map(String::from).collect()
+    }
+}
+
+impl FromStr for SpillCompression {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(SpillCompression::None),
+            "zstd" => Ok(SpillCompression::Zstd),
+            _ => Err(ErrorCode::InvalidConfig(format!(
+                "invalid SpillCompression: {:?}",
+                s
+            ))),
+        }
+    }
+}
+
+/// How the plan baselines are used by the queries.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PlanBaselineMode {
+    /// The plan baselines are ignored.
+    Off,
+    /// The queries use the plans pinned by their baselines.
+    Fixed,
+    /// A new plan replaces the pinned plan if it executes faster.
+    Evolve,
+}
+
+impl PlanBaselineMode {
+    pub fn range() -> Vec<String> {
+        ["off", "fixed", "evolve"]
+            .iter()
+            .copied()
+            .map(String::from)
+            .collect()
+    }
+}
+
+impl FromStr for PlanBaselineMode {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "off" => Ok(PlanBaselineMode::Off),
+            "fixed" => Ok(PlanBaselineMode::Fixed),
+            "evolve" => Ok(PlanBaselineMode::Evolve),
+            _ => Err(ErrorCode::InvalidConfig(format!(
+                "invalid PlanBaselineMode: {:?}",
+                s
+            ))),
+        }
+    }
+}
+
 impl Settings {
     // Get u64 value, we don't get from the metasrv.
     fn try_get_u64(&self, key: &str) -> Result<u64> {
@@ -306,6 +372,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_dphyp")? != 0)
     }
 
+    pub fn get_enable_join_elimination(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_join_elimination")? != 0)
+    }
+
     pub fn get_enable_cbo(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_cbo")? != 0)
     }
@@ -343,6 +413,14 @@ impl Settings {
         self.try_get_string("spilling_file_format")?.parse()
     }
 
+    pub fn get_spilling_compression(&self) -> Result<SpillCompression> {
+        self.try_get_string("spilling_compression")?.parse()
+    }
+
+    pub fn get_enable_spilling_encryption(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_spilling_encryption")? != 0)
+    }
+
     pub fn get_spilling_to_disk_vacuum_unknown_temp_dirs_limit(&self) -> Result<usize> {
         Ok(self.try_get_u64("spilling_to_disk_vacuum_unknown_temp_dirs_limit")? as usize)
     }
@@ -367,6 +445,18 @@ impl Settings {
         Ok(self.try_get_u64("enforce_shuffle_join")? != 0)
     }
 
+    pub fn get_enable_adaptive_query_execution(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_adaptive_query_execution")? != 0)
+    }
+
+    pub fn get_adaptive_broadcast_join_threshold(&self) -> Result<usize> {
+        Ok(self.try_get_u64("adaptive_broadcast_join_threshold")? as usize)
+    }
+
+    pub fn get_join_skew_hot_key_percent(&self) -> Result<u64> {
+        self.try_get_u64("join_skew_hot_key_percent")
+    }
+
     pub fn get_enable_merge_into_row_fetch(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_merge_into_row_fetch")? != 0)
     }
@@ -588,6 +678,18 @@ impl Settings {
         Ok(self.try_get_u64("enable_aggregating_index_scan")? != 0)
     }
 
+    pub fn get_enable_materialized_view_rewrite(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_materialized_view_rewrite")? != 0)
+    }
+
+    pub fn get_plan_baseline_mode(&self) -> Result<PlanBaselineMode> {
+        self.try_get_string("plan_baseline_mode")?.parse()
+    }
+
+    pub fn get_enable_query_lineage(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_query_lineage")? != 0)
+    }
+
     pub fn get_enable_compact_after_write(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_compact_after_write")? != 0)
     }
@@ -1456,6 +1456,19 @@ fn hash_join_to_format_tree(
         )));
     }
 
+    if let Some(skew) = &plan.skew {
+        let hot_keys = skew
+            .hot_keys
+            .iter()
+            .map(|key| key.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let side = if skew.build_skewed { "build" } else { "probe" };
+        children.push(FormatTreeNode::new(format!(
+            "hot keys: [{hot_keys}] ({side})"
+        )));
+    }
+
     if let Some(info) = &plan.stat_info {
         let items = plan_stats_info_to_format_tree(info);
         children.extend(items);
@@ -277,6 +277,7 @@ pub trait PhysicalPlanReplacer {
             broadcast: plan.broadcast,
             single_to_inner: plan.single_to_inner.clone(),
             build_side_cache_info: plan.build_side_cache_info.clone(),
+            skew: plan.skew.clone(),
         }))
     }
 
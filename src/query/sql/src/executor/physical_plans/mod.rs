@@ -79,6 +79,9 @@ pub use physical_exchange_source::ExchangeSource;
 pub use physical_expression_scan::ExpressionScan;
 pub use physical_filter::Filter;
 pub use physical_hash_join::HashJoin;
+pub use physical_hash_join::JoinSkew;
+pub(crate) use physical_join::physical_join;
+pub(crate) use physical_join::split_range_conditions;
 pub use physical_join::PhysicalJoinType;
 pub use physical_limit::Limit;
 pub use physical_multi_table_insert::*;
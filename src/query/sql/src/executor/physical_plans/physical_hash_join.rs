@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
@@ -21,13 +22,17 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::type_check::check_cast;
 use databend_common_expression::type_check::common_super_type;
+use databend_common_expression::types::number::NumberScalar;
 use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
 use databend_common_expression::ConstantFolder;
 use databend_common_expression::DataField;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::DataSchemaRefExt;
 use databend_common_expression::RemoteExpr;
+use databend_common_expression::Scalar;
 use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_storage::Datum;
 use databend_storages_common_table_meta::table::get_change_type;
 
 use crate::executor::explain::PlanStatsInfo;
@@ -91,6 +96,21 @@ pub struct HashJoin {
     // Hash join build side cache information for ExpressionScan, which includes the cache index and
     // a HashMap for mapping the column indexes to the BlockEntry indexes in DataBlock.
     pub build_side_cache_info: Option<(usize, HashMap<IndexType, usize>)>,
+
+    // The hot keys of a shuffle join, found in the statistics of its inputs.
+    pub skew: Option<JoinSkew>,
+}
+
+/// The hot keys of a shuffle join.
+///
+/// The rows with the hot keys are spread evenly across the nodes on the skewed side of the join
+/// and sent to all the nodes on the other side, instead of being sent to the node of their hash.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct JoinSkew {
+    // The hot values of the join key, in the type of the shuffle keys.
+    pub hot_keys: Vec<Scalar>,
+    // Whether the hot keys are found in the build side, otherwise in the probe side.
+    pub build_skewed: bool,
 }
 
 impl HashJoin {
@@ -172,6 +192,8 @@ impl PhysicalPlanBuilder {
             }
         }
 
+        let skew = self.join_skew(join, s_expr, &probe_side, &build_side)?;
+
         let build_schema = match join.join_type {
             JoinType::Left | JoinType::LeftSingle | JoinType::Full => {
                 let build_schema = build_side.output_schema()?;
@@ -544,8 +566,156 @@ impl PhysicalPlanBuilder {
             )
             .await?,
             build_side_cache_info,
+            skew,
         }))
     }
+
+    // Find the hot keys of a shuffle join from the accurate histograms of its inputs, which are
+    // collected by `ANALYZE TABLE` with `enable_analyze_histogram`.
+    fn join_skew(
+        &self,
+        join: &Join,
+        s_expr: &SExpr,
+        probe_side: &PhysicalPlan,
+        build_side: &PhysicalPlan,
+    ) -> Result<Option<JoinSkew>> {
+        let percent = self.ctx.get_settings().get_join_skew_hot_key_percent()?;
+        if percent == 0
+            || join.equi_conditions.len() != 1
+            || join.need_hold_hash_table
+            || join.build_side_cache_info.is_some()
+        {
+            return Ok(None);
+        }
+        let (
+            PhysicalPlan::Exchange(Exchange {
+                kind: FragmentKind::Normal,
+                keys: probe_keys,
+                ..
+            }),
+            PhysicalPlan::Exchange(Exchange {
+                kind: FragmentKind::Normal,
+                keys: build_keys,
+                ..
+            }),
+        ) = (probe_side, build_side)
+        else {
+            return Ok(None);
+        };
+        let condition = &join.equi_conditions[0];
+
+        // The probe rows with a hot key can be split if each of them is joined with all the
+        // matched build rows on one node.
+        if matches!(
+            join.join_type,
+            JoinType::Inner | JoinType::Left | JoinType::LeftSemi | JoinType::LeftAnti
+        ) {
+            let key_type = probe_keys[0]
+                .as_expr(&BUILTIN_FUNCTIONS)
+                .data_type()
+                .clone();
+            let hot_keys = hot_join_keys(s_expr.child(0)?, &condition.left, &key_type, percent)?;
+            if !hot_keys.is_empty() {
+                return Ok(Some(JoinSkew {
+                    hot_keys,
+                    build_skewed: false,
+                }));
+            }
+        }
+
+        // The build rows with a hot key can only be split if the unmatched build rows and the
+        // duplicated probe rows are not in the output.
+        if join.join_type == JoinType::Inner {
+            let key_type = build_keys[0]
+                .as_expr(&BUILTIN_FUNCTIONS)
+                .data_type()
+                .clone();
+            let hot_keys = hot_join_keys(s_expr.child(1)?, &condition.right, &key_type, percent)?;
+            if !hot_keys.is_empty() {
+                return Ok(Some(JoinSkew {
+                    hot_keys,
+                    build_skewed: true,
+                }));
+            }
+        }
+        Ok(None)
+    }
+}
+
+// The values of a join key that are in more than `percent` of the rows of the join input. A hot
+// value fills up the buckets of an accurate histogram that have no other values.
+fn hot_join_keys(
+    input: &SExpr,
+    key: &ScalarExpr,
+    key_type: &DataType,
+    percent: u64,
+) -> Result<Vec<Scalar>> {
+    let ScalarExpr::BoundColumnRef(column) = key else {
+        return Ok(vec![]);
+    };
+    let stat_info = RelExpr::with_s_expr(input).derive_cardinality()?;
+    let Some(histogram) = stat_info
+        .statistics
+        .column_stats
+        .get(&column.column.index)
+        .and_then(|stat| stat.histogram.as_ref())
+    else {
+        return Ok(vec![]);
+    };
+    let num_values = histogram.num_values();
+    if !histogram.accuracy || num_values <= 0.0 {
+        return Ok(vec![]);
+    }
+
+    let mut values: Vec<(&Datum, f64)> = vec![];
+    for bucket in histogram.buckets_iter() {
+        let value = bucket.lower_bound();
+        if !value.type_comparable(bucket.upper_bound())
+            || value.compare(bucket.upper_bound())? != Ordering::Equal
+        {
+            continue;
+        }
+        match values.last_mut() {
+            Some((last, count))
+                if last.type_comparable(value) && last.compare(value)? == Ordering::Equal =>
+            {
+                *count += bucket.num_values();
+            }
+            _ => values.push((value, bucket.num_values())),
+        }
+    }
+
+    Ok(values
+        .into_iter()
+        .filter(|(_, count)| *count * 100.0 >= num_values * percent as f64)
+        .filter_map(|(value, _)| datum_to_scalar(value, key_type))
+        .collect())
+}
+
+fn datum_to_scalar(datum: &Datum, data_type: &DataType) -> Option<Scalar> {
+    let int = match datum {
+        Datum::Int(v) => Some(*v as i128),
+        Datum::UInt(v) => Some(*v as i128),
+        _ => None,
+    };
+    Some(match (data_type.remove_nullable(), datum) {
+        (DataType::Boolean, Datum::Bool(v)) => Scalar::Boolean(*v),
+        (DataType::String, Datum::Bytes(v)) => Scalar::String(String::from_utf8(v.clone()).ok()?),
+        (DataType::Date, _) => Scalar::Date(i32::try_from(int?).ok()?),
+        (DataType::Timestamp, _) => Scalar::Timestamp(i64::try_from(int?).ok()?),
+        (DataType::Number(ty), _) => Scalar::Number(match ty {
+            NumberDataType::UInt8 => NumberScalar::UInt8(u8::try_from(int?).ok()?),
+            NumberDataType::UInt16 => NumberScalar::UInt16(u16::try_from(int?).ok()?),
+            NumberDataType::UInt32 => NumberScalar::UInt32(u32::try_from(int?).ok()?),
+            NumberDataType::UInt64 => NumberScalar::UInt64(u64::try_from(int?).ok()?),
+            NumberDataType::Int8 => NumberScalar::Int8(i8::try_from(int?).ok()?),
+            NumberDataType::Int16 => NumberScalar::Int16(i16::try_from(int?).ok()?),
+            NumberDataType::Int32 => NumberScalar::Int32(i32::try_from(int?).ok()?),
+            NumberDataType::Int64 => NumberScalar::Int64(i64::try_from(int?).ok()?),
+            NumberDataType::Float32 | NumberDataType::Float64 => return None,
+        }),
+        _ => return None,
+    })
 }
 
 // Check if enable bloom runtime filter
@@ -24,6 +24,7 @@ use crate::optimizer::RelExpr;
 use crate::optimizer::RelationalProperty;
 use crate::optimizer::SExpr;
 use crate::plans::Join;
+use crate::plans::JoinMethod;
 use crate::plans::JoinType;
 use crate::ColumnSet;
 use crate::ScalarExpr;
@@ -46,18 +47,43 @@ pub fn physical_join(join: &Join, s_expr: &SExpr) -> Result<PhysicalJoinType> {
         return Ok(PhysicalJoinType::Hash);
     }
 
-    let left_rel_expr = RelExpr::with_s_expr(s_expr.child(0)?);
+    if matches!(
+        join.method_hint,
+        Some(JoinMethod::Hash | JoinMethod::NestedLoop)
+    ) {
+        // The join method is chosen by hints, nested loop join is executed by hash join.
+        return Ok(PhysicalJoinType::Hash);
+    }
+
     let right_rel_expr = RelExpr::with_s_expr(s_expr.child(1)?);
     let right_stat_info = right_rel_expr.derive_cardinality()?;
-    if matches!(right_stat_info.statistics.precise_cardinality, Some(1))
-        || right_stat_info.cardinality == 1.0
+    if join.method_hint.is_none()
+        && (matches!(right_stat_info.statistics.precise_cardinality, Some(1))
+            || right_stat_info.cardinality == 1.0)
     {
         // If the output rows of build side is equal to 1, we use CROSS JOIN + FILTER instead of RANGE JOIN.
         return Ok(PhysicalJoinType::Hash);
     }
 
-    let left_prop = left_rel_expr.derive_relational_prop()?;
-    let right_prop = right_rel_expr.derive_relational_prop()?;
+    let (range_conditions, other_conditions) = split_range_conditions(join, s_expr)?;
+    if !range_conditions.is_empty() && matches!(join.join_type, JoinType::Inner | JoinType::Cross) {
+        return Ok(PhysicalJoinType::RangeJoin(
+            range_conditions,
+            other_conditions,
+        ));
+    }
+
+    // Leverage hash join to execute nested loop join
+    Ok(PhysicalJoinType::Hash)
+}
+
+/// Split the non-equi conditions of a join into range conditions and other conditions.
+pub(crate) fn split_range_conditions(
+    join: &Join,
+    s_expr: &SExpr,
+) -> Result<(Vec<ScalarExpr>, Vec<ScalarExpr>)> {
+    let left_prop = RelExpr::with_s_expr(s_expr.child(0)?).derive_relational_prop()?;
+    let right_prop = RelExpr::with_s_expr(s_expr.child(1)?).derive_relational_prop()?;
     let mut range_conditions = vec![];
     let mut other_conditions = vec![];
     for condition in join.non_equi_conditions.iter() {
@@ -69,16 +95,7 @@ pub fn physical_join(join: &Join, s_expr: &SExpr) -> Result<PhysicalJoinType> {
             &mut other_conditions,
         )
     }
-
-    if !range_conditions.is_empty() && matches!(join.join_type, JoinType::Inner | JoinType::Cross) {
-        return Ok(PhysicalJoinType::RangeJoin(
-            range_conditions,
-            other_conditions,
-        ));
-    }
-
-    // Leverage hash join to execute nested loop join
-    Ok(PhysicalJoinType::Hash)
+    Ok((range_conditions, other_conditions))
 }
 
 fn check_condition(
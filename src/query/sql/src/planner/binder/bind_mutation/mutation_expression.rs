@@ -97,7 +97,7 @@ impl MutationExpression {
 
                 // Bind target table reference.
                 let (mut target_s_expr, mut target_context) =
-                    binder.bind_table_reference(bind_context, target)?;
+                    binder.bind_mutation_target(bind_context, target)?;
 
                 // Get target table index.
                 let target_table_index = binder
@@ -185,7 +185,7 @@ impl MutationExpression {
             | MutationExpression::Delete { target, filter } => {
                 // Bind target table reference.
                 let (mut s_expr, mut bind_context) =
-                    binder.bind_table_reference(bind_context, target)?;
+                    binder.bind_mutation_target(bind_context, target)?;
 
                 // Get target table index.
                 let target_table_index = binder
@@ -71,6 +71,13 @@ impl Binder {
                     hints, e
                 );
             }
+            for hint in &hints.optimizer_hints {
+                let mut hint = hint.clone();
+                for table in hint.tables_mut() {
+                    *table = self.normalize_identifier(table);
+                }
+                self.metadata.write().add_optimizer_hint(hint);
+            }
         }
 
         // whether allow rewrite virtual column and pushdown
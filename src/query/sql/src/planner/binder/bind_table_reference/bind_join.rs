@@ -443,6 +443,8 @@ impl Binder {
             is_lateral,
             single_to_inner: None,
             build_side_cache_info,
+            method_hint: None,
+            distribution_hint: None,
         };
         Ok(SExpr::create_binary(
             Arc::new(logical_join.into()),
@@ -21,6 +21,7 @@ use databend_common_ast::ast::Statement;
 use databend_common_ast::ast::TableAlias;
 use databend_common_ast::ast::TemporalClause;
 use databend_common_ast::ast::WithOptions;
+use databend_common_ast::parser::parse_expr;
 use databend_common_ast::parser::parse_sql;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_ast::Span;
@@ -37,13 +38,16 @@ use databend_common_meta_app::schema::VirtualField;
 use databend_common_meta_types::MetaId;
 use databend_common_storages_view::view_table::QUERY;
 use databend_storages_common_table_meta::table::get_change_type;
+use databend_storages_common_table_meta::table::OPT_KEY_TTL;
 
 use crate::binder::util::TableIdentifier;
 use crate::binder::Binder;
 use crate::optimizer::SExpr;
+use crate::plans::Filter;
 use crate::BindContext;
 use crate::ColumnBindingBuilder;
 use crate::IndexType;
+use crate::ScalarBinder;
 use crate::Visibility;
 
 impl Binder {
@@ -312,11 +316,47 @@ impl Binder {
                     table_name.as_str(),
                 )?;
 
+                let s_expr = match table_meta.options().get(OPT_KEY_TTL) {
+                    Some(ttl) if !self.skip_table_ttl && !bind_context.planning_agg_index => {
+                        self.bind_table_ttl(&mut bind_context, ttl, s_expr)?
+                    }
+                    _ => s_expr,
+                };
+
                 Ok((s_expr, bind_context))
             }
         }
     }
 
+    /// Filters out the rows of a table which are expired by its TTL.
+    ///
+    /// Whole expired blocks are dropped by compaction, the rows of partially expired blocks are
+    /// only removed when the blocks get rewritten, so they must be filtered out on read.
+    fn bind_table_ttl(
+        &mut self,
+        bind_context: &mut BindContext,
+        ttl: &str,
+        child: SExpr,
+    ) -> Result<SExpr> {
+        let tokens = tokenize_sql(&format!("ifnull(({}) > now(), true)", ttl))?;
+        let expr = parse_expr(&tokens, self.dialect)?;
+        let mut scalar_binder = ScalarBinder::new(
+            bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+        let (scalar, _) = scalar_binder.bind(&expr)?;
+        let filter = Filter {
+            predicates: vec![scalar],
+        };
+        Ok(SExpr::create_unary(
+            Arc::new(filter.into()),
+            Arc::new(child),
+        ))
+    }
+
     pub(crate) fn check_view_dep(
         bind_context: &BindContext,
         database: &str,
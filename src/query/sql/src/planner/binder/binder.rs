@@ -23,6 +23,7 @@ use databend_common_ast::ast::Hint;
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::Settings;
 use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TableReference;
 use databend_common_ast::parser::parse_sql;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_ast::parser::Dialect;
@@ -107,6 +108,9 @@ pub struct Binder {
     /// if meet recursive cte table name in cte definition, set `bind_recursive_cte` true and treat it as `CteScan`.
     pub bind_recursive_cte: bool,
     pub m_cte_table_name: HashMap<String, String>,
+    /// Rows expired by the TTL of a table are filtered out when it is bound, unless it is the
+    /// target of a mutation, which must see the whole table.
+    pub skip_table_ttl: bool,
 
     pub enable_result_cache: bool,
 
@@ -134,6 +138,7 @@ impl<'a> Binder {
             expression_scan_context: ExpressionScanContext::new(),
             bind_recursive_cte: false,
             m_cte_table_name: HashMap::new(),
+            skip_table_ttl: false,
             enable_result_cache,
             subquery_executor: None,
         }
@@ -164,6 +169,8 @@ impl<'a> Binder {
         let mut bind_context = BindContext::new();
         let plan = self.bind_statement(&mut bind_context, stmt).await?;
         self.bind_query_index(&mut bind_context, &plan).await?;
+        self.bind_materialized_views(&mut bind_context, &plan)
+            .await?;
         self.ctx.set_status_info(&format!(
             "bind stmt to plan done, time used: {:?}",
             start.elapsed()
@@ -270,6 +277,8 @@ impl<'a> Binder {
             Statement::CreateDatabase(stmt) => self.bind_create_database(stmt).await?,
             Statement::DropDatabase(stmt) => self.bind_drop_database(stmt).await?,
             Statement::UndropDatabase(stmt) => self.bind_undrop_database(stmt).await?,
+            Statement::BackupDatabase(stmt) => self.bind_backup_database(stmt).await?,
+            Statement::RestoreDatabase(stmt) => self.bind_restore_database(stmt).await?,
             Statement::AlterDatabase(stmt) => self.bind_alter_database(stmt).await?,
             Statement::UseDatabase { database } => {
                 let database = normalize_identifier(database, &self.name_resolution_ctx).name;
@@ -315,6 +324,18 @@ impl<'a> Binder {
             Statement::ShowViews(stmt) => self.bind_show_views(bind_context, stmt).await?,
             Statement::DescribeView(stmt) => self.bind_describe_view(stmt).await?,
 
+            // Materialized views
+            Statement::CreateMaterializedView(stmt) => {
+                self.bind_create_materialized_view(stmt).await?
+            }
+            Statement::DropMaterializedView(stmt) => self.bind_drop_materialized_view(stmt).await?,
+            Statement::RefreshMaterializedView(stmt) => {
+                self.bind_refresh_materialized_view(stmt).await?
+            }
+
+            // Plan baselines
+            Statement::AlterQuery(stmt) => self.bind_alter_query(bind_context, stmt).await?,
+
             // Indexes
             Statement::CreateIndex(stmt) => self.bind_create_index(bind_context, stmt).await?,
             Statement::DropIndex(stmt) => self.bind_drop_index(stmt).await?,
@@ -616,6 +637,15 @@ impl<'a> Binder {
             Statement::Begin => Plan::Begin,
             Statement::Commit => Plan::Commit,
             Statement::Abort => Plan::Abort,
+            Statement::Savepoint(name) => {
+                Plan::Savepoint(self.normalize_identifier(name).name)
+            }
+            Statement::RollbackToSavepoint(name) => {
+                Plan::RollbackToSavepoint(self.normalize_identifier(name).name)
+            }
+            Statement::ReleaseSavepoint(name) => {
+                Plan::ReleaseSavepoint(self.normalize_identifier(name).name)
+            }
             Statement::ExecuteImmediate(stmt) => self.bind_execute_immediate(stmt).await?,
             Statement::SetPriority { priority, object_id } => {
                 self.bind_set_priority(priority, object_id).await?
@@ -746,6 +776,18 @@ impl<'a> Binder {
         self.bind_recursive_cte = val;
     }
 
+    /// Binds the target table of a mutation, rows expired by its TTL are not filtered out.
+    pub(crate) fn bind_mutation_target(
+        &mut self,
+        bind_context: &mut BindContext,
+        target: &TableReference,
+    ) -> Result<(SExpr, BindContext)> {
+        self.skip_table_ttl = true;
+        let res = self.bind_table_reference(bind_context, target);
+        self.skip_table_ttl = false;
+        res
+    }
+
     #[async_backtrace::framed]
     pub(crate) async fn bind_rewrite_to_query(
         &mut self,
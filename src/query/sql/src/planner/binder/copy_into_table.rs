@@ -186,6 +186,7 @@ impl Binder {
             Some(cols) => self.schema_project(&table.schema(), cols)?,
             None => self.schema_project(&table.schema(), &[])?,
         };
+        Self::check_identity_columns_not_given(table.as_ref(), &stage_schema)?;
 
         let required_values_schema: DataSchemaRef = Arc::new(stage_schema.clone().into());
 
@@ -490,6 +491,7 @@ impl Binder {
                         value: Literal::UInt64(1),
                     },
                 }],
+                optimizer_hints: vec![],
             };
             if let Some(e) = self.opt_hints_set_var(&mut output_context, &hints).err() {
                 warn!(
@@ -16,16 +16,19 @@ use std::collections::BTreeMap;
 
 use databend_common_ast::ast::AlterDatabaseAction;
 use databend_common_ast::ast::AlterDatabaseStmt;
+use databend_common_ast::ast::BackupDatabaseStmt;
 use databend_common_ast::ast::CreateDatabaseStmt;
 use databend_common_ast::ast::DatabaseEngine;
 use databend_common_ast::ast::DatabaseRef;
 use databend_common_ast::ast::DropDatabaseStmt;
+use databend_common_ast::ast::RestoreDatabaseStmt;
 use databend_common_ast::ast::SQLProperty;
 use databend_common_ast::ast::ShowCreateDatabaseStmt;
 use databend_common_ast::ast::ShowDatabasesStmt;
 use databend_common_ast::ast::ShowDropDatabasesStmt;
 use databend_common_ast::ast::ShowLimit;
 use databend_common_ast::ast::UndropDatabaseStmt;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
 use databend_common_expression::DataField;
@@ -33,13 +36,16 @@ use databend_common_expression::DataSchemaRefExt;
 use databend_common_meta_app::schema::DatabaseMeta;
 use log::debug;
 
+use crate::binder::resolve_file_location;
 use crate::binder::Binder;
 use crate::planner::semantic::normalize_identifier;
+use crate::plans::BackupDatabasePlan;
 use crate::plans::CreateDatabasePlan;
 use crate::plans::DropDatabasePlan;
 use crate::plans::Plan;
 use crate::plans::RenameDatabaseEntity;
 use crate::plans::RenameDatabasePlan;
+use crate::plans::RestoreDatabasePlan;
 use crate::plans::RewriteKind;
 use crate::plans::ShowCreateDatabasePlan;
 use crate::plans::UndropDatabasePlan;
@@ -243,6 +249,68 @@ impl Binder {
         })))
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_backup_database(
+        &self,
+        stmt: &BackupDatabaseStmt,
+    ) -> Result<Plan> {
+        let BackupDatabaseStmt {
+            catalog,
+            database,
+            location,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let catalog = catalog
+            .as_ref()
+            .map(|catalog| normalize_identifier(catalog, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_catalog());
+        let database = normalize_identifier(database, &self.name_resolution_ctx).name;
+        let (stage_info, path) = resolve_file_location(self.ctx.as_ref(), location).await?;
+
+        Ok(Plan::BackupDatabase(Box::new(BackupDatabasePlan {
+            tenant,
+            catalog,
+            database,
+            stage_info: Box::new(stage_info),
+            path,
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_restore_database(
+        &self,
+        stmt: &RestoreDatabaseStmt,
+    ) -> Result<Plan> {
+        let RestoreDatabaseStmt {
+            catalog,
+            database,
+            location,
+            new_database,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let catalog = catalog
+            .as_ref()
+            .map(|catalog| normalize_identifier(catalog, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_catalog());
+        let database = normalize_identifier(database, &self.name_resolution_ctx).name;
+        let new_database = new_database
+            .as_ref()
+            .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| database.clone());
+        let (stage_info, path) = resolve_file_location(self.ctx.as_ref(), location).await?;
+
+        Ok(Plan::RestoreDatabase(Box::new(RestoreDatabasePlan {
+            tenant,
+            catalog,
+            database,
+            new_database,
+            stage_info: Box::new(stage_info),
+            path,
+        })))
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_create_database(
         &self,
@@ -253,6 +321,7 @@ impl Binder {
             database: DatabaseRef { catalog, database },
             engine,
             options,
+            clone_from,
         } = stmt;
 
         let tenant = self.ctx.get_tenant();
@@ -264,12 +333,32 @@ impl Binder {
 
         let meta = self.database_meta(engine, options)?;
 
+        let clone_from = match clone_from {
+            Some(DatabaseRef {
+                catalog: source_catalog,
+                database: source_database,
+            }) => {
+                let source_catalog = source_catalog
+                    .as_ref()
+                    .map(|catalog| normalize_identifier(catalog, &self.name_resolution_ctx).name)
+                    .unwrap_or_else(|| self.ctx.get_current_catalog());
+                if source_catalog != catalog {
+                    return Err(ErrorCode::BadArguments(
+                        "CREATE DATABASE ... CLONE across catalogs is not supported",
+                    ));
+                }
+                Some(normalize_identifier(source_database, &self.name_resolution_ctx).name)
+            }
+            None => None,
+        };
+
         Ok(Plan::CreateDatabase(Box::new(CreateDatabasePlan {
             create_option: create_option.clone().into(),
             tenant,
             catalog,
             database,
             meta,
+            clone_from,
         })))
     }
 
@@ -0,0 +1,386 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use databend_common_ast::ast::CreateMaterializedViewStmt;
+use databend_common_ast::ast::CreateTableStmt;
+use databend_common_ast::ast::DropMaterializedViewStmt;
+use databend_common_ast::ast::Engine;
+use databend_common_ast::ast::ExplainKind;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::RefreshMaterializedViewStmt;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TableType;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::TableInfo;
+use databend_storages_common_table_meta::table::encode_materialized_view_sources;
+use databend_storages_common_table_meta::table::parse_materialized_view_sources;
+use databend_storages_common_table_meta::table::parse_materialized_views;
+use databend_storages_common_table_meta::table::MaterializedViewSource;
+use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_AUTO_REFRESH;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCES;
+use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use derive_visitor::DriveMut;
+
+use crate::binder::Binder;
+use crate::plans::CreateMaterializedViewPlan;
+use crate::plans::DropMaterializedViewPlan;
+use crate::plans::Plan;
+use crate::plans::RefreshMaterializedViewPlan;
+use crate::BindContext;
+use crate::MaterializedViewEntry;
+use crate::MaterializedViewRewriter;
+use crate::MetadataRef;
+use crate::Visibility;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_materialized_view(
+        &mut self,
+        stmt: &CreateMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let CreateMaterializedViewStmt {
+            create_option,
+            catalog,
+            database,
+            view,
+            refresh_on_write,
+            query,
+        } = stmt;
+
+        let (_, database_name, _) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+        let mut query = *query.clone();
+        let mut rewriter = MaterializedViewRewriter::new(Identifier::from_name_with_quoted(
+            None,
+            &database_name,
+            Some(self.dialect.default_ident_quote()),
+        ));
+        query.drive_mut(&mut rewriter);
+        rewriter.check()?;
+
+        let create_table = CreateTableStmt {
+            create_option: create_option.clone(),
+            catalog: catalog.clone(),
+            database: database.clone(),
+            table: view.clone(),
+            source: None,
+            engine: Some(Engine::Fuse),
+            uri_location: None,
+            cluster_by: None,
+            table_options: BTreeMap::new(),
+            as_query: Some(Box::new(query.clone())),
+            table_type: TableType::Normal,
+        };
+        let Plan::CreateTable(mut plan) = self.bind_create_table(&create_table).await? else {
+            unreachable!()
+        };
+
+        let Some(Plan::Query { metadata, .. }) = plan.as_select.as_deref() else {
+            unreachable!()
+        };
+        let mut sources: Vec<MaterializedViewSource> = vec![];
+        for table_entry in metadata.read().tables() {
+            let table = table_entry.table();
+            if table_entry.is_source_of_view() {
+                return Err(ErrorCode::IllegalMaterializedView(format!(
+                    "Materialized view can't be created on view '{}'",
+                    table.name()
+                )));
+            }
+            if table.engine() != "FUSE" || table.is_temp() {
+                return Err(ErrorCode::IllegalMaterializedView(format!(
+                    "Materialized view can only be created on fuse tables, but '{}' is a {} table",
+                    table.name(),
+                    if table.is_temp() {
+                        "temporary"
+                    } else {
+                        table.engine()
+                    }
+                )));
+            }
+            // the view is refreshed by its writer, it would expose the masked data as seen
+            // by the role of the writer to any reader of the view.
+            if has_mask_policy(table.get_table_info()) {
+                return Err(ErrorCode::IllegalMaterializedView(format!(
+                    "Materialized view can't be created on table '{}' with masking policies",
+                    table.name()
+                )));
+            }
+            if sources
+                .iter()
+                .all(|source| source.table_id != table.get_id())
+            {
+                sources.push(MaterializedViewSource {
+                    table_id: table.get_id(),
+                    snapshot_location: table.options().get(OPT_KEY_SNAPSHOT_LOCATION).cloned(),
+                });
+            }
+        }
+
+        plan.options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_QUERY.to_string(),
+            query.to_string(),
+        );
+        plan.options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_AUTO_REFRESH.to_string(),
+            refresh_on_write.to_string(),
+        );
+        plan.options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_SOURCES.to_string(),
+            encode_materialized_view_sources(&sources)?,
+        );
+
+        Ok(Plan::CreateMaterializedView(Box::new(
+            CreateMaterializedViewPlan {
+                create_table: *plan,
+            },
+        )))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_materialized_view(
+        &mut self,
+        stmt: &DropMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let DropMaterializedViewStmt {
+            if_exists,
+            catalog,
+            database,
+            view,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let (catalog, database, view_name) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+        Ok(Plan::DropMaterializedView(Box::new(
+            DropMaterializedViewPlan {
+                if_exists: *if_exists,
+                tenant,
+                catalog,
+                database,
+                view_name,
+            },
+        )))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_refresh_materialized_view(
+        &mut self,
+        stmt: &RefreshMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let RefreshMaterializedViewStmt {
+            catalog,
+            database,
+            view,
+        } = stmt;
+
+        let (catalog, database, view_name) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+        Ok(Plan::RefreshMaterializedView(Box::new(
+            RefreshMaterializedViewPlan {
+                catalog,
+                database,
+                view_name,
+            },
+        )))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_materialized_views(
+        &mut self,
+        bind_context: &mut BindContext,
+        plan: &Plan,
+    ) -> Result<()> {
+        match plan {
+            Plan::Query { metadata, .. } => {
+                self.do_bind_materialized_views(bind_context, metadata)
+                    .await?;
+            }
+            Plan::Explain { kind, plan, .. }
+                if matches!(kind, ExplainKind::Plan) && matches!(**plan, Plan::Query { .. }) =>
+            {
+                match **plan {
+                    Plan::Query { ref metadata, .. } => {
+                        self.do_bind_materialized_views(bind_context, metadata)
+                            .await?;
+                    }
+                    _ => unreachable!(),
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Binds the fresh materialized views over the tables of the query, so that the optimizer
+    /// can rewrite the query to read from them.
+    ///
+    /// A materialized view is fresh if the snapshots of its sources are the snapshots read by
+    /// the query.
+    #[async_backtrace::framed]
+    async fn do_bind_materialized_views(
+        &mut self,
+        bind_context: &mut BindContext,
+        metadata: &MetadataRef,
+    ) -> Result<()> {
+        if bind_context.planning_agg_index
+            || !self
+                .ctx
+                .get_settings()
+                .get_enable_materialized_view_rewrite()?
+        {
+            return Ok(());
+        }
+
+        let tables = metadata.read().tables().to_vec();
+        let snapshots = tables
+            .iter()
+            .map(|table_entry| {
+                let table = table_entry.table();
+                (
+                    table.get_id(),
+                    table.options().get(OPT_KEY_SNAPSHOT_LOCATION).cloned(),
+                )
+            })
+            .collect::<HashMap<_, _>>();
+        // a masking policy may have been set on a source after the view was created
+        let masked = tables
+            .iter()
+            .map(|table_entry| table_entry.table())
+            .filter(|table| has_mask_policy(table.get_table_info()))
+            .map(|table| table.get_id())
+            .collect::<HashSet<_>>();
+
+        let mut visited = vec![];
+        for table_entry in tables {
+            let table = table_entry.table();
+            if table_entry.is_source_of_index() || table.engine() != "FUSE" {
+                continue;
+            }
+            let catalog = self.ctx.get_catalog(table_entry.catalog()).await?;
+            for view_id in parse_materialized_views(table.options()) {
+                if visited.contains(&view_id) {
+                    continue;
+                }
+                visited.push(view_id);
+
+                let Some(view_meta) = catalog.get_table_meta_by_id(view_id).await? else {
+                    continue;
+                };
+                if view_meta.drop_on.is_some() {
+                    continue;
+                }
+                let Some(query) = view_meta.options.get(OPT_KEY_MATERIALIZED_VIEW_QUERY) else {
+                    continue;
+                };
+                let sources = parse_materialized_view_sources(&view_meta.options)?;
+                let is_fresh = sources.iter().all(|source| {
+                    snapshots
+                        .get(&source.table_id)
+                        .is_some_and(|location| *location == source.snapshot_location)
+                });
+                if !is_fresh
+                    || sources
+                        .iter()
+                        .any(|source| masked.contains(&source.table_id))
+                {
+                    continue;
+                }
+
+                let Some(db_id) = view_meta
+                    .options
+                    .get(OPT_KEY_DATABASE_ID)
+                    .and_then(|id| id.parse::<u64>().ok())
+                else {
+                    continue;
+                };
+                let database = catalog.get_db_name_by_id(db_id).await?;
+                let Some(view_name) = catalog.get_table_name_by_id(view_id).await? else {
+                    continue;
+                };
+                let view = self
+                    .ctx
+                    .get_table(table_entry.catalog(), &database, &view_name)
+                    .await?;
+                if view.get_id() != view_id {
+                    continue;
+                }
+
+                let tokens = tokenize_sql(query)?;
+                let (stmt, _) = parse_sql(&tokens, self.dialect)?;
+                let Statement::Query(query) = &stmt else {
+                    continue;
+                };
+                let mut view_bind_context = BindContext::with_parent(bind_context.clone())?;
+                view_bind_context.planning_agg_index = true;
+                let (s_expr, view_bind_context) = self.bind_query(&mut view_bind_context, query)?;
+
+                let table_index = self.metadata.write().add_table(
+                    table_entry.catalog().to_string(),
+                    database.clone(),
+                    view,
+                    None,
+                    false,
+                    true,
+                    false,
+                    None,
+                );
+                let (scan, scan_bind_context) =
+                    self.bind_base_table(bind_context, &database, table_index, None, &None)?;
+                let columns = scan_bind_context
+                    .columns
+                    .iter()
+                    .filter(|column| column.visibility == Visibility::Visible)
+                    .map(|column| column.index)
+                    .collect::<Vec<_>>();
+                if columns.len() != view_bind_context.columns.len() {
+                    continue;
+                }
+
+                metadata
+                    .write()
+                    .add_materialized_view(MaterializedViewEntry {
+                        name: format!("{}.{}.{}", table_entry.catalog(), database, view_name),
+                        s_expr,
+                        output_columns: view_bind_context
+                            .columns
+                            .iter()
+                            .map(|column| column.index)
+                            .collect(),
+                        scan,
+                        columns,
+                    });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn has_mask_policy(table_info: &TableInfo) -> bool {
+    table_info
+        .meta
+        .column_mask_policy
+        .as_ref()
+        .is_some_and(|policies| !policies.is_empty())
+}
@@ -0,0 +1,80 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::ast::AlterQueryAction;
+use databend_common_ast::ast::AlterQueryStmt;
+use databend_common_ast::ast::SelectStmt;
+use databend_common_ast::ast::Statement;
+use databend_common_exception::Result;
+use derive_visitor::DriveMut;
+use derive_visitor::VisitorMut;
+
+use crate::binder::Binder;
+use crate::plans::PinPlanBaselinePlan;
+use crate::plans::Plan;
+use crate::plans::UnpinPlanBaselinePlan;
+use crate::BindContext;
+use crate::Planner;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_query(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &AlterQueryStmt,
+    ) -> Result<Plan> {
+        let AlterQueryStmt { query, action } = stmt;
+
+        // The baseline is used by the query without optimizer hints.
+        let mut normalized = query.clone();
+        normalized.drive_mut(&mut OptimizerHintRemover);
+        let normalized = Statement::Query(normalized);
+        let query_text = normalized.to_string();
+        let sql_hash = Planner::plan_baseline_key(&normalized);
+
+        match action {
+            AlterQueryAction::PinPlan => {
+                let plan = self
+                    .bind_statement(bind_context, &Statement::Query(query.clone()))
+                    .await?;
+                Ok(Plan::PinPlanBaseline(Box::new(PinPlanBaselinePlan {
+                    sql_hash,
+                    query_text,
+                    plan: Box::new(plan),
+                })))
+            }
+            AlterQueryAction::UnpinPlan => {
+                Ok(Plan::UnpinPlanBaseline(Box::new(UnpinPlanBaselinePlan {
+                    sql_hash,
+                    query_text,
+                })))
+            }
+        }
+    }
+}
+
+#[derive(VisitorMut)]
+#[visitor(SelectStmt(enter))]
+struct OptimizerHintRemover;
+
+impl OptimizerHintRemover {
+    fn enter_select_stmt(&mut self, select: &mut SelectStmt) {
+        if let Some(hints) = &mut select.hints {
+            hints.optimizer_hints.clear();
+            if hints.hints_list.is_empty() {
+                select.hints = None;
+            }
+        }
+    }
+}
@@ -45,6 +45,7 @@ impl Binder {
             stream,
             table_database,
             table,
+            on_view,
             travel_point,
             append_only,
             comment,
@@ -74,6 +75,7 @@ impl Binder {
             stream_name,
             table_database,
             table_name,
+            on_view: *on_view,
             navigation,
             append_only: *append_only,
             comment: comment.clone(),
@@ -26,6 +26,8 @@ use databend_common_ast::ast::ClusterType as AstClusterType;
 use databend_common_ast::ast::ColumnDefinition;
 use databend_common_ast::ast::ColumnExpr;
 use databend_common_ast::ast::CompactTarget;
+use databend_common_ast::ast::ConstraintDefinition;
+use databend_common_ast::ast::ConstraintType;
 use databend_common_ast::ast::CreateTableSource;
 use databend_common_ast::ast::CreateTableStmt;
 use databend_common_ast::ast::DescribeTableStmt;
@@ -48,6 +50,7 @@ use databend_common_ast::ast::ShowTablesStmt;
 use databend_common_ast::ast::Statement;
 use databend_common_ast::ast::TableReference;
 use databend_common_ast::ast::TableType;
+use databend_common_ast::ast::TimeTravelPoint;
 use databend_common_ast::ast::TruncateTableStmt;
 use databend_common_ast::ast::TypeName;
 use databend_common_ast::ast::UndropTableStmt;
@@ -63,7 +66,9 @@ use databend_common_base::runtime::TrySpawn;
 use databend_common_catalog::lock::LockTableOption;
 use databend_common_catalog::plan::Filters;
 use databend_common_catalog::table::CompactionLimits;
+use databend_common_catalog::table::Table;
 use databend_common_catalog::table::TableExt;
+use databend_common_catalog::table::TimeNavigation;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -73,6 +78,7 @@ use databend_common_expression::type_check::check_function;
 use databend_common_expression::types::DataType;
 use databend_common_expression::ComputedExpr;
 use databend_common_expression::DataField;
+use databend_common_expression::DataSchema;
 use databend_common_expression::DataSchemaRefExt;
 use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
@@ -83,22 +89,28 @@ use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_license::license::Feature;
 use databend_common_license::license_manager::LicenseManagerSwitch;
 use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::TableConstraint;
 use databend_common_meta_app::schema::TableIndex;
 use databend_common_meta_app::storage::StorageParams;
 use databend_common_storage::check_operator;
 use databend_common_storage::init_operator;
 use databend_common_storages_view::view_table::QUERY;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
+use databend_storages_common_table_meta::table::encode_identity_columns;
 use databend_storages_common_table_meta::table::is_reserved_opt_key;
 use databend_storages_common_table_meta::table::ClusterType;
+use databend_storages_common_table_meta::table::IdentityColumn;
+use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 use databend_storages_common_table_meta::table::OPT_KEY_CLUSTER_TYPE;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_ENGINE_META;
+use databend_storages_common_table_meta::table::OPT_KEY_IDENTITY_COLUMNS;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
+use databend_storages_common_table_meta::table::OPT_KEY_TTL;
 use derive_visitor::DriveMut;
 use log::debug;
 use opendal::Operator;
@@ -112,6 +124,7 @@ use crate::binder::ColumnBindingBuilder;
 use crate::binder::Visibility;
 use crate::executor::cast_expr_to_non_null_boolean;
 use crate::optimizer::SExpr;
+use crate::parse_check_constraint_expr;
 use crate::parse_computed_expr_to_string;
 use crate::parse_default_expr_to_string;
 use crate::planner::semantic::normalize_identifier;
@@ -119,12 +132,16 @@ use crate::planner::semantic::resolve_type_name;
 use crate::planner::semantic::IdentifierNormalizer;
 use crate::plans::AddColumnOption;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AddTableConstraintPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AnalyzeTablePlan;
+use crate::plans::CacheWarmupTablePlan;
+use crate::plans::CloneTableSource;
 use crate::plans::CreateTablePlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
+use crate::plans::DropTableConstraintPlan;
 use crate::plans::DropTablePlan;
 use crate::plans::ExistsTablePlan;
 use crate::plans::ModifyColumnAction as ModifyColumnActionInPlan;
@@ -150,6 +167,7 @@ use crate::plans::VacuumDropTablePlan;
 use crate::plans::VacuumTableOption;
 use crate::plans::VacuumTablePlan;
 use crate::plans::VacuumTemporaryFilesPlan;
+use crate::plans::ValidateTableConstraintPlan;
 use crate::BindContext;
 use crate::NameResolutionContext;
 use crate::Planner;
@@ -515,7 +533,7 @@ impl Binder {
         };
 
         // todo(geometry): remove this when geometry stable.
-        if let Some(CreateTableSource::Columns(cols, _)) = &source {
+        if let Some(CreateTableSource::Columns(cols, _, _)) = &source {
             if cols
                 .iter()
                 .any(|col| matches!(col.data_type, TypeName::Geometry | TypeName::Geography))
@@ -529,8 +547,77 @@ impl Binder {
             }
         }
 
+        // `CREATE TABLE ... CLONE` shares the snapshot of the source table.
+        let clone_from = match &source {
+            Some(CreateTableSource::Clone {
+                catalog: source_catalog,
+                database: source_database,
+                table: source_table,
+                travel_point,
+            }) if as_query.is_none() => {
+                if engine != Engine::Fuse
+                    || uri_location.is_some()
+                    || *table_type == TableType::Temporary
+                {
+                    return Err(ErrorCode::BadArguments(
+                        "CLONE is only supported for FUSE tables without external location, and can not create temporary table",
+                    ));
+                }
+                Some(
+                    self.resolve_clone_source(
+                        source_catalog,
+                        source_database,
+                        source_table,
+                        travel_point,
+                    )
+                    .await?,
+                )
+            }
+            _ => None,
+        };
+
+        if let Some(clone_from) = &clone_from {
+            // the shared blocks must be read in the same way as the source table does
+            let source_options = clone_from.table_info.options();
+            let mut inherited_keys = vec![
+                OPT_KEY_STORAGE_FORMAT,
+                OPT_KEY_TABLE_COMPRESSION,
+                OPT_KEY_BLOOM_INDEX_COLUMNS,
+            ];
+            if cluster_by.is_none() {
+                inherited_keys.push(OPT_KEY_CLUSTER_TYPE);
+            }
+            for key in inherited_keys {
+                let Some(value) = source_options.get(key) else {
+                    continue;
+                };
+                match options.get(key) {
+                    Some(v) if key == OPT_KEY_STORAGE_FORMAT && v != value => {
+                        return Err(ErrorCode::TableOptionInvalid(format!(
+                            "storage format of the cloned table must be the same as the source table: {}",
+                            value
+                        )));
+                    }
+                    Some(_) => {}
+                    None => {
+                        options.insert(key.to_string(), value.clone());
+                    }
+                }
+            }
+        }
+
         // Build table schema
         let (schema, field_comments, inverted_indexes, as_query_plan) = match (&source, &as_query) {
+            (Some(_), None) if clone_from.is_some() => {
+                // `CREATE TABLE ... CLONE`, use the schema of the snapshot being cloned
+                let source_meta = &clone_from.as_ref().unwrap().table_info.meta;
+                (
+                    source_meta.schema.clone(),
+                    source_meta.field_comments.clone(),
+                    None,
+                    None,
+                )
+            }
             (Some(source), None) => {
                 // `CREATE TABLE` without `AS SELECT ...`
                 let (schema, field_comments, inverted_indexes) =
@@ -695,7 +782,77 @@ impl Binder {
             )));
         }
 
-        let mut cluster_key = None;
+        // the sequences of the identity columns are created along with the table
+        if let Some(CreateTableSource::Columns(columns, _, _)) = &source {
+            let mut identity_columns = vec![];
+            for column in columns {
+                if let Some(ColumnExpr::Identity {
+                    always,
+                    start,
+                    increment,
+                }) = &column.expr
+                {
+                    let name = normalize_identifier(&column.name, &self.name_resolution_ctx).name;
+                    let field = schema.field_with_name(&name)?;
+                    identity_columns.push(Self::analyze_identity_column(
+                        field, *always, *start, *increment,
+                    )?);
+                }
+            }
+            if !identity_columns.is_empty() {
+                if engine != Engine::Fuse {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Table engine {} does not support identity column",
+                        engine
+                    )));
+                }
+                options.insert(
+                    OPT_KEY_IDENTITY_COLUMNS.to_owned(),
+                    encode_identity_columns(&identity_columns)?,
+                );
+            }
+        }
+
+        // the cloned table shares the data, which satisfies the constraints of the source table
+        let mut constraints = clone_from
+            .as_ref()
+            .map(|clone_from| clone_from.table_info.meta.constraints.clone())
+            .unwrap_or_default();
+        if let Some(CreateTableSource::Columns(columns, _, table_constraints)) = &source {
+            // the key constraints declared on a column apply to the column itself
+            let defs = columns
+                .iter()
+                .flat_map(|column| {
+                    column
+                        .constraints
+                        .iter()
+                        .map(|def| Self::column_constraint_to_table(&column.name, def))
+                })
+                .chain(table_constraints.iter().flatten().cloned())
+                .collect::<Vec<_>>();
+            if !defs.is_empty() {
+                if engine != Engine::Fuse {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Table engine {} does not support constraints",
+                        engine
+                    )));
+                }
+                constraints = self
+                    .analyze_constraints(
+                        &catalog,
+                        &database,
+                        &table,
+                        schema.clone(),
+                        &defs.iter().collect::<Vec<_>>(),
+                        &BTreeMap::new(),
+                    )
+                    .await?;
+            }
+        }
+
+        let mut cluster_key = clone_from
+            .as_ref()
+            .and_then(|clone_from| clone_from.table_info.meta.cluster_key.clone());
         if let Some(cluster_opt) = cluster_by {
             let keys = self
                 .analyze_cluster_keys(cluster_opt, schema.clone())
@@ -724,6 +881,8 @@ impl Binder {
             cluster_key,
             as_select: as_query_plan,
             inverted_indexes,
+            constraints,
+            clone_from,
         };
         Ok(Plan::CreateTable(Box::new(plan)))
     }
@@ -789,6 +948,8 @@ impl Binder {
             cluster_key: None,
             as_select: None,
             inverted_indexes: None,
+            constraints: BTreeMap::new(),
+            clone_from: None,
         })))
     }
 
@@ -923,6 +1084,16 @@ impl Binder {
                     .schema();
                 let (field, comment, is_deterministic) =
                     self.analyze_add_column(column, schema).await?;
+                let identity = match &column.expr {
+                    Some(ColumnExpr::Identity {
+                        always,
+                        start,
+                        increment,
+                    }) => Some(Self::analyze_identity_column(
+                        &field, *always, *start, *increment,
+                    )?),
+                    _ => None,
+                };
                 let option = match ast_option {
                     AstAddColumnOption::First => AddColumnOption::First,
                     AstAddColumnOption::After(ident) => AddColumnOption::After(
@@ -939,6 +1110,7 @@ impl Binder {
                     comment,
                     option,
                     is_deterministic,
+                    identity,
                 })))
             }
             AlterTableAction::ModifyColumn { action } => {
@@ -990,6 +1162,54 @@ impl Binder {
                     lock_guard,
                 })))
             }
+            AlterTableAction::AddConstraint { constraint } => {
+                let table_info = self
+                    .ctx
+                    .get_table(&catalog, &database, &table)
+                    .await?
+                    .get_table_info()
+                    .clone();
+                let constraints = self
+                    .analyze_constraints(
+                        &catalog,
+                        &database,
+                        &table,
+                        table_info.schema(),
+                        &[constraint],
+                        &table_info.meta.constraints,
+                    )
+                    .await?;
+                let (constraint_name, constraint) = constraints.into_iter().next().unwrap();
+                Ok(Plan::AddTableConstraint(Box::new(AddTableConstraintPlan {
+                    catalog,
+                    database,
+                    table,
+                    constraint_name,
+                    constraint,
+                })))
+            }
+            AlterTableAction::DropConstraint { name } => {
+                let constraint_name = normalize_identifier(name, &self.name_resolution_ctx).name;
+                Ok(Plan::DropTableConstraint(Box::new(
+                    DropTableConstraintPlan {
+                        catalog,
+                        database,
+                        table,
+                        constraint_name,
+                    },
+                )))
+            }
+            AlterTableAction::ValidateConstraint { name } => {
+                let constraint_name = normalize_identifier(name, &self.name_resolution_ctx).name;
+                Ok(Plan::ValidateTableConstraint(Box::new(
+                    ValidateTableConstraintPlan {
+                        catalog,
+                        database,
+                        table,
+                        constraint_name,
+                    },
+                )))
+            }
             AlterTableAction::DropColumn { column } => {
                 let column = self.normalize_object_identifier(column);
                 Ok(Plan::DropTableColumn(Box::new(DropTableColumnPlan {
@@ -1067,9 +1287,103 @@ impl Binder {
                     table,
                 })))
             }
+            AlterTableAction::SetTtl { ttl } => {
+                let mut ttl = ttl.clone();
+                let mut normalizer = IdentifierNormalizer {
+                    ctx: &self.name_resolution_ctx,
+                };
+                ttl.drive_mut(&mut normalizer);
+                let mut set_options = BTreeMap::new();
+                set_options.insert(OPT_KEY_TTL.to_string(), format!("{:#}", &ttl));
+                Ok(Plan::SetOptions(Box::new(SetOptionsPlan {
+                    set_options,
+                    catalog,
+                    database,
+                    table,
+                })))
+            }
+            AlterTableAction::UnsetTtl => Ok(Plan::UnsetOptions(Box::new(UnsetOptionsPlan {
+                options: vec![OPT_KEY_TTL.to_string()],
+                catalog,
+                database,
+                table,
+            }))),
+            AlterTableAction::CacheWarmup {
+                columns,
+                selection,
+                pin,
+            } => {
+                let tbl = self.ctx.get_table(&catalog, &database, &table).await?;
+                let columns = match columns {
+                    Some(columns) => {
+                        let schema = tbl.schema();
+                        let mut names = Vec::with_capacity(columns.len());
+                        for column in columns {
+                            let name = self.normalize_object_identifier(column);
+                            schema.field_with_name(&name)?;
+                            names.push(name);
+                        }
+                        Some(names)
+                    }
+                    None => None,
+                };
+                let filters = selection
+                    .as_ref()
+                    .map(|expr| self.bind_table_filters(tbl, expr))
+                    .transpose()?;
+                Ok(Plan::CacheWarmupTable(Box::new(CacheWarmupTablePlan {
+                    catalog,
+                    database,
+                    table,
+                    columns,
+                    filters,
+                    pin: *pin,
+                    unpin: false,
+                })))
+            }
+            AlterTableAction::CacheUnpin => {
+                Ok(Plan::CacheWarmupTable(Box::new(CacheWarmupTablePlan {
+                    catalog,
+                    database,
+                    table,
+                    columns: None,
+                    filters: None,
+                    pin: false,
+                    unpin: true,
+                })))
+            }
         }
     }
 
+    /// Binds the predicate on the columns of the table into filters pushed down to its scan.
+    fn bind_table_filters(&mut self, tbl: Arc<dyn Table>, expr: &Expr) -> Result<Filters> {
+        let (mut context, metadata) = bind_table(tbl)?;
+        let mut type_checker = TypeChecker::try_create(
+            &mut context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            metadata,
+            &[],
+            true,
+        )?;
+        let (scalar, _) = *type_checker.resolve(expr)?;
+
+        // prepare the filter expression
+        let filter = cast_expr_to_non_null_boolean(
+            scalar
+                .as_expr()?
+                .project_column_ref(|col| col.column_name.clone()),
+        )?;
+        // prepare the inverse filter expression
+        let inverted_filter =
+            check_function(None, "not", &[], &[filter.clone()], &BUILTIN_FUNCTIONS)?;
+
+        Ok(Filters {
+            filter: filter.as_remote_expr(),
+            inverted_filter: inverted_filter.as_remote_expr(),
+        })
+    }
+
     #[async_backtrace::framed]
     pub async fn bind_recluster_table(
         &mut self,
@@ -1090,35 +1404,10 @@ impl Binder {
             )));
         };
 
-        let filters = if let Some(expr) = selection {
-            let (mut context, metadata) = bind_table(tbl.clone())?;
-            let mut type_checker = TypeChecker::try_create(
-                &mut context,
-                self.ctx.clone(),
-                &self.name_resolution_ctx,
-                metadata,
-                &[],
-                true,
-            )?;
-            let (scalar, _) = *type_checker.resolve(expr)?;
-
-            // prepare the filter expression
-            let filter = cast_expr_to_non_null_boolean(
-                scalar
-                    .as_expr()?
-                    .project_column_ref(|col| col.column_name.clone()),
-            )?;
-            // prepare the inverse filter expression
-            let inverted_filter =
-                check_function(None, "not", &[], &[filter.clone()], &BUILTIN_FUNCTIONS)?;
-
-            Some(Filters {
-                filter: filter.as_remote_expr(),
-                inverted_filter: inverted_filter.as_remote_expr(),
-            })
-        } else {
-            None
-        };
+        let filters = selection
+            .as_ref()
+            .map(|expr| self.bind_table_filters(tbl.clone(), expr))
+            .transpose()?;
 
         let hilbert_query = if matches!(cluster_type, ClusterType::Hilbert) {
             LicenseManagerSwitch::instance()
@@ -1436,15 +1725,35 @@ impl Binder {
             catalog,
             database,
             table,
+            columns,
         } = stmt;
 
         let (catalog, database, table) =
             self.normalize_object_identifier_triple(catalog, database, table);
 
+        let mut column_names = Vec::with_capacity(columns.len());
+        for column in columns {
+            let name = normalize_identifier(column, &self.name_resolution_ctx).name;
+            if column_names.contains(&name) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "duplicated column {name} in COMPUTE STATISTICS"
+                ))
+                .set_span(column.span));
+            }
+            column_names.push(name);
+        }
+        if column_names.len() == 1 {
+            return Err(ErrorCode::SemanticError(
+                "COMPUTE STATISTICS requires at least two columns".to_string(),
+            )
+            .set_span(columns[0].span));
+        }
+
         Ok(Plan::AnalyzeTable(Box::new(AnalyzeTablePlan {
             catalog,
             database,
             table,
+            columns: column_names,
         })))
     }
 
@@ -1512,6 +1821,12 @@ impl Binder {
         table_schema: TableSchemaRef,
     ) -> Result<(TableField, String, bool)> {
         let name = normalize_identifier(&column.name, &self.name_resolution_ctx).name;
+        if !column.constraints.is_empty() {
+            return Err(ErrorCode::SemanticError(format!(
+                "constraints of column `{}` can not be defined here, use `ALTER TABLE ... ADD CONSTRAINT` instead",
+                name
+            )));
+        }
         let not_null = self.is_column_not_null();
         let data_type = resolve_type_name(&column.data_type, not_null)?;
         let mut is_deterministic = true;
@@ -1543,6 +1858,7 @@ impl Binder {
                     field = field.with_computed_expr(Some(ComputedExpr::Stored(expr)));
                     is_deterministic = false;
                 }
+                ColumnExpr::Identity { .. } => {}
             }
         }
         let comment = column.comment.clone().unwrap_or_default();
@@ -1571,6 +1887,7 @@ impl Binder {
                             parse_default_expr_to_string(self.ctx.clone(), &field, default_expr)?;
                         field = field.with_default_expr(Some(expr));
                     }
+                    ColumnExpr::Identity { .. } => {}
                     _ => has_computed = true,
                 }
             }
@@ -1670,7 +1987,7 @@ impl Binder {
         Option<BTreeMap<String, TableIndex>>,
     )> {
         match source {
-            CreateTableSource::Columns(columns, inverted_index_defs) => {
+            CreateTableSource::Columns(columns, inverted_index_defs, _) => {
                 let (schema, comments) =
                     self.analyze_create_table_schema_by_columns(columns).await?;
                 let inverted_indexes = if let Some(inverted_index_defs) = inverted_index_defs {
@@ -1706,7 +2023,292 @@ impl Binder {
                     Ok((table.schema(), table.field_comments().clone(), None))
                 }
             }
+            CreateTableSource::Clone { .. } => Err(ErrorCode::BadArguments(
+                "CLONE can only be used in CREATE TABLE statement without AS SELECT",
+            )),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn resolve_clone_source(
+        &mut self,
+        catalog: &Option<Identifier>,
+        database: &Option<Identifier>,
+        table: &Identifier,
+        travel_point: &Option<TimeTravelPoint>,
+    ) -> Result<CloneTableSource> {
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let source = self.ctx.get_table(&catalog, &database, &table).await?;
+        let table_info = source.get_table_info();
+        if source.engine() != "FUSE"
+            || source.is_read_only()
+            || source.is_temp()
+            || table_info.meta.storage_params.is_some()
+        {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "Table '{}' can not be cloned, only FUSE table without external location can be cloned",
+                table
+            )));
+        }
+
+        let source = match travel_point {
+            Some(point) => {
+                let mut bind_context = BindContext::new();
+                let point = self.resolve_data_travel_point(&mut bind_context, point)?;
+                source
+                    .navigate_to(
+                        &TimeNavigation::TimeTravel(point),
+                        self.ctx.clone().get_abort_checker(),
+                    )
+                    .await?
+            }
+            None => source,
+        };
+
+        Ok(CloneTableSource {
+            catalog,
+            database,
+            table,
+            table_info: source.get_table_info().clone(),
+        })
+    }
+
+    /// The values of the identity column are allocated from a sequence, whose name is
+    /// decided when the sequence is created.
+    fn analyze_identity_column(
+        field: &TableField,
+        always: bool,
+        start: u64,
+        increment: u64,
+    ) -> Result<IdentityColumn> {
+        if !matches!(field.data_type().remove_nullable(), TableDataType::Number(ty) if ty.is_integer())
+        {
+            return Err(ErrorCode::SemanticError(format!(
+                "identity column `{}` must be of integer type, but got {}",
+                field.name(),
+                field.data_type()
+            )));
+        }
+        if increment == 0 || increment > i64::MAX as u64 {
+            return Err(ErrorCode::SemanticError(format!(
+                "increment of identity column `{}` must be between 1 and {}",
+                field.name(),
+                i64::MAX
+            )));
+        }
+        Ok(IdentityColumn {
+            column_id: field.column_id(),
+            sequence: String::new(),
+            start,
+            increment,
+            always,
+        })
+    }
+
+    /// Turns a constraint declared on the column `column` into a table constraint.
+    fn column_constraint_to_table(
+        column: &Identifier,
+        def: &ConstraintDefinition,
+    ) -> ConstraintDefinition {
+        let constraint = match &def.constraint {
+            ConstraintType::Check(_) => def.constraint.clone(),
+            ConstraintType::PrimaryKey(_) => ConstraintType::PrimaryKey(vec![column.clone()]),
+            ConstraintType::Unique(_) => ConstraintType::Unique(vec![column.clone()]),
+            ConstraintType::ForeignKey { reference, .. } => ConstraintType::ForeignKey {
+                columns: vec![column.clone()],
+                reference: reference.clone(),
+            },
+        };
+        ConstraintDefinition {
+            name: def.name.clone(),
+            constraint,
+        }
+    }
+
+    /// Analyzes the constraints defined on a table of `schema`, the unnamed ones are named after
+    /// the table, and all the names must differ from the ones in `existing`.
+    ///
+    /// The foreign keys are analyzed last, so they can reference a key of the same table
+    /// regardless of the order of the definitions.
+    #[async_backtrace::framed]
+    async fn analyze_constraints(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+        schema: TableSchemaRef,
+        defs: &[&ConstraintDefinition],
+        existing: &BTreeMap<String, TableConstraint>,
+    ) -> Result<BTreeMap<String, TableConstraint>> {
+        // the virtual computed columns are not materialized in the written blocks
+        let data_schema = Arc::new(DataSchema::from(&schema.remove_virtual_computed_fields()));
+        let mut defs = defs.to_vec();
+        defs.sort_by_key(|def| matches!(def.constraint, ConstraintType::ForeignKey { .. }));
+        let mut constraints = BTreeMap::new();
+        for def in defs {
+            let (constraint, kind) = match &def.constraint {
+                ConstraintType::Check(expr) => {
+                    let mut expr = expr.clone();
+                    let mut normalizer = IdentifierNormalizer {
+                        ctx: &self.name_resolution_ctx,
+                    };
+                    expr.drive_mut(&mut normalizer);
+                    let check = format!("{:#}", expr);
+                    parse_check_constraint_expr(self.ctx.clone(), data_schema.clone(), &check)?;
+                    (TableConstraint::Check(check), "check")
+                }
+                ConstraintType::PrimaryKey(columns) => {
+                    let columns = self.analyze_key_columns(table, &schema, columns)?;
+                    if let Some(name) = existing
+                        .iter()
+                        .chain(constraints.iter())
+                        .find(|(_, c)| matches!(c, TableConstraint::PrimaryKey(_)))
+                        .map(|(name, _)| name)
+                    {
+                        return Err(ErrorCode::InvalidConstraint(format!(
+                            "table `{}` already has the primary key `{}`",
+                            table, name
+                        )));
+                    }
+                    (TableConstraint::PrimaryKey(columns), "pk")
+                }
+                ConstraintType::Unique(columns) => {
+                    let columns = self.analyze_key_columns(table, &schema, columns)?;
+                    (TableConstraint::Unique(columns), "unique")
+                }
+                ConstraintType::ForeignKey { columns, reference } => {
+                    let columns = self.analyze_key_columns(table, &schema, columns)?;
+                    let ref_database = reference
+                        .database
+                        .as_ref()
+                        .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+                        .unwrap_or_else(|| self.ctx.get_current_database());
+                    let ref_table =
+                        normalize_identifier(&reference.table, &self.name_resolution_ctx).name;
+                    let (ref_schema, ref_constraints) =
+                        if ref_database == database && ref_table == table {
+                            let mut keys = existing.clone();
+                            keys.extend(constraints.clone());
+                            (schema.clone(), keys)
+                        } else {
+                            let ref_table_info = self
+                                .ctx
+                                .get_table(catalog, &ref_database, &ref_table)
+                                .await?
+                                .get_table_info()
+                                .clone();
+                            (ref_table_info.schema(), ref_table_info.meta.constraints)
+                        };
+                    let ref_columns = if reference.columns.is_empty() {
+                        ref_constraints
+                            .values()
+                            .find_map(|c| match c {
+                                TableConstraint::PrimaryKey(columns) => Some(columns.clone()),
+                                _ => None,
+                            })
+                            .ok_or_else(|| {
+                                ErrorCode::InvalidConstraint(format!(
+                                    "referenced table `{}`.`{}` has no primary key",
+                                    ref_database, ref_table
+                                ))
+                            })?
+                    } else {
+                        self.analyze_key_columns(&ref_table, &ref_schema, &reference.columns)?
+                    };
+                    let is_key = |key: &[String]| {
+                        key.len() == ref_columns.len()
+                            && key.iter().all(|column| ref_columns.contains(column))
+                    };
+                    if !ref_constraints.values().any(|c| match c {
+                        TableConstraint::PrimaryKey(key) | TableConstraint::Unique(key) => {
+                            is_key(key)
+                        }
+                        _ => false,
+                    }) {
+                        return Err(ErrorCode::InvalidConstraint(format!(
+                            "columns ({}) of table `{}`.`{}` are not a primary key or unique key",
+                            ref_columns.join(", "),
+                            ref_database,
+                            ref_table
+                        )));
+                    }
+                    if columns.len() != ref_columns.len() {
+                        return Err(ErrorCode::InvalidConstraint(format!(
+                            "foreign key of table `{}` has {} columns, but the referenced key has {} columns",
+                            table,
+                            columns.len(),
+                            ref_columns.len()
+                        )));
+                    }
+                    for (column, ref_column) in columns.iter().zip(ref_columns.iter()) {
+                        let data_type = schema
+                            .field_with_name(column)?
+                            .data_type()
+                            .remove_nullable();
+                        let ref_data_type = ref_schema
+                            .field_with_name(ref_column)?
+                            .data_type()
+                            .remove_nullable();
+                        if data_type != ref_data_type {
+                            return Err(ErrorCode::InvalidConstraint(format!(
+                                "column `{}` of type {} cannot reference column `{}` of type {}",
+                                column, data_type, ref_column, ref_data_type
+                            )));
+                        }
+                    }
+                    let constraint = TableConstraint::ForeignKey {
+                        columns,
+                        ref_database,
+                        ref_table,
+                        ref_columns,
+                    };
+                    (constraint, "fk")
+                }
+            };
+            let name = match &def.name {
+                Some(name) => normalize_identifier(name, &self.name_resolution_ctx).name,
+                None => (1..)
+                    .map(|i| format!("{}_{}_{}", table, kind, i))
+                    .find(|name| !constraints.contains_key(name) && !existing.contains_key(name))
+                    .unwrap(),
+            };
+            if constraints.contains_key(&name) || existing.contains_key(&name) {
+                return Err(ErrorCode::ConstraintAlreadyExists(format!(
+                    "constraint `{}` already exists in table `{}`",
+                    name, table
+                )));
+            }
+            constraints.insert(name, constraint);
+        }
+        Ok(constraints)
+    }
+
+    /// Normalizes the columns of a key constraint, they must be distinct columns of the table.
+    fn analyze_key_columns(
+        &self,
+        table: &str,
+        schema: &TableSchemaRef,
+        columns: &[Identifier],
+    ) -> Result<Vec<String>> {
+        let mut names = Vec::with_capacity(columns.len());
+        for column in columns {
+            let name = normalize_identifier(column, &self.name_resolution_ctx).name;
+            if schema.field_with_name(&name).is_err() {
+                return Err(ErrorCode::UnknownColumn(format!(
+                    "column `{}` does not exist in table `{}`",
+                    name, table
+                )));
+            }
+            if names.contains(&name) {
+                return Err(ErrorCode::InvalidConstraint(format!(
+                    "column `{}` appears more than once in the key",
+                    name
+                )));
+            }
+            names.push(name);
         }
+        Ok(names)
     }
 
     /// Validate the schema of the table to be created.
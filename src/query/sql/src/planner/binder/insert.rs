@@ -18,10 +18,12 @@ use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::InsertSource;
 use databend_common_ast::ast::InsertStmt;
 use databend_common_ast::ast::Statement;
+use databend_common_catalog::table::Table;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRefExt;
+use databend_storages_common_table_meta::table::identity_columns_of_schema;
 
 use super::util::TableIdentifier;
 use crate::binder::Binder;
@@ -67,6 +69,19 @@ impl Binder {
         Ok(TableSchemaRefExt::create(fields))
     }
 
+    /// The values of `GENERATED ALWAYS AS IDENTITY` columns are always allocated from
+    /// their sequences, so they must not be in the columns to insert.
+    pub fn check_identity_columns_not_given(table: &dyn Table, schema: &TableSchema) -> Result<()> {
+        let identity_columns = identity_columns_of_schema(table.options(), schema)?;
+        match identity_columns.iter().find(|(_, column)| column.always) {
+            Some((index, _)) => Err(ErrorCode::BadArguments(format!(
+                "The value specified for identity column '{}' is not allowed, it is generated always",
+                schema.field(*index).name()
+            ))),
+            None => Ok(()),
+        }
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_insert(
         &mut self,
@@ -100,6 +115,7 @@ impl Binder {
             .map_err(|err| table_identifier.not_found_suggest_error(err))?;
 
         let schema = self.schema_project(&table.schema(), columns)?;
+        Self::check_identity_columns_not_given(table.as_ref(), &schema)?;
 
         let input_source: Result<InsertInputSource> = match source.clone() {
             InsertSource::Values { rows } => {
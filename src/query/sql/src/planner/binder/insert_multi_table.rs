@@ -232,6 +232,7 @@ impl Binder {
                     next_column_id: casted_schema.next_column_id(),
                 });
             }
+            Self::check_identity_columns_not_given(target_table.as_ref(), &casted_schema)?;
 
             let source_scalar_exprs = if source_columns.is_empty() {
                 None
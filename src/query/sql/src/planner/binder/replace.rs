@@ -79,6 +79,7 @@ impl Binder {
                 .collect::<Result<Vec<_>>>()?;
             Arc::new(schema.project(&field_indexes))
         };
+        Self::check_identity_columns_not_given(table.as_ref(), &schema)?;
 
         let on_conflict_fields = on_conflict_columns
             .iter()
@@ -46,6 +46,7 @@ use parking_lot::RwLock;
 use crate::binder::wrap_cast;
 use crate::binder::ColumnBindingBuilder;
 use crate::binder::ExprContext;
+use crate::executor::cast_expr_to_non_null_boolean;
 use crate::planner::binder::BindContext;
 use crate::planner::semantic::NameResolutionContext;
 use crate::planner::semantic::TypeChecker;
@@ -242,6 +243,81 @@ pub fn parse_computed_expr(
     Ok(expr)
 }
 
+/// Parses the TTL expression of a table, which must be a deterministic `TIMESTAMP` or `DATE`.
+pub fn parse_ttl_expr(
+    ctx: Arc<dyn TableContext>,
+    schema: DataSchemaRef,
+    sql: &str,
+) -> Result<Expr> {
+    let expr = parse_computed_expr(ctx, schema, sql)?;
+    if !matches!(
+        expr.data_type().remove_nullable(),
+        DataType::Timestamp | DataType::Date
+    ) {
+        return Err(ErrorCode::TableOptionInvalid(format!(
+            "TTL expression `{}` must be of type TIMESTAMP or DATE, but got {}",
+            sql,
+            expr.data_type()
+        )));
+    }
+    if expr.column_refs().is_empty() || !expr.is_deterministic(&BUILTIN_FUNCTIONS) {
+        return Err(ErrorCode::TableOptionInvalid(format!(
+            "TTL expression `{}` must be deterministic and refer to the columns of the table",
+            sql
+        )));
+    }
+    Ok(expr)
+}
+
+/// Builds the predicate which holds for the rows that are not expired by the TTL of a table.
+///
+/// Rows whose TTL expression evaluates to NULL never expire.
+pub fn parse_ttl_alive_filter(
+    ctx: Arc<dyn TableContext>,
+    schema: DataSchemaRef,
+    ttl: &str,
+) -> Result<Expr> {
+    parse_ttl_expr(ctx.clone(), schema.clone(), ttl)?;
+    let expr = parse_computed_expr(ctx, schema, &format!("ifnull(({}) > now(), true)", ttl))?;
+    cast_expr_to_non_null_boolean(expr)
+}
+
+/// Parses the expression of a CHECK constraint, which must be a deterministic `BOOLEAN`.
+pub fn parse_check_constraint_expr(
+    ctx: Arc<dyn TableContext>,
+    schema: DataSchemaRef,
+    sql: &str,
+) -> Result<Expr> {
+    let expr = parse_computed_expr(ctx, schema, sql)?;
+    if expr.data_type().remove_nullable() != DataType::Boolean {
+        return Err(ErrorCode::SemanticError(format!(
+            "CHECK constraint expression `{}` must be of type BOOLEAN, but got {}",
+            sql,
+            expr.data_type()
+        )));
+    }
+    if !expr.is_deterministic(&BUILTIN_FUNCTIONS) {
+        return Err(ErrorCode::SemanticError(format!(
+            "CHECK constraint expression `{}` must be deterministic",
+            sql
+        )));
+    }
+    Ok(expr)
+}
+
+/// Builds the predicate which holds for the rows satisfying a CHECK constraint.
+///
+/// Rows for which the constraint evaluates to NULL satisfy it.
+pub fn parse_check_constraint_filter(
+    ctx: Arc<dyn TableContext>,
+    schema: DataSchemaRef,
+    check: &str,
+) -> Result<Expr> {
+    parse_check_constraint_expr(ctx.clone(), schema.clone(), check)?;
+    let expr = parse_computed_expr(ctx, schema, &format!("ifnull(({}), true)", check))?;
+    cast_expr_to_non_null_boolean(expr)
+}
+
 pub fn parse_default_expr_to_string(
     ctx: Arc<dyn TableContext>,
     field: &TableField,
@@ -55,6 +55,8 @@ impl Plan {
             Plan::DropDatabase(_) => Ok("DropDatabase".to_string()),
             Plan::UndropDatabase(_) => Ok("UndropDatabase".to_string()),
             Plan::RenameDatabase(_) => Ok("RenameDatabase".to_string()),
+            Plan::BackupDatabase(_) => Ok("BackupDatabase".to_string()),
+            Plan::RestoreDatabase(_) => Ok("RestoreDatabase".to_string()),
 
             // Tables
             Plan::CreateTable(create_table) => format_create_table(create_table),
@@ -70,10 +72,14 @@ impl Plan {
             Plan::AddTableColumn(_) => Ok("AddTableColumn".to_string()),
             Plan::ModifyTableColumn(_) => Ok("ModifyTableColumn".to_string()),
             Plan::DropTableColumn(_) => Ok("DropTableColumn".to_string()),
+            Plan::AddTableConstraint(_) => Ok("AddTableConstraint".to_string()),
+            Plan::DropTableConstraint(_) => Ok("DropTableConstraint".to_string()),
+            Plan::ValidateTableConstraint(_) => Ok("ValidateTableConstraint".to_string()),
             Plan::AlterTableClusterKey(_) => Ok("AlterTableClusterKey".to_string()),
             Plan::DropTableClusterKey(_) => Ok("DropTableClusterKey".to_string()),
             Plan::ReclusterTable { .. } => Ok("ReclusterTable".to_string()),
             Plan::TruncateTable(_) => Ok("TruncateTable".to_string()),
+            Plan::CacheWarmupTable(_) => Ok("CacheWarmupTable".to_string()),
             Plan::OptimizePurge(_) => Ok("OptimizePurge".to_string()),
             Plan::OptimizeCompactSegment(_) => Ok("OptimizeCompactSegment".to_string()),
             Plan::OptimizeCompactBlock { .. } => Ok("OptimizeCompactBlock".to_string()),
@@ -89,6 +95,13 @@ impl Plan {
             Plan::DropView(_) => Ok("DropView".to_string()),
             Plan::DescribeView(_) => Ok("DescribeView".to_string()),
 
+            // Materialized views
+            Plan::CreateMaterializedView(_) => Ok("CreateMaterializedView".to_string()),
+            Plan::DropMaterializedView(_) => Ok("DropMaterializedView".to_string()),
+            Plan::RefreshMaterializedView(_) => Ok("RefreshMaterializedView".to_string()),
+            Plan::PinPlanBaseline(_) => Ok("PinPlanBaseline".to_string()),
+            Plan::UnpinPlanBaseline(_) => Ok("UnpinPlanBaseline".to_string()),
+
             // Streams
             Plan::CreateStream(_) => Ok("CreateStream".to_string()),
             Plan::DropStream(_) => Ok("DropStream".to_string()),
@@ -186,6 +199,9 @@ impl Plan {
             Plan::Begin => Ok("Begin".to_string()),
             Plan::Commit => Ok("commit".to_string()),
             Plan::Abort => Ok("Abort".to_string()),
+            Plan::Savepoint(_) => Ok("Savepoint".to_string()),
+            Plan::RollbackToSavepoint(_) => Ok("RollbackToSavepoint".to_string()),
+            Plan::ReleaseSavepoint(_) => Ok("ReleaseSavepoint".to_string()),
 
             // Notification
             Plan::CreateNotification(_) => Ok("CreateNotification".to_string()),
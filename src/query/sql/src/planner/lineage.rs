@@ -0,0 +1,594 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Column-level lineage of the queries, that is, which source columns feed which columns of
+//! the result or of the written tables. Tables and columns are identified by their IDs as well
+//! as their names, so that the history survives renames.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::binder::Visibility;
+use crate::optimizer::SExpr;
+use crate::plans::BoundColumnRef;
+use crate::plans::CopyIntoTablePlan;
+use crate::plans::Insert;
+use crate::plans::InsertInputSource;
+use crate::plans::Plan;
+use crate::plans::RelOperator;
+use crate::plans::SubqueryExpr;
+use crate::plans::Visitor;
+use crate::BindContext;
+use crate::ColumnEntry;
+use crate::ColumnSet;
+use crate::IndexType;
+use crate::Metadata;
+use crate::MetadataRef;
+use crate::ScalarExpr;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ObjectDomain {
+    Table,
+    Stage,
+    /// The result set of a query.
+    Result,
+}
+
+/// A column of a table, a stage or a query result.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LineageColumn {
+    pub object_domain: ObjectDomain,
+    /// The table ID, 0 for the stages and the query results.
+    pub object_id: u64,
+    /// `catalog.database.table` of the tables, the name of the stages.
+    pub object_name: String,
+    pub column_id: Option<u32>,
+    pub column_name: String,
+}
+
+impl LineageColumn {
+    fn result(column_name: &str) -> Self {
+        LineageColumn {
+            object_domain: ObjectDomain::Result,
+            object_id: 0,
+            object_name: "".to_string(),
+            column_id: None,
+            column_name: column_name.to_string(),
+        }
+    }
+
+    fn stage(stage_name: &str, column_name: &str) -> Self {
+        LineageColumn {
+            object_domain: ObjectDomain::Stage,
+            object_id: 0,
+            object_name: stage_name.to_string(),
+            column_id: None,
+            column_name: column_name.to_string(),
+        }
+    }
+
+    fn table(table_id: u64, object_name: &str, column_id: Option<u32>, name: &str) -> Self {
+        LineageColumn {
+            object_domain: ObjectDomain::Table,
+            object_id: table_id,
+            object_name: object_name.to_string(),
+            column_id,
+            column_name: name.to_string(),
+        }
+    }
+}
+
+/// A table or a stage read or written by a query, with the columns accessed.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AccessedObject {
+    pub object_domain: ObjectDomain,
+    pub object_id: u64,
+    pub object_name: String,
+    pub columns: Vec<AccessedColumn>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct AccessedColumn {
+    pub column_id: Option<u32>,
+    pub column_name: String,
+}
+
+/// The source columns a column of the result or of a written table is computed from.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ColumnLineage {
+    pub target: LineageColumn,
+    pub sources: Vec<LineageColumn>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct QueryLineage {
+    pub objects_read: Vec<AccessedObject>,
+    pub objects_written: Vec<AccessedObject>,
+    pub columns: Vec<ColumnLineage>,
+}
+
+impl QueryLineage {
+    /// Computes the lineage of SELECT, INSERT, COPY INTO table and the mutations (MERGE,
+    /// UPDATE and DELETE). Returns None for the other plans, and the queries accessing no
+    /// objects, such as `SELECT 1`.
+    #[async_recursion::async_recursion(#[recursive::recursive])]
+    pub async fn from_plan(ctx: &dyn TableContext, plan: &Plan) -> Result<Option<QueryLineage>> {
+        let lineage = match plan {
+            Plan::Query {
+                s_expr,
+                metadata,
+                bind_context,
+                rewrite_kind: None,
+                ..
+            } => Self::from_query(s_expr, metadata, bind_context)?,
+            Plan::Insert(insert) => Self::from_insert(ctx, insert).await?,
+            Plan::CopyIntoTable(copy) => Self::from_copy(ctx, copy).await?,
+            Plan::DataMutation {
+                s_expr, metadata, ..
+            } => match Self::from_mutation(s_expr, metadata)? {
+                Some(lineage) => lineage,
+                None => return Ok(None),
+            },
+            _ => return Ok(None),
+        };
+
+        match lineage.objects_read.is_empty() && lineage.objects_written.is_empty() {
+            true => Ok(None),
+            false => Ok(Some(lineage)),
+        }
+    }
+
+    /// The lineage of writing the result of `source` into `targets`, the columns of the result
+    /// are mapped to the targets by position.
+    fn into_table(self, targets: Vec<LineageColumn>) -> QueryLineage {
+        let columns = self
+            .columns
+            .into_iter()
+            .zip(targets.iter())
+            .filter(|(column, _)| !column.sources.is_empty())
+            .map(|(column, target)| ColumnLineage {
+                target: target.clone(),
+                sources: column.sources,
+            })
+            .collect();
+
+        QueryLineage {
+            objects_read: self.objects_read,
+            objects_written: accessed_objects(targets),
+            columns,
+        }
+    }
+
+    fn from_query(
+        s_expr: &SExpr,
+        metadata: &MetadataRef,
+        bind_context: &BindContext,
+    ) -> Result<QueryLineage> {
+        let metadata = metadata.read();
+        let mut builder = LineageBuilder::new(&metadata);
+        builder.collect(s_expr)?;
+
+        let columns = bind_context
+            .columns
+            .iter()
+            .filter(|column| column.visibility == Visibility::Visible)
+            .map(|column| ColumnLineage {
+                target: LineageColumn::result(&column.column_name),
+                sources: builder.resolve(column.index),
+            })
+            .collect();
+
+        Ok(QueryLineage {
+            objects_read: builder.objects_read(),
+            objects_written: vec![],
+            columns,
+        })
+    }
+
+    async fn from_insert(ctx: &dyn TableContext, insert: &Insert) -> Result<QueryLineage> {
+        // The table created by CREATE TABLE AS SELECT is not visible yet.
+        let table_id = match &insert.table_info {
+            Some(table_info) => table_info.ident.table_id,
+            None => ctx
+                .get_table(&insert.catalog, &insert.database, &insert.table)
+                .await?
+                .get_id(),
+        };
+        let object_name = table_name(&insert.catalog, &insert.database, &insert.table);
+        let targets = insert
+            .schema
+            .fields()
+            .iter()
+            .map(|field| {
+                LineageColumn::table(
+                    table_id,
+                    &object_name,
+                    Some(field.column_id()),
+                    field.name(),
+                )
+            })
+            .collect();
+
+        let source = match &insert.source {
+            InsertInputSource::SelectPlan(plan) | InsertInputSource::Stage(plan) => {
+                Self::from_plan(ctx, plan).await?.unwrap_or_default()
+            }
+            // The values are written, but come from no object.
+            InsertInputSource::Values(_) => QueryLineage::default(),
+        };
+        Ok(source.into_table(targets))
+    }
+
+    async fn from_copy(ctx: &dyn TableContext, copy: &CopyIntoTablePlan) -> Result<QueryLineage> {
+        let catalog = copy.catalog_info.catalog_name();
+        let table = ctx
+            .get_table(catalog, &copy.database_name, &copy.table_name)
+            .await?;
+        let schema = table.schema();
+        let object_name = table_name(catalog, &copy.database_name, &copy.table_name);
+        // The columns of the values schema carry no IDs, they are looked up by name.
+        let targets = copy
+            .required_values_schema
+            .fields()
+            .iter()
+            .map(|field| {
+                let column_id = schema
+                    .field_with_name(field.name())
+                    .ok()
+                    .map(|field| field.column_id());
+                LineageColumn::table(table.get_id(), &object_name, column_id, field.name())
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(query) = &copy.query {
+            let source = Self::from_plan(ctx, query).await?.unwrap_or_default();
+            return Ok(source.into_table(targets));
+        }
+
+        let stage_name = &copy.stage_table_info.stage_info.stage_name;
+        let sources = copy
+            .required_source_schema
+            .fields()
+            .iter()
+            .map(|field| LineageColumn::stage(stage_name, field.name()))
+            .collect::<Vec<_>>();
+        let columns = targets
+            .iter()
+            .filter(|target| copy.required_source_schema.has_field(&target.column_name))
+            .map(|target| ColumnLineage {
+                target: target.clone(),
+                sources: vec![LineageColumn::stage(stage_name, &target.column_name)],
+            })
+            .collect();
+
+        Ok(QueryLineage {
+            objects_read: accessed_objects(sources),
+            objects_written: accessed_objects(targets),
+            columns,
+        })
+    }
+
+    fn from_mutation(s_expr: &SExpr, metadata: &MetadataRef) -> Result<Option<QueryLineage>> {
+        let RelOperator::Mutation(mutation) = s_expr.plan() else {
+            return Ok(None);
+        };
+        let metadata = metadata.read();
+        let mut builder = LineageBuilder::new(&metadata);
+        builder.collect(s_expr)?;
+
+        let table_entry = metadata.table(mutation.target_table_index);
+        let table = table_entry.table();
+        let schema = table.schema_with_stream();
+        let object_name = table_name(
+            table_entry.catalog(),
+            table_entry.database(),
+            table_entry.name(),
+        );
+        let target = |name: &str| {
+            schema.field_with_name(name).ok().map(|field| {
+                LineageColumn::table(
+                    table.get_id(),
+                    &object_name,
+                    Some(field.column_id()),
+                    field.name(),
+                )
+            })
+        };
+
+        let mut sources_by_target: BTreeMap<LineageColumn, BTreeSet<LineageColumn>> =
+            BTreeMap::new();
+        for evaluator in &mutation.matched_evaluators {
+            for (idx, scalar) in evaluator.update.iter().flatten() {
+                if let Some(target) = target(schema.field(*idx).name()) {
+                    let sources = builder.resolve_scalar(scalar)?;
+                    sources_by_target.entry(target).or_default().extend(sources);
+                }
+            }
+        }
+        for evaluator in &mutation.unmatched_evaluators {
+            let fields = evaluator.source_schema.fields();
+            for (field, scalar) in fields.iter().zip(&evaluator.values) {
+                if let Some(target) = target(field.name()) {
+                    let sources = builder.resolve_scalar(scalar)?;
+                    sources_by_target.entry(target).or_default().extend(sources);
+                }
+            }
+        }
+
+        // A DELETE writes the table as a whole rather than some columns.
+        let objects_written = match sources_by_target.is_empty() {
+            true => vec![AccessedObject {
+                object_domain: ObjectDomain::Table,
+                object_id: table.get_id(),
+                object_name: object_name.clone(),
+                columns: vec![],
+            }],
+            false => accessed_objects(sources_by_target.keys().cloned().collect()),
+        };
+        let columns = sources_by_target
+            .into_iter()
+            .filter(|(_, sources)| !sources.is_empty())
+            .map(|(target, sources)| ColumnLineage {
+                target,
+                sources: sources.into_iter().collect(),
+            })
+            .collect();
+
+        Ok(Some(QueryLineage {
+            objects_read: builder.objects_read(),
+            objects_written,
+            columns,
+        }))
+    }
+}
+
+/// Collects the columns scanned and how the derived columns are computed from the plan.
+struct LineageBuilder<'a> {
+    metadata: &'a Metadata,
+    /// The columns each derived column is directly computed from.
+    derivations: HashMap<IndexType, ColumnSet>,
+    scanned: ColumnSet,
+}
+
+impl<'a> LineageBuilder<'a> {
+    fn new(metadata: &'a Metadata) -> Self {
+        LineageBuilder {
+            metadata,
+            derivations: HashMap::new(),
+            scanned: ColumnSet::new(),
+        }
+    }
+
+    #[recursive::recursive]
+    fn collect(&mut self, s_expr: &SExpr) -> Result<()> {
+        match s_expr.plan() {
+            RelOperator::Scan(scan) => {
+                self.scanned.extend(scan.columns.iter().copied());
+            }
+            RelOperator::MutationSource(source) => {
+                self.scanned.extend(source.columns.iter().copied());
+            }
+            RelOperator::EvalScalar(eval) => {
+                for item in &eval.items {
+                    self.derive(item.index, &item.scalar)?;
+                }
+            }
+            RelOperator::Filter(filter) => {
+                for predicate in &filter.predicates {
+                    self.used_columns(predicate)?;
+                }
+            }
+            RelOperator::Aggregate(aggregate) => {
+                for item in aggregate
+                    .group_items
+                    .iter()
+                    .chain(aggregate.aggregate_functions.iter())
+                {
+                    self.derive(item.index, &item.scalar)?;
+                }
+            }
+            RelOperator::Window(window) => {
+                for item in window.arguments.iter().chain(window.partition_by.iter()) {
+                    self.derive(item.index, &item.scalar)?;
+                }
+                let arguments = window.arguments.iter().map(|item| item.index);
+                self.derivations
+                    .entry(window.index)
+                    .or_default()
+                    .extend(arguments);
+            }
+            RelOperator::ProjectSet(project_set) => {
+                for item in &project_set.srfs {
+                    self.derive(item.index, &item.scalar)?;
+                }
+            }
+            RelOperator::Udf(udf) => {
+                for item in &udf.items {
+                    self.derive(item.index, &item.scalar)?;
+                }
+            }
+            RelOperator::AsyncFunction(async_function) => {
+                for item in &async_function.items {
+                    self.derive(item.index, &item.scalar)?;
+                }
+            }
+            RelOperator::UnionAll(union_all) => {
+                for (idx, output) in union_all.output_indexes.iter().enumerate() {
+                    for (input, cast) in [&union_all.left_outputs, &union_all.right_outputs]
+                        .into_iter()
+                        .filter_map(|outputs| outputs.get(idx))
+                    {
+                        let mut inputs = ColumnSet::from([*input]);
+                        if let Some(cast) = cast {
+                            inputs.extend(self.used_columns(cast)?);
+                        }
+                        inputs.remove(output);
+                        self.derivations.entry(*output).or_default().extend(inputs);
+                    }
+                }
+            }
+            RelOperator::ExpressionScan(scan) => {
+                for row in &scan.values {
+                    for (index, value) in scan.column_indexes.iter().zip(row) {
+                        self.derive(*index, value)?;
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        for child in s_expr.children() {
+            self.collect(child)?;
+        }
+        Ok(())
+    }
+
+    fn derive(&mut self, index: IndexType, scalar: &ScalarExpr) -> Result<()> {
+        let mut inputs = self.used_columns(scalar)?;
+        inputs.remove(&index);
+        self.derivations.entry(index).or_default().extend(inputs);
+        Ok(())
+    }
+
+    /// Returns the columns referenced by `scalar`, the subqueries in it are collected too.
+    fn used_columns(&mut self, scalar: &ScalarExpr) -> Result<ColumnSet> {
+        let mut visitor = ColumnVisitor::default();
+        visitor.visit(scalar)?;
+        for subquery in visitor.subqueries {
+            self.collect(subquery)?;
+        }
+        Ok(visitor.columns)
+    }
+
+    fn resolve_scalar(&mut self, scalar: &ScalarExpr) -> Result<Vec<LineageColumn>> {
+        let mut sources = BTreeSet::new();
+        for index in self.used_columns(scalar)? {
+            sources.extend(self.resolve(index));
+        }
+        Ok(sources.into_iter().collect())
+    }
+
+    /// Resolves a column to the table columns it is computed from.
+    fn resolve(&self, index: IndexType) -> Vec<LineageColumn> {
+        let mut visited = ColumnSet::new();
+        let mut sources = BTreeSet::new();
+        let mut pending = vec![index];
+        while let Some(index) = pending.pop() {
+            if !visited.insert(index) || index >= self.metadata.columns().len() {
+                continue;
+            }
+            if let Some(column) = self.table_column(index) {
+                sources.insert(column);
+            } else if let Some(inputs) = self.derivations.get(&index) {
+                pending.extend(inputs.iter().copied());
+            }
+        }
+        sources.into_iter().collect()
+    }
+
+    fn table_column(&self, index: IndexType) -> Option<LineageColumn> {
+        let (table_index, column_id, column_name) = match self.metadata.column(index) {
+            ColumnEntry::BaseTableColumn(column) => (
+                column.table_index,
+                column.column_id,
+                column.column_name.as_str(),
+            ),
+            ColumnEntry::VirtualColumn(column) => (
+                column.table_index,
+                Some(column.source_column_id),
+                column.source_column_name.as_str(),
+            ),
+            ColumnEntry::InternalColumn(_) | ColumnEntry::DerivedColumn(_) => return None,
+        };
+
+        let entry = self.metadata.table(table_index);
+        let table = entry.table();
+        Some(match entry.is_source_of_stage() {
+            true => LineageColumn::stage(table.name(), column_name),
+            false => LineageColumn::table(
+                table.get_id(),
+                &table_name(entry.catalog(), entry.database(), entry.name()),
+                column_id,
+                column_name,
+            ),
+        })
+    }
+
+    fn objects_read(&self) -> Vec<AccessedObject> {
+        accessed_objects(
+            self.scanned
+                .iter()
+                .filter_map(|index| self.table_column(*index))
+                .collect(),
+        )
+    }
+}
+
+#[derive(Default)]
+struct ColumnVisitor<'a> {
+    columns: ColumnSet,
+    subqueries: Vec<&'a SExpr>,
+}
+
+impl<'a> Visitor<'a> for ColumnVisitor<'a> {
+    fn visit_bound_column_ref(&mut self, col: &'a BoundColumnRef) -> Result<()> {
+        self.columns.insert(col.column.index);
+        Ok(())
+    }
+
+    fn visit_subquery(&mut self, subquery: &'a SubqueryExpr) -> Result<()> {
+        if let Some(child_expr) = subquery.child_expr.as_ref() {
+            self.visit(child_expr)?;
+        }
+        self.columns.insert(subquery.output_column.index);
+        self.subqueries.push(&subquery.subquery);
+        Ok(())
+    }
+}
+
+/// Groups the columns by the object they belong to.
+fn accessed_objects(columns: Vec<LineageColumn>) -> Vec<AccessedObject> {
+    let mut objects: BTreeMap<(ObjectDomain, u64, String), BTreeSet<AccessedColumn>> =
+        BTreeMap::new();
+    for column in columns {
+        objects
+            .entry((column.object_domain, column.object_id, column.object_name))
+            .or_default()
+            .insert(AccessedColumn {
+                column_id: column.column_id,
+                column_name: column.column_name,
+            });
+    }
+    objects
+        .into_iter()
+        .map(
+            |((object_domain, object_id, object_name), columns)| AccessedObject {
+                object_domain,
+                object_id,
+                object_name,
+                columns: columns.into_iter().collect(),
+            },
+        )
+        .collect()
+}
+
+fn table_name(catalog: &str, database: &str, table: &str) -> String {
+    format!("{}.{}.{}", catalog, database, table)
+}
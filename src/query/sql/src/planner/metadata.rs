@@ -21,6 +21,7 @@ use std::sync::Arc;
 
 use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::OptimizerHint;
 use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::InternalColumn;
 use databend_common_catalog::table::Table;
@@ -32,6 +33,8 @@ use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
 use parking_lot::RwLock;
 
+use crate::optimizer::HintState;
+use crate::optimizer::PlanBaselineState;
 use crate::optimizer::SExpr;
 use crate::ScalarExpr;
 
@@ -73,12 +76,21 @@ pub struct Metadata {
     /// Mappings from table index to _row_id column index.
     table_row_id_index: HashMap<IndexType, IndexType>,
     agg_indexes: HashMap<String, Vec<(u64, String, SExpr)>>,
+    /// Fresh materialized views over the tables of the query.
+    materialized_views: Vec<MaterializedViewEntry>,
     max_column_position: usize, // for CSV
 
     /// Scan id of each scan operator.
     next_scan_id: usize,
     /// Mappings from base column index to scan id.
     base_column_scan_id: HashMap<IndexType, usize>,
+
+    /// Optimizer hints of the query, with whether they have been applied.
+    optimizer_hints: Vec<(OptimizerHint, HintState)>,
+    /// Fingerprint of the join decisions of the optimized plan.
+    plan_fingerprint: Option<String>,
+    /// The plan baseline of the query, with how it is used by the plan.
+    plan_baseline: Option<PlanBaselineState>,
 }
 
 impl Metadata {
@@ -347,6 +359,61 @@ impl Metadata {
         !self.agg_indexes.is_empty()
     }
 
+    pub fn add_materialized_view(&mut self, materialized_view: MaterializedViewEntry) {
+        self.materialized_views.push(materialized_view);
+    }
+
+    pub fn materialized_views(&self) -> &[MaterializedViewEntry] {
+        &self.materialized_views
+    }
+
+    pub fn add_optimizer_hint(&mut self, hint: OptimizerHint) {
+        if !self.optimizer_hints.iter().any(|(v, _)| v == &hint) {
+            self.optimizer_hints.push((hint, HintState::Unused));
+        }
+    }
+
+    pub fn optimizer_hints(&self) -> &[(OptimizerHint, HintState)] {
+        &self.optimizer_hints
+    }
+
+    /// Update the state of the hint at `index`, a hint applied to any part of the plan stays applied.
+    pub fn set_optimizer_hint_state(&mut self, index: usize, state: HintState) {
+        if let Some((_, current)) = self.optimizer_hints.get_mut(index) {
+            if *current != HintState::Applied {
+                *current = state;
+            }
+        }
+    }
+
+    /// Mark the hint as applied, returns false if the query has no such hint.
+    pub fn apply_optimizer_hint(&mut self, hint: &OptimizerHint) -> bool {
+        let mut found = false;
+        for (v, state) in self.optimizer_hints.iter_mut() {
+            if v == hint {
+                *state = HintState::Applied;
+                found = true;
+            }
+        }
+        found
+    }
+
+    pub fn set_plan_fingerprint(&mut self, fingerprint: String) {
+        self.plan_fingerprint = Some(fingerprint);
+    }
+
+    pub fn plan_fingerprint(&self) -> Option<&String> {
+        self.plan_fingerprint.as_ref()
+    }
+
+    pub fn set_plan_baseline(&mut self, plan_baseline: PlanBaselineState) {
+        self.plan_baseline = Some(plan_baseline);
+    }
+
+    pub fn plan_baseline(&self) -> Option<&PlanBaselineState> {
+        self.plan_baseline.as_ref()
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn add_table(
         &mut self,
@@ -500,6 +567,21 @@ impl Metadata {
     }
 }
 
+/// A materialized view that may answer the query, with its query bound in the same metadata.
+#[derive(Clone, Debug)]
+pub struct MaterializedViewEntry {
+    /// The full name of the view, `catalog.database.view`.
+    pub name: String,
+    /// The plan of the query of the view.
+    pub s_expr: SExpr,
+    /// The output columns of the query of the view.
+    pub output_columns: Vec<IndexType>,
+    /// The scan of the table of the view.
+    pub scan: SExpr,
+    /// The columns of the table of the view, in the same order as `output_columns`.
+    pub columns: Vec<IndexType>,
+}
+
 #[derive(Clone)]
 pub struct TableEntry {
     catalog: String,
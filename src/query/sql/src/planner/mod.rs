@@ -14,7 +14,9 @@
 
 mod bloom_index;
 mod format;
+mod lineage;
 mod metadata;
+mod plan_baseline;
 #[allow(clippy::module_inception)]
 mod planner;
 pub mod query_executor;
@@ -41,6 +43,7 @@ pub use binder::Visibility;
 pub use bloom_index::BloomIndexColumns;
 pub use expression_parser::*;
 pub use format::format_scalar;
+pub use lineage::*;
 pub use metadata::*;
 pub use planner::get_query_kind;
 pub use planner::PlanExtras;
@@ -15,6 +15,7 @@
 use std::collections::HashSet;
 use std::sync::Arc;
 
+use databend_common_ast::ast::OptimizerHint;
 use databend_common_ast::Span;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
@@ -201,6 +202,8 @@ impl SubqueryRewriter {
             is_lateral: false,
             single_to_inner: None,
             build_side_cache_info: None,
+            method_hint: None,
+            distribution_hint: None,
         };
 
         // Rewrite plan to semi-join.
@@ -295,6 +298,8 @@ impl SubqueryRewriter {
                     is_lateral: false,
                     single_to_inner: None,
                     build_side_cache_info: None,
+                    method_hint: None,
+                    distribution_hint: None,
                 };
                 let s_expr = SExpr::create_binary(
                     Arc::new(join_plan.into()),
@@ -304,7 +309,13 @@ impl SubqueryRewriter {
                 Ok((s_expr, UnnestResult::SingleJoin))
             }
             SubqueryType::Exists | SubqueryType::NotExists => {
-                if is_conjunctive_predicate {
+                // The NO_DECORRELATE hint keeps the subquery as a mark join.
+                if is_conjunctive_predicate
+                    && !self
+                        .metadata
+                        .write()
+                        .apply_optimizer_hint(&OptimizerHint::NoDecorrelate)
+                {
                     if let Some(result) = self.try_decorrelate_simple_subquery(left, subquery)? {
                         return Ok((result, UnnestResult::SimpleJoin { output_index: None }));
                     }
@@ -349,6 +360,8 @@ impl SubqueryRewriter {
                     is_lateral: false,
                     single_to_inner: None,
                     build_side_cache_info: None,
+                    method_hint: None,
+                    distribution_hint: None,
                 };
                 let s_expr = SExpr::create_binary(
                     Arc::new(join_plan.into()),
@@ -418,6 +431,8 @@ impl SubqueryRewriter {
                     is_lateral: false,
                     single_to_inner: None,
                     build_side_cache_info: None,
+                    method_hint: None,
+                    distribution_hint: None,
                 }
                 .into();
                 Ok((
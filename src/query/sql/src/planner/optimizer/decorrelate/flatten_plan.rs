@@ -170,6 +170,8 @@ impl SubqueryRewriter {
                 is_lateral: false,
                 single_to_inner: None,
                 build_side_cache_info: None,
+                method_hint: None,
+                distribution_hint: None,
             }
             .into();
 
@@ -528,6 +530,8 @@ impl SubqueryRewriter {
                     is_lateral: false,
                     single_to_inner: None,
                     build_side_cache_info: None,
+                    method_hint: None,
+                    distribution_hint: None,
                 }
                 .into(),
             ),
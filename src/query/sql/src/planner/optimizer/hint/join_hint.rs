@@ -0,0 +1,276 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_ast::ast::OptimizerHint;
+use databend_common_exception::Result;
+
+use crate::executor::physical_plans::split_range_conditions;
+use crate::optimizer::resolve_hint_tables;
+use crate::optimizer::HintState;
+use crate::optimizer::SExpr;
+use crate::plans::FunctionCall;
+use crate::plans::Join;
+use crate::plans::JoinDistribution;
+use crate::plans::JoinMethod;
+use crate::plans::JoinType;
+use crate::plans::RelOperator;
+use crate::IndexType;
+use crate::MetadataRef;
+use crate::ScalarExpr;
+
+enum JoinHintKind {
+    Method(JoinMethod),
+    Distribution(JoinDistribution),
+}
+
+struct JoinHint {
+    // Index of the hint in metadata.
+    index: usize,
+    kind: JoinHintKind,
+    // The hinted tables, empty means all joins.
+    tables: Vec<IndexType>,
+}
+
+// The JoinHintOptimizer applies join method and distribution hints to the joins.
+// It runs after the join order is decided, a hint is applied to a join if one
+// of the join inputs is exactly a hinted table.
+pub struct JoinHintOptimizer {
+    metadata: MetadataRef,
+    enable_distributed_optimization: bool,
+    hints: Vec<JoinHint>,
+}
+
+impl JoinHintOptimizer {
+    pub fn new(metadata: MetadataRef, enable_distributed_optimization: bool) -> Self {
+        let mut hints = vec![];
+        {
+            let mut guard = metadata.write();
+            let optimizer_hints = guard.optimizer_hints().to_vec();
+            for (index, (hint, _)) in optimizer_hints.iter().enumerate() {
+                let kind = match hint {
+                    OptimizerHint::HashJoin(_) => JoinHintKind::Method(JoinMethod::Hash),
+                    OptimizerHint::MergeJoin(_) => JoinHintKind::Method(JoinMethod::Merge),
+                    OptimizerHint::NlJoin(_) => JoinHintKind::Method(JoinMethod::NestedLoop),
+                    OptimizerHint::Broadcast(_) => {
+                        JoinHintKind::Distribution(JoinDistribution::Broadcast)
+                    }
+                    OptimizerHint::Shuffle(_) => {
+                        JoinHintKind::Distribution(JoinDistribution::Shuffle)
+                    }
+                    _ => continue,
+                };
+                match resolve_hint_tables(&guard, hint.tables()) {
+                    Ok(tables) => hints.push(JoinHint {
+                        index,
+                        kind,
+                        tables,
+                    }),
+                    Err(reason) => {
+                        guard.set_optimizer_hint_state(index, HintState::Ignored(reason))
+                    }
+                }
+            }
+        }
+
+        JoinHintOptimizer {
+            metadata,
+            enable_distributed_optimization,
+            hints,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.hints.is_empty()
+    }
+
+    pub fn run(&self, s_expr: &SExpr) -> Result<SExpr> {
+        self.apply_hints(s_expr)
+    }
+
+    #[recursive::recursive]
+    fn apply_hints(&self, s_expr: &SExpr) -> Result<SExpr> {
+        let mut children_changed = false;
+        let mut children = Vec::with_capacity(s_expr.arity());
+        for child in s_expr.children() {
+            let new_child = self.apply_hints(child)?;
+            if !new_child.eq(child) {
+                children_changed = true;
+            }
+            children.push(Arc::new(new_child));
+        }
+        let mut s_expr = s_expr.clone();
+        if children_changed {
+            s_expr = s_expr.replace_children(children);
+        }
+
+        let RelOperator::Join(join) = s_expr.plan() else {
+            return Ok(s_expr);
+        };
+        let mut join = join.clone();
+        let mut left_tables = HashSet::new();
+        collect_tables(s_expr.child(0)?, &mut left_tables);
+        let mut right_tables = HashSet::new();
+        collect_tables(s_expr.child(1)?, &mut right_tables);
+
+        for hint in self.hints.iter() {
+            let on_left = hint
+                .tables
+                .iter()
+                .any(|table| left_tables.len() == 1 && left_tables.contains(table));
+            let on_right = hint
+                .tables
+                .iter()
+                .any(|table| right_tables.len() == 1 && right_tables.contains(table));
+            if !hint.tables.is_empty() && !on_left && !on_right {
+                continue;
+            }
+
+            let res = match hint.kind {
+                JoinHintKind::Method(_) if join.method_hint.is_some() => continue,
+                JoinHintKind::Method(method) => self.apply_method(&mut join, &s_expr, method),
+                JoinHintKind::Distribution(_) if join.distribution_hint.is_some() => continue,
+                JoinHintKind::Distribution(distribution) => {
+                    match self.apply_distribution(&mut join, distribution, on_right) {
+                        // The hinted table is on the probe side, swap it to the build side.
+                        Ok(true) => {
+                            s_expr = s_expr.replace_children([
+                                Arc::new(s_expr.child(1)?.clone()),
+                                Arc::new(s_expr.child(0)?.clone()),
+                            ]);
+                            std::mem::swap(&mut left_tables, &mut right_tables);
+                            Ok(())
+                        }
+                        Ok(false) => Ok(()),
+                        Err(reason) => Err(reason),
+                    }
+                }
+            };
+            let state = match res {
+                Ok(()) => HintState::Applied,
+                Err(reason) => HintState::Ignored(reason),
+            };
+            self.metadata
+                .write()
+                .set_optimizer_hint_state(hint.index, state);
+        }
+
+        Ok(s_expr.replace_plan(Arc::new(RelOperator::Join(join))))
+    }
+
+    fn apply_method(
+        &self,
+        join: &mut Join,
+        s_expr: &SExpr,
+        method: JoinMethod,
+    ) -> std::result::Result<(), String> {
+        match method {
+            JoinMethod::Hash => {}
+            JoinMethod::Merge => {
+                if !join.equi_conditions.is_empty()
+                    || !matches!(join.join_type, JoinType::Inner | JoinType::Cross)
+                    || join.build_side_cache_info.is_some()
+                {
+                    return Err("merge join only supports inner range joins".to_string());
+                }
+                let (range_conditions, _) =
+                    split_range_conditions(join, s_expr).map_err(|e| e.message())?;
+                if range_conditions.is_empty() {
+                    return Err("merge join needs a range join condition".to_string());
+                }
+            }
+            JoinMethod::NestedLoop => {
+                if !matches!(join.join_type, JoinType::Inner | JoinType::Cross)
+                    || join.build_side_cache_info.is_some()
+                    || join.equi_conditions.iter().any(|c| c.is_null_equal)
+                {
+                    return Err("nested loop join only supports inner joins".to_string());
+                }
+                // Evaluate the equi conditions as filters of the cross product.
+                for condition in std::mem::take(&mut join.equi_conditions) {
+                    join.non_equi_conditions
+                        .push(ScalarExpr::FunctionCall(FunctionCall {
+                            span: None,
+                            func_name: "eq".to_string(),
+                            params: vec![],
+                            arguments: vec![condition.left, condition.right],
+                        }));
+                }
+            }
+        }
+        join.method_hint = Some(method);
+        Ok(())
+    }
+
+    // Returns true if the join inputs need to be swapped.
+    fn apply_distribution(
+        &self,
+        join: &mut Join,
+        distribution: JoinDistribution,
+        on_right: bool,
+    ) -> std::result::Result<bool, String> {
+        if !self.enable_distributed_optimization {
+            return Err("the query is not distributed".to_string());
+        }
+        let mut swap = false;
+        match distribution {
+            JoinDistribution::Broadcast => {
+                if !on_right {
+                    if !matches!(join.join_type, JoinType::Inner | JoinType::Cross) {
+                        return Err(format!(
+                            "the probe side of {} join can not be broadcast",
+                            join.join_type
+                        ));
+                    }
+                    for condition in join.equi_conditions.iter_mut() {
+                        std::mem::swap(&mut condition.left, &mut condition.right);
+                    }
+                    swap = true;
+                }
+                if matches!(
+                    join.join_type,
+                    JoinType::Right
+                        | JoinType::Full
+                        | JoinType::RightAnti
+                        | JoinType::RightSemi
+                        | JoinType::LeftMark
+                        | JoinType::RightSingle
+                ) {
+                    return Err(format!(
+                        "the build side of {} join can not be broadcast",
+                        join.join_type
+                    ));
+                }
+            }
+            JoinDistribution::Shuffle => {
+                if join.equi_conditions.is_empty() {
+                    return Err("shuffle join needs an equi join condition".to_string());
+                }
+            }
+        }
+        join.distribution_hint = Some(distribution);
+        Ok(swap)
+    }
+}
+
+pub(super) fn collect_tables(s_expr: &SExpr, tables: &mut HashSet<IndexType>) {
+    if let RelOperator::Scan(scan) = s_expr.plan() {
+        tables.insert(scan.table_index);
+    }
+    for child in s_expr.children() {
+        collect_tables(child, tables);
+    }
+}
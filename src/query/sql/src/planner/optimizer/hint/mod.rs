@@ -0,0 +1,24 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod join_hint;
+mod optimizer_hint;
+mod plan_outline;
+
+pub use join_hint::JoinHintOptimizer;
+pub use optimizer_hint::resolve_hint_tables;
+pub use optimizer_hint::HintState;
+pub use plan_outline::PlanBaselineState;
+pub use plan_outline::PlanBaselineUsage;
+pub use plan_outline::PlanOutline;
@@ -0,0 +1,67 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use databend_common_ast::ast::Identifier;
+
+use crate::IndexType;
+use crate::Metadata;
+
+/// Whether an optimizer hint has been applied to the plan.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HintState {
+    /// The hint does not match any part of the plan.
+    Unused,
+    /// The hint matches the plan but can not be applied.
+    Ignored(String),
+    Applied,
+}
+
+impl Display for HintState {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            HintState::Unused => write!(f, "ignored, not applicable to the query"),
+            HintState::Ignored(reason) => write!(f, "ignored, {}", reason),
+            HintState::Applied => write!(f, "applied"),
+        }
+    }
+}
+
+/// Resolve the tables named by a hint, a table is named by its alias if it has one.
+pub fn resolve_hint_tables(
+    metadata: &Metadata,
+    tables: &[Identifier],
+) -> std::result::Result<Vec<IndexType>, String> {
+    tables
+        .iter()
+        .map(|table| {
+            let mut indexes = metadata
+                .tables()
+                .iter()
+                .filter(|entry| !entry.is_source_of_index())
+                .filter(|entry| match entry.alias_name() {
+                    Some(alias) => alias == &table.name,
+                    None => entry.name() == table.name,
+                })
+                .map(|entry| entry.index());
+            match (indexes.next(), indexes.next()) {
+                (Some(index), None) => Ok(index),
+                (None, _) => Err(format!("unknown table '{}'", table.name)),
+                (Some(_), Some(_)) => Err(format!("ambiguous table '{}'", table.name)),
+            }
+        })
+        .collect()
+}
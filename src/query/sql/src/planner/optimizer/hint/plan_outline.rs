@@ -0,0 +1,276 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::OptimizerHint;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::PlanBaseline;
+use databend_common_meta_app::principal::PlanBaselineHint;
+use databend_common_meta_types::SeqV;
+use sha2::Digest;
+use sha2::Sha256;
+
+use super::join_hint::collect_tables;
+use crate::executor::physical_plans::physical_join;
+use crate::executor::physical_plans::PhysicalJoinType;
+use crate::optimizer::resolve_hint_tables;
+use crate::optimizer::SExpr;
+use crate::plans::Join;
+use crate::plans::JoinDistribution;
+use crate::plans::JoinMethod;
+use crate::plans::JoinType;
+use crate::plans::RelOperator;
+use crate::Metadata;
+
+/// The join decisions of an optimized plan, described by the optimizer hints that reproduce them.
+///
+/// Only the first inner join tree of the plan is described: the tables in the order they are
+/// joined, the methods of the joins without equi conditions and the hinted distributions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlanOutline {
+    pub join_order: Vec<String>,
+    pub hints: Vec<OptimizerHint>,
+}
+
+impl PlanOutline {
+    /// Extract the outline of a plan, returns `None` if the plan has no inner join tree whose
+    /// tables can be named by hints.
+    pub fn extract(metadata: &Metadata, s_expr: &SExpr) -> Result<Option<PlanOutline>> {
+        let Some(root) = find_join_tree(s_expr) else {
+            return Ok(None);
+        };
+        let mut outline = PlanOutline {
+            join_order: vec![],
+            hints: vec![],
+        };
+        match outline.visit_join_tree(metadata, root)? {
+            Some(join_order) => {
+                outline.join_order = join_order;
+                outline.hints.sort_by_key(|hint| hint.to_string());
+                Ok(Some(outline))
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub fn from_baseline(baseline: &PlanBaseline) -> PlanOutline {
+        let hints = baseline
+            .hints
+            .iter()
+            .filter_map(|hint| {
+                let tables = hint
+                    .tables
+                    .iter()
+                    .map(|table| Identifier::from_name(None, table.clone()))
+                    .collect();
+                match hint.name.as_str() {
+                    "HASH_JOIN" => Some(OptimizerHint::HashJoin(tables)),
+                    "MERGE_JOIN" => Some(OptimizerHint::MergeJoin(tables)),
+                    "NL_JOIN" => Some(OptimizerHint::NlJoin(tables)),
+                    "BROADCAST" => Some(OptimizerHint::Broadcast(tables)),
+                    "SHUFFLE" => Some(OptimizerHint::Shuffle(tables)),
+                    _ => None,
+                }
+            })
+            .collect();
+        PlanOutline {
+            join_order: baseline.join_order.clone(),
+            hints,
+        }
+    }
+
+    /// The hints of the baseline, in the form stored in meta.
+    pub fn baseline_hints(&self) -> Vec<PlanBaselineHint> {
+        self.hints
+            .iter()
+            .map(|hint| PlanBaselineHint {
+                name: hint_name(hint),
+                tables: hint.tables().iter().map(|t| t.name.clone()).collect(),
+            })
+            .collect()
+    }
+
+    /// The optimizer hints to reproduce the plan.
+    pub fn optimizer_hints(&self) -> Vec<OptimizerHint> {
+        let leading = self
+            .join_order
+            .iter()
+            .map(|table| Identifier::from_name(None, table.clone()))
+            .collect();
+        let mut hints = vec![OptimizerHint::Leading(leading)];
+        hints.extend(self.hints.iter().cloned());
+        hints
+    }
+
+    pub fn fingerprint(&self) -> String {
+        let mut fingerprint = format!("{:x}", Sha256::digest(self.to_string()));
+        fingerprint.truncate(16);
+        fingerprint
+    }
+
+    // Returns the tables of the join tree in the canonical order they are joined, the order does
+    // not depend on the build and probe sides: a join tree goes before a table and two tables
+    // are ordered by names.
+    fn visit_join_tree(
+        &mut self,
+        metadata: &Metadata,
+        s_expr: &SExpr,
+    ) -> Result<Option<Vec<String>>> {
+        let RelOperator::Join(join) = s_expr.plan() else {
+            return Ok(leaf_table_name(metadata, s_expr).map(|name| vec![name]));
+        };
+        if !is_inner_join(join) {
+            return Ok(leaf_table_name(metadata, s_expr).map(|name| vec![name]));
+        }
+
+        let Some(left) = self.visit_join_tree(metadata, s_expr.child(0)?)? else {
+            return Ok(None);
+        };
+        let Some(right) = self.visit_join_tree(metadata, s_expr.child(1)?)? else {
+            return Ok(None);
+        };
+        self.add_join_hints(join, s_expr, &left, &right)?;
+
+        let (first, second) = match (left.len(), right.len()) {
+            (1, 1) if left[0] <= right[0] => (left, right),
+            (1, 1) => (right, left),
+            (1, _) => (right, left),
+            (_, 1) => (left, right),
+            _ if left[0] <= right[0] => (left, right),
+            _ => (right, left),
+        };
+        Ok(Some(first.into_iter().chain(second).collect()))
+    }
+
+    fn add_join_hints(
+        &mut self,
+        join: &Join,
+        s_expr: &SExpr,
+        left: &[String],
+        right: &[String],
+    ) -> Result<()> {
+        // The hinted table is an input of the join, the build side is preferred.
+        let table = match (left, right) {
+            (_, [table]) | ([table], _) => Identifier::from_name(None, table.clone()),
+            _ => return Ok(()),
+        };
+
+        if join.equi_conditions.is_empty() {
+            let method = match join.method_hint {
+                Some(method) => method,
+                None => match physical_join(join, s_expr)? {
+                    PhysicalJoinType::Hash => JoinMethod::Hash,
+                    PhysicalJoinType::RangeJoin(_, _) => JoinMethod::Merge,
+                },
+            };
+            self.hints.push(match method {
+                JoinMethod::Hash => OptimizerHint::HashJoin(vec![table.clone()]),
+                JoinMethod::Merge => OptimizerHint::MergeJoin(vec![table.clone()]),
+                JoinMethod::NestedLoop => OptimizerHint::NlJoin(vec![table.clone()]),
+            });
+        }
+
+        match join.distribution_hint {
+            Some(JoinDistribution::Broadcast) => {
+                self.hints.push(OptimizerHint::Broadcast(vec![table]));
+            }
+            Some(JoinDistribution::Shuffle) => {
+                self.hints.push(OptimizerHint::Shuffle(vec![table]));
+            }
+            None => {}
+        }
+        Ok(())
+    }
+}
+
+impl Display for PlanOutline {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        for (i, hint) in self.optimizer_hints().iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}", hint)?;
+        }
+        Ok(())
+    }
+}
+
+/// How the plan baseline of a query is used by its plan.
+#[derive(Clone, Debug)]
+pub struct PlanBaselineState {
+    pub baseline: SeqV<PlanBaseline>,
+    pub usage: PlanBaselineUsage,
+}
+
+#[derive(Clone, Debug)]
+pub enum PlanBaselineUsage {
+    /// The plan is the pinned plan of the baseline.
+    Applied,
+    /// The plan is a new plan that is compared with the pinned plan.
+    Evolving(PlanOutline),
+}
+
+impl Display for PlanBaselineUsage {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            PlanBaselineUsage::Applied => write!(f, "applied"),
+            PlanBaselineUsage::Evolving(_) => write!(f, "evolving"),
+        }
+    }
+}
+
+fn hint_name(hint: &OptimizerHint) -> String {
+    let mut name = hint.to_string();
+    if let Some(pos) = name.find('(') {
+        name.truncate(pos);
+    }
+    name
+}
+
+fn is_inner_join(join: &Join) -> bool {
+    matches!(join.join_type, JoinType::Inner | JoinType::Cross)
+}
+
+#[recursive::recursive]
+fn find_join_tree(s_expr: &SExpr) -> Option<&SExpr> {
+    if let RelOperator::Join(join) = s_expr.plan() {
+        if is_inner_join(join) {
+            return Some(s_expr);
+        }
+    }
+    s_expr.children().find_map(find_join_tree)
+}
+
+// The name of the only table of a join input, it must name the table unambiguously in hints.
+fn leaf_table_name(metadata: &Metadata, s_expr: &SExpr) -> Option<String> {
+    let mut tables = HashSet::new();
+    collect_tables(s_expr, &mut tables);
+    if tables.len() != 1 {
+        return None;
+    }
+    let index = tables.into_iter().next()?;
+    let entry = metadata.table(index);
+    let name = match entry.alias_name() {
+        Some(alias) => alias.clone(),
+        None => entry.name().to_string(),
+    };
+    match resolve_hint_tables(metadata, &[Identifier::from_name(None, name.clone())]) {
+        Ok(indexes) if indexes == [index] => Some(name),
+        _ => None,
+    }
+}
@@ -16,6 +16,7 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 
+use databend_common_ast::ast::OptimizerHint;
 use databend_common_base::runtime::spawn;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
@@ -27,7 +28,9 @@ use crate::optimizer::hyper_dp::join_relation::RelationSetTree;
 use crate::optimizer::hyper_dp::query_graph::QueryGraph;
 use crate::optimizer::hyper_dp::util::intersect;
 use crate::optimizer::hyper_dp::util::union;
+use crate::optimizer::resolve_hint_tables;
 use crate::optimizer::rule::TransformResult;
+use crate::optimizer::HintState;
 use crate::optimizer::OptimizerContext;
 use crate::optimizer::RuleFactory;
 use crate::optimizer::RuleID;
@@ -398,13 +401,76 @@ impl DPhpy {
             self.dp_table.insert(nodes, join);
         }
 
+        // The tables in a `LEADING` hint are joined first, in the given order.
+        if let Some(leading) = self.leading_relations() {
+            return self.join_reorder_by_leading(&leading).await;
+        }
+
         // First, try to use dynamic programming to find the optimal join order.
         if !self.join_reorder_by_dphyp().await? {
             // When DPhpy takes too much time during join ordering, it is necessary to exit the dynamic programming algorithm
             // and switch to a greedy algorithm to minimizes the overall query time.
-            self.join_reorder_by_greedy().await?;
+            let join_relations = (0..self.join_relations.len())
+                .map(|idx| self.relation_set_tree.get_relation_set_by_index(idx))
+                .collect::<Result<Vec<_>>>()?;
+            self.join_reorder_by_greedy(join_relations).await?;
+        }
+
+        Ok(())
+    }
+
+    // Find the relations of the first `LEADING` hint whose tables are all base relations of the graph.
+    fn leading_relations(&self) -> Option<Vec<IndexType>> {
+        let metadata = self.opt_ctx.metadata.clone();
+        let mut metadata = metadata.write();
+        let hints = metadata.optimizer_hints().to_vec();
+        for (index, (hint, _)) in hints.iter().enumerate() {
+            let OptimizerHint::Leading(tables) = hint else {
+                continue;
+            };
+            let table_indexes = match resolve_hint_tables(&metadata, tables) {
+                Ok(table_indexes) => table_indexes,
+                Err(reason) => {
+                    metadata.set_optimizer_hint_state(index, HintState::Ignored(reason));
+                    continue;
+                }
+            };
+
+            let mut relations = Vec::with_capacity(table_indexes.len());
+            for table_index in table_indexes.iter() {
+                match self.table_index_map.get(table_index) {
+                    Some(relation) if !relations.contains(relation) => relations.push(*relation),
+                    _ => break,
+                }
+            }
+            if relations.len() == table_indexes.len() {
+                metadata.set_optimizer_hint_state(index, HintState::Applied);
+                return Some(relations);
+            }
+        }
+        None
+    }
+
+    // Join the leading relations from left to right, then join the others greedily.
+    async fn join_reorder_by_leading(&mut self, leading: &[IndexType]) -> Result<()> {
+        let mut joined = self
+            .relation_set_tree
+            .get_relation_set_by_index(leading[0])?;
+        for idx in leading.iter().skip(1) {
+            let relation = self.relation_set_tree.get_relation_set_by_index(*idx)?;
+            let join_conditions = self.query_graph.is_connected(&joined, &relation)?;
+            self.emit_csg_cmp(&joined, &relation, join_conditions)
+                .await?;
+            joined = union(&joined, &relation);
         }
 
+        let mut join_relations = vec![joined];
+        for idx in 0..self.join_relations.len() {
+            if !leading.contains(&idx) {
+                join_relations.push(self.relation_set_tree.get_relation_set_by_index(idx)?);
+            }
+        }
+        self.join_reorder_by_greedy(join_relations).await?;
         Ok(())
     }
 
@@ -430,14 +496,14 @@ impl DPhpy {
     }
 
     // Join reorder by greedy algorithm.
-    async fn join_reorder_by_greedy(&mut self) -> Result<bool> {
+    async fn join_reorder_by_greedy(
+        &mut self,
+        mut join_relations: Vec<Vec<IndexType>>,
+    ) -> Result<bool> {
         // The Greedy Operator Ordering starts with a single relation and iteratively adds the relation that minimizes the cost of the join.
         // the algorithm terminates when all relations have been added, the cost of a join is the sum of the cardinalities of the node involved
         // in the tree, the algorithm is not guaranteed to find the optimal join tree, it is guaranteed to find it in polynomial time.
-        // Create join relations list, all relations have been inserted into dp_table in func `join_reorder`.
-        let mut join_relations = (0..self.join_relations.len())
-            .map(|idx| self.relation_set_tree.get_relation_set_by_index(idx))
-            .collect::<Result<Vec<_>>>()?;
+        // All relations in `join_relations` have been inserted into dp_table.
         // When all relations have been added, the algorithm terminates.
         while join_relations.len() > 1 {
             // The cost is the sum of the cardinalities of the node involved in the tree.
@@ -95,6 +95,8 @@ impl JoinNode {
             is_lateral: false,
             single_to_inner: None,
             build_side_cache_info: None,
+            method_hint: None,
+            distribution_hint: None,
         });
         let children = self
             .children
@@ -0,0 +1,279 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::TableConstraint;
+
+use crate::optimizer::ColumnSet;
+use crate::optimizer::RelExpr;
+use crate::optimizer::SExpr;
+use crate::plans::Filter;
+use crate::plans::FunctionCall;
+use crate::plans::Join;
+use crate::plans::JoinType;
+use crate::plans::RelOperator;
+use crate::BaseTableColumn;
+use crate::ColumnEntry;
+use crate::IndexType;
+use crate::MetadataRef;
+use crate::ScalarExpr;
+
+// The EliminateJoinOptimizer removes the joins whose one side contributes no column to the
+// result and changes no row of the other side, which is known from the primary, unique
+// and foreign key constraints of the tables. The constraints are not enforced, so the
+// result is only correct if the data satisfies them.
+//
+// - `a LEFT JOIN b ON a.x = b.k` is `a` if `b.k` is a unique key of `b`.
+// - `a INNER JOIN b ON a.x = b.k` is `a WHERE a.x IS NOT NULL` if `a.x` is a foreign key
+//   referencing the key `b.k`, and `b` is a whole table.
+pub struct EliminateJoinOptimizer {
+    metadata: MetadataRef,
+}
+
+impl EliminateJoinOptimizer {
+    pub fn new(metadata: MetadataRef) -> Self {
+        EliminateJoinOptimizer { metadata }
+    }
+
+    /// `required` are the columns of `s_expr` required by the result of the query.
+    pub fn run(self, s_expr: &SExpr, required: ColumnSet) -> Result<SExpr> {
+        self.eliminate(s_expr, required)
+    }
+
+    #[recursive::recursive]
+    fn eliminate(&self, s_expr: &SExpr, mut required: ColumnSet) -> Result<SExpr> {
+        // the columns required by the children are the ones required by the parents,
+        // plus the ones used by the operator itself
+        match s_expr.plan() {
+            RelOperator::Join(join) => {
+                if let Some(s_expr) = self.eliminate_join(s_expr, join, &required)? {
+                    return self.eliminate(&s_expr, required);
+                }
+                required.extend(join.used_columns()?);
+            }
+            RelOperator::EvalScalar(eval_scalar) => required.extend(eval_scalar.used_columns()?),
+            RelOperator::Filter(filter) => required.extend(filter.used_columns()?),
+            RelOperator::Sort(sort) => required.extend(sort.used_columns()),
+            RelOperator::Limit(_) => {}
+            RelOperator::Aggregate(aggregate) => required = aggregate.used_columns()?,
+            _ => return Ok(s_expr.clone()),
+        }
+
+        let mut children = Vec::with_capacity(s_expr.arity());
+        for child in s_expr.children() {
+            children.push(Arc::new(self.eliminate(child, required.clone())?));
+        }
+        Ok(s_expr.replace_children(children))
+    }
+
+    /// Returns the input which replaces the `join`, if it can be eliminated.
+    fn eliminate_join(
+        &self,
+        s_expr: &SExpr,
+        join: &Join,
+        required: &ColumnSet,
+    ) -> Result<Option<SExpr>> {
+        if join.marker_index.is_some()
+            || join.from_correlated_subquery
+            || join.is_lateral
+            || join.build_side_cache_info.is_some()
+            || join.equi_conditions.is_empty()
+            || join.equi_conditions.iter().any(|c| c.is_null_equal)
+        {
+            return Ok(None);
+        }
+        let Some(keys) = join
+            .equi_conditions
+            .iter()
+            .map(|c| Some((column_index(&c.left)?, column_index(&c.right)?)))
+            .collect::<Option<Vec<_>>>()
+        else {
+            return Ok(None);
+        };
+
+        let left = s_expr.child(0)?;
+        let right = s_expr.child(1)?;
+        let is_unused = |child: &SExpr| -> Result<bool> {
+            let prop = RelExpr::with_s_expr(child).derive_relational_prop()?;
+            Ok(prop.output_columns.is_disjoint(required))
+        };
+
+        match join.join_type {
+            // each row of the left side matches at most one row of the right side
+            JoinType::Left => {
+                let right_keys = keys.iter().map(|(_, r)| *r).collect::<Vec<_>>();
+                if is_unused(right)? && self.is_unique(right, &right_keys) {
+                    return Ok(Some(left.clone()));
+                }
+            }
+            // each row of the referencing side matches exactly one row of the referenced
+            // side, unless its foreign key is NULL
+            JoinType::Inner if join.non_equi_conditions.is_empty() => {
+                let flipped = keys.iter().map(|(l, r)| (*r, *l)).collect::<Vec<_>>();
+                for (child, parent, keys) in [(left, right, &keys), (right, left, &flipped)] {
+                    if is_unused(parent)? && self.is_foreign_key(parent, keys) {
+                        let predicates = keys
+                            .iter()
+                            .map(|(index, _)| {
+                                let column = join
+                                    .equi_conditions
+                                    .iter()
+                                    .flat_map(|c| [&c.left, &c.right])
+                                    .find(|expr| column_index(expr) == Some(*index))
+                                    .unwrap()
+                                    .clone();
+                                ScalarExpr::FunctionCall(FunctionCall {
+                                    span: None,
+                                    func_name: "is_not_null".to_string(),
+                                    params: vec![],
+                                    arguments: vec![column],
+                                })
+                            })
+                            .collect();
+                        let filter = Filter { predicates };
+                        return Ok(Some(SExpr::create_unary(
+                            Arc::new(filter.into()),
+                            Arc::new(child.clone()),
+                        )));
+                    }
+                }
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    /// Whether the rows of `s_expr`, a scan filtered or not, are unique on the columns `keys`.
+    fn is_unique(&self, s_expr: &SExpr, keys: &[IndexType]) -> bool {
+        let mut s_expr = s_expr;
+        while let RelOperator::Filter(_) = s_expr.plan() {
+            s_expr = match s_expr.child(0) {
+                Ok(child) => child,
+                Err(_) => return false,
+            };
+        }
+        let RelOperator::Scan(scan) = s_expr.plan() else {
+            return false;
+        };
+        if scan.change_type.is_some() {
+            return false;
+        }
+        let Some(columns) = self.base_columns(scan.table_index, keys) else {
+            return false;
+        };
+        let metadata = self.metadata.read();
+        let table = metadata.table(scan.table_index).table();
+        let constraints = &table.get_table_info().meta.constraints;
+        constraints.values().any(|constraint| match constraint {
+            TableConstraint::PrimaryKey(key) | TableConstraint::Unique(key) => {
+                key.iter().all(|column| columns.contains(column))
+            }
+            _ => false,
+        })
+    }
+
+    /// Whether the columns `keys` (referencing side, referenced side) are a foreign key
+    /// referencing `parent`, which must be a whole table.
+    fn is_foreign_key(&self, parent: &SExpr, keys: &[(IndexType, IndexType)]) -> bool {
+        let RelOperator::Scan(scan) = parent.plan() else {
+            return false;
+        };
+        if scan.change_type.is_some()
+            || scan.push_down_predicates.is_some()
+            || scan.prewhere.is_some()
+            || scan.limit.is_some()
+            || scan.sample.is_some()
+        {
+            return false;
+        }
+        let parent_keys = keys.iter().map(|(_, p)| *p).collect::<Vec<_>>();
+        // the referenced key is only checked when the foreign key is created, it may have
+        // been dropped since, or the parent table replaced.
+        if !self.is_unique(parent, &parent_keys) {
+            return false;
+        }
+        let Some(parent_columns) = self.base_columns(scan.table_index, &parent_keys) else {
+            return false;
+        };
+        let Some(child_table_index) = self.table_index_of(keys[0].0) else {
+            return false;
+        };
+        let child_keys = keys.iter().map(|(c, _)| *c).collect::<Vec<_>>();
+        let Some(child_columns) = self.base_columns(child_table_index, &child_keys) else {
+            return false;
+        };
+        let pairs = child_columns
+            .into_iter()
+            .zip(parent_columns)
+            .collect::<HashSet<_>>();
+
+        let metadata = self.metadata.read();
+        let parent_table = metadata.table(scan.table_index);
+        let child_table = metadata.table(child_table_index).table();
+        let constraints = &child_table.get_table_info().meta.constraints;
+        constraints.values().any(|constraint| match constraint {
+            TableConstraint::ForeignKey {
+                columns,
+                ref_database,
+                ref_table,
+                ref_columns,
+            } => {
+                ref_database == parent_table.database()
+                    && ref_table == parent_table.name()
+                    && columns.len() == pairs.len()
+                    && columns
+                        .iter()
+                        .cloned()
+                        .zip(ref_columns.iter().cloned())
+                        .all(|pair| pairs.contains(&pair))
+            }
+            _ => false,
+        })
+    }
+
+    fn table_index_of(&self, column: IndexType) -> Option<IndexType> {
+        match self.metadata.read().column(column) {
+            ColumnEntry::BaseTableColumn(BaseTableColumn { table_index, .. }) => Some(*table_index),
+            _ => None,
+        }
+    }
+
+    /// The names of `columns`, which must be top level columns of the table `table_index`.
+    fn base_columns(&self, table_index: IndexType, columns: &[IndexType]) -> Option<Vec<String>> {
+        let metadata = self.metadata.read();
+        columns
+            .iter()
+            .map(|column| match metadata.column(*column) {
+                ColumnEntry::BaseTableColumn(BaseTableColumn {
+                    table_index: index,
+                    column_name,
+                    path_indices: None,
+                    virtual_expr: None,
+                    ..
+                }) if *index == table_index => Some(column_name.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+fn column_index(expr: &ScalarExpr) -> Option<IndexType> {
+    match expr {
+        ScalarExpr::BoundColumnRef(column) => Some(column.column.index),
+        _ => None,
+    }
+}
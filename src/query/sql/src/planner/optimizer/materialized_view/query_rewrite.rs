@@ -0,0 +1,977 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_functions::aggregates::AggregateFunctionFactory;
+use log::info;
+
+use crate::binder::split_conjunctions;
+use crate::binder::ColumnBindingBuilder;
+use crate::optimizer::SExpr;
+use crate::plans::Aggregate;
+use crate::plans::AggregateFunction;
+use crate::plans::AggregateMode;
+use crate::plans::BoundColumnRef;
+use crate::plans::CastExpr;
+use crate::plans::EvalScalar;
+use crate::plans::Filter;
+use crate::plans::FunctionCall;
+use crate::plans::JoinType;
+use crate::plans::RelOperator;
+use crate::plans::ScalarItem;
+use crate::ColumnEntry;
+use crate::ColumnSet;
+use crate::IndexType;
+use crate::MaterializedViewEntry;
+use crate::Metadata;
+use crate::MetadataRef;
+use crate::ScalarExpr;
+use crate::Visibility;
+
+type ColumnMap = HashMap<IndexType, ScalarExpr>;
+
+// The MaterializedViewOptimizer rewrites the query to read from the fresh materialized views
+// bound by the binder. A select-project-join part of the query, i.e. inner joins and filters
+// over tables, is answered by a view over the same tables whose predicates are implied by the
+// query, the remaining predicates of the query are applied on the view as compensations. An
+// aggregate over such a part is answered by an aggregate view, either directly if the group
+// keys are the same, or by rolling up the aggregates of the view.
+pub struct MaterializedViewOptimizer {
+    metadata: MetadataRef,
+    views: Vec<MaterializedViewEntry>,
+    // A view can only be used once, as its columns are bound once.
+    used: Vec<bool>,
+}
+
+impl MaterializedViewOptimizer {
+    pub fn new(metadata: MetadataRef) -> Self {
+        let views = metadata.read().materialized_views().to_vec();
+        let used = vec![false; views.len()];
+        MaterializedViewOptimizer {
+            metadata,
+            views,
+            used,
+        }
+    }
+
+    pub fn run(&mut self, s_expr: &SExpr) -> Result<SExpr> {
+        // The projection of the query uses all the output columns.
+        self.rewrite(s_expr, &ColumnSet::new())
+    }
+
+    // `required` is the columns used by the ancestors of the expression.
+    #[recursive::recursive]
+    fn rewrite(&mut self, s_expr: &SExpr, required: &ColumnSet) -> Result<SExpr> {
+        match s_expr.plan() {
+            RelOperator::Aggregate(_) => {
+                if let Some(result) = self.try_rewrite_aggregate(s_expr)? {
+                    return Ok(result);
+                }
+            }
+            RelOperator::Scan(_) | RelOperator::Filter(_) | RelOperator::Join(_) => {
+                if let Some(result) = self.try_rewrite_spj(s_expr, required)? {
+                    return Ok(result);
+                }
+            }
+            _ => {}
+        }
+
+        let mut required = required.clone();
+        match s_expr.plan() {
+            RelOperator::EvalScalar(plan) => required.extend(plan.used_columns()?),
+            RelOperator::Filter(plan) => required.extend(plan.used_columns()?),
+            RelOperator::Aggregate(plan) => required.extend(plan.used_columns()?),
+            RelOperator::Sort(plan) => required.extend(plan.used_columns()),
+            RelOperator::Limit(_) => {}
+            RelOperator::Join(plan) => required.extend(plan.used_columns()?),
+            RelOperator::UnionAll(plan) => required.extend(plan.used_columns()?),
+            RelOperator::Window(plan) => required.extend(plan.used_columns()?),
+            _ => return Ok(s_expr.clone()),
+        }
+
+        let mut children_changed = false;
+        let mut children = Vec::with_capacity(s_expr.arity());
+        for child in s_expr.children() {
+            let new_child = self.rewrite(child, &required)?;
+            if !new_child.eq(child) {
+                children_changed = true;
+            }
+            children.push(Arc::new(new_child));
+        }
+        if children_changed {
+            Ok(s_expr.replace_children(children))
+        } else {
+            Ok(s_expr.clone())
+        }
+    }
+
+    fn try_rewrite_spj(&mut self, s_expr: &SExpr, required: &ColumnSet) -> Result<Option<SExpr>> {
+        let metadata = self.metadata.read().clone();
+        let Some(query) = SpjInfo::new(&metadata, s_expr)? else {
+            return Ok(None);
+        };
+
+        // The columns of the query part used by the ancestors, they must be base columns.
+        let mut required_columns = required
+            .iter()
+            .filter(|index| query.columns.contains(index))
+            .cloned()
+            .collect::<Vec<_>>();
+        required_columns.sort();
+        if required_columns
+            .iter()
+            .any(|index| column_key(&metadata, *index).is_none())
+        {
+            return Ok(None);
+        }
+
+        let query_map = ColumnMap::new();
+        for (i, view) in self.views.iter().enumerate() {
+            if self.used[i] {
+                continue;
+            }
+            let Some(view_info) = ViewInfo::new(&metadata, &view.s_expr)? else {
+                continue;
+            };
+            if view_info.aggregate.is_some()
+                || (view_info.spj.table_ids.len() < 2 && view_info.spj.predicates.is_empty())
+            {
+                continue;
+            }
+            let Some(spj_match) = match_spj(
+                &metadata,
+                &query,
+                &query_map,
+                &view_info.spj,
+                &view_info.column_map,
+            ) else {
+                continue;
+            };
+
+            let outputs = view_outputs(
+                &metadata,
+                view,
+                &view_info.column_map,
+                &spj_match.classes,
+                |_| true,
+            );
+            let rewriter = ExprRewriter {
+                formatter: ScalarFormatter {
+                    metadata: &metadata,
+                    column_map: &query_map,
+                    classes: &spj_match.classes,
+                },
+                outputs: &outputs,
+            };
+            let Some(compensations) = rewriter.rewrite_all(&spj_match.compensations)? else {
+                continue;
+            };
+            let mut items = Vec::with_capacity(required_columns.len());
+            for index in required_columns.iter() {
+                let column = column_ref(&metadata, *index);
+                let Some(scalar) = rewriter.rewrite(&column)? else {
+                    break;
+                };
+                items.push(ScalarItem {
+                    scalar,
+                    index: *index,
+                });
+            }
+            if items.len() != required_columns.len() {
+                continue;
+            }
+
+            info!("Rewrite query with materialized view {}", view.name);
+            self.used[i] = true;
+            let mut result = add_filter(view.scan.clone(), compensations);
+            if !items.is_empty() {
+                result =
+                    SExpr::create_unary(Arc::new(EvalScalar { items }.into()), Arc::new(result));
+            }
+            return Ok(Some(result));
+        }
+
+        Ok(None)
+    }
+
+    fn try_rewrite_aggregate(&mut self, s_expr: &SExpr) -> Result<Option<SExpr>> {
+        let RelOperator::Aggregate(aggregate) = s_expr.plan() else {
+            return Ok(None);
+        };
+        if aggregate.mode != AggregateMode::Initial
+            || aggregate.grouping_sets.is_some()
+            || aggregate.rank_limit.is_some()
+        {
+            return Ok(None);
+        }
+
+        let metadata = self.metadata.read().clone();
+        let mut query_map = ColumnMap::new();
+        let mut child = s_expr.child(0)?;
+        while let RelOperator::EvalScalar(eval_scalar) = child.plan() {
+            collect_column_map(&eval_scalar.items, &mut query_map);
+            child = child.child(0)?;
+        }
+        let Some(query) = SpjInfo::new(&metadata, child)? else {
+            return Ok(None);
+        };
+
+        for (i, view) in self.views.iter().enumerate() {
+            if self.used[i] {
+                continue;
+            }
+            let Some(view_info) = ViewInfo::new(&metadata, &view.s_expr)? else {
+                continue;
+            };
+            let Some(view_aggregate) = &view_info.aggregate else {
+                continue;
+            };
+            let Some(spj_match) = match_spj(
+                &metadata,
+                &query,
+                &query_map,
+                &view_info.spj,
+                &view_info.column_map,
+            ) else {
+                continue;
+            };
+
+            let view_formatter = ScalarFormatter {
+                metadata: &metadata,
+                column_map: &view_info.column_map,
+                classes: &spj_match.classes,
+            };
+            let group_indexes = view_aggregate
+                .group_items
+                .iter()
+                .map(|item| item.index)
+                .collect::<HashSet<_>>();
+            let agg_indexes = view_aggregate
+                .aggregate_functions
+                .iter()
+                .map(|item| item.index)
+                .collect::<HashSet<_>>();
+            let mut view_group_keys = HashSet::new();
+            for item in view_aggregate.group_items.iter() {
+                match view_formatter.format(&item.scalar) {
+                    Some(key) => view_group_keys.insert(key),
+                    None => break,
+                };
+            }
+            if view_group_keys.len() != view_aggregate.group_items.len() {
+                continue;
+            }
+            let group_outputs = view_outputs(
+                &metadata,
+                view,
+                &view_info.column_map,
+                &spj_match.classes,
+                |index| group_indexes.contains(&index),
+            );
+            let agg_outputs = view_outputs(
+                &metadata,
+                view,
+                &view_info.column_map,
+                &spj_match.classes,
+                |index| agg_indexes.contains(&index),
+            );
+
+            // The compensations and the group keys of the query must be computed from the
+            // group keys of the view.
+            let rewriter = ExprRewriter {
+                formatter: ScalarFormatter {
+                    metadata: &metadata,
+                    column_map: &query_map,
+                    classes: &spj_match.classes,
+                },
+                outputs: &group_outputs,
+            };
+            let Some(compensations) = rewriter.rewrite_all(&spj_match.compensations)? else {
+                continue;
+            };
+            let mut group_items = Vec::with_capacity(aggregate.group_items.len());
+            let mut query_group_keys = HashSet::new();
+            for item in aggregate.group_items.iter() {
+                let (Some(key), Some(scalar)) = (
+                    rewriter.formatter.format(&item.scalar),
+                    rewriter.rewrite(&item.scalar)?,
+                ) else {
+                    break;
+                };
+                query_group_keys.insert(key);
+                group_items.push(ScalarItem {
+                    scalar,
+                    index: item.index,
+                });
+            }
+            if group_items.len() != aggregate.group_items.len() {
+                continue;
+            }
+
+            let is_exact = group_outputs.len() == view_group_keys.len()
+                && query_group_keys == view_group_keys
+                && aggregate.aggregate_functions.iter().all(|item| {
+                    rewriter
+                        .formatter
+                        .format(&item.scalar)
+                        .is_some_and(|key| agg_outputs.contains_key(&key))
+                });
+            let result = if is_exact {
+                let mut items = group_items;
+                for item in aggregate.aggregate_functions.iter() {
+                    let key = rewriter.formatter.format(&item.scalar).unwrap();
+                    let column = column_ref(&metadata, agg_outputs[&key]);
+                    items.push(ScalarItem {
+                        scalar: cast_if_needed(column, &item.scalar.data_type()?)?,
+                        index: item.index,
+                    });
+                }
+                let filter = add_filter(view.scan.clone(), compensations);
+                SExpr::create_unary(Arc::new(EvalScalar { items }.into()), Arc::new(filter))
+            } else {
+                let Some(result) = self.roll_up(
+                    &metadata,
+                    view,
+                    aggregate,
+                    &rewriter.formatter,
+                    &agg_outputs,
+                    group_items,
+                    compensations,
+                )?
+                else {
+                    continue;
+                };
+                result
+            };
+
+            info!("Rewrite query with materialized view {}", view.name);
+            self.used[i] = true;
+            return Ok(Some(result));
+        }
+
+        Ok(None)
+    }
+
+    // Rolls up the aggregates of the view to the group keys of the query.
+    #[allow(clippy::too_many_arguments)]
+    fn roll_up(
+        &self,
+        metadata: &Metadata,
+        view: &MaterializedViewEntry,
+        aggregate: &Aggregate,
+        formatter: &ScalarFormatter,
+        agg_outputs: &HashMap<String, IndexType>,
+        group_items: Vec<ScalarItem>,
+        compensations: Vec<ScalarExpr>,
+    ) -> Result<Option<SExpr>> {
+        let mut aggregate_functions = Vec::with_capacity(aggregate.aggregate_functions.len());
+        let mut casts = vec![];
+        for item in aggregate.aggregate_functions.iter() {
+            let ScalarExpr::AggregateFunction(agg) = &item.scalar else {
+                return Ok(None);
+            };
+            let func_name = match agg.func_name.as_str() {
+                "sum" | "min" | "max" => agg.func_name.clone(),
+                // The count of an empty group is 0 but the sum is NULL.
+                "count" if !aggregate.group_items.is_empty() => "sum".to_string(),
+                _ => return Ok(None),
+            };
+            if !agg.params.is_empty() || agg.distinct {
+                return Ok(None);
+            }
+            let Some(key) = formatter.format(&item.scalar) else {
+                return Ok(None);
+            };
+            let Some(column) = agg_outputs.get(&key) else {
+                return Ok(None);
+            };
+            let Some(view_type) = view_aggregate_type(view, *column, metadata)? else {
+                return Ok(None);
+            };
+            let arg = cast_if_needed(column_ref(metadata, *column), &view_type)?;
+            let return_type = AggregateFunctionFactory::instance()
+                .get(&func_name, vec![], vec![view_type])?
+                .return_type()?;
+
+            let mut index = item.index;
+            if return_type != *agg.return_type {
+                // The nullability of the aggregate may be changed, this is only safe if
+                // there is no empty group.
+                if aggregate.group_items.is_empty() {
+                    return Ok(None);
+                }
+                index = self.metadata.write().add_derived_column(
+                    agg.display_name.clone(),
+                    return_type.clone(),
+                    None,
+                );
+                casts.push(ScalarItem {
+                    scalar: ScalarExpr::CastExpr(CastExpr {
+                        span: None,
+                        is_try: false,
+                        argument: Box::new(new_column_ref(
+                            agg.display_name.clone(),
+                            index,
+                            return_type.clone(),
+                        )),
+                        target_type: agg.return_type.clone(),
+                    }),
+                    index: item.index,
+                });
+            }
+            aggregate_functions.push(ScalarItem {
+                scalar: ScalarExpr::AggregateFunction(AggregateFunction {
+                    span: None,
+                    func_name: func_name.clone(),
+                    distinct: false,
+                    params: vec![],
+                    args: vec![arg],
+                    return_type: Box::new(return_type),
+                    display_name: agg.display_name.clone(),
+                }),
+                index,
+            });
+        }
+
+        let new_aggregate = Aggregate {
+            mode: AggregateMode::Initial,
+            group_items: group_items
+                .iter()
+                .map(|item| ScalarItem {
+                    scalar: column_ref(metadata, item.index),
+                    index: item.index,
+                })
+                .collect(),
+            aggregate_functions,
+            ..Default::default()
+        };
+
+        let mut result = add_filter(view.scan.clone(), compensations);
+        if !group_items.is_empty() {
+            result = SExpr::create_unary(
+                Arc::new(EvalScalar { items: group_items }.into()),
+                Arc::new(result),
+            );
+        }
+        result = SExpr::create_unary(Arc::new(new_aggregate.into()), Arc::new(result));
+        if !casts.is_empty() {
+            result = SExpr::create_unary(
+                Arc::new(EvalScalar { items: casts }.into()),
+                Arc::new(result),
+            );
+        }
+        Ok(Some(result))
+    }
+}
+
+// The select-project-join part of a plan, i.e. inner joins and filters over tables.
+struct SpjInfo {
+    // The sorted ids of the tables.
+    table_ids: Vec<u64>,
+    // The columns of the tables.
+    columns: ColumnSet,
+    predicates: Vec<ScalarExpr>,
+}
+
+impl SpjInfo {
+    fn new(metadata: &Metadata, s_expr: &SExpr) -> Result<Option<Self>> {
+        let mut spj = SpjInfo {
+            table_ids: vec![],
+            columns: ColumnSet::new(),
+            predicates: vec![],
+        };
+        if !spj.collect(metadata, s_expr)? {
+            return Ok(None);
+        }
+        spj.table_ids.sort();
+        let num_tables = spj.table_ids.len();
+        spj.table_ids.dedup();
+        if spj.table_ids.len() != num_tables {
+            return Ok(None);
+        }
+        Ok(Some(spj))
+    }
+
+    #[recursive::recursive]
+    fn collect(&mut self, metadata: &Metadata, s_expr: &SExpr) -> Result<bool> {
+        match s_expr.plan() {
+            RelOperator::Scan(scan) => {
+                if scan.push_down_predicates.is_some()
+                    || scan.change_type.is_some()
+                    || scan.sample.is_some()
+                {
+                    return Ok(false);
+                }
+                self.table_ids
+                    .push(metadata.table(scan.table_index).table().get_id());
+                self.columns.extend(scan.columns.iter().cloned());
+                Ok(true)
+            }
+            RelOperator::Filter(filter) => {
+                for predicate in filter.predicates.iter() {
+                    self.predicates.extend(split_conjunctions(predicate));
+                }
+                self.collect(metadata, s_expr.child(0)?)
+            }
+            RelOperator::Join(join) => {
+                if !matches!(join.join_type, JoinType::Inner | JoinType::Cross)
+                    || join.marker_index.is_some()
+                    || join.is_lateral
+                    || join
+                        .equi_conditions
+                        .iter()
+                        .any(|condition| condition.is_null_equal)
+                {
+                    return Ok(false);
+                }
+                for condition in join.equi_conditions.iter() {
+                    self.predicates.push(ScalarExpr::FunctionCall(FunctionCall {
+                        span: None,
+                        func_name: "eq".to_string(),
+                        params: vec![],
+                        arguments: vec![condition.left.clone(), condition.right.clone()],
+                    }));
+                }
+                self.predicates
+                    .extend(join.non_equi_conditions.iter().cloned());
+                Ok(self.collect(metadata, s_expr.child(0)?)?
+                    && self.collect(metadata, s_expr.child(1)?)?)
+            }
+            _ => Ok(false),
+        }
+    }
+}
+
+// The query of a view, `[EvalScalar]* [Aggregate [EvalScalar]*] SPJ`.
+struct ViewInfo {
+    column_map: ColumnMap,
+    aggregate: Option<Aggregate>,
+    spj: SpjInfo,
+}
+
+impl ViewInfo {
+    fn new(metadata: &Metadata, s_expr: &SExpr) -> Result<Option<Self>> {
+        let mut column_map = ColumnMap::new();
+        let mut s_expr = s_expr;
+        while let RelOperator::EvalScalar(eval_scalar) = s_expr.plan() {
+            collect_column_map(&eval_scalar.items, &mut column_map);
+            s_expr = s_expr.child(0)?;
+        }
+
+        let mut aggregate = None;
+        if let RelOperator::Aggregate(agg) = s_expr.plan() {
+            if agg.mode != AggregateMode::Initial
+                || agg.grouping_sets.is_some()
+                || agg.rank_limit.is_some()
+            {
+                return Ok(None);
+            }
+            collect_column_map(&agg.group_items, &mut column_map);
+            collect_column_map(&agg.aggregate_functions, &mut column_map);
+            aggregate = Some(agg.clone());
+            s_expr = s_expr.child(0)?;
+            while let RelOperator::EvalScalar(eval_scalar) = s_expr.plan() {
+                collect_column_map(&eval_scalar.items, &mut column_map);
+                s_expr = s_expr.child(0)?;
+            }
+        }
+
+        let Some(spj) = SpjInfo::new(metadata, s_expr)? else {
+            return Ok(None);
+        };
+        Ok(Some(ViewInfo {
+            column_map,
+            aggregate,
+            spj,
+        }))
+    }
+}
+
+struct SpjMatch {
+    // The equivalence classes of the view, the columns of the query are mapped to the columns
+    // of the view through them.
+    classes: EquivalenceClasses,
+    // The predicates of the query not implied by the view.
+    compensations: Vec<ScalarExpr>,
+}
+
+// Matches the select-project-join part of the query with the view, the view matches if it is
+// over the same tables and its predicates are implied by the query.
+fn match_spj(
+    metadata: &Metadata,
+    query: &SpjInfo,
+    query_map: &ColumnMap,
+    view: &SpjInfo,
+    view_map: &ColumnMap,
+) -> Option<SpjMatch> {
+    if query.table_ids != view.table_ids {
+        return None;
+    }
+
+    let (query_equalities, query_others) = split_predicates(metadata, query_map, query);
+    let (view_equalities, view_others) = split_predicates(metadata, view_map, view);
+    let mut query_classes = EquivalenceClasses::default();
+    for (_, left, right) in query_equalities.iter() {
+        query_classes.union(left, right);
+    }
+    let mut view_classes = EquivalenceClasses::default();
+    for (_, left, right) in view_equalities.iter() {
+        view_classes.union(left, right);
+    }
+    if view_equalities
+        .iter()
+        .any(|(_, left, right)| !query_classes.is_equal(left, right))
+    {
+        return None;
+    }
+
+    let query_formatter = ScalarFormatter {
+        metadata,
+        column_map: query_map,
+        classes: &query_classes,
+    };
+    let view_formatter = ScalarFormatter {
+        metadata,
+        column_map: view_map,
+        classes: &query_classes,
+    };
+    let mut query_predicates = Vec::with_capacity(query_others.len());
+    for predicate in query_others {
+        query_predicates.push((query_formatter.format(predicate)?, predicate));
+    }
+    let mut view_predicates = HashSet::with_capacity(view_others.len());
+    for predicate in view_others {
+        view_predicates.insert(view_formatter.format(predicate)?);
+    }
+    if view_predicates.iter().any(|view_predicate| {
+        query_predicates
+            .iter()
+            .all(|(key, _)| key != view_predicate)
+    }) {
+        return None;
+    }
+
+    let mut compensations = vec![];
+    for (predicate, left, right) in query_equalities {
+        if !view_classes.is_equal(&left, &right) {
+            compensations.push(predicate.clone());
+        }
+    }
+    for (key, predicate) in query_predicates {
+        if !view_predicates.contains(&key) {
+            compensations.push(predicate.clone());
+        }
+    }
+
+    Some(SpjMatch {
+        classes: view_classes,
+        compensations,
+    })
+}
+
+type ColumnEquality<'a> = (&'a ScalarExpr, String, String);
+
+// Splits the predicates into the equalities between columns and the others.
+fn split_predicates<'a>(
+    metadata: &Metadata,
+    column_map: &ColumnMap,
+    spj: &'a SpjInfo,
+) -> (Vec<ColumnEquality<'a>>, Vec<&'a ScalarExpr>) {
+    let mut equalities = vec![];
+    let mut others = vec![];
+    for predicate in spj.predicates.iter() {
+        if let ScalarExpr::FunctionCall(func) = predicate
+            && func.func_name == "eq"
+            && let Some(left) = resolve_column_key(metadata, column_map, &func.arguments[0])
+            && let Some(right) = resolve_column_key(metadata, column_map, &func.arguments[1])
+        {
+            equalities.push((predicate, left, right));
+        } else {
+            others.push(predicate);
+        }
+    }
+    (equalities, others)
+}
+
+fn resolve_column_key(
+    metadata: &Metadata,
+    column_map: &ColumnMap,
+    scalar: &ScalarExpr,
+) -> Option<String> {
+    let ScalarExpr::BoundColumnRef(col) = scalar else {
+        return None;
+    };
+    let index = col.column.index;
+    if let Some(key) = column_key(metadata, index) {
+        return Some(key);
+    }
+    match column_map.get(&index) {
+        Some(scalar) if !is_column_ref_to(scalar, index) => {
+            resolve_column_key(metadata, column_map, scalar)
+        }
+        _ => None,
+    }
+}
+
+// The key of a base column, which is the same in the query and the views.
+fn column_key(metadata: &Metadata, index: IndexType) -> Option<String> {
+    match metadata.column(index) {
+        ColumnEntry::BaseTableColumn(column)
+            if column.path_indices.is_none() && column.virtual_expr.is_none() =>
+        {
+            let table_id = metadata.table(column.table_index).table().get_id();
+            Some(format!("{}.{}", table_id, column.column_name))
+        }
+        _ => None,
+    }
+}
+
+fn is_column_ref_to(scalar: &ScalarExpr, index: IndexType) -> bool {
+    matches!(scalar, ScalarExpr::BoundColumnRef(col) if col.column.index == index)
+}
+
+fn collect_column_map(items: &[ScalarItem], column_map: &mut ColumnMap) {
+    for item in items {
+        if !is_column_ref_to(&item.scalar, item.index) {
+            column_map.insert(item.index, item.scalar.clone());
+        }
+    }
+}
+
+// Maps the outputs of the view accepted by `filter` to the columns of the table of the view.
+fn view_outputs(
+    metadata: &Metadata,
+    view: &MaterializedViewEntry,
+    column_map: &ColumnMap,
+    classes: &EquivalenceClasses,
+    filter: impl Fn(IndexType) -> bool,
+) -> HashMap<String, IndexType> {
+    let formatter = ScalarFormatter {
+        metadata,
+        column_map,
+        classes,
+    };
+    let mut outputs = HashMap::new();
+    for (output, column) in view.output_columns.iter().zip(view.columns.iter()) {
+        if !filter(*output) {
+            continue;
+        }
+        let scalar = column_ref(metadata, *output);
+        if let Some(key) = formatter.format(&scalar) {
+            outputs.entry(key).or_insert(*column);
+        }
+    }
+    outputs
+}
+
+// The return type of the aggregate of the view stored in `column`.
+fn view_aggregate_type(
+    view: &MaterializedViewEntry,
+    column: IndexType,
+    metadata: &Metadata,
+) -> Result<Option<DataType>> {
+    let Some(position) = view.columns.iter().position(|index| *index == column) else {
+        return Ok(None);
+    };
+    Ok(Some(
+        metadata.column(view.output_columns[position]).data_type(),
+    ))
+}
+
+#[derive(Default)]
+struct EquivalenceClasses {
+    parents: HashMap<String, String>,
+}
+
+impl EquivalenceClasses {
+    // The representative of a class is the smallest key in it.
+    fn find(&self, key: &str) -> String {
+        let mut key = key;
+        while let Some(parent) = self.parents.get(key) {
+            key = parent;
+        }
+        key.to_string()
+    }
+
+    fn union(&mut self, left: &str, right: &str) {
+        let left = self.find(left);
+        let right = self.find(right);
+        if left < right {
+            self.parents.insert(right, left);
+        } else if right < left {
+            self.parents.insert(left, right);
+        }
+    }
+
+    fn is_equal(&self, left: &str, right: &str) -> bool {
+        self.find(left) == self.find(right)
+    }
+}
+
+struct ScalarFormatter<'a> {
+    metadata: &'a Metadata,
+    column_map: &'a ColumnMap,
+    classes: &'a EquivalenceClasses,
+}
+
+impl ScalarFormatter<'_> {
+    // Formats the scalar with the base columns, the columns are replaced by the representatives
+    // of their classes. Returns `None` if the scalar is not supported.
+    fn format(&self, scalar: &ScalarExpr) -> Option<String> {
+        match scalar {
+            ScalarExpr::BoundColumnRef(col) => {
+                let index = col.column.index;
+                if let Some(key) = column_key(self.metadata, index) {
+                    return Some(self.classes.find(&key));
+                }
+                match self.column_map.get(&index) {
+                    Some(scalar) if !is_column_ref_to(scalar, index) => self.format(scalar),
+                    _ => None,
+                }
+            }
+            ScalarExpr::ConstantExpr(val) => Some(format!("{}", val.value)),
+            ScalarExpr::FunctionCall(func) => Some(format!(
+                "{}({})",
+                &func.func_name,
+                self.format_list(&func.arguments)?
+            )),
+            ScalarExpr::CastExpr(cast) => {
+                let func_name = if cast.is_try { "try_cast" } else { "cast" };
+                Some(format!(
+                    "{}({} as {})",
+                    func_name,
+                    self.format(&cast.argument)?,
+                    cast.target_type
+                ))
+            }
+            ScalarExpr::AggregateFunction(agg) if !agg.distinct => {
+                let params = agg
+                    .params
+                    .iter()
+                    .map(|i| i.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let args = self.format_list(&agg.args)?;
+                if !params.is_empty() {
+                    Some(format!("{}<{}>({})", &agg.func_name, params, args))
+                } else {
+                    Some(format!("{}({})", &agg.func_name, args))
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn format_list(&self, scalars: &[ScalarExpr]) -> Option<String> {
+        let mut args = Vec::with_capacity(scalars.len());
+        for scalar in scalars {
+            args.push(self.format(scalar)?);
+        }
+        Some(args.join(", "))
+    }
+}
+
+// Rewrites the scalars of the query with the columns of the table of the view.
+struct ExprRewriter<'a> {
+    formatter: ScalarFormatter<'a>,
+    outputs: &'a HashMap<String, IndexType>,
+}
+
+impl ExprRewriter<'_> {
+    #[recursive::recursive]
+    fn rewrite(&self, scalar: &ScalarExpr) -> Result<Option<ScalarExpr>> {
+        if let Some(column) = self
+            .formatter
+            .format(scalar)
+            .and_then(|key| self.outputs.get(&key))
+        {
+            let column = column_ref(self.formatter.metadata, *column);
+            return Ok(Some(cast_if_needed(column, &scalar.data_type()?)?));
+        }
+
+        Ok(match scalar {
+            ScalarExpr::BoundColumnRef(col) => {
+                match self.formatter.column_map.get(&col.column.index) {
+                    Some(mapped) if !is_column_ref_to(mapped, col.column.index) => {
+                        self.rewrite(mapped)?
+                    }
+                    _ => None,
+                }
+            }
+            ScalarExpr::ConstantExpr(_) => Some(scalar.clone()),
+            ScalarExpr::FunctionCall(func) => {
+                let Some(arguments) = self.rewrite_all(&func.arguments)? else {
+                    return Ok(None);
+                };
+                Some(ScalarExpr::FunctionCall(FunctionCall {
+                    arguments,
+                    ..func.clone()
+                }))
+            }
+            ScalarExpr::CastExpr(cast) => self.rewrite(&cast.argument)?.map(|argument| {
+                ScalarExpr::CastExpr(CastExpr {
+                    argument: Box::new(argument),
+                    ..cast.clone()
+                })
+            }),
+            _ => None,
+        })
+    }
+
+    fn rewrite_all(&self, scalars: &[ScalarExpr]) -> Result<Option<Vec<ScalarExpr>>> {
+        let mut results = Vec::with_capacity(scalars.len());
+        for scalar in scalars {
+            let Some(result) = self.rewrite(scalar)? else {
+                return Ok(None);
+            };
+            results.push(result);
+        }
+        Ok(Some(results))
+    }
+}
+
+fn column_ref(metadata: &Metadata, index: IndexType) -> ScalarExpr {
+    let entry = metadata.column(index);
+    new_column_ref(entry.name(), index, entry.data_type())
+}
+
+fn new_column_ref(name: String, index: IndexType, data_type: DataType) -> ScalarExpr {
+    let column =
+        ColumnBindingBuilder::new(name, index, Box::new(data_type), Visibility::Visible).build();
+    ScalarExpr::BoundColumnRef(BoundColumnRef { span: None, column })
+}
+
+fn cast_if_needed(scalar: ScalarExpr, data_type: &DataType) -> Result<ScalarExpr> {
+    if scalar.data_type()? == *data_type {
+        return Ok(scalar);
+    }
+    Ok(ScalarExpr::CastExpr(CastExpr {
+        span: None,
+        is_try: false,
+        argument: Box::new(scalar),
+        target_type: Box::new(data_type.clone()),
+    }))
+}
+
+fn add_filter(s_expr: SExpr, predicates: Vec<ScalarExpr>) -> SExpr {
+    if predicates.is_empty() {
+        return s_expr;
+    }
+    SExpr::create_unary(Arc::new(Filter { predicates }.into()), Arc::new(s_expr))
+}
@@ -21,9 +21,11 @@ mod extract;
 mod filter;
 mod format;
 mod group;
+mod hint;
 mod hyper_dp;
 mod join;
 mod m_expr;
+mod materialized_view;
 mod memo;
 #[allow(clippy::module_inception)]
 mod optimizer;
@@ -39,8 +41,15 @@ pub use cascades::CascadesOptimizer;
 pub use decorrelate::FlattenInfo;
 pub use decorrelate::SubqueryRewriter;
 pub use extract::PatternExtractor;
+pub use hint::resolve_hint_tables;
+pub use hint::HintState;
+pub use hint::JoinHintOptimizer;
+pub use hint::PlanBaselineState;
+pub use hint::PlanBaselineUsage;
+pub use hint::PlanOutline;
 pub use hyper_dp::DPhpy;
 pub use m_expr::MExpr;
+pub use materialized_view::MaterializedViewOptimizer;
 pub use memo::Memo;
 pub use optimizer::optimize;
 pub use optimizer::optimize_query;
@@ -37,11 +37,15 @@ use crate::optimizer::distributed::SortAndLimitPushDownOptimizer;
 use crate::optimizer::filter::DeduplicateJoinConditionOptimizer;
 use crate::optimizer::filter::PullUpFilterOptimizer;
 use crate::optimizer::hyper_dp::DPhpy;
+use crate::optimizer::join::EliminateJoinOptimizer;
 use crate::optimizer::join::SingleToInnerOptimizer;
 use crate::optimizer::rule::TransformResult;
 use crate::optimizer::statistics::CollectStatisticsOptimizer;
 use crate::optimizer::util::contains_local_table_scan;
 use crate::optimizer::util::contains_warehouse_table_scan;
+use crate::optimizer::ColumnSet;
+use crate::optimizer::JoinHintOptimizer;
+use crate::optimizer::MaterializedViewOptimizer;
 use crate::optimizer::RuleFactory;
 use crate::optimizer::RuleID;
 use crate::optimizer::SExpr;
@@ -75,6 +79,9 @@ pub struct OptimizerContext {
     planning_agg_index: bool,
     #[educe(Debug(ignore))]
     pub(crate) sample_executor: Option<Arc<dyn QueryExecutor>>,
+    // The columns of the result of the query, the joins contributing no column to
+    // the result may be eliminated.
+    output_columns: Option<ColumnSet>,
 }
 
 impl OptimizerContext {
@@ -89,6 +96,7 @@ impl OptimizerContext {
             max_push_down_limit: 10000,
             sample_executor: None,
             planning_agg_index: false,
+            output_columns: None,
         }
     }
 
@@ -200,14 +208,17 @@ pub async fn optimize(mut opt_ctx: OptimizerContext, plan: Plan) -> Result<Plan>
             rewrite_kind,
             formatted_ast,
             ignore_result,
-        } => Ok(Plan::Query {
-            s_expr: Box::new(optimize_query(&mut opt_ctx, *s_expr).await?),
-            bind_context,
-            metadata,
-            rewrite_kind,
-            formatted_ast,
-            ignore_result,
-        }),
+        } => {
+            opt_ctx.output_columns = Some(bind_context.columns.iter().map(|c| c.index).collect());
+            Ok(Plan::Query {
+                s_expr: Box::new(optimize_query(&mut opt_ctx, *s_expr).await?),
+                bind_context,
+                metadata,
+                rewrite_kind,
+                formatted_ast,
+                ignore_result,
+            })
+        }
         Plan::Explain { kind, config, plan } => match kind {
             ExplainKind::Ast(_) | ExplainKind::Syntax(_) => {
                 Ok(Plan::Explain { config, kind, plan })
@@ -356,6 +367,20 @@ pub async fn optimize(mut opt_ctx: OptimizerContext, plan: Plan) -> Result<Plan>
             Ok(Plan::CreateTable(plan))
         }
 
+        Plan::CreateMaterializedView(mut plan) => {
+            if let Some(p) = &plan.create_table.as_select {
+                let optimized_plan = optimize(opt_ctx.clone(), *p.clone()).await?;
+                plan.create_table.as_select = Some(Box::new(optimized_plan));
+            }
+
+            Ok(Plan::CreateMaterializedView(plan))
+        }
+
+        Plan::PinPlanBaseline(mut plan) => {
+            plan.plan = Box::new(optimize(opt_ctx.clone(), *plan.plan.clone()).await?);
+            Ok(Plan::PinPlanBaseline(plan))
+        }
+
         Plan::Set(mut plan) => {
             if let SetScalarsOrQuery::Query(q) = plan.values {
                 let optimized_plan = optimize(opt_ctx.clone(), *q.clone()).await?;
@@ -396,6 +421,22 @@ pub async fn optimize_query(opt_ctx: &mut OptimizerContext, mut s_expr: SExpr) -
         s_expr = decorrelate_subquery(opt_ctx.metadata.clone(), s_expr.clone())?;
     }
 
+    // Rewrite the query to read from the fresh materialized views.
+    if !opt_ctx.planning_agg_index && has_materialized_views(&opt_ctx.metadata) {
+        s_expr = MaterializedViewOptimizer::new(opt_ctx.metadata.clone()).run(&s_expr)?;
+    }
+
+    // Eliminate the joins made redundant by the key constraints.
+    if let Some(output_columns) = &opt_ctx.output_columns
+        && opt_ctx
+            .table_ctx
+            .get_settings()
+            .get_enable_join_elimination()?
+    {
+        s_expr = EliminateJoinOptimizer::new(opt_ctx.metadata.clone())
+            .run(&s_expr, output_columns.clone())?;
+    }
+
     s_expr = RuleStatsAggregateOptimizer::new(opt_ctx.table_ctx.clone(), opt_ctx.metadata.clone())
         .run(&s_expr)
         .await?;
@@ -432,12 +473,22 @@ pub async fn optimize_query(opt_ctx: &mut OptimizerContext, mut s_expr: SExpr) -
     // Deduplicate join conditions.
     s_expr = DeduplicateJoinConditionOptimizer::new().run(&s_expr)?;
 
-    let mut cascades = CascadesOptimizer::new(opt_ctx.clone(), dphyp_optimized)?;
-
     if opt_ctx.enable_join_reorder {
         s_expr = RecursiveOptimizer::new([RuleID::CommuteJoin].as_slice(), opt_ctx).run(&s_expr)?;
     }
 
+    // Apply join method and distribution hints, the join order must not be changed afterwards.
+    let join_hint_optimizer = JoinHintOptimizer::new(
+        opt_ctx.metadata.clone(),
+        opt_ctx.enable_distributed_optimization,
+    );
+    if !join_hint_optimizer.is_empty() {
+        s_expr = join_hint_optimizer.run(&s_expr)?;
+        dphyp_optimized = true;
+    }
+
+    let mut cascades = CascadesOptimizer::new(opt_ctx.clone(), dphyp_optimized)?;
+
     // Cascades optimizer may fail due to timeout, fallback to heuristic optimizer in this case.
     s_expr = match cascades.optimize(s_expr.clone()) {
         Ok(mut s_expr) => {
@@ -471,6 +522,12 @@ pub async fn optimize_query(opt_ctx: &mut OptimizerContext, mut s_expr: SExpr) -
     Ok(s_expr)
 }
 
+fn has_materialized_views(metadata: &MetadataRef) -> bool {
+    let metadata = metadata.read();
+    // The lazy columns are bound to the tables of the query.
+    !metadata.materialized_views().is_empty() && metadata.lazy_columns().is_empty()
+}
+
 // TODO(leiysky): reuse the optimization logic with `optimize_query`
 async fn get_optimized_memo(opt_ctx: &mut OptimizerContext, mut s_expr: SExpr) -> Result<Memo> {
     if contains_local_table_scan(&s_expr, &opt_ctx.metadata) {
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp::Ordering;
 use std::collections::HashMap;
 
 use databend_common_storage::Datum;
@@ -40,6 +41,41 @@ pub struct ColumnStat {
     pub histogram: Option<Histogram>,
 }
 
+#[derive(Debug, Clone)]
+/// Joint statistics of a group of columns
+pub struct MultiColumnStat {
+    /// Indexes of the columns, in the order of the values of `mcv`
+    pub columns: Vec<IndexType>,
+
+    /// Number of distinct combinations of the column values
+    pub ndv: f64,
+
+    /// Most common combinations of the column values and their frequencies
+    pub mcv: Vec<(Vec<Datum>, f64)>,
+}
+
+impl MultiColumnStat {
+    /// Estimate the fraction of rows having the given values.
+    pub fn frequency(&self, values: &[Datum]) -> f64 {
+        let matched = self.mcv.iter().find(|(mcv, _)| {
+            mcv.iter().zip(values.iter()).all(|(l, r)| {
+                l.type_comparable(r) && l.compare(r).is_ok_and(|ord| ord == Ordering::Equal)
+            })
+        });
+        if let Some((_, frequency)) = matched {
+            return *frequency;
+        }
+
+        // The rest rows are assumed to be evenly distributed among the other combinations.
+        let remaining_ndv = self.ndv - self.mcv.len() as f64;
+        if remaining_ndv < 1.0 {
+            return 0.0;
+        }
+        let mcv_frequency: f64 = self.mcv.iter().map(|(_, frequency)| frequency).sum();
+        (1.0 - mcv_frequency).max(0.0) / remaining_ndv
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct NewStatistic {
     pub min: Option<Datum>,
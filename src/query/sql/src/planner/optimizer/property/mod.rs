@@ -24,6 +24,7 @@ mod selectivity;
 pub use builder::RelExpr;
 pub use column_stat::ColumnStat;
 pub use column_stat::ColumnStatSet;
+pub use column_stat::MultiColumnStat;
 pub use column_stat::NewStatistic;
 pub use enforcer::require_property;
 pub use enforcer::DistributionEnforcer;
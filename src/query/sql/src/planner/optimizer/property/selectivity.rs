@@ -14,6 +14,7 @@
 
 use std::cmp::max;
 use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::collections::HashSet;
 
 use databend_common_exception::ErrorCode;
@@ -130,6 +131,58 @@ impl<'a> SelectivityEstimator<'a> {
         })
     }
 
+    /// Compute the selectivity of the equal predicates on a group of columns with joint
+    /// statistics, returns the selectivity and the positions of the covered predicates.
+    pub fn compute_multi_column_selectivity(
+        &self,
+        predicates: &[ScalarExpr],
+    ) -> (f64, HashSet<usize>) {
+        let mut selectivity = MAX_SELECTIVITY;
+        let mut covered = HashSet::new();
+        if self.input_stat.multi_column_stats.is_empty() {
+            return (selectivity, covered);
+        }
+
+        // column index -> (position of predicate, value)
+        let mut equal_values = HashMap::new();
+        for (position, predicate) in predicates.iter().enumerate() {
+            let ScalarExpr::FunctionCall(func) = predicate else {
+                continue;
+            };
+            if func.func_name != "eq" {
+                continue;
+            }
+            let (column_ref, constant) = match (&func.arguments[0], &func.arguments[1]) {
+                (ScalarExpr::BoundColumnRef(column_ref), ScalarExpr::ConstantExpr(constant))
+                | (ScalarExpr::ConstantExpr(constant), ScalarExpr::BoundColumnRef(column_ref)) => {
+                    (column_ref, constant)
+                }
+                _ => continue,
+            };
+            if let Some(datum) = Datum::from_scalar(constant.value.clone()) {
+                equal_values.insert(column_ref.column.index, (position, datum));
+            }
+        }
+
+        for stat in self.input_stat.multi_column_stats.iter() {
+            let Some(values) = stat
+                .columns
+                .iter()
+                .map(|column| equal_values.get(column))
+                .collect::<Option<Vec<_>>>()
+            else {
+                continue;
+            };
+            let datums = values
+                .iter()
+                .map(|(_, datum)| datum.clone())
+                .collect::<Vec<_>>();
+            selectivity = selectivity.min(stat.frequency(&datums));
+            covered.extend(values.iter().map(|(position, _)| *position));
+        }
+        (selectivity, covered)
+    }
+
     // The method uses probability predication to compute like selectivity.
     // The core idea is from postgresql.
     fn compute_like_selectivity(&mut self, func: &FunctionCall) -> Result<f64> {
@@ -21,7 +21,9 @@ use databend_common_expression::types::NumberScalar;
 use databend_common_expression::types::F64;
 use databend_common_expression::ColumnId;
 use databend_common_expression::Scalar;
+use databend_common_meta_app::schema::TableConstraint;
 
+use crate::optimizer::MultiColumnStat;
 use crate::optimizer::SExpr;
 use crate::plans::ConstantExpr;
 use crate::plans::Filter;
@@ -71,14 +73,21 @@ impl CollectStatisticsOptimizer {
 
                 let mut column_stats = HashMap::new();
                 let mut histograms = HashMap::new();
+                let mut column_indexes = HashMap::new();
+                let mut column_names = HashMap::new();
                 for column in columns.iter() {
                     if let ColumnEntry::BaseTableColumn(BaseTableColumn {
                         column_index,
+                        column_name,
                         column_id,
+                        path_indices,
                         virtual_expr,
                         ..
                     }) = column
                     {
+                        if path_indices.is_none() {
+                            column_names.insert(column_name.clone(), *column_index);
+                        }
                         if virtual_expr.is_none() {
                             if let Some(column_id) = *column_id {
                                 let col_stat = column_statistics_provider
@@ -87,16 +96,82 @@ impl CollectStatisticsOptimizer {
                                 let histogram =
                                     column_statistics_provider.histogram(column_id as ColumnId);
                                 histograms.insert(*column_index, histogram);
+                                column_indexes.insert(column_id as ColumnId, *column_index);
                             }
                         }
                     }
                 }
 
+                let mut multi_column_stats = column_statistics_provider
+                    .multi_column_statistics()
+                    .iter()
+                    .filter_map(|stat| {
+                        let columns = stat
+                            .column_ids
+                            .iter()
+                            .map(|column_id| column_indexes.get(column_id).cloned())
+                            .collect::<Option<Vec<_>>>()?;
+                        Some(MultiColumnStat {
+                            columns,
+                            ndv: stat.ndv as f64,
+                            mcv: stat
+                                .mcv
+                                .iter()
+                                .map(|mcv| (mcv.values.clone(), mcv.frequency))
+                                .collect(),
+                        })
+                    })
+                    .collect::<Vec<_>>();
+
+                // The values of a primary or unique key are distinct. The key constraints are
+                // not enforced, they are only trusted if the user opts in.
+                let trust_keys = self
+                    .table_ctx
+                    .get_settings()
+                    .get_enable_join_elimination()?;
+                if let Some(num_rows) = table_stats
+                    .as_ref()
+                    .and_then(|stats| stats.num_rows)
+                    .filter(|_| trust_keys)
+                {
+                    for constraint in table.get_table_info().meta.constraints.values() {
+                        let (TableConstraint::PrimaryKey(key) | TableConstraint::Unique(key)) =
+                            constraint
+                        else {
+                            continue;
+                        };
+                        let Some(key) = key
+                            .iter()
+                            .map(|column| column_names.get(column).cloned())
+                            .collect::<Option<Vec<_>>>()
+                        else {
+                            continue;
+                        };
+                        if let [column] = key.as_slice() {
+                            if let Some(Some(stat)) = column_stats.get_mut(column) {
+                                stat.ndv = Some(num_rows.saturating_sub(stat.null_count));
+                            }
+                        } else if let Some(stat) = multi_column_stats
+                            .iter_mut()
+                            .find(|stat| stat.columns == key)
+                        {
+                            stat.ndv = num_rows as f64;
+                        } else {
+                            multi_column_stats.push(MultiColumnStat {
+                                columns: key,
+                                ndv: num_rows as f64,
+                                mcv: vec![],
+                            });
+                        }
+                    }
+                }
+
                 let mut scan = scan.clone();
                 scan.statistics = Arc::new(Statistics {
                     table_stats,
                     column_stats,
                     histograms,
+                    multi_column_stats,
                 });
                 let mut s_expr = s_expr.replace_plan(Arc::new(RelOperator::Scan(scan.clone())));
                 if let Some(sample) = &scan.sample {
@@ -0,0 +1,187 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::SelectStmt;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TableReference;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::PlanBaseline;
+use databend_common_meta_types::SeqV;
+use databend_common_settings::PlanBaselineMode;
+use databend_common_users::UserApiProvider;
+use derive_visitor::Drive;
+use derive_visitor::DriveMut;
+use derive_visitor::Visitor;
+use derive_visitor::VisitorMut;
+use log::info;
+use log::warn;
+use md5::Digest;
+use md5::Md5;
+
+use crate::optimizer::PlanBaselineState;
+use crate::optimizer::PlanBaselineUsage;
+use crate::optimizer::PlanOutline;
+use crate::plans::Plan;
+use crate::NameResolutionContext;
+use crate::Planner;
+
+impl Planner {
+    /// The key of the plan baseline of a query, it is the same as the `query_parameterized_hash`
+    /// in the query log. The literals are replaced by NULL, so the queries that differ only in
+    /// constants share a baseline.
+    pub fn plan_baseline_key(stmt: &Statement) -> String {
+        let mut stmt = stmt.clone();
+        stmt.drive_mut(&mut LiteralRemover);
+        format!("{:x}", Md5::digest(stmt.to_string()))
+    }
+
+    /// Get the plan baseline of the statement, only the queries with joins have baselines.
+    pub(crate) async fn get_plan_baseline(
+        &self,
+        stmt: &Statement,
+    ) -> Result<Option<(PlanBaselineMode, SeqV<PlanBaseline>)>> {
+        if !matches!(stmt, Statement::Query(_)) {
+            return Ok(None);
+        }
+        let mode = self.ctx.get_settings().get_plan_baseline_mode()?;
+        if mode == PlanBaselineMode::Off {
+            return Ok(None);
+        }
+        let mut visitor = JoinVisitor::default();
+        stmt.drive(&mut visitor);
+        if !visitor.has_join {
+            return Ok(None);
+        }
+
+        let sql_hash = Self::plan_baseline_key(stmt);
+        let baseline = match UserApiProvider::instance().plan_baseline_api(&self.ctx.get_tenant()) {
+            Ok(api) => api.get_baseline(&sql_hash).await,
+            Err(e) => Err(e),
+        };
+        match baseline {
+            Ok(baseline) => Ok(baseline.map(|baseline| (mode, baseline))),
+            Err(e) => {
+                // The query is planned without the baseline if it can not be read.
+                warn!(
+                    "failed to get the plan baseline of query {}: {}",
+                    sql_hash, e
+                );
+                Ok(None)
+            }
+        }
+    }
+
+    /// Plan the query with its baseline.
+    ///
+    /// In the `fixed` mode the pinned plan is used. In the `evolve` mode a new plan is tried if it
+    /// is not known to be slower than the pinned plan, it is compared with the pinned plan when
+    /// the query finishes.
+    pub(crate) async fn plan_with_baseline(
+        &self,
+        stmt: &Statement,
+        name_resolution_ctx: &NameResolutionContext,
+        attach_query: bool,
+        mode: PlanBaselineMode,
+        baseline: SeqV<PlanBaseline>,
+    ) -> Result<Plan> {
+        if mode == PlanBaselineMode::Evolve && baseline.data.execution_time_ms.is_some() {
+            let plan = self
+                .bind_and_optimize(stmt, name_resolution_ctx, attach_query, vec![])
+                .await?;
+            if let Plan::Query {
+                s_expr, metadata, ..
+            } = &plan
+            {
+                let outline = PlanOutline::extract(&metadata.read(), s_expr)?;
+                if let Some(outline) = outline {
+                    let fingerprint = outline.fingerprint();
+                    let usage = if fingerprint == baseline.data.plan_fingerprint {
+                        Some(PlanBaselineUsage::Applied)
+                    } else if !baseline.data.rejected_fingerprints.contains(&fingerprint) {
+                        info!(
+                            "try the plan {} of query {} against its baseline",
+                            fingerprint, baseline.data.sql_hash
+                        );
+                        Some(PlanBaselineUsage::Evolving(outline))
+                    } else {
+                        None
+                    };
+                    if let Some(usage) = usage {
+                        metadata
+                            .write()
+                            .set_plan_baseline(PlanBaselineState { baseline, usage });
+                        return Ok(plan);
+                    }
+                }
+            }
+        }
+
+        let hints = PlanOutline::from_baseline(&baseline.data).optimizer_hints();
+        let plan = self
+            .bind_and_optimize(stmt, name_resolution_ctx, attach_query, hints)
+            .await?;
+        if let Plan::Query { metadata, .. } = &plan {
+            metadata.write().set_plan_baseline(PlanBaselineState {
+                baseline,
+                usage: PlanBaselineUsage::Applied,
+            });
+        }
+        Ok(plan)
+    }
+}
+
+/// Record the fingerprint of the join decisions of a query plan.
+pub(crate) fn set_plan_fingerprint(plan: &Plan) -> Result<()> {
+    if let Plan::Query {
+        s_expr, metadata, ..
+    } = plan
+    {
+        let outline = PlanOutline::extract(&metadata.read(), s_expr)?;
+        if let Some(outline) = outline {
+            metadata.write().set_plan_fingerprint(outline.fingerprint());
+        }
+    }
+    Ok(())
+}
+
+#[derive(Visitor, Default)]
+#[visitor(TableReference(enter), SelectStmt(enter))]
+struct JoinVisitor {
+    has_join: bool,
+}
+
+impl JoinVisitor {
+    fn enter_table_reference(&mut self, table_ref: &TableReference) {
+        if matches!(table_ref, TableReference::Join { .. }) {
+            self.has_join = true;
+        }
+    }
+
+    fn enter_select_stmt(&mut self, select: &SelectStmt) {
+        if select.from.len() > 1 {
+            self.has_join = true;
+        }
+    }
+}
+
+#[derive(VisitorMut)]
+#[visitor(Literal(enter))]
+struct LiteralRemover;
+
+impl LiteralRemover {
+    fn enter_literal(&mut self, lit: &mut Literal) {
+        *lit = Literal::Null;
+    }
+}
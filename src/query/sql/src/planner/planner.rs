@@ -19,6 +19,7 @@ use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::InsertSource;
 use databend_common_ast::ast::InsertStmt;
 use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::OptimizerHint;
 use databend_common_ast::ast::Statement;
 use databend_common_ast::parser::parse_raw_insert_stmt;
 use databend_common_ast::parser::parse_raw_replace_stmt;
@@ -37,6 +38,7 @@ use log::info;
 use log::warn;
 use parking_lot::RwLock;
 
+use super::plan_baseline::set_plan_fingerprint;
 use super::semantic::AggregateRewriter;
 use super::semantic::DistinctToGroupBy;
 use crate::optimizer::optimize;
@@ -234,6 +236,12 @@ impl Planner {
             None
         };
 
+        // The plan of a query with a baseline is not cached, it depends on the baseline.
+        let plan_baseline = self.get_plan_baseline(stmt).await?;
+        if plan_baseline.is_some() {
+            enable_planner_cache = false;
+        }
+
         if enable_planner_cache {
             let (c, plan) = self.get_cache(
                 name_resolution_ctx.clone(),
@@ -251,11 +259,41 @@ impl Planner {
             enable_planner_cache = c;
         }
 
+        let optimized_plan = match plan_baseline {
+            Some((mode, baseline)) => {
+                self.plan_with_baseline(stmt, &name_resolution_ctx, attach_query, mode, baseline)
+                    .await?
+            }
+            None => {
+                self.bind_and_optimize(stmt, &name_resolution_ctx, attach_query, vec![])
+                    .await?
+            }
+        };
+        set_plan_fingerprint(&optimized_plan)?;
+
+        if enable_planner_cache {
+            self.set_cache(planner_cache_key.clone().unwrap(), optimized_plan.clone());
+        }
+
+        info!("logical plan built, time used: {:?}", start.elapsed());
+        Ok(optimized_plan)
+    }
+
+    /// Bind the statement and optimize the plan, the hints are added to the hints of the statement.
+    pub(crate) async fn bind_and_optimize(
+        &self,
+        stmt: &Statement,
+        name_resolution_ctx: &NameResolutionContext,
+        attach_query: bool,
+        hints: Vec<OptimizerHint>,
+    ) -> Result<Plan> {
+        let query_kind = get_query_kind(stmt);
+        let settings = self.ctx.get_settings();
         let metadata = Arc::new(RwLock::new(Metadata::default()));
         let binder = Binder::new(
             self.ctx.clone(),
             CatalogManager::instance(),
-            name_resolution_ctx,
+            name_resolution_ctx.clone(),
             metadata.clone(),
         )
         .with_subquery_executor(self.query_executor.clone());
@@ -269,6 +307,9 @@ impl Planner {
         if attach_query {
             self.ctx.attach_query_str(query_kind, stmt.to_mask_sql());
         }
+        for hint in hints {
+            metadata.write().add_optimizer_hint(hint);
+        }
 
         // Step 4: Optimize the SExpr with optimizers, and generate optimized physical SExpr
         let opt_ctx = OptimizerContext::new(self.ctx.clone(), metadata.clone())
@@ -278,14 +319,7 @@ impl Planner {
             .with_max_push_down_limit(settings.get_max_push_down_limit()?)
             .with_sample_executor(self.query_executor.clone());
 
-        let optimized_plan = optimize(opt_ctx, plan).await?;
-
-        if enable_planner_cache {
-            self.set_cache(planner_cache_key.clone().unwrap(), optimized_plan.clone());
-        }
-
-        info!("logical plan built, time used: {:?}", start.elapsed());
-        Ok(optimized_plan)
+        optimize(opt_ctx, plan).await
     }
 
     fn add_max_rows_limit(&self, statement: &mut Statement) {
@@ -294,13 +328,16 @@ impl Planner {
             return;
         }
 
-        if let Statement::Query(query) = statement {
-            if query.limit.is_empty() {
-                query.limit = vec![Expr::Literal {
-                    span: None,
-                    value: Literal::UInt64(max_rows),
-                }];
-            }
+        let query = match statement {
+            Statement::Query(query) => query,
+            Statement::AlterQuery(stmt) => &mut stmt.query,
+            _ => return,
+        };
+        if query.limit.is_empty() {
+            query.limit = vec![Expr::Literal {
+                span: None,
+                value: Literal::UInt64(max_rows),
+            }];
         }
     }
 
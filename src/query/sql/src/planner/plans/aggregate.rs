@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use databend_common_catalog::table_context::TableContext;
@@ -117,11 +118,29 @@ impl Aggregate {
         {
             cardinality
         } else {
-            // A upper bound
-            let res = self.group_items.iter().fold(1.0, |acc, item| {
-                let item_stat = statistics.column_stats.get(&item.index).unwrap();
-                acc * item_stat.ndv
-            });
+            // A upper bound, correlated columns are estimated by their joint ndv.
+            let group_columns: HashSet<IndexType> =
+                self.group_items.iter().map(|item| item.index).collect();
+            let mut covered_columns = HashSet::new();
+            let mut res = 1.0;
+            for stat in statistics.multi_column_stats.iter() {
+                if stat
+                    .columns
+                    .iter()
+                    .all(|c| group_columns.contains(c) && !covered_columns.contains(c))
+                {
+                    res *= stat.ndv;
+                    covered_columns.extend(stat.columns.iter().cloned());
+                }
+            }
+            let res = self
+                .group_items
+                .iter()
+                .filter(|item| !covered_columns.contains(&item.index))
+                .fold(res, |acc, item| {
+                    let item_stat = statistics.column_stats.get(&item.index).unwrap();
+                    acc * item_stat.ndv
+                });
             for item in self.group_items.iter() {
                 let item_stat = statistics.column_stats.get_mut(&item.index).unwrap();
                 if let Some(histogram) = &mut item_stat.histogram {
@@ -154,6 +173,7 @@ impl Aggregate {
             statistics: Statistics {
                 precise_cardinality,
                 column_stats: statistics.column_stats,
+                multi_column_stats: statistics.multi_column_stats,
             },
         }))
     }
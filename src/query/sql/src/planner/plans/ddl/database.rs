@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use databend_common_expression::DataSchemaRef;
+use databend_common_meta_app::principal::StageInfo;
 use databend_common_meta_app::schema::database_name_ident::DatabaseNameIdent;
 use databend_common_meta_app::schema::CreateDatabaseReq;
 use databend_common_meta_app::schema::CreateOption;
@@ -29,6 +30,8 @@ pub struct CreateDatabasePlan {
     pub catalog: String,
     pub database: String,
     pub meta: DatabaseMeta,
+    /// The source database of `CREATE DATABASE ... CLONE`, in the same catalog.
+    pub clone_from: Option<String>,
 }
 
 impl From<CreateDatabasePlan> for CreateDatabaseReq {
@@ -109,6 +112,29 @@ impl From<UndropDatabasePlan> for UndropDatabaseReq {
     }
 }
 
+/// Backup.
+#[derive(Clone, Debug)]
+pub struct BackupDatabasePlan {
+    pub tenant: Tenant,
+    pub catalog: String,
+    pub database: String,
+    pub stage_info: Box<StageInfo>,
+    pub path: String,
+}
+
+/// Restore.
+#[derive(Clone, Debug)]
+pub struct RestoreDatabasePlan {
+    pub tenant: Tenant,
+    pub catalog: String,
+    /// The backed up database.
+    pub database: String,
+    /// The database to restore into.
+    pub new_database: String,
+    pub stage_info: Box<StageInfo>,
+    pub path: String,
+}
+
 /// Use.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct UseDatabasePlan {
@@ -20,7 +20,9 @@ mod dictionary;
 mod dynamic_table;
 mod file_format;
 mod index;
+mod materialized_view;
 mod notification;
+mod plan_baseline;
 mod procedure;
 mod sequence;
 mod stage;
@@ -40,7 +42,9 @@ pub use dictionary::*;
 pub use dynamic_table::*;
 pub use file_format::*;
 pub use index::*;
+pub use materialized_view::*;
 pub use notification::*;
+pub use plan_baseline::*;
 pub use procedure::*;
 pub use sequence::*;
 pub use stage::*;
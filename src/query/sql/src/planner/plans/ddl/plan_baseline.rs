@@ -0,0 +1,30 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::plans::Plan;
+
+/// Pin the plan of a query as its baseline, the query is identified by the hash of its SQL
+/// without optimizer hints, so the hints of the pinned query choose the plan of the query.
+#[derive(Clone, Debug)]
+pub struct PinPlanBaselinePlan {
+    pub sql_hash: String,
+    pub query_text: String,
+    pub plan: Box<Plan>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnpinPlanBaselinePlan {
+    pub sql_hash: String,
+    pub query_text: String,
+}
@@ -17,6 +17,7 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use databend_common_ast::ast::Engine;
+use databend_common_catalog::plan::Filters;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::DataField;
@@ -27,12 +28,15 @@ use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRef;
 use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::TableConstraint;
 use databend_common_meta_app::schema::TableIndex;
+use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableNameIdent;
 use databend_common_meta_app::schema::UndropTableReq;
 use databend_common_meta_app::storage::StorageParams;
 use databend_common_meta_app::tenant::Tenant;
 use databend_common_pipeline_core::LockGuard;
+use databend_storages_common_table_meta::table::IdentityColumn;
 
 use crate::plans::Plan;
 
@@ -55,6 +59,8 @@ pub struct CreateTablePlan {
     pub cluster_key: Option<String>,
     pub as_select: Option<Box<Plan>>,
     pub inverted_indexes: Option<BTreeMap<String, TableIndex>>,
+    pub constraints: BTreeMap<String, TableConstraint>,
+    pub clone_from: Option<CloneTableSource>,
 }
 
 impl CreateTablePlan {
@@ -63,6 +69,16 @@ impl CreateTablePlan {
     }
 }
 
+/// The source table of `CREATE TABLE ... CLONE`.
+#[derive(Clone, Debug)]
+pub struct CloneTableSource {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    /// Table info of the source table, navigated to the snapshot being cloned.
+    pub table_info: TableInfo,
+}
+
 /// Desc.
 #[derive(Clone, Debug)]
 pub struct DescribeTablePlan {
@@ -200,6 +216,8 @@ pub struct AnalyzeTablePlan {
     pub catalog: String,
     pub database: String,
     pub table: String,
+    /// columns whose joint statistics are collected, empty if not specified
+    pub columns: Vec<String>,
 }
 
 impl AnalyzeTablePlan {
@@ -281,6 +299,9 @@ pub struct AddTableColumnPlan {
     pub comment: String,
     pub option: AddColumnOption,
     pub is_deterministic: bool,
+    /// Set if the new column is an identity column, the column id and the sequence
+    /// are decided when the column is added.
+    pub identity: Option<IdentityColumn>,
 }
 
 impl AddTableColumnPlan {
@@ -329,6 +350,52 @@ impl DropTableColumnPlan {
     }
 }
 
+// Table add constraint
+#[derive(Clone, Debug)]
+pub struct AddTableConstraintPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub constraint_name: String,
+    pub constraint: TableConstraint,
+}
+
+impl AddTableConstraintPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+// Table drop constraint
+#[derive(Clone, Debug)]
+pub struct DropTableConstraintPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub constraint_name: String,
+}
+
+impl DropTableConstraintPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+// Table validate constraint
+#[derive(Clone, Debug)]
+pub struct ValidateTableConstraintPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub constraint_name: String,
+}
+
+impl ValidateTableConstraintPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
 // ModifyColumnAction after name resolved, used in ModifyTableColumnPlan
 #[derive(Debug, Clone)]
 pub enum ModifyColumnAction {
@@ -403,6 +470,28 @@ impl TruncateTablePlan {
     }
 }
 
+/// Warm up the caches with the data of a table, or unpin it.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct CacheWarmupTablePlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    /// The columns to warm up, all the columns if `None`.
+    pub columns: Option<Vec<String>>,
+    /// The filters selecting the blocks to warm up.
+    pub filters: Option<Filters>,
+    /// Exempt the warmed up items from eviction.
+    pub pin: bool,
+    /// Unpin the items of the table instead of warming up.
+    pub unpin: bool,
+}
+
+impl CacheWarmupTablePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
 /// Undrop.
 #[derive(Clone, Debug)]
 pub struct UndropTablePlan {
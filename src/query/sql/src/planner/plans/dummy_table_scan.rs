@@ -69,6 +69,7 @@ impl Operator for DummyTableScan {
             statistics: Statistics {
                 precise_cardinality: Some(1),
                 column_stats: Default::default(),
+                multi_column_stats: Default::default(),
             },
         }))
     }
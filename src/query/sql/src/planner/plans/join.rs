@@ -200,6 +200,27 @@ pub struct Join {
     pub single_to_inner: Option<JoinType>,
     // Cache info for ExpressionScan.
     pub build_side_cache_info: Option<HashJoinBuildCacheInfo>,
+    // Join method chosen by an optimizer hint.
+    pub method_hint: Option<JoinMethod>,
+    // Distribution of the join inputs chosen by an optimizer hint.
+    pub distribution_hint: Option<JoinDistribution>,
+}
+
+/// Physical join method requested by `HASH_JOIN`, `MERGE_JOIN` or `NL_JOIN` hints.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum JoinMethod {
+    Hash,
+    Merge,
+    NestedLoop,
+}
+
+/// Distribution of the join inputs requested by `BROADCAST` or `SHUFFLE` hints.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum JoinDistribution {
+    /// Broadcast the build side to all nodes.
+    Broadcast,
+    /// Shuffle both sides by the join keys.
+    Shuffle,
 }
 
 impl Default for Join {
@@ -214,6 +235,8 @@ impl Default for Join {
             is_lateral: false,
             single_to_inner: None,
             build_side_cache_info: None,
+            method_hint: None,
+            distribution_hint: None,
         }
     }
 }
@@ -349,6 +372,19 @@ impl Join {
                 _ => continue,
             }
         }
+        // Correlated join keys are estimated by their joint ndv.
+        if join_card != 0.0
+            && let Some(card) = evaluate_by_multi_column_stats(
+                &self.equi_conditions,
+                left_statistics,
+                right_statistics,
+                *left_cardinality,
+                *right_cardinality,
+            )
+            && card < join_card
+        {
+            join_card = card;
+        }
         if join_card_updated {
             for (idx, left) in left_statistics.column_stats.iter_mut() {
                 if *idx == left_column_index {
@@ -445,19 +481,22 @@ impl Join {
             JoinType::RightSingle | JoinType::LeftMark | JoinType::RightAnti => right_cardinality,
         };
         // Derive column statistics
-        let column_stats = if cardinality == 0.0 {
-            HashMap::new()
+        let (column_stats, multi_column_stats) = if cardinality == 0.0 {
+            (HashMap::new(), vec![])
         } else {
             let mut column_stats = HashMap::new();
             column_stats.extend(left_statistics.column_stats);
             column_stats.extend(right_statistics.column_stats);
-            column_stats
+            let mut multi_column_stats = left_statistics.multi_column_stats;
+            multi_column_stats.extend(right_statistics.multi_column_stats);
+            (column_stats, multi_column_stats)
         };
         Ok(Arc::new(StatInfo {
             cardinality,
             statistics: Statistics {
                 precise_cardinality: None,
                 column_stats,
+                multi_column_stats,
             },
         }))
     }
@@ -586,15 +625,27 @@ impl Operator for Join {
             return Ok(required);
         }
 
+        // Follow the distribution requested by hints
+        if self.distribution_hint == Some(JoinDistribution::Broadcast) {
+            if child_index == 1 {
+                required.distribution = Distribution::Broadcast;
+            } else {
+                required.distribution = Distribution::Any;
+            }
+            return Ok(required);
+        }
+
         // Try to use broadcast join
-        if !matches!(
-            self.join_type,
-            JoinType::Right
-                | JoinType::Full
-                | JoinType::RightAnti
-                | JoinType::RightSemi
-                | JoinType::LeftMark
-        ) {
+        if self.distribution_hint.is_none()
+            && !matches!(
+                self.join_type,
+                JoinType::Right
+                    | JoinType::Full
+                    | JoinType::RightAnti
+                    | JoinType::RightSemi
+                    | JoinType::LeftMark
+            )
+        {
             let settings = ctx.get_settings();
             let left_stat_info = rel_expr.derive_cardinality_child(0)?;
             let right_stat_info = rel_expr.derive_cardinality_child(1)?;
@@ -649,7 +700,15 @@ impl Operator for Join {
         let mut children_required = vec![];
 
         let settings = ctx.get_settings();
-        if self.join_type != JoinType::Cross && !settings.get_enforce_broadcast_join()? {
+        let (enforce_broadcast, enforce_shuffle) = match self.distribution_hint {
+            Some(JoinDistribution::Broadcast) => (true, false),
+            Some(JoinDistribution::Shuffle) => (false, true),
+            None => (
+                settings.get_enforce_broadcast_join()?,
+                settings.get_enforce_shuffle_join()?,
+            ),
+        };
+        if self.join_type != JoinType::Cross && !enforce_broadcast {
             // (Hash, Hash)
             children_required.extend(self.equi_conditions.iter().map(|condition| {
                 vec![
@@ -671,7 +730,7 @@ impl Operator for Join {
                 | JoinType::RightSemi
                 | JoinType::LeftMark
                 | JoinType::RightSingle
-        ) && !settings.get_enforce_shuffle_join()?
+        ) && !enforce_shuffle
         {
             // (Any, Broadcast)
             let left_distribution = Distribution::Any;
@@ -825,6 +884,59 @@ fn evaluate_by_ndv(
     }
 }
 
+// Evaluate the cardinality of a multi-key join by the joint ndv of the key columns,
+// returns None if neither side has joint statistics of its keys.
+fn evaluate_by_multi_column_stats(
+    equi_conditions: &[JoinEquiCondition],
+    left_statistics: &Statistics,
+    right_statistics: &Statistics,
+    left_cardinality: f64,
+    right_cardinality: f64,
+) -> Option<f64> {
+    let (left_keys, right_keys): (HashSet<IndexType>, HashSet<IndexType>) = equi_conditions
+        .iter()
+        .filter_map(|condition| {
+            let left = condition.left.used_columns();
+            let right = condition.right.used_columns();
+            if left.len() != 1 || right.len() != 1 {
+                return None;
+            }
+            Some((*left.iter().next()?, *right.iter().next()?))
+        })
+        .unzip();
+    if left_keys.len() < 2 || right_keys.len() < 2 {
+        return None;
+    }
+
+    // Returns the ndv of the keys, and whether it comes from joint statistics.
+    let keys_ndv = |statistics: &Statistics, keys: &HashSet<IndexType>, cardinality: f64| {
+        let joint_ndv = statistics
+            .multi_column_stats
+            .iter()
+            .filter(|stat| stat.columns.iter().all(|column| keys.contains(column)))
+            .map(|stat| stat.ndv)
+            .reduce(f64::max);
+        let column_ndv = keys
+            .iter()
+            .filter_map(|key| statistics.column_stats.get(key))
+            .map(|stat| stat.ndv)
+            .fold(0.0, f64::max);
+        let ndv = joint_ndv.map_or(column_ndv, |ndv| ndv.max(column_ndv));
+        (ndv.min(cardinality), joint_ndv.is_some())
+    };
+    let (left_ndv, left_joint) = keys_ndv(left_statistics, &left_keys, left_cardinality);
+    let (right_ndv, right_joint) = keys_ndv(right_statistics, &right_keys, right_cardinality);
+    if !left_joint && !right_joint {
+        return None;
+    }
+
+    let max_ndv = f64::max(left_ndv, right_ndv);
+    if max_ndv == 0.0 {
+        return None;
+    }
+    Some(left_cardinality * right_cardinality / max_ndv)
+}
+
 fn update_statistic(
     left_statistics: &mut Statistics,
     right_statistics: &mut Statistics,
@@ -94,6 +94,7 @@ impl Operator for MutationSource {
             statistics: OpStatistics {
                 precise_cardinality: None,
                 column_stats: Default::default(),
+                multi_column_stats: Default::default(),
             },
         }))
     }
@@ -33,6 +33,7 @@ use crate::binder::ExplainConfig;
 use crate::optimizer::SExpr;
 use crate::plans::copy_into_location::CopyIntoLocationPlan;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AddTableConstraintPlan;
 use crate::plans::AddWarehouseClusterPlan;
 use crate::plans::AlterNetworkPolicyPlan;
 use crate::plans::AlterNotificationPlan;
@@ -45,6 +46,8 @@ use crate::plans::AlterViewPlan;
 use crate::plans::AlterVirtualColumnPlan;
 use crate::plans::AnalyzeTablePlan;
 use crate::plans::AssignWarehouseNodesPlan;
+use crate::plans::BackupDatabasePlan;
+use crate::plans::CacheWarmupTablePlan;
 use crate::plans::CallProcedurePlan;
 use crate::plans::CopyIntoTableMode;
 use crate::plans::CopyIntoTablePlan;
@@ -55,6 +58,7 @@ use crate::plans::CreateDatamaskPolicyPlan;
 use crate::plans::CreateDynamicTablePlan;
 use crate::plans::CreateFileFormatPlan;
 use crate::plans::CreateIndexPlan;
+use crate::plans::CreateMaterializedViewPlan;
 use crate::plans::CreateNetworkPolicyPlan;
 use crate::plans::CreateNotificationPlan;
 use crate::plans::CreatePasswordPolicyPlan;
@@ -87,6 +91,7 @@ use crate::plans::DropDatabasePlan;
 use crate::plans::DropDatamaskPolicyPlan;
 use crate::plans::DropFileFormatPlan;
 use crate::plans::DropIndexPlan;
+use crate::plans::DropMaterializedViewPlan;
 use crate::plans::DropNetworkPolicyPlan;
 use crate::plans::DropNotificationPlan;
 use crate::plans::DropPasswordPolicyPlan;
@@ -97,6 +102,7 @@ use crate::plans::DropStagePlan;
 use crate::plans::DropStreamPlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
+use crate::plans::DropTableConstraintPlan;
 use crate::plans::DropTableIndexPlan;
 use crate::plans::DropTablePlan;
 use crate::plans::DropTaskPlan;
@@ -120,8 +126,10 @@ use crate::plans::ModifyTableColumnPlan;
 use crate::plans::ModifyTableCommentPlan;
 use crate::plans::OptimizeCompactSegmentPlan;
 use crate::plans::OptimizePurgePlan;
+use crate::plans::PinPlanBaselinePlan;
 use crate::plans::PresignPlan;
 use crate::plans::RefreshIndexPlan;
+use crate::plans::RefreshMaterializedViewPlan;
 use crate::plans::RefreshTableIndexPlan;
 use crate::plans::RefreshVirtualColumnPlan;
 use crate::plans::RelOperator;
@@ -132,6 +140,7 @@ use crate::plans::RenameTablePlan;
 use crate::plans::RenameWarehouseClusterPlan;
 use crate::plans::RenameWarehousePlan;
 use crate::plans::Replace;
+use crate::plans::RestoreDatabasePlan;
 use crate::plans::ResumeWarehousePlan;
 use crate::plans::RevertTablePlan;
 use crate::plans::RevokePrivilegePlan;
@@ -155,6 +164,7 @@ use crate::plans::TruncateTablePlan;
 use crate::plans::UnassignWarehouseNodesPlan;
 use crate::plans::UndropDatabasePlan;
 use crate::plans::UndropTablePlan;
+use crate::plans::UnpinPlanBaselinePlan;
 use crate::plans::UnsetOptionsPlan;
 use crate::plans::UnsetPlan;
 use crate::plans::UseCatalogPlan;
@@ -163,6 +173,7 @@ use crate::plans::UseWarehousePlan;
 use crate::plans::VacuumDropTablePlan;
 use crate::plans::VacuumTablePlan;
 use crate::plans::VacuumTemporaryFilesPlan;
+use crate::plans::ValidateTableConstraintPlan;
 use crate::BindContext;
 use crate::MetadataRef;
 
@@ -227,6 +238,8 @@ pub enum Plan {
     DropDatabase(Box<DropDatabasePlan>),
     UndropDatabase(Box<UndropDatabasePlan>),
     RenameDatabase(Box<RenameDatabasePlan>),
+    BackupDatabase(Box<BackupDatabasePlan>),
+    RestoreDatabase(Box<RestoreDatabasePlan>),
     UseDatabase(Box<UseDatabasePlan>),
 
     // Tables
@@ -240,6 +253,9 @@ pub enum Plan {
     RenameTableColumn(Box<RenameTableColumnPlan>),
     AddTableColumn(Box<AddTableColumnPlan>),
     DropTableColumn(Box<DropTableColumnPlan>),
+    AddTableConstraint(Box<AddTableConstraintPlan>),
+    DropTableConstraint(Box<DropTableConstraintPlan>),
+    ValidateTableConstraint(Box<ValidateTableConstraintPlan>),
     ModifyTableColumn(Box<ModifyTableColumnPlan>),
     AlterTableClusterKey(Box<AlterTableClusterKeyPlan>),
     DropTableClusterKey(Box<DropTableClusterKeyPlan>),
@@ -250,6 +266,7 @@ pub enum Plan {
     },
     RevertTable(Box<RevertTablePlan>),
     TruncateTable(Box<TruncateTablePlan>),
+    CacheWarmupTable(Box<CacheWarmupTablePlan>),
     VacuumTable(Box<VacuumTablePlan>),
     VacuumDropTable(Box<VacuumDropTablePlan>),
     VacuumTemporaryFiles(Box<VacuumTemporaryFilesPlan>),
@@ -285,6 +302,15 @@ pub enum Plan {
     DropView(Box<DropViewPlan>),
     DescribeView(Box<DescribeViewPlan>),
 
+    // Materialized views
+    CreateMaterializedView(Box<CreateMaterializedViewPlan>),
+    DropMaterializedView(Box<DropMaterializedViewPlan>),
+    RefreshMaterializedView(Box<RefreshMaterializedViewPlan>),
+
+    // Plan baselines
+    PinPlanBaseline(Box<PinPlanBaselinePlan>),
+    UnpinPlanBaseline(Box<UnpinPlanBaselinePlan>),
+
     // Streams
     CreateStream(Box<CreateStreamPlan>),
     DropStream(Box<DropStreamPlan>),
@@ -383,6 +409,9 @@ pub enum Plan {
     Begin,
     Commit,
     Abort,
+    Savepoint(String),
+    RollbackToSavepoint(String),
+    ReleaseSavepoint(String),
 
     // Notifications
     CreateNotification(Box<CreateNotificationPlan>),
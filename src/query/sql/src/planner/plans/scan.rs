@@ -35,6 +35,7 @@ use crate::optimizer::ColumnSet;
 use crate::optimizer::ColumnStat;
 use crate::optimizer::ColumnStatSet;
 use crate::optimizer::Distribution;
+use crate::optimizer::MultiColumnStat;
 use crate::optimizer::PhysicalProperty;
 use crate::optimizer::RelExpr;
 use crate::optimizer::RelationalProperty;
@@ -42,7 +43,6 @@ use crate::optimizer::RequiredProperty;
 use crate::optimizer::SelectivityEstimator;
 use crate::optimizer::StatInfo;
 use crate::optimizer::Statistics as OpStatistics;
-use crate::optimizer::MAX_SELECTIVITY;
 use crate::plans::Operator;
 use crate::plans::RelOp;
 use crate::plans::ScalarExpr;
@@ -89,6 +89,7 @@ pub struct Statistics {
     // statistics will be ignored in comparison and hashing
     pub column_stats: HashMap<IndexType, Option<BasicColumnStatistics>>,
     pub histograms: HashMap<IndexType, Option<Histogram>>,
+    pub multi_column_stats: Vec<MultiColumnStat>,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -130,6 +131,14 @@ impl Scan {
             .map(|(col, hist)| (*col, hist.clone()))
             .collect();
 
+        let multi_column_stats = self
+            .statistics
+            .multi_column_stats
+            .iter()
+            .filter(|stat| stat.columns.iter().all(|col| columns.contains(col)))
+            .cloned()
+            .collect();
+
         Scan {
             table_index: self.table_index,
             columns,
@@ -140,6 +149,7 @@ impl Scan {
                 table_stats: self.statistics.table_stats,
                 column_stats,
                 histograms,
+                multi_column_stats,
             }),
             prewhere,
             agg_index: self.agg_index.clone(),
@@ -268,6 +278,18 @@ impl Operator for Scan {
             }
         }
 
+        let mut multi_column_stats: Vec<MultiColumnStat> = self
+            .statistics
+            .multi_column_stats
+            .iter()
+            .filter(|stat| {
+                stat.columns
+                    .iter()
+                    .all(|col| column_stats.contains_key(col))
+            })
+            .cloned()
+            .collect();
+
         let precise_cardinality = self
             .statistics
             .table_stats
@@ -279,6 +301,7 @@ impl Operator for Scan {
                 let mut statistics = OpStatistics {
                     precise_cardinality: Some(precise_cardinality),
                     column_stats,
+                    multi_column_stats,
                 };
                 // Derive cardinality
                 let mut sb = SelectivityEstimator::new(
@@ -286,14 +309,20 @@ impl Operator for Scan {
                     precise_cardinality as f64,
                     HashSet::new(),
                 );
-                let mut selectivity = MAX_SELECTIVITY;
-                for pred in prewhere.predicates.iter() {
+                let (mut selectivity, covered) =
+                    sb.compute_multi_column_selectivity(&prewhere.predicates);
+                for (position, pred) in prewhere.predicates.iter().enumerate() {
                     // Compute selectivity for each conjunction
-                    selectivity = selectivity.min(sb.compute_selectivity(pred, true)?);
+                    let pred_selectivity = sb.compute_selectivity(pred, true)?;
+                    // The predicates covered by joint statistics only update the column statistics.
+                    if !covered.contains(&position) {
+                        selectivity = selectivity.min(pred_selectivity);
+                    }
                 }
                 // Update other columns's statistic according to selectivity.
                 sb.update_other_statistic_by_selectivity(selectivity);
                 column_stats = statistics.column_stats;
+                multi_column_stats = statistics.multi_column_stats;
                 (precise_cardinality as f64) * selectivity
             }
             (Some(precise_cardinality), None) => precise_cardinality as f64,
@@ -311,6 +340,7 @@ impl Operator for Scan {
             statistics: OpStatistics {
                 precise_cardinality,
                 column_stats,
+                multi_column_stats,
             },
         }))
     }
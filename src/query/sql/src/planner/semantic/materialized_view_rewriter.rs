@@ -0,0 +1,186 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::FunctionCall;
+use databend_common_ast::ast::GroupBy;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::JoinCondition;
+use databend_common_ast::ast::JoinOperator;
+use databend_common_ast::ast::Query;
+use databend_common_ast::ast::SelectStmt;
+use databend_common_ast::ast::SetExpr;
+use databend_common_ast::ast::TableReference;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::FunctionKind;
+use databend_common_functions::aggregates::AggregateFunctionFactory;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use derive_visitor::VisitorMut;
+
+use crate::normalize_identifier;
+use crate::NameResolutionContext;
+
+/// Checks the query of a materialized view and qualifies its tables.
+///
+/// The query must be a single SELECT over tables combined with inner or cross joins, optionally
+/// with a filter and a GROUP BY, so that the optimizer can match it against other queries. The
+/// tables without database are qualified by the current database, so that the query can be
+/// bound again regardless of the current database.
+#[derive(Debug, Clone, VisitorMut)]
+#[visitor(
+    Query(enter),
+    SetExpr(enter),
+    SelectStmt(enter),
+    TableReference(enter),
+    FunctionCall(enter),
+    Expr(enter)
+)]
+pub struct MaterializedViewRewriter {
+    database: Identifier,
+    num_queries: usize,
+    not_support: Option<String>,
+    /// The `(database, table)` of the source tables of the view.
+    pub tables: Vec<(String, String)>,
+}
+
+impl MaterializedViewRewriter {
+    pub fn new(database: Identifier) -> Self {
+        MaterializedViewRewriter {
+            database,
+            num_queries: 0,
+            not_support: None,
+            tables: vec![],
+        }
+    }
+
+    /// Returns an error if the query is not supported by materialized views.
+    pub fn check(&self) -> Result<()> {
+        match &self.not_support {
+            Some(reason) => Err(ErrorCode::IllegalMaterializedView(format!(
+                "Materialized view only supports filters, inner joins and GROUP BY over tables, but the query has {reason}"
+            ))),
+            None if self.tables.is_empty() => Err(ErrorCode::IllegalMaterializedView(
+                "Materialized view must select from at least one table",
+            )),
+            None => Ok(()),
+        }
+    }
+
+    fn set_not_support(&mut self, reason: &str) {
+        if self.not_support.is_none() {
+            self.not_support = Some(reason.to_string());
+        }
+    }
+
+    fn enter_query(&mut self, query: &mut Query) {
+        self.num_queries += 1;
+        if self.num_queries > 1 {
+            self.set_not_support("subqueries");
+        } else if query.with.is_some() {
+            self.set_not_support("a WITH clause");
+        } else if !query.order_by.is_empty() || !query.limit.is_empty() || query.offset.is_some() {
+            self.set_not_support("ORDER BY or LIMIT");
+        }
+    }
+
+    fn enter_set_expr(&mut self, set_expr: &mut SetExpr) {
+        match set_expr {
+            SetExpr::SetOperation(_) => self.set_not_support("set operations"),
+            SetExpr::Values { .. } => self.set_not_support("a VALUES clause"),
+            SetExpr::Select(_) | SetExpr::Query(_) => {}
+        }
+    }
+
+    fn enter_select_stmt(&mut self, stmt: &mut SelectStmt) {
+        if stmt.distinct || stmt.top_n.is_some() {
+            self.set_not_support("DISTINCT or TOP");
+        }
+        if !matches!(stmt.group_by, None | Some(GroupBy::Normal(_))) {
+            self.set_not_support("GROUPING SETS, CUBE, ROLLUP or GROUP BY ALL");
+        }
+        if stmt.having.is_some() {
+            self.set_not_support("HAVING");
+        }
+        if stmt.window_list.is_some() || stmt.qualify.is_some() {
+            self.set_not_support("WINDOW or QUALIFY");
+        }
+    }
+
+    fn enter_table_reference(&mut self, table_ref: &mut TableReference) {
+        match table_ref {
+            TableReference::Table {
+                database,
+                table,
+                temporal,
+                with_options,
+                pivot,
+                unpivot,
+                sample,
+                ..
+            } => {
+                if temporal.is_some()
+                    || with_options.is_some()
+                    || pivot.is_some()
+                    || unpivot.is_some()
+                    || sample.is_some()
+                {
+                    self.set_not_support("time travel, options, pivot or sample on the table");
+                    return;
+                }
+
+                let name_resolution_ctx = NameResolutionContext::default();
+                let database = database.get_or_insert_with(|| self.database.clone());
+                let database = normalize_identifier(database, &name_resolution_ctx).name;
+                let table = normalize_identifier(table, &name_resolution_ctx).name;
+                self.tables.push((database, table));
+            }
+            TableReference::Join { join, .. } => {
+                if !matches!(join.op, JoinOperator::Inner | JoinOperator::CrossJoin)
+                    || !matches!(join.condition, JoinCondition::On(_) | JoinCondition::None)
+                {
+                    self.set_not_support("joins other than inner joins with ON conditions");
+                }
+            }
+            _ => self.set_not_support("subqueries or table functions in FROM"),
+        }
+    }
+
+    fn enter_function_call(&mut self, func: &mut FunctionCall) {
+        let name = func.name.name.to_lowercase();
+        if func.window.is_some() {
+            self.set_not_support("window functions");
+        } else if AggregateFunctionFactory::instance().contains(&name) {
+            if func.distinct {
+                self.set_not_support("DISTINCT aggregate functions");
+            }
+        } else if let Some(property) = BUILTIN_FUNCTIONS.get_property(&name) {
+            if property.kind == FunctionKind::SRF {
+                self.set_not_support("set-returning functions");
+            } else if property.non_deterministic {
+                self.set_not_support("non-deterministic functions");
+            }
+        } else {
+            self.set_not_support("user-defined or unknown functions");
+        }
+    }
+
+    fn enter_expr(&mut self, expr: &mut Expr) {
+        if matches!(expr, Expr::CountAll {
+            window: Some(_),
+            ..
+        }) {
+            self.set_not_support("window functions");
+        }
+    }
+}
@@ -19,10 +19,12 @@ mod count_set_ops;
 mod distinct_to_groupby;
 mod grouping_check;
 mod lowering;
+mod materialized_view_rewriter;
 mod name_resolution;
 mod type_check;
 mod udf_rewriter;
 mod view_rewriter;
+mod view_stream_rewriter;
 mod virtual_column_rewriter;
 mod window_check;
 
@@ -35,6 +37,7 @@ pub use count_set_ops::CountSetOps;
 pub use distinct_to_groupby::DistinctToGroupBy;
 pub use grouping_check::GroupingChecker;
 pub use lowering::*;
+pub use materialized_view_rewriter::MaterializedViewRewriter;
 pub use name_resolution::compare_table_name;
 pub use name_resolution::normalize_identifier;
 pub use name_resolution::IdentifierNormalizer;
@@ -48,6 +51,7 @@ pub use type_check::validate_function_arg;
 pub use type_check::TypeChecker;
 pub(crate) use udf_rewriter::UdfRewriter;
 pub use view_rewriter::ViewRewriter;
+pub use view_stream_rewriter::ViewStreamRewriter;
 pub(crate) use virtual_column_rewriter::VirtualColumnRewriter;
 pub use window_check::WindowChecker;
 
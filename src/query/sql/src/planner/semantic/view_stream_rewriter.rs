@@ -0,0 +1,219 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::ast::ChangesInterval;
+use databend_common_ast::ast::ColumnID;
+use databend_common_ast::ast::ColumnRef;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Query;
+use databend_common_ast::ast::SelectStmt;
+use databend_common_ast::ast::SelectTarget;
+use databend_common_ast::ast::SetExpr;
+use databend_common_ast::ast::SetOperator;
+use databend_common_ast::ast::TableReference;
+use databend_common_ast::ast::TemporalClause;
+use databend_common_ast::ast::TimeTravelPoint;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::FunctionKind;
+use databend_common_expression::CHANGE_ACTION_COL_NAME;
+use databend_common_expression::CHANGE_IS_UPDATE_COL_NAME;
+use databend_common_expression::CHANGE_ROW_ID_COL_NAME;
+use databend_common_functions::aggregates::AggregateFunctionFactory;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use derive_visitor::VisitorMut;
+
+use crate::normalize_identifier;
+use crate::NameResolutionContext;
+
+/// Rewrites the query of a view into the changes of the view since the offsets of a stream on it.
+///
+/// Only simple views are supported, i.e. projections and filters over tables combined with
+/// `UNION ALL`. Each table is replaced by its changes `AT (STREAM => <stream>)`, and the change
+/// columns are added to the projections. The tables without database are qualified by the
+/// database of the view, so that the changes query doesn't depend on the current database.
+#[derive(Debug, Clone, VisitorMut)]
+#[visitor(
+    Query(enter),
+    SetExpr(enter),
+    SelectStmt(enter),
+    TableReference(enter),
+    Expr(enter)
+)]
+pub struct ViewStreamRewriter {
+    stream: TimeTravelPoint,
+    view_database: Identifier,
+    append_only: bool,
+    num_queries: usize,
+    not_support: Option<String>,
+    /// The `(database, table)` of the tables underlying the view.
+    pub tables: Vec<(String, String)>,
+}
+
+impl ViewStreamRewriter {
+    pub fn new(
+        catalog: Identifier,
+        database: Identifier,
+        stream: Identifier,
+        view_database: Identifier,
+        append_only: bool,
+    ) -> Self {
+        ViewStreamRewriter {
+            stream: TimeTravelPoint::Stream {
+                catalog: Some(catalog),
+                database: Some(database),
+                name: stream,
+            },
+            view_database,
+            append_only,
+            num_queries: 0,
+            not_support: None,
+            tables: vec![],
+        }
+    }
+
+    /// Returns an error if the view query is not a simple view.
+    pub fn check(&self) -> Result<()> {
+        match &self.not_support {
+            Some(reason) => Err(ErrorCode::IllegalStream(format!(
+                "Stream on view only supports projections and filters over tables combined with UNION ALL, but the view has {reason}"
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    fn set_not_support(&mut self, reason: &str) {
+        if self.not_support.is_none() {
+            self.not_support = Some(reason.to_string());
+        }
+    }
+
+    fn enter_query(&mut self, query: &mut Query) {
+        self.num_queries += 1;
+        if self.num_queries > 1 {
+            self.set_not_support("subqueries");
+        } else if query.with.is_some() {
+            self.set_not_support("a WITH clause");
+        } else if !query.order_by.is_empty() || !query.limit.is_empty() || query.offset.is_some() {
+            self.set_not_support("ORDER BY or LIMIT");
+        }
+    }
+
+    fn enter_set_expr(&mut self, set_expr: &mut SetExpr) {
+        match set_expr {
+            SetExpr::SetOperation(set_operation) => {
+                if !matches!(set_operation.op, SetOperator::Union) || !set_operation.all {
+                    self.set_not_support("set operations other than UNION ALL");
+                }
+            }
+            SetExpr::Values { .. } => self.set_not_support("a VALUES clause"),
+            SetExpr::Select(_) | SetExpr::Query(_) => {}
+        }
+    }
+
+    fn enter_select_stmt(&mut self, stmt: &mut SelectStmt) {
+        if stmt.distinct || stmt.top_n.is_some() {
+            self.set_not_support("DISTINCT or TOP");
+        }
+        if stmt.group_by.is_some() || stmt.having.is_some() {
+            self.set_not_support("GROUP BY or HAVING");
+        }
+        if stmt.window_list.is_some() || stmt.qualify.is_some() {
+            self.set_not_support("WINDOW or QUALIFY");
+        }
+        if stmt.from.len() != 1 {
+            self.set_not_support("a SELECT without exactly one table");
+        }
+
+        // The change columns are already included by the star.
+        if !stmt.select_list.iter().any(|target| target.is_star()) {
+            for name in [
+                CHANGE_ACTION_COL_NAME,
+                CHANGE_IS_UPDATE_COL_NAME,
+                CHANGE_ROW_ID_COL_NAME,
+            ] {
+                stmt.select_list.push(SelectTarget::AliasedExpr {
+                    expr: Box::new(Expr::ColumnRef {
+                        span: None,
+                        column: ColumnRef {
+                            database: None,
+                            table: None,
+                            column: ColumnID::Name(Identifier::from_name(None, name)),
+                        },
+                    }),
+                    alias: None,
+                });
+            }
+        }
+    }
+
+    fn enter_table_reference(&mut self, table_ref: &mut TableReference) {
+        let TableReference::Table {
+            database,
+            table,
+            temporal,
+            with_options,
+            pivot,
+            unpivot,
+            sample,
+            ..
+        } = table_ref
+        else {
+            self.set_not_support("joins, subqueries or table functions in FROM");
+            return;
+        };
+        if temporal.is_some()
+            || with_options.is_some()
+            || pivot.is_some()
+            || unpivot.is_some()
+            || sample.is_some()
+        {
+            self.set_not_support("time travel, options, pivot or sample on the table");
+            return;
+        }
+
+        let name_resolution_ctx = NameResolutionContext::default();
+        let database = database.get_or_insert_with(|| self.view_database.clone());
+        let database = normalize_identifier(database, &name_resolution_ctx).name;
+        let table = normalize_identifier(table, &name_resolution_ctx).name;
+        self.tables.push((database, table));
+
+        *temporal = Some(TemporalClause::Changes(ChangesInterval {
+            append_only: self.append_only,
+            at_point: self.stream.clone(),
+            end_point: None,
+        }));
+    }
+
+    fn enter_expr(&mut self, expr: &mut Expr) {
+        let func = match expr {
+            Expr::CountAll { .. } => {
+                self.set_not_support("aggregate or window functions");
+                return;
+            }
+            Expr::FunctionCall { func, .. } => func,
+            _ => return,
+        };
+        let name = func.name.name.to_lowercase();
+        if AggregateFunctionFactory::instance().contains(&name) || func.window.is_some() {
+            self.set_not_support("aggregate or window functions");
+        } else if BUILTIN_FUNCTIONS
+            .get_property(&name)
+            .is_some_and(|property| property.kind == FunctionKind::SRF)
+        {
+            self.set_not_support("set-returning functions");
+        }
+    }
+}
@@ -12,11 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 use std::sync::Arc;
 
 use databend_common_base::base::GlobalInstance;
+use databend_common_cache::CachePolicy;
 use databend_common_config::CacheConfig;
+use databend_common_config::CachePolicyConfig;
 use databend_common_config::CacheStorageTypeInnerConfig;
 use databend_common_config::DiskCacheKeyReloadPolicy;
 use databend_common_exception::ErrorCode;
@@ -66,6 +69,53 @@ impl<T: Clone> CacheSlot<T> {
     }
 }
 
+/// The eviction policies of the caches, by cache name.
+struct CachePolicies {
+    default: CachePolicy,
+    overrides: BTreeMap<String, CachePolicy>,
+    /// The share of the capacity of each cache the pinned items can take, in percent.
+    pin_quota_ratio: usize,
+}
+
+impl CachePolicies {
+    fn try_create(config: &CacheConfig) -> Result<Self> {
+        let mut overrides = BTreeMap::new();
+        for (name, policy) in &config.policies {
+            if !CACHE_NAMES.contains(&name.as_str()) {
+                return Err(ErrorCode::InvalidConfig(format!(
+                    "cannot set the eviction policy of unknown cache '{}', expecting one of {}",
+                    name,
+                    CACHE_NAMES.join(", ")
+                )));
+            }
+            overrides.insert(name.clone(), Self::to_cache_policy(policy));
+        }
+        if config.pin_quota_ratio > 100 {
+            return Err(ErrorCode::InvalidConfig(format!(
+                "cache pin_quota_ratio must be within [0, 100], got {}",
+                config.pin_quota_ratio
+            )));
+        }
+        Ok(CachePolicies {
+            default: Self::to_cache_policy(&config.policy),
+            overrides,
+            pin_quota_ratio: config.pin_quota_ratio as usize,
+        })
+    }
+
+    fn to_cache_policy(policy: &CachePolicyConfig) -> CachePolicy {
+        match policy {
+            CachePolicyConfig::Lru => CachePolicy::Lru,
+            CachePolicyConfig::S3Fifo => CachePolicy::S3Fifo,
+            CachePolicyConfig::TinyLfu => CachePolicy::TinyLfu,
+        }
+    }
+
+    fn of(&self, name: &str) -> CachePolicy {
+        self.overrides.get(name).copied().unwrap_or(self.default)
+    }
+}
+
 /// Where all the caches reside
 pub struct CacheManager {
     table_snapshot_cache: CacheSlot<TableSnapshotCache>,
@@ -81,6 +131,7 @@ pub struct CacheManager {
     in_memory_table_data_cache: CacheSlot<ColumnArrayCache>,
     segment_block_metas_cache: CacheSlot<SegmentBlockMetasCache>,
     block_meta_cache: CacheSlot<BlockMetaCache>,
+    policies: CachePolicies,
 }
 
 impl CacheManager {
@@ -90,6 +141,8 @@ impl CacheManager {
         max_server_memory_usage: &u64,
         tenant_id: impl Into<String>,
     ) -> Result<()> {
+        let policies = CachePolicies::try_create(config)?;
+
         // setup table data cache
         let table_data_cache = {
             match config.data_cache_storage {
@@ -121,6 +174,7 @@ impl CacheManager {
                         config.disk_cache_config.max_bytes as usize,
                         config.data_cache_key_reload_policy.clone(),
                         config.disk_cache_config.sync_data,
+                        &policies,
                     )?
                 }
             }
@@ -137,7 +191,7 @@ impl CacheManager {
 
         // Cache of deserialized table data
         let in_memory_table_data_cache =
-            Self::new_bytes_cache_slot(MEMORY_CACHE_TABLE_DATA, memory_cache_capacity);
+            Self::new_bytes_cache_slot(&policies, MEMORY_CACHE_TABLE_DATA, memory_cache_capacity);
 
         // setup in-memory table meta cache
         if !config.enable_table_meta_cache {
@@ -155,29 +209,36 @@ impl CacheManager {
                 in_memory_table_data_cache,
                 segment_block_metas_cache: CacheSlot::new(None),
                 block_meta_cache: CacheSlot::new(None),
+                policies,
             }));
         } else {
             let table_snapshot_cache = Self::new_items_cache_slot(
+                &policies,
                 MEMORY_CACHE_TABLE_SNAPSHOT,
                 config.table_meta_snapshot_count as usize,
             );
             let table_statistic_cache = Self::new_items_cache_slot(
+                &policies,
                 MEMORY_CACHE_TABLE_STATISTICS,
                 config.table_meta_statistic_count as usize,
             );
             let compact_segment_info_cache = Self::new_bytes_cache_slot(
+                &policies,
                 MEMORY_CACHE_COMPACT_SEGMENT_INFO,
                 config.table_meta_segment_bytes as usize,
             );
             let bloom_index_filter_cache = Self::new_bytes_cache_slot(
+                &policies,
                 MEMORY_CACHE_BLOOM_INDEX_FILTER,
                 config.table_bloom_index_filter_size as usize,
             );
             let bloom_index_meta_cache = Self::new_items_cache_slot(
+                &policies,
                 MEMORY_CACHE_BLOOM_INDEX_FILE_META_DATA,
                 config.table_bloom_index_meta_count as usize,
             );
             let inverted_index_meta_cache = Self::new_items_cache_slot(
+                &policies,
                 MEMORY_CACHE_INVERTED_INDEX_FILE_META_DATA,
                 config.inverted_index_meta_count as usize,
             );
@@ -191,25 +252,30 @@ impl CacheManager {
                 config.inverted_index_filter_size as usize
             };
             let inverted_index_file_cache = Self::new_bytes_cache_slot(
+                &policies,
                 MEMORY_CACHE_INVERTED_INDEX_FILE,
                 inverted_index_file_size,
             );
             let prune_partitions_cache = Self::new_items_cache_slot(
+                &policies,
                 MEMORY_CACHE_PRUNE_PARTITIONS,
                 config.table_prune_partitions_count as usize,
             );
 
             let parquet_meta_data_cache = Self::new_items_cache_slot(
+                &policies,
                 MEMORY_CACHE_PARQUET_META_DATA,
                 DEFAULT_PARQUET_META_DATA_CACHE_ITEMS,
             );
 
             let segment_block_metas_cache = Self::new_items_cache_slot(
+                &policies,
                 MEMORY_CACHE_SEGMENT_BLOCK_METAS,
                 config.block_meta_count as usize,
             );
 
             let block_meta_cache = Self::new_items_cache_slot(
+                &policies,
                 MEMORY_CACHE_BLOCK_META,
                 // TODO replace this config
                 config.block_meta_count as usize,
@@ -229,6 +295,7 @@ impl CacheManager {
                 segment_block_metas_cache,
                 parquet_meta_data_cache,
                 block_meta_cache,
+                policies,
             }));
         }
 
@@ -247,44 +314,44 @@ impl CacheManager {
         match name {
             MEMORY_CACHE_TABLE_DATA => {
                 let cache = &self.in_memory_table_data_cache;
-                Self::set_bytes_capacity(cache, new_capacity, name);
+                self.set_bytes_capacity(cache, new_capacity, name);
             }
             MEMORY_CACHE_PARQUET_META_DATA => {
                 let cache = &self.parquet_meta_data_cache;
-                Self::set_items_capacity(cache, new_capacity, name)
+                self.set_items_capacity(cache, new_capacity, name)
             }
             MEMORY_CACHE_PRUNE_PARTITIONS => {
                 let cache = &self.prune_partitions_cache;
-                Self::set_items_capacity(cache, new_capacity, name)
+                self.set_items_capacity(cache, new_capacity, name)
             }
             MEMORY_CACHE_INVERTED_INDEX_FILE => {
                 let cache = &self.inverted_index_file_cache;
-                Self::set_bytes_capacity(cache, new_capacity, name);
+                self.set_bytes_capacity(cache, new_capacity, name);
             }
             MEMORY_CACHE_INVERTED_INDEX_FILE_META_DATA => {
                 let cache = &self.inverted_index_meta_cache;
-                Self::set_items_capacity(cache, new_capacity, name);
+                self.set_items_capacity(cache, new_capacity, name);
             }
             MEMORY_CACHE_BLOOM_INDEX_FILE_META_DATA => {
-                Self::set_items_capacity(&self.bloom_index_meta_cache, new_capacity, name);
+                self.set_items_capacity(&self.bloom_index_meta_cache, new_capacity, name);
             }
             MEMORY_CACHE_BLOOM_INDEX_FILTER => {
-                Self::set_bytes_capacity(&self.bloom_index_filter_cache, new_capacity, name);
+                self.set_bytes_capacity(&self.bloom_index_filter_cache, new_capacity, name);
             }
             MEMORY_CACHE_COMPACT_SEGMENT_INFO => {
-                Self::set_bytes_capacity(&self.compact_segment_info_cache, new_capacity, name);
+                self.set_bytes_capacity(&self.compact_segment_info_cache, new_capacity, name);
             }
             MEMORY_CACHE_TABLE_STATISTICS => {
-                Self::set_items_capacity(&self.table_statistic_cache, new_capacity, name);
+                self.set_items_capacity(&self.table_statistic_cache, new_capacity, name);
             }
             MEMORY_CACHE_TABLE_SNAPSHOT => {
-                Self::set_items_capacity(&self.table_snapshot_cache, new_capacity, name);
+                self.set_items_capacity(&self.table_snapshot_cache, new_capacity, name);
             }
             MEMORY_CACHE_SEGMENT_BLOCK_METAS => {
-                Self::set_items_capacity(&self.segment_block_metas_cache, new_capacity, name);
+                self.set_items_capacity(&self.segment_block_metas_cache, new_capacity, name);
             }
             MEMORY_CACHE_BLOCK_META => {
-                Self::set_items_capacity(&self.block_meta_cache, new_capacity, name);
+                self.set_items_capacity(&self.block_meta_cache, new_capacity, name);
             }
 
             crate::DISK_TABLE_DATA_CACHE_NAME => {
@@ -299,6 +366,7 @@ impl CacheManager {
     }
 
     fn set_bytes_capacity<T: Into<CacheValue<T>>>(
+        &self,
         cache: &CacheSlot<InMemoryLruCache<T>>,
         new_capacity: u64,
         name: impl Into<String>,
@@ -306,12 +374,13 @@ impl CacheManager {
         if let Some(v) = cache.get() {
             v.set_bytes_capacity(new_capacity as usize);
         } else {
-            let new_cache = Self::new_bytes_cache(name, new_capacity as usize);
+            let new_cache = Self::new_bytes_cache(&self.policies, name, new_capacity as usize);
             cache.set(new_cache)
         }
     }
 
     fn set_items_capacity<T: Into<CacheValue<T>>>(
+        &self,
         cache: &CacheSlot<InMemoryLruCache<T>>,
         new_capacity: u64,
         name: impl Into<String>,
@@ -319,7 +388,7 @@ impl CacheManager {
         if let Some(v) = cache.get() {
             v.set_items_capacity(new_capacity as usize);
         } else {
-            let new_cache = Self::new_items_cache(name, new_capacity as usize);
+            let new_cache = Self::new_items_cache(&self.policies, name, new_capacity as usize);
             cache.set(new_cache)
         }
     }
@@ -372,40 +441,73 @@ impl CacheManager {
         self.in_memory_table_data_cache.get()
     }
 
+    /// Unpins the cached items whose keys start with `prefix`, returns the number of them.
+    pub fn unpin_by_prefix(&self, prefix: &str) -> usize {
+        let mut num_unpinned = 0;
+        if let Some(cache) = self.compact_segment_info_cache.get() {
+            num_unpinned += cache.unpin_prefix(prefix);
+        }
+        if let Some(cache) = self.bloom_index_meta_cache.get() {
+            num_unpinned += cache.unpin_prefix(prefix);
+        }
+        if let Some(cache) = self.bloom_index_filter_cache.get() {
+            num_unpinned += cache.unpin_prefix(prefix);
+        }
+        if let Some(cache) = self.table_data_cache.get() {
+            num_unpinned += cache.unpin_prefix(prefix);
+        }
+        num_unpinned
+    }
+
     fn new_items_cache_slot<V: Into<CacheValue<V>>>(
+        policies: &CachePolicies,
         name: impl Into<String>,
         capacity: usize,
     ) -> CacheSlot<InMemoryLruCache<V>> {
-        CacheSlot::new(Self::new_items_cache(name, capacity))
+        CacheSlot::new(Self::new_items_cache(policies, name, capacity))
     }
 
     fn new_items_cache<V: Into<CacheValue<V>>>(
+        policies: &CachePolicies,
         name: impl Into<String>,
         capacity: usize,
     ) -> Option<InMemoryLruCache<V>> {
+        let name = name.into();
+        let policy = policies.of(&name);
         match capacity {
             0 => None,
-            _ => Some(InMemoryLruCache::with_items_capacity(name.into(), capacity)),
+            _ => {
+                let cache =
+                    InMemoryLruCache::with_items_capacity_and_policy(name, capacity, policy);
+                cache.set_pin_quota_ratio(policies.pin_quota_ratio);
+                Some(cache)
+            }
         }
     }
 
     fn new_bytes_cache_slot<V: Into<CacheValue<V>>>(
+        policies: &CachePolicies,
         name: impl Into<String>,
         bytes_capacity: usize,
     ) -> CacheSlot<InMemoryLruCache<V>> {
-        CacheSlot::new(Self::new_bytes_cache(name, bytes_capacity))
+        CacheSlot::new(Self::new_bytes_cache(policies, name, bytes_capacity))
     }
 
     fn new_bytes_cache<V: Into<CacheValue<V>>>(
+        policies: &CachePolicies,
         name: impl Into<String>,
         bytes_capacity: usize,
     ) -> Option<InMemoryLruCache<V>> {
+        let name = name.into();
+        let policy = policies.of(&name);
         match bytes_capacity {
             0 => None,
-            _ => Some(InMemoryLruCache::with_bytes_capacity(
-                name.into(),
-                bytes_capacity,
-            )),
+            _ => {
+                let cache =
+                    InMemoryLruCache::with_bytes_capacity_and_policy(name, bytes_capacity, policy);
+                cache.set_pin_quota_ratio(policies.pin_quota_ratio);
+                Some(cache)
+            }
         }
     }
 
@@ -415,6 +517,7 @@ impl CacheManager {
         disk_cache_bytes_size: usize,
         disk_cache_key_reload_policy: DiskCacheKeyReloadPolicy,
         sync_data: bool,
+        policies: &CachePolicies,
     ) -> Result<CacheSlot<TableDataCache>> {
         if disk_cache_bytes_size > 0 {
             let cache_holder = TableDataCacheBuilder::new_table_data_disk_cache(
@@ -423,7 +526,9 @@ impl CacheManager {
                 disk_cache_bytes_size,
                 disk_cache_key_reload_policy,
                 sync_data,
+                policies.of(crate::DISK_TABLE_DATA_CACHE_NAME),
             )?;
+            cache_holder.set_pin_quota_ratio(policies.pin_quota_ratio);
             Ok(CacheSlot::new(Some(cache_holder)))
         } else {
             Ok(CacheSlot::new(None))
@@ -446,3 +551,19 @@ const MEMORY_CACHE_TABLE_SNAPSHOT: &str = "memory_cache_table_snapshot";
 const MEMORY_CACHE_SEGMENT_BLOCK_METAS: &str = "memory_cache_segment_block_metas";
 
 const MEMORY_CACHE_BLOCK_META: &str = "memory_cache_block_meta";
+
+const CACHE_NAMES: &[&str] = &[
+    MEMORY_CACHE_TABLE_DATA,
+    MEMORY_CACHE_PARQUET_META_DATA,
+    MEMORY_CACHE_PRUNE_PARTITIONS,
+    MEMORY_CACHE_INVERTED_INDEX_FILE,
+    MEMORY_CACHE_INVERTED_INDEX_FILE_META_DATA,
+    MEMORY_CACHE_BLOOM_INDEX_FILE_META_DATA,
+    MEMORY_CACHE_BLOOM_INDEX_FILTER,
+    MEMORY_CACHE_COMPACT_SEGMENT_INFO,
+    MEMORY_CACHE_TABLE_STATISTICS,
+    MEMORY_CACHE_TABLE_SNAPSHOT,
+    MEMORY_CACHE_SEGMENT_BLOCK_METAS,
+    MEMORY_CACHE_BLOCK_META,
+    crate::DISK_TABLE_DATA_CACHE_NAME,
+];
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeSet;
 use std::fs;
 use std::fs::File;
 use std::io::IoSlice;
@@ -23,8 +24,10 @@ use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::time::Instant;
 
+use databend_common_cache::AdmissionStats;
 use databend_common_cache::Cache;
-use databend_common_cache::LruCache;
+use databend_common_cache::CachePolicy;
+use databend_common_cache::PolicyCache;
 use databend_common_config::DiskCacheKeyReloadPolicy;
 use databend_common_exception::Result;
 use log::error;
@@ -38,9 +41,11 @@ use crate::CacheValue;
 use crate::DiskCacheKey;
 
 pub struct DiskCache {
-    cache: LruCache<String, CacheValue<FileSize>>,
+    cache: PolicyCache<String, CacheValue<FileSize>>,
     root: PathBuf,
     sync_data: bool,
+    /// The keys of the pinned files, before being hashed into cache keys.
+    pinned_keys: BTreeSet<String>,
 }
 
 impl DiskCache {
@@ -59,13 +64,34 @@ impl DiskCache {
         disk_cache_key_reload_policy: DiskCacheKeyReloadPolicy,
         sync_data: bool,
     ) -> self::io_result::Result<Self>
+    where
+        PathBuf: From<T>,
+    {
+        Self::new_with_policy(
+            path,
+            size,
+            disk_cache_key_reload_policy,
+            sync_data,
+            CachePolicy::Lru,
+        )
+    }
+
+    /// Create an `DiskCache` like [`DiskCache::new`], evicting files by the given policy.
+    pub fn new_with_policy<T>(
+        path: T,
+        size: usize,
+        disk_cache_key_reload_policy: DiskCacheKeyReloadPolicy,
+        sync_data: bool,
+        policy: CachePolicy,
+    ) -> self::io_result::Result<Self>
     where
         PathBuf: From<T>,
     {
         DiskCache {
-            cache: LruCache::<String, CacheValue<FileSize>>::with_bytes_capacity(size),
+            cache: PolicyCache::with_bytes_capacity(policy, size),
             root: PathBuf::from(path),
             sync_data,
+            pinned_keys: BTreeSet::new(),
         }
         .init(disk_cache_key_reload_policy)
     }
@@ -97,6 +123,60 @@ impl DiskCache {
         self.cache.bytes_capacity()
     }
 
+    pub fn policy(&self) -> CachePolicy {
+        self.cache.policy()
+    }
+
+    pub fn admission_stats(&self) -> AdmissionStats {
+        self.cache.admission_stats()
+    }
+
+    /// Sets the share of the capacity the pinned files can take, in percent.
+    pub fn set_pin_quota_ratio(&mut self, ratio: usize) {
+        self.cache.set_pin_quota_ratio(ratio);
+    }
+
+    /// Exempts the file of `key` from eviction, returns false if it is not cached or the pin
+    /// quota is used up.
+    pub fn pin(&mut self, key: &str) -> bool {
+        let cache_key = self.cache_key(key);
+        let pinned = self.cache.pin(cache_key.0);
+        if pinned {
+            self.pinned_keys.insert(key.to_owned());
+        }
+        pinned
+    }
+
+    /// Unpins the files whose keys start with `prefix`, returns the number of them.
+    pub fn unpin_prefix(&mut self, prefix: &str) -> usize {
+        let keys = self
+            .pinned_keys
+            .range(prefix.to_owned()..)
+            .take_while(|key| key.starts_with(prefix))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut num_unpinned = 0;
+        for key in keys {
+            self.pinned_keys.remove(&key);
+            let cache_key = self.cache_key(&key);
+            if self.cache.unpin(&cache_key.0) {
+                num_unpinned += 1;
+            }
+        }
+        num_unpinned
+    }
+
+    /// Return the count of the pinned files.
+    pub fn pinned_len(&self) -> usize {
+        self.cache.pinned_len()
+    }
+
+    /// Return the size of the pinned files.
+    pub fn pinned_size(&self) -> u64 {
+        self.cache.pinned_bytes_size()
+    }
+
     /// Return the path in which the cache is stored.
     pub fn path(&self) -> &Path {
         self.root.as_path()
@@ -291,6 +371,9 @@ impl DiskCache {
                         cached_item_path, e
                     )
                 });
+            } else {
+                // the rest of the cache is pinned
+                return Err(Error::FileTooLarge);
             }
         }
 
@@ -331,6 +414,7 @@ impl DiskCache {
 
     /// Remove the given key from the cache.
     pub fn remove(&mut self, key: &str) -> Result<()> {
+        self.pinned_keys.remove(key);
         let cache_key = self.cache_key(key);
         match self.cache.pop(&cache_key.0) {
             Some(_) => {
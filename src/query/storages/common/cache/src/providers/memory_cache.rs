@@ -14,8 +14,10 @@
 
 use std::sync::Arc;
 
+use databend_common_cache::AdmissionStats;
 use databend_common_cache::Cache;
-use databend_common_cache::LruCache;
+use databend_common_cache::CachePolicy;
+use databend_common_cache::PolicyCache;
 use parking_lot::RwLock;
 
 use crate::caches::CacheValue;
@@ -24,7 +26,7 @@ use crate::Unit;
 pub struct InMemoryLruCache<V: Into<CacheValue<V>>> {
     unit: Unit,
     name: String,
-    inner: Arc<RwLock<LruCache<String, CacheValue<V>>>>,
+    inner: Arc<RwLock<PolicyCache<String, CacheValue<V>>>>,
 }
 
 impl<V: Into<CacheValue<V>>> InMemoryLruCache<V> {
@@ -51,18 +53,40 @@ impl<V: Into<CacheValue<V>>> Clone for InMemoryLruCache<V> {
 
 impl<V: Into<CacheValue<V>>> InMemoryLruCache<V> {
     pub fn with_items_capacity(name: String, items_capacity: usize) -> Self {
+        Self::with_items_capacity_and_policy(name, items_capacity, CachePolicy::Lru)
+    }
+
+    pub fn with_bytes_capacity(name: String, bytes_capacity: usize) -> Self {
+        Self::with_bytes_capacity_and_policy(name, bytes_capacity, CachePolicy::Lru)
+    }
+
+    pub fn with_items_capacity_and_policy(
+        name: String,
+        items_capacity: usize,
+        policy: CachePolicy,
+    ) -> Self {
         Self {
             name,
             unit: Unit::Count,
-            inner: Arc::new(RwLock::new(LruCache::with_items_capacity(items_capacity))),
+            inner: Arc::new(RwLock::new(PolicyCache::with_items_capacity(
+                policy,
+                items_capacity,
+            ))),
         }
     }
 
-    pub fn with_bytes_capacity(name: String, bytes_capacity: usize) -> Self {
+    pub fn with_bytes_capacity_and_policy(
+        name: String,
+        bytes_capacity: usize,
+        policy: CachePolicy,
+    ) -> Self {
         Self {
             unit: Unit::Bytes,
             name,
-            inner: Arc::new(RwLock::new(LruCache::with_bytes_capacity(bytes_capacity))),
+            inner: Arc::new(RwLock::new(PolicyCache::with_bytes_capacity(
+                policy,
+                bytes_capacity,
+            ))),
         }
     }
 
@@ -73,6 +97,37 @@ impl<V: Into<CacheValue<V>>> InMemoryLruCache<V> {
     pub fn unit(&self) -> Unit {
         self.unit
     }
+
+    pub fn policy(&self) -> CachePolicy {
+        self.inner.read().policy()
+    }
+
+    pub fn admission_stats(&self) -> AdmissionStats {
+        self.inner.read().admission_stats()
+    }
+
+    pub fn set_pin_quota_ratio(&self, ratio: usize) {
+        self.inner.write().set_pin_quota_ratio(ratio);
+    }
+
+    /// Exempts the cached item from eviction, returns false if it is not cached or the pin
+    /// quota is used up.
+    pub fn pin(&self, k: &str) -> bool {
+        self.inner.write().pin(k.to_owned())
+    }
+
+    /// Unpins the items whose keys start with `prefix`, returns the number of them.
+    pub fn unpin_prefix(&self, prefix: &str) -> usize {
+        self.inner.write().unpin_where(|k| k.starts_with(prefix))
+    }
+
+    pub fn pinned_len(&self) -> usize {
+        self.inner.read().pinned_len()
+    }
+
+    pub fn pinned_bytes_size(&self) -> u64 {
+        self.inner.read().pinned_bytes_size()
+    }
 }
 
 // default impls
@@ -20,6 +20,8 @@ use bytes::Bytes;
 use crossbeam_channel::TrySendError;
 use databend_common_base::runtime::profile::Profile;
 use databend_common_base::runtime::profile::ProfileStatisticsName;
+use databend_common_cache::AdmissionStats;
+use databend_common_cache::CachePolicy;
 use databend_common_config::DiskCacheKeyReloadPolicy;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -79,12 +81,14 @@ impl TableDataCacheBuilder {
         disk_cache_bytes_size: usize,
         disk_cache_reload_policy: DiskCacheKeyReloadPolicy,
         sync_data: bool,
+        policy: CachePolicy,
     ) -> Result<TableDataCache<LruDiskCacheHolder>> {
         let disk_cache = LruDiskCacheBuilder::new_disk_cache(
             path,
             disk_cache_bytes_size,
             disk_cache_reload_policy,
             sync_data,
+            policy,
         )?;
         let (tx, rx) = crossbeam_channel::bounded(population_queue_size as usize);
         let num_population_thread = 1;
@@ -96,6 +100,47 @@ impl TableDataCacheBuilder {
     }
 }
 
+impl TableDataCache {
+    pub fn policy(&self) -> CachePolicy {
+        self.external_cache.read().policy()
+    }
+
+    pub fn admission_stats(&self) -> AdmissionStats {
+        self.external_cache.read().admission_stats()
+    }
+
+    pub fn set_pin_quota_ratio(&self, ratio: usize) {
+        self.external_cache.write().set_pin_quota_ratio(ratio);
+    }
+
+    /// Writes the item into the disk cache right away, unlike `insert` which leaves it to the
+    /// population thread and may drop it if the population queue is full.
+    pub fn insert_now(&self, k: String, v: Bytes) {
+        if !self.external_cache.contains_key(&k) {
+            self.external_cache.insert(k, v);
+        }
+    }
+
+    /// Exempts the cached item from eviction, returns false if it is not cached or the pin
+    /// quota is used up.
+    pub fn pin(&self, k: &str) -> bool {
+        self.external_cache.write().pin(k)
+    }
+
+    /// Unpins the items whose keys start with `prefix`, returns the number of them.
+    pub fn unpin_prefix(&self, prefix: &str) -> usize {
+        self.external_cache.write().unpin_prefix(prefix)
+    }
+
+    pub fn pinned_len(&self) -> usize {
+        self.external_cache.read().pinned_len()
+    }
+
+    pub fn pinned_size(&self) -> u64 {
+        self.external_cache.read().pinned_size()
+    }
+}
+
 impl CacheAccessor for TableDataCache {
     type V = Bytes;
 
@@ -18,9 +18,13 @@ use std::collections::HashSet;
 use std::sync::Arc;
 
 use databend_common_meta_app::principal::StageInfo;
+use databend_common_meta_app::schema::CommitTableMetaReq;
+use databend_common_meta_app::schema::DropTableByIdReq;
+use databend_common_meta_app::schema::RenameTableReq;
 use databend_common_meta_app::schema::TableCopiedFileInfo;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TruncateTableReq;
 use databend_common_meta_app::schema::UpdateMultiTableMetaReq;
 use databend_common_meta_app::schema::UpdateStreamMetaReq;
 use databend_common_meta_app::schema::UpdateTableMetaReq;
@@ -40,6 +44,8 @@ pub struct TxnManager {
     state: TxnState,
     txn_buffer: TxnBuffer,
     txn_id: String,
+    /// Snapshots of the txn buffer taken by `SAVEPOINT`, oldest first.
+    savepoints: Vec<(String, TxnBuffer)>,
 }
 
 pub type TxnManagerRef = Arc<Mutex<TxnManager>>;
@@ -60,11 +66,28 @@ pub struct TxnBuffer {
     deduplicated_labels: HashSet<String>,
     stream_tables: HashMap<u64, StreamSnapshot>,
     need_purge_files: Vec<(StageInfo, Vec<String>)>,
+    // tables created as dropped in this txn, made visible on commit
+    created_tables: HashMap<u64, CommitTableMetaReq>,
+    // tables dropped in this txn
+    dropped_tables: HashMap<u64, DropTableByIdReq>,
+    // tables renamed in this txn, from the name before the txn
+    renamed_tables: HashMap<u64, RenamedTable>,
+    // tables truncated in this txn
+    truncated_tables: HashSet<u64>,
+    // names of the tables dropped or renamed in this txn
+    released_table_descs: HashSet<String>,
 
     temp_table_desc_to_id: HashMap<String, u64>,
     mutated_temp_tables: HashMap<u64, TempTable>,
 }
 
+#[derive(Debug, Clone)]
+struct RenamedTable {
+    req: RenameTableReq,
+    // the table under its current name
+    table_info: TableInfo,
+}
+
 #[derive(Debug, Clone)]
 pub struct StreamSnapshot {
     pub stream: TableInfo,
@@ -125,6 +148,7 @@ impl TxnManager {
             state: TxnState::AutoCommit,
             txn_buffer: TxnBuffer::default(),
             txn_id: "".to_string(),
+            savepoints: vec![],
         }))
     }
 
@@ -143,6 +167,42 @@ impl TxnManager {
         self.state = TxnState::AutoCommit;
         self.txn_buffer.clear();
         self.txn_id = "".to_string();
+        self.savepoints.clear();
+    }
+
+    /// Remember the current txn buffer under `name`, replacing an existing
+    /// savepoint of the same name.
+    pub fn create_savepoint(&mut self, name: &str) {
+        self.savepoints.retain(|(n, _)| n != name);
+        self.savepoints
+            .push((name.to_string(), self.txn_buffer.clone()));
+    }
+
+    /// Restore the txn buffer to the savepoint `name`, discarding the
+    /// savepoints created after it. A failed txn becomes active again.
+    ///
+    /// Returns false if there is no such savepoint.
+    pub fn rollback_to_savepoint(&mut self, name: &str) -> bool {
+        let Some(pos) = self.savepoints.iter().position(|(n, _)| n == name) else {
+            return false;
+        };
+        self.savepoints.truncate(pos + 1);
+        self.txn_buffer = self.savepoints[pos].1.clone();
+        if let TxnState::Fail = self.state {
+            self.state = TxnState::Active;
+        }
+        true
+    }
+
+    /// Remove the savepoint `name` and the savepoints created after it.
+    ///
+    /// Returns false if there is no such savepoint.
+    pub fn release_savepoint(&mut self, name: &str) -> bool {
+        let Some(pos) = self.savepoints.iter().position(|(n, _)| n == name) else {
+            return false;
+        };
+        self.savepoints.truncate(pos);
+        true
     }
 
     pub fn set_fail(&mut self) {
@@ -179,6 +239,100 @@ impl TxnManager {
         self.txn_buffer.update_stream_metas(reqs);
     }
 
+    pub fn add_created_table(&mut self, req: CommitTableMetaReq) {
+        self.txn_buffer.created_tables.insert(req.table_id, req);
+    }
+
+    /// Drop the table `desc` when the txn commits.
+    ///
+    /// A table created in this txn is never made visible.
+    pub fn drop_table(&mut self, desc: &str, mut req: DropTableByIdReq) {
+        let buffer = &mut self.txn_buffer;
+        let table_id = req.tb_id;
+        buffer.table_desc_to_id.remove(desc);
+        if buffer.created_tables.remove(&table_id).is_some() {
+            buffer.mutated_tables.remove(&table_id);
+            buffer.copied_files.remove(&table_id);
+            return;
+        }
+        match buffer.renamed_tables.remove(&table_id) {
+            // the table is dropped by the name it had before the txn
+            Some(renamed) => req.table_name = renamed.req.name_ident.table_name,
+            None => {
+                buffer.released_table_descs.insert(desc.to_string());
+            }
+        }
+        buffer.dropped_tables.insert(table_id, req);
+    }
+
+    /// Rename the table `table_info` when the txn commits.
+    ///
+    /// `req.name_ident` is the current name of the table.
+    pub fn rename_table(&mut self, req: RenameTableReq, table_info: TableInfo) {
+        let buffer = &mut self.txn_buffer;
+        let table_id = table_info.ident.table_id;
+        let new_desc = format!("'{}'.'{}'", req.new_db_name, req.new_table_name);
+        buffer.table_desc_to_id.remove(&table_info.desc);
+        buffer.table_desc_to_id.insert(new_desc.clone(), table_id);
+        let rename = |info: &mut TableInfo| {
+            info.desc = new_desc.clone();
+            info.name = req.new_table_name.clone();
+        };
+        if let Some(info) = buffer.mutated_tables.get_mut(&table_id) {
+            rename(info);
+        }
+
+        if let Some(created) = buffer.created_tables.get_mut(&table_id) {
+            // the new name has no table before the txn, or its table is released in the txn
+            created.name_ident.table_name = req.new_table_name.clone();
+            created.prev_table_id = None;
+            return;
+        }
+
+        let renamed = buffer
+            .renamed_tables
+            .entry(table_id)
+            .or_insert_with(|| RenamedTable {
+                req: req.clone(),
+                table_info: table_info.clone(),
+            });
+        renamed.req.new_db_name = req.new_db_name.clone();
+        renamed.req.new_table_name = req.new_table_name.clone();
+        rename(&mut renamed.table_info);
+
+        let origin = &renamed.req.name_ident;
+        let origin_desc = format!("'{}'.'{}'", origin.db_name, origin.table_name);
+        if origin_desc == new_desc {
+            // renamed back
+            buffer.renamed_tables.remove(&table_id);
+            buffer.released_table_descs.remove(&new_desc);
+        } else {
+            buffer.released_table_descs.insert(origin_desc);
+        }
+    }
+
+    /// Remove the copied files of the table when the txn commits.
+    pub fn truncate_table(&mut self, table_id: u64) {
+        self.txn_buffer.copied_files.remove(&table_id);
+        self.txn_buffer.truncated_tables.insert(table_id);
+    }
+
+    pub fn is_created_table(&self, table_id: u64) -> bool {
+        self.txn_buffer.created_tables.contains_key(&table_id)
+    }
+
+    pub fn is_table_truncated(&self, table_id: u64) -> bool {
+        self.txn_buffer.truncated_tables.contains(&table_id)
+    }
+
+    /// Whether the table `db_name`.`table_name` before the txn is dropped or renamed in the txn,
+    /// and no other table takes the name.
+    pub fn is_table_released(&self, db_name: &str, table_name: &str) -> bool {
+        let desc = format!("'{}'.'{}'", db_name, table_name);
+        self.txn_buffer.released_table_descs.contains(&desc)
+            && !self.txn_buffer.table_desc_to_id.contains_key(&desc)
+    }
+
     // for caching stream table to impl the rr semantics
     pub fn upsert_stream_table(
         &mut self,
@@ -233,7 +387,14 @@ impl TxnManager {
         self.txn_buffer
             .table_desc_to_id
             .get(&desc)
-            .and_then(|id| self.txn_buffer.mutated_tables.get(id))
+            .and_then(|id| {
+                self.txn_buffer.mutated_tables.get(id).or_else(|| {
+                    self.txn_buffer
+                        .renamed_tables
+                        .get(id)
+                        .map(|renamed| &renamed.table_info)
+                })
+            })
             .cloned()
     }
 
@@ -254,6 +415,12 @@ impl TxnManager {
                     .mutated_tables
                     .get(&table_id)
                     .cloned()
+                    .or_else(|| {
+                        self.txn_buffer
+                            .renamed_tables
+                            .get(&table_id)
+                            .map(|renamed| renamed.table_info.clone())
+                    })
                     .or_else(|| {
                         self.txn_buffer
                             .stream_tables
@@ -310,6 +477,23 @@ impl TxnManager {
                     desc: format!("'{}'.'{}'", t.db_name, t.table_name),
                 })
                 .collect(),
+            commit_tables: self.txn_buffer.created_tables.values().cloned().collect(),
+            drop_tables: self.txn_buffer.dropped_tables.values().cloned().collect(),
+            rename_tables: self
+                .txn_buffer
+                .renamed_tables
+                .values()
+                .map(|renamed| renamed.req.clone())
+                .collect(),
+            truncate_tables: self
+                .txn_buffer
+                .truncated_tables
+                .iter()
+                .map(|table_id| TruncateTableReq {
+                    table_id: *table_id,
+                    batch_size: None,
+                })
+                .collect(),
         }
     }
 
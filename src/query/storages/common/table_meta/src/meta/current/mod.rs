@@ -17,8 +17,11 @@ pub use v2::BlockMeta;
 pub use v2::ClusterStatistics;
 pub use v2::ColumnMeta;
 pub use v2::ColumnStatistics;
+pub use v2::DeletionVector;
 pub use v2::MetaHLL;
 pub use v2::Statistics;
+pub use v3::MostCommonValue;
+pub use v3::MultiColumnStatistics;
 pub use v3::TableSnapshotStatistics;
 pub use v4::CompactSegmentInfo;
 pub use v4::SegmentInfo;
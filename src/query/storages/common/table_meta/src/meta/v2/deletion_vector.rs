@@ -0,0 +1,55 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use databend_common_exception::Result;
+use databend_common_expression::types::Bitmap;
+use databend_common_expression::types::MutableBitmap;
+use roaring::RoaringBitmap;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Rows deleted from a block without rewriting the block.
+///
+/// The deleted rows are kept in the [super::BlockMeta] as a roaring bitmap of row offsets,
+/// readers filter them out, and compaction folds them into new blocks.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DeletionVector {
+    /// number of the deleted rows
+    pub deleted_rows: u64,
+    /// offsets of the deleted rows, serialized roaring bitmap
+    pub bitmap: Vec<u8>,
+}
+
+impl DeletionVector {
+    pub fn try_create(deleted: &RoaringBitmap) -> Result<Self> {
+        let mut bitmap = Vec::with_capacity(deleted.serialized_size());
+        deleted.serialize_into(&mut bitmap)?;
+        Ok(Self {
+            deleted_rows: deleted.len(),
+            bitmap,
+        })
+    }
+
+    pub fn deleted(&self) -> Result<RoaringBitmap> {
+        Ok(RoaringBitmap::deserialize_from(self.bitmap.as_slice())?)
+    }
+
+    /// Returns a bitmap of `num_rows` bits, the bits of the deleted rows are unset.
+    pub fn live_rows(&self, num_rows: usize) -> Result<Bitmap> {
+        let mut live_rows = MutableBitmap::from_len_set(num_rows);
+        for offset in self.deleted()? {
+            live_rows.set(offset as usize, false);
+        }
+        Ok(live_rows.into())
+    }
+}
@@ -12,11 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod deletion_vector;
 mod segment;
 mod snapshot;
 pub mod statistics;
 mod table_snapshot_statistics;
 
+pub use deletion_vector::DeletionVector;
 pub use segment::BlockMeta;
 pub use segment::ColumnMeta;
 pub use segment::SegmentInfo;
@@ -29,6 +29,7 @@ use serde::Serialize;
 
 use crate::meta::v0;
 use crate::meta::v1;
+use crate::meta::v2::DeletionVector;
 use crate::meta::ClusterStatistics;
 use crate::meta::ColumnStatistics;
 use crate::meta::Compression;
@@ -82,6 +83,10 @@ pub struct BlockMeta {
 
     // block create_on
     pub create_on: Option<DateTime<Utc>>,
+
+    /// rows deleted from the block, not counted in `row_count`
+    #[serde(default)]
+    pub deletion_vector: Option<DeletionVector>,
 }
 
 impl BlockMeta {
@@ -113,6 +118,7 @@ impl BlockMeta {
             inverted_index_size,
             compression,
             create_on,
+            deletion_vector: None,
         }
     }
 
@@ -120,6 +126,15 @@ impl BlockMeta {
         self.compression
     }
 
+    /// Get the number of rows stored in the block file, including the deleted rows.
+    pub fn physical_row_count(&self) -> u64 {
+        self.row_count
+            + self
+                .deletion_vector
+                .as_ref()
+                .map_or(0, |dv| dv.deleted_rows)
+    }
+
     /// Get the page size of the block.
     ///
     /// - If the format is parquet, its page size is its row count.
@@ -254,6 +269,7 @@ impl BlockMeta {
             compression: Compression::Lz4,
             inverted_index_size: None,
             create_on: None,
+            deletion_vector: None,
         }
     }
 
@@ -278,6 +294,7 @@ impl BlockMeta {
             compression: s.compression,
             inverted_index_size: None,
             create_on: None,
+            deletion_vector: None,
         }
     }
 }
@@ -21,4 +21,6 @@ mod table_snapshot_statistics;
 
 pub use segment::SegmentInfo;
 pub use snapshot::TableSnapshot;
+pub use table_snapshot_statistics::MostCommonValue;
+pub use table_snapshot_statistics::MultiColumnStatistics;
 pub use table_snapshot_statistics::TableSnapshotStatistics;
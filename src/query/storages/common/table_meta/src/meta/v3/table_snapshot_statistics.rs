@@ -15,6 +15,7 @@
 use std::collections::HashMap;
 
 use databend_common_expression::ColumnId;
+use databend_common_storage::Datum;
 use databend_common_storage::Histogram;
 use serde::Deserialize;
 use serde::Serialize;
@@ -36,12 +37,35 @@ pub struct TableSnapshotStatistics {
     pub snapshot_id: SnapshotId,
     pub hll: HashMap<ColumnId, MetaHLL>,
     pub histograms: HashMap<ColumnId, Histogram>,
+    /// joint statistics of column groups, collected by `ANALYZE TABLE ... COMPUTE STATISTICS ON (...)`
+    #[serde(default)]
+    pub multi_column_stats: Vec<MultiColumnStatistics>,
+}
+
+/// Statistics of a group of correlated columns.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct MultiColumnStatistics {
+    /// ids of the columns, sorted
+    pub column_ids: Vec<ColumnId>,
+    /// number of distinct combinations of the column values
+    pub ndv: u64,
+    /// most common combinations, in descending order of frequency
+    pub mcv: Vec<MostCommonValue>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct MostCommonValue {
+    /// values of the columns, in the order of `column_ids`
+    pub values: Vec<Datum>,
+    /// fraction of the rows having these values
+    pub frequency: f64,
 }
 
 impl TableSnapshotStatistics {
     pub fn new(
         hll: HashMap<ColumnId, MetaHLL>,
         histograms: HashMap<ColumnId, Histogram>,
+        multi_column_stats: Vec<MultiColumnStatistics>,
         snapshot_id: SnapshotId,
     ) -> Self {
         Self {
@@ -49,6 +73,7 @@ impl TableSnapshotStatistics {
             snapshot_id,
             hll,
             histograms,
+            multi_column_stats,
         }
     }
 
@@ -71,6 +96,7 @@ impl From<v2::TableSnapshotStatistics> for TableSnapshotStatistics {
             snapshot_id: value.snapshot_id,
             hll: HashMap::new(),
             histograms: HashMap::new(),
+            multi_column_stats: vec![],
         }
     }
 }
@@ -82,6 +108,7 @@ impl From<v1::TableSnapshotStatistics> for TableSnapshotStatistics {
             snapshot_id: value.snapshot_id,
             hll: HashMap::new(),
             histograms: HashMap::new(),
+            multi_column_stats: vec![],
         }
     }
 }
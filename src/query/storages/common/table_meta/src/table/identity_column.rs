@@ -0,0 +1,80 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::ColumnId;
+use databend_common_expression::TableSchema;
+
+use crate::table::OPT_KEY_IDENTITY_COLUMNS;
+
+/// Prefix of the names of the sequences owned by identity columns, which can not be
+/// created or dropped by users.
+pub const IDENTITY_SEQUENCE_PREFIX: &str = "identity_";
+
+pub fn is_identity_sequence(name: &str) -> bool {
+    name.starts_with(IDENTITY_SEQUENCE_PREFIX)
+}
+
+/// An `AUTOINCREMENT` / `IDENTITY` column of a table.
+///
+/// The values of the column are allocated from a sequence owned by the table,
+/// `start` and `increment` are kept for `SHOW CREATE TABLE`. The values of an
+/// `always` generated column can not be given explicitly.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct IdentityColumn {
+    pub column_id: ColumnId,
+    pub sequence: String,
+    pub start: u64,
+    pub increment: u64,
+    #[serde(default)]
+    pub always: bool,
+}
+
+/// Parses the identity columns kept in the table options.
+pub fn parse_identity_columns(options: &BTreeMap<String, String>) -> Result<Vec<IdentityColumn>> {
+    match options.get(OPT_KEY_IDENTITY_COLUMNS) {
+        Some(value) => serde_json::from_str(value).map_err(|e| {
+            ErrorCode::TableOptionInvalid(format!("invalid identity columns {value}: {e}"))
+        }),
+        None => Ok(vec![]),
+    }
+}
+
+/// Encodes the identity columns as the value of the table option.
+pub fn encode_identity_columns(columns: &[IdentityColumn]) -> Result<String> {
+    serde_json::to_string(columns)
+        .map_err(|e| ErrorCode::Internal(format!("failed to encode identity columns: {e}")))
+}
+
+/// The identity columns of the table whose column still exists in `schema`,
+/// along with the index of the column in `schema`.
+pub fn identity_columns_of_schema(
+    options: &BTreeMap<String, String>,
+    schema: &TableSchema,
+) -> Result<Vec<(usize, IdentityColumn)>> {
+    let columns = parse_identity_columns(options)?;
+    Ok(columns
+        .into_iter()
+        .filter_map(|column| {
+            schema
+                .fields()
+                .iter()
+                .position(|f| f.column_id() == column.column_id)
+                .map(|index| (index, column))
+        })
+        .collect())
+}
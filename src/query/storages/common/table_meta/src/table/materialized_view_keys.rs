@@ -0,0 +1,100 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::BTreeMap;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+// Materialized view options.
+// The query of the materialized view, with the tables qualified by their databases.
+pub const OPT_KEY_MATERIALIZED_VIEW_QUERY: &str = "materialized_view_query";
+// Whether the materialized view is refreshed on each write to its source tables.
+pub const OPT_KEY_MATERIALIZED_VIEW_AUTO_REFRESH: &str = "materialized_view_auto_refresh";
+// The snapshots of the source tables the materialized view is refreshed from, as a JSON.
+pub const OPT_KEY_MATERIALIZED_VIEW_SOURCES: &str = "materialized_view_sources";
+// The ids of the materialized views on a source table, separated by commas.
+pub const OPT_KEY_MATERIALIZED_VIEWS: &str = "materialized_views";
+
+/// The snapshot of a source table that a materialized view is refreshed from.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct MaterializedViewSource {
+    pub table_id: u64,
+    pub snapshot_location: Option<String>,
+}
+
+/// Parses the sources of a materialized view kept in the view options.
+pub fn parse_materialized_view_sources(
+    options: &BTreeMap<String, String>,
+) -> Result<Vec<MaterializedViewSource>> {
+    match options.get(OPT_KEY_MATERIALIZED_VIEW_SOURCES) {
+        Some(value) => serde_json::from_str(value).map_err(|e| {
+            ErrorCode::IllegalMaterializedView(format!(
+                "invalid materialized view sources {value}: {e}"
+            ))
+        }),
+        None => Ok(vec![]),
+    }
+}
+
+/// Encodes the sources of a materialized view as the value of the view option.
+pub fn encode_materialized_view_sources(sources: &[MaterializedViewSource]) -> Result<String> {
+    serde_json::to_string(sources).map_err(|e| {
+        ErrorCode::Internal(format!("failed to encode materialized view sources: {e}"))
+    })
+}
+
+/// Parses the ids of the materialized views on a source table.
+pub fn parse_materialized_views(options: &BTreeMap<String, String>) -> Vec<u64> {
+    options
+        .get(OPT_KEY_MATERIALIZED_VIEWS)
+        .map(|value| {
+            value
+                .split(',')
+                .filter_map(|id| id.trim().parse::<u64>().ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Encodes the ids of the materialized views on a source table, `None` if there is none.
+pub fn encode_materialized_views(ids: &[u64]) -> Option<String> {
+    if ids.is_empty() {
+        return None;
+    }
+    Some(
+        ids.iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>()
+            .join(","),
+    )
+}
+
+/// Whether the materialized view is refreshed on each write to its source tables.
+pub fn is_materialized_view_auto_refresh(options: &BTreeMap<String, String>) -> bool {
+    options
+        .get(OPT_KEY_MATERIALIZED_VIEW_AUTO_REFRESH)
+        .is_some_and(|value| value == "true")
+}
+
+/// Whether the option is kept for a materialized view or its source tables, these options
+/// refer to other tables by id and must not be copied to another table.
+pub fn is_materialized_view_opt_key(opt_key: &str) -> bool {
+    matches!(
+        opt_key,
+        OPT_KEY_MATERIALIZED_VIEW_QUERY
+            | OPT_KEY_MATERIALIZED_VIEW_AUTO_REFRESH
+            | OPT_KEY_MATERIALIZED_VIEW_SOURCES
+            | OPT_KEY_MATERIALIZED_VIEWS
+    )
+}
@@ -12,11 +12,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+use crate::table::OPT_KEY_SNAPSHOT_LOCATION;
+
 // Stream table options.
 pub const OPT_KEY_SOURCE_DATABASE_ID: &str = "source_db_id";
 pub const OPT_KEY_SOURCE_TABLE_ID: &str = "table_id";
 pub const OPT_KEY_TABLE_VER: &str = "table_version";
 pub const OPT_KEY_MODE: &str = "mode";
+// The engine of the source if it is not a fuse table, e.g. `VIEW`, `DELTA` and `ICEBERG`.
+pub const OPT_KEY_SOURCE_ENGINE: &str = "source_engine";
+// The version of the Delta/Iceberg source table that the stream starts from.
+pub const OPT_KEY_SOURCE_VERSION: &str = "source_version";
+// The offsets of the stream in the tables underlying its source view, as a JSON.
+pub const OPT_KEY_VIEW_SOURCE_OFFSETS: &str = "view_source_offsets";
 
 pub const OPT_KEY_TABLE_NAME: &str = "table_name";
 pub const OPT_KEY_DATABASE_NAME: &str = "table_database";
@@ -24,6 +37,62 @@ pub const OPT_KEY_DATABASE_NAME: &str = "table_database";
 pub const MODE_APPEND_ONLY: &str = "append_only";
 pub const MODE_STANDARD: &str = "standard";
 
+/// The offset of a stream in a fuse table.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct StreamSourceOffset {
+    pub database_id: u64,
+    pub table_id: u64,
+    pub table_version: u64,
+    pub snapshot_location: Option<String>,
+}
+
+/// Parses the offsets of a view stream kept in the stream options.
+pub fn parse_view_source_offsets(
+    options: &BTreeMap<String, String>,
+) -> Result<Vec<StreamSourceOffset>> {
+    match options.get(OPT_KEY_VIEW_SOURCE_OFFSETS) {
+        Some(value) => serde_json::from_str(value).map_err(|e| {
+            ErrorCode::IllegalStream(format!("invalid view source offsets {value}: {e}"))
+        }),
+        None => Ok(vec![]),
+    }
+}
+
+/// Encodes the offsets of a view stream as the value of the stream option.
+pub fn encode_view_source_offsets(offsets: &[StreamSourceOffset]) -> Result<String> {
+    serde_json::to_string(offsets)
+        .map_err(|e| ErrorCode::Internal(format!("failed to encode view source offsets: {e}")))
+}
+
+/// The table version and snapshot location the stream starts from in the table `table_id`,
+/// `None` if the table is neither the source of the stream nor underlying its source view.
+pub fn stream_offset_of_table(
+    options: &BTreeMap<String, String>,
+    table_id: u64,
+) -> Result<Option<(u64, Option<String>)>> {
+    if options.contains_key(OPT_KEY_VIEW_SOURCE_OFFSETS) {
+        let offset = parse_view_source_offsets(options)?
+            .into_iter()
+            .find(|offset| offset.table_id == table_id)
+            .map(|offset| (offset.table_version, offset.snapshot_location));
+        return Ok(offset);
+    }
+
+    let source_table_id = options
+        .get(OPT_KEY_SOURCE_TABLE_ID)
+        .ok_or_else(|| ErrorCode::Internal("table id must be set"))?
+        .parse::<u64>()?;
+    if source_table_id != table_id {
+        return Ok(None);
+    }
+    let table_version = options
+        .get(OPT_KEY_TABLE_VER)
+        .ok_or_else(|| ErrorCode::Internal("table version must be set"))?
+        .parse::<u64>()?;
+    let snapshot_location = options.get(OPT_KEY_SNAPSHOT_LOCATION).cloned();
+    Ok(Some((table_version, snapshot_location)))
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub enum ChangeType {
     // append only.
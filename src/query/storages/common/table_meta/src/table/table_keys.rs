@@ -16,6 +16,12 @@ use std::collections::HashSet;
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::sync::LazyLock;
+
+use crate::table::OPT_KEY_MATERIALIZED_VIEWS;
+use crate::table::OPT_KEY_MATERIALIZED_VIEW_AUTO_REFRESH;
+use crate::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use crate::table::OPT_KEY_MATERIALIZED_VIEW_SOURCES;
+
 pub const OPT_KEY_DATABASE_ID: &str = "database_id";
 pub const OPT_KEY_STORAGE_PREFIX: &str = "storage_prefix";
 pub const OPT_KEY_TEMP_PREFIX: &str = "temp_prefix";
@@ -28,6 +34,13 @@ pub const OPT_KEY_ENGINE: &str = "engine";
 pub const OPT_KEY_BLOOM_INDEX_COLUMNS: &str = "bloom_index_columns";
 pub const OPT_KEY_CHANGE_TRACKING: &str = "change_tracking";
 pub const OPT_KEY_CHANGE_TRACKING_BEGIN_VER: &str = "begin_version";
+// Rows whose TTL expression is not later than now are expired.
+pub const OPT_KEY_TTL: &str = "ttl";
+// The `AUTOINCREMENT` columns and the sequences allocating their values, as a JSON.
+pub const OPT_KEY_IDENTITY_COLUMNS: &str = "identity_columns";
+
+// Storage prefixes of the tables whose files are referenced by a cloned table.
+pub const OPT_KEY_CLONED_FROM: &str = "cloned_from";
 
 // Attached table options.
 pub const OPT_KEY_TABLE_ATTACHED_DATA_URI: &str = "table_data_uri";
@@ -65,6 +78,12 @@ pub static RESERVED_TABLE_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLoc
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_CLONED_FROM);
+    r.insert(OPT_KEY_IDENTITY_COLUMNS);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_AUTO_REFRESH);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_SOURCES);
+    r.insert(OPT_KEY_MATERIALIZED_VIEWS);
     r
 });
 
@@ -76,6 +95,12 @@ pub static INTERNAL_TABLE_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLoc
     r.insert(OPT_KEY_ENGINE_META);
     r.insert(OPT_KEY_CHANGE_TRACKING_BEGIN_VER);
     r.insert(OPT_KEY_TEMP_PREFIX);
+    r.insert(OPT_KEY_CLONED_FROM);
+    r.insert(OPT_KEY_IDENTITY_COLUMNS);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_AUTO_REFRESH);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_SOURCES);
+    r.insert(OPT_KEY_MATERIALIZED_VIEWS);
     r
 });
 
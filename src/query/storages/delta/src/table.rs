@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use arrow_schema::Schema as ArrowSchema;
@@ -48,6 +49,7 @@ use databend_common_storages_parquet::ParquetRSPruner;
 use databend_common_storages_parquet::ParquetRSReaderBuilder;
 use databend_storages_common_pruner::partition_prunner::FetchPartitionScalars;
 use databend_storages_common_pruner::partition_prunner::PartitionPruner;
+use databend_storages_common_table_meta::table::ChangeType;
 use databend_storages_common_table_meta::table::OPT_KEY_ENGINE_META;
 use deltalake::kernel::Add;
 use deltalake::DeltaTableBuilder;
@@ -279,17 +281,65 @@ impl DeltaTable {
         push_downs: Option<PushDownInfo>,
     ) -> Result<(PartStatistics, Partitions)> {
         let table = self.table().await?;
+        let adds = Self::file_actions(table)?;
+        self.adds_to_partitions(ctx, push_downs, adds)
+    }
+
+    #[async_backtrace::framed]
+    async fn do_read_changes_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+        change_type: ChangeType,
+        base_version: &Option<String>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let table = self.table().await?;
+        let latest_adds = Self::file_actions(table)?;
+        let base_adds = match base_version {
+            Some(version) => {
+                let mut base = table.clone();
+                base.load_version(version.parse::<i64>()?)
+                    .await
+                    .map_err(|err| {
+                        ErrorCode::ReadTableDataError(format!(
+                            "Delta table load version {version} failed: {err:?}"
+                        ))
+                    })?;
+                Self::file_actions(&base)?
+            }
+            None => vec![],
+        };
 
+        // A data file is immutable once written, the changes are the files only in one side.
+        let (adds, others) = match change_type {
+            ChangeType::Append | ChangeType::Insert => (latest_adds, base_adds),
+            ChangeType::Delete => (base_adds, latest_adds),
+        };
+        let others: HashSet<&str> = others.iter().map(|add| add.path.as_str()).collect();
+        let adds = adds
+            .into_iter()
+            .filter(|add| !others.contains(add.path.as_str()))
+            .collect();
+        self.adds_to_partitions(ctx, push_downs, adds)
+    }
+
+    fn file_actions(table: &deltalake::table::DeltaTable) -> Result<Vec<Add>> {
+        table
+            .snapshot()
+            .and_then(|f| f.file_actions())
+            .map_err(|e| ErrorCode::ReadTableDataError(format!("Cannot read file_actions: {e:?}")))
+    }
+
+    fn adds_to_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+        mut adds: Vec<Add>,
+    ) -> Result<(PartStatistics, Partitions)> {
         let mut read_rows = 0;
         let mut read_bytes = 0;
 
         let partition_fields = self.get_partition_fields()?;
-        let mut adds = table
-            .snapshot()
-            .and_then(|f| f.file_actions())
-            .map_err(|e| {
-                ErrorCode::ReadTableDataError(format!("Cannot read file_actions: {e:?}"))
-            })?;
 
         let filter_expression = push_downs.as_ref().and_then(|p| {
             p.filters
@@ -412,6 +462,24 @@ impl Table for DeltaTable {
     fn support_prewhere(&self) -> bool {
         true
     }
+
+    #[async_backtrace::framed]
+    async fn changes_version(&self) -> Result<Option<String>> {
+        let table = self.table().await?;
+        Ok(Some(table.version().to_string()))
+    }
+
+    #[async_backtrace::framed]
+    async fn read_changes_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+        change_type: ChangeType,
+        base_version: &Option<String>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        self.do_read_changes_partitions(ctx, push_downs, change_type, base_version)
+            .await
+    }
 }
 
 pub fn get_partition_values(add: &Add, fields: &[TableField]) -> Result<Vec<Scalar>> {
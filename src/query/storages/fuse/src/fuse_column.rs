@@ -20,18 +20,21 @@ use databend_common_expression::ColumnId;
 use databend_common_storage::Datum;
 use databend_common_storage::Histogram;
 use databend_storages_common_table_meta::meta::ColumnStatistics as FuseColumnStatistics;
+use databend_storages_common_table_meta::meta::MultiColumnStatistics;
 
 /// A column statistics provider for fuse table.
 #[derive(Default)]
 pub struct FuseTableColumnStatisticsProvider {
     column_stats: HashMap<ColumnId, Option<BasicColumnStatistics>>,
     histograms: HashMap<ColumnId, Histogram>,
+    multi_column_stats: Vec<MultiColumnStatistics>,
 }
 
 impl FuseTableColumnStatisticsProvider {
     pub fn new(
         column_stats: HashMap<ColumnId, FuseColumnStatistics>,
         histograms: HashMap<ColumnId, Histogram>,
+        multi_column_stats: Vec<MultiColumnStatistics>,
         column_distinct_values: Option<HashMap<ColumnId, u64>>,
         row_count: u64,
     ) -> Self {
@@ -53,6 +56,7 @@ impl FuseTableColumnStatisticsProvider {
         Self {
             column_stats,
             histograms,
+            multi_column_stats,
         }
     }
 }
@@ -69,4 +73,8 @@ impl ColumnStatisticsProvider for FuseTableColumnStatisticsProvider {
     fn histogram(&self, column_id: ColumnId) -> Option<Histogram> {
         self.histograms.get(&column_id).cloned()
     }
+
+    fn multi_column_statistics(&self) -> &[MultiColumnStatistics] {
+        &self.multi_column_stats
+    }
 }
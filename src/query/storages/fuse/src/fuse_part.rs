@@ -33,6 +33,7 @@ use databend_storages_common_pruner::BlockMetaIndex;
 use databend_storages_common_table_meta::meta::ColumnMeta;
 use databend_storages_common_table_meta::meta::ColumnStatistics;
 use databend_storages_common_table_meta::meta::Compression;
+use databend_storages_common_table_meta::meta::DeletionVector;
 use databend_storages_common_table_meta::meta::Location;
 
 /// Fuse table partition information.
@@ -48,6 +49,8 @@ pub struct FuseBlockPartInfo {
 
     pub sort_min_max: Option<(Scalar, Scalar)>,
     pub block_meta_index: Option<BlockMetaIndex>,
+    /// The deleted rows of the block, `nums_rows` includes them.
+    pub deletion_vector: Option<DeletionVector>,
 }
 
 #[typetag::serde(name = "fuse")]
@@ -84,6 +87,7 @@ impl FuseBlockPartInfo {
         sort_min_max: Option<(Scalar, Scalar)>,
         block_meta_index: Option<BlockMetaIndex>,
         create_on: Option<DateTime<Utc>>,
+        deletion_vector: Option<DeletionVector>,
     ) -> Arc<Box<dyn PartInfo>> {
         Arc::new(Box::new(FuseBlockPartInfo {
             location,
@@ -94,6 +98,7 @@ impl FuseBlockPartInfo {
             sort_min_max,
             block_meta_index,
             columns_stat,
+            deletion_vector,
         }))
     }
 
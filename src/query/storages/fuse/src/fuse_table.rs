@@ -118,6 +118,7 @@ use crate::DEFAULT_ROW_PER_PAGE_FOR_BLOCKING;
 use crate::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use crate::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
 use crate::FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS;
+use crate::FUSE_OPT_KEY_ENABLE_DELETION_VECTOR;
 use crate::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use crate::FUSE_OPT_KEY_ROW_PER_PAGE;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
@@ -474,6 +475,20 @@ impl FuseTable {
         self.storage_format
     }
 
+    /// Deletes write deletion vectors instead of rewriting the blocks.
+    ///
+    /// Not used for the tables tracking changes, the rows of streams are identified by
+    /// their offsets in the rewritten blocks.
+    pub fn deletion_vector_enabled(&self) -> bool {
+        matches!(self.storage_format, FuseStorageFormat::Parquet)
+            && !self.change_tracking_enabled()
+            && self
+                .table_info
+                .options()
+                .get(FUSE_OPT_KEY_ENABLE_DELETION_VECTOR)
+                .is_some_and(|v| v.eq_ignore_ascii_case("true"))
+    }
+
     pub fn get_storage_prefix(&self) -> &str {
         self.meta_location_generator.prefix()
     }
@@ -823,6 +838,7 @@ impl Table for FuseTable {
                 FuseTableColumnStatisticsProvider::new(
                     stats.clone(),
                     table_statistics.histograms.clone(),
+                    table_statistics.multi_column_stats.clone(),
                     Some(table_statistics.column_distinct_values()),
                     snapshot.summary.row_count,
                 )
@@ -830,6 +846,7 @@ impl Table for FuseTable {
                 FuseTableColumnStatisticsProvider::new(
                     stats.clone(),
                     HashMap::new(),
+                    vec![],
                     None,
                     snapshot.summary.row_count,
                 )
@@ -1013,6 +1030,11 @@ impl Table for FuseTable {
         self.do_compact_blocks(ctx, limits).await
     }
 
+    #[async_backtrace::framed]
+    async fn drop_expired_blocks(&self, ctx: Arc<dyn TableContext>) -> Result<usize> {
+        self.do_drop_expired_blocks(ctx).await
+    }
+
     #[async_backtrace::framed]
     async fn recluster(
         &self,
@@ -409,6 +409,7 @@ impl BlockBuilder {
             compression: self.write_settings.table_compression.into(),
             inverted_index_size,
             create_on: Some(Utc::now()),
+            deletion_vector: None,
         };
 
         let serialized = BlockSerialization {
@@ -41,6 +41,8 @@ use databend_common_storage::Histogram;
 use databend_common_storage::HistogramBucket;
 use databend_storages_common_table_meta::meta::ClusterStatistics;
 use databend_storages_common_table_meta::meta::MetaHLL;
+use databend_storages_common_table_meta::meta::MostCommonValue;
+use databend_storages_common_table_meta::meta::MultiColumnStatistics;
 use databend_storages_common_table_meta::meta::SegmentInfo;
 use databend_storages_common_table_meta::meta::SnapshotId;
 use databend_storages_common_table_meta::meta::StatisticsOfColumns;
@@ -56,6 +58,7 @@ use crate::FuseTable;
 enum AnalyzeStep {
     CollectNDV,
     CollectHistogram,
+    CollectMultiColumnStatistics,
     CommitStatistics,
 }
 
@@ -70,6 +73,7 @@ impl FuseTable {
         snapshot_id: SnapshotId,
         pipeline: &mut Pipeline,
         histogram_info_receivers: HashMap<u32, Receiver<DataBlock>>,
+        multi_column_receivers: Vec<(Vec<ColumnId>, Receiver<DataBlock>)>,
     ) -> Result<()> {
         pipeline.add_sink(|input| {
             SinkAnalyzeState::create(
@@ -81,6 +85,7 @@ impl FuseTable {
                 snapshot_id,
                 input,
                 histogram_info_receivers.clone(),
+                multi_column_receivers.clone(),
             )
         })?;
         Ok(())
@@ -97,10 +102,12 @@ struct SinkAnalyzeState {
     table: String,
     snapshot_id: SnapshotId,
     histogram_info_receivers: HashMap<u32, Receiver<DataBlock>>,
+    multi_column_receivers: Vec<(Vec<ColumnId>, Receiver<DataBlock>)>,
     input_data: Option<DataBlock>,
     committed: bool,
     ndv_states: HashMap<ColumnId, MetaHLL>,
     histograms: HashMap<ColumnId, Histogram>,
+    multi_column_stats: Vec<MultiColumnStatistics>,
     step: AnalyzeStep,
 }
 
@@ -115,7 +122,16 @@ impl SinkAnalyzeState {
         snapshot_id: SnapshotId,
         input: Arc<InputPort>,
         histogram_info_receivers: HashMap<u32, Receiver<DataBlock>>,
+        multi_column_receivers: Vec<(Vec<ColumnId>, Receiver<DataBlock>)>,
     ) -> Result<ProcessorPtr> {
+        let multi_column_stats = multi_column_receivers
+            .iter()
+            .map(|(column_ids, _)| MultiColumnStatistics {
+                column_ids: column_ids.clone(),
+                ndv: 0,
+                mcv: vec![],
+            })
+            .collect();
         Ok(ProcessorPtr::create(Box::new(SinkAnalyzeState {
             ctx,
             output_schema,
@@ -125,10 +141,12 @@ impl SinkAnalyzeState {
             table: table.to_string(),
             snapshot_id,
             histogram_info_receivers,
+            multi_column_receivers,
             input_data: None,
             committed: false,
             ndv_states: Default::default(),
             histograms: Default::default(),
+            multi_column_stats,
             step: AnalyzeStep::CollectNDV,
         })))
     }
@@ -237,6 +255,48 @@ impl SinkAnalyzeState {
         Ok(())
     }
 
+    // The columns of the block are: ndv, total rows, count of the values, the values.
+    fn create_multi_column_statistics(
+        &mut self,
+        index: usize,
+        data_block: DataBlock,
+    ) -> Result<()> {
+        let stat = &mut self.multi_column_stats[index];
+        let columns = data_block.columns();
+        for row in 0..data_block.num_rows() {
+            let number = |col: usize| -> Result<u64> {
+                let value = columns[col].value.index(row).unwrap();
+                value
+                    .as_number()
+                    .and_then(|number| number.as_u_int64())
+                    .cloned()
+                    .ok_or_else(|| {
+                        ErrorCode::Internal(format!(
+                            "Invalid value {value} of multi-column statistics"
+                        ))
+                    })
+            };
+            stat.ndv = number(0)?;
+            let total = number(1)?;
+            let count = number(2)?;
+            // The combinations with null are not kept.
+            let Some(values) = columns[3..]
+                .iter()
+                .map(|col| Datum::from_scalar(col.value.index(row).unwrap().to_owned()))
+                .collect::<Option<Vec<_>>>()
+            else {
+                continue;
+            };
+            if total > 0 {
+                stat.mcv.push(MostCommonValue {
+                    values,
+                    frequency: count as f64 / total as f64,
+                });
+            }
+        }
+        Ok(())
+    }
+
     async fn commit_statistics(&self) -> Result<()> {
         let table = self.get_table().await?;
         let table = FuseTable::try_from_table(table.as_ref())?;
@@ -249,6 +309,7 @@ impl SinkAnalyzeState {
         let table_statistics = TableSnapshotStatistics::new(
             self.ndv_states.clone(),
             self.histograms.clone(),
+            self.multi_column_stats.clone(),
             self.snapshot_id,
         );
         let table_statistics_location = table
@@ -303,7 +364,7 @@ impl Processor for SinkAnalyzeState {
                     self.step = AnalyzeStep::CollectHistogram;
                     return Ok(Event::Async);
                 }
-                AnalyzeStep::CollectHistogram => {
+                AnalyzeStep::CollectHistogram | AnalyzeStep::CollectMultiColumnStatistics => {
                     return Ok(Event::Async);
                 }
                 AnalyzeStep::CommitStatistics => {
@@ -344,6 +405,20 @@ impl Processor for SinkAnalyzeState {
                     }
                 }
                 if finished_count == self.histogram_info_receivers.len() {
+                    self.step = AnalyzeStep::CollectMultiColumnStatistics;
+                }
+            }
+            AnalyzeStep::CollectMultiColumnStatistics => {
+                let mut finished_count = 0;
+                let receivers = self.multi_column_receivers.clone();
+                for (index, (_, receiver)) in receivers.iter().enumerate() {
+                    if let Ok(res) = receiver.recv().await {
+                        self.create_multi_column_statistics(index, res)?;
+                    } else {
+                        finished_count += 1;
+                    }
+                }
+                if finished_count == self.multi_column_receivers.len() {
                     self.step = AnalyzeStep::CommitStatistics;
                 }
             }
@@ -0,0 +1,412 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_base::runtime::execute_futures_in_parallel;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::TableMeta;
+use databend_storages_common_cache::LoadParams;
+use databend_storages_common_table_meta::meta::SegmentInfo;
+use databend_storages_common_table_meta::meta::TableSnapshot;
+use databend_storages_common_table_meta::meta::Versioned;
+use log::info;
+use opendal::Operator;
+use serde::Deserialize;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::io::MetaReaders;
+use crate::io::MetaWriter;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseTable;
+use crate::FUSE_TBL_XOR_BLOOM_INDEX_PREFIX;
+
+/// Layout of the backups of a database under their location:
+///
+/// - `manifests/<millis>.json`: one manifest per backup, RESTORE uses the latest one.
+/// - `data/<location>`: the fuse files referenced by the backed up snapshots, kept under their
+///   original locations and shared by all the backups of the location. RESTORE copies them
+///   under the storage prefix of the restored table.
+const BACKUP_MANIFEST_DIR: &str = "manifests";
+const BACKUP_DATA_DIR: &str = "data";
+const BACKUP_FORMAT_VERSION: u64 = 1;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BackupManifest {
+    pub format_version: u64,
+    pub database: String,
+    pub created_on: DateTime<Utc>,
+    /// The manifest this backup is based on. Files stored by it are not copied again.
+    pub base: Option<String>,
+    pub tables: Vec<BackupTable>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BackupTable {
+    pub name: String,
+    pub meta: TableMeta,
+    /// The backed up snapshot, `None` for views and empty tables.
+    pub snapshot_location: Option<String>,
+    /// Files referenced by the snapshot, the snapshot itself included.
+    pub files: Vec<String>,
+}
+
+impl BackupManifest {
+    pub fn create(database: String, base: Option<String>, tables: Vec<BackupTable>) -> Self {
+        Self {
+            format_version: BACKUP_FORMAT_VERSION,
+            database,
+            created_on: Utc::now(),
+            base,
+            tables,
+        }
+    }
+
+    /// Reads the latest manifest under `root`, returns its name along with it.
+    #[async_backtrace::framed]
+    pub async fn read_latest(
+        operator: &Operator,
+        root: &str,
+    ) -> Result<Option<(String, BackupManifest)>> {
+        let dir = backup_path(root, &format!("{}/", BACKUP_MANIFEST_DIR));
+        let latest = operator
+            .list(&dir)
+            .await?
+            .into_iter()
+            .filter(|e| e.name().ends_with(".json"))
+            .max_by(|a, b| a.name().cmp(b.name()));
+        let Some(entry) = latest else {
+            return Ok(None);
+        };
+
+        let data = operator.read(entry.path()).await?.to_vec();
+        let manifest: BackupManifest = serde_json::from_slice(&data)?;
+        if manifest.format_version > BACKUP_FORMAT_VERSION {
+            return Err(ErrorCode::StorageOther(format!(
+                "unsupported backup format version {}, backup {}",
+                manifest.format_version,
+                entry.path()
+            )));
+        }
+        Ok(Some((entry.name().to_string(), manifest)))
+    }
+
+    /// Writes the manifest under `root`, returns its name.
+    #[async_backtrace::framed]
+    pub async fn write(&self, operator: &Operator, root: &str) -> Result<String> {
+        let name = format!("{:020}.json", self.created_on.timestamp_millis());
+        let path = backup_path(root, &format!("{}/{}", BACKUP_MANIFEST_DIR, name));
+        operator.write(&path, serde_json::to_vec(self)?).await?;
+        Ok(name)
+    }
+
+    /// Files already stored in the location by this backup.
+    pub fn stored_files(&self) -> HashSet<String> {
+        self.tables
+            .iter()
+            .flat_map(|t| t.files.iter().cloned())
+            .collect()
+    }
+}
+
+/// The snapshot of a table to back up, `None` if the table is empty.
+pub type BackupSnapshot = Option<(String, Arc<TableSnapshot>)>;
+
+impl FuseTable {
+    /// Reads the current snapshot of this table, the snapshots of all the tables of a database
+    /// are read before any file is copied, so that the backup is a consistent point in time.
+    #[async_backtrace::framed]
+    pub async fn backup_snapshot(&self) -> Result<BackupSnapshot> {
+        match (self.read_table_snapshot().await?, self.snapshot_loc()) {
+            (Some(snapshot), Some(location)) => Ok(Some((location, snapshot))),
+            _ => Ok(None),
+        }
+    }
+
+    /// Backs up the snapshot of this table into `root` of `backup_op`.
+    ///
+    /// Only the files which are not in `stored` are copied.
+    #[async_backtrace::framed]
+    pub async fn backup(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        backup_op: &Operator,
+        root: &str,
+        stored: &HashSet<String>,
+        snapshot: BackupSnapshot,
+    ) -> Result<BackupTable> {
+        let mut backup = BackupTable {
+            name: self.table_info.name.clone(),
+            meta: self.table_info.meta.clone(),
+            snapshot_location: None,
+            files: vec![],
+        };
+        let Some((snapshot_location, snapshot)) = snapshot else {
+            return Ok(backup);
+        };
+
+        let locations = self
+            .get_block_locations(ctx.clone(), &snapshot.segments, false, false)
+            .await?;
+        let mut files = vec![snapshot_location.clone()];
+        files.extend(snapshot.segments.iter().map(|loc| loc.0.clone()));
+        files.extend(snapshot.table_statistics_location.iter().cloned());
+        files.extend(locations.block_location);
+
+        let to_copy = files
+            .iter()
+            .filter(|loc| !stored.contains(*loc))
+            .map(|loc| (loc.clone(), backup_path(root, &data_location(loc))))
+            .collect::<Vec<_>>();
+        let num_copied = to_copy.len();
+        let copied = copy_files(ctx.clone(), &self.operator, backup_op, to_copy).await?;
+        if copied.iter().any(|c| !c) {
+            return Err(ErrorCode::StorageNotFound(format!(
+                "files of snapshot {} of table {} are missing, it may have been purged",
+                snapshot_location, self.table_info.desc
+            )));
+        }
+
+        // bloom index files are optional, keep the ones that exist
+        let (stored_blooms, new_blooms): (Vec<_>, Vec<_>) = locations
+            .bloom_location
+            .into_iter()
+            .partition(|loc| stored.contains(loc));
+        let to_copy = new_blooms
+            .iter()
+            .map(|loc| (loc.clone(), backup_path(root, &data_location(loc))))
+            .collect::<Vec<_>>();
+        let copied = copy_files(ctx, &self.operator, backup_op, to_copy).await?;
+        files.extend(stored_blooms);
+        files.extend(
+            new_blooms
+                .into_iter()
+                .zip(copied)
+                .filter_map(|(loc, copied)| copied.then_some(loc)),
+        );
+
+        info!(
+            "backup table {}, snapshot {}, {} of {} files copied",
+            self.table_info.desc,
+            snapshot_location,
+            num_copied,
+            files.len()
+        );
+        backup.snapshot_location = Some(snapshot_location);
+        backup.files = files;
+        Ok(backup)
+    }
+
+    /// Restores a backed up table into this newly created table.
+    ///
+    /// The files of the backup are copied under the storage prefix of this table, and the
+    /// segments and the snapshot are rewritten to reference them, so the restored table owns
+    /// all of its files.
+    #[async_backtrace::framed]
+    pub async fn restore(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        backup_op: &Operator,
+        root: &str,
+        backup: &BackupTable,
+    ) -> Result<()> {
+        let Some(snapshot_location) = &backup.snapshot_location else {
+            return Ok(());
+        };
+
+        let reader = MetaReaders::table_snapshot_reader_without_cache(backup_op.clone());
+        let params = LoadParams {
+            location: backup_path(root, &data_location(snapshot_location)),
+            len_hint: None,
+            ver: TableMetaLocationGenerator::snapshot_version(snapshot_location),
+            put_cache: false,
+        };
+        let snapshot = reader.read(&params).await?;
+
+        // blocks, bloom indexes and table statistics are copied as they are
+        let prefix = self.meta_location_generator().prefix();
+        let segments = snapshot
+            .segments
+            .iter()
+            .map(|(loc, _)| loc)
+            .collect::<HashSet<_>>();
+        let to_copy = backup
+            .files
+            .iter()
+            .filter(|loc| *loc != snapshot_location && !segments.contains(loc))
+            .map(|loc| {
+                (
+                    backup_path(root, &data_location(loc)),
+                    relocate(prefix, loc),
+                )
+            })
+            .collect::<Vec<_>>();
+        let copied = copy_files(ctx.clone(), backup_op, &self.operator, to_copy.clone()).await?;
+        let mut missing_blooms = HashSet::new();
+        for ((from, _), copied) in to_copy.iter().zip(copied) {
+            if copied {
+                continue;
+            }
+            // bloom index files are optional, the others must all be restored
+            if !is_bloom_index_location(from) {
+                return Err(ErrorCode::StorageNotFound(format!(
+                    "file {} of snapshot {} is missing in the backup of table {}",
+                    from, snapshot_location, backup.name
+                )));
+            }
+            missing_blooms.insert(from.clone());
+        }
+
+        let schema = Arc::new(snapshot.schema.clone());
+        let segment_reader =
+            MetaReaders::segment_info_reader_without_cache(backup_op.clone(), schema);
+        let mut new_segments = Vec::with_capacity(snapshot.segments.len());
+        for (loc, ver) in &snapshot.segments {
+            let params = LoadParams {
+                location: backup_path(root, &data_location(loc)),
+                len_hint: None,
+                ver: *ver,
+                put_cache: false,
+            };
+            let segment = SegmentInfo::try_from(segment_reader.read(&params).await?)?;
+            let blocks = segment
+                .blocks
+                .iter()
+                .map(|block| {
+                    let mut block = block.as_ref().clone();
+                    block.location.0 = relocate(prefix, &block.location.0);
+                    if let Some((bloom, ver)) = block.bloom_filter_index_location.take() {
+                        if missing_blooms.contains(&backup_path(root, &data_location(&bloom))) {
+                            block.bloom_filter_index_size = 0;
+                        } else {
+                            block.bloom_filter_index_location =
+                                Some((relocate(prefix, &bloom), ver));
+                        }
+                    }
+                    Arc::new(block)
+                })
+                .collect();
+            let new_segment = SegmentInfo::new(blocks, segment.summary.clone());
+            let new_location = self.meta_location_generator().gen_segment_info_location();
+            new_segment
+                .write_meta(&self.operator, &new_location)
+                .await?;
+            new_segments.push((new_location, SegmentInfo::VERSION));
+        }
+
+        let new_snapshot = TableSnapshot::new(
+            Uuid::new_v4(),
+            None,
+            &snapshot.timestamp,
+            None,
+            snapshot.schema.clone(),
+            snapshot.summary.clone(),
+            new_segments,
+            snapshot
+                .table_statistics_location
+                .as_ref()
+                .map(|loc| relocate(prefix, loc)),
+        );
+        info!(
+            "restore table {} from snapshot {}, {} files copied",
+            self.table_info.desc,
+            snapshot_location,
+            to_copy.len() - missing_blooms.len()
+        );
+        FuseTable::commit_to_meta_server(
+            ctx.as_ref(),
+            &self.table_info,
+            self.meta_location_generator(),
+            new_snapshot,
+            None,
+            &None,
+            self.get_operator_ref(),
+        )
+        .await
+    }
+}
+
+// e.g. `1/2/_i_b_v2/xxx.parquet`
+fn is_bloom_index_location(location: &str) -> bool {
+    location
+        .rsplit_once('/')
+        .and_then(|(dir, _)| dir.rsplit_once('/'))
+        .is_some_and(|(_, dir)| dir == FUSE_TBL_XOR_BLOOM_INDEX_PREFIX)
+}
+
+/// Copies `(from, to)` files from `src` to `dst`, files missing in `src` are ignored.
+///
+/// Returns whether each of the files is copied.
+async fn copy_files(
+    ctx: Arc<dyn TableContext>,
+    src: &Operator,
+    dst: &Operator,
+    files: Vec<(String, String)>,
+) -> Result<Vec<bool>> {
+    let tasks = files.into_iter().map(|(from, to)| {
+        let src = src.clone();
+        let dst = dst.clone();
+        async move {
+            match src.read(&from).await {
+                Ok(data) => {
+                    dst.write(&to, data).await?;
+                    Ok(true)
+                }
+                Err(e) if e.kind() == opendal::ErrorKind::NotFound => Ok(false),
+                Err(e) => Err(ErrorCode::from(e)),
+            }
+        }
+    });
+
+    let thread_nums = ctx.get_settings().get_max_threads()? as usize;
+    execute_futures_in_parallel(
+        tasks,
+        thread_nums,
+        thread_nums * 2,
+        "backup-copy-worker".to_owned(),
+    )
+    .await?
+    .into_iter()
+    .collect()
+}
+
+fn backup_path(root: &str, path: &str) -> String {
+    let root = root.trim_matches('/');
+    if root.is_empty() {
+        path.to_string()
+    } else {
+        format!("{}/{}", root, path)
+    }
+}
+
+fn data_location(location: &str) -> String {
+    format!("{}/{}", BACKUP_DATA_DIR, location)
+}
+
+/// Moves a file under the storage prefix of another table, e.g. `1/2/_b/xxx.parquet` is
+/// moved to `<prefix>/_b/xxx.parquet`.
+fn relocate(prefix: &str, location: &str) -> String {
+    let mut parts = location.rsplitn(3, '/');
+    let file = parts.next().unwrap_or_default();
+    match parts.next() {
+        Some(dir) => format!("{}/{}/{}", prefix, dir, file),
+        None => format!("{}/{}", prefix, file),
+    }
+}
@@ -0,0 +1,273 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::Filters;
+use databend_common_catalog::plan::Partitions;
+use databend_common_catalog::plan::Projection;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_metrics::cache::metrics_inc_cache_warmup_loaded_count;
+use databend_common_metrics::cache::metrics_inc_cache_warmup_scheduled_count;
+use databend_storages_common_cache::CacheAccessor;
+use databend_storages_common_cache::CacheManager;
+use databend_storages_common_cache::LoadParams;
+use databend_storages_common_cache::TableDataCacheKey;
+use databend_storages_common_index::BloomIndex;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use futures_util::future::try_join_all;
+use log::warn;
+
+use crate::io::BloomBlockFilterReader;
+use crate::io::MetaReaders;
+use crate::io::SegmentsIO;
+use crate::FuseBlockPartInfo;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Returns the partitions of the blocks selected by `filters`, projected on `columns`
+    /// (all the columns if `None`).
+    #[async_backtrace::framed]
+    pub async fn cache_warmup_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        columns: Option<&[String]>,
+        filters: Option<Filters>,
+    ) -> Result<Partitions> {
+        let schema = self.schema();
+        let projection = columns
+            .map(|columns| {
+                columns
+                    .iter()
+                    .map(|name| schema.index_of(name))
+                    .collect::<Result<Vec<_>>>()
+            })
+            .transpose()?
+            .map(Projection::Columns);
+        let push_downs = PushDownInfo {
+            projection,
+            filters,
+            ..Default::default()
+        };
+        let (_, parts) = self.do_read_partitions(ctx, Some(push_downs), true).await?;
+        Ok(parts)
+    }
+
+    /// Loads the segments, bloom filters and column data of the blocks of `parts` into the
+    /// caches of this node, and pins them if `pin` is set.
+    #[async_backtrace::framed]
+    pub async fn warm_up_cache(
+        &self,
+        parts: &Partitions,
+        columns: Option<&[String]>,
+        pin: bool,
+    ) -> Result<()> {
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(());
+        };
+        let segment_versions: HashMap<&str, u64> = snapshot
+            .segments
+            .iter()
+            .map(|(path, ver)| (path.as_str(), *ver))
+            .collect();
+
+        let schema = self.schema();
+        let fields = match columns {
+            Some(columns) => columns
+                .iter()
+                .map(|name| schema.field_with_name(name).cloned())
+                .collect::<Result<Vec<_>>>()?,
+            None => schema.fields().clone(),
+        };
+
+        let mut blocks = Vec::with_capacity(parts.partitions.len());
+        for part in &parts.partitions {
+            blocks.push(FuseBlockPartInfo::from_part(part)?);
+        }
+
+        let mut blocks_by_segment: BTreeMap<&str, Vec<usize>> = BTreeMap::new();
+        for block in &blocks {
+            if let Some(index) = &block.block_meta_index {
+                blocks_by_segment
+                    .entry(index.segment_location.as_str())
+                    .or_default()
+                    .push(index.block_idx);
+            }
+        }
+
+        for (segment_path, block_indexes) in blocks_by_segment {
+            // The segment was compacted away after the partitions were pruned.
+            let Some(ver) = segment_versions.get(segment_path) else {
+                continue;
+            };
+            let block_metas = self
+                .warm_up_segment(segment_path, *ver, schema.clone(), pin)
+                .await?;
+            for block_idx in block_indexes {
+                if let Some(block_meta) = block_metas.get(block_idx) {
+                    self.warm_up_bloom_filters(block_meta, &fields, pin).await;
+                }
+            }
+        }
+
+        for block in &blocks {
+            self.warm_up_column_data(block, pin).await?;
+        }
+        Ok(())
+    }
+
+    /// Unpins the cached items of this table on this node, returns the number of them.
+    pub fn unpin_cache(&self) -> usize {
+        let prefix = format!("{}/", self.meta_location_generator().prefix());
+        CacheManager::instance().unpin_by_prefix(&prefix)
+    }
+
+    async fn warm_up_segment(
+        &self,
+        segment_path: &str,
+        ver: u64,
+        schema: TableSchemaRef,
+        pin: bool,
+    ) -> Result<Arc<Vec<Arc<BlockMeta>>>> {
+        let segment = SegmentsIO::read_compact_segment(
+            self.get_operator(),
+            (segment_path.to_string(), ver),
+            schema,
+            true,
+        )
+        .await?;
+
+        let cache_manager = CacheManager::instance();
+        if let Some(cache) = cache_manager.get_table_segment_cache() {
+            warm_up_item(cache.name(), cache.contains_key(segment_path), || {
+                !pin || cache.pin(segment_path)
+            });
+        }
+
+        match cache_manager.get_segment_block_metas_cache() {
+            Some(cache) => match cache.get(segment_path) {
+                Some(block_metas) => Ok(block_metas),
+                None => Ok(cache.insert(segment_path.to_string(), segment.block_metas()?)),
+            },
+            None => Ok(Arc::new(segment.block_metas()?)),
+        }
+    }
+
+    /// Failing to load the bloom filters of a block is not fatal, the block is read without
+    /// them as well.
+    async fn warm_up_bloom_filters(
+        &self,
+        block_meta: &BlockMeta,
+        fields: &[TableField],
+        pin: bool,
+    ) {
+        let Some(location) = &block_meta.bloom_filter_index_location else {
+            return;
+        };
+        let (index_path, index_ver) = location;
+        let column_names = fields
+            .iter()
+            .filter_map(|field| BloomIndex::build_filter_column_name(*index_ver, field).ok())
+            .collect::<Vec<_>>();
+        if let Err(e) = location
+            .read_block_filter(
+                self.get_operator(),
+                &column_names,
+                block_meta.bloom_filter_index_size,
+            )
+            .await
+        {
+            warn!("failed to warm up bloom filters of {}: {}", index_path, e);
+            return;
+        }
+
+        let cache_manager = CacheManager::instance();
+        if let Some(cache) = cache_manager.get_bloom_index_meta_cache() {
+            warm_up_item(cache.name(), cache.contains_key(index_path), || {
+                !pin || cache.pin(index_path)
+            });
+        }
+
+        let Some(cache) = cache_manager.get_bloom_index_filter_cache() else {
+            return;
+        };
+        // The bloom meta is cached now, and the filters are keyed by their positions in it.
+        let load_params = LoadParams {
+            location: index_path.clone(),
+            len_hint: Some(block_meta.bloom_filter_index_size),
+            ver: 0,
+            put_cache: true,
+        };
+        let Ok(index_meta) = MetaReaders::bloom_index_meta_reader(self.get_operator())
+            .read(&load_params)
+            .await
+        else {
+            return;
+        };
+        for (idx, (name, _)) in index_meta.columns.iter().enumerate() {
+            if column_names.contains(name) {
+                let key = format!("{index_path}-{idx}");
+                warm_up_item(cache.name(), cache.contains_key(&key), || {
+                    !pin || cache.pin(&key)
+                });
+            }
+        }
+    }
+
+    async fn warm_up_column_data(&self, block: &FuseBlockPartInfo, pin: bool) -> Result<()> {
+        let Some(cache) = CacheManager::instance().get_table_data_cache() else {
+            return Ok(());
+        };
+
+        let operator = self.get_operator();
+        let futs = block.columns_meta.iter().map(|(column_id, column_meta)| {
+            let cache = cache.clone();
+            let operator = operator.clone();
+            async move {
+                let (offset, len) = column_meta.offset_length();
+                let key = TableDataCacheKey::new(&block.location, *column_id, offset, len);
+                if !cache.contains_key(key.as_ref()) {
+                    let data = operator
+                        .read_with(&block.location)
+                        .range(offset..offset + len)
+                        .await?;
+                    cache.insert_now(key.as_ref().to_owned(), data.to_bytes());
+                }
+                warm_up_item(cache.name(), cache.contains_key(key.as_ref()), || {
+                    !pin || cache.pin(key.as_ref())
+                });
+                Ok::<_, ErrorCode>(())
+            }
+        });
+        try_join_all(futs).await?;
+        Ok(())
+    }
+}
+
+/// Records the progress of warming up one item, which counts as loaded if it is cached and,
+/// when asked, pinned.
+fn warm_up_item(cache_name: &str, cached: bool, pin: impl FnOnce() -> bool) {
+    metrics_inc_cache_warmup_scheduled_count(1, cache_name);
+    if cached && pin() {
+        metrics_inc_cache_warmup_loaded_count(1, cache_name);
+    }
+}
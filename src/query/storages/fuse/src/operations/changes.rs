@@ -40,10 +40,10 @@ use databend_storages_common_table_meta::meta::BlockMeta;
 use databend_storages_common_table_meta::meta::Location;
 use databend_storages_common_table_meta::meta::SegmentInfo;
 use databend_storages_common_table_meta::meta::TableSnapshot;
+use databend_storages_common_table_meta::table::stream_offset_of_table;
 use databend_storages_common_table_meta::table::ChangeType;
 use databend_storages_common_table_meta::table::StreamMode;
 use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING_BEGIN_VER;
-use databend_storages_common_table_meta::table::OPT_KEY_TABLE_VER;
 use log::info;
 
 use crate::io::SegmentsIO;
@@ -78,11 +78,16 @@ impl FuseTable {
         };
         let location = source.snapshot_loc();
         let seq = match navigation {
-            Some(NavigationPoint::StreamInfo(info)) => info
-                .options()
-                .get(OPT_KEY_TABLE_VER)
-                .ok_or_else(|| ErrorCode::Internal("table version must be set"))?
-                .parse::<u64>()?,
+            Some(NavigationPoint::StreamInfo(info)) => {
+                stream_offset_of_table(info.options(), self.table_info.ident.table_id)?
+                    .ok_or_else(|| {
+                        ErrorCode::IllegalStream(format!(
+                            "The stream '{}' is not match the table '{}'",
+                            info.desc, self.table_info.desc
+                        ))
+                    })?
+                    .0
+            }
             Some(_) => {
                 if let Some(snapshot_loc) = &location {
                     let (snapshot, _) =
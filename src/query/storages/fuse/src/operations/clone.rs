@@ -0,0 +1,488 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_storages_common_cache::LoadParams;
+use databend_storages_common_table_meta::meta::TableSnapshot;
+use databend_storages_common_table_meta::table::OPT_KEY_CLONED_FROM;
+use databend_storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
+use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use log::info;
+use log::warn;
+use opendal::Operator;
+use uuid::Uuid;
+
+use crate::io::MetaReaders;
+use crate::io::SnapshotLiteExtended;
+use crate::io::SnapshotsIO;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseTable;
+use crate::FUSE_TBL_CLONE_PREFIX;
+
+/// Locations which must be kept while purging a table.
+///
+/// A purge may only remove the files that are owned by the table (i.e. under its own
+/// storage prefix), and must keep the files that are still referenced by the snapshots
+/// of the tables cloned from it.
+///
+/// If the files referenced by some clone could not be resolved, none of the files of the
+/// table are purgeable.
+pub struct SharedLocations {
+    owner_prefix: String,
+    referenced_by_clones: HashSet<String>,
+    unresolved: bool,
+}
+
+impl SharedLocations {
+    pub fn create(owner_prefix: &str, referenced_by_clones: HashSet<String>) -> Self {
+        Self {
+            owner_prefix: format!("{}/", owner_prefix),
+            referenced_by_clones,
+            unresolved: false,
+        }
+    }
+
+    pub fn create_unresolved(owner_prefix: &str) -> Self {
+        Self {
+            owner_prefix: format!("{}/", owner_prefix),
+            referenced_by_clones: HashSet::new(),
+            unresolved: true,
+        }
+    }
+
+    pub fn is_purgeable(&self, location: &str) -> bool {
+        !self.unresolved
+            && location.starts_with(&self.owner_prefix)
+            && !self.referenced_by_clones.contains(location)
+    }
+
+    pub fn retain_purgeable(&self, locations: &mut HashSet<String>) {
+        locations.retain(|loc| self.is_purgeable(loc));
+    }
+
+    pub fn is_referenced_by_clones(&self, location: &str) -> bool {
+        self.unresolved || self.referenced_by_clones.contains(location)
+    }
+}
+
+impl FuseTable {
+    /// Makes `target` a zero-copy clone of the current snapshot of this table.
+    #[async_backtrace::framed]
+    pub async fn do_clone_to(&self, ctx: Arc<dyn TableContext>, target: &FuseTable) -> Result<()> {
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            // nothing to share, the clone is an empty table
+            return Ok(());
+        };
+
+        info!(
+            "clone table {} from {}, snapshot {}",
+            target.table_info.desc, self.table_info.desc, snapshot.snapshot_id
+        );
+        let location = self.snapshot_loc().unwrap_or_default();
+        target
+            .commit_shared_snapshot(ctx, &location, &snapshot)
+            .await
+    }
+
+    /// Commits a new snapshot of this table which references the segments of `snapshot` directly.
+    ///
+    /// Before it is committed, a marker is left in the storage prefix of every table that owns
+    /// some of the referenced files, so that purging those tables will keep them. The marker
+    /// records the location of `snapshot`, whose files are kept until the clone is committed.
+    #[async_backtrace::framed]
+    async fn commit_shared_snapshot(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        snapshot_location: &str,
+        snapshot: &TableSnapshot,
+    ) -> Result<()> {
+        let owners = self.snapshot_owner_prefixes(ctx.clone(), snapshot).await?;
+        let clone_table_id = self.get_id();
+        for owner in &owners {
+            let marker = clone_marker_location(owner, clone_table_id);
+            self.operator
+                .write(&marker, snapshot_location.as_bytes().to_vec())
+                .await?;
+        }
+        info!(
+            "table {} shares the files of {:?}",
+            self.table_info.desc, owners
+        );
+
+        let new_snapshot = TableSnapshot::new(
+            Uuid::new_v4(),
+            None,
+            &snapshot.timestamp,
+            None,
+            snapshot.schema.clone(),
+            snapshot.summary.clone(),
+            snapshot.segments.clone(),
+            snapshot.table_statistics_location.clone(),
+        );
+
+        let mut table_info = self.table_info.clone();
+        table_info.meta.options.insert(
+            OPT_KEY_CLONED_FROM.to_owned(),
+            owners.into_iter().collect::<Vec<_>>().join(","),
+        );
+
+        let res = FuseTable::commit_to_meta_server(
+            ctx.as_ref(),
+            &table_info,
+            self.meta_location_generator(),
+            new_snapshot,
+            None,
+            &None,
+            self.get_operator_ref(),
+        )
+        .await;
+        if res.is_err() {
+            // the clone never references the files, do not keep them for it
+            if let Err(e) =
+                unregister_clone(&self.operator, table_info.options(), clone_table_id).await
+            {
+                warn!(
+                    "failed to remove the clone markers of table {}: {}",
+                    self.table_info.desc, e
+                );
+            }
+        }
+        res
+    }
+
+    /// Storage prefixes of the tables that own the files referenced by the given snapshot.
+    #[async_backtrace::framed]
+    async fn snapshot_owner_prefixes(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        snapshot: &TableSnapshot,
+    ) -> Result<BTreeSet<String>> {
+        let mut owners = BTreeSet::new();
+        for loc in self.snapshot_locations(ctx, snapshot).await? {
+            owners.insert(owner_prefix_of_location(&loc)?.to_owned());
+        }
+        Ok(owners)
+    }
+
+    /// Locations of the segments, blocks, bloom indexes and table statistics referenced by the
+    /// given snapshot.
+    #[async_backtrace::framed]
+    async fn snapshot_locations(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        snapshot: &TableSnapshot,
+    ) -> Result<Vec<String>> {
+        let locations = self
+            .get_block_locations(ctx, &snapshot.segments, false, false)
+            .await?;
+        Ok(snapshot
+            .segments
+            .iter()
+            .map(|loc| loc.0.clone())
+            .chain(snapshot.table_statistics_location.iter().cloned())
+            .chain(locations.block_location)
+            .chain(locations.bloom_location)
+            .collect())
+    }
+
+    /// Collects the files of this table which are still referenced by any snapshot of its
+    /// clones, including the snapshots a clone may still travel back to.
+    ///
+    /// Markers of clones that no longer exist in the meta service, or none of whose snapshots
+    /// reference any file of this table, are removed unless it is a dry run.
+    #[async_backtrace::framed]
+    pub async fn shared_locations(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        dry_run: bool,
+    ) -> Result<SharedLocations> {
+        let owner_prefix = self.meta_location_generator().prefix();
+        let owned = format!("{}/", owner_prefix);
+        let mut referenced_by_clones = HashSet::new();
+
+        let marker_prefix = format!("{}/{}/", owner_prefix, FUSE_TBL_CLONE_PREFIX);
+        let entries = self.operator.list(&marker_prefix).await?;
+        if entries.is_empty() {
+            return Ok(SharedLocations::create(owner_prefix, referenced_by_clones));
+        }
+
+        // the markers are written by `do_clone_to`, named after the table id of the clone
+        let catalog = ctx.get_catalog(self.get_table_info().catalog()).await?;
+        for entry in entries {
+            let Ok(clone_table_id) = entry.name().parse::<u64>() else {
+                continue;
+            };
+
+            let Some(clone_meta) = catalog.get_table_meta_by_id(clone_table_id).await? else {
+                info!(
+                    "clone {} of table {} no longer exists, remove marker {}",
+                    clone_table_id,
+                    self.table_info.desc,
+                    entry.path()
+                );
+                if !dry_run {
+                    self.operator.delete(entry.path()).await?;
+                }
+                continue;
+            };
+
+            let Some((location, snapshot)) =
+                self.read_clone_snapshot(&clone_meta.data.options).await?
+            else {
+                // the clone is not committed yet, keep the files of the snapshot it is cloned from
+                match self
+                    .marker_snapshot_locations(ctx.clone(), entry.path())
+                    .await?
+                {
+                    Some(locations) => {
+                        referenced_by_clones
+                            .extend(locations.into_iter().filter(|loc| loc.starts_with(&owned)));
+                    }
+                    None => {
+                        warn!(
+                            "files shared with clone {} of table {} are unknown, skip purging them",
+                            clone_table_id, self.table_info.desc
+                        );
+                        return Ok(SharedLocations::create_unresolved(owner_prefix));
+                    }
+                }
+                continue;
+            };
+
+            // a marker is only removed if all the files of the clone are known
+            let (locations, complete) = self
+                .clone_snapshots_locations(ctx.clone(), clone_table_id, &location, &snapshot)
+                .await?;
+            let mut referenced = false;
+            for loc in locations {
+                if loc.starts_with(&owned) {
+                    referenced = true;
+                    referenced_by_clones.insert(loc);
+                }
+            }
+
+            if complete && !referenced && !dry_run {
+                info!(
+                    "clone {} of table {} no longer references its files, remove marker {}",
+                    clone_table_id,
+                    self.table_info.desc,
+                    entry.path()
+                );
+                self.operator.delete(entry.path()).await?;
+            }
+        }
+
+        Ok(SharedLocations::create(owner_prefix, referenced_by_clones))
+    }
+
+    /// Locations referenced by the current snapshot of a clone and by all its older snapshots
+    /// which are still present, and whether all of them could be read.
+    #[async_backtrace::framed]
+    async fn clone_snapshots_locations(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        clone_table_id: u64,
+        root_location: &str,
+        root_snapshot: &TableSnapshot,
+    ) -> Result<(Vec<String>, bool)> {
+        let mut complete = true;
+        let root_lite = Arc::new(SnapshotLiteExtended {
+            format_version: TableMetaLocationGenerator::snapshot_version(root_location),
+            snapshot_id: root_snapshot.snapshot_id,
+            timestamp: root_snapshot.timestamp,
+            segments: HashSet::from_iter(root_snapshot.segments.clone()),
+            table_statistics_location: root_snapshot.table_statistics_location.clone(),
+        });
+        let mut segments = root_lite.segments.clone();
+        let mut table_statistics = root_snapshot
+            .table_statistics_location
+            .iter()
+            .cloned()
+            .collect::<HashSet<_>>();
+
+        if let Some(prefix) = SnapshotsIO::get_s3_prefix_from_file(root_location) {
+            let snapshot_files =
+                SnapshotsIO::list_files(self.get_operator(), &prefix, Some(root_location)).await?;
+            let snapshots_io = SnapshotsIO::create(ctx.clone(), self.get_operator());
+            let chunk_size = ctx.get_settings().get_max_threads()? as usize;
+            for chunk in snapshot_files.chunks(chunk_size.max(1)) {
+                let results = snapshots_io
+                    .read_snapshot_lite_extends(chunk, root_lite.clone(), true)
+                    .await?;
+                for result in results {
+                    match result {
+                        Ok(lite) => {
+                            segments.extend(lite.segments);
+                            table_statistics.extend(lite.table_statistics_location);
+                        }
+                        // purged concurrently, it references nothing any more
+                        Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => {}
+                        Err(e) => {
+                            warn!(
+                                "failed to read a snapshot of clone {} of table {}: {}",
+                                clone_table_id, self.table_info.desc, e
+                            );
+                            complete = false;
+                        }
+                    }
+                }
+            }
+        }
+
+        let segments = segments.into_iter().collect::<Vec<_>>();
+        let locations = match self
+            .get_block_locations(ctx.clone(), &segments, false, false)
+            .await
+        {
+            Ok(locations) => locations,
+            Err(e) => {
+                warn!(
+                    "failed to read the files of clone {} of table {}: {}",
+                    clone_table_id, self.table_info.desc, e
+                );
+                complete = false;
+                self.get_block_locations(ctx, &segments, false, true)
+                    .await?
+            }
+        };
+        let locations = segments
+            .into_iter()
+            .map(|loc| loc.0)
+            .chain(table_statistics)
+            .chain(locations.block_location)
+            .chain(locations.bloom_location)
+            .collect();
+        Ok((locations, complete))
+    }
+
+    /// Locations referenced by the snapshot recorded in a clone marker, `None` if they could
+    /// not be read.
+    #[async_backtrace::framed]
+    async fn marker_snapshot_locations(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        marker: &str,
+    ) -> Result<Option<Vec<String>>> {
+        let content = self.operator.read(marker).await?.to_vec();
+        let location = String::from_utf8(content).unwrap_or_default();
+        if location.is_empty() {
+            return Ok(None);
+        }
+
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let params = LoadParams {
+            location: location.clone(),
+            len_hint: None,
+            ver: TableMetaLocationGenerator::snapshot_version(&location),
+            put_cache: false,
+        };
+        let snapshot = match reader.read(&params).await {
+            Ok(snapshot) => snapshot,
+            Err(e) => {
+                warn!(
+                    "failed to read snapshot {} of marker {}: {}",
+                    location, marker, e
+                );
+                return Ok(None);
+            }
+        };
+        match self.snapshot_locations(ctx, &snapshot).await {
+            Ok(locations) => Ok(Some(locations)),
+            Err(e) => {
+                warn!("failed to read the files of snapshot {}: {}", location, e);
+                Ok(None)
+            }
+        }
+    }
+
+    async fn read_clone_snapshot(
+        &self,
+        options: &BTreeMap<String, String>,
+    ) -> Result<Option<(String, Arc<TableSnapshot>)>> {
+        let Some(location) = options
+            .get(OPT_KEY_SNAPSHOT_LOCATION)
+            .or_else(|| options.get(OPT_KEY_LEGACY_SNAPSHOT_LOC))
+        else {
+            return Ok(None);
+        };
+
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let params = LoadParams {
+            location: location.clone(),
+            len_hint: None,
+            ver: TableMetaLocationGenerator::snapshot_version(location),
+            put_cache: false,
+        };
+        match reader.read(&params).await {
+            Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => {
+                warn!("snapshot {} of clone not found, ignored", location);
+                Ok(None)
+            }
+            Err(e) => Err(e),
+            Ok(snapshot) => Ok(Some((location.clone(), snapshot))),
+        }
+    }
+}
+
+/// Removes the markers a clone left in the storage prefixes of the tables it was cloned from.
+#[async_backtrace::framed]
+pub async fn unregister_clone(
+    operator: &Operator,
+    options: &BTreeMap<String, String>,
+    clone_table_id: u64,
+) -> Result<()> {
+    if let Some(owners) = options.get(OPT_KEY_CLONED_FROM) {
+        for owner in owners.split(',').filter(|s| !s.is_empty()) {
+            operator
+                .delete(&clone_marker_location(owner, clone_table_id))
+                .await?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns true if some clones of the table, which stores its data under `prefix`,
+/// may still reference its files.
+#[async_backtrace::framed]
+pub async fn has_clone_markers(operator: &Operator, prefix: &str) -> Result<bool> {
+    let marker_prefix = format!("{}/{}/", prefix, FUSE_TBL_CLONE_PREFIX);
+    let entries = operator.list(&marker_prefix).await?;
+    Ok(entries.iter().any(|e| e.name().parse::<u64>().is_ok()))
+}
+
+fn clone_marker_location(owner_prefix: &str, clone_table_id: u64) -> String {
+    format!(
+        "{}/{}/{}",
+        owner_prefix, FUSE_TBL_CLONE_PREFIX, clone_table_id
+    )
+}
+
+// e.g. `1/2/_b/xxx.parquet` is owned by the table stored under `1/2`
+fn owner_prefix_of_location(location: &str) -> Result<&str> {
+    location
+        .rsplit_once('/')
+        .and_then(|(dir, _)| dir.rsplit_once('/'))
+        .map(|(prefix, _)| prefix)
+        .ok_or_else(|| {
+            ErrorCode::StorageOther(format!("unexpected fuse table file location: {}", location))
+        })
+}
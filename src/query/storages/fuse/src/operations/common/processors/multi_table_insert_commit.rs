@@ -122,6 +122,7 @@ impl AsyncSink for CommitMultiTableInsert {
                 update_stream_metas: self.update_stream_meta.clone(),
                 deduplicated_labels: self.deduplicated_label.clone().into_iter().collect(),
                 update_temp_tables: std::mem::take(&mut update_temp_tables),
+                ..Default::default()
             };
 
             let update_meta_result = match self
@@ -43,7 +43,9 @@ use crate::operations::common::BlockMetaIndex;
 use crate::operations::common::MutationLogEntry;
 use crate::operations::common::MutationLogs;
 use crate::operations::mutation::ClusterStatsGenType;
+use crate::operations::mutation::DeletionVectorBlock;
 use crate::operations::mutation::SerializeDataMeta;
+use crate::statistics::gen_columns_statistics;
 use crate::statistics::ClusterStatsGenerator;
 use crate::FuseTable;
 
@@ -59,6 +61,11 @@ enum State {
         serialized: BlockSerialization,
         index: Option<BlockMetaIndex>,
     },
+    // The live rows of a block with deletion vector, to generate its column statistics.
+    NeedStatistics {
+        block: DataBlock,
+        deletion_vector_block: DeletionVectorBlock,
+    },
 }
 
 pub struct TransformSerializeBlock {
@@ -185,7 +192,10 @@ impl Processor for TransformSerializeBlock {
     }
 
     fn event(&mut self) -> Result<Event> {
-        if matches!(self.state, State::NeedSerialize { .. }) {
+        if matches!(
+            self.state,
+            State::NeedSerialize { .. } | State::NeedStatistics { .. }
+        ) {
             return Ok(Event::Sync);
         }
 
@@ -247,6 +257,14 @@ impl Processor for TransformSerializeBlock {
                         Ok(Event::Sync)
                     }
                 }
+                SerializeDataMeta::DeletionVector(deletion_vector_block) => {
+                    // the deleted rows are recorded in the block meta, block level
+                    self.state = State::NeedStatistics {
+                        block: input_data,
+                        deletion_vector_block,
+                    };
+                    Ok(Event::Sync)
+                }
                 SerializeDataMeta::CompactExtras(compact_extras) => {
                     // compact extras
                     let data_block = Self::mutation_logs(MutationLogEntry::CompactExtras {
@@ -295,6 +313,21 @@ impl Processor for TransformSerializeBlock {
 
                 self.state = State::Serialized { serialized, index };
             }
+            State::NeedStatistics {
+                block,
+                deletion_vector_block,
+            } => {
+                // the statistics of the block only describe the live rows
+                let mut block_meta = deletion_vector_block.block_meta.as_ref().clone();
+                block_meta.col_stats =
+                    gen_columns_statistics(&block, None, &self.block_builder.source_schema)?;
+                block_meta.block_size = block.memory_size() as u64;
+
+                self.output_data = Some(Self::mutation_logs(MutationLogEntry::ReplacedBlock {
+                    index: deletion_vector_block.index,
+                    block_meta: Arc::new(block_meta),
+                }));
+            }
             _ => return Err(ErrorCode::Internal("It's a bug.")),
         }
         Ok(())
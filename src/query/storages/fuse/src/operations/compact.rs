@@ -125,7 +125,12 @@ impl FuseTable {
             return Ok(None);
         };
 
-        if base_snapshot.summary.block_count <= 1 {
+        // A single block is only compacted to fold its deletion vector.
+        let summary = &base_snapshot.summary;
+        if summary.block_count == 0
+            || summary.block_count == 1
+                && (summary.perfect_block_count == 1 || !self.deletion_vector_enabled())
+        {
             return Ok(None);
         }
 
@@ -45,6 +45,7 @@ use crate::io::SegmentsIO;
 use crate::io::SnapshotLiteExtended;
 use crate::io::SnapshotsIO;
 use crate::io::TableMetaLocationGenerator;
+use crate::operations::SharedLocations;
 use crate::FuseTable;
 use crate::FUSE_TBL_SNAPSHOT_PREFIX;
 
@@ -83,7 +84,9 @@ impl FuseTable {
         dry_run: bool,
     ) -> Result<Option<Vec<String>>> {
         // 1. Read the root snapshot.
-        let root_snapshot_info_opt = self.read_root_snapshot(ctx, keep_last_snapshot).await?;
+        let root_snapshot_info_opt = self
+            .read_root_snapshot(ctx, keep_last_snapshot, dry_run)
+            .await?;
         if root_snapshot_info_opt.is_none() {
             if dry_run {
                 return Ok(Some(vec![]));
@@ -195,6 +198,7 @@ impl FuseTable {
                         ctx,
                         &mut dry_run_purge_files,
                         &root_snapshot_info.referenced_locations,
+                        &root_snapshot_info.shared_locations,
                         segments_to_be_purged,
                         ts_to_be_purged,
                         snapshots_to_be_purged,
@@ -210,6 +214,7 @@ impl FuseTable {
                         ctx,
                         counter,
                         &root_snapshot_info.referenced_locations,
+                        &root_snapshot_info.shared_locations,
                         segments_to_be_purged,
                         ts_to_be_purged,
                         snapshots_to_be_purged,
@@ -251,6 +256,7 @@ impl FuseTable {
                     ctx,
                     &mut dry_run_purge_files,
                     &root_snapshot_info.referenced_locations,
+                    &root_snapshot_info.shared_locations,
                     segments_to_be_purged,
                     ts_to_be_purged,
                     snapshots_to_be_purged,
@@ -262,6 +268,7 @@ impl FuseTable {
                     ctx,
                     counter,
                     &root_snapshot_info.referenced_locations,
+                    &root_snapshot_info.shared_locations,
                     segments_to_be_purged,
                     ts_to_be_purged,
                     snapshots_to_be_purged,
@@ -283,6 +290,7 @@ impl FuseTable {
                 counter,
                 root_snapshot_info.snapshot_lite,
                 root_snapshot_info.referenced_locations,
+                &root_snapshot_info.shared_locations,
                 root_snapshot_info.snapshot_location,
                 &table_agg_index_ids,
                 inverted_indexes,
@@ -297,6 +305,7 @@ impl FuseTable {
         &self,
         ctx: &Arc<dyn TableContext>,
         put_cache: bool,
+        dry_run: bool,
     ) -> Result<Option<RootSnapshotInfo>> {
         let root_snapshot_location_op = self.snapshot_loc();
         if root_snapshot_location_op.is_none() {
@@ -336,9 +345,13 @@ impl FuseTable {
             segments: HashSet::from_iter(root_snapshot.segments.clone()),
             table_statistics_location: root_snapshot.table_statistics_location.clone(),
         });
+        // files of this table referenced by its clones, and files of other tables
+        // referenced by this table (if it is a clone) should be kept.
+        let shared_locations = self.shared_locations(ctx.clone(), dry_run).await?;
         Ok(Some(RootSnapshotInfo {
             snapshot_location,
             referenced_locations,
+            shared_locations,
             snapshot_lite,
         }))
     }
@@ -349,6 +362,7 @@ impl FuseTable {
         ctx: &Arc<dyn TableContext>,
         purge_files: &mut Vec<String>,
         locations_referenced_by_root: &LocationTuple,
+        shared_locations: &SharedLocations,
         segments_to_be_purged: HashSet<Location>,
         ts_to_be_purged: HashSet<String>,
         snapshots_to_be_purged: HashSet<String>,
@@ -364,7 +378,9 @@ impl FuseTable {
                 .await?;
 
             for loc in &locations.block_location {
-                if locations_referenced_by_root.block_location.contains(loc) {
+                if locations_referenced_by_root.block_location.contains(loc)
+                    || !shared_locations.is_purgeable(loc)
+                {
                     continue;
                 }
                 purge_files.push(loc.to_string());
@@ -378,15 +394,27 @@ impl FuseTable {
             }
 
             for loc in &locations.bloom_location {
-                if locations_referenced_by_root.bloom_location.contains(loc) {
+                if locations_referenced_by_root.bloom_location.contains(loc)
+                    || !shared_locations.is_purgeable(loc)
+                {
                     continue;
                 }
                 purge_files.push(loc.to_string())
             }
 
-            purge_files.extend(chunk.iter().map(|loc| loc.0.clone()));
+            purge_files.extend(
+                chunk
+                    .iter()
+                    .filter(|loc| shared_locations.is_purgeable(&loc.0))
+                    .map(|loc| loc.0.clone()),
+            );
         }
-        purge_files.extend(ts_to_be_purged.iter().map(|loc| loc.to_string()));
+        purge_files.extend(
+            ts_to_be_purged
+                .iter()
+                .filter(|loc| shared_locations.is_purgeable(loc))
+                .map(|loc| loc.to_string()),
+        );
         purge_files.extend(snapshots_to_be_purged.iter().map(|loc| loc.to_string()));
 
         Ok(())
@@ -398,8 +426,9 @@ impl FuseTable {
         ctx: &Arc<dyn TableContext>,
         counter: &mut PurgeCounter,
         locations_referenced_by_root: &LocationTuple,
+        shared_locations: &SharedLocations,
         segments_to_be_purged: HashSet<Location>,
-        ts_to_be_purged: HashSet<String>,
+        mut ts_to_be_purged: HashSet<String>,
         snapshots_to_be_purged: HashSet<String>,
         table_agg_index_ids: &[u64],
         inverted_indexes: &BTreeMap<String, TableIndex>,
@@ -418,7 +447,9 @@ impl FuseTable {
             let mut agg_indexes_to_be_purged = HashSet::new();
             let mut inverted_indexes_to_be_purged = HashSet::new();
             for loc in &locations.block_location {
-                if locations_referenced_by_root.block_location.contains(loc) {
+                if locations_referenced_by_root.block_location.contains(loc)
+                    || !shared_locations.is_purgeable(loc)
+                {
                     continue;
                 }
                 blocks_to_be_purged.insert(loc.to_string());
@@ -443,7 +474,9 @@ impl FuseTable {
 
             let mut blooms_to_be_purged = HashSet::new();
             for loc in &locations.bloom_location {
-                if locations_referenced_by_root.bloom_location.contains(loc) {
+                if locations_referenced_by_root.bloom_location.contains(loc)
+                    || !shared_locations.is_purgeable(loc)
+                {
                     continue;
                 }
                 blooms_to_be_purged.insert(loc.to_string());
@@ -452,6 +485,7 @@ impl FuseTable {
             let segment_locations_to_be_purged = HashSet::from_iter(
                 chunk
                     .iter()
+                    .filter(|loc| shared_locations.is_purgeable(&loc.0))
                     .map(|loc| loc.0.clone())
                     .collect::<Vec<String>>(),
             );
@@ -480,6 +514,7 @@ impl FuseTable {
             .await?;
         }
 
+        shared_locations.retain_purgeable(&mut ts_to_be_purged);
         self.purge_ts_snapshots(ctx, counter, ts_to_be_purged, snapshots_to_be_purged)
             .await
     }
@@ -489,7 +524,8 @@ impl FuseTable {
         ctx: &Arc<dyn TableContext>,
         counter: &mut PurgeCounter,
         root_snapshot: Arc<SnapshotLiteExtended>,
-        root_location_tuple: LocationTuple,
+        mut root_location_tuple: LocationTuple,
+        shared_locations: &SharedLocations,
         root_snapshot_location: String,
         table_agg_index_ids: &[u64],
         inverted_indexes: &BTreeMap<String, TableIndex>,
@@ -498,9 +534,12 @@ impl FuseTable {
             root_snapshot
                 .segments
                 .iter()
+                .filter(|loc| shared_locations.is_purgeable(&loc.0))
                 .map(|loc| loc.0.clone())
                 .collect::<Vec<_>>(),
         );
+        shared_locations.retain_purgeable(&mut root_location_tuple.block_location);
+        shared_locations.retain_purgeable(&mut root_location_tuple.bloom_location);
 
         let mut agg_indexes_to_be_purged = HashSet::new();
         let mut inverted_indexes_to_be_purged = HashSet::new();
@@ -543,6 +582,7 @@ impl FuseTable {
         if let Some(ts) = root_snapshot.table_statistics_location.clone() {
             ts_to_be_purged.insert(ts);
         }
+        shared_locations.retain_purgeable(&mut ts_to_be_purged);
         self.purge_ts_snapshots(
             ctx,
             counter,
@@ -753,6 +793,7 @@ impl FuseTable {
 struct RootSnapshotInfo {
     snapshot_location: String,
     referenced_locations: LocationTuple,
+    shared_locations: SharedLocations,
     snapshot_lite: Arc<SnapshotLiteExtended>,
 }
 
@@ -403,10 +403,18 @@ impl AggregationContext {
             origin_data_block.add_column(row_num);
         }
 
-        // apply delete
+        // apply delete, the rows deleted by deletion vector are removed as well
+        let deleted_rows = match &block_meta.deletion_vector {
+            Some(deletion_vector) => Some(deletion_vector.deleted()?),
+            None => None,
+        };
         let mut bitmap = MutableBitmap::new();
         for row in 0..origin_num_rows {
-            if modified_offsets.contains(&row) {
+            if modified_offsets.contains(&row)
+                || deleted_rows
+                    .as_ref()
+                    .is_some_and(|deleted| deleted.contains(row as u32))
+            {
                 bitmap.push(false);
             } else {
                 bitmap.push(true);
@@ -15,7 +15,10 @@
 mod agg_index_sink;
 mod analyze;
 mod append;
+mod backup;
+mod cache_warmup;
 mod changes;
+mod clone;
 mod commit;
 mod common;
 mod compact;
@@ -34,11 +37,18 @@ mod replace;
 mod replace_into;
 mod revert;
 mod truncate;
+mod ttl;
 mod util;
 
 pub use agg_index_sink::AggIndexSink;
 pub use analyze::HistogramInfoSink;
+pub use backup::BackupManifest;
+pub use backup::BackupSnapshot;
+pub use backup::BackupTable;
 pub use changes::ChangesDesc;
+pub use clone::has_clone_markers;
+pub use clone::unregister_clone;
+pub use clone::SharedLocations;
 pub use common::*;
 pub use compact::CompactOptions;
 pub use merge_into::*;
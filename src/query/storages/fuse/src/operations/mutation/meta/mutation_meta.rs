@@ -30,6 +30,7 @@ pub enum SerializeDataMeta {
     SerializeBlock(SerializeBlock),
     DeletedSegment(DeletedSegmentInfo),
     CompactExtras(CompactExtraInfo),
+    DeletionVector(DeletionVectorBlock),
 }
 
 #[typetag::serde(name = "serialize_data_meta")]
@@ -61,6 +62,14 @@ impl SerializeBlock {
     }
 }
 
+/// The rows deleted from a block are recorded in the deletion vector of its meta,
+/// the block is not rewritten.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct DeletionVectorBlock {
+    pub index: BlockMetaIndex,
+    pub block_meta: Arc<BlockMeta>,
+}
+
 pub enum CompactSourceMeta {
     Concat {
         read_res: Vec<BlockReadResult>,
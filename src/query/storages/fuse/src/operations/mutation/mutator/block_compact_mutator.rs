@@ -322,7 +322,13 @@ impl SegmentCompactChecker {
 
         if segments.len() == 1 {
             let summary = &segments[0].1.summary;
-            if (summary.block_count == 1 || summary.perfect_block_count == summary.block_count)
+            // The block with deletion vector needs to be compacted.
+            let single_block = summary.block_count == 1
+                && segments[0]
+                    .1
+                    .block_metas()
+                    .is_ok_and(|blocks| blocks.iter().all(|block| block.deletion_vector.is_none()));
+            if (single_block || summary.perfect_block_count == summary.block_count)
                 && (self.cluster_key_id.is_none()
                     || self.cluster_key_id
                         == summary.cluster_stats.as_ref().map(|v| v.cluster_key_id))
@@ -481,6 +487,11 @@ impl CompactTaskBuilder {
     }
 
     fn check_compact(&self, block: &Arc<BlockMeta>) -> bool {
+        // Fold the deletion vector into a new block.
+        if block.deletion_vector.is_some() {
+            return true;
+        }
+
         // The snapshot schema does not contain stream columns,
         // so the stream columns need to be filtered out.
         let column_ids = block
@@ -19,8 +19,14 @@ use databend_common_base::base::Progress;
 use databend_common_base::base::ProgressValues;
 use databend_common_catalog::plan::gen_mutation_stream_meta;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::BooleanType;
 use databend_common_expression::DataBlock;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_metrics::storage::*;
 use databend_common_pipeline_sources::PrefetchAsyncSource;
 use databend_common_pipeline_transforms::processors::BlockMetaTransform;
@@ -129,21 +135,27 @@ pub struct CompactTransform {
     block_reader: Arc<BlockReader>,
     storage_format: FuseStorageFormat,
     stream_ctx: Option<StreamContext>,
+    func_ctx: FunctionContext,
+    // rows expired by the ttl of the table are removed while the blocks are compacted.
+    ttl_filter: Option<Expr>,
 }
 
 impl CompactTransform {
-    pub fn create(
+    pub fn try_create(
         ctx: Arc<dyn TableContext>,
         block_reader: Arc<BlockReader>,
         storage_format: FuseStorageFormat,
         stream_ctx: Option<StreamContext>,
-    ) -> Self {
-        Self {
+        ttl_filter: Option<Expr>,
+    ) -> Result<Self> {
+        Ok(Self {
             scan_progress: ctx.get_scan_progress(),
             block_reader,
             storage_format,
             stream_ctx,
-        }
+            func_ctx: ctx.get_function_context()?,
+            ttl_filter,
+        })
     }
 }
 
@@ -168,6 +180,12 @@ impl BlockMetaTransform<CompactSourceMeta> for CompactTransform {
                             &self.storage_format,
                             data,
                         )?;
+                        // Fold the deletion vector into the compacted block.
+                        if let Some(deletion_vector) = &meta.deletion_vector {
+                            let num_rows = block.num_rows();
+                            block =
+                                block.filter_with_bitmap(&deletion_vector.live_rows(num_rows)?)?;
+                        }
 
                         self.scan_progress.incr(&ProgressValues {
                             rows: block.num_rows(),
@@ -187,6 +205,23 @@ impl BlockMetaTransform<CompactSourceMeta> for CompactTransform {
                 } else {
                     DataBlock::concat(&blocks)?
                 };
+                let block = match &self.ttl_filter {
+                    Some(filter) => {
+                        let evaluator = Evaluator::new(&block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+                        let alive = evaluator
+                            .run(filter)
+                            .map_err(|e| e.add_message("eval ttl filter failed:"))?
+                            .try_downcast::<BooleanType>()
+                            .ok_or_else(|| {
+                                ErrorCode::Internal(format!(
+                                    "ttl filter {} must be of type BOOLEAN",
+                                    filter
+                                ))
+                            })?;
+                        block.filter_boolean_value(&alive)?
+                    }
+                    None => block,
+                };
 
                 let meta = Box::new(SerializeDataMeta::SerializeBlock(SerializeBlock::create(
                     index,
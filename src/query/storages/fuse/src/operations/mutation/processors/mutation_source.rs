@@ -39,11 +39,15 @@ use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_sql::evaluator::BlockOperator;
 use databend_common_storage::MutationStatus;
 use databend_storages_common_io::ReadSettings;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use databend_storages_common_table_meta::meta::DeletionVector;
+use roaring::RoaringBitmap;
 
 use crate::fuse_part::FuseBlockPartInfo;
 use crate::io::BlockReader;
 use crate::operations::common::BlockMetaIndex;
 use crate::operations::mutation::ClusterStatsGenType;
+use crate::operations::mutation::DeletionVectorBlock;
 use crate::operations::mutation::Mutation;
 use crate::operations::mutation::SerializeBlock;
 use crate::operations::mutation::SerializeDataMeta;
@@ -89,6 +93,10 @@ pub struct MutationSource {
 
     index: BlockMetaIndex,
     stats_type: ClusterStatsGenType,
+    // The meta of the block if the deletion writes deletion vector.
+    block_meta: Option<Arc<BlockMeta>>,
+    // The meta with the deletion vector written, output with the live rows of the block.
+    deletion_vector_meta: Option<BlockMeta>,
 }
 
 impl MutationSource {
@@ -115,6 +123,8 @@ impl MutationSource {
             action,
             index: BlockMetaIndex::default(),
             stats_type: ClusterStatsGenType::Generally,
+            block_meta: None,
+            deletion_vector_meta: None,
         })))
     }
 }
@@ -177,13 +187,19 @@ impl Processor for MutationSource {
                     chunks,
                     &self.storage_format,
                 )?;
+                let fuse_part = FuseBlockPartInfo::from_part(&part)?;
+                // Remove the rows deleted by deletion vector.
+                let num_physical_rows = data_block.num_rows();
+                if let Some(deletion_vector) = &fuse_part.deletion_vector {
+                    data_block = data_block
+                        .filter_with_bitmap(&deletion_vector.live_rows(num_physical_rows)?)?;
+                }
                 let rows = data_block.num_rows();
                 self.ctx.get_scan_progress().incr(&ProgressValues {
                     rows,
                     bytes: data_block.memory_size(),
                 });
 
-                let fuse_part = FuseBlockPartInfo::from_part(&part)?;
                 if let Some(filter) = self.filter.as_ref() {
                     assert_eq!(filter.data_type(), &DataType::Boolean);
 
@@ -225,6 +241,18 @@ impl Processor for MutationSource {
                                         DataBlock::empty_with_meta(meta),
                                     );
                                 } else {
+                                    if let Some(block_meta) = self.block_meta.take() {
+                                        // The block is not rewritten, but the live rows are still
+                                        // read to generate the column statistics of the block.
+                                        self.deletion_vector_meta =
+                                            Some(Self::write_deletion_vector(
+                                                block_meta,
+                                                fuse_part,
+                                                num_physical_rows,
+                                                &predicates,
+                                                affect_rows,
+                                            )?);
+                                    }
                                     if self.block_reader.update_stream_columns {
                                         let row_num = build_origin_block_row_num(rows);
                                         data_block.add_column(row_num);
@@ -285,11 +313,18 @@ impl Processor for MutationSource {
                 let path = FuseBlockPartInfo::from_part(&part)?.location.clone();
                 if let Some(remain_reader) = self.remain_reader.as_ref() {
                     let chunks = block_read_result.columns_chunks()?;
-                    let remain_block = remain_reader.deserialize_chunks_with_part_info(
+                    let deletion_vector =
+                        FuseBlockPartInfo::from_part(&part)?.deletion_vector.clone();
+                    let mut remain_block = remain_reader.deserialize_chunks_with_part_info(
                         part,
                         chunks,
                         &self.storage_format,
                     )?;
+                    if let Some(deletion_vector) = deletion_vector {
+                        let num_rows = remain_block.num_rows();
+                        remain_block = remain_block
+                            .filter_with_bitmap(&deletion_vector.live_rows(num_rows)?)?;
+                    }
 
                     // Record the remain memory size, rows has been recorded.
                     self.ctx.get_scan_progress().incr(&ProgressValues {
@@ -319,9 +354,18 @@ impl Processor for MutationSource {
                     .operators
                     .iter()
                     .try_fold(data_block, |input, op| op.execute(&func_ctx, input))?;
-                let inner_meta = Box::new(SerializeDataMeta::SerializeBlock(
-                    SerializeBlock::create(self.index.clone(), self.stats_type.clone()),
-                ));
+                let inner_meta = match self.deletion_vector_meta.take() {
+                    Some(block_meta) => {
+                        Box::new(SerializeDataMeta::DeletionVector(DeletionVectorBlock {
+                            index: self.index.clone(),
+                            block_meta: Arc::new(block_meta),
+                        }))
+                    }
+                    None => Box::new(SerializeDataMeta::SerializeBlock(SerializeBlock::create(
+                        self.index.clone(),
+                        self.stats_type.clone(),
+                    ))),
+                };
                 let meta: BlockMetaInfoPtr = if self.block_reader.update_stream_columns() {
                     Box::new(gen_mutation_stream_meta(Some(inner_meta), &path)?)
                 } else {
@@ -358,6 +402,7 @@ impl Processor for MutationSource {
                             self.stats_type =
                                 ClusterStatsGenType::WithOrigin(part.cluster_stats.clone());
                         }
+                        self.block_meta = part.block_meta.clone();
 
                         let inner_part = part.inner_part.clone();
                         let fuse_part = FuseBlockPartInfo::from_part(&inner_part)?;
@@ -366,7 +411,11 @@ impl Processor for MutationSource {
                             && matches!(self.action, MutationAction::Deletion)
                         {
                             // whole block deletion.
-                            self.update_mutation_status(fuse_part.nums_rows);
+                            let deleted_rows = fuse_part
+                                .deletion_vector
+                                .as_ref()
+                                .map_or(0, |dv| dv.deleted_rows as usize);
+                            self.update_mutation_status(fuse_part.nums_rows - deleted_rows);
                             let meta = Box::new(SerializeDataMeta::SerializeBlock(
                                 SerializeBlock::create(self.index.clone(), self.stats_type.clone()),
                             ));
@@ -423,6 +472,38 @@ impl Processor for MutationSource {
 }
 
 impl MutationSource {
+    // Adds the deleted rows to the deletion vector of the block, the offsets of
+    // `predicates` are the offsets of the live rows.
+    fn write_deletion_vector(
+        block_meta: Arc<BlockMeta>,
+        fuse_part: &FuseBlockPartInfo,
+        num_physical_rows: usize,
+        predicates: &Value<BooleanType>,
+        affect_rows: usize,
+    ) -> Result<BlockMeta> {
+        let mut deleted = match &fuse_part.deletion_vector {
+            Some(deletion_vector) => deletion_vector.deleted()?,
+            None => RoaringBitmap::new(),
+        };
+        let live_offsets = (0..num_physical_rows as u32)
+            .filter(|offset| !deleted.contains(*offset))
+            .collect::<Vec<_>>();
+        match predicates {
+            Value::Scalar(_) => deleted.extend(live_offsets),
+            Value::Column(bitmap) => deleted.extend(
+                live_offsets
+                    .into_iter()
+                    .zip(bitmap.iter())
+                    .filter_map(|(offset, deleted)| deleted.then_some(offset)),
+            ),
+        }
+
+        let mut block_meta = block_meta.as_ref().clone();
+        block_meta.row_count -= affect_rows as u64;
+        block_meta.deletion_vector = Some(DeletionVector::try_create(&deleted)?);
+        Ok(block_meta)
+    }
+
     fn update_mutation_status(&self, num_rows: usize) {
         let (update_rows, deleted_rows) = if self.action == MutationAction::Update {
             (num_rows as u64, 0)
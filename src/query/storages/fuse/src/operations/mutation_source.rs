@@ -296,6 +296,7 @@ impl FuseTable {
             PruningStatistics::default(),
         )?;
 
+        let write_deletion_vector = is_delete && self.deletion_vector_enabled();
         let mut parts = Partitions::create(
             PartitionsShuffleKind::Mod,
             block_metas
@@ -309,12 +310,15 @@ impl FuseTable {
                     };
                     let key = (index.segment_idx, index.block_idx);
                     let whole_block_mutation = whole_block_deletions.contains(&key);
+                    let block_meta =
+                        (write_deletion_vector && !whole_block_mutation).then_some(block_meta);
                     let part_info_ptr: PartInfoPtr =
                         Arc::new(Box::new(Mutation::MutationPartInfo(MutationPartInfo {
                             index,
                             cluster_stats,
                             inner_part,
                             whole_block_mutation,
+                            block_meta,
                         })));
                     part_info_ptr
                 })
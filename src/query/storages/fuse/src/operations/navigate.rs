@@ -25,8 +25,8 @@ use databend_common_exception::ResultExt;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableStatistics;
 use databend_storages_common_table_meta::meta::TableSnapshot;
+use databend_storages_common_table_meta::table::stream_offset_of_table;
 use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
-use databend_storages_common_table_meta::table::OPT_KEY_SOURCE_TABLE_ID;
 use futures::TryStreamExt;
 use opendal::EntryMode;
 
@@ -65,19 +65,16 @@ impl FuseTable {
 
     #[async_backtrace::framed]
     pub async fn navigate_to_stream(&self, stream_info: &TableInfo) -> Result<Arc<FuseTable>> {
-        let options = stream_info.options();
-        let stream_table_id = options
-            .get(OPT_KEY_SOURCE_TABLE_ID)
-            .ok_or_else(|| ErrorCode::Internal("table id must be set"))?
-            .parse::<u64>()?;
-        if stream_table_id != self.table_info.ident.table_id {
+        let Some((_, snapshot_loc)) =
+            stream_offset_of_table(stream_info.options(), self.table_info.ident.table_id)?
+        else {
             return Err(ErrorCode::IllegalStream(format!(
                 "The stream '{}' is not match the table '{}'",
                 stream_info.desc, self.table_info.desc
             )));
-        }
+        };
 
-        let Some(snapshot_loc) = options.get(OPT_KEY_SNAPSHOT_LOCATION) else {
+        let Some(snapshot_loc) = snapshot_loc else {
             let mut table_info = self.table_info.clone();
             table_info.meta.options.remove(OPT_KEY_SNAPSHOT_LOCATION);
             table_info.meta.statistics = TableStatistics::default();
@@ -312,24 +309,18 @@ impl FuseTable {
         stream_info: TableInfo,
         retention_point: DateTime<Utc>,
     ) -> Result<(String, Vec<String>)> {
-        let options = stream_info.options();
-        let stream_table_id = options
-            .get(OPT_KEY_SOURCE_TABLE_ID)
-            .ok_or_else(|| ErrorCode::Internal("table id must be set"))?
-            .parse::<u64>()?;
-        if stream_table_id != self.table_info.ident.table_id {
+        let Some((_, snapshot_loc)) =
+            stream_offset_of_table(stream_info.options(), self.table_info.ident.table_id)?
+        else {
             return Err(ErrorCode::IllegalStream(format!(
                 "The stream '{}' is not match the table '{}'",
                 stream_info.desc, self.table_info.desc
             )));
-        }
+        };
 
-        let snapshot_loc = options
-            .get(OPT_KEY_SNAPSHOT_LOCATION)
-            .ok_or_else(|| {
-                ErrorCode::TableHistoricalDataNotFound("No historical data found at given point")
-            })?
-            .parse::<String>()?;
+        let snapshot_loc = snapshot_loc.ok_or_else(|| {
+            ErrorCode::TableHistoricalDataNotFound("No historical data found at given point")
+        })?;
 
         let mut found = false;
         let prefix = format!(
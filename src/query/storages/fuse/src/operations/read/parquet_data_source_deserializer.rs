@@ -291,11 +291,17 @@ impl Processor for DeserializeDataTransform {
 
                     let origin_num_rows = data_block.num_rows();
 
-                    let mut filter = None;
+                    // Remove the rows deleted by deletion vector.
+                    let mut filter = match &part.deletion_vector {
+                        Some(deletion_vector) => Some(deletion_vector.live_rows(origin_num_rows)?),
+                        None => None,
+                    };
                     if self.ctx.has_bloom_runtime_filters(self.table_index) {
                         if let Some(bitmap) = self.runtime_filter(data_block.clone())? {
-                            data_block = data_block.filter_with_bitmap(&bitmap)?;
-                            filter = Some(bitmap);
+                            filter = Some(match filter {
+                                Some(live_rows) => (&live_rows).bitand(&bitmap),
+                                None => bitmap,
+                            });
                         }
                     }
 
@@ -305,6 +311,10 @@ impl Processor for DeserializeDataTransform {
                             .deserialize_virtual_columns(data_block.clone(), virtual_data)?;
                     }
 
+                    if let Some(bitmap) = &filter {
+                        data_block = data_block.filter_with_bitmap(bitmap)?;
+                    }
+
                     // Perf.
                     {
                         metrics_inc_remote_io_deserialize_milliseconds(
@@ -135,8 +135,14 @@ impl Transform for ReadParquetDataTransform<true> {
                     return Ok(DataBlock::empty());
                 }
 
-                if let Some(index_reader) = self.index_reader.as_ref() {
-                    let fuse_part = FuseBlockPartInfo::from_part(&part)?;
+                // The aggregating index is not aware of the rows deleted by deletion vector.
+                let fuse_part = FuseBlockPartInfo::from_part(&part)?;
+                if let Some(index_reader) = self
+                    .index_reader
+                    .as_ref()
+                    .as_ref()
+                    .filter(|_| fuse_part.deletion_vector.is_none())
+                {
                     let loc =
                         TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
                             &fuse_part.location,
@@ -231,7 +237,12 @@ impl AsyncTransform for ReadParquetDataTransform<false> {
                             databend_common_base::runtime::spawn(async move {
                                 let part = FuseBlockPartInfo::from_part(&part)?;
 
-                                if let Some(index_reader) = index_reader.as_ref() {
+                                // The aggregating index is not aware of the rows deleted by deletion vector.
+                                if let Some(index_reader) = index_reader
+                                    .as_ref()
+                                    .as_ref()
+                                    .filter(|_| part.deletion_vector.is_none())
+                                {
                                     let loc =
                                         TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
                                             &part.location,
@@ -816,7 +816,7 @@ impl FuseTable {
             }
         }
 
-        let rows_count = meta.row_count;
+        let rows_count = meta.physical_row_count();
         let location = meta.location.0.clone();
         let create_on = meta.create_on;
 
@@ -836,6 +836,7 @@ impl FuseTable {
             sort_min_max,
             block_meta_index.to_owned(),
             create_on,
+            meta.deletion_vector.clone(),
         )
     }
 
@@ -862,7 +863,7 @@ impl FuseTable {
             }
         }
 
-        let rows_count = meta.row_count;
+        let rows_count = meta.physical_row_count();
         let location = meta.location.0.clone();
         let create_on = meta.create_on;
 
@@ -884,6 +885,7 @@ impl FuseTable {
             sort_min_max,
             block_meta_index.to_owned(),
             create_on,
+            meta.deletion_vector.clone(),
         )
     }
 }
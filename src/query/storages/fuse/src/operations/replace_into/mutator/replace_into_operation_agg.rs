@@ -468,9 +468,19 @@ impl AggregationContext {
                 .value);
         }
 
+        // the rows deleted by deletion vector are removed while the block is rewritten.
+        let deleted_rows = match &block_meta.deletion_vector {
+            Some(deletion_vector) => Some(deletion_vector.deleted()?),
+            None => None,
+        };
         let mut bitmap = MutableBitmap::new();
         for row in 0..num_rows {
-            if let Some(hash) = row_hash_of_columns(&columns, row)? {
+            if deleted_rows
+                .as_ref()
+                .is_some_and(|deleted| deleted.contains(row as u32))
+            {
+                bitmap.push(false);
+            } else if let Some(hash) = row_hash_of_columns(&columns, row)? {
                 // some row hash means on-conflict columns of this row contains non-null values
                 // let's check it out
                 bitmap.push(!deleted_key_hashes.contains(&hash));
@@ -480,7 +490,7 @@ impl AggregationContext {
             }
         }
 
-        let delete_nums = bitmap.null_count();
+        let delete_nums = bitmap.null_count() - deleted_rows.map_or(0, |v| v.len() as usize);
         info!("number of row deleted: {}", delete_nums);
 
         // shortcut: nothing to be deleted
@@ -497,7 +507,7 @@ impl AggregationContext {
             metrics_inc_replace_whole_block_deletion(1);
             metrics_inc_replace_deleted_blocks_rows(num_rows as u64);
             // whole block deletion
-            // NOTE that if deletion marker is enabled, check the real meaning of `row_count`
+            // NOTE that `row_count` does not include the rows deleted by deletion vector
             let mutation = MutationLogEntry::DeletedBlock {
                 index: BlockMetaIndex {
                     segment_idx: segment_index,
@@ -663,7 +673,7 @@ impl AggregationContext {
                 let column_chunks = merged_io_read_result.columns_chunks()?;
                 reader.deserialize_chunks(
                     block_meta_ptr.location.0.as_str(),
-                    block_meta_ptr.row_count as usize,
+                    block_meta_ptr.physical_row_count() as usize,
                     &block_meta_ptr.compression,
                     &block_meta_ptr.col_metas,
                     column_chunks,
@@ -0,0 +1,144 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::type_check::check_function;
+use databend_common_expression::DataSchema;
+use databend_common_expression::Expr;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_sql::parse_ttl_alive_filter;
+use databend_storages_common_index::RangeIndex;
+use databend_storages_common_pruner::RangePruner;
+use databend_storages_common_table_meta::meta::SegmentInfo;
+use databend_storages_common_table_meta::meta::StatisticsOfColumns;
+use databend_storages_common_table_meta::meta::Versioned;
+use databend_storages_common_table_meta::table::OPT_KEY_TTL;
+use log::info;
+
+use crate::io::CachedMetaWriter;
+use crate::io::SegmentsIO;
+use crate::statistics::reducers::deduct_statistics_mut;
+use crate::statistics::reducers::reduce_block_metas;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// The predicate which holds for the rows that are not expired by the TTL of this table,
+    /// `None` if the table has no TTL.
+    pub fn ttl_alive_filter(&self, ctx: Arc<dyn TableContext>) -> Result<Option<Expr<String>>> {
+        let Some(ttl) = self.table_info.options().get(OPT_KEY_TTL) else {
+            return Ok(None);
+        };
+
+        let schema = self.schema();
+        let expr = parse_ttl_alive_filter(ctx, Arc::new(DataSchema::from(schema.clone())), ttl)?;
+        Ok(Some(expr.project_column_ref(|index| {
+            schema.field(*index).name().to_string()
+        })))
+    }
+
+    /// Drops the blocks whose rows are all expired by the TTL of this table.
+    ///
+    /// Blocks are judged by their column statistics only, no data is read or rewritten. The
+    /// segments that lose some of their blocks are rewritten without them.
+    #[async_backtrace::framed]
+    pub async fn do_drop_expired_blocks(&self, ctx: Arc<dyn TableContext>) -> Result<usize> {
+        let Some(alive) = self.ttl_alive_filter(ctx.clone())? else {
+            return Ok(0);
+        };
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(0);
+        };
+
+        let schema = self.schema();
+        let func_ctx = ctx.get_function_context()?;
+        let expired = check_function(None, "not", &[], &[alive.clone()], &BUILTIN_FUNCTIONS)?;
+        // keeps the blocks which may have rows not expired yet
+        let alive_index = RangeIndex::try_create(
+            func_ctx.clone(),
+            &alive,
+            schema.clone(),
+            StatisticsOfColumns::default(),
+        )?;
+        // keeps the segments which may have expired rows
+        let expired_index = RangeIndex::try_create(
+            func_ctx,
+            &expired,
+            schema.clone(),
+            StatisticsOfColumns::default(),
+        )?;
+
+        let thresholds = self.get_block_thresholds();
+        let default_cluster_key_id = self.cluster_key_id();
+        let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), schema);
+        let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
+
+        let mut segments = Vec::with_capacity(snapshot.segments.len());
+        let mut expired_blocks = vec![];
+        for chunk in snapshot.segments.chunks(chunk_size) {
+            let infos = segments_io
+                .read_segments::<SegmentInfo>(chunk, false)
+                .await?;
+            for (info, location) in infos.into_iter().zip(chunk.iter()) {
+                let info = info?;
+                if !expired_index.should_keep(&info.summary.col_stats, None) {
+                    segments.push(location.clone());
+                    continue;
+                }
+
+                let (kept, dropped): (Vec<_>, Vec<_>) = info
+                    .blocks
+                    .into_iter()
+                    .partition(|block| alive_index.should_keep(&block.col_stats, None));
+                if dropped.is_empty() {
+                    segments.push(location.clone());
+                    continue;
+                }
+
+                if !kept.is_empty() {
+                    let summary = reduce_block_metas(&kept, thresholds, default_cluster_key_id);
+                    let new_segment = SegmentInfo::new(kept, summary);
+                    let new_location = self.meta_location_generator.gen_segment_info_location();
+                    new_segment
+                        .write_meta_through_cache(&self.operator, &new_location)
+                        .await?;
+                    segments.push((new_location, SegmentInfo::VERSION));
+                }
+                expired_blocks.extend(dropped);
+            }
+        }
+
+        if expired_blocks.is_empty() {
+            return Ok(0);
+        }
+
+        let mut summary = snapshot.summary.clone();
+        deduct_statistics_mut(
+            &mut summary,
+            &reduce_block_metas(&expired_blocks, thresholds, default_cluster_key_id),
+        );
+        self.commit_mutation(&ctx, snapshot, &segments, summary, None)
+            .await?;
+
+        info!(
+            "dropped {} blocks expired by the ttl of table {}",
+            expired_blocks.len(),
+            self.table_info.desc
+        );
+        Ok(expired_blocks.len())
+    }
+}
@@ -156,7 +156,7 @@ pub async fn read_block(
             let column_chunks = merged_io_read_result.columns_chunks()?;
             reader.deserialize_chunks(
                 block_meta_ptr.location.0.as_str(),
-                block_meta_ptr.row_count as usize,
+                block_meta_ptr.physical_row_count() as usize,
                 &block_meta_ptr.compression,
                 &block_meta_ptr.col_metas,
                 column_chunks,
@@ -212,7 +212,10 @@ impl BlockPruner {
                                         pruning_stats.set_blocks_inverted_index_pruning_before(1);
                                     }
                                     let matched_rows = inverted_index_pruner
-                                        .should_keep(&block_location.0, row_count)
+                                        .should_keep(
+                                            &block_location.0,
+                                            block_meta.physical_row_count(),
+                                        )
                                         .await?;
                                     prune_result.keep = matched_rows.is_some();
                                     prune_result.matched_rows = matched_rows;
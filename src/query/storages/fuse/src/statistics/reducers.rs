@@ -211,8 +211,10 @@ pub fn reduce_block_metas<T: Borrow<BlockMeta>>(
         compressed_byte_size += b.file_size;
         index_size += b.bloom_filter_index_size;
         index_size += b.inverted_index_size.unwrap_or_default();
-        if thresholds.check_large_enough(b.row_count as usize, b.block_size as usize)
-            || b.cluster_stats.as_ref().is_some_and(|v| v.level != 0)
+        // the block with deletion vector is not perfect, it will be compacted.
+        if b.deletion_vector.is_none()
+            && (thresholds.check_large_enough(b.row_count as usize, b.block_size as usize)
+                || b.cluster_stats.as_ref().is_some_and(|v| v.level != 0))
         {
             perfect_block_count += 1;
         }
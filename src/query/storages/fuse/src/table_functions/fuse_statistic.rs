@@ -157,6 +157,34 @@ impl<'a> FuseStatisticImpl<'a> {
                     col_his.push("".to_string());
                 }
             }
+            for stat in table_statistics.multi_column_stats.iter() {
+                let mut names = Vec::with_capacity(stat.column_ids.len());
+                for column_id in stat.column_ids.iter() {
+                    let table_field = self
+                        .table
+                        .table_info
+                        .meta
+                        .schema
+                        .field_of_column_id(*column_id)?;
+                    names.push(table_field.name.clone());
+                }
+                col_names.push(format!("({})", names.join(", ")));
+                col_ndvs.push(stat.ndv);
+                let mut mcv_infos = Vec::with_capacity(stat.mcv.len());
+                for mcv in stat.mcv.iter() {
+                    let values = mcv
+                        .values
+                        .iter()
+                        .map(|v| v.to_string())
+                        .collect::<Result<Vec<_>>>()?;
+                    mcv_infos.push(format!(
+                        "[values: ({}), frequency: {:.4}]",
+                        values.join(", "),
+                        mcv.frequency
+                    ));
+                }
+                col_his.push(mcv_infos.join(", "));
+            }
         };
 
         Ok(DataBlock::new_from_columns(vec![
@@ -15,6 +15,7 @@
 use std::any::Any;
 use std::collections::BTreeMap;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use arrow_schema::Schema as ArrowSchema;
@@ -240,9 +241,56 @@ impl IcebergTable {
         _: Arc<dyn TableContext>,
         push_downs: Option<PushDownInfo>,
     ) -> Result<(PartStatistics, Partitions)> {
+        let tasks = self.plan_files(&push_downs, None).await?;
+        Ok(Self::tasks_to_partitions(tasks))
+    }
+
+    #[async_backtrace::framed]
+    async fn do_read_changes_partitions(
+        &self,
+        push_downs: Option<PushDownInfo>,
+        change_type: ChangeType,
+        base_version: &Option<String>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let latest_tasks = match self.table.metadata().current_snapshot() {
+            Some(_) => self.plan_files(&push_downs, None).await?,
+            None => vec![],
+        };
+        let base_tasks = match base_version {
+            Some(version) => {
+                self.plan_files(&push_downs, Some(version.parse::<i64>()?))
+                    .await?
+            }
+            None => vec![],
+        };
+
+        // A data file is immutable once written, the changes are the files only in one side.
+        let (tasks, others) = match change_type {
+            ChangeType::Append | ChangeType::Insert => (latest_tasks, base_tasks),
+            ChangeType::Delete => (base_tasks, latest_tasks),
+        };
+        let others: HashSet<&str> = others
+            .iter()
+            .map(|task| task.data_file_path.as_str())
+            .collect();
+        let tasks = tasks
+            .into_iter()
+            .filter(|task| !others.contains(task.data_file_path.as_str()))
+            .collect();
+        Ok(Self::tasks_to_partitions(tasks))
+    }
+
+    async fn plan_files(
+        &self,
+        push_downs: &Option<PushDownInfo>,
+        snapshot_id: Option<i64>,
+    ) -> Result<Vec<iceberg::scan::FileScanTask>> {
         let mut scan = self.table.scan();
+        if let Some(snapshot_id) = snapshot_id {
+            scan = scan.snapshot_id(snapshot_id);
+        }
 
-        if let Some(push_downs) = &push_downs {
+        if let Some(push_downs) = push_downs {
             if let Some(projection) = &push_downs.projection {
                 scan = scan.select(
                     projection
@@ -267,7 +315,12 @@ impl IcebergTable {
             .try_collect()
             .await
             .map_err(|err| ErrorCode::Internal(format!("iceberg table scan collect: {err:?}")))?;
+        Ok(tasks)
+    }
 
+    fn tasks_to_partitions(
+        tasks: Vec<iceberg::scan::FileScanTask>,
+    ) -> (PartStatistics, Partitions) {
         let mut read_rows = 0;
         let mut read_bytes = 0;
         let total_files = tasks.len();
@@ -280,10 +333,10 @@ impl IcebergTable {
             })
             .collect();
 
-        Ok((
+        (
             PartStatistics::new_estimated(None, read_rows, read_bytes, parts.len(), total_files),
             Partitions::create(PartitionsShuffleKind::Mod, parts),
-        ))
+        )
     }
 }
 
@@ -347,4 +400,25 @@ impl Table for IcebergTable {
     fn support_prewhere(&self) -> bool {
         false
     }
+
+    #[async_backtrace::framed]
+    async fn changes_version(&self) -> Result<Option<String>> {
+        Ok(self
+            .table
+            .metadata()
+            .current_snapshot()
+            .map(|snapshot| snapshot.snapshot_id().to_string()))
+    }
+
+    #[async_backtrace::framed]
+    async fn read_changes_partitions(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+        change_type: ChangeType,
+        base_version: &Option<String>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        self.do_read_changes_partitions(push_downs, change_type, base_version)
+            .await
+    }
 }
@@ -0,0 +1,130 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Utc;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::parser::Dialect;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_sql::ViewStreamRewriter;
+use databend_storages_common_table_meta::table::StreamMode;
+use derive_visitor::DriveMut;
+
+/// Builds the changes query of a stream on a Delta or Iceberg table.
+///
+/// The inserted and deleted rows are read from the data files added and removed since the
+/// offset of the stream. The rows of a rewritten data file are in both sides, they are
+/// identified by the hash of the row and the number of its occurrence, and cancel each other.
+pub fn lake_changes_query(
+    mode: &StreamMode,
+    cols: &[String],
+    table_desc: &str,
+    quote: char,
+) -> String {
+    let suffix = format!("{:08x}", Utc::now().timestamp());
+
+    let a_table_alias = format!("_change_insert${}", suffix);
+    let d_table_alias = format!("_change_delete${}", suffix);
+
+    let mut a_cols_vec = Vec::with_capacity(cols.len());
+    let mut d_alias_vec = Vec::with_capacity(cols.len());
+    let mut d_cols_vec = Vec::with_capacity(cols.len());
+    for col in cols {
+        a_cols_vec.push(format!("{quote}{col}{quote}"));
+        d_alias_vec.push(format!("{quote}{col}{quote} as d_{col}"));
+        d_cols_vec.push(format!("d_{col}"));
+    }
+    let a_cols = a_cols_vec.join(", ");
+    let d_cols_alias = d_alias_vec.join(", ");
+    let d_cols = d_cols_vec.join(", ");
+    let row_hash = format!("md5(to_string(({a_cols},)))");
+
+    let cte_name = format!("_change${}", suffix);
+    let query = format!(
+        "with {cte_name} as materialized \
+        ( \
+            select * \
+            from ( \
+                select {a_cols}, \
+                        'INSERT' as a_change$action, \
+                        concat(a_change$hash, lpad(hex(row_number() over (partition by a_change$hash)), 6, '0')) as a_change$row_id \
+                from (select *, {row_hash} as a_change$hash from {table_desc} as {a_table_alias}) as A0 \
+            ) as A \
+            FULL OUTER JOIN ( \
+                select {d_cols_alias}, \
+                        'DELETE' as d_change$action, \
+                        concat(d_change$hash, lpad(hex(row_number() over (partition by d_change$hash)), 6, '0')) as d_change$row_id \
+                from (select *, {row_hash} as d_change$hash from {table_desc} as {d_table_alias}) as D0 \
+            ) as D \
+            on A.a_change$row_id = D.d_change$row_id \
+            where A.a_change$row_id is null or D.d_change$row_id is null \
+        ) \
+        select {a_cols}, \
+                a_change$action as change$action, \
+                a_change$row_id as change$row_id, \
+                false as change$is_update \
+        from {cte_name} \
+        where a_change$action is not null",
+    );
+
+    match mode {
+        StreamMode::AppendOnly => query,
+        StreamMode::Standard => format!(
+            "{query} \
+            union all \
+            select {d_cols}, \
+                    d_change$action, \
+                    d_change$row_id, \
+                    false \
+            from {cte_name} \
+            where d_change$action is not null",
+        ),
+    }
+}
+
+/// Rewrites the query of the view in `view_database` into the changes of the view since the
+/// offsets of the stream `catalog.database.stream`, returns the changes query and the
+/// `(database, table)` of the tables underlying the view.
+pub fn view_changes_query(
+    view_query: &str,
+    dialect: Dialect,
+    catalog: &str,
+    database: &str,
+    stream: &str,
+    view_database: &str,
+    append_only: bool,
+) -> Result<(String, Vec<(String, String)>)> {
+    let tokens = tokenize_sql(view_query)?;
+    let (stmt, _) = parse_sql(&tokens, dialect)?;
+    let Statement::Query(mut query) = stmt else {
+        return Err(ErrorCode::Internal(format!(
+            "Invalid VIEW query: {view_query}"
+        )));
+    };
+
+    let quote = Some(dialect.default_ident_quote());
+    let mut rewriter = ViewStreamRewriter::new(
+        Identifier::from_name_with_quoted(None, catalog, quote),
+        Identifier::from_name_with_quoted(None, database, quote),
+        Identifier::from_name_with_quoted(None, stream, quote),
+        Identifier::from_name_with_quoted(None, view_database, quote),
+        append_only,
+    );
+    query.drive_mut(&mut rewriter);
+    rewriter.check()?;
+    Ok((query.to_string(), rewriter.tables))
+}
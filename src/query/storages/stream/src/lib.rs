@@ -13,5 +13,6 @@
 // limitations under the License.
 
 #![feature(impl_trait_in_assoc_type)]
+pub mod changes_query;
 pub mod stream_status_table_func;
 pub mod stream_table;
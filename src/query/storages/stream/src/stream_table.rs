@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -45,16 +46,27 @@ use databend_common_storages_fuse::io::MetaReaders;
 use databend_common_storages_fuse::io::SnapshotHistoryReader;
 use databend_common_storages_fuse::io::TableMetaLocationGenerator;
 use databend_common_storages_fuse::FuseTable;
+use databend_common_storages_view::view_table::QUERY;
+use databend_common_storages_view::view_table::VIEW_ENGINE;
+use databend_storages_common_table_meta::table::encode_view_source_offsets;
+use databend_storages_common_table_meta::table::parse_view_source_offsets;
 use databend_storages_common_table_meta::table::ChangeType;
 use databend_storages_common_table_meta::table::StreamMode;
+use databend_storages_common_table_meta::table::StreamSourceOffset;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_MODE;
 use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use databend_storages_common_table_meta::table::OPT_KEY_SOURCE_DATABASE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_SOURCE_ENGINE;
 use databend_storages_common_table_meta::table::OPT_KEY_SOURCE_TABLE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_SOURCE_VERSION;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_VER;
+use databend_storages_common_table_meta::table::OPT_KEY_VIEW_SOURCE_OFFSETS;
 use futures::TryStreamExt;
 
+use crate::changes_query::lake_changes_query;
+use crate::changes_query::view_changes_query;
+
 pub const STREAM_ENGINE: &str = "STREAM";
 
 pub enum StreamStatus {
@@ -108,7 +120,12 @@ impl StreamTable {
     }
 
     pub async fn source_table(&self, ctx: Arc<dyn TableContext>) -> Result<Arc<dyn Table>> {
-        let source = if let Some(source) = &self.source_table {
+        // The storage parameters of the Delta and Iceberg tables are attached by the query context.
+        let source = if let Some(source) = self
+            .source_table
+            .as_ref()
+            .filter(|_| self.source_engine().is_none())
+        {
             source.clone()
         } else {
             let catalog = ctx.get_catalog(self.info.catalog()).await?;
@@ -130,8 +147,10 @@ impl StreamTable {
             )));
         }
 
-        let fuse_table = FuseTable::try_from_table(source.as_ref())?;
-        fuse_table.check_changes_valid(desc, self.offset()?)?;
+        if self.source_engine().is_none() {
+            let fuse_table = FuseTable::try_from_table(source.as_ref())?;
+            fuse_table.check_changes_valid(desc, self.offset()?)?;
+        }
         Ok(source)
     }
 
@@ -160,6 +179,12 @@ impl StreamTable {
         let Some(batch_limit) = batch_limit else {
             return Ok(source);
         };
+        if self.source_engine().is_some() {
+            return Err(ErrorCode::IllegalStream(format!(
+                "MAX_BATCH_SIZE is only supported by the streams on fuse tables, but got stream {}",
+                stream_desc
+            )));
+        }
 
         let source_desc = &source.get_table_info().desc;
         if source.get_table_info().ident.table_id != self.source_table_id()? {
@@ -247,6 +272,100 @@ impl StreamTable {
         self.info.options().get(OPT_KEY_SNAPSHOT_LOCATION).cloned()
     }
 
+    /// The engine of the source, `None` if the source is a fuse table.
+    pub fn source_engine(&self) -> Option<&str> {
+        self.info
+            .options()
+            .get(OPT_KEY_SOURCE_ENGINE)
+            .map(|s| s.as_str())
+    }
+
+    pub fn is_view_stream(&self) -> bool {
+        self.source_engine() == Some(VIEW_ENGINE)
+    }
+
+    /// The version of the Delta/Iceberg source table that the stream starts from.
+    pub fn source_version(&self) -> Option<String> {
+        self.info.options().get(OPT_KEY_SOURCE_VERSION).cloned()
+    }
+
+    /// Gets the fuse table underlying the source view of the stream.
+    async fn view_source_table(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        catalog: &dyn Catalog,
+        offset: &StreamSourceOffset,
+    ) -> Result<Arc<dyn Table>> {
+        let database_name = catalog.get_db_name_by_id(offset.database_id).await?;
+        let table_name = catalog
+            .get_table_name_by_id(offset.table_id)
+            .await?
+            .ok_or_else(|| {
+                ErrorCode::IllegalStream(format!(
+                    "Base table {} of view dropped, cannot read from stream {}",
+                    offset.table_id, self.info.desc,
+                ))
+            })?;
+        let table = ctx
+            .get_table(self.info.catalog(), &database_name, &table_name)
+            .await?;
+        if table.get_table_info().ident.table_id != offset.table_id {
+            return Err(ErrorCode::IllegalStream(format!(
+                "Base table {} of view dropped, cannot read from stream {}",
+                table.get_table_info().desc,
+                self.info.desc,
+            )));
+        }
+        Ok(table)
+    }
+
+    /// Whether any table underlying the source view has been changed since the offsets.
+    pub async fn view_sources_changed(&self, catalog: &dyn Catalog) -> Result<bool> {
+        for offset in parse_view_source_offsets(self.info.options())? {
+            let changed = catalog
+                .get_table_meta_by_id(offset.table_id)
+                .await?
+                .is_none_or(|meta| meta.seq != offset.table_version);
+            if changed {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// The options of a stream on a view or a Delta/Iceberg table after its changes are
+    /// consumed, the offsets are moved to the versions read by the query.
+    pub async fn consumed_options(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        source: &dyn Table,
+    ) -> Result<BTreeMap<String, String>> {
+        let mut options = self.info.options().clone();
+        let source_version = source.get_table_info().ident.seq;
+        options.insert(OPT_KEY_TABLE_VER.to_string(), source_version.to_string());
+        if self.is_view_stream() {
+            let catalog = ctx.get_catalog(self.info.catalog()).await?;
+            let mut offsets = parse_view_source_offsets(self.info.options())?;
+            for offset in offsets.iter_mut() {
+                let table = self
+                    .view_source_table(ctx.clone(), catalog.as_ref(), offset)
+                    .await?;
+                let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+                offset.table_version = fuse_table.get_table_info().ident.seq;
+                offset.snapshot_location = fuse_table.snapshot_loc();
+            }
+            options.insert(
+                OPT_KEY_VIEW_SOURCE_OFFSETS.to_string(),
+                encode_view_source_offsets(&offsets)?,
+            );
+        } else if let Some(version) = source.changes_version().await? {
+            options.insert(OPT_KEY_SOURCE_VERSION.to_string(), version);
+        } else {
+            options.remove(OPT_KEY_SOURCE_VERSION);
+        }
+        Ok(options)
+    }
+
     pub fn source_table_id(&self) -> Result<u64> {
         let table_id = self
             .info
@@ -313,10 +432,16 @@ impl StreamTable {
         push_downs: Option<PushDownInfo>,
     ) -> Result<(PartStatistics, Partitions)> {
         let table = self.source_table(ctx.clone()).await?;
-        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
         let change_type = push_downs.as_ref().map_or(ChangeType::Append, |v| {
             v.change_type.clone().unwrap_or(ChangeType::Append)
         });
+        if self.source_engine().is_some() {
+            return table
+                .read_changes_partitions(ctx, push_downs, change_type, &self.source_version())
+                .await;
+        }
+
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
         fuse_table
             .do_read_changes_partitions(ctx, push_downs, change_type, &self.snapshot_loc())
             .await
@@ -324,11 +449,19 @@ impl StreamTable {
 
     #[fastrace::trace]
     pub async fn check_stream_status(&self, ctx: Arc<dyn TableContext>) -> Result<StreamStatus> {
-        let base_table = self.source_table(ctx).await?;
-        let status = if base_table.get_table_info().ident.seq == self.offset()? {
-            StreamStatus::NoData
-        } else {
+        let base_table = self.source_table(ctx.clone()).await?;
+        let has_data = match self.source_engine() {
+            None => base_table.get_table_info().ident.seq != self.offset()?,
+            Some(VIEW_ENGINE) => {
+                let catalog = ctx.get_catalog(self.info.catalog()).await?;
+                self.view_sources_changed(catalog.as_ref()).await?
+            }
+            Some(_) => base_table.changes_version().await? != self.source_version(),
+        };
+        let status = if has_data {
             StreamStatus::MayHaveData
+        } else {
+            StreamStatus::NoData
         };
         Ok(status)
     }
@@ -358,6 +491,10 @@ impl Table for StreamTable {
     }
 
     fn stream_columns(&self) -> Vec<StreamColumn> {
+        // The changes of the other sources are not tracked by rows.
+        if self.source_engine().is_some() {
+            return vec![];
+        }
         vec![
             STREAM_COLUMN_FACTORY
                 .get_stream_column(ORIGIN_VERSION_COL_NAME)
@@ -403,6 +540,9 @@ impl Table for StreamTable {
         _require_fresh: bool,
         change_type: Option<ChangeType>,
     ) -> Result<Option<TableStatistics>> {
+        if self.source_engine().is_some() {
+            return Ok(None);
+        }
         let table = self.source_table(ctx.clone()).await?;
         let fuse_table = FuseTable::try_from_table(table.as_ref())?;
         let change_type = change_type.unwrap_or(ChangeType::Append);
@@ -429,10 +569,40 @@ impl Table for StreamTable {
         with_options: &str,
     ) -> Result<String> {
         let table = self.source_table(ctx.clone()).await?;
-        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
-        let quote = ctx.get_settings().get_sql_dialect()?.default_ident_quote();
+        let dialect = ctx.get_settings().get_sql_dialect()?;
+        let quote = dialect.default_ident_quote();
+        if self.is_view_stream() {
+            let view_query = table
+                .options()
+                .get(QUERY)
+                .ok_or_else(|| ErrorCode::Internal("Invalid VIEW object"))?;
+            let catalog = ctx.get_catalog(self.info.catalog()).await?;
+            let view_database = self.source_database_name(catalog.as_ref()).await?;
+            let (query, _) = view_changes_query(
+                view_query,
+                dialect,
+                self.info.catalog(),
+                database_name,
+                table_name,
+                &view_database,
+                self.mode() == StreamMode::AppendOnly,
+            )?;
+            return Ok(query);
+        }
+
         let table_desc =
             format!("{quote}{database_name}{quote}.{quote}{table_name}{quote}{with_options}");
+        if self.source_engine().is_some() {
+            let cols = table
+                .schema()
+                .fields()
+                .iter()
+                .map(|f| f.name().clone())
+                .collect::<Vec<_>>();
+            return Ok(lake_changes_query(&self.mode(), &cols, &table_desc, quote));
+        }
+
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
         fuse_table
             .get_changes_query(
                 ctx,
@@ -31,6 +31,8 @@ use databend_common_meta_app::schema::TableMeta;
 use databend_common_metrics::cache::get_cache_access_count;
 use databend_common_metrics::cache::get_cache_hit_count;
 use databend_common_metrics::cache::get_cache_miss_count;
+use databend_common_metrics::cache::get_cache_warmup_loaded_count;
+use databend_common_metrics::cache::get_cache_warmup_scheduled_count;
 use databend_common_storages_fuse::TableContext;
 use databend_storages_common_cache::CacheAccessor;
 use databend_storages_common_cache::CacheManager;
@@ -57,6 +59,13 @@ struct CachesTableColumns {
     access: Vec<u64>,
     hit: Vec<u64>,
     miss: Vec<u64>,
+    policy: Vec<String>,
+    admitted: Vec<u64>,
+    rejected: Vec<u64>,
+    pinned_items: Vec<u64>,
+    pinned_size: Vec<u64>,
+    warmup_scheduled: Vec<u64>,
+    warmup_loaded: Vec<u64>,
 }
 
 impl SyncSystemTable for CachesTable {
@@ -145,6 +154,13 @@ impl SyncSystemTable for CachesTable {
             columns.access.push(access);
             columns.hit.push(hit);
             columns.miss.push(miss);
+            let admission_stats = cache.admission_stats();
+            columns.policy.push(cache.policy().to_string());
+            columns.admitted.push(admission_stats.admitted);
+            columns.rejected.push(admission_stats.rejected);
+            columns.pinned_items.push(cache.pinned_len() as u64);
+            columns.pinned_size.push(cache.pinned_size());
+            Self::append_warmup_progress(DISK_TABLE_DATA_CACHE_NAME, &mut columns);
         }
 
         if let Some(table_column_array_cache) = table_column_array_cache {
@@ -161,6 +177,13 @@ impl SyncSystemTable for CachesTable {
             UInt64Type::from_data(columns.access),
             UInt64Type::from_data(columns.hit),
             UInt64Type::from_data(columns.miss),
+            StringType::from_data(columns.policy),
+            UInt64Type::from_data(columns.admitted),
+            UInt64Type::from_data(columns.rejected),
+            UInt64Type::from_data(columns.pinned_items),
+            UInt64Type::from_data(columns.pinned_size),
+            UInt64Type::from_data(columns.warmup_scheduled),
+            UInt64Type::from_data(columns.warmup_loaded),
         ]))
     }
 }
@@ -177,6 +200,22 @@ impl CachesTable {
             TableField::new("access", TableDataType::Number(NumberDataType::UInt64)),
             TableField::new("hit", TableDataType::Number(NumberDataType::UInt64)),
             TableField::new("miss", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("policy", TableDataType::String),
+            TableField::new("admitted", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("rejected", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "pinned_items",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("pinned_size", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "warmup_scheduled",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "warmup_loaded",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
         ]);
 
         let table_info = TableInfo {
@@ -222,5 +261,22 @@ impl CachesTable {
         columns.access.push(access);
         columns.hit.push(hit);
         columns.miss.push(miss);
+
+        let admission_stats = cache.admission_stats();
+        columns.policy.push(cache.policy().to_string());
+        columns.admitted.push(admission_stats.admitted);
+        columns.rejected.push(admission_stats.rejected);
+        columns.pinned_items.push(cache.pinned_len() as u64);
+        columns.pinned_size.push(cache.pinned_bytes_size());
+        Self::append_warmup_progress(cache.name(), columns);
+    }
+
+    fn append_warmup_progress(cache_name: &str, columns: &mut CachesTableColumns) {
+        columns
+            .warmup_scheduled
+            .push(get_cache_warmup_scheduled_count(cache_name));
+        columns
+            .warmup_loaded
+            .push(get_cache_warmup_loaded_count(cache_name));
     }
 }
@@ -48,6 +48,7 @@ mod notification_history_table;
 mod notifications_table;
 mod one_table;
 mod password_policies_table;
+mod plan_baselines_table;
 mod procedures_table;
 mod processes_table;
 mod queries_profiling;
@@ -104,6 +105,7 @@ pub use notifications_table::parse_notifications_to_datablock;
 pub use notifications_table::NotificationsTable;
 pub use one_table::OneTable;
 pub use password_policies_table::PasswordPoliciesTable;
+pub use plan_baselines_table::PlanBaselinesTable;
 pub use procedures_table::ProceduresTable;
 pub use processes_table::ProcessesTable;
 pub use queries_profiling::ProfilesLogElement;
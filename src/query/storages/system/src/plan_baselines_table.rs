@@ -0,0 +1,135 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::utils::FromData;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub struct PlanBaselinesTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for PlanBaselinesTable {
+    const NAME: &'static str = "system.plan_baselines";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let mut baselines = UserApiProvider::instance()
+            .plan_baseline_api(&tenant)?
+            .list_baselines()
+            .await?;
+        baselines.sort_by(|a, b| a.created_on.cmp(&b.created_on));
+
+        let mut sql_hashes = Vec::with_capacity(baselines.len());
+        let mut query_texts = Vec::with_capacity(baselines.len());
+        let mut join_orders = Vec::with_capacity(baselines.len());
+        let mut hints = Vec::with_capacity(baselines.len());
+        let mut plan_fingerprints = Vec::with_capacity(baselines.len());
+        let mut execution_times = Vec::with_capacity(baselines.len());
+        let mut rejected_plans = Vec::with_capacity(baselines.len());
+        let mut created_on_columns = Vec::with_capacity(baselines.len());
+        let mut updated_on_columns = Vec::with_capacity(baselines.len());
+        for baseline in baselines {
+            sql_hashes.push(baseline.sql_hash);
+            query_texts.push(baseline.query_text);
+            join_orders.push(baseline.join_order.join(", "));
+            hints.push(
+                baseline
+                    .hints
+                    .iter()
+                    .map(|hint| format!("{}({})", hint.name, hint.tables.join(" ")))
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            );
+            plan_fingerprints.push(baseline.plan_fingerprint);
+            execution_times.push(baseline.execution_time_ms);
+            rejected_plans.push(baseline.rejected_fingerprints.join(", "));
+            created_on_columns.push(baseline.created_on.timestamp_micros());
+            updated_on_columns.push(baseline.updated_on.timestamp_micros());
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(sql_hashes),
+            StringType::from_data(query_texts),
+            StringType::from_data(join_orders),
+            StringType::from_data(hints),
+            StringType::from_data(plan_fingerprints),
+            UInt64Type::from_opt_data(execution_times),
+            StringType::from_data(rejected_plans),
+            TimestampType::from_data(created_on_columns),
+            TimestampType::from_data(updated_on_columns),
+        ]))
+    }
+}
+
+impl PlanBaselinesTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("sql_hash", TableDataType::String),
+            TableField::new("query_text", TableDataType::String),
+            TableField::new("join_order", TableDataType::String),
+            TableField::new("hints", TableDataType::String),
+            TableField::new("plan_fingerprint", TableDataType::String),
+            TableField::new(
+                "execution_time_ms",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt64))),
+            ),
+            TableField::new("rejected_plans", TableDataType::String),
+            TableField::new("created_on", TableDataType::Timestamp),
+            TableField::new("updated_on", TableDataType::Timestamp),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'plan_baselines'".to_string(),
+            name: "plan_baselines".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemPlanBaselines".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        AsyncOneBlockSystemTable::create(PlanBaselinesTable { table_info })
+    }
+}
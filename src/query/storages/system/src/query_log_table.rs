@@ -109,6 +109,8 @@ pub struct QueryLogElement {
     pub query_text: String,
     pub query_hash: String,
     pub query_parameterized_hash: String,
+    pub plan_fingerprint: String,
+    pub plan_baseline: String,
 
     #[serde(serialize_with = "date_str")]
     pub event_date: i32,
@@ -125,6 +127,10 @@ pub struct QueryLogElement {
     pub tables: String,
     pub columns: String,
     pub projections: String,
+    // Access history, in JSON.
+    pub objects_read: String,
+    pub objects_written: String,
+    pub column_lineage: String,
 
     // Stats.
     pub written_rows: u64,
@@ -201,6 +207,8 @@ impl SystemLogElement for QueryLogElement {
             TableField::new("query_text", TableDataType::String),
             TableField::new("query_hash", TableDataType::String),
             TableField::new("query_parameterized_hash", TableDataType::String),
+            TableField::new("plan_fingerprint", TableDataType::String),
+            TableField::new("plan_baseline", TableDataType::String),
             TableField::new("event_date", TableDataType::Date),
             TableField::new("event_time", TableDataType::Timestamp),
             TableField::new("query_start_time", TableDataType::Timestamp),
@@ -218,6 +226,10 @@ impl SystemLogElement for QueryLogElement {
             TableField::new("tables", TableDataType::String),
             TableField::new("columns", TableDataType::String),
             TableField::new("projections", TableDataType::String),
+            // Access history.
+            TableField::new("objects_read", TableDataType::String),
+            TableField::new("objects_written", TableDataType::String),
+            TableField::new("column_lineage", TableDataType::String),
             // Stats.
             TableField::new(
                 "written_rows",
@@ -381,6 +393,14 @@ impl SystemLogElement for QueryLogElement {
             .next()
             .unwrap()
             .push(Scalar::String(self.query_parameterized_hash.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.plan_fingerprint.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.plan_baseline.clone()).as_ref());
         columns
             .next()
             .unwrap()
@@ -422,6 +442,19 @@ impl SystemLogElement for QueryLogElement {
             .next()
             .unwrap()
             .push(Scalar::String(self.projections.clone()).as_ref());
+        // Access history.
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.objects_read.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.objects_written.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.column_lineage.clone()).as_ref());
         // Stats.
         columns
             .next()
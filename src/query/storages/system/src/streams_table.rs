@@ -254,10 +254,11 @@ impl<const T: bool> AsyncSystemTable for StreamsTable<T> {
                                     StreamTable::try_from_table(table.as_ref()).unwrap();
                                 match stream_table.source_table(ctx).await {
                                     Ok(source) => {
-                                        // safe unwrap, has been checked in source_table.
-                                        let fuse_table =
-                                            FuseTable::try_from_table(source.as_ref()).unwrap();
-                                        if let Some(location) = stream_table.snapshot_loc() {
+                                        // The offset snapshot is only kept by the fuse source.
+                                        if let (Ok(fuse_table), Some(location)) = (
+                                            FuseTable::try_from_table(source.as_ref()),
+                                            stream_table.snapshot_loc(),
+                                        ) {
                                             reason = fuse_table
                                                 .changes_read_offset_snapshot(&location)
                                                 .await
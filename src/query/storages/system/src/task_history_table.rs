@@ -40,6 +40,7 @@ use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_sql::plans::task_run_schema;
+use databend_common_users::UserApiProvider;
 use jiff::tz::TimeZone;
 
 use crate::table::AsyncOneBlockSystemTable;
@@ -131,7 +132,9 @@ impl AsyncSystemTable for TaskHistoryTable {
         push_downs: Option<PushDownInfo>,
     ) -> Result<DataBlock> {
         let config = GlobalConfig::instance();
-        if config.query.cloud_control_grpc_server_address.is_none() {
+        if !config.query.embedded_task_scheduler()
+            && config.query.cloud_control_grpc_server_address.is_none()
+        {
             return Err(ErrorCode::CloudControlNotEnabled(
                 "cannot view system.task_history table without cloud control enabled, please set cloud_control_grpc_server_address in config",
             ));
@@ -197,6 +200,12 @@ impl AsyncSystemTable for TaskHistoryTable {
             root_task_id: None,
         };
 
+        if config.query.embedded_task_scheduler() {
+            let task_mgr = UserApiProvider::instance().task_api(&tenant)?;
+            let resp = task_mgr.show_task_runs(req).await?;
+            return parse_task_runs_to_datablock(resp.task_runs);
+        }
+
         let cloud_api = CloudControlApiProvider::instance();
         let task_client = cloud_api.get_task_client();
         let config = build_client_config(
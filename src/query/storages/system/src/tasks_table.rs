@@ -36,6 +36,7 @@ use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_sql::plans::task_schema;
+use databend_common_users::UserApiProvider;
 
 use crate::table::AsyncOneBlockSystemTable;
 use crate::table::AsyncSystemTable;
@@ -121,7 +122,9 @@ impl AsyncSystemTable for TasksTable {
         _push_downs: Option<PushDownInfo>,
     ) -> Result<DataBlock> {
         let config = GlobalConfig::instance();
-        if config.query.cloud_control_grpc_server_address.is_none() {
+        if !config.query.embedded_task_scheduler()
+            && config.query.cloud_control_grpc_server_address.is_none()
+        {
             return Err(ErrorCode::CloudControlNotEnabled(
                 "cannot view system.tasks table without cloud control enabled, please set cloud_control_grpc_server_address in config",
             ));
@@ -142,6 +145,12 @@ impl AsyncSystemTable for TasksTable {
             task_ids: vec![],
         };
 
+        if config.query.embedded_task_scheduler() {
+            let task_mgr = UserApiProvider::instance().task_api(&tenant)?;
+            let resp = task_mgr.show_tasks(req).await?;
+            return parse_tasks_to_datablock(resp.tasks);
+        }
+
         let cloud_api = CloudControlApiProvider::instance();
         let task_client = cloud_api.get_task_client();
         let cfg = build_client_config(
@@ -25,6 +25,7 @@ use databend_common_management::ConnectionMgr;
 use databend_common_management::FileFormatMgr;
 use databend_common_management::NetworkPolicyMgr;
 use databend_common_management::PasswordPolicyMgr;
+use databend_common_management::PlanBaselineMgr;
 use databend_common_management::ProcedureMgr;
 use databend_common_management::QuotaApi;
 use databend_common_management::QuotaMgr;
@@ -33,6 +34,7 @@ use databend_common_management::RoleMgr;
 use databend_common_management::SettingMgr;
 use databend_common_management::StageApi;
 use databend_common_management::StageMgr;
+use databend_common_management::TaskMgr;
 use databend_common_management::UserApi;
 use databend_common_management::UserMgr;
 use databend_common_meta_app::principal::AuthInfo;
@@ -170,6 +172,14 @@ impl UserApiProvider {
         ClientSessionMgr::create(self.client.clone(), tenant)
     }
 
+    pub fn task_api(&self, tenant: &Tenant) -> Result<TaskMgr> {
+        TaskMgr::create(self.client.clone(), tenant)
+    }
+
+    pub fn plan_baseline_api(&self, tenant: &Tenant) -> Result<PlanBaselineMgr> {
+        PlanBaselineMgr::create(self.client.clone(), tenant)
+    }
+
     pub fn get_meta_store_client(&self) -> Arc<MetaStore> {
         Arc::new(self.meta.clone())
     }
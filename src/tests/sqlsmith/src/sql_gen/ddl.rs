@@ -199,6 +199,7 @@ impl<R: Rng> SqlGenerator<'_, R> {
             name: new_column_name,
             data_type,
             expr: None,
+            constraints: vec![],
             comment: None,
         }
     }
@@ -227,11 +228,12 @@ impl<R: Rng> SqlGenerator<'_, R> {
                 name: Identifier::from_name(None, name),
                 data_type,
                 expr: default_expr,
+                constraints: vec![],
                 comment: None,
             };
             column_defs.push(column_def);
         }
-        CreateTableSource::Columns(column_defs, None)
+        CreateTableSource::Columns(column_defs, None, None)
     }
 }
 
@@ -318,7 +318,10 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
                 };
                 hints_list.push(hint);
             }
-            Some(Hint { hints_list })
+            Some(Hint {
+                hints_list,
+                optimizer_hints: vec![],
+            })
         } else {
             None
         }
@@ -482,6 +485,7 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
                     name,
                     data_type,
                     expr: None,
+                    constraints: vec![],
                     comment: None,
                 };
                 (
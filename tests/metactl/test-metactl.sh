@@ -128,6 +128,48 @@ else
 fi
 
 
+echo " === "
+echo " === 4.1. Test get/list/upsert/delete keys in a running metasrv"
+echo " === "
+
+audit_log="$SCRIPT_PATH/_metactl_audit.log"
+rm -f "$audit_log"
+
+metactl() {
+    ./target/${BUILD_PROFILE}/databend-metactl "$@" --grpc-api-address "localhost:9191"
+}
+
+echo " === dry-run does not write"
+metactl upsert --key "metactl_test/a" --value "foo" --dry-run --audit-log "$audit_log"
+if metactl get --key "metactl_test/a" | grep -Fq '"record":null'; then
+    echo " === key is not written by dry-run, good!"
+else
+    echo " === key is written by dry-run!!!"
+    exit 1
+fi
+
+echo " === a raw value is not written into a protobuf key without --force"
+if metactl upsert --key "__fd_database_by_id/999999" --value "foo" --dry-run --audit-log "$audit_log"; then
+    echo " === raw value is accepted for a protobuf key!!!"
+    exit 1
+fi
+
+echo " === upsert, get and list"
+metactl upsert --key "metactl_test/a" --value "foo" --audit-log "$audit_log"
+metactl upsert --key "metactl_test/b" --value "bar" --audit-log "$audit_log"
+metactl get --key "metactl_test/a" | grep -Fq '"value":"foo"'
+metactl get --key "metactl_test/b" --raw | grep -Fq '"value":"0x626172"'
+test "$(metactl list --prefix "metactl_test/" | wc -l)" = "2"
+
+echo " === delete"
+metactl delete --key "metactl_test/a" --audit-log "$audit_log"
+test "$(metactl list --prefix "metactl_test/" | wc -l)" = "1"
+
+echo " === every applied change is audited, with the values hex encoded"
+cat "$audit_log"
+test "$(wc -l <"$audit_log")" = "3"
+grep -Fq '"value":"0x666f6f"' "$audit_log"
+
 kill $METASRV_PID
 
 sleep 3